@@ -6,40 +6,20 @@
 // care is needed: https://doc.rust-lang.org/cargo/reference/build-scripts.html
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Define our search paths, for this example we're using out test_msgs and std_msgs for ros1
-    // These can be pulled either from ROS_PACKAGE_PATH or given manually.
+    // These can also be pulled from ROS_PACKAGE_PATH with `.use_ros_package_path(true)`.
     // While ROS_PACKAGE_PATH can be very convenient, it can also be VERY confusing
     // We recommend using explicit paths only for more reliable and reproducible builds.
-    let p = vec![
-        "../assets/ros1_common_interfaces/std_msgs".into(),
-        "../assets/ros1_test_msgs".into(),
-    ];
-
-    // Actually invoke code generation on our search paths.
-    // What we get back is a TokenStream the type normally returned by a proc_macro in rust.
-    // For a build.rs we typically want to serialize this data to a file for later import
-    let (source, dependent_paths) =
-        roslibrust::codegen::find_and_generate_ros_messages_without_ros_package_path(p)?;
-
-    // It is important for build scripts to only output files to OUT_DIR.
-    // This guidance can be ignored for end applications. However, crates published and downloaded with cargo
-    // will not work if they rely on output files to other folders.
-    let out_dir = std::env::var_os("OUT_DIR").unwrap();
-    let dest_path = std::path::Path::new(&out_dir).join("messages.rs");
-    // Write the generate code to disk
-    // Note: it will not be nicely formatted at this point which can affect readability when debugging
-    std::fs::write(dest_path, source.to_string())?;
-    // Optionally rustfmt could be invoked to format the file at this point
-    // Or https://github.com/dtolnay/prettyplease used on the TokenStream ahead of writing to disk
-
-    // If we stopped at this point, our code would still work, but Cargo would not know to rebuild
-    // our package when a message file changed.
-    for path in &dependent_paths {
-        let path_str = path
-            .as_os_str()
-            .to_str()
-            .expect(&format!("Failed to convert path into utf8: {path:?}"));
-        cargo_emit::rerun_if_changed!(path_str);
-    }
+    // Search paths can be narrowed down further with `.include_package()` and `.exclude_package()`.
+    roslibrust::codegen::build_helper::BuildHelper::new()
+        .search_path("../assets/ros1_common_interfaces/std_msgs")
+        .search_path("../assets/ros1_test_msgs")
+        // Actually invoke code generation on our search paths.
+        // The generated code is written to OUT_DIR/messages.rs. It is important for build scripts to only output
+        // files to OUT_DIR, crates published and downloaded with cargo will not work if they write to other folders.
+        // A `cargo:rerun-if-changed` line is printed for every message file used, so that Cargo knows to rebuild
+        // our package when a message file changes.
+        // Note: the file will not be nicely formatted which can affect readability when debugging
+        .generate()?;
 
     Ok(())
 }
@@ -0,0 +1,303 @@
+//! Streams the state of an actionlib goal as it progresses, so e.g. a UI can show a goal being accepted, executed
+//! and canceled rather than only its final result, see [GoalTracker].
+//!
+//! The states and their transitions are those of [GoalStateMachine], re-exported here from `roslibrust_common`,
+//! filled in from the `actionlib_msgs/GoalStatusArray` the action server publishes on its `status` topic.
+//!
+//! ```no_run
+//! use roslibrust::actions::GoalTracker;
+//! use roslibrust::Ros;
+//!
+//! async fn run(ros: impl Ros, goal_id: String) -> roslibrust::Result<()> {
+//!     let mut goal = GoalTracker::new("/fibonacci", goal_id).start(&ros).await?;
+//!     while let Some(transition) = goal.next().await {
+//!         log::info!("Goal moved from {} to {}", transition.from, transition.to);
+//!     }
+//!     log::info!("Goal finished as {}", goal.state());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Result, Subscribe, TopicProvider};
+use abort_on_drop::ChildTask;
+use log::*;
+pub use roslibrust_common::actions::*;
+use tokio::sync::{mpsc, watch};
+
+/// The status messages of actionlib, `actionlib_msgs/GoalStatusArray` and the types it contains.
+pub mod ros1 {
+    use crate::RosMessageType;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Time {
+        pub secs: i32,
+        pub nsecs: i32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Header {
+        pub seq: u32,
+        pub stamp: Time,
+        pub frame_id: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct GoalID {
+        pub stamp: Time,
+        pub id: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct GoalStatus {
+        pub goal_id: GoalID,
+        /// One of the codes of [super::GoalState::status]
+        pub status: u8,
+        pub text: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct GoalStatusArray {
+        pub header: Header,
+        pub status_list: Vec<GoalStatus>,
+    }
+
+    impl RosMessageType for GoalStatusArray {
+        const ROS_TYPE_NAME: &'static str = "actionlib_msgs/GoalStatusArray";
+        const MD5SUM: &'static str = "8b2b82f13216d0a8ea88bd3af735e619";
+        const DEFINITION: &'static str = r####"# Stores the statuses for goals that are currently being tracked
+# by an action server
+Header header
+GoalStatus[] status_list
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data 
+# in a particular coordinate frame.
+# 
+# sequence ID: consecutively increasing ID 
+uint32 seq
+#Two-integer timestamp that is expressed as:
+# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')
+# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')
+# time-handling sugar is provided by the client library
+time stamp
+#Frame this data is associated with
+string frame_id
+================================================================================
+MSG: actionlib_msgs/GoalStatus
+GoalID goal_id
+uint8 status
+uint8 PENDING         = 0   # The goal has yet to be processed by the action server
+uint8 ACTIVE          = 1   # The goal is currently being processed by the action server
+uint8 PREEMPTED       = 2   # The goal received a cancel request after it started executing
+                            #   and has since completed its execution (Terminal State)
+uint8 SUCCEEDED       = 3   # The goal was achieved successfully by the action server (Terminal State)
+uint8 ABORTED         = 4   # The goal was aborted during execution by the action server due
+                            #    to some failure (Terminal State)
+uint8 REJECTED        = 5   # The goal was rejected by the action server without being processed,
+                            #    because the goal was unattainable or invalid (Terminal State)
+uint8 PREEMPTING      = 6   # The goal received a cancel request after it started executing
+                            #    and has not yet completed execution
+uint8 RECALLING       = 7   # The goal received a cancel request before it started executing,
+                            #    but the action server has not yet confirmed that the goal is canceled
+uint8 RECALLED        = 8   # The goal received a cancel request before it started executing
+                            #    and was successfully cancelled (Terminal State)
+uint8 LOST            = 9   # An action client can determine that a goal is LOST. This should not be
+                            #    sent over the wire by an action server
+
+#Allow for the user to associate a string with GoalStatus for debugging
+string text
+================================================================================
+MSG: actionlib_msgs/GoalID
+# The stamp should store the time at which this goal was requested.
+# It is used by an action server when it tries to preempt all
+# goals that were requested before a certain time
+time stamp
+
+# The id provides a way to associate feedback and
+# result message with specific goal requests. The id
+# specified must be unique.
+string id"####;
+    }
+}
+
+/// Follows one goal of an action server, streaming the transitions of its state.
+pub struct GoalTracker {
+    action: String,
+    goal_id: String,
+}
+
+impl GoalTracker {
+    /// Follows the goal with id `goal_id` sent to the action server at `action`, e.g. `/fibonacci`.
+    pub fn new(action: impl Into<String>, goal_id: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            goal_id: goal_id.into(),
+        }
+    }
+
+    /// Subscribes to the status of the action server on `ros`, the goal being [GoalState::Pending] until the
+    /// server reports it.
+    pub async fn start(self, ros: &impl TopicProvider) -> Result<GoalHandle> {
+        let topic = format!("{}/status", self.action.trim_end_matches('/'));
+        let mut subscriber = ros.subscribe::<ros1::GoalStatusArray>(&topic).await?;
+        let (state_sender, state) = watch::channel(GoalState::Pending);
+        // Unbounded so a slow reader never causes transitions to be dropped, a goal has only a handful of them
+        let (sender, transitions) = mpsc::unbounded_channel();
+        let goal_id = self.goal_id;
+        let task = tokio::spawn(async move {
+            let mut goal = GoalStateMachine::new();
+            let mut reported = false;
+            while !goal.state().is_terminal() {
+                let statuses = match subscriber.next().await {
+                    Ok(statuses) => statuses,
+                    Err(Error::Disconnected) => {
+                        warn!("Subscriber for {topic} disconnected, no longer tracking goal {goal_id}");
+                        return;
+                    }
+                    Err(e) => {
+                        warn!("Failed to receive status on {topic}: {e}");
+                        continue;
+                    }
+                };
+                let status = statuses
+                    .status_list
+                    .iter()
+                    .find(|status| status.goal_id.id == goal_id);
+                let transitions = match status {
+                    Some(status) => {
+                        reported = true;
+                        goal.update_status(status.status)
+                    }
+                    // The server has forgotten a goal it reported before it finished
+                    None if reported => goal.update(GoalState::Lost),
+                    None => continue,
+                };
+                for transition in transitions {
+                    debug!(
+                        "Goal {goal_id} moved from {} to {}",
+                        transition.from, transition.to
+                    );
+                    state_sender.send_replace(transition.to);
+                    let _ = sender.send(transition);
+                }
+            }
+        });
+        Ok(GoalHandle {
+            state,
+            transitions,
+            _task: task.into(),
+        })
+    }
+}
+
+/// A goal followed by a [GoalTracker], tracking stops when it is dropped.
+pub struct GoalHandle {
+    state: watch::Receiver<GoalState>,
+    transitions: mpsc::UnboundedReceiver<GoalTransition>,
+    _task: ChildTask<()>,
+}
+
+impl GoalHandle {
+    /// The current state of the goal, including transitions not yet read with [GoalHandle::next].
+    pub fn state(&self) -> GoalState {
+        *self.state.borrow()
+    }
+
+    /// Waits for the next transition of the goal, None once it reached a terminal state and all its transitions
+    /// were read, or tracking stopped because the subscriber disconnected.
+    pub async fn next(&mut self) -> Option<GoalTransition> {
+        self.transitions.recv().await
+    }
+
+    /// Waits for the goal to reach a terminal state, returning it.
+    pub async fn finished(&mut self) -> Result<GoalState> {
+        self.state
+            .wait_for(GoalState::is_terminal)
+            .await
+            .map(|state| *state)
+            .map_err(|_| Error::Disconnected)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Publish;
+    use roslibrust_mock::MockRos;
+
+    fn statuses(statuses: &[(&str, u8)]) -> ros1::GoalStatusArray {
+        ros1::GoalStatusArray {
+            status_list: statuses
+                .iter()
+                .map(|(id, status)| ros1::GoalStatus {
+                    goal_id: ros1::GoalID {
+                        id: id.to_string(),
+                        ..Default::default()
+                    },
+                    status: *status,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn streams_goal_transitions() {
+        let ros = MockRos::new();
+        let publisher = ros
+            .advertise::<ros1::GoalStatusArray>("/fibonacci/status")
+            .await
+            .unwrap();
+        let mut goal = GoalTracker::new("/fibonacci", "goal_1")
+            .start(&ros)
+            .await
+            .unwrap();
+        assert_eq!(goal.state(), GoalState::Pending);
+
+        for update in [
+            statuses(&[("goal_0", 3)]),
+            statuses(&[("goal_0", 3), ("goal_1", 0)]),
+            statuses(&[("goal_1", 1)]),
+            // Canceled and finished between two updates
+            statuses(&[("goal_1", 2)]),
+        ] {
+            publisher.publish(&update).await.unwrap();
+        }
+
+        let mut transitions = vec![];
+        while let Some(transition) = goal.next().await {
+            transitions.push((transition.to, transition.inferred));
+        }
+        assert_eq!(
+            transitions,
+            [
+                (GoalState::Active, false),
+                (GoalState::Preempting, true),
+                (GoalState::Preempted, false)
+            ]
+        );
+        assert_eq!(goal.finished().await.unwrap(), GoalState::Preempted);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn detects_lost_goals() {
+        let ros = MockRos::new();
+        let publisher = ros
+            .advertise::<ros1::GoalStatusArray>("/fibonacci/status")
+            .await
+            .unwrap();
+        let mut goal = GoalTracker::new("/fibonacci/", "goal_1")
+            .start(&ros)
+            .await
+            .unwrap();
+        publisher
+            .publish(&statuses(&[("goal_1", 1)]))
+            .await
+            .unwrap();
+        publisher.publish(&statuses(&[])).await.unwrap();
+        assert_eq!(goal.finished().await.unwrap(), GoalState::Lost);
+    }
+}
@@ -0,0 +1,309 @@
+//! Combinators processing the messages of any [Subscribe], see [SubscribeExt].
+//!
+//! Each adapter is itself a [Subscribe], so they chain and can be handed to code generic over subscribers:
+//!
+//! ```no_run
+//! use roslibrust::adapters::SubscribeExt;
+//! use roslibrust::{Ros, Subscribe};
+//! use roslibrust_test::ros1::{sensor_msgs, std_msgs};
+//! use std::time::Duration;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     let mut closest = ros
+//!         .subscribe::<sensor_msgs::LaserScan>("/scan")
+//!         .await?
+//!         .decimate(5)
+//!         .map(|scan| std_msgs::Float32 {
+//!             data: scan.ranges.iter().copied().fold(f32::INFINITY, f32::min),
+//!         })
+//!         .filter(|closest| closest.data.is_finite())
+//!         .latest_only();
+//!     loop {
+//!         let closest = closest.next().await?;
+//!         log::info!("Closest obstacle at {}m", closest.data);
+//!     }
+//! }
+//! ```
+
+use crate::{Error, Result, RosMessageType, Subscribe};
+use abort_on_drop::ChildTask;
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+use tokio::{sync::Notify, time::Instant};
+
+/// Adapters available on every [Subscribe], see the [module level documentation](self).
+///
+/// Adapters which wait on time or run in the background ([SubscribeExt::debounce], [SubscribeExt::latest_only])
+/// require the subscriber's `next` to be cancel safe, as it is for all of roslibrust's backends.
+pub trait SubscribeExt<T: RosMessageType>: Subscribe<T> + Sized {
+    /// Converts every message with `f`.
+    fn map<U: RosMessageType, F: FnMut(T) -> U + Send>(self, f: F) -> Map<Self, F, T> {
+        Map {
+            inner: self,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Only returns the messages for which `predicate` returns true.
+    fn filter<F: FnMut(&T) -> bool + Send>(self, predicate: F) -> Filter<Self, F> {
+        Filter {
+            inner: self,
+            predicate,
+        }
+    }
+
+    /// Only returns every `n`th message, starting with the first, like topic_tools' `drop`.
+    /// `n` of 0 is treated as 1.
+    fn decimate(self, n: usize) -> Decimate<Self> {
+        Decimate {
+            inner: self,
+            n: n.max(1),
+            skipped: 0,
+        }
+    }
+
+    /// Only returns a message once `quiet` has passed without a newer one arriving, returning the newest of a burst.
+    fn debounce(self, quiet: Duration) -> Debounce<Self, T> {
+        Debounce {
+            inner: self,
+            quiet,
+            pending: None,
+        }
+    }
+
+    /// Receives messages in a background task, so a slow consumer skips to the newest message instead of
+    /// working through a backlog. The task stops after the subscriber returns an error, which is returned once,
+    /// followed by [Error::Disconnected].
+    fn latest_only(self) -> LatestOnly<T>
+    where
+        Self: Send + 'static,
+    {
+        LatestOnly::new(self)
+    }
+}
+
+impl<T: RosMessageType, S: Subscribe<T>> SubscribeExt<T> for S {}
+
+/// See [SubscribeExt::map].
+pub struct Map<S, F, T> {
+    inner: S,
+    f: F,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<S, F, T, U> Subscribe<U> for Map<S, F, T>
+where
+    S: Subscribe<T> + Send,
+    F: FnMut(T) -> U + Send,
+    T: RosMessageType,
+    U: RosMessageType,
+{
+    async fn next(&mut self) -> Result<U> {
+        let msg = self.inner.next().await?;
+        Ok((self.f)(msg))
+    }
+}
+
+/// See [SubscribeExt::filter].
+pub struct Filter<S, F> {
+    inner: S,
+    predicate: F,
+}
+
+impl<S, F, T> Subscribe<T> for Filter<S, F>
+where
+    S: Subscribe<T> + Send,
+    F: FnMut(&T) -> bool + Send,
+    T: RosMessageType,
+{
+    async fn next(&mut self) -> Result<T> {
+        loop {
+            let msg = self.inner.next().await?;
+            if (self.predicate)(&msg) {
+                return Ok(msg);
+            }
+        }
+    }
+}
+
+/// See [SubscribeExt::decimate].
+pub struct Decimate<S> {
+    inner: S,
+    n: usize,
+    // Messages still to skip before the next one is returned
+    skipped: usize,
+}
+
+impl<S: Subscribe<T> + Send, T: RosMessageType> Subscribe<T> for Decimate<S> {
+    async fn next(&mut self) -> Result<T> {
+        loop {
+            let msg = self.inner.next().await?;
+            if self.skipped == 0 {
+                self.skipped = self.n - 1;
+                return Ok(msg);
+            }
+            self.skipped -= 1;
+        }
+    }
+}
+
+/// See [SubscribeExt::debounce].
+pub struct Debounce<S, T> {
+    inner: S,
+    quiet: Duration,
+    // The newest message and when it arrived, kept across cancelled calls
+    pending: Option<(T, Instant)>,
+}
+
+impl<S: Subscribe<T> + Send, T: RosMessageType> Subscribe<T> for Debounce<S, T> {
+    async fn next(&mut self) -> Result<T> {
+        loop {
+            let deadline = match &self.pending {
+                Some((_, received)) => *received + self.quiet,
+                None => {
+                    let msg = self.inner.next().await?;
+                    self.pending = Some((msg, Instant::now()));
+                    continue;
+                }
+            };
+            tokio::select! {
+                msg = self.inner.next() => self.pending = Some((msg?, Instant::now())),
+                _ = tokio::time::sleep_until(deadline) => {
+                    if let Some((msg, _)) = self.pending.take() {
+                        return Ok(msg);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The newest message received by a [LatestOnly]'s task, and a signal that it was replaced
+struct Latest<T> {
+    value: Mutex<Option<Result<T>>>,
+    updated: Notify,
+}
+
+/// See [SubscribeExt::latest_only].
+pub struct LatestOnly<T> {
+    latest: Arc<Latest<T>>,
+    task: ChildTask<()>,
+}
+
+impl<T: RosMessageType> LatestOnly<T> {
+    fn new<S: Subscribe<T> + Send + 'static>(mut inner: S) -> Self {
+        let latest = Arc::new(Latest {
+            value: Mutex::new(None),
+            updated: Notify::new(),
+        });
+        let task = tokio::spawn({
+            let latest = latest.clone();
+            async move {
+                loop {
+                    let msg = inner.next().await;
+                    let failed = msg.is_err();
+                    *latest.value.lock().unwrap_or_else(PoisonError::into_inner) = Some(msg);
+                    latest.updated.notify_one();
+                    if failed {
+                        return;
+                    }
+                }
+            }
+        });
+        Self {
+            latest,
+            task: task.into(),
+        }
+    }
+}
+
+impl<T: RosMessageType> Subscribe<T> for LatestOnly<T> {
+    async fn next(&mut self) -> Result<T> {
+        loop {
+            let value = self
+                .latest
+                .value
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take();
+            match value {
+                Some(value) => return value,
+                None if self.task.is_finished() => return Err(Error::Disconnected),
+                None => self.latest.updated.notified().await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Publish, TopicProvider};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+
+    fn uint(data: u32) -> std_msgs::UInt32 {
+        std_msgs::UInt32 { data }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn adapters_compose() {
+        let ros = MockRos::new();
+        let publisher = ros.advertise::<std_msgs::UInt32>("/numbers").await.unwrap();
+        let mut subscriber = ros
+            .subscribe::<std_msgs::UInt32>("/numbers")
+            .await
+            .unwrap()
+            .decimate(2)
+            .filter(|msg| msg.data % 4 == 0)
+            .map(|msg| std_msgs::String {
+                data: msg.data.to_string(),
+            });
+        for i in 0..10 {
+            publisher.publish(&uint(i)).await.unwrap();
+        }
+        // Every other message is 0, 2, 4, 6, 8, of which 0, 4, 8 are multiples of 4
+        for expected in ["0", "4", "8"] {
+            assert_eq!(subscriber.next().await.unwrap().data, expected);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn debounce_returns_newest_of_burst() {
+        let ros = MockRos::new();
+        let publisher = ros.advertise::<std_msgs::UInt32>("/numbers").await.unwrap();
+        let mut subscriber = ros
+            .subscribe::<std_msgs::UInt32>("/numbers")
+            .await
+            .unwrap()
+            .debounce(Duration::from_millis(50));
+        for i in 0..3 {
+            publisher.publish(&uint(i)).await.unwrap();
+        }
+        assert_eq!(subscriber.next().await.unwrap(), uint(2));
+        publisher.publish(&uint(3)).await.unwrap();
+        assert_eq!(subscriber.next().await.unwrap(), uint(3));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn latest_only_skips_backlog() {
+        let ros = MockRos::new();
+        let publisher = ros.advertise::<std_msgs::UInt32>("/numbers").await.unwrap();
+        let mut subscriber = ros
+            .subscribe::<std_msgs::UInt32>("/numbers")
+            .await
+            .unwrap()
+            .latest_only();
+        for i in 0..5 {
+            publisher.publish(&uint(i)).await.unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(subscriber.next().await.unwrap(), uint(4));
+        publisher.publish(&uint(5)).await.unwrap();
+        assert_eq!(subscriber.next().await.unwrap(), uint(5));
+    }
+}
@@ -0,0 +1,429 @@
+//! Forwards topics and services between two [Ros] backends, typically a ROS1 node and a ROS2 (zenoh) session.
+//!
+//! The topics and services to bridge are described by a [BridgeConfig], which can be loaded from a file, while
+//! the message and service types able to cross the bridge are registered on a [BridgeBuilder] as pairs of the
+//! ROS1 and ROS2 generated types. Messages are translated between the two field by field with [translate], so
+//! fields only present on one side (e.g. `seq` in a ROS1 `std_msgs/Header`) are dropped or left at their default,
+//! and the ROS2 names for time and duration fields (`sec` / `nanosec`) are matched to the ROS1 ones (`secs` / `nsecs`).
+//!
+//! ```no_run
+//! use roslibrust::bridge::{BridgeBuilder, BridgeConfig};
+//! use roslibrust::Ros;
+//! use roslibrust_test::{ros1, ros2};
+//!
+//! async fn run(ros1: impl Ros, ros2: impl Ros) -> roslibrust::Result<()> {
+//!     let config: BridgeConfig = serde_json::from_str(
+//!         r#"{
+//!             "topics": [{ "topic": "/chatter", "type": "std_msgs/String", "direction": "ros1_to_ros2" }],
+//!             "services": [{ "service": "/reset", "type": "std_srvs/Trigger", "direction": "ros2_to_ros1" }]
+//!         }"#,
+//!     )
+//!     .unwrap();
+//!     let _bridge = BridgeBuilder::new(ros1, ros2)
+//!         .message::<ros1::std_msgs::String, ros2::std_msgs::String>()
+//!         .service::<ros1::std_srvs::Trigger, ros2::std_srvs::Trigger>()
+//!         .start(&config)
+//!         .await?;
+//!     // Forwards until the bridge is dropped
+//!     let _ = tokio::signal::ctrl_c().await;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Publish, Result, Ros, RosMessageType, RosServiceType, Subscribe};
+use abort_on_drop::ChildTask;
+use log::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::HashMap, future::Future, pin::Pin};
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+/// Starts forwarding a topic between the backends, returning the task doing the forwarding
+type TopicFn<R1, R2> =
+    Box<dyn Fn(R1, R2, TopicMapping) -> BoxFuture<Result<ChildTask<()>>> + Send + Sync + 'static>;
+
+/// Advertises a service on one backend which forwards calls to the other, returning the service's handle
+type ServiceFn<R1, R2> =
+    Box<dyn Fn(R1, R2, ServiceMapping) -> BoxFuture<Result<Box<dyn Send>>> + Send + Sync + 'static>;
+
+/// Which way messages, or service requests, travel across the bridge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// For topics, messages published on ROS1 are published on ROS2.
+    /// For services, the service is advertised on ROS1 and calls are forwarded to the ROS2 server.
+    Ros1ToRos2,
+    /// For topics, messages published on ROS2 are published on ROS1.
+    /// For services, the service is advertised on ROS2 and calls are forwarded to the ROS1 server.
+    Ros2ToRos1,
+}
+
+/// A topic to forward from one backend to the other.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TopicMapping {
+    /// Name of the topic on the side messages come from.
+    pub topic: String,
+    /// Name of the topic on the side messages go to, defaults to `topic`.
+    #[serde(default)]
+    pub remap: Option<String>,
+    /// Type of the topic, matching either type registered with [BridgeBuilder::message].
+    #[serde(rename = "type")]
+    pub msg_type: String,
+    pub direction: Direction,
+}
+
+/// A service to make available on one backend, served by the other.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ServiceMapping {
+    /// Name of the service as it is advertised by the bridge.
+    pub service: String,
+    /// Name of the service the bridge forwards calls to, defaults to `service`.
+    #[serde(default)]
+    pub remap: Option<String>,
+    /// Type of the service, matching either type registered with [BridgeBuilder::service].
+    #[serde(rename = "type")]
+    pub srv_type: String,
+    pub direction: Direction,
+}
+
+/// Describes everything a bridge forwards, deserializable from e.g. a JSON or YAML file.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct BridgeConfig {
+    #[serde(default)]
+    pub topics: Vec<TopicMapping>,
+    #[serde(default)]
+    pub services: Vec<ServiceMapping>,
+}
+
+/// Registers the types a bridge is able to translate, see the [module level documentation](self) for an example.
+pub struct BridgeBuilder<R1: Ros, R2: Ros> {
+    ros1: R1,
+    ros2: R2,
+    // Keyed by both the ROS1 and ROS2 names of each type, which normally match
+    messages: HashMap<String, TopicFn<R1, R2>>,
+    services: HashMap<String, ServiceFn<R1, R2>>,
+}
+
+impl<R1: Ros, R2: Ros> BridgeBuilder<R1, R2> {
+    pub fn new(ros1: R1, ros2: R2) -> Self {
+        Self {
+            ros1,
+            ros2,
+            messages: HashMap::new(),
+            services: HashMap::new(),
+        }
+    }
+
+    /// Allows topics of the given ROS1 and ROS2 message types to be bridged.
+    pub fn message<M1, M2>(mut self) -> Self
+    where
+        M1: RosMessageType + Default,
+        M2: RosMessageType + Default,
+    {
+        for name in [M1::ROS_TYPE_NAME, M2::ROS_TYPE_NAME] {
+            let start =
+                |ros1: R1, ros2: R2, mapping: TopicMapping| -> BoxFuture<Result<ChildTask<()>>> {
+                    Box::pin(async move {
+                        match mapping.direction {
+                            Direction::Ros1ToRos2 => {
+                                forward_topic::<M1, M2>(&ros1, &ros2, mapping).await
+                            }
+                            Direction::Ros2ToRos1 => {
+                                forward_topic::<M2, M1>(&ros2, &ros1, mapping).await
+                            }
+                        }
+                    })
+                };
+            self.messages.insert(name.to_string(), Box::new(start));
+        }
+        self
+    }
+
+    /// Allows services of the given ROS1 and ROS2 service types to be bridged.
+    pub fn service<S1, S2>(mut self) -> Self
+    where
+        S1: RosServiceType,
+        S2: RosServiceType,
+        S1::Request: Default,
+        S1::Response: Default,
+        S2::Request: Default,
+        S2::Response: Default,
+    {
+        for name in [S1::ROS_SERVICE_NAME, S2::ROS_SERVICE_NAME] {
+            let start =
+                |ros1: R1, ros2: R2, mapping: ServiceMapping| -> BoxFuture<Result<Box<dyn Send>>> {
+                    Box::pin(async move {
+                        match mapping.direction {
+                            Direction::Ros1ToRos2 => {
+                                forward_service::<S1, S2>(&ros1, ros2, mapping).await
+                            }
+                            Direction::Ros2ToRos1 => {
+                                forward_service::<S2, S1>(&ros2, ros1, mapping).await
+                            }
+                        }
+                    })
+                };
+            self.services.insert(name.to_string(), Box::new(start));
+        }
+        self
+    }
+
+    /// Starts forwarding everything described by `config`.
+    ///
+    /// Fails if the config refers to a type which hasn't been registered or any topic or service can't be set up,
+    /// in which case nothing is left running.
+    pub async fn start(self, config: &BridgeConfig) -> Result<Bridge> {
+        let mut bridge = Bridge {
+            _topics: Vec::new(),
+            _services: Vec::new(),
+        };
+        for mapping in &config.topics {
+            let Some(start) = self.messages.get(&mapping.msg_type) else {
+                return Err(Error::SerializationError(format!(
+                    "Can't bridge topic {}, type {} hasn't been registered",
+                    mapping.topic, mapping.msg_type
+                )));
+            };
+            let task = start(self.ros1.clone(), self.ros2.clone(), mapping.clone()).await?;
+            debug!("Bridging topic {} ({:?})", mapping.topic, mapping.direction);
+            bridge._topics.push(task);
+        }
+        for mapping in &config.services {
+            let Some(start) = self.services.get(&mapping.srv_type) else {
+                return Err(Error::SerializationError(format!(
+                    "Can't bridge service {}, type {} hasn't been registered",
+                    mapping.service, mapping.srv_type
+                )));
+            };
+            let handle = start(self.ros1.clone(), self.ros2.clone(), mapping.clone()).await?;
+            debug!(
+                "Bridging service {} ({:?})",
+                mapping.service, mapping.direction
+            );
+            bridge._services.push(handle);
+        }
+        Ok(bridge)
+    }
+}
+
+/// A running bridge, everything stops being forwarded when it is dropped.
+pub struct Bridge {
+    _topics: Vec<ChildTask<()>>,
+    _services: Vec<Box<dyn Send>>,
+}
+
+async fn forward_topic<A, B>(
+    from: &impl Ros,
+    to: &impl Ros,
+    mapping: TopicMapping,
+) -> Result<ChildTask<()>>
+where
+    A: RosMessageType,
+    B: RosMessageType + Default,
+{
+    let target = mapping.remap.as_deref().unwrap_or(&mapping.topic);
+    let mut subscriber = from.subscribe::<A>(mapping.topic.as_str()).await?;
+    let publisher = to.advertise::<B>(target).await?;
+    let topic = mapping.topic;
+    Ok(tokio::spawn(async move {
+        loop {
+            let msg = match subscriber.next().await {
+                Ok(msg) => msg,
+                Err(Error::Disconnected) => {
+                    warn!(
+                        "Subscriber for bridged topic {topic} disconnected, no longer bridging it"
+                    );
+                    return;
+                }
+                Err(e) => {
+                    warn!("Failed to receive message on bridged topic {topic}: {e}");
+                    continue;
+                }
+            };
+            match translate::<A, B>(&msg) {
+                Ok(msg) => {
+                    if let Err(e) = publisher.publish(&msg).await {
+                        warn!("Failed to publish message on bridged topic {topic}: {e}");
+                    }
+                }
+                Err(e) => warn!("Failed to translate message on bridged topic {topic}: {e}"),
+            }
+        }
+    })
+    .into())
+}
+
+async fn forward_service<A, B>(
+    offer_on: &impl Ros,
+    call_on: impl Ros,
+    mapping: ServiceMapping,
+) -> Result<Box<dyn Send>>
+where
+    A: RosServiceType,
+    B: RosServiceType,
+    A::Response: Default,
+    B::Request: Default,
+{
+    let target = mapping.remap.unwrap_or_else(|| mapping.service.clone());
+    // Service functions are run inside spawn_blocking, so can block on the call to the other side
+    let runtime = tokio::runtime::Handle::current();
+    let server = move |request: A::Request| {
+        let request = translate::<A::Request, B::Request>(&request)?;
+        let response = runtime.block_on(call_on.call_service::<B>(target.as_str(), request))?;
+        Ok(translate::<B::Response, A::Response>(&response)?)
+    };
+    let handle = offer_on
+        .advertise_service::<A, _>(mapping.service.as_str(), server)
+        .await?;
+    Ok(Box::new(handle))
+}
+
+/// Converts a message into another type with the same fields, e.g. between the ROS1 and ROS2 versions of a message.
+///
+/// Fields are matched by name, with ROS2's `sec` and `nanosec` also matching ROS1's `secs` and `nsecs`.
+/// Fields the target type doesn't have are dropped, and fields missing from the message are left at their default.
+/// Elements of arrays are converted as they are, so must have the same fields on both sides.
+pub fn translate<A: Serialize, B: DeserializeOwned + Serialize + Default>(msg: &A) -> Result<B> {
+    let template =
+        serde_json::to_value(B::default()).map_err(|e| Error::SerializationError(e.to_string()))?;
+    let value = serde_json::to_value(msg).map_err(|e| Error::SerializationError(e.to_string()))?;
+    serde_json::from_value(merge(template, value))
+        .map_err(|e| Error::SerializationError(e.to_string()))
+}
+
+/// Overwrites the fields of `template` with the matching fields of `value`
+fn merge(template: Value, value: Value) -> Value {
+    match (template, value) {
+        (Value::Object(mut template), Value::Object(mut value)) => {
+            for (name, field) in template.iter_mut() {
+                let incoming = value
+                    .remove(name)
+                    .or_else(|| alias(name).and_then(|alias| value.remove(alias)));
+                if let Some(incoming) = incoming {
+                    *field = merge(field.take(), incoming);
+                }
+            }
+            Value::Object(template)
+        }
+        (_, value) => value,
+    }
+}
+
+/// The other name of fields ROS2 renamed from ROS1
+fn alias(name: &str) -> Option<&'static str> {
+    match name {
+        "secs" => Some("sec"),
+        "sec" => Some("secs"),
+        "nsecs" => Some("nanosec"),
+        "nanosec" => Some("nsecs"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ServiceProvider, TopicProvider};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::{ros1, ros2};
+    use std::time::Duration;
+
+    #[test]
+    fn translates_between_ros1_and_ros2() {
+        let header = ros1::std_msgs::Header {
+            seq: 3,
+            stamp: roslibrust_codegen::integral_types::Time { secs: 1, nsecs: 2 },
+            frame_id: "map".to_string(),
+        };
+        let translated: ros2::std_msgs::Header = translate(&header).unwrap();
+        assert_eq!(translated.stamp, header.stamp);
+        assert_eq!(translated.frame_id, "map");
+
+        let back: ros1::std_msgs::Header = translate(&translated).unwrap();
+        assert_eq!(back.seq, 0);
+        assert_eq!(back.stamp, header.stamp);
+
+        // Hand written types may use the ROS2 field names
+        #[derive(Serialize)]
+        struct Time {
+            sec: i32,
+            nanosec: u32,
+        }
+        let time: roslibrust_codegen::integral_types::Time =
+            translate(&Time { sec: 5, nanosec: 6 }).unwrap();
+        assert_eq!((time.secs, time.nsecs), (5, 6));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn bridges_topics_and_services() {
+        let ros1 = MockRos::new();
+        let ros2 = MockRos::new();
+        let config: BridgeConfig = serde_json::from_value(serde_json::json!({
+            "topics": [{
+                "topic": "/header",
+                "remap": "/header2",
+                "type": "std_msgs/Header",
+                "direction": "ros1_to_ros2",
+            }],
+            "services": [{
+                "service": "/set",
+                "type": "std_srvs/SetBool",
+                "direction": "ros1_to_ros2",
+            }],
+        }))
+        .unwrap();
+
+        let _server = ros2
+            .advertise_service::<ros2::std_srvs::SetBool, _>(
+                "/set",
+                |request: ros2::std_srvs::SetBoolRequest| {
+                    Ok(ros2::std_srvs::SetBoolResponse {
+                        success: request.data,
+                        message: "set".to_string(),
+                    })
+                },
+            )
+            .await
+            .unwrap();
+        let mut subscriber = ros2
+            .subscribe::<ros2::std_msgs::Header>("/header2")
+            .await
+            .unwrap();
+
+        let _bridge = BridgeBuilder::new(ros1.clone(), ros2.clone())
+            .message::<ros1::std_msgs::Header, ros2::std_msgs::Header>()
+            .service::<ros1::std_srvs::SetBool, ros2::std_srvs::SetBool>()
+            .start(&config)
+            .await
+            .unwrap();
+
+        let publisher = ros1
+            .advertise::<ros1::std_msgs::Header>("/header")
+            .await
+            .unwrap();
+        publisher
+            .publish(&ros1::std_msgs::Header {
+                seq: 1,
+                frame_id: "base_link".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(1), subscriber.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(received.frame_id, "base_link");
+
+        let response = ros1
+            .call_service::<ros1::std_srvs::SetBool>(
+                "/set",
+                ros1::std_srvs::SetBoolRequest { data: true },
+            )
+            .await
+            .unwrap();
+        assert!(response.success);
+        assert_eq!(response.message, "set");
+    }
+}
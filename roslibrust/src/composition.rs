@@ -0,0 +1,561 @@
+//! Runs several components inside one process on one backend connection, the equivalent of nodelets or ROS2
+//! composition, see [Container].
+//!
+//! Each component is given a [ComponentRos], a [Ros] implementation resolving names within the component's own
+//! namespace and remappings. Messages published by one component reach the subscribers of other components in
+//! the container directly, without being serialized or passing through the backend, while still reaching
+//! subscribers outside of the container through the backend as usual.
+//!
+//! ```no_run
+//! use roslibrust::composition::Container;
+//! use roslibrust::{Publish, Ros, Subscribe, TopicProvider};
+//! use roslibrust_test::ros1::std_msgs;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     let container = Container::new(ros);
+//!     // Publishes on /camera/image_raw
+//!     container.load("/camera/driver", |ros, mut shutdown| async move {
+//!         let publisher = ros.advertise::<std_msgs::String>("image_raw").await?;
+//!         while !shutdown.is_shutdown() {
+//!             publisher.publish(&std_msgs::String { data: "frame".to_string() }).await?;
+//!             tokio::time::sleep(std::time::Duration::from_millis(33)).await;
+//!         }
+//!         Ok(())
+//!     })?;
+//!     // Receives the frames of the driver without them being copied through the backend
+//!     container.load("/camera/rectify", |ros, mut shutdown| async move {
+//!         let mut images = ros.subscribe::<std_msgs::String>("image_raw").await?;
+//!         loop {
+//!             tokio::select! {
+//!                 _ = shutdown.wait() => return Ok(()),
+//!                 image = images.next_shared() => log::info!("Rectifying {}", image?.data),
+//!             }
+//!         }
+//!     })?;
+//!
+//!     tokio::signal::ctrl_c().await?;
+//!     container.shutdown().await;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    node::Shutdown, Error, Publish, Remappings, Result, Ros, RosMessageType, RosServiceType,
+    ServiceFn, ServiceProvider, Subscribe, ToServiceName, ToTopicName, TopicName, TopicProvider,
+};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    any::{Any, TypeId},
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    future::Future,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{
+    sync::{broadcast, watch},
+    time::Instant,
+};
+
+/// How many messages of a topic a subscriber in the container may fall behind the publishers in it
+const LOCAL_QUEUE_SIZE: usize = 100;
+
+/// How long a subscriber expects a message received from within the container to arrive through the backend too
+const LOOPBACK_WINDOW: Duration = Duration::from_secs(1);
+
+/// The channels carrying messages between the components of a container, by topic and message type
+type Bus = Arc<Mutex<HashMap<(String, TypeId), Box<dyn Any + Send + Sync>>>>;
+
+/// A message sent within the container, with a fingerprint to recognize it when it arrives through the backend
+#[derive(Clone)]
+struct Local<T> {
+    msg: Arc<T>,
+    fingerprint: u64,
+}
+
+fn fingerprint<T: RosMessageType>(msg: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_vec(msg)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The channel of `topic` within the container, created on first use
+fn channel<T: RosMessageType>(bus: &Bus, topic: &TopicName) -> broadcast::Sender<Local<T>> {
+    let mut channels = bus.lock().unwrap();
+    channels
+        .entry((topic.to_string(), TypeId::of::<T>()))
+        .or_insert_with(|| Box::new(broadcast::channel::<Local<T>>(LOCAL_QUEUE_SIZE).0))
+        .downcast_ref::<broadcast::Sender<Local<T>>>()
+        .expect("Channels are keyed by their message type")
+        .clone()
+}
+
+/// The handle to ros given to a component of a [Container].
+///
+/// Relative and private names are resolved within the component's name, e.g. "image" to "/camera/image" and
+/// "~rate" to "/camera/driver/rate" for "/camera/driver", after which the component's remappings are applied.
+#[derive(Clone)]
+pub struct ComponentRos<R> {
+    ros: R,
+    name: String,
+    remappings: Arc<Remappings>,
+    bus: Bus,
+}
+
+impl<R: Ros> ComponentRos<R> {
+    /// The fully qualified name of the component
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The handle to the backend shared by all components of the container
+    pub fn backend(&self) -> &R {
+        &self.ros
+    }
+}
+
+impl<R: Ros> TopicProvider for ComponentRos<R> {
+    type Publisher<T: RosMessageType> = ComponentPublisher<T, R>;
+    type Subscriber<T: RosMessageType> = ComponentSubscriber<T, R>;
+
+    async fn advertise<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Publisher<T>> {
+        let topic = self
+            .remappings
+            .resolve_topic(topic, Some(self.name.as_str()))?;
+        let remote = self.ros.advertise::<T>(&topic).await?;
+        Ok(ComponentPublisher {
+            local: channel(&self.bus, &topic),
+            remote,
+        })
+    }
+
+    async fn subscribe<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Subscriber<T>> {
+        let topic = self
+            .remappings
+            .resolve_topic(topic, Some(self.name.as_str()))?;
+        let remote = self.ros.subscribe::<T>(&topic).await?;
+        Ok(ComponentSubscriber {
+            local: channel(&self.bus, &topic).subscribe(),
+            remote,
+            queued: VecDeque::new(),
+            expected: VecDeque::new(),
+        })
+    }
+}
+
+impl<R: Ros> ServiceProvider for ComponentRos<R> {
+    type ServiceClient<T: RosServiceType> = R::ServiceClient<T>;
+    type ServiceServer = R::ServiceServer;
+
+    async fn call_service<T: RosServiceType>(
+        &self,
+        service: impl ToServiceName + Send,
+        request: T::Request,
+    ) -> Result<T::Response> {
+        let service = self
+            .remappings
+            .resolve_service(service, Some(self.name.as_str()))?;
+        self.ros.call_service::<T>(&service, request).await
+    }
+
+    async fn service_client<T: RosServiceType + 'static>(
+        &self,
+        service: impl ToServiceName + Send,
+    ) -> Result<Self::ServiceClient<T>> {
+        let service = self
+            .remappings
+            .resolve_service(service, Some(self.name.as_str()))?;
+        self.ros.service_client::<T>(&service).await
+    }
+
+    async fn advertise_service<T: RosServiceType + 'static, F>(
+        &self,
+        service: impl ToServiceName + Send,
+        server: F,
+    ) -> Result<Self::ServiceServer>
+    where
+        F: ServiceFn<T>,
+    {
+        let service = self
+            .remappings
+            .resolve_service(service, Some(self.name.as_str()))?;
+        self.ros.advertise_service::<T, F>(&service, server).await
+    }
+}
+
+/// A publisher of a component, handing its messages directly to the subscribers within the container and through
+/// the backend to everyone else.
+pub struct ComponentPublisher<T: RosMessageType, R: TopicProvider> {
+    local: broadcast::Sender<Local<T>>,
+    remote: R::Publisher<T>,
+}
+
+impl<T: RosMessageType, R: TopicProvider> Publish<T> for ComponentPublisher<T, R> {
+    fn publish(&self, data: &T) -> impl Future<Output = Result<()>> + Send {
+        // Within the container first, so the subscribers know of the message before its copy through the backend
+        // arrives. Without subscribers in the container there is nobody to send it to.
+        let _ = self.local.send(Local {
+            msg: Arc::new(data.clone()),
+            fingerprint: fingerprint(data),
+        });
+        self.remote.publish(data)
+    }
+}
+
+/// A subscriber of a component, receiving the messages published within the container directly and those
+/// published elsewhere through the backend.
+///
+/// The backend also delivers the messages published within the container, those copies are recognized by their
+/// content and skipped. Should the backend not deliver a copy within a second, an identical message published
+/// elsewhere in that time may be skipped in its place.
+pub struct ComponentSubscriber<T: RosMessageType, R: TopicProvider> {
+    local: broadcast::Receiver<Local<T>>,
+    remote: R::Subscriber<T>,
+    // Received within the container while checking a message from the backend, to be returned first
+    queued: VecDeque<Arc<T>>,
+    // The fingerprints of the messages received within the container, whose copies are yet to skip
+    expected: VecDeque<(Instant, u64)>,
+}
+
+impl<T: RosMessageType, R: TopicProvider> ComponentSubscriber<T, R> {
+    /// Receives the next message, shared with the other subscribers within the container instead of copied for each.
+    pub async fn next_shared(&mut self) -> Result<Arc<T>> {
+        loop {
+            if let Some(msg) = self.queued.pop_front() {
+                return Ok(msg);
+            }
+            tokio::select! {
+                local = self.local.recv() => match local {
+                    Ok(local) => {
+                        self.expect(local.fingerprint);
+                        return Ok(local.msg);
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Subscriber fell behind the publishers in its container, skipped {skipped} messages");
+                    }
+                    // The container holds on to the sender of every channel
+                    Err(broadcast::error::RecvError::Closed) => return Err(Error::Disconnected),
+                },
+                remote = self.remote.next() => {
+                    let msg = remote?;
+                    // Publishers send within the container before through the backend, so the original of a
+                    // copy is already waiting
+                    self.receive_local();
+                    if !self.is_copy(&msg) {
+                        return Ok(Arc::new(msg));
+                    }
+                }
+            }
+        }
+    }
+
+    fn expect(&mut self, fingerprint: u64) {
+        self.expected.push_back((Instant::now(), fingerprint));
+    }
+
+    /// Queues the messages already sent within the container
+    fn receive_local(&mut self) {
+        loop {
+            match self.local.try_recv() {
+                Ok(local) => {
+                    self.expect(local.fingerprint);
+                    self.queued.push_back(local.msg);
+                }
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    warn!("Subscriber fell behind the publishers in its container, skipped {skipped} messages");
+                }
+                Err(_) => return,
+            }
+        }
+    }
+
+    /// Whether `msg` came through the backend after being received within the container
+    fn is_copy(&mut self, msg: &T) -> bool {
+        while self
+            .expected
+            .front()
+            .is_some_and(|(received, _)| received.elapsed() > LOOPBACK_WINDOW)
+        {
+            self.expected.pop_front();
+        }
+        let fingerprint = fingerprint(msg);
+        match self.expected.iter().position(|(_, f)| *f == fingerprint) {
+            Some(index) => {
+                self.expected.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T: RosMessageType, R: TopicProvider> Subscribe<T> for ComponentSubscriber<T, R> {
+    async fn next(&mut self) -> Result<T> {
+        let msg = self.next_shared().await?;
+        Ok(Arc::try_unwrap(msg).unwrap_or_else(|msg| (*msg).clone()))
+    }
+}
+
+/// What a component of a [Container] is doing.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ComponentState {
+    Running,
+    /// The component returned `Ok(())`
+    Finished,
+    /// The component returned an error or panicked
+    Failed(String),
+}
+
+struct Loaded {
+    name: String,
+    state: Arc<Mutex<ComponentState>>,
+    shutdown: watch::Sender<bool>,
+    task: ChildTask<()>,
+}
+
+/// Runs components sharing one backend handle, each within its own namespace and with its own lifecycle.
+///
+/// Components are loaded and unloaded at any time, each is given a [ComponentRos] and a [Shutdown] signal it
+/// should exit on, like the tasks of a [crate::node::NodeBuilder]. A component may itself build a node on its
+/// [ComponentRos]. Dropping the container aborts every component, use [Container::shutdown] to stop them gracefully.
+pub struct Container<R: Ros> {
+    ros: R,
+    bus: Bus,
+    components: Mutex<Vec<Loaded>>,
+    unload_timeout: Duration,
+}
+
+impl<R: Ros> Container<R> {
+    /// Creates an empty container whose components share `ros`.
+    pub fn new(ros: R) -> Self {
+        Self {
+            ros,
+            bus: Bus::default(),
+            components: Mutex::new(Vec::new()),
+            unload_timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets how long unloading waits for a component to exit before aborting it, defaults to 5 seconds.
+    pub fn unload_timeout(mut self, timeout: Duration) -> Self {
+        self.unload_timeout = timeout;
+        self
+    }
+
+    /// The handle to the backend shared by the components
+    pub fn ros(&self) -> &R {
+        &self.ros
+    }
+
+    /// Starts `component` under `name`, e.g. "/camera/driver", without remappings.
+    pub fn load<F, Fut>(&self, name: &str, component: F) -> Result<()>
+    where
+        F: FnOnce(ComponentRos<R>, Shutdown) -> Fut,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.load_remapped(name, Remappings::new(), component)
+    }
+
+    /// Starts `component` under `name` with `remappings` applied to the names it uses.
+    ///
+    /// Relative names are loaded into the root namespace. Fails with [Error::InvalidName] if `name` isn't a valid
+    /// name or another component is loaded under it.
+    pub fn load_remapped<F, Fut>(
+        &self,
+        name: &str,
+        remappings: Remappings,
+        component: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(ComponentRos<R>, Shutdown) -> Fut,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let name = TopicName::new(name)?.resolve_to_root()?.to_string();
+        let mut components = self.components.lock().unwrap();
+        if components.iter().any(|loaded| loaded.name == name) {
+            return Err(Error::InvalidName(format!(
+                "A component named {name} is already loaded"
+            )));
+        }
+
+        let ros = ComponentRos {
+            ros: self.ros.clone(),
+            name: name.clone(),
+            remappings: Arc::new(remappings),
+            bus: self.bus.clone(),
+        };
+        let (shutdown, receiver) = watch::channel(false);
+        let run = component(ros, Shutdown::new(receiver));
+        let state = Arc::new(Mutex::new(ComponentState::Running));
+        let task = tokio::spawn({
+            let name = name.clone();
+            let state = state.clone();
+            async move {
+                // Run in a separate task so that panics are caught and reported
+                let result = ChildTask::from(tokio::spawn(run)).await;
+                let finished = match result {
+                    Ok(Ok(())) => {
+                        debug!("Component {name} finished");
+                        ComponentState::Finished
+                    }
+                    Ok(Err(e)) => {
+                        error!("Component {name} failed: {e}");
+                        ComponentState::Failed(e.to_string())
+                    }
+                    Err(e) => {
+                        error!("Component {name} panicked: {e}");
+                        ComponentState::Failed(e.to_string())
+                    }
+                };
+                *state.lock().unwrap() = finished;
+            }
+        });
+        info!("Loaded component {name}");
+        components.push(Loaded {
+            name,
+            state,
+            shutdown,
+            task: task.into(),
+        });
+        Ok(())
+    }
+
+    /// The name and state of each loaded component, in the order they were loaded.
+    pub fn components(&self) -> Vec<(String, ComponentState)> {
+        self.components
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|loaded| (loaded.name.clone(), loaded.state.lock().unwrap().clone()))
+            .collect()
+    }
+
+    /// Signals the component `name` to exit and waits up to the unload timeout for it, aborting it after.
+    /// Returns whether a component of that name was loaded.
+    pub async fn unload(&self, name: &str) -> bool {
+        let loaded = {
+            let mut components = self.components.lock().unwrap();
+            let Some(index) = components.iter().position(|loaded| loaded.name == name) else {
+                return false;
+            };
+            components.remove(index)
+        };
+        let deadline = Instant::now() + self.unload_timeout;
+        stop(loaded, deadline).await;
+        true
+    }
+
+    /// Signals every component to exit, then waits up to the unload timeout for them, aborting those still running.
+    pub async fn shutdown(self) {
+        let components = std::mem::take(&mut *self.components.lock().unwrap());
+        for loaded in &components {
+            let _ = loaded.shutdown.send(true);
+        }
+        let deadline = Instant::now() + self.unload_timeout;
+        for loaded in components.into_iter().rev() {
+            stop(loaded, deadline).await;
+        }
+    }
+}
+
+async fn stop(mut loaded: Loaded, deadline: Instant) {
+    let _ = loaded.shutdown.send(true);
+    if tokio::time::timeout_at(deadline, &mut loaded.task)
+        .await
+        .is_err()
+    {
+        warn!(
+            "Component {} did not exit within timeout, aborting it",
+            loaded.name
+        );
+    }
+    info!("Unloaded component {}", loaded.name);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+    use tokio::sync::mpsc;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delivers_each_message_once_within_the_container() {
+        let ros = MockRos::new();
+        let container = Container::new(ros.clone());
+        let (sender, mut received) = mpsc::unbounded_channel();
+        container
+            .load("/robot/listener", |ros, mut shutdown| async move {
+                let mut counts = ros.subscribe::<std_msgs::UInt32>("count").await?;
+                loop {
+                    tokio::select! {
+                        _ = shutdown.wait() => return Ok(()),
+                        count = counts.next() => {
+                            let _ = sender.send(count?.data);
+                        }
+                    }
+                }
+            })
+            .unwrap();
+        // Outside of the container, receiving through the backend
+        let mut outside = ros
+            .subscribe::<std_msgs::UInt32>("/robot/count")
+            .await
+            .unwrap();
+
+        let mut remappings = Remappings::new();
+        remappings.insert("/robot/total", "/robot/count").unwrap();
+        container
+            .load_remapped("/robot/talker", remappings, |ros, shutdown| async move {
+                let publisher = ros.advertise::<std_msgs::UInt32>("total").await?;
+                for data in 0.. {
+                    if shutdown.is_shutdown() {
+                        break;
+                    }
+                    publisher.publish(&std_msgs::UInt32 { data }).await?;
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+                Ok(())
+            })
+            .unwrap();
+        assert!(container
+            .load("/robot/talker", |_, _| async { Ok(()) })
+            .is_err());
+
+        let first = received.recv().await.unwrap();
+        for expected in first + 1..first + 10 {
+            assert_eq!(received.recv().await, Some(expected));
+        }
+        assert_eq!(outside.next().await.unwrap().data, 0);
+
+        assert!(container.unload("/robot/talker").await);
+        assert!(!container.unload("/robot/talker").await);
+        assert_eq!(
+            container.components(),
+            vec![("/robot/listener".to_string(), ComponentState::Running)]
+        );
+        container.shutdown().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reports_failed_components() {
+        let container = Container::new(MockRos::new());
+        container
+            .load("failing", |_, _| async { Err(Error::Disconnected) })
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(matches!(
+            &container.components()[..],
+            [(name, ComponentState::Failed(_))] if name == "/failing"
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+//! Calls the same service on many nodes at once and collects their responses, see [FanOut].
+//!
+//! ```no_run
+//! use roslibrust::fanout::FanOut;
+//! use roslibrust::{GraphProvider, Ros};
+//! use roslibrust_test::ros1::std_srvs;
+//! use std::time::Duration;
+//!
+//! async fn report(ros: impl Ros + GraphProvider) -> roslibrust::Result<()> {
+//!     let responses = FanOut::<std_srvs::Trigger>::matching(&ros, "/robot*/get_status")
+//!         .await?
+//!         .timeout(Duration::from_secs(2))
+//!         .call(&ros, std_srvs::TriggerRequest {})
+//!         .await;
+//!     for (service, response) in responses {
+//!         match response {
+//!             Ok(response) => log::info!("{service}: {}", response.message),
+//!             Err(e) => log::warn!("{service} failed: {e}"),
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, GraphProvider, Result, Ros, RosServiceType};
+use std::{collections::BTreeMap, marker::PhantomData, time::Duration};
+
+/// Calls one service type on a set of services concurrently, giving each call its own timeout.
+///
+/// The services are named directly ([FanOut::new]), as the same service within several namespaces
+/// ([FanOut::namespaces]), or by a pattern matched against the services currently advertised ([FanOut::matching]).
+pub struct FanOut<T> {
+    services: Vec<String>,
+    timeout: Duration,
+    _marker: PhantomData<T>,
+}
+
+impl<T: RosServiceType> FanOut<T> {
+    /// Calls each of `services`.
+    pub fn new(services: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            services: services.into_iter().map(Into::into).collect(),
+            timeout: Duration::from_secs(5),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Calls `service` within each of `namespaces`, e.g. `get_status` in `/robot1` and `/robot2`.
+    pub fn namespaces(
+        namespaces: impl IntoIterator<Item = impl AsRef<str>>,
+        service: &str,
+    ) -> Self {
+        let service = service.trim_start_matches('/');
+        Self::new(
+            namespaces
+                .into_iter()
+                .map(|namespace| format!("{}/{service}", namespace.as_ref().trim_end_matches('/'))),
+        )
+    }
+
+    /// Calls every service currently advertised on `ros` whose name matches `pattern`, in which `*` stands for
+    /// any part of a name between two `/`, e.g. `/robot*/get_status`.
+    ///
+    /// Services of a different type are left out, unless the backend can't tell their type.
+    pub async fn matching(ros: &impl GraphProvider, pattern: &str) -> Result<Self> {
+        let services = ros.services().await?;
+        Ok(Self::new(
+            services
+                .into_iter()
+                .filter(|service| {
+                    service.srv_type.is_empty() || service.srv_type == T::ROS_SERVICE_NAME
+                })
+                .filter(|service| matches(pattern, &service.name))
+                .map(|service| service.name),
+        ))
+    }
+
+    /// Fails calls which haven't completed after `timeout` with [Error::Timeout], instead of after 5 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The services which will be called.
+    pub fn services(&self) -> &[String] {
+        &self.services
+    }
+
+    /// Sends `request` to every service at once, returning each response or error by the name of its service.
+    pub async fn call(
+        &self,
+        ros: &impl Ros,
+        request: T::Request,
+    ) -> BTreeMap<String, Result<T::Response>> {
+        let mut calls = tokio::task::JoinSet::new();
+        for service in &self.services {
+            let ros = ros.clone();
+            let service = service.clone();
+            let request = request.clone();
+            let timeout = self.timeout;
+            calls.spawn(async move {
+                let response =
+                    tokio::time::timeout(timeout, ros.call_service::<T>(service.as_str(), request))
+                        .await
+                        .unwrap_or_else(|_| {
+                            Err(Error::Timeout(format!(
+                                "Call to {service} took longer than {timeout:?}"
+                            )))
+                        });
+                (service, response)
+            });
+        }
+        let mut responses = BTreeMap::new();
+        while let Some(result) = calls.join_next().await {
+            match result {
+                Ok((service, response)) => {
+                    responses.insert(service, response);
+                }
+                // The call panicked, which leaves no name to file it under
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            }
+        }
+        responses
+    }
+}
+
+/// True if `name` matches `pattern`, where `*` matches any characters other than `/`
+fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<_> = pattern.split('/').collect();
+    let name: Vec<_> = name.split('/').collect();
+    pattern.len() == name.len()
+        && pattern
+            .iter()
+            .zip(&name)
+            .all(|(pattern, segment)| segment_matches(pattern, segment))
+}
+
+fn segment_matches(pattern: &str, segment: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == segment,
+        Some((prefix, rest)) => {
+            let Some(segment) = segment.strip_prefix(prefix) else {
+                return false;
+            };
+            // Let the wildcard take every possible length, the patterns involved are short
+            (0..=segment.len())
+                .filter(|&i| segment.is_char_boundary(i))
+                .any(|i| segment_matches(rest, &segment[i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ServiceProvider;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_srvs;
+
+    #[test_log::test]
+    fn matches_wildcards_within_segments() {
+        assert!(matches("/robot*/get_status", "/robot1/get_status"));
+        assert!(matches("/robot*/get_status", "/robot/get_status"));
+        assert!(matches("/*/get_*", "/drone3/get_battery"));
+        assert!(!matches("/robot*/get_status", "/robot1/arm/get_status"));
+        assert!(!matches("/robot*/get_status", "/drone1/get_status"));
+        assert!(!matches("/robot*/get_status", "/robot1/get_status_old"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn collects_responses_and_failures() {
+        let ros = MockRos::new();
+        let mut servers = vec![];
+        for robot in ["robot1", "robot2", "drone1"] {
+            let message = format!("{robot} ok");
+            let server = ros
+                .advertise_service::<std_srvs::Trigger, _>(
+                    format!("/{robot}/get_status"),
+                    move |_| {
+                        Ok(std_srvs::TriggerResponse {
+                            success: true,
+                            message: message.clone(),
+                        })
+                    },
+                )
+                .await
+                .unwrap();
+            servers.push(server);
+        }
+
+        let fan_out = FanOut::<std_srvs::Trigger>::matching(&ros, "/robot*/get_status")
+            .await
+            .unwrap();
+        assert_eq!(
+            fan_out.services(),
+            ["/robot1/get_status", "/robot2/get_status"]
+        );
+        let responses = fan_out.call(&ros, std_srvs::TriggerRequest {}).await;
+        assert_eq!(responses.len(), 2);
+        assert_eq!(
+            responses["/robot2/get_status"].as_ref().unwrap().message,
+            "robot2 ok"
+        );
+
+        // A missing service fails without holding up the others
+        let responses =
+            FanOut::<std_srvs::Trigger>::namespaces(["/robot1", "/robot3/"], "get_status")
+                .timeout(Duration::from_millis(200))
+                .call(&ros, std_srvs::TriggerRequest {})
+                .await;
+        assert!(responses["/robot1/get_status"].is_ok());
+        assert!(responses["/robot3/get_status"].is_err());
+    }
+}
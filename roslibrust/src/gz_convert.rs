@@ -0,0 +1,793 @@
+//! Converts ROS messages to and from the gz-msgs protobuf messages carried by Gazebo Transport, see [GzConvert].
+//!
+//! This is a conversion layer, not a Gazebo Transport client: speaking gz-transport's own protocol is out of scope.
+//! Applications implement [GzTransport] over whichever route to Gazebo they have, e.g. a gz-transport binding or
+//! Gazebo's websocket server, and [GzBridge] moves converted messages between ROS topics and that transport, so a
+//! simulation can be driven without running ros_gz_bridge.
+//!
+//! [gz] contains the gz-msgs messages, and [ros1] the ROS messages they are converted to and from. Twist, Image and
+//! LaserScan are supported so far.
+//!
+//! ```no_run
+//! use roslibrust::gz_convert::{ros1, GzBridge, GzTransport};
+//! use roslibrust::Ros;
+//!
+//! async fn run(ros: impl Ros, gz: impl GzTransport) -> roslibrust::Result<()> {
+//!     // Drive the simulated robot from /cmd_vel, and publish what its lidar sees on /scan
+//!     let _cmd_vel = GzBridge::<ros1::Twist>::to_gz("/cmd_vel", "/model/robot/cmd_vel")
+//!         .start(&ros, &gz)
+//!         .await?;
+//!     let _scan = GzBridge::<ros1::LaserScan>::from_gz("/lidar", "/scan")
+//!         .start(&ros, &gz)
+//!         .await?;
+//!     // Bridges until the handles are dropped
+//!     let _ = tokio::signal::ctrl_c().await;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Publish, Result, RosMessageType, Subscribe, TopicProvider};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    future::Future,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::mpsc;
+
+/// A gz-msgs protobuf message
+pub trait GzMessage: prost::Message + Default + 'static {
+    /// The full name of the message, e.g. `gz.msgs.Twist`
+    const GZ_TYPE_NAME: &'static str;
+}
+
+/// A ROS message with a gz-msgs counterpart, implemented for the messages in [ros1].
+pub trait GzConvert: RosMessageType {
+    type Gz: GzMessage;
+    fn to_gz(&self) -> Self::Gz;
+    /// Fields the gz message lacks are left at their defaults
+    fn from_gz(gz: Self::Gz) -> Self;
+}
+
+/// Carries serialized gz-msgs messages to and from Gazebo.
+pub trait GzTransport: Clone + Send + Sync + 'static {
+    /// Publishes `payload`, a serialized message of type `type_name`, on `topic`
+    fn publish(
+        &self,
+        topic: &str,
+        type_name: &str,
+        payload: Vec<u8>,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Receives the serialized messages of type `type_name` published on `topic`, until the receiver is dropped
+    fn subscribe(
+        &self,
+        topic: &str,
+        type_name: &str,
+    ) -> impl Future<Output = Result<mpsc::Receiver<Vec<u8>>>> + Send;
+}
+
+enum Direction {
+    ToGz,
+    FromGz,
+}
+
+/// Forwards the messages of one ROS topic to a Gazebo topic, or the other way around, converting them with
+/// [GzConvert].
+pub struct GzBridge<T> {
+    ros_topic: String,
+    gz_topic: String,
+    direction: Direction,
+    _marker: PhantomData<T>,
+}
+
+impl<T: GzConvert> GzBridge<T> {
+    /// Publishes the messages of `ros_topic` on `gz_topic`, e.g. velocity commands for a simulated robot
+    pub fn to_gz(ros_topic: impl Into<String>, gz_topic: impl Into<String>) -> Self {
+        Self {
+            ros_topic: ros_topic.into(),
+            gz_topic: gz_topic.into(),
+            direction: Direction::ToGz,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Publishes the messages of `gz_topic` on `ros_topic`, e.g. the readings of a simulated sensor
+    pub fn from_gz(gz_topic: impl Into<String>, ros_topic: impl Into<String>) -> Self {
+        Self {
+            ros_topic: ros_topic.into(),
+            gz_topic: gz_topic.into(),
+            direction: Direction::FromGz,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Starts bridging between `ros` and `gz`
+    pub async fn start(
+        self,
+        ros: &impl TopicProvider,
+        gz: &impl GzTransport,
+    ) -> Result<GzBridgeHandle> {
+        let forwarded = Arc::new(AtomicU64::new(0));
+        let GzBridge {
+            ros_topic,
+            gz_topic,
+            direction,
+            ..
+        } = self;
+        let type_name = <T::Gz as GzMessage>::GZ_TYPE_NAME;
+        let task = match direction {
+            Direction::ToGz => {
+                let mut subscriber = ros.subscribe::<T>(ros_topic.as_str()).await?;
+                let gz = gz.clone();
+                let forwarded = forwarded.clone();
+                debug!("Bridging {ros_topic} to gazebo topic {gz_topic}");
+                tokio::spawn(async move {
+                    loop {
+                        let msg = match subscriber.next().await {
+                            Ok(msg) => msg,
+                            Err(Error::Disconnected) => {
+                                warn!("Subscriber for {ros_topic} disconnected, no longer bridging it to gazebo");
+                                return;
+                            }
+                            Err(e) => {
+                                warn!("Failed to receive message on {ros_topic} for gazebo: {e}");
+                                continue;
+                            }
+                        };
+                        let payload = prost::Message::encode_to_vec(&msg.to_gz());
+                        match gz.publish(&gz_topic, type_name, payload).await {
+                            Ok(()) => {
+                                forwarded.fetch_add(1, Ordering::Relaxed);
+                            }
+                            Err(e) => warn!("Failed to publish on gazebo topic {gz_topic}: {e}"),
+                        }
+                    }
+                })
+            }
+            Direction::FromGz => {
+                let mut receiver = gz.subscribe(&gz_topic, type_name).await?;
+                let publisher = ros.advertise::<T>(ros_topic.as_str()).await?;
+                let forwarded = forwarded.clone();
+                debug!("Bridging gazebo topic {gz_topic} to {ros_topic}");
+                tokio::spawn(async move {
+                    while let Some(payload) = receiver.recv().await {
+                        let gz_msg = match <T::Gz as prost::Message>::decode(payload.as_slice()) {
+                            Ok(gz_msg) => gz_msg,
+                            Err(e) => {
+                                warn!("Failed to decode {type_name} from gazebo topic {gz_topic}: {e}");
+                                continue;
+                            }
+                        };
+                        match publisher.publish(&T::from_gz(gz_msg)).await {
+                            Ok(()) => {
+                                forwarded.fetch_add(1, Ordering::Relaxed);
+                            }
+                            Err(e) => warn!("Failed to publish on {ros_topic}: {e}"),
+                        }
+                    }
+                    debug!("Gazebo topic {gz_topic} closed, no longer bridging it to {ros_topic}");
+                })
+            }
+        };
+        Ok(GzBridgeHandle {
+            _task: task.into(),
+            forwarded,
+        })
+    }
+}
+
+/// Keeps a [GzBridge] running until dropped
+pub struct GzBridgeHandle {
+    _task: ChildTask<()>,
+    forwarded: Arc<AtomicU64>,
+}
+
+impl GzBridgeHandle {
+    /// The messages forwarded so far
+    pub fn forwarded(&self) -> u64 {
+        self.forwarded.load(Ordering::Relaxed)
+    }
+}
+
+/// The gz-msgs messages, as defined in <https://github.com/gazebosim/gz-msgs/tree/main/proto/gz/msgs>.
+///
+/// Only the fields of each message which have a ROS counterpart are given a meaning by the conversions, the others
+/// are carried as is.
+pub mod gz {
+    use super::GzMessage;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Time {
+        #[prost(int64, tag = "1")]
+        pub sec: i64,
+        #[prost(int32, tag = "2")]
+        pub nsec: i32,
+    }
+
+    /// A key with its values, an entry of [Header::data]
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct HeaderMap {
+        #[prost(string, tag = "1")]
+        pub key: String,
+        #[prost(string, repeated, tag = "2")]
+        pub value: Vec<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Header {
+        #[prost(message, optional, tag = "1")]
+        pub stamp: Option<Time>,
+        /// Gazebo stores the frame of a message under the key `frame_id`
+        #[prost(message, repeated, tag = "2")]
+        pub data: Vec<HeaderMap>,
+    }
+
+    impl Header {
+        /// The value of the `frame_id` entry, empty if there is none
+        pub fn frame_id(&self) -> &str {
+            self.data
+                .iter()
+                .find(|entry| entry.key == "frame_id")
+                .and_then(|entry| entry.value.first())
+                .map_or("", String::as_str)
+        }
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Vector3d {
+        #[prost(message, optional, tag = "1")]
+        pub header: Option<Header>,
+        #[prost(double, tag = "2")]
+        pub x: f64,
+        #[prost(double, tag = "3")]
+        pub y: f64,
+        #[prost(double, tag = "4")]
+        pub z: f64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Quaternion {
+        #[prost(message, optional, tag = "1")]
+        pub header: Option<Header>,
+        #[prost(double, tag = "2")]
+        pub x: f64,
+        #[prost(double, tag = "3")]
+        pub y: f64,
+        #[prost(double, tag = "4")]
+        pub z: f64,
+        #[prost(double, tag = "5")]
+        pub w: f64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Pose {
+        #[prost(message, optional, tag = "1")]
+        pub header: Option<Header>,
+        #[prost(string, tag = "2")]
+        pub name: String,
+        #[prost(uint32, tag = "3")]
+        pub id: u32,
+        #[prost(message, optional, tag = "4")]
+        pub position: Option<Vector3d>,
+        #[prost(message, optional, tag = "5")]
+        pub orientation: Option<Quaternion>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Twist {
+        #[prost(message, optional, tag = "1")]
+        pub header: Option<Header>,
+        #[prost(message, optional, tag = "2")]
+        pub linear: Option<Vector3d>,
+        #[prost(message, optional, tag = "3")]
+        pub angular: Option<Vector3d>,
+    }
+
+    impl GzMessage for Twist {
+        const GZ_TYPE_NAME: &'static str = "gz.msgs.Twist";
+    }
+
+    /// The layout of the pixels of an [Image]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+    #[repr(i32)]
+    pub enum PixelFormatType {
+        UnknownPixelFormat = 0,
+        LInt8 = 1,
+        LInt16 = 2,
+        RgbInt8 = 3,
+        RgbaInt8 = 4,
+        BgraInt8 = 5,
+        RgbInt16 = 6,
+        RgbInt32 = 7,
+        BgrInt8 = 8,
+        BgrInt16 = 9,
+        BgrInt32 = 10,
+        RFloat16 = 11,
+        RgbFloat16 = 12,
+        RFloat32 = 13,
+        RgbFloat32 = 14,
+        BayerRggb8 = 15,
+        BayerBggr8 = 16,
+        BayerGbrg8 = 17,
+        BayerGrbg8 = 18,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Image {
+        #[prost(message, optional, tag = "1")]
+        pub header: Option<Header>,
+        #[prost(uint32, tag = "2")]
+        pub width: u32,
+        #[prost(uint32, tag = "3")]
+        pub height: u32,
+        #[prost(uint32, tag = "4")]
+        pub step: u32,
+        #[prost(bytes = "vec", tag = "5")]
+        pub data: Vec<u8>,
+        #[prost(enumeration = "PixelFormatType", tag = "6")]
+        pub pixel_format_type: i32,
+    }
+
+    impl GzMessage for Image {
+        const GZ_TYPE_NAME: &'static str = "gz.msgs.Image";
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct LaserScan {
+        #[prost(message, optional, tag = "1")]
+        pub header: Option<Header>,
+        #[prost(string, tag = "2")]
+        pub frame: String,
+        #[prost(message, optional, tag = "3")]
+        pub world_pose: Option<Pose>,
+        #[prost(double, tag = "4")]
+        pub angle_min: f64,
+        #[prost(double, tag = "5")]
+        pub angle_max: f64,
+        #[prost(double, tag = "6")]
+        pub angle_step: f64,
+        #[prost(double, tag = "7")]
+        pub range_min: f64,
+        #[prost(double, tag = "8")]
+        pub range_max: f64,
+        #[prost(uint32, tag = "9")]
+        pub count: u32,
+        #[prost(double, tag = "10")]
+        pub vertical_angle_min: f64,
+        #[prost(double, tag = "11")]
+        pub vertical_angle_max: f64,
+        #[prost(double, tag = "12")]
+        pub vertical_angle_step: f64,
+        #[prost(uint32, tag = "13")]
+        pub vertical_count: u32,
+        #[prost(double, repeated, tag = "14")]
+        pub ranges: Vec<f64>,
+        #[prost(double, repeated, tag = "15")]
+        pub intensities: Vec<f64>,
+    }
+
+    impl GzMessage for LaserScan {
+        const GZ_TYPE_NAME: &'static str = "gz.msgs.LaserScan";
+    }
+}
+
+/// The ROS1 messages `geometry_msgs/Twist`, `sensor_msgs/Image` and `sensor_msgs/LaserScan`, converted to and
+/// from their [gz] counterparts.
+pub mod ros1 {
+    use super::{gz, GzConvert};
+    use crate::RosMessageType;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Time {
+        pub secs: i32,
+        pub nsecs: i32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Header {
+        pub seq: u32,
+        pub stamp: Time,
+        pub frame_id: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Vector3 {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Twist {
+        pub linear: Vector3,
+        pub angular: Vector3,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Image {
+        pub header: Header,
+        pub height: u32,
+        pub width: u32,
+        pub encoding: String,
+        pub is_bigendian: u8,
+        pub step: u32,
+        pub data: Vec<u8>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct LaserScan {
+        pub header: Header,
+        pub angle_min: f32,
+        pub angle_max: f32,
+        pub angle_increment: f32,
+        pub time_increment: f32,
+        pub scan_time: f32,
+        pub range_min: f32,
+        pub range_max: f32,
+        pub ranges: Vec<f32>,
+        pub intensities: Vec<f32>,
+    }
+
+    impl RosMessageType for Twist {
+        const ROS_TYPE_NAME: &'static str = "geometry_msgs/Twist";
+        const MD5SUM: &'static str = "9f195f881246fdfa2798d1d3eebca84a";
+        const DEFINITION: &'static str = r####"Vector3  linear
+Vector3  angular
+================================================================================
+MSG: geometry_msgs/Vector3
+float64 x
+float64 y
+float64 z"####;
+    }
+
+    impl RosMessageType for Image {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Image";
+        const MD5SUM: &'static str = "060021388200f6f0f447d0fcd9c64743";
+        const DEFINITION: &'static str = r####"Header header
+uint32 height
+uint32 width
+string encoding
+uint8 is_bigendian
+uint32 step
+uint8[] data
+================================================================================
+MSG: std_msgs/Header
+uint32 seq
+time stamp
+string frame_id"####;
+    }
+
+    impl RosMessageType for LaserScan {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/LaserScan";
+        const MD5SUM: &'static str = "90c7ef2dc6895d81024acba2ac42f369";
+        const DEFINITION: &'static str = r####"Header header
+float32 angle_min
+float32 angle_max
+float32 angle_increment
+float32 time_increment
+float32 scan_time
+float32 range_min
+float32 range_max
+float32[] ranges
+float32[] intensities
+================================================================================
+MSG: std_msgs/Header
+uint32 seq
+time stamp
+string frame_id"####;
+    }
+
+    fn header_to_gz(header: &Header) -> gz::Header {
+        gz::Header {
+            stamp: Some(gz::Time {
+                sec: header.stamp.secs as i64,
+                nsec: header.stamp.nsecs,
+            }),
+            data: vec![gz::HeaderMap {
+                key: "frame_id".to_owned(),
+                value: vec![header.frame_id.clone()],
+            }],
+        }
+    }
+
+    fn header_from_gz(header: Option<gz::Header>) -> Header {
+        let header = header.unwrap_or_default();
+        let stamp = header.stamp.clone().unwrap_or_default();
+        Header {
+            seq: 0,
+            stamp: Time {
+                secs: stamp.sec as i32,
+                nsecs: stamp.nsec,
+            },
+            frame_id: header.frame_id().to_owned(),
+        }
+    }
+
+    fn vector_to_gz(vector: &Vector3) -> gz::Vector3d {
+        gz::Vector3d {
+            header: None,
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+
+    fn vector_from_gz(vector: Option<gz::Vector3d>) -> Vector3 {
+        let vector = vector.unwrap_or_default();
+        Vector3 {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+
+    impl GzConvert for Twist {
+        type Gz = gz::Twist;
+
+        fn to_gz(&self) -> gz::Twist {
+            gz::Twist {
+                header: None,
+                linear: Some(vector_to_gz(&self.linear)),
+                angular: Some(vector_to_gz(&self.angular)),
+            }
+        }
+
+        fn from_gz(gz: gz::Twist) -> Self {
+            Twist {
+                linear: vector_from_gz(gz.linear),
+                angular: vector_from_gz(gz.angular),
+            }
+        }
+    }
+
+    /// The ROS image encodings with a gz pixel format, as named in sensor_msgs/image_encodings.h
+    const ENCODINGS: [(&str, gz::PixelFormatType); 14] = [
+        ("mono8", gz::PixelFormatType::LInt8),
+        ("mono16", gz::PixelFormatType::LInt16),
+        ("rgb8", gz::PixelFormatType::RgbInt8),
+        ("rgba8", gz::PixelFormatType::RgbaInt8),
+        ("bgra8", gz::PixelFormatType::BgraInt8),
+        ("rgb16", gz::PixelFormatType::RgbInt16),
+        ("bgr8", gz::PixelFormatType::BgrInt8),
+        ("bgr16", gz::PixelFormatType::BgrInt16),
+        ("32FC1", gz::PixelFormatType::RFloat32),
+        ("32FC3", gz::PixelFormatType::RgbFloat32),
+        ("bayer_rggb8", gz::PixelFormatType::BayerRggb8),
+        ("bayer_bggr8", gz::PixelFormatType::BayerBggr8),
+        ("bayer_gbrg8", gz::PixelFormatType::BayerGbrg8),
+        ("bayer_grbg8", gz::PixelFormatType::BayerGrbg8),
+    ];
+
+    impl GzConvert for Image {
+        type Gz = gz::Image;
+
+        /// Encodings without a gz pixel format are sent as [gz::PixelFormatType::UnknownPixelFormat]
+        fn to_gz(&self) -> gz::Image {
+            let format = ENCODINGS
+                .iter()
+                .find(|(encoding, _)| *encoding == self.encoding)
+                .map_or(gz::PixelFormatType::UnknownPixelFormat, |(_, format)| {
+                    *format
+                });
+            gz::Image {
+                header: Some(header_to_gz(&self.header)),
+                width: self.width,
+                height: self.height,
+                step: self.step,
+                data: self.data.clone(),
+                pixel_format_type: format as i32,
+            }
+        }
+
+        /// Pixel formats without a ROS encoding leave the encoding empty
+        fn from_gz(gz: gz::Image) -> Self {
+            let encoding = ENCODINGS
+                .iter()
+                .find(|(_, format)| *format as i32 == gz.pixel_format_type)
+                .map_or("", |(encoding, _)| encoding);
+            Image {
+                header: header_from_gz(gz.header),
+                height: gz.height,
+                width: gz.width,
+                encoding: encoding.to_owned(),
+                is_bigendian: 0,
+                step: gz.step,
+                data: gz.data,
+            }
+        }
+    }
+
+    impl GzConvert for LaserScan {
+        type Gz = gz::LaserScan;
+
+        fn to_gz(&self) -> gz::LaserScan {
+            gz::LaserScan {
+                header: Some(header_to_gz(&self.header)),
+                frame: self.header.frame_id.clone(),
+                world_pose: None,
+                angle_min: self.angle_min.into(),
+                angle_max: self.angle_max.into(),
+                angle_step: self.angle_increment.into(),
+                range_min: self.range_min.into(),
+                range_max: self.range_max.into(),
+                count: self.ranges.len() as u32,
+                vertical_angle_min: 0.0,
+                vertical_angle_max: 0.0,
+                vertical_angle_step: 0.0,
+                vertical_count: 1,
+                ranges: self.ranges.iter().map(|&range| range.into()).collect(),
+                intensities: self.intensities.iter().map(|&i| i.into()).collect(),
+            }
+        }
+
+        /// Only the first row of a scan with several vertical rows is kept, as ros_gz_bridge does
+        fn from_gz(gz: gz::LaserScan) -> Self {
+            let mut header = header_from_gz(gz.header);
+            if header.frame_id.is_empty() {
+                header.frame_id = gz.frame;
+            }
+            // Older publishers may leave the count unset
+            let count = match gz.count {
+                0 => gz.ranges.len(),
+                count => count as usize,
+            };
+            LaserScan {
+                header,
+                angle_min: gz.angle_min as f32,
+                angle_max: gz.angle_max as f32,
+                angle_increment: gz.angle_step as f32,
+                time_increment: 0.0,
+                scan_time: 0.0,
+                range_min: gz.range_min as f32,
+                range_max: gz.range_max as f32,
+                ranges: gz.ranges.iter().take(count).map(|&r| r as f32).collect(),
+                intensities: gz
+                    .intensities
+                    .iter()
+                    .take(count)
+                    .map(|&i| i as f32)
+                    .collect(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_mock::MockRos;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    type Subscribers = Arc<Mutex<HashMap<String, Vec<mpsc::Sender<Vec<u8>>>>>>;
+
+    /// Delivers each published payload to the subscribers of its topic within the process
+    #[derive(Clone, Default)]
+    struct LoopbackTransport {
+        subscribers: Subscribers,
+    }
+
+    impl GzTransport for LoopbackTransport {
+        async fn publish(&self, topic: &str, _type_name: &str, payload: Vec<u8>) -> Result<()> {
+            let senders = self
+                .subscribers
+                .lock()
+                .unwrap()
+                .get(topic)
+                .cloned()
+                .unwrap_or_default();
+            for sender in senders {
+                let _ = sender.send(payload.clone()).await;
+            }
+            Ok(())
+        }
+
+        async fn subscribe(
+            &self,
+            topic: &str,
+            _type_name: &str,
+        ) -> Result<mpsc::Receiver<Vec<u8>>> {
+            let (sender, receiver) = mpsc::channel(10);
+            self.subscribers
+                .lock()
+                .unwrap()
+                .entry(topic.to_owned())
+                .or_default()
+                .push(sender);
+            Ok(receiver)
+        }
+    }
+
+    #[test_log::test]
+    fn converts_messages() {
+        let twist = ros1::Twist {
+            linear: ros1::Vector3 {
+                x: 1.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        // Field 2 (linear) holding field 2 (x) as a little endian double, fields at their defaults are omitted
+        let encoded = prost::Message::encode_to_vec(&twist.to_gz());
+        assert_eq!(
+            encoded,
+            [0x12, 9, 0x11, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, 0x1a, 0]
+        );
+        let decoded = <gz::Twist as prost::Message>::decode(encoded.as_slice()).unwrap();
+        assert_eq!(ros1::Twist::from_gz(decoded), twist);
+
+        let scan = ros1::LaserScan {
+            header: ros1::Header {
+                seq: 0,
+                stamp: ros1::Time { secs: 5, nsecs: 10 },
+                frame_id: "lidar".to_owned(),
+            },
+            angle_min: -1.5,
+            angle_max: 1.5,
+            angle_increment: 1.5,
+            range_min: 0.1,
+            range_max: 10.0,
+            ranges: vec![1.0, 2.5, 4.0],
+            ..Default::default()
+        };
+        let gz_scan = scan.to_gz();
+        assert_eq!(gz_scan.count, 3);
+        assert_eq!(gz_scan.header.as_ref().unwrap().frame_id(), "lidar");
+        assert_eq!(ros1::LaserScan::from_gz(gz_scan), scan);
+
+        let image = ros1::Image {
+            height: 1,
+            width: 2,
+            encoding: "rgb8".to_owned(),
+            step: 6,
+            data: vec![0, 1, 2, 3, 4, 5],
+            ..Default::default()
+        };
+        let gz_image = image.to_gz();
+        assert_eq!(
+            gz_image.pixel_format_type,
+            gz::PixelFormatType::RgbInt8 as i32
+        );
+        assert_eq!(ros1::Image::from_gz(gz_image), image);
+        let yuv = ros1::Image {
+            encoding: "yuv422".to_owned(),
+            ..image
+        };
+        assert_eq!(yuv.to_gz().pixel_format_type, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn bridges_both_ways() {
+        let ros = MockRos::new();
+        let gz = LoopbackTransport::default();
+        // Out to gazebo and straight back in under another name
+        let to_gz = GzBridge::<ros1::Twist>::to_gz("/cmd_vel", "/model/robot/cmd_vel")
+            .start(&ros, &gz)
+            .await
+            .unwrap();
+        let from_gz = GzBridge::<ros1::Twist>::from_gz("/model/robot/cmd_vel", "/cmd_vel_echo")
+            .start(&ros, &gz)
+            .await
+            .unwrap();
+        let mut echo = ros.subscribe::<ros1::Twist>("/cmd_vel_echo").await.unwrap();
+        let publisher = ros.advertise::<ros1::Twist>("/cmd_vel").await.unwrap();
+
+        let twist = ros1::Twist {
+            angular: ros1::Vector3 {
+                z: 0.5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        publisher.publish(&twist).await.unwrap();
+        assert_eq!(echo.next().await.unwrap(), twist);
+        assert_eq!(to_gz.forwarded(), 1);
+        assert_eq!(from_gz.forwarded(), 1);
+    }
+}
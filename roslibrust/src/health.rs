@@ -0,0 +1,373 @@
+//! Reports the status of a node over HTTP, so orchestration systems (Kubernetes probes, systemd watchdogs, load
+//! balancers) can check on it.
+//!
+//! A [Health] collects the backend's connection status, the rate of each topic, the last error and the uptime.
+//! Topics are measured by wrapping their publishers and subscribers with [Health::monitor], and any error can be
+//! recorded with [Health::record_error]. [Health::serve] answers:
+//! - `GET /health` with a [HealthReport] as JSON, with status 200 while the backend is connected and 503 otherwise,
+//!   suitable as a readiness probe.
+//! - `GET /live` with status 200 as long as the process is responsive, suitable as a liveness probe.
+//!
+//! ```no_run
+//! use roslibrust::health::Health;
+//! use roslibrust::ros1::NodeHandle;
+//! use roslibrust::{Subscribe, TopicProvider};
+//! use roslibrust_test::ros1::std_msgs;
+//!
+//! async fn run() -> roslibrust::Result<()> {
+//!     let nh = NodeHandle::new("http://localhost:11311", "my_node").await?;
+//!     let health = Health::new("ros1").connection_check({
+//!         let nh = nh.clone();
+//!         move || nh.is_ok()
+//!     });
+//!     let _server = health.serve("0.0.0.0:8080").await?;
+//!
+//!     let subscriber = TopicProvider::subscribe::<std_msgs::String>(&nh, "/chatter").await?;
+//!     let mut subscriber = health.monitor("/chatter", subscriber);
+//!     loop {
+//!         let msg = subscriber.next().await?;
+//!         log::info!("Got: {}", msg.data);
+//!     }
+//! }
+//! ```
+
+use crate::{monitor::TopicMonitor, Publish, Result, RosMessageType, Subscribe};
+use abort_on_drop::ChildTask;
+use log::*;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    net::SocketAddr,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Instant,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+};
+
+/// Requests larger than this are rejected, probes never send bodies
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+type ConnectionCheck = Box<dyn Fn() -> bool + Send + Sync + 'static>;
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+struct Inner {
+    backend: String,
+    started: Instant,
+    connection_check: Option<ConnectionCheck>,
+    topics: Mutex<BTreeMap<String, TopicMonitor>>,
+    last_error: Mutex<Option<(Instant, String)>>,
+}
+
+/// Collects the status of a node, see the [module level documentation](self).
+///
+/// Clones share the same status, so one can be handed to each part of the node.
+#[derive(Clone)]
+pub struct Health {
+    inner: Arc<Inner>,
+}
+
+impl Health {
+    /// Creates a health report for a node using `backend`, a name for it shown in the report e.g. `ros1`.
+    pub fn new(backend: impl Into<String>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                backend: backend.into(),
+                started: Instant::now(),
+                connection_check: None,
+                topics: Mutex::new(BTreeMap::new()),
+                last_error: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Sets how to check if the backend is connected to its master, bridge or router, e.g.
+    /// `move || node_handle.is_ok()`. Without a check the node is reported healthy while it responds.
+    ///
+    /// Must be called before the [Health] is cloned.
+    pub fn connection_check(mut self, check: impl Fn() -> bool + Send + Sync + 'static) -> Self {
+        match Arc::get_mut(&mut self.inner) {
+            Some(inner) => inner.connection_check = Some(Box::new(check)),
+            None => {
+                warn!("Health::connection_check called after the health was cloned, ignoring it")
+            }
+        }
+        self
+    }
+
+    /// Counts a message published or received on `topic`.
+    pub fn record_message(&self, topic: &str) {
+        lock(&self.inner.topics)
+            .entry(topic.to_owned())
+            .or_default()
+            .record(0);
+    }
+
+    /// Records an error, replacing the last error reported.
+    pub fn record_error(&self, error: &impl Display) {
+        *lock(&self.inner.last_error) = Some((Instant::now(), error.to_string()));
+    }
+
+    /// Wraps a publisher or subscriber of `topic` so every message it publishes or receives is counted, and every
+    /// error it returns recorded.
+    pub fn monitor<S>(&self, topic: impl Into<String>, inner: S) -> Monitored<S> {
+        Monitored {
+            inner,
+            topic: topic.into(),
+            health: self.clone(),
+        }
+    }
+
+    /// Returns the current status of the node.
+    pub fn report(&self) -> HealthReport {
+        let now = Instant::now();
+        let connected = self.inner.connection_check.as_ref().map(|check| check());
+        let topics = lock(&self.inner.topics)
+            .iter()
+            .map(|(topic, monitor)| {
+                let stats = monitor.stats_at(now);
+                let report = TopicReport {
+                    messages: stats.messages,
+                    rate_hz: stats.rate_hz,
+                    last_message_secs_ago: stats.since_last.map(|since| since.as_secs_f64()),
+                };
+                (topic.clone(), report)
+            })
+            .collect();
+        let last_error = lock(&self.inner.last_error)
+            .as_ref()
+            .map(|(seen, message)| ErrorReport {
+                message: message.clone(),
+                secs_ago: now.duration_since(*seen).as_secs_f64(),
+            });
+        HealthReport {
+            healthy: connected != Some(false),
+            backend: self.inner.backend.clone(),
+            connected,
+            uptime_secs: now.duration_since(self.inner.started).as_secs_f64(),
+            topics,
+            last_error,
+        }
+    }
+
+    /// Serves the health endpoints on `addr` until the returned server is dropped.
+    pub async fn serve(&self, addr: impl ToSocketAddrs) -> Result<HealthServer> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let health = self.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        warn!("Failed to accept health check connection: {e}");
+                        continue;
+                    }
+                };
+                let health = health.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = health.respond(stream).await {
+                        debug!("Failed to respond to health check: {e}");
+                    }
+                });
+            }
+        });
+        info!("Serving health checks on {local_addr}");
+        Ok(HealthServer {
+            local_addr,
+            _task: task.into(),
+        })
+    }
+
+    /// Answers a single HTTP request, closing the connection afterwards
+    async fn respond(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let read = stream.read(&mut buf).await?;
+            if read == 0 || request.len() + read > MAX_REQUEST_SIZE {
+                return Ok(());
+            }
+            request.extend_from_slice(&buf[..read]);
+        }
+        let request = String::from_utf8_lossy(&request);
+        let mut request_line = request.lines().next().unwrap_or_default().split(' ');
+        let (method, path) = (request_line.next(), request_line.next());
+        // Query strings are ignored
+        let path = path.map(|path| path.split('?').next().unwrap_or_default());
+
+        let (status, content_type, body) = match (method, path) {
+            (Some("GET"), Some("/health")) => {
+                let report = self.report();
+                let status = match report.healthy {
+                    true => "200 OK",
+                    false => "503 Service Unavailable",
+                };
+                let body = serde_json::to_string(&report).unwrap_or_default();
+                (status, "application/json", body)
+            }
+            (Some("GET"), Some("/live")) => ("200 OK", "text/plain", "ok".to_owned()),
+            (Some("GET"), _) => ("404 Not Found", "text/plain", "not found".to_owned()),
+            _ => (
+                "405 Method Not Allowed",
+                "text/plain",
+                "method not allowed".to_owned(),
+            ),
+        };
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+}
+
+/// The status of a node reported by [Health::report] and served as JSON.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct HealthReport {
+    /// False while the connection check fails
+    pub healthy: bool,
+    pub backend: String,
+    /// The result of the connection check, None if there isn't one
+    pub connected: Option<bool>,
+    pub uptime_secs: f64,
+    pub topics: BTreeMap<String, TopicReport>,
+    pub last_error: Option<ErrorReport>,
+}
+
+/// The traffic on a topic monitored with [Health::monitor].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TopicReport {
+    /// Messages counted since the node started
+    pub messages: u64,
+    /// Messages per second over the last 10 seconds, see [TopicMonitor]
+    pub rate_hz: f64,
+    pub last_message_secs_ago: Option<f64>,
+}
+
+/// The last error recorded with [Health::record_error].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ErrorReport {
+    pub message: String,
+    pub secs_ago: f64,
+}
+
+/// Serves the health endpoints until dropped, see [Health::serve].
+pub struct HealthServer {
+    local_addr: SocketAddr,
+    _task: ChildTask<()>,
+}
+
+impl HealthServer {
+    /// The address the server is listening on, useful when binding to port 0.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+/// A publisher or subscriber whose messages and errors are recorded in a [Health], see [Health::monitor].
+pub struct Monitored<S> {
+    inner: S,
+    topic: String,
+    health: Health,
+}
+
+impl<S> Monitored<S> {
+    /// Returns the wrapped publisher or subscriber.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<T: RosMessageType, S: Publish<T> + Sync> Publish<T> for Monitored<S> {
+    async fn publish(&self, data: &T) -> Result<()> {
+        let result = self.inner.publish(data).await;
+        match &result {
+            Ok(()) => self.health.record_message(&self.topic),
+            Err(e) => self.health.record_error(e),
+        }
+        result
+    }
+}
+
+impl<T: RosMessageType, S: Subscribe<T> + Send> Subscribe<T> for Monitored<S> {
+    async fn next(&mut self) -> Result<T> {
+        let result = self.inner.next().await;
+        match &result {
+            Ok(_) => self.health.record_message(&self.topic),
+            Err(e) => self.health.record_error(e),
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TopicProvider;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    async fn get(addr: SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reports_health() {
+        let ros = MockRos::new();
+        let connected = Arc::new(AtomicBool::new(true));
+        let health = Health::new("mock").connection_check({
+            let connected = connected.clone();
+            move || connected.load(Ordering::SeqCst)
+        });
+        let server = health.serve("127.0.0.1:0").await.unwrap();
+
+        let publisher = health.monitor(
+            "/chatter",
+            ros.advertise::<std_msgs::String>("/chatter").await.unwrap(),
+        );
+        for _ in 0..3 {
+            publisher
+                .publish(&std_msgs::String::default())
+                .await
+                .unwrap();
+        }
+        health.record_error(&"lost connection");
+
+        let report = health.report();
+        assert!(report.healthy);
+        assert_eq!(report.backend, "mock");
+        assert_eq!(report.topics["/chatter"].messages, 3);
+        assert!(report.topics["/chatter"].rate_hz > 0.0);
+        assert_eq!(report.last_error.unwrap().message, "lost connection");
+
+        let response = get(server.local_addr(), "/health").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+        let body: serde_json::Value =
+            serde_json::from_str(response.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(body["connected"], true);
+        assert_eq!(body["topics"]["/chatter"]["messages"], 3);
+
+        connected.store(false, Ordering::SeqCst);
+        let response = get(server.local_addr(), "/health").await;
+        assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        let response = get(server.local_addr(), "/live").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        let response = get(server.local_addr(), "/other").await;
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+}
@@ -0,0 +1,141 @@
+//! Keeps the most recent message of a topic at hand, e.g. for slow changing map or configuration topics,
+//! see [LatestValue].
+//!
+//! ```no_run
+//! use roslibrust::latest::LatestValue;
+//! use roslibrust::Ros;
+//! use roslibrust_test::ros1::std_msgs;
+//! use std::time::Duration;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     let speed_limit = LatestValue::<std_msgs::Float64>::subscribe(&ros, "/speed_limit").await?;
+//!     // Waits for the first message
+//!     let limit = speed_limit.wait_for_fresh(Duration::MAX).await?;
+//!     log::info!("Speed limit is {}", limit.msg.data);
+//!     // Later on, without waiting
+//!     if let Some(limit) = speed_limit.get() {
+//!         log::info!("Speed limit was {} {:?} ago", limit.msg.data, limit.received.elapsed());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Result, RosMessageType, Subscribe, TopicProvider};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::time::Duration;
+use tokio::{sync::watch, time::Instant};
+
+/// A message and when it was received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Received<T> {
+    pub msg: T,
+    pub received: Instant,
+}
+
+/// Receives a topic in a background task, only keeping the most recent message.
+///
+/// Unlike a subscriber, reading the value doesn't consume it, so any number of readers can share a [LatestValue]
+/// and each gets the latest message whenever it asks. Receiving stops when it is dropped, or when the subscriber
+/// disconnects, after which the last message can still be read.
+pub struct LatestValue<T> {
+    latest: watch::Receiver<Option<Received<T>>>,
+    _task: ChildTask<()>,
+}
+
+impl<T: RosMessageType> LatestValue<T> {
+    /// Subscribes to `topic` on `ros` and keeps its most recent message.
+    pub async fn subscribe(ros: &impl TopicProvider, topic: &str) -> Result<Self> {
+        let subscriber = ros.subscribe::<T>(topic).await?;
+        Ok(Self::new(subscriber, topic))
+    }
+
+    /// Keeps the most recent message of `subscriber`, naming `topic` when logging errors.
+    pub fn new(mut subscriber: impl Subscribe<T> + Send + 'static, topic: &str) -> Self {
+        let (sender, latest) = watch::channel(None);
+        let topic = topic.to_string();
+        let task = tokio::spawn(async move {
+            loop {
+                match subscriber.next().await {
+                    Ok(msg) => {
+                        sender.send_replace(Some(Received {
+                            msg,
+                            received: Instant::now(),
+                        }));
+                    }
+                    Err(Error::Disconnected) => {
+                        warn!("Subscriber for {topic} disconnected, its latest value will no longer update");
+                        return;
+                    }
+                    Err(e) => warn!("Failed to receive message on {topic}: {e}"),
+                }
+            }
+        });
+        Self {
+            latest,
+            _task: task.into(),
+        }
+    }
+
+    /// The most recent message, None if none was received yet.
+    pub fn get(&self) -> Option<Received<T>> {
+        self.latest.borrow().clone()
+    }
+
+    /// Returns the most recent message if it was received within `max_age`, otherwise waits for the next one.
+    /// `Duration::MAX` waits for the first message if none was received yet.
+    ///
+    /// Fails with [Error::Disconnected] if the subscriber disconnects while waiting.
+    pub async fn wait_for_fresh(&self, max_age: Duration) -> Result<Received<T>> {
+        let mut latest = self.latest.clone();
+        let fresh = latest
+            .wait_for(|latest| {
+                latest
+                    .as_ref()
+                    .is_some_and(|latest| latest.received.elapsed() <= max_age)
+            })
+            .await
+            .map_err(|_| Error::Disconnected)?;
+        Ok(fresh.clone().expect("Only fresh values are waited for"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Publish;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn keeps_latest_message() {
+        let ros = MockRos::new();
+        let publisher = ros.advertise::<std_msgs::UInt32>("/value").await.unwrap();
+        let latest = LatestValue::<std_msgs::UInt32>::subscribe(&ros, "/value")
+            .await
+            .unwrap();
+        assert!(latest.get().is_none());
+
+        for data in 0..3 {
+            publisher.publish(&std_msgs::UInt32 { data }).await.unwrap();
+        }
+        let first = latest.wait_for_fresh(Duration::MAX).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let received = latest.get().unwrap();
+        assert_eq!(received.msg.data, 2);
+        assert!(received.received >= first.received);
+        // Reading doesn't consume the value
+        assert_eq!(latest.get(), Some(received));
+
+        // Too old, so waits for the next message
+        let next =
+            tokio::spawn(async move { latest.wait_for_fresh(Duration::from_millis(10)).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!next.is_finished());
+        publisher
+            .publish(&std_msgs::UInt32 { data: 3 })
+            .await
+            .unwrap();
+        assert_eq!(next.await.unwrap().unwrap().msg.data, 3);
+    }
+}
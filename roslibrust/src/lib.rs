@@ -20,6 +20,14 @@ pub use roslibrust_zenoh as zenoh;
 #[cfg(feature = "mock")]
 pub use roslibrust_mock as mock;
 
+// If the foxglove feature is enabled, export the roslibrust_foxglove crate under foxglove
+#[cfg(feature = "foxglove")]
+pub use roslibrust_foxglove as foxglove;
+
+// If the rosmaster feature is enabled, export the roslibrust_rosmaster crate under rosmaster
+#[cfg(feature = "rosmaster")]
+pub use roslibrust_rosmaster as rosmaster;
+
 // If the codegen feature is enabled, export the roslibrust_codegen crate under codegen
 #[cfg(feature = "codegen")]
 pub use roslibrust_codegen as codegen;
@@ -29,3 +37,101 @@ pub use roslibrust_codegen as codegen;
 pub use roslibrust_codegen_macro::find_and_generate_ros_messages;
 #[cfg(feature = "macro")]
 pub use roslibrust_codegen_macro::find_and_generate_ros_messages_without_ros_package_path;
+#[cfg(feature = "macro")]
+pub use roslibrust_codegen_macro::RosParams;
+
+// If the node feature is enabled, provide the node framework under node
+#[cfg(feature = "node")]
+pub mod node;
+
+// If the bridge feature is enabled, provide the ROS1 <-> ROS2 bridge under bridge
+#[cfg(feature = "bridge")]
+pub mod bridge;
+
+// If the health feature is enabled, provide the HTTP health endpoint under health
+#[cfg(feature = "health")]
+pub mod health;
+
+// If the watchdog feature is enabled, provide the stale topic detection under watchdog
+#[cfg(feature = "watchdog")]
+pub mod watchdog;
+
+// If the adapters feature is enabled, provide the subscriber combinators under adapters
+#[cfg(feature = "adapters")]
+pub mod adapters;
+
+// If the relay feature is enabled, provide the topic republisher under relay
+#[cfg(feature = "relay")]
+pub mod relay;
+
+// If the latest feature is enabled, provide the latest message cache under latest
+#[cfg(feature = "latest")]
+pub mod latest;
+
+// If the rosout feature is enabled, provide forwarding of /rosout into tracing under rosout
+#[cfg(feature = "rosout")]
+pub mod rosout;
+
+// If the stats feature is enabled, provide the topic statistics recorder under stats
+#[cfg(feature = "stats")]
+pub mod stats;
+
+// If the fanout feature is enabled, provide calling a service on many nodes at once under fanout
+#[cfg(feature = "fanout")]
+pub mod fanout;
+
+// If the params feature is enabled, provide reloading of parameter structs under params
+#[cfg(feature = "params")]
+pub mod params;
+
+// If the router feature is enabled, provide combining backends by topic and service name under router
+#[cfg(feature = "router")]
+pub mod router;
+
+// If the reliable feature is enabled, provide the publisher riding out lost connections under reliable
+#[cfg(feature = "reliable")]
+pub mod reliable;
+
+// If the recorder feature is enabled, provide recording of topics to MCAP files under recorder
+#[cfg(feature = "recorder")]
+pub mod recorder;
+
+// If the player feature is enabled, provide playback of MCAP files under player
+#[cfg(feature = "player")]
+pub mod player;
+
+// If the timesync feature is enabled, provide estimating the clock of another machine under timesync
+#[cfg(feature = "timesync")]
+pub mod timesync;
+
+// If the gazebo feature is enabled, provide converting messages to and from gz-msgs under gz_convert
+#[cfg(feature = "gazebo")]
+pub mod gz_convert;
+
+// If the actions feature is enabled, provide streaming the state of action goals under actions
+#[cfg(feature = "actions")]
+pub mod actions;
+
+// If the mux feature is enabled, provide choosing between topics by priority under mux
+#[cfg(feature = "mux")]
+pub mod mux;
+
+// If the service_cache feature is enabled, provide caching of service responses under service_cache
+#[cfg(feature = "service_cache")]
+pub mod service_cache;
+
+// If the scope feature is enabled, provide tying handles and tasks to the lifetime of a scope under scope
+#[cfg(feature = "scope")]
+pub mod scope;
+
+// If the topic_pair feature is enabled, provide calls over request and response topics under topic_pair
+#[cfg(feature = "topic_pair")]
+pub mod topic_pair;
+
+// If the composition feature is enabled, provide running components within one process under composition
+#[cfg(feature = "composition")]
+pub mod composition;
+
+// If the transcode feature is enabled, provide converting messages between JSON and ROS1 under transcode
+#[cfg(feature = "transcode")]
+pub mod transcode;
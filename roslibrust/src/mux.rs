@@ -0,0 +1,262 @@
+//! Chooses between several topics by priority, republishing the one in control like twist_mux, see [TopicMux].
+//!
+//! ```no_run
+//! use roslibrust::mux::TopicMux;
+//! use roslibrust::Ros;
+//! use roslibrust_test::ros1::geometry_msgs;
+//! use std::time::Duration;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     // A joystick overrides the navigation stack for as long as it keeps sending commands
+//!     let mux = TopicMux::<geometry_msgs::Twist>::new("/cmd_vel")
+//!         .input("/joy_vel", 100, Duration::from_millis(500))
+//!         .input("/nav_vel", 10, Duration::from_millis(500))
+//!         .start(&ros)
+//!         .await?;
+//!     // Muxes until the handle is dropped
+//!     let _ = tokio::signal::ctrl_c().await;
+//!     log::info!("{:?} was in control", mux.active());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Publish, Result, RosMessageType, Subscribe, TopicProvider};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::{sync::mpsc, time::Instant};
+
+/// An input topic of a [TopicMux]
+#[derive(Clone, Debug)]
+struct Input {
+    topic: String,
+    priority: u32,
+    timeout: Duration,
+}
+
+/// Republishes the messages of the highest priority input topic currently publishing onto an output topic, the
+/// equivalent of twist_mux for any message type.
+///
+/// An input is active while its last message is younger than its timeout. A message is forwarded unless an input of
+/// higher priority is active, so a higher priority input takes over with its first message, and hands control back
+/// once it stops publishing for longer than its timeout. Inputs of equal priority are all forwarded.
+pub struct TopicMux<T> {
+    output: String,
+    inputs: Vec<Input>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: RosMessageType> TopicMux<T> {
+    /// Muxes onto `output`, inputs are added with [TopicMux::input].
+    pub fn new(output: impl Into<String>) -> Self {
+        Self {
+            output: output.into(),
+            inputs: vec![],
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Adds `topic` as an input, higher `priority` taking precedence, which stays active for `timeout` after each
+    /// message.
+    pub fn input(mut self, topic: impl Into<String>, priority: u32, timeout: Duration) -> Self {
+        self.inputs.push(Input {
+            topic: topic.into(),
+            priority,
+            timeout,
+        });
+        self
+    }
+
+    /// Starts muxing within `ros`.
+    pub async fn start(self, ros: &impl TopicProvider) -> Result<TopicMuxHandle> {
+        if let Some(input) = self.inputs.iter().find(|input| input.topic == self.output) {
+            return Err(Error::InvalidName(format!(
+                "Can't mux {} onto itself",
+                input.topic
+            )));
+        }
+        let publisher = ros.advertise::<T>(self.output.as_str()).await?;
+
+        // Each input is received on its own task, the messages are arbitrated in the order they arrive
+        let (sender, mut receiver) = mpsc::channel::<(usize, T)>(self.inputs.len().max(1));
+        let mut tasks: Vec<ChildTask<()>> = vec![];
+        for (index, input) in self.inputs.iter().enumerate() {
+            let mut subscriber = ros.subscribe::<T>(input.topic.as_str()).await?;
+            let sender = sender.clone();
+            let topic = input.topic.clone();
+            let task = tokio::spawn(async move {
+                loop {
+                    let msg = match subscriber.next().await {
+                        Ok(msg) => msg,
+                        Err(Error::Disconnected) => {
+                            warn!("Subscriber for muxed topic {topic} disconnected, no longer muxing it");
+                            return;
+                        }
+                        Err(e) => {
+                            warn!("Failed to receive message on muxed topic {topic}: {e}");
+                            continue;
+                        }
+                    };
+                    if sender.send((index, msg)).await.is_err() {
+                        return;
+                    }
+                }
+            });
+            tasks.push(task.into());
+        }
+        drop(sender);
+        debug!(
+            "Muxing {:?} onto {}",
+            self.inputs
+                .iter()
+                .map(|input| &input.topic)
+                .collect::<Vec<_>>(),
+            self.output
+        );
+
+        let state = Arc::new(MuxState {
+            last_received: Mutex::new(vec![None; self.inputs.len()]),
+            inputs: self.inputs,
+        });
+        let forwarded = Arc::new(AtomicU64::new(0));
+        let output = self.output;
+        let task = tokio::spawn({
+            let state = state.clone();
+            let forwarded = forwarded.clone();
+            async move {
+                while let Some((index, msg)) = receiver.recv().await {
+                    if !state.receive(index, Instant::now()) {
+                        continue;
+                    }
+                    match publisher.publish(&msg).await {
+                        Ok(()) => {
+                            forwarded.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Err(e) => warn!("Failed to publish message on muxed topic {output}: {e}"),
+                    }
+                }
+            }
+        });
+        tasks.push(task.into());
+
+        Ok(TopicMuxHandle {
+            state,
+            forwarded,
+            _tasks: tasks,
+        })
+    }
+}
+
+/// When each input last received a message, shared by the arbitrating task and the handle
+struct MuxState {
+    inputs: Vec<Input>,
+    last_received: Mutex<Vec<Option<Instant>>>,
+}
+
+impl MuxState {
+    /// Records a message received on input `index`, returning whether it is forwarded
+    fn receive(&self, index: usize, now: Instant) -> bool {
+        let mut last_received = self.last_received.lock().unwrap();
+        last_received[index] = Some(now);
+        let priority = self.inputs[index].priority;
+        let outranked = self
+            .active_inputs(&last_received, now)
+            .any(|active| self.inputs[active].priority > priority);
+        !outranked
+    }
+
+    /// The indices of the inputs whose last message is younger than their timeout
+    fn active_inputs<'a>(
+        &'a self,
+        last_received: &'a [Option<Instant>],
+        now: Instant,
+    ) -> impl Iterator<Item = usize> + 'a {
+        last_received
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, last)| {
+                let last = (*last)?;
+                (now.duration_since(last) < self.inputs[index].timeout).then_some(index)
+            })
+    }
+}
+
+/// A running [TopicMux], muxing stops when it is dropped.
+pub struct TopicMuxHandle {
+    state: Arc<MuxState>,
+    forwarded: Arc<AtomicU64>,
+    _tasks: Vec<ChildTask<()>>,
+}
+
+impl TopicMuxHandle {
+    /// The input topic in control, the active input of highest priority, or None when no input is active.
+    pub fn active(&self) -> Option<String> {
+        let last_received = self.state.last_received.lock().unwrap();
+        self.state
+            .active_inputs(&last_received, Instant::now())
+            .max_by_key(|index| self.state.inputs[*index].priority)
+            .map(|index| self.state.inputs[index].topic.clone())
+    }
+
+    /// The number of messages published so far.
+    pub fn forwarded(&self) -> u64 {
+        self.forwarded.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn higher_priority_takes_over_until_it_times_out() {
+        let ros = MockRos::new();
+        let mux = TopicMux::<std_msgs::String>::new("/cmd")
+            .input("/joystick", 100, Duration::from_millis(300))
+            .input("/planner", 10, Duration::from_millis(300))
+            .start(&ros)
+            .await
+            .unwrap();
+        let mut output = ros.subscribe::<std_msgs::String>("/cmd").await.unwrap();
+        let joystick = ros
+            .advertise::<std_msgs::String>("/joystick")
+            .await
+            .unwrap();
+        let planner = ros.advertise::<std_msgs::String>("/planner").await.unwrap();
+        let msg = |data: &str| std_msgs::String {
+            data: data.to_string(),
+        };
+        assert_eq!(mux.active(), None);
+
+        planner.publish(&msg("planner 1")).await.unwrap();
+        assert_eq!(output.next().await.unwrap().data, "planner 1");
+        assert_eq!(mux.active().as_deref(), Some("/planner"));
+
+        joystick.publish(&msg("joystick 1")).await.unwrap();
+        assert_eq!(output.next().await.unwrap().data, "joystick 1");
+        assert_eq!(mux.active().as_deref(), Some("/joystick"));
+
+        // Dropped while the joystick is in control
+        planner.publish(&msg("planner 2")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        joystick.publish(&msg("joystick 2")).await.unwrap();
+        assert_eq!(output.next().await.unwrap().data, "joystick 2");
+
+        // The planner is back in control once the joystick times out
+        tokio::time::sleep(Duration::from_millis(400)).await;
+        assert_eq!(mux.active(), None);
+        planner.publish(&msg("planner 3")).await.unwrap();
+        assert_eq!(output.next().await.unwrap().data, "planner 3");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(mux.forwarded(), 4);
+    }
+}
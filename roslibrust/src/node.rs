@@ -0,0 +1,556 @@
+//! A lightweight framework for structuring larger applications on top of the generic [Ros] traits.
+//!
+//! A [NodeBuilder] owns a [Ros] implementation and has subscriptions, services, timers, and arbitrary tasks
+//! registered with it declaratively. When [NodeBuilder::start] is called each component is started in the order
+//! it was registered, and the returned [Node] supervises them: components which fail are restarted according to
+//! the [RestartPolicy], and [Node::shutdown] signals every component to stop and waits for them to exit.
+//!
+//! ```no_run
+//! use roslibrust::node::NodeBuilder;
+//! use roslibrust::{Publish, Ros};
+//! use roslibrust_test::ros1::std_msgs;
+//! use std::time::Duration;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     let node = NodeBuilder::new(ros)
+//!         .subscription("/in", |msg: std_msgs::String| async move {
+//!             log::info!("Got: {}", msg.data);
+//!             Ok(())
+//!         })
+//!         .task("heartbeat", |ros, mut shutdown| async move {
+//!             let publisher = ros.advertise::<std_msgs::String>("/out").await?;
+//!             loop {
+//!                 tokio::select! {
+//!                     _ = shutdown.wait() => return Ok(()),
+//!                     _ = tokio::time::sleep(Duration::from_secs(1)) => {
+//!                         publisher.publish(&std_msgs::String { data: "alive".to_string() }).await?;
+//!                     }
+//!                 }
+//!             }
+//!         })
+//!         .start()
+//!         .await?;
+//!
+//!     // Run until ctrl-c is pressed, then shutdown all components
+//!     node.run_until(async {
+//!         let _ = tokio::signal::ctrl_c().await;
+//!     })
+//!     .await;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    Error, Result, Ros, RosMessageType, RosServiceType, ServiceFn, ServiceName, Subscribe,
+    ToServiceName, ToTopicName, TopicName,
+};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+use tokio::sync::watch;
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+/// Performs the setup of a component (advertise, subscribe, etc.) and returns the long running body of the component.
+type StartFn<R> =
+    Box<dyn Fn(R, Shutdown) -> BoxFuture<Result<BoxFuture<Result<()>>>> + Send + Sync + 'static>;
+
+// Callbacks are only ever invoked by one task at a time, the lock exists so FnMut callbacks can be shared across restarts
+fn lock<F>(callback: &Mutex<F>) -> MutexGuard<'_, F> {
+    callback.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+struct Component<R> {
+    name: String,
+    start: StartFn<R>,
+}
+
+/// Determines what a [Node] does when one of its components fails.
+///
+/// A component fails when its callback returns an error, it panics, or its underlying subscription returns an error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RestartPolicy {
+    /// Failed components are logged and left stopped.
+    Never,
+    /// Failed components are restarted (re-subscribed, re-advertised, etc.) after waiting `backoff`.
+    /// If `max_restarts` is set a component will be left stopped after failing that many times.
+    OnFailure {
+        backoff: Duration,
+        max_restarts: Option<usize>,
+    },
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        RestartPolicy::OnFailure {
+            backoff: Duration::from_secs(1),
+            max_restarts: None,
+        }
+    }
+}
+
+/// A signal handed to components that resolves when the owning [Node] is shutting down.
+///
+/// Long running [NodeBuilder::task] components should watch this signal and exit when it fires.
+#[derive(Clone)]
+pub struct Shutdown {
+    receiver: watch::Receiver<bool>,
+}
+
+impl Shutdown {
+    pub(crate) fn new(receiver: watch::Receiver<bool>) -> Self {
+        Self { receiver }
+    }
+
+    /// True once the owning node has started shutting down.
+    pub fn is_shutdown(&self) -> bool {
+        *self.receiver.borrow()
+    }
+
+    /// Waits until the owning node starts shutting down.
+    pub async fn wait(&mut self) {
+        // An error means the node was dropped, which is treated the same as shutdown
+        let _ = self.receiver.wait_for(|shutdown| *shutdown).await;
+    }
+}
+
+/// Declaratively builds a [Node], see the [module level documentation](self) for an example.
+pub struct NodeBuilder<R: Ros> {
+    ros: R,
+    components: Vec<Component<R>>,
+    restart_policy: RestartPolicy,
+    shutdown_timeout: Duration,
+    // Errors encountered while building (e.g. invalid topic names) are reported by start()
+    errors: Vec<Error>,
+}
+
+impl<R: Ros> NodeBuilder<R> {
+    /// Creates a new builder which will own the provided ros handle.
+    pub fn new(ros: R) -> Self {
+        Self {
+            ros,
+            components: Vec::new(),
+            restart_policy: RestartPolicy::default(),
+            shutdown_timeout: Duration::from_secs(5),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Sets the policy applied to all components when they fail, defaults to [RestartPolicy::default].
+    pub fn restart_policy(mut self, policy: RestartPolicy) -> Self {
+        self.restart_policy = policy;
+        self
+    }
+
+    /// Sets how long [Node::shutdown] waits for components to exit before aborting them, defaults to 5 seconds.
+    pub fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Subscribes to `topic` and calls `callback` with every message received.
+    ///
+    /// If the callback returns an error the component is considered failed and handled per the [RestartPolicy].
+    pub fn subscription<T, F, Fut>(mut self, topic: impl ToTopicName, callback: F) -> Self
+    where
+        T: RosMessageType,
+        F: FnMut(T) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let topic: TopicName = match topic.to_topic_name() {
+            Ok(topic) => topic,
+            Err(e) => {
+                self.errors.push(e);
+                return self;
+            }
+        };
+        let callback = Arc::new(Mutex::new(callback));
+        let name = format!("subscription {topic}");
+        let start =
+            move |ros: R, mut shutdown: Shutdown| -> BoxFuture<Result<BoxFuture<Result<()>>>> {
+                let topic = topic.clone();
+                let callback = callback.clone();
+                Box::pin(async move {
+                    let mut subscriber = ros.subscribe::<T>(&topic).await?;
+                    let run: BoxFuture<Result<()>> = Box::pin(async move {
+                        loop {
+                            tokio::select! {
+                                _ = shutdown.wait() => return Ok(()),
+                                msg = subscriber.next() => {
+                                    let fut = lock(&callback)(msg?);
+                                    fut.await?
+                                }
+                            }
+                        }
+                    });
+                    Ok(run)
+                })
+            };
+        self.push(name, Box::new(start))
+    }
+
+    /// Advertises a service which will remain available until the node is shutdown.
+    pub fn service<T, F>(mut self, service: impl ToServiceName, server: F) -> Self
+    where
+        T: RosServiceType,
+        F: ServiceFn<T>,
+    {
+        let service: ServiceName = match service.to_service_name() {
+            Ok(service) => service,
+            Err(e) => {
+                self.errors.push(e);
+                return self;
+            }
+        };
+        let server = Arc::new(server);
+        let name = format!("service {service}");
+        let start =
+            move |ros: R, mut shutdown: Shutdown| -> BoxFuture<Result<BoxFuture<Result<()>>>> {
+                let service = service.clone();
+                let server = server.clone();
+                Box::pin(async move {
+                    let handle = ros
+                        .advertise_service::<T, _>(&service, move |request| server(request))
+                        .await?;
+                    let run: BoxFuture<Result<()>> = Box::pin(async move {
+                        // The service remains advertised until the handle is dropped
+                        let _handle = handle;
+                        shutdown.wait().await;
+                        Ok(())
+                    });
+                    Ok(run)
+                })
+            };
+        self.push(name, Box::new(start))
+    }
+
+    /// Calls `callback` every `period`, the first call happens immediately on start.
+    pub fn timer<F, Fut>(self, name: &str, period: Duration, callback: F) -> Self
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let callback = Arc::new(Mutex::new(callback));
+        let start =
+            move |_ros: R, mut shutdown: Shutdown| -> BoxFuture<Result<BoxFuture<Result<()>>>> {
+                let callback = callback.clone();
+                Box::pin(async move {
+                    let run: BoxFuture<Result<()>> = Box::pin(async move {
+                        let mut interval = tokio::time::interval(period);
+                        loop {
+                            tokio::select! {
+                                _ = shutdown.wait() => return Ok(()),
+                                _ = interval.tick() => {
+                                    let fut = lock(&callback)();
+                                    fut.await?
+                                }
+                            }
+                        }
+                    });
+                    Ok(run)
+                })
+            };
+        self.push(format!("timer {name}"), Box::new(start))
+    }
+
+    /// Runs an arbitrary long running task which is given a handle to ros and the node's [Shutdown] signal.
+    ///
+    /// The task should exit when the shutdown signal fires, otherwise it is aborted after the shutdown timeout.
+    /// A task returning `Ok(())` before shutdown is considered complete and is not restarted.
+    pub fn task<F, Fut>(self, name: &str, task: F) -> Self
+    where
+        F: FnMut(R, Shutdown) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let task = Arc::new(Mutex::new(task));
+        let start = move |ros: R, shutdown: Shutdown| -> BoxFuture<Result<BoxFuture<Result<()>>>> {
+            let run: BoxFuture<Result<()>> = Box::pin(lock(&task)(ros, shutdown));
+            Box::pin(async move { Ok(run) })
+        };
+        self.push(format!("task {name}"), Box::new(start))
+    }
+
+    fn push(mut self, name: String, start: StartFn<R>) -> Self {
+        self.components.push(Component { name, start });
+        self
+    }
+
+    /// Starts all registered components in the order they were registered.
+    ///
+    /// Each component's setup (e.g. subscribing) completes before the next component is started.
+    /// If any component fails to start, all previously started components are stopped and the error is returned.
+    pub async fn start(self) -> Result<Node<R>> {
+        if let Some(e) = self.errors.into_iter().next() {
+            return Err(e);
+        }
+
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let shutdown = Shutdown::new(shutdown_rx);
+        let mut supervisors = Vec::with_capacity(self.components.len());
+        for component in self.components {
+            let run = match (component.start)(self.ros.clone(), shutdown.clone()).await {
+                Ok(run) => run,
+                Err(e) => {
+                    error!("Failed to start {}: {e}", component.name);
+                    // Dropping the supervisors aborts everything started so far
+                    let _ = shutdown_tx.send(true);
+                    return Err(e);
+                }
+            };
+            debug!("Started {}", component.name);
+            let supervisor = supervise(
+                self.ros.clone(),
+                Arc::new(component),
+                run,
+                self.restart_policy,
+                shutdown.clone(),
+            );
+            supervisors.push(ChildTask::from(tokio::spawn(supervisor)));
+        }
+
+        Ok(Node {
+            ros: self.ros,
+            shutdown: shutdown_tx,
+            supervisors,
+            shutdown_timeout: self.shutdown_timeout,
+        })
+    }
+}
+
+/// Runs a component, restarting it per the restart policy until it completes or the node shuts down.
+async fn supervise<R: Ros>(
+    ros: R,
+    component: Arc<Component<R>>,
+    mut run: BoxFuture<Result<()>>,
+    policy: RestartPolicy,
+    mut shutdown: Shutdown,
+) {
+    let mut restarts = 0;
+    loop {
+        // Run in a separate task so that panics are caught and reported
+        let result = ChildTask::from(tokio::spawn(run)).await;
+        if shutdown.is_shutdown() {
+            return;
+        }
+        match result {
+            Ok(Ok(())) => {
+                debug!("{} finished", component.name);
+                return;
+            }
+            Ok(Err(e)) => error!("{} failed: {e}", component.name),
+            Err(e) => error!("{} panicked: {e}", component.name),
+        }
+
+        let RestartPolicy::OnFailure {
+            backoff,
+            max_restarts,
+        } = policy
+        else {
+            return;
+        };
+        loop {
+            if max_restarts.is_some_and(|max| restarts >= max) {
+                error!(
+                    "{} failed {restarts} times, no longer restarting",
+                    component.name
+                );
+                return;
+            }
+            restarts += 1;
+            tokio::select! {
+                _ = shutdown.wait() => return,
+                _ = tokio::time::sleep(backoff) => {},
+            }
+            match (component.start)(ros.clone(), shutdown.clone()).await {
+                Ok(new_run) => {
+                    info!("Restarted {}", component.name);
+                    run = new_run;
+                    break;
+                }
+                Err(e) => error!("Failed to restart {}: {e}", component.name),
+            }
+        }
+    }
+}
+
+/// A running set of components created by [NodeBuilder::start].
+///
+/// Dropping the node aborts all of its components, use [Node::shutdown] to stop them gracefully.
+pub struct Node<R: Ros> {
+    ros: R,
+    shutdown: watch::Sender<bool>,
+    supervisors: Vec<ChildTask<()>>,
+    shutdown_timeout: Duration,
+}
+
+impl<R: Ros> Node<R> {
+    /// Returns the ros handle owned by this node.
+    pub fn ros(&self) -> &R {
+        &self.ros
+    }
+
+    /// Returns a copy of the shutdown signal handed to this node's components.
+    pub fn shutdown_signal(&self) -> Shutdown {
+        Shutdown::new(self.shutdown.subscribe())
+    }
+
+    /// True if every component has exited (completed, or failed and not restarted).
+    pub fn is_finished(&self) -> bool {
+        self.supervisors.iter().all(|s| s.is_finished())
+    }
+
+    /// Signals all components to stop and waits up to the shutdown timeout for them to exit.
+    /// Components which have not exited by then are aborted.
+    pub async fn shutdown(mut self) {
+        let _ = self.shutdown.send(true);
+        let join_all = async {
+            for supervisor in &mut self.supervisors {
+                let _ = supervisor.await;
+            }
+        };
+        if tokio::time::timeout(self.shutdown_timeout, join_all)
+            .await
+            .is_err()
+        {
+            warn!("Node components did not shutdown within timeout, aborting them");
+        }
+    }
+
+    /// Waits for `signal` to resolve and then shuts the node down.
+    pub async fn run_until(self, signal: impl Future<Output = ()>) {
+        signal.await;
+        self.shutdown().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Publish, Service, ServiceProvider, TopicProvider};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::{std_msgs, std_srvs};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn node_runs_components() {
+        let ros = MockRos::new();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let publisher = Arc::new(ros.advertise::<std_msgs::String>("/out").await.unwrap());
+
+        let node = NodeBuilder::new(ros.clone())
+            .subscription("/in", move |msg: std_msgs::String| {
+                let tx = tx.clone();
+                async move {
+                    tx.send(msg.data).unwrap();
+                    Ok(())
+                }
+            })
+            .service::<std_srvs::SetBool, _>("/set", |request: std_srvs::SetBoolRequest| {
+                Ok(std_srvs::SetBoolResponse {
+                    success: request.data,
+                    message: String::new(),
+                })
+            })
+            .timer("heartbeat", Duration::from_millis(10), move || {
+                let publisher = publisher.clone();
+                async move {
+                    publisher
+                        .publish(&std_msgs::String {
+                            data: "tick".to_string(),
+                        })
+                        .await
+                }
+            })
+            .start()
+            .await
+            .unwrap();
+
+        // Timer output is observable
+        let mut out = ros.subscribe::<std_msgs::String>("/out").await.unwrap();
+        assert_eq!(out.next().await.unwrap().data, "tick");
+
+        // Subscription is receiving
+        let input = ros.advertise::<std_msgs::String>("/in").await.unwrap();
+        input
+            .publish(&std_msgs::String {
+                data: "hello".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(rx.recv().await.unwrap(), "hello");
+
+        // Service is available
+        let client = ros
+            .service_client::<std_srvs::SetBool>("/set")
+            .await
+            .unwrap();
+        let response = client
+            .call(&std_srvs::SetBoolRequest { data: true })
+            .await
+            .unwrap();
+        assert!(response.success);
+
+        node.shutdown().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn node_restarts_failed_components() {
+        let ros = MockRos::new();
+        let starts = Arc::new(AtomicUsize::new(0));
+        let starts_copy = starts.clone();
+
+        let node = NodeBuilder::new(ros)
+            .restart_policy(RestartPolicy::OnFailure {
+                backoff: Duration::from_millis(1),
+                max_restarts: Some(2),
+            })
+            .task("flaky", move |_ros, _shutdown| {
+                starts_copy.fetch_add(1, Ordering::SeqCst);
+                async { Err(Error::Disconnected) }
+            })
+            .start()
+            .await
+            .unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !node.is_finished() {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .unwrap();
+        // Initial start plus two restarts
+        assert_eq!(starts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn node_shutdown_stops_tasks() {
+        let ros = MockRos::new();
+        let node = NodeBuilder::new(ros)
+            .task("waits", |_ros, mut shutdown| async move {
+                shutdown.wait().await;
+                Ok(())
+            })
+            .start()
+            .await
+            .unwrap();
+        assert!(!node.is_finished());
+        let signal = node.shutdown_signal();
+        node.shutdown().await;
+        assert!(signal.is_shutdown());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn node_rejects_invalid_names() {
+        let result = NodeBuilder::new(MockRos::new())
+            .subscription("bad name", |_msg: std_msgs::String| async { Ok(()) })
+            .start()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidName(_))));
+    }
+}
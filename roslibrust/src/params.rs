@@ -0,0 +1,199 @@
+//! Keeps a [RosParams] struct up to date with the parameter server while a node runs, see [LiveParams].
+//!
+//! ```no_run
+//! use roslibrust::params::LiveParams;
+//! use roslibrust::{ParamProvider, RosParams};
+//! use std::time::Duration;
+//!
+//! #[derive(RosParams, Clone, Debug, PartialEq)]
+//! #[ros_params(namespace = "/controller")]
+//! struct Gains {
+//!     #[param(min = 0.0)]
+//!     kp: f64,
+//!     #[param(default, min = 0.0)]
+//!     ki: f64,
+//! }
+//!
+//! async fn run(ros: impl ParamProvider + Send + Sync + 'static) -> roslibrust::Result<()> {
+//!     let mut gains = LiveParams::<Gains>::start(ros, Duration::from_secs(1)).await?;
+//!     loop {
+//!         let current = gains.changed().await;
+//!         log::info!("Now using {current:?}");
+//!     }
+//! }
+//! ```
+
+use crate::{ParamProvider, Result, RosParams};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::watch;
+
+/// The latest valid value of a [RosParams] struct, reloaded from its parameters at a fixed interval.
+///
+/// The parameter traits have no notification of changes, so the parameters are polled. A reload which fails, e.g.
+/// because a parameter was set out of range, is logged and the previous value is kept. Clones share the same value,
+/// and reloading stops once every clone is dropped.
+#[derive(Clone)]
+pub struct LiveParams<T> {
+    receiver: watch::Receiver<T>,
+    _task: Arc<ChildTask<()>>,
+}
+
+impl<T: RosParams + Clone + PartialEq + Sync + 'static> LiveParams<T> {
+    /// Loads `T` from [RosParams::NAMESPACE], failing if it can't be loaded now, then reloads it every `interval`.
+    pub async fn start(
+        ros: impl ParamProvider + Send + Sync + 'static,
+        interval: Duration,
+    ) -> Result<Self> {
+        Self::start_in(ros, T::NAMESPACE, interval).await
+    }
+
+    /// Loads `T` from `namespace`, failing if it can't be loaded now, then reloads it every `interval`.
+    pub async fn start_in(
+        ros: impl ParamProvider + Send + Sync + 'static,
+        namespace: impl Into<String>,
+        interval: Duration,
+    ) -> Result<Self> {
+        let namespace = namespace.into();
+        let value = T::load_from(&ros, &namespace).await?;
+        let (sender, receiver) = watch::channel(value);
+        let task = tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(interval);
+            ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick completes immediately, just after the value was loaded
+            ticks.tick().await;
+            loop {
+                ticks.tick().await;
+                match T::load_from(&ros, &namespace).await {
+                    Ok(value) => {
+                        sender.send_if_modified(|current| {
+                            let modified = *current != value;
+                            if modified {
+                                debug!("Parameters in {namespace} changed");
+                                *current = value;
+                            }
+                            modified
+                        });
+                    }
+                    Err(e) => {
+                        warn!("Failed to reload parameters in {namespace}, keeping the previous values: {e}")
+                    }
+                }
+            }
+        });
+        Ok(Self {
+            receiver,
+            _task: Arc::new(task.into()),
+        })
+    }
+
+    /// The latest valid value.
+    pub fn get(&self) -> T {
+        self.receiver.borrow().clone()
+    }
+
+    /// Waits until the parameters change, returning the new value.
+    pub async fn changed(&mut self) -> T {
+        // The sender lives as long as the task, which this keeps running
+        let _ = self.receiver.changed().await;
+        self.receiver.borrow_and_update().clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Error;
+    use roslibrust_codegen_macro::RosParams;
+    use roslibrust_mock::MockRos;
+
+    fn in_range(camera: &Camera) -> std::result::Result<(), String> {
+        match camera.exposure {
+            Some(exposure) if exposure > 1.0 / camera.frame_rate as f64 => {
+                Err(format!("Exposure {exposure} is longer than a frame"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[derive(RosParams, Clone, Debug, PartialEq)]
+    #[ros_params(crate = "crate", namespace = "/camera", validate = "in_range")]
+    struct Camera {
+        #[param(default = 30, min = 1, max = 120)]
+        frame_rate: u32,
+        #[param(name = "frame")]
+        frame_id: String,
+        #[param(min = "0.0")]
+        exposure: Option<f64>,
+        #[param(nested)]
+        lens: Lens,
+    }
+
+    #[derive(RosParams, Clone, Debug, PartialEq)]
+    #[ros_params(crate = "crate")]
+    struct Lens {
+        #[param(default)]
+        zoom: f64,
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn loads_and_validates_fields() {
+        let ros = MockRos::new();
+        // The frame is required
+        assert!(matches!(
+            Camera::load(&ros).await,
+            Err(Error::SerializationError(_))
+        ));
+
+        ros.set_param("/camera/frame", &"camera_link")
+            .await
+            .unwrap();
+        ros.set_param("/camera/lens/zoom", &2.0).await.unwrap();
+        assert_eq!(
+            Camera::load(&ros).await.unwrap(),
+            Camera {
+                frame_rate: 30,
+                frame_id: "camera_link".to_owned(),
+                exposure: None,
+                lens: Lens { zoom: 2.0 },
+            }
+        );
+
+        ros.set_param("/camera/frame_rate", &240).await.unwrap();
+        let error = Camera::load(&ros).await.unwrap_err().to_string();
+        assert!(error.contains("above its maximum"), "{error}");
+        ros.set_param("/camera/frame_rate", &60).await.unwrap();
+        ros.set_param("/camera/exposure", &0.1).await.unwrap();
+        let error = Camera::load(&ros).await.unwrap_err().to_string();
+        assert!(error.contains("longer than a frame"), "{error}");
+
+        // The same struct can be bound to another namespace
+        ros.set_param("/rear/frame", &"rear_link").await.unwrap();
+        let rear = Camera::load_from(&ros, "/rear/").await.unwrap();
+        assert_eq!(rear.frame_id, "rear_link");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn keeps_latest_valid_value() {
+        let ros = MockRos::new();
+        ros.set_param("/camera/frame", &"camera_link")
+            .await
+            .unwrap();
+        let mut camera = LiveParams::<Camera>::start(ros.clone(), Duration::from_millis(20))
+            .await
+            .unwrap();
+        assert_eq!(camera.get().frame_rate, 30);
+
+        ros.set_param("/camera/frame_rate", &15).await.unwrap();
+        let changed = tokio::time::timeout(Duration::from_secs(1), camera.changed())
+            .await
+            .unwrap();
+        assert_eq!(changed.frame_rate, 15);
+
+        // Invalid values are ignored
+        ros.set_param("/camera/frame_rate", &0).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(camera.get().frame_rate, 15);
+    }
+}
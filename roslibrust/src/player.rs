@@ -0,0 +1,652 @@
+//! Plays back MCAP files on any backend, controllable from Rust and over services, see [Player].
+//!
+//! ```no_run
+//! use roslibrust::player::{Player, PlayerOptions};
+//! use roslibrust::{RawTopicProvider, Ros};
+//! use std::time::Duration;
+//!
+//! async fn replay<R: Ros + RawTopicProvider>(ros: R) -> roslibrust::Result<()> {
+//!     let player = Player::open(&ros, "incident_0.mcap", PlayerOptions::new().rate(0.5)).await?;
+//!     // Lets a test harness or UI pause, resume, seek and change the rate through /player/...
+//!     let _control = player.advertise_control(&ros, "/player").await?;
+//!     player.seek(Duration::from_secs(10));
+//!     player.finished().await;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    mcap::{read_messages, McapMessage},
+    schema::MessageSchema,
+    Error, Publish, RawPublish, RawTopicProvider, Result, ServiceProvider, TopicProvider,
+};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    collections::HashMap,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{sync::watch, time::Instant};
+
+/// Configures a [Player].
+#[derive(Clone, Debug)]
+pub struct PlayerOptions {
+    rate: f64,
+    paused: bool,
+}
+
+impl Default for PlayerOptions {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            paused: false,
+        }
+    }
+}
+
+impl PlayerOptions {
+    /// Plays at the speed the messages were recorded at, starting right away.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plays `rate` times as fast as the messages were recorded, e.g. 0.5 for half speed.
+    pub fn rate(mut self, rate: f64) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Starts paused, waiting for [Player::resume].
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+}
+
+/// How far a [Player] got, see [Player::progress].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Progress {
+    /// The recording time played up to
+    pub time: SystemTime,
+    /// The time played up to, since the first message of the recording
+    pub position: Duration,
+    /// The time from the first to the last message of the recording
+    pub duration: Duration,
+    pub paused: bool,
+    pub rate: f64,
+    /// True once every message was played, until seeking back
+    pub finished: bool,
+}
+
+/// What the playback task is told to do
+#[derive(Clone, Copy, Debug)]
+struct Control {
+    paused: bool,
+    rate: f64,
+    // Incremented for every seek, so seeking twice to the same position is seen as two seeks
+    seeks: u64,
+    seek_to: Duration,
+}
+
+/// A message ready to be published
+struct Scheduled {
+    publisher: usize,
+    // Since the first message
+    offset: Duration,
+    data: Vec<u8>,
+}
+
+/// Publishes the messages of a recording with the timing they were recorded with, as `rosbag play` does.
+///
+/// Only messages of channels with the encoding of the backend, [RawTopicProvider::ENCODING], can be played,
+/// e.g. recordings of the ROS1 backend on the ROS1 backend. Messages of other channels are left out.
+///
+/// Playback can be paused, resumed, sped up or slowed down, and moved to any point of the recording, through the
+/// methods of the player or through the services of [Player::advertise_control]. Dropping the player stops it.
+pub struct Player {
+    control: watch::Sender<Control>,
+    progress: watch::Receiver<Progress>,
+    _task: ChildTask<()>,
+}
+
+impl Player {
+    /// Reads the MCAP file at `path` and plays it on `ros`, see [crate::mcap::read_messages].
+    pub async fn open<R>(ros: &R, path: impl AsRef<Path>, options: PlayerOptions) -> Result<Self>
+    where
+        R: RawTopicProvider + Send + Sync,
+    {
+        let file = std::fs::File::open(path)?;
+        let messages = read_messages(std::io::BufReader::new(file))?;
+        Self::new(ros, messages, options).await
+    }
+
+    /// Plays `messages` on `ros`, advertising every topic before the first message is published.
+    pub async fn new<R>(ros: &R, messages: Vec<McapMessage>, options: PlayerOptions) -> Result<Self>
+    where
+        R: RawTopicProvider + Send + Sync,
+    {
+        check_rate(options.rate)?;
+        let start = messages
+            .first()
+            .map(|message| message.log_time)
+            .unwrap_or(UNIX_EPOCH);
+        let mut publishers = vec![];
+        let mut by_topic = HashMap::new();
+        let mut scheduled = Vec::with_capacity(messages.len());
+        for message in messages {
+            let channel = &message.channel;
+            if channel.message_encoding != R::ENCODING {
+                if by_topic.insert(channel.topic.clone(), None).is_none() {
+                    warn!(
+                        "Not playing {}, its encoding {:?} isn't the backend's {:?}",
+                        channel.topic,
+                        channel.message_encoding,
+                        R::ENCODING
+                    );
+                }
+                continue;
+            }
+            let publisher = match by_topic.get(&channel.topic) {
+                Some(Some(publisher)) => *publisher,
+                Some(None) => continue,
+                None => {
+                    let definition = String::from_utf8_lossy(&channel.schema).into_owned();
+                    let mut schema = MessageSchema::new(&channel.schema_name, definition);
+                    if channel.schema.is_empty() {
+                        // An md5sum computed from a missing definition would be wrong
+                        schema.md5sum = None;
+                    }
+                    publishers.push(ros.advertise_raw(&channel.topic, &schema).await?);
+                    by_topic.insert(channel.topic.clone(), Some(publishers.len() - 1));
+                    publishers.len() - 1
+                }
+            };
+            scheduled.push(Scheduled {
+                publisher,
+                offset: message.log_time.duration_since(start).unwrap_or_default(),
+                data: message.data,
+            });
+        }
+
+        let duration = scheduled.last().map(|m| m.offset).unwrap_or_default();
+        let control = Control {
+            paused: options.paused,
+            rate: options.rate,
+            seeks: 0,
+            seek_to: Duration::ZERO,
+        };
+        let (control_tx, control_rx) = watch::channel(control);
+        let (progress_tx, progress_rx) = watch::channel(Progress {
+            time: start,
+            position: Duration::ZERO,
+            duration,
+            paused: options.paused,
+            rate: options.rate,
+            finished: scheduled.is_empty(),
+        });
+        let task = tokio::spawn(play(publishers, scheduled, start, control_rx, progress_tx));
+        Ok(Self {
+            control: control_tx,
+            progress: progress_rx,
+            _task: task.into(),
+        })
+    }
+
+    /// Stops publishing until [Player::resume] is called.
+    pub fn pause(&self) {
+        self.control.send_modify(|control| control.paused = true);
+    }
+
+    /// Continues publishing after [Player::pause], from where it was paused.
+    pub fn resume(&self) {
+        self.control.send_modify(|control| control.paused = false);
+    }
+
+    /// Plays `rate` times as fast as the messages were recorded from now on.
+    ///
+    /// Fails if `rate` isn't a positive number.
+    pub fn set_rate(&self, rate: f64) -> Result<()> {
+        check_rate(rate)?;
+        self.control.send_modify(|control| control.rate = rate);
+        Ok(())
+    }
+
+    /// Continues playing from `position` since the first message of the recording, skipping the messages before
+    /// it. Seeking past the end finishes playback.
+    pub fn seek(&self, position: Duration) {
+        self.control.send_modify(|control| {
+            control.seeks += 1;
+            control.seek_to = position;
+        });
+    }
+
+    /// How far playback got.
+    pub fn progress(&self) -> Progress {
+        *self.progress.borrow()
+    }
+
+    /// Waits until every message was played.
+    pub async fn finished(&self) {
+        let mut progress = self.progress.clone();
+        // Only fails once the playback task is gone, along with the player
+        let _ = progress.wait_for(|progress| progress.finished).await;
+    }
+
+    /// Serves services controlling playback under `namespace`, typed like those of ROS2's `rosbag2` player:
+    /// `pause` and `resume`, `set_rate` and `seek` to a recording time, see [srv]. The recording time played up
+    /// to is published as a `rosgraph_msgs/Clock` on `clock` under `namespace` ten times a second.
+    ///
+    /// The services and the publishing stop when the returned [PlayerControl] is dropped.
+    pub async fn advertise_control<R>(&self, ros: &R, namespace: &str) -> Result<PlayerControl<R>>
+    where
+        R: TopicProvider + ServiceProvider + Send + Sync,
+    {
+        let namespace = namespace.trim_end_matches('/');
+        let control = self.control.clone();
+        let pause = ros
+            .advertise_service::<srv::Pause, _>(format!("{namespace}/pause"), move |_| {
+                control.send_modify(|control| control.paused = true);
+                Ok(srv::PauseResponse {})
+            })
+            .await?;
+        let control = self.control.clone();
+        let resume = ros
+            .advertise_service::<srv::Resume, _>(format!("{namespace}/resume"), move |_| {
+                control.send_modify(|control| control.paused = false);
+                Ok(srv::ResumeResponse {})
+            })
+            .await?;
+        let control = self.control.clone();
+        let set_rate = ros
+            .advertise_service::<srv::SetRate, _>(format!("{namespace}/set_rate"), move |request| {
+                let success = check_rate(request.rate).is_ok();
+                if success {
+                    control.send_modify(|control| control.rate = request.rate);
+                }
+                Ok(srv::SetRateResponse { success })
+            })
+            .await?;
+        let control = self.control.clone();
+        let progress = self.progress.clone();
+        let seek = ros
+            .advertise_service::<srv::Seek, _>(format!("{namespace}/seek"), move |request| {
+                let time =
+                    UNIX_EPOCH + Duration::new(request.time.secs as u64, request.time.nsecs as u32);
+                let start = {
+                    let progress = progress.borrow();
+                    progress.time - progress.position
+                };
+                let success = time >= start;
+                if success {
+                    let position = time.duration_since(start).unwrap_or_default();
+                    control.send_modify(|control| {
+                        control.seeks += 1;
+                        control.seek_to = position;
+                    });
+                }
+                Ok(srv::SeekResponse { success })
+            })
+            .await?;
+
+        let clock = ros
+            .advertise::<srv::Clock>(format!("{namespace}/clock"))
+            .await?;
+        let mut progress = self.progress.clone();
+        let publishing = tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(Duration::from_millis(100));
+            ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticks.tick().await;
+                let time = progress.borrow_and_update().time;
+                let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+                let clock_msg = srv::Clock {
+                    clock: srv::Time {
+                        secs: since_epoch.as_secs() as i32,
+                        nsecs: since_epoch.subsec_nanos() as i32,
+                    },
+                };
+                if let Err(e) = clock.publish(&clock_msg).await {
+                    debug!("Failed to publish the playback clock: {e}");
+                }
+            }
+        });
+        Ok(PlayerControl {
+            _servers: vec![pause, resume, set_rate, seek],
+            _publishing: publishing.into(),
+        })
+    }
+}
+
+/// The services and clock publisher of [Player::advertise_control], removed when dropped.
+pub struct PlayerControl<R: ServiceProvider> {
+    _servers: Vec<R::ServiceServer>,
+    _publishing: ChildTask<()>,
+}
+
+fn check_rate(rate: f64) -> Result<()> {
+    if rate.is_finite() && rate > 0.0 {
+        Ok(())
+    } else {
+        Err(Error::IoError(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Playback rate must be a positive number, not {rate}"),
+        )))
+    }
+}
+
+/// Publishes `scheduled` as told by `control`, reporting on `progress`
+async fn play<P: RawPublish + Send + Sync + 'static>(
+    publishers: Vec<P>,
+    scheduled: Vec<Scheduled>,
+    start: SystemTime,
+    mut control: watch::Receiver<Control>,
+    progress: watch::Sender<Progress>,
+) {
+    let mut next = 0;
+    let mut position = Duration::ZERO;
+    let mut seeks = 0;
+    loop {
+        let current = *control.borrow_and_update();
+        if current.seeks != seeks {
+            seeks = current.seeks;
+            position = current.seek_to;
+            next = scheduled.partition_point(|message| message.offset < position);
+            debug!(
+                "Seeked to {position:?}, message {next} of {}",
+                scheduled.len()
+            );
+        }
+        let finished = next >= scheduled.len();
+        progress.send_modify(|progress| {
+            progress.time = start + position;
+            progress.position = position;
+            progress.paused = current.paused;
+            progress.rate = current.rate;
+            progress.finished = finished;
+        });
+        if current.paused || finished {
+            if control.changed().await.is_err() {
+                // The player was dropped
+                return;
+            }
+            continue;
+        }
+
+        // Playing from `position` now, until told otherwise
+        let resumed = Instant::now();
+        let from = position;
+        loop {
+            let message = &scheduled[next];
+            let due = resumed + (message.offset.saturating_sub(from)).div_f64(current.rate);
+            tokio::select! {
+                _ = tokio::time::sleep_until(due) => {
+                    if let Err(e) = publishers[message.publisher].publish_raw(&message.data).await {
+                        warn!("Failed to play back a message: {e}");
+                    }
+                    position = position.max(message.offset);
+                    next += 1;
+                    if next >= scheduled.len() {
+                        break;
+                    }
+                }
+                changed = control.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                    // Keep the time played so far, so pausing and changing the rate continue from here
+                    let played = from + resumed.elapsed().mul_f64(current.rate);
+                    position = played.min(message.offset).max(position);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// The services of [Player::advertise_control], typed like those of ROS2's `rosbag2_interfaces`, and the
+/// `rosgraph_msgs/Clock` message it publishes.
+pub mod srv {
+    use crate::{RosMessageType, RosServiceType};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Time {
+        pub secs: i32,
+        pub nsecs: i32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Clock {
+        pub clock: Time,
+    }
+
+    impl RosMessageType for Clock {
+        const ROS_TYPE_NAME: &'static str = "rosgraph_msgs/Clock";
+        const MD5SUM: &'static str = "a9c97c1d230cfc112e270351a944ee47";
+        const DEFINITION: &'static str = "time clock";
+    }
+
+    /// Declares an empty service
+    macro_rules! empty_service {
+        ($name:ident, $request:ident, $response:ident, $type_name:literal) => {
+            #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+            pub struct $request {}
+
+            impl RosMessageType for $request {
+                const ROS_TYPE_NAME: &'static str = concat!($type_name, "Request");
+                const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+                const DEFINITION: &'static str = "";
+            }
+
+            #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+            pub struct $response {}
+
+            impl RosMessageType for $response {
+                const ROS_TYPE_NAME: &'static str = concat!($type_name, "Response");
+                const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+                const DEFINITION: &'static str = "";
+            }
+
+            pub struct $name {}
+
+            impl RosServiceType for $name {
+                const ROS_SERVICE_NAME: &'static str = $type_name;
+                const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+                type Request = $request;
+                type Response = $response;
+            }
+        };
+    }
+
+    empty_service!(
+        Pause,
+        PauseRequest,
+        PauseResponse,
+        "rosbag2_interfaces/Pause"
+    );
+    empty_service!(
+        Resume,
+        ResumeRequest,
+        ResumeResponse,
+        "rosbag2_interfaces/Resume"
+    );
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct SetRateRequest {
+        pub rate: f64,
+    }
+
+    impl RosMessageType for SetRateRequest {
+        const ROS_TYPE_NAME: &'static str = "rosbag2_interfaces/SetRateRequest";
+        const MD5SUM: &'static str = "4910f3d55cbb29566b6c8f8f16528adf";
+        const DEFINITION: &'static str = "float64 rate";
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct SetRateResponse {
+        pub success: bool,
+    }
+
+    impl RosMessageType for SetRateResponse {
+        const ROS_TYPE_NAME: &'static str = "rosbag2_interfaces/SetRateResponse";
+        const MD5SUM: &'static str = "358e233cde0c8a8bcfea4ce193f8fc15";
+        const DEFINITION: &'static str = "bool success";
+    }
+
+    pub struct SetRate {}
+
+    impl RosServiceType for SetRate {
+        const ROS_SERVICE_NAME: &'static str = "rosbag2_interfaces/SetRate";
+        const MD5SUM: &'static str = "2dab78641a030de22e861fe0db4f5f1e";
+        type Request = SetRateRequest;
+        type Response = SetRateResponse;
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct SeekRequest {
+        pub time: Time,
+    }
+
+    impl RosMessageType for SeekRequest {
+        const ROS_TYPE_NAME: &'static str = "rosbag2_interfaces/SeekRequest";
+        const MD5SUM: &'static str = "556a4fb76023a469987922359d08a844";
+        const DEFINITION: &'static str = "time time";
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct SeekResponse {
+        pub success: bool,
+    }
+
+    impl RosMessageType for SeekResponse {
+        const ROS_TYPE_NAME: &'static str = "rosbag2_interfaces/SeekResponse";
+        const MD5SUM: &'static str = "358e233cde0c8a8bcfea4ce193f8fc15";
+        const DEFINITION: &'static str = "bool success";
+    }
+
+    pub struct Seek {}
+
+    impl RosServiceType for Seek {
+        const ROS_SERVICE_NAME: &'static str = "rosbag2_interfaces/Seek";
+        const MD5SUM: &'static str = "7eb2c01a6b8581963274fd4a659d6c9d";
+        type Request = SeekRequest;
+        type Response = SeekResponse;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mcap::McapWriter;
+    use crate::{GraphProvider, RawSubscribe, Subscribe};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+
+    /// A recording of `/count` with a message every second as the mock records it, and of a channel it can't play
+    async fn recording(count: u32) -> Vec<McapMessage> {
+        // Let the mock serialize the messages
+        let source = MockRos::new();
+        let publisher = source
+            .advertise::<std_msgs::UInt32>("/count")
+            .await
+            .unwrap();
+        let (_, mut subscriber) = source.subscribe_raw("/count").await.unwrap();
+
+        let mut writer = McapWriter::new(Vec::new(), "").unwrap();
+        let schema = writer.add_schema("std_msgs/UInt32", "", &[]).unwrap();
+        let channel = writer.add_channel(schema, "/count", "bincode").unwrap();
+        let other = writer.add_channel(0, "/other", "cdr").unwrap();
+        for i in 0..count {
+            publisher
+                .publish(&std_msgs::UInt32 { data: i })
+                .await
+                .unwrap();
+            let data = subscriber.next_raw().await.unwrap();
+            let time = UNIX_EPOCH + Duration::from_secs(1000 + i as u64);
+            writer.write_message(channel, time, time, &data).unwrap();
+            writer.write_message(other, time, time, &[]).unwrap();
+        }
+        read_messages(&writer.finish().unwrap()[..]).unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn plays_with_recorded_timing() {
+        let ros = MockRos::new();
+        let mut count = ros.subscribe::<std_msgs::UInt32>("/count").await.unwrap();
+        let options = PlayerOptions::new().rate(2.0);
+        let player = Player::new(&ros, recording(3).await, options)
+            .await
+            .unwrap();
+        assert_eq!(player.progress().duration, Duration::from_secs(2));
+
+        let started = Instant::now();
+        for i in 0..3 {
+            assert_eq!(count.next().await.unwrap().data, i);
+        }
+        // Twice as fast as recorded
+        assert_eq!(started.elapsed(), Duration::from_secs(1));
+        player.finished().await;
+        assert_eq!(player.progress().position, Duration::from_secs(2));
+        // The channel in another encoding was left out
+        let topics = ros.topics().await.unwrap();
+        assert!(topics.iter().all(|topic| topic.name != "/other"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pauses_and_seeks() {
+        let ros = MockRos::new();
+        let mut count = ros.subscribe::<std_msgs::UInt32>("/count").await.unwrap();
+        let options = PlayerOptions::new().paused(true);
+        let player = Player::new(&ros, recording(10).await, options)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(player.progress().position, Duration::ZERO);
+
+        player.seek(Duration::from_millis(7500));
+        player.resume();
+        assert_eq!(count.next().await.unwrap().data, 8);
+        assert_eq!(count.next().await.unwrap().data, 9);
+        player.finished().await;
+        assert!(!player.progress().paused);
+        assert!(player.set_rate(0.0).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn controlled_over_services() {
+        let ros = MockRos::new();
+        let options = PlayerOptions::new().paused(true);
+        let player = Player::new(&ros, recording(3).await, options)
+            .await
+            .unwrap();
+        let _control = player.advertise_control(&ros, "/player/").await.unwrap();
+
+        let response = ros
+            .call_service::<srv::SetRate>("/player/set_rate", srv::SetRateRequest { rate: -1.0 })
+            .await
+            .unwrap();
+        assert!(!response.success);
+        ros.call_service::<srv::SetRate>("/player/set_rate", srv::SetRateRequest { rate: 4.0 })
+            .await
+            .unwrap();
+        let seek = srv::SeekRequest {
+            time: srv::Time {
+                secs: 1002,
+                nsecs: 0,
+            },
+        };
+        let response = ros
+            .call_service::<srv::Seek>("/player/seek", seek)
+            .await
+            .unwrap();
+        assert!(response.success);
+        ros.call_service::<srv::Resume>("/player/resume", srv::ResumeRequest {})
+            .await
+            .unwrap();
+        player.finished().await;
+        let progress = player.progress();
+        assert_eq!(progress.rate, 4.0);
+        assert_eq!(progress.time, UNIX_EPOCH + Duration::from_secs(1002));
+    }
+}
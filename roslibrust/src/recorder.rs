@@ -0,0 +1,711 @@
+//! Records topics of any type to MCAP files, see [Recorder].
+//!
+//! ```no_run
+//! use roslibrust::mcap::Compression;
+//! use roslibrust::recorder::{Recorder, RecorderConfig, Regex};
+//! use roslibrust::{GraphProvider, RawTopicProvider};
+//! use std::time::Duration;
+//!
+//! async fn run<R>(ros: R) -> roslibrust::Result<()>
+//! where
+//!     R: RawTopicProvider + GraphProvider + Clone + Send + Sync + 'static,
+//! {
+//!     // Everything under /camera except the uncompressed images, in 10 minute files of at most 1 GB
+//!     let config = RecorderConfig::new("/data/camera")
+//!         .include(Regex::new("/camera/.*").unwrap())
+//!         .exclude(Regex::new(".*/image_raw").unwrap())
+//!         .max_size(1_000_000_000)
+//!         .split_interval(Duration::from_secs(600))
+//!         .compression(Compression::Lz4);
+//!     let recorder = Recorder::start(&ros, config).await?;
+//!     tokio::time::sleep(Duration::from_secs(3600)).await;
+//!     for file in recorder.stop().await? {
+//!         log::info!("Recorded {}", file.display());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    mcap::{Compression, McapWriter},
+    schema::MessageSchema,
+    GraphProvider, RawSubscribe, RawTopicProvider, Result, ServiceProvider, ToServiceName,
+};
+use abort_on_drop::ChildTask;
+use log::*;
+pub use regex::Regex;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fs::File,
+    io::{self, BufWriter},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant, SystemTime},
+};
+
+/// The size of the chunks compressed messages are grouped in, before compression
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Configures which topics a [Recorder] records and the files it writes.
+#[derive(Clone, Debug)]
+pub struct RecorderConfig {
+    prefix: PathBuf,
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+    max_size: Option<u64>,
+    split_interval: Option<Duration>,
+    compression: Option<Compression>,
+    discovery_interval: Duration,
+    snapshot: Option<Duration>,
+}
+
+impl RecorderConfig {
+    /// Records every topic into a single uncompressed file, named `prefix` followed by `_0.mcap`.
+    ///
+    /// Splitting the recording continues with `_1.mcap` and so on, like `rosbag record --split`.
+    pub fn new(prefix: impl Into<PathBuf>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            include: vec![],
+            exclude: vec![],
+            max_size: None,
+            split_interval: None,
+            compression: None,
+            discovery_interval: Duration::from_secs(1),
+            snapshot: None,
+        }
+    }
+
+    /// Records only the topics whose whole name matches `pattern`, or any other included pattern.
+    pub fn include(mut self, pattern: Regex) -> Self {
+        self.include.push(pattern);
+        self
+    }
+
+    /// Leaves out the topics whose whole name matches `pattern`, even if they are included.
+    pub fn exclude(mut self, pattern: Regex) -> Self {
+        self.exclude.push(pattern);
+        self
+    }
+
+    /// Starts the next file once a file reaches `bytes`.
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Starts the next file once a file was written to for `interval`.
+    pub fn split_interval(mut self, interval: Duration) -> Self {
+        self.split_interval = Some(interval);
+        self
+    }
+
+    /// Compresses messages in chunks of about a megabyte with `compression`.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Looks for new topics to record every `interval` instead of every second.
+    pub fn discovery_interval(mut self, interval: Duration) -> Self {
+        self.discovery_interval = interval;
+        self
+    }
+
+    /// Keeps the messages received within the last `window` in memory instead of writing them, until
+    /// [Recorder::snapshot] writes them to a file of their own, e.g. to capture what led up to an incident.
+    ///
+    /// Snapshots aren't split by [RecorderConfig::max_size] or [RecorderConfig::split_interval].
+    pub fn snapshot(mut self, window: Duration) -> Self {
+        self.snapshot = Some(window);
+        self
+    }
+
+    /// True if `topic` is included and not excluded.
+    pub fn selects(&self, topic: &str) -> bool {
+        let full_match = |pattern: &Regex| {
+            pattern
+                .find(topic)
+                .is_some_and(|m| m.start() == 0 && m.end() == topic.len())
+        };
+        (self.include.is_empty() || self.include.iter().any(full_match))
+            && !self.exclude.iter().any(full_match)
+    }
+}
+
+/// A message kept in memory for the next snapshot
+struct Buffered {
+    topic: Arc<str>,
+    schema: Arc<MessageSchema>,
+    received: Instant,
+    time: SystemTime,
+    data: Vec<u8>,
+}
+
+/// The file currently being written and the files written before it
+struct Output {
+    config: RecorderConfig,
+    encoding: &'static str,
+    writer: Option<McapWriter<BufWriter<File>>>,
+    opened: Instant,
+    // The channel id of each topic in the current file
+    channels: HashMap<Arc<str>, u16>,
+    files: Vec<PathBuf>,
+    // The messages received within the snapshot window, oldest first
+    buffer: VecDeque<Buffered>,
+    // Set once the recorder stopped, from when messages still being received are ignored
+    stopped: bool,
+}
+
+impl Output {
+    fn open(&mut self) -> io::Result<()> {
+        let path = self.config.prefix.with_file_name(format!(
+            "{}_{}.mcap",
+            self.config
+                .prefix
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy(),
+            self.files.len()
+        ));
+        let profile = match self.encoding {
+            "ros1" => "ros1",
+            "cdr" => "ros2",
+            _ => "",
+        };
+        let mut writer = McapWriter::new(BufWriter::new(File::create(&path)?), profile)?;
+        if let Some(compression) = self.config.compression {
+            writer = writer.with_chunks(compression, CHUNK_SIZE);
+        }
+        info!("Recording to {}", path.display());
+        self.writer = Some(writer);
+        self.opened = Instant::now();
+        self.channels.clear();
+        self.files.push(path);
+        Ok(())
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        match self.writer.take() {
+            Some(writer) => writer.finish().map(drop),
+            None => Ok(()),
+        }
+    }
+
+    fn write(
+        &mut self,
+        topic: &Arc<str>,
+        schema: &Arc<MessageSchema>,
+        data: Vec<u8>,
+    ) -> io::Result<()> {
+        if self.stopped {
+            return Ok(());
+        }
+        let time = SystemTime::now();
+        if let Some(window) = self.config.snapshot {
+            let received = Instant::now();
+            while self
+                .buffer
+                .front()
+                .is_some_and(|oldest| received.duration_since(oldest.received) > window)
+            {
+                self.buffer.pop_front();
+            }
+            self.buffer.push_back(Buffered {
+                topic: topic.clone(),
+                schema: schema.clone(),
+                received,
+                time,
+                data,
+            });
+            return Ok(());
+        }
+
+        let full = self.writer.as_ref().is_some_and(|writer| {
+            self.config
+                .max_size
+                .is_some_and(|max_size| writer.size() >= max_size)
+                || self
+                    .config
+                    .split_interval
+                    .is_some_and(|interval| self.opened.elapsed() >= interval)
+        });
+        if full {
+            self.close()?;
+        }
+        if self.writer.is_none() {
+            self.open()?;
+        }
+        self.append(topic, schema, time, &data)
+    }
+
+    /// Writes the buffered messages to a file of their own, returning its path
+    fn snapshot(&mut self) -> io::Result<PathBuf> {
+        if self.config.snapshot.is_none() {
+            return Err(io::Error::other(
+                "The recorder isn't configured for snapshots",
+            ));
+        }
+        self.open()?;
+        let buffer = std::mem::take(&mut self.buffer);
+        let result = buffer.iter().try_for_each(|message| {
+            self.append(&message.topic, &message.schema, message.time, &message.data)
+        });
+        // Later snapshots overlap with this one, like the snapshots of `rosbag snapshot`
+        self.buffer = buffer;
+        result?;
+        self.close()?;
+        Ok(self.files.last().expect("Opened above").clone())
+    }
+
+    /// Writes a message to the open file, adding the channel of its topic if it is the first
+    fn append(
+        &mut self,
+        topic: &Arc<str>,
+        schema: &MessageSchema,
+        time: SystemTime,
+        data: &[u8],
+    ) -> io::Result<()> {
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| io::Error::other("No recording file is open"))?;
+        let channel_id = match self.channels.get(topic) {
+            Some(id) => *id,
+            None => {
+                // Each file has the schemas and channels of its own topics, so it can be read on its own
+                let schema_encoding = match self.encoding {
+                    "ros1" => "ros1msg",
+                    "cdr" => "ros2msg",
+                    _ => "",
+                };
+                // Without a known schema encoding only the name of the type is kept, for playing it back
+                let definition = match schema_encoding {
+                    "" => &[][..],
+                    _ => schema.definition.as_bytes(),
+                };
+                let schema_id =
+                    writer.add_schema(&schema.type_name, schema_encoding, definition)?;
+                let id = writer.add_channel(schema_id, topic, self.encoding)?;
+                self.channels.insert(topic.clone(), id);
+                id
+            }
+        };
+        writer.write_message(channel_id, time, time, data)
+    }
+}
+
+struct Shared {
+    output: Mutex<Output>,
+    paused: AtomicBool,
+    messages: AtomicU64,
+    // The subscription of each recorded topic
+    subscriptions: Mutex<BTreeMap<String, ChildTask<()>>>,
+}
+
+impl Shared {
+    fn output(&self) -> MutexGuard<'_, Output> {
+        self.output.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn subscriptions(&self) -> MutexGuard<'_, BTreeMap<String, ChildTask<()>>> {
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Subscribes to the selected topics which aren't recorded yet
+    async fn discover<R: RawTopicProvider + GraphProvider>(
+        self: &Arc<Self>,
+        ros: &R,
+        config: &RecorderConfig,
+    ) -> Result<()> {
+        for topic in ros.topics().await? {
+            if !config.selects(&topic.name) || self.subscriptions().contains_key(&topic.name) {
+                continue;
+            }
+            let (schema, mut subscriber) = match ros.subscribe_raw(&topic.name).await {
+                Ok(subscription) => subscription,
+                Err(e) => {
+                    // Typically nothing publishes it yet, it is retried on the next discovery
+                    debug!("Not recording {} yet: {e}", topic.name);
+                    continue;
+                }
+            };
+            info!("Recording {} of type {}", topic.name, schema.type_name);
+            let task = tokio::spawn({
+                let shared = self.clone();
+                let topic: Arc<str> = topic.name.clone().into();
+                let schema = Arc::new(schema);
+                async move {
+                    loop {
+                        let data = match subscriber.next_raw().await {
+                            Ok(data) => data,
+                            Err(e) => {
+                                warn!("Stopped recording {topic}: {e}");
+                                break;
+                            }
+                        };
+                        if shared.paused.load(Ordering::Relaxed) {
+                            continue;
+                        }
+                        match shared.output().write(&topic, &schema, data) {
+                            Ok(()) => {
+                                shared.messages.fetch_add(1, Ordering::Relaxed);
+                            }
+                            Err(e) => error!("Failed to record a message of {topic}: {e}"),
+                        }
+                    }
+                }
+            });
+            self.subscriptions().insert(topic.name, task.into());
+        }
+        Ok(())
+    }
+}
+
+/// Records the topics selected by a [RecorderConfig] to MCAP files, on any backend providing subscriptions to
+/// topics of any type through [RawTopicProvider].
+///
+/// Topics are looked for when recording starts and at the [RecorderConfig::discovery_interval] after, so topics
+/// appearing later are recorded too. Messages are stored as the backend received them, e.g. in the ROS1 wire
+/// format, along with the definition of their type where the backend knows it.
+///
+/// With [RecorderConfig::snapshot] nothing is written until a snapshot of the last few seconds is requested, through
+/// [Recorder::snapshot] or a service call, see [Recorder::advertise_snapshot].
+///
+/// Dropping the recorder stops recording and finishes the current file, [Recorder::stop] does the same but also
+/// reports whether finishing it succeeded.
+pub struct Recorder {
+    shared: Arc<Shared>,
+    // None once stopped
+    discovery: Option<ChildTask<()>>,
+}
+
+impl Recorder {
+    /// Starts recording the topics of `ros` selected by `config`.
+    ///
+    /// Fails if the graph of `ros` can't be listed, the first file is only created once a message is received.
+    pub async fn start<R>(ros: &R, config: RecorderConfig) -> Result<Self>
+    where
+        R: RawTopicProvider + GraphProvider + Clone + Send + Sync + 'static,
+    {
+        let shared = Arc::new(Shared {
+            output: Mutex::new(Output {
+                config: config.clone(),
+                encoding: R::ENCODING,
+                writer: None,
+                opened: Instant::now(),
+                channels: HashMap::new(),
+                files: vec![],
+                buffer: VecDeque::new(),
+                stopped: false,
+            }),
+            paused: AtomicBool::new(false),
+            messages: AtomicU64::new(0),
+            subscriptions: Mutex::new(BTreeMap::new()),
+        });
+        shared.discover(ros, &config).await?;
+        let discovery = tokio::spawn({
+            let shared = shared.clone();
+            let ros = ros.clone();
+            async move {
+                let mut ticks = tokio::time::interval(config.discovery_interval);
+                ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                // The first tick completes immediately, right after the discovery in start
+                ticks.tick().await;
+                loop {
+                    ticks.tick().await;
+                    if let Err(e) = shared.discover(&ros, &config).await {
+                        warn!("Failed to look for new topics to record: {e}");
+                    }
+                }
+            }
+        });
+        Ok(Self {
+            shared,
+            discovery: Some(discovery.into()),
+        })
+    }
+
+    /// Stops writing received messages to the file until [Recorder::resume] is called. Topics are still
+    /// discovered and subscribed to while paused.
+    pub fn pause(&self) {
+        self.shared.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Continues writing received messages after [Recorder::pause].
+    pub fn resume(&self) {
+        self.shared.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// True while paused.
+    pub fn is_paused(&self) -> bool {
+        self.shared.paused.load(Ordering::Relaxed)
+    }
+
+    /// The topics being recorded, sorted by name.
+    pub fn topics(&self) -> Vec<String> {
+        self.shared.subscriptions().keys().cloned().collect()
+    }
+
+    /// The number of messages recorded so far.
+    pub fn messages(&self) -> u64 {
+        self.shared.messages.load(Ordering::Relaxed)
+    }
+
+    /// The files written so far, the last one still being written.
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.shared.output().files.clone()
+    }
+
+    /// Writes the messages of the snapshot window to the next file, returning its path.
+    ///
+    /// Fails if the recorder wasn't configured with [RecorderConfig::snapshot].
+    pub fn snapshot(&self) -> Result<PathBuf> {
+        let path = self.shared.output().snapshot()?;
+        info!("Wrote a snapshot to {}", path.display());
+        Ok(path)
+    }
+
+    /// Serves `service` with the `std_srvs/Trigger` type, taking a [Recorder::snapshot] for every call and
+    /// responding with the path of the file written, or the error if it failed.
+    ///
+    /// The service keeps working after the recorder is stopped, with the messages buffered until then.
+    pub async fn advertise_snapshot<R: ServiceProvider>(
+        &self,
+        ros: &R,
+        service: impl ToServiceName + Send,
+    ) -> Result<R::ServiceServer> {
+        let shared = Arc::downgrade(&self.shared);
+        ros.advertise_service::<std_srvs::Trigger, _>(service, move |_| {
+            let Some(shared) = shared.upgrade() else {
+                return Ok(std_srvs::TriggerResponse {
+                    success: false,
+                    message: "The recorder was dropped".to_string(),
+                });
+            };
+            let result = shared.output().snapshot();
+            Ok(match result {
+                Ok(path) => {
+                    info!("Wrote a snapshot to {}", path.display());
+                    std_srvs::TriggerResponse {
+                        success: true,
+                        message: path.display().to_string(),
+                    }
+                }
+                Err(e) => {
+                    warn!("Failed to write a snapshot: {e}");
+                    std_srvs::TriggerResponse {
+                        success: false,
+                        message: e.to_string(),
+                    }
+                }
+            })
+        })
+        .await
+    }
+
+    /// Stops recording and finishes the current file, returning every file written.
+    pub async fn stop(mut self) -> Result<Vec<PathBuf>> {
+        Ok(self.finish()?)
+    }
+
+    fn finish(&mut self) -> io::Result<Vec<PathBuf>> {
+        // The subscriptions hold on to the shared state, so they are dropped explicitly rather than with it
+        self.discovery = None;
+        std::mem::take(&mut *self.shared.subscriptions());
+        let mut output = self.shared.output();
+        output.stopped = true;
+        output.close()?;
+        Ok(output.files.clone())
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        // Like BufWriter, errors can't be reported when dropped, stop reports them
+        let _ = self.finish();
+    }
+}
+
+/// The ROS1 service triggering snapshots, `std_srvs/Trigger`, see [Recorder::advertise_snapshot].
+pub mod std_srvs {
+    use crate::{RosMessageType, RosServiceType};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct TriggerRequest {}
+
+    impl RosMessageType for TriggerRequest {
+        const ROS_TYPE_NAME: &'static str = "std_srvs/TriggerRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "";
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct TriggerResponse {
+        pub success: bool,
+        pub message: String,
+    }
+
+    impl RosMessageType for TriggerResponse {
+        const ROS_TYPE_NAME: &'static str = "std_srvs/TriggerResponse";
+        const MD5SUM: &'static str = "937c9679a518e3a18d831e57125ea522";
+        const DEFINITION: &'static str = r####"bool success   # indicate successful run of triggered service
+string message # informational, e.g. for error messages"####;
+    }
+
+    pub struct Trigger {}
+
+    impl RosServiceType for Trigger {
+        const ROS_SERVICE_NAME: &'static str = "std_srvs/Trigger";
+        const MD5SUM: &'static str = "937c9679a518e3a18d831e57125ea522";
+        type Request = TriggerRequest;
+        type Response = TriggerResponse;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Publish, TopicProvider};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+
+    /// A directory of its own for each test
+    fn output_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("roslibrust_recorder_{name}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    async fn wait_for_messages(recorder: &Recorder, count: u64) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while recorder.messages() < count {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("Messages weren't recorded");
+    }
+
+    #[test_log::test]
+    fn selects_topics() {
+        let config = RecorderConfig::new("recording")
+            .include(Regex::new("/camera/.*").unwrap())
+            .exclude(Regex::new(".*/image_raw").unwrap());
+        assert!(config.selects("/camera/info"));
+        assert!(!config.selects("/camera/image_raw"));
+        // Patterns must match the whole name
+        assert!(!config.selects("/robot/camera/info"));
+        assert!(RecorderConfig::new("recording").selects("/anything"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn records_selected_topics_until_stopped() {
+        let ros = MockRos::new();
+        let chatter = ros.advertise::<std_msgs::String>("/chatter").await.unwrap();
+        let _secret = ros.advertise::<std_msgs::String>("/secret").await.unwrap();
+        let prefix = output_dir("selected").join("recording");
+        let config = RecorderConfig::new(&prefix).exclude(Regex::new("/secret").unwrap());
+        let recorder = Recorder::start(&ros, config).await.unwrap();
+        assert_eq!(recorder.topics(), ["/chatter"]);
+
+        let msg = std_msgs::String {
+            data: "hello".to_string(),
+        };
+        chatter.publish(&msg).await.unwrap();
+        chatter.publish(&msg).await.unwrap();
+        wait_for_messages(&recorder, 2).await;
+
+        recorder.pause();
+        chatter.publish(&msg).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(recorder.messages(), 2);
+        recorder.resume();
+        chatter.publish(&msg).await.unwrap();
+        wait_for_messages(&recorder, 3).await;
+
+        let files = recorder.stop().await.unwrap();
+        assert_eq!(files, [prefix.with_file_name("recording_0.mcap")]);
+        let data = std::fs::read(&files[0]).unwrap();
+        assert!(data.starts_with(b"\x89MCAP0\r\n"));
+        assert!(data.ends_with(b"\x89MCAP0\r\n"));
+        let contains = |needle: &[u8]| data.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"/chatter"));
+        assert!(!contains(b"/secret"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn splits_files_by_size() {
+        let ros = MockRos::new();
+        let chatter = ros.advertise::<std_msgs::String>("/chatter").await.unwrap();
+        let prefix = output_dir("split").join("recording");
+        let recorder = Recorder::start(&ros, RecorderConfig::new(&prefix).max_size(1))
+            .await
+            .unwrap();
+        for _ in 0..3 {
+            chatter
+                .publish(&std_msgs::String {
+                    data: "hello".to_string(),
+                })
+                .await
+                .unwrap();
+        }
+        wait_for_messages(&recorder, 3).await;
+        // Every file is full after its first message
+        assert_eq!(recorder.stop().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn snapshots_the_window_on_request() {
+        let ros = MockRos::new();
+        let chatter = ros.advertise::<std_msgs::String>("/chatter").await.unwrap();
+        let prefix = output_dir("snapshot").join("incident");
+        let config = RecorderConfig::new(&prefix).snapshot(Duration::from_millis(200));
+        let recorder = Recorder::start(&ros, config).await.unwrap();
+        let _server = recorder
+            .advertise_snapshot(&ros, "/snapshot")
+            .await
+            .unwrap();
+
+        let publish = |data: &str| {
+            let msg = std_msgs::String {
+                data: data.to_string(),
+            };
+            let chatter = &chatter;
+            async move { chatter.publish(&msg).await }
+        };
+        publish("old").await.unwrap();
+        wait_for_messages(&recorder, 1).await;
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        publish("recent").await.unwrap();
+        wait_for_messages(&recorder, 2).await;
+        // Nothing is written until a snapshot is taken
+        assert!(recorder.files().is_empty());
+
+        let response = ros
+            .call_service::<std_srvs::Trigger>("/snapshot", std_srvs::TriggerRequest {})
+            .await
+            .unwrap();
+        assert!(response.success, "{}", response.message);
+        let path = prefix.with_file_name("incident_0.mcap");
+        assert_eq!(response.message, path.display().to_string());
+        let data = std::fs::read(&path).unwrap();
+        let contains = |needle: &[u8]| data.windows(needle.len()).any(|w| w == needle);
+        // The old message fell out of the window before the recent one was received
+        assert!(contains(b"recent"));
+        assert!(!contains(b"old"));
+
+        // Snapshots keep the buffer, so the next one has the same messages
+        assert_eq!(
+            recorder.snapshot().unwrap(),
+            prefix.with_file_name("incident_1.mcap")
+        );
+    }
+}
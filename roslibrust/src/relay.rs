@@ -0,0 +1,264 @@
+//! Republishes a topic, covering what topic_tools' `relay`, `throttle` and `drop` are used for, see [Relay].
+//!
+//! ```no_run
+//! use roslibrust::relay::Relay;
+//! use roslibrust::Ros;
+//! use roslibrust_test::ros1::sensor_msgs;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     // A low rate copy of the camera images for a remote viewer
+//!     let _relay = Relay::<sensor_msgs::Image>::new("/camera/image_raw")
+//!         .to("/camera/image_preview")
+//!         .throttle(2.0)
+//!         .start(&ros)
+//!         .await?;
+//!     // Relays until the handle is dropped
+//!     let _ = tokio::signal::ctrl_c().await;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Publish, Result, RosMessageType, Subscribe, TopicProvider};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::time::Instant;
+
+type Transform<A, B> = Box<dyn FnMut(A) -> Option<B> + Send + 'static>;
+
+/// Forwards messages from one topic to another, optionally renaming, rate limiting and transforming them.
+///
+/// The topics can be on the same [TopicProvider] ([Relay::start]) or on two different ones ([Relay::start_between]),
+/// e.g. to expose a topic of a ROS1 node over rosbridge. Messages pass through [Relay::decimate], then
+/// [Relay::throttle], then the transforms, in that order, regardless of the order they were configured in.
+pub struct Relay<A, B = A> {
+    input: String,
+    output: Option<String>,
+    min_period: Option<Duration>,
+    keep_every: usize,
+    transform: Transform<A, B>,
+}
+
+impl<A: RosMessageType> Relay<A> {
+    /// Relays `topic`, by default onto a topic of the same name.
+    pub fn new(topic: impl Into<String>) -> Self {
+        Self {
+            input: topic.into(),
+            output: None,
+            min_period: None,
+            keep_every: 1,
+            transform: Box::new(Some),
+        }
+    }
+}
+
+impl<A: RosMessageType, B: RosMessageType> Relay<A, B> {
+    /// Publishes the relayed messages on `topic` instead.
+    pub fn to(mut self, topic: impl Into<String>) -> Self {
+        self.output = Some(topic.into());
+        self
+    }
+
+    /// Forwards at most `hz` messages per second, dropping those arriving sooner than `1 / hz` after the last one
+    /// forwarded, like topic_tools' `throttle messages`.
+    pub fn throttle(mut self, hz: f64) -> Self {
+        self.min_period = (hz > 0.0).then(|| Duration::from_secs_f64(1.0 / hz));
+        self
+    }
+
+    /// Only forwards every `n`th message, like topic_tools' `drop` with `X = n - 1` and `Y = n`.
+    /// `n` of 0 is treated as 1.
+    pub fn decimate(mut self, n: usize) -> Self {
+        self.keep_every = n.max(1);
+        self
+    }
+
+    /// Converts each message with `f` before it is published, dropping it if `f` returns None.
+    /// Applied after any transform configured earlier.
+    pub fn transform<C: RosMessageType>(
+        self,
+        mut f: impl FnMut(B) -> Option<C> + Send + 'static,
+    ) -> Relay<A, C> {
+        let mut previous = self.transform;
+        Relay {
+            input: self.input,
+            output: self.output,
+            min_period: self.min_period,
+            keep_every: self.keep_every,
+            transform: Box::new(move |msg| previous(msg).and_then(&mut f)),
+        }
+    }
+
+    /// Starts relaying within `ros`, which requires the topic to have been renamed with [Relay::to].
+    pub async fn start(self, ros: &impl TopicProvider) -> Result<RelayHandle> {
+        if self.output.as_deref().unwrap_or(&self.input) == self.input {
+            return Err(Error::InvalidName(format!(
+                "Can't relay {} onto itself, use Relay::to to rename it",
+                self.input
+            )));
+        }
+        self.start_between(ros, ros).await
+    }
+
+    /// Starts relaying from the topic on `from` to the topic on `to`.
+    pub async fn start_between(
+        self,
+        from: &impl TopicProvider,
+        to: &impl TopicProvider,
+    ) -> Result<RelayHandle> {
+        let output = self.output.as_deref().unwrap_or(&self.input);
+        let mut subscriber = from.subscribe::<A>(self.input.as_str()).await?;
+        let publisher = to.advertise::<B>(output).await?;
+        debug!("Relaying {} to {output}", self.input);
+
+        let forwarded = Arc::new(AtomicU64::new(0));
+        let Relay {
+            input: topic,
+            min_period,
+            keep_every,
+            mut transform,
+            ..
+        } = self;
+        let task = tokio::spawn({
+            let forwarded = forwarded.clone();
+            async move {
+                // Messages still to skip before the next one is considered
+                let mut skipped = 0usize;
+                let mut last_forwarded: Option<Instant> = None;
+                loop {
+                    let msg = match subscriber.next().await {
+                        Ok(msg) => msg,
+                        Err(Error::Disconnected) => {
+                            warn!("Subscriber for relayed topic {topic} disconnected, no longer relaying it");
+                            return;
+                        }
+                        Err(e) => {
+                            warn!("Failed to receive message on relayed topic {topic}: {e}");
+                            continue;
+                        }
+                    };
+                    if skipped > 0 {
+                        skipped -= 1;
+                        continue;
+                    }
+                    skipped = keep_every - 1;
+                    let now = Instant::now();
+                    if let (Some(min_period), Some(last)) = (min_period, last_forwarded) {
+                        if now.duration_since(last) < min_period {
+                            continue;
+                        }
+                    }
+                    let Some(msg) = transform(msg) else {
+                        continue;
+                    };
+                    last_forwarded = Some(now);
+                    match publisher.publish(&msg).await {
+                        Ok(()) => {
+                            forwarded.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Err(e) => warn!("Failed to publish message on relayed topic {topic}: {e}"),
+                    }
+                }
+            }
+        });
+        Ok(RelayHandle {
+            forwarded,
+            _task: task.into(),
+        })
+    }
+}
+
+/// Relays `topic` from `from` to the topic of the same name on `to`, see [Relay] for more options.
+pub async fn relay<T: RosMessageType>(
+    from: &impl TopicProvider,
+    to: &impl TopicProvider,
+    topic: impl Into<String>,
+) -> Result<RelayHandle> {
+    Relay::<T>::new(topic).start_between(from, to).await
+}
+
+/// A running [Relay], messages stop being relayed when it is dropped.
+pub struct RelayHandle {
+    forwarded: Arc<AtomicU64>,
+    _task: ChildTask<()>,
+}
+
+impl RelayHandle {
+    /// The number of messages published so far.
+    pub fn forwarded(&self) -> u64 {
+        self.forwarded.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn relays_with_rename_decimation_and_transform() {
+        let ros = MockRos::new();
+        let relay = Relay::<std_msgs::UInt32>::new("/numbers")
+            .to("/odd_squares")
+            .decimate(2)
+            .transform(|msg| Some(std_msgs::UInt32 { data: msg.data + 1 }))
+            .transform(|msg| {
+                Some(std_msgs::UInt32 {
+                    data: msg.data * msg.data,
+                })
+            });
+        let relay = relay.start(&ros).await.unwrap();
+        let mut subscriber = ros
+            .subscribe::<std_msgs::UInt32>("/odd_squares")
+            .await
+            .unwrap();
+        let publisher = ros.advertise::<std_msgs::UInt32>("/numbers").await.unwrap();
+        for data in 0..6 {
+            publisher.publish(&std_msgs::UInt32 { data }).await.unwrap();
+        }
+        // 0, 2 and 4 are kept, incremented and squared
+        for expected in [1, 9, 25] {
+            assert_eq!(subscriber.next().await.unwrap().data, expected);
+        }
+        assert_eq!(relay.forwarded(), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn throttles_between_providers() {
+        let (from, to) = (MockRos::new(), MockRos::new());
+        let _relay = Relay::<std_msgs::UInt32>::new("/numbers")
+            .throttle(5.0)
+            .start_between(&from, &to)
+            .await
+            .unwrap();
+        let mut subscriber = to.subscribe::<std_msgs::UInt32>("/numbers").await.unwrap();
+        let publisher = from
+            .advertise::<std_msgs::UInt32>("/numbers")
+            .await
+            .unwrap();
+        // A burst is reduced to its first message
+        for data in 0..5 {
+            publisher.publish(&std_msgs::UInt32 { data }).await.unwrap();
+        }
+        assert_eq!(subscriber.next().await.unwrap().data, 0);
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        publisher
+            .publish(&std_msgs::UInt32 { data: 5 })
+            .await
+            .unwrap();
+        assert_eq!(subscriber.next().await.unwrap().data, 5);
+
+        // Relaying a topic onto itself would loop forever
+        assert!(Relay::<std_msgs::UInt32>::new("/numbers")
+            .start(&from)
+            .await
+            .is_err());
+    }
+}
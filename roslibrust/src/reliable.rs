@@ -0,0 +1,354 @@
+//! A publisher riding out losses of connection to the backend, see [ReliablePublisher].
+//!
+//! ```no_run
+//! use roslibrust::reliable::{ReliableOptions, ReliablePublisher};
+//! use roslibrust::{Publish, Ros};
+//! use roslibrust_test::ros1::sensor_msgs;
+//!
+//! async fn report(ros: impl Ros, fix: sensor_msgs::NavSatFix) -> roslibrust::Result<()> {
+//!     // Keeps the last 100 fixes while the link to the base station is down
+//!     let options = ReliableOptions::new().buffer(100);
+//!     let publisher = ReliablePublisher::advertise(&ros, "/gps/fix", options).await?;
+//!     publisher.publish(&fix).await
+//! }
+//! ```
+
+use crate::{Publish, Result, Ros, RosMessageType, ToTopicName, TopicName, TopicProvider};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::Mutex;
+
+/// Configures a [ReliablePublisher].
+#[derive(Clone, Debug)]
+pub struct ReliableOptions {
+    buffer: usize,
+    retry_interval: Duration,
+}
+
+impl Default for ReliableOptions {
+    fn default() -> Self {
+        Self {
+            buffer: 0,
+            retry_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl ReliableOptions {
+    /// Buffers nothing and retries advertising every second.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps up to `messages` which couldn't be published, publishing them in order once the backend is reachable
+    /// again. When the buffer is full the oldest message is dropped, counted in [ReliablePublisher::dropped].
+    pub fn buffer(mut self, messages: usize) -> Self {
+        self.buffer = messages;
+        self
+    }
+
+    /// Retries advertising and flushing buffered messages every `interval` while any are buffered, instead of
+    /// every second.
+    pub fn retry_interval(mut self, interval: Duration) -> Self {
+        self.retry_interval = interval;
+        self
+    }
+}
+
+struct State<T, P> {
+    publisher: Option<P>,
+    pending: VecDeque<T>,
+}
+
+struct Shared<T: RosMessageType, R: TopicProvider> {
+    ros: R,
+    topic: TopicName,
+    state: Mutex<State<T, R::Publisher<T>>>,
+    dropped: AtomicU64,
+}
+
+impl<T: RosMessageType, R: Ros> Shared<T, R> {
+    /// Publishes the buffered messages in order, advertising first if the publisher was lost
+    async fn flush(&self, state: &mut State<T, R::Publisher<T>>) -> Result<()> {
+        while let Some(msg) = state.pending.front() {
+            let publisher = match &state.publisher {
+                Some(publisher) => publisher,
+                None => {
+                    let publisher = self.ros.advertise::<T>(&self.topic).await?;
+                    info!("Advertised {} again", self.topic);
+                    &*state.publisher.insert(publisher)
+                }
+            };
+            if let Err(e) = publisher.publish(msg).await {
+                // Advertise again on the next attempt, the backend may have forgotten the publisher
+                state.publisher = None;
+                return Err(e);
+            }
+            state.pending.pop_front();
+        }
+        Ok(())
+    }
+}
+
+/// Wraps the publisher of any backend, advertising again after publishing fails, e.g. while a rosbridge or zenoh
+/// connection is down, and optionally buffering the messages published in the meantime.
+///
+/// Publishing only fails when a message can't be published and there is no buffer to keep it in, with
+/// [ReliableOptions::buffer] messages are kept instead and publishing succeeds. Buffered messages are published
+/// before any newer one, on the next publish or on the next retry in the background, whichever comes first.
+pub struct ReliablePublisher<T: RosMessageType, R: Ros> {
+    shared: Arc<Shared<T, R>>,
+    buffer: usize,
+    _retry: ChildTask<()>,
+}
+
+impl<T: RosMessageType, R: Ros> ReliablePublisher<T, R> {
+    /// Advertises `topic` on `ros`. Failing to advertise isn't an error, it is retried like a failed publish,
+    /// only an invalid topic name is.
+    pub async fn advertise(
+        ros: &R,
+        topic: impl ToTopicName,
+        options: ReliableOptions,
+    ) -> Result<Self> {
+        let topic = topic.to_topic_name()?;
+        let publisher = match ros.advertise::<T>(&topic).await {
+            Ok(publisher) => Some(publisher),
+            Err(e) => {
+                warn!("Failed to advertise {topic}, retrying in the background: {e}");
+                None
+            }
+        };
+        let shared = Arc::new(Shared {
+            ros: ros.clone(),
+            topic,
+            state: Mutex::new(State {
+                publisher,
+                pending: VecDeque::new(),
+            }),
+            dropped: AtomicU64::new(0),
+        });
+        let retry = tokio::spawn({
+            let shared = shared.clone();
+            async move {
+                let mut ticks = tokio::time::interval(options.retry_interval);
+                ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                loop {
+                    ticks.tick().await;
+                    let mut state = shared.state.lock().await;
+                    if state.pending.is_empty() && state.publisher.is_some() {
+                        continue;
+                    }
+                    if state.pending.is_empty() {
+                        // Advertise again even with nothing to send, so subscribers see the topic
+                        match shared.ros.advertise::<T>(&shared.topic).await {
+                            Ok(publisher) => state.publisher = Some(publisher),
+                            Err(e) => debug!("Still unable to advertise {}: {e}", shared.topic),
+                        }
+                        continue;
+                    }
+                    match shared.flush(&mut state).await {
+                        Ok(()) => info!("Flushed buffered messages on {}", shared.topic),
+                        Err(e) => debug!("Still unable to publish on {}: {e}", shared.topic),
+                    }
+                }
+            }
+        });
+        Ok(Self {
+            shared,
+            buffer: options.buffer,
+            _retry: retry.into(),
+        })
+    }
+
+    /// The topic being published to.
+    pub fn topic(&self) -> &TopicName {
+        &self.shared.topic
+    }
+
+    /// The number of messages buffered, waiting for the backend to be reachable.
+    pub async fn pending(&self) -> usize {
+        self.shared.state.lock().await.pending.len()
+    }
+
+    /// The number of buffered messages dropped because the buffer was full.
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<T: RosMessageType, R: Ros> Publish<T> for ReliablePublisher<T, R> {
+    async fn publish(&self, data: &T) -> Result<()> {
+        let mut state = self.shared.state.lock().await;
+        if state.pending.len() >= self.buffer.max(1) {
+            state.pending.pop_front();
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        state.pending.push_back(data.clone());
+        match self.shared.flush(&mut state).await {
+            Ok(()) => Ok(()),
+            Err(e) if self.buffer == 0 => {
+                state.pending.clear();
+                Err(e)
+            }
+            Err(e) => {
+                debug!(
+                    "Buffering a message for {}, {} waiting: {e}",
+                    self.shared.topic,
+                    state.pending.len()
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Error, Subscribe};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+    use std::sync::atomic::AtomicBool;
+
+    /// A [MockRos] whose publishers fail while it is offline, like a backend which lost its connection
+    #[derive(Clone)]
+    struct Flaky {
+        ros: MockRos,
+        online: Arc<AtomicBool>,
+    }
+
+    struct FlakyPublisher<T: RosMessageType> {
+        inner: <MockRos as TopicProvider>::Publisher<T>,
+        online: Arc<AtomicBool>,
+    }
+
+    impl<T: RosMessageType> Publish<T> for FlakyPublisher<T> {
+        async fn publish(&self, data: &T) -> Result<()> {
+            if !self.online.load(Ordering::Relaxed) {
+                return Err(Error::Disconnected);
+            }
+            self.inner.publish(data).await
+        }
+    }
+
+    impl TopicProvider for Flaky {
+        type Publisher<T: RosMessageType> = FlakyPublisher<T>;
+        type Subscriber<T: RosMessageType> = <MockRos as TopicProvider>::Subscriber<T>;
+
+        async fn advertise<T: RosMessageType>(
+            &self,
+            topic: impl ToTopicName + Send,
+        ) -> Result<Self::Publisher<T>> {
+            if !self.online.load(Ordering::Relaxed) {
+                return Err(Error::Disconnected);
+            }
+            Ok(FlakyPublisher {
+                inner: self.ros.advertise(topic).await?,
+                online: self.online.clone(),
+            })
+        }
+
+        async fn subscribe<T: RosMessageType>(
+            &self,
+            topic: impl ToTopicName + Send,
+        ) -> Result<Self::Subscriber<T>> {
+            self.ros.subscribe(topic).await
+        }
+    }
+
+    impl crate::ServiceProvider for Flaky {
+        type ServiceClient<T: crate::RosServiceType> =
+            <MockRos as crate::ServiceProvider>::ServiceClient<T>;
+        type ServiceServer = <MockRos as crate::ServiceProvider>::ServiceServer;
+
+        async fn call_service<T: crate::RosServiceType>(
+            &self,
+            topic: impl crate::ToServiceName + Send,
+            request: T::Request,
+        ) -> Result<T::Response> {
+            self.ros.call_service::<T>(topic, request).await
+        }
+
+        async fn service_client<T: crate::RosServiceType + 'static>(
+            &self,
+            topic: impl crate::ToServiceName + Send,
+        ) -> Result<Self::ServiceClient<T>> {
+            self.ros.service_client::<T>(topic).await
+        }
+
+        async fn advertise_service<T: crate::RosServiceType + 'static, F>(
+            &self,
+            topic: impl crate::ToServiceName + Send,
+            server: F,
+        ) -> Result<Self::ServiceServer>
+        where
+            F: crate::ServiceFn<T>,
+        {
+            self.ros.advertise_service::<T, F>(topic, server).await
+        }
+    }
+
+    fn msg(data: u32) -> std_msgs::UInt32 {
+        std_msgs::UInt32 { data }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn buffers_during_outage_and_flushes_in_order() {
+        let ros = Flaky {
+            ros: MockRos::new(),
+            online: Arc::new(AtomicBool::new(true)),
+        };
+        let mut subscriber = ros
+            .subscribe::<std_msgs::UInt32>("/telemetry")
+            .await
+            .unwrap();
+        let options = ReliableOptions::new()
+            .buffer(2)
+            .retry_interval(Duration::from_millis(20));
+        let publisher = ReliablePublisher::advertise(&ros, "/telemetry", options)
+            .await
+            .unwrap();
+
+        publisher.publish(&msg(1)).await.unwrap();
+        assert_eq!(subscriber.next().await.unwrap().data, 1);
+
+        ros.online.store(false, Ordering::Relaxed);
+        for i in 2..5 {
+            publisher.publish(&msg(i)).await.unwrap();
+        }
+        assert_eq!(publisher.pending().await, 2);
+        assert_eq!(publisher.dropped(), 1);
+
+        // The background retry flushes once the backend is back, without another publish
+        ros.online.store(true, Ordering::Relaxed);
+        assert_eq!(subscriber.next().await.unwrap().data, 3);
+        assert_eq!(subscriber.next().await.unwrap().data, 4);
+        assert_eq!(publisher.pending().await, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn fails_without_buffer() {
+        let ros = Flaky {
+            ros: MockRos::new(),
+            online: Arc::new(AtomicBool::new(false)),
+        };
+        let publisher = ReliablePublisher::advertise(&ros, "/telemetry", ReliableOptions::new())
+            .await
+            .unwrap();
+        assert!(matches!(
+            publisher.publish(&msg(1)).await,
+            Err(Error::Disconnected)
+        ));
+        assert_eq!(publisher.pending().await, 0);
+        ros.online.store(true, Ordering::Relaxed);
+        publisher.publish(&msg(2)).await.unwrap();
+    }
+}
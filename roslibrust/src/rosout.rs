@@ -0,0 +1,443 @@
+//! Forwards the log messages ROS nodes publish on `/rosout` into [tracing], so logs of C++ and Python nodes end up
+//! in the same pipeline as those of the Rust application, see [RosoutBridge].
+//!
+//! Each entry becomes a [tracing] event with target `rosout`, the entry's level, its text as the message, and the
+//! fields `node`, `file`, `function`, `line` and `stamp` (seconds since the epoch as set by the publishing node).
+//!
+//! ```no_run
+//! use roslibrust::rosout::{ros1, RosoutBridge};
+//! use roslibrust::Ros;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     // Entries of this node were already logged when they were published
+//!     let _bridge = RosoutBridge::new()
+//!         .ignore_node("/my_node")
+//!         .start::<ros1::Log>(&ros)
+//!         .await?;
+//!     // Forwards until the handle is dropped
+//!     let _ = tokio::signal::ctrl_c().await;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Result, RosMessageType, Subscribe, TopicProvider};
+use abort_on_drop::ChildTask;
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tracing::Level;
+
+/// A message type carrying a log entry, implemented for [ros1::Log] and [ros2::Log].
+pub trait LogEntry: RosMessageType {
+    /// The entry's severity, fatal entries are reported as [Level::ERROR]
+    fn level(&self) -> Level;
+    /// Name of the node which logged the entry
+    fn node(&self) -> &str;
+    fn msg(&self) -> &str;
+    /// Source file the entry was logged from
+    fn file(&self) -> &str;
+    /// Function the entry was logged from
+    fn function(&self) -> &str;
+    /// Line the entry was logged from
+    fn line(&self) -> u32;
+    /// When the entry was logged, in seconds since the epoch
+    fn stamp(&self) -> f64;
+}
+
+/// The ROS1 log message, `rosgraph_msgs/Log`.
+pub mod ros1 {
+    use crate::RosMessageType;
+    use serde::{Deserialize, Serialize};
+    use tracing::Level;
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Time {
+        pub secs: i32,
+        pub nsecs: i32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Header {
+        pub seq: u32,
+        pub stamp: Time,
+        pub frame_id: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Log {
+        pub header: Header,
+        pub level: u8,
+        pub name: String,
+        pub msg: String,
+        pub file: String,
+        pub function: String,
+        pub line: u32,
+        pub topics: Vec<String>,
+    }
+
+    impl Log {
+        pub const DEBUG: u8 = 1;
+        pub const INFO: u8 = 2;
+        pub const WARN: u8 = 4;
+        pub const ERROR: u8 = 8;
+        pub const FATAL: u8 = 16;
+    }
+
+    impl RosMessageType for Log {
+        const ROS_TYPE_NAME: &'static str = "rosgraph_msgs/Log";
+        const MD5SUM: &'static str = "acffd30cd6b6de30f120938c17c593fb";
+        const DEFINITION: &'static str = r####"##
+## Severity level constants
+##
+byte DEBUG=1 #debug level
+byte INFO=2  #general level
+byte WARN=4  #warning level
+byte ERROR=8 #error level
+byte FATAL=16 #fatal/critical level
+##
+## Fields
+##
+Header header
+byte level
+string name # name of the node
+string msg # message
+string file # file the message came from
+string function # function the message came from
+uint32 line # line the message came from
+string[] topics # topic names that the node publishes
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+#
+# sequence ID: consecutively increasing ID
+uint32 seq
+#Two-integer timestamp that is expressed as:
+# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')
+# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')
+# time-handling sugar is provided by the client library
+time stamp
+#Frame this data is associated with
+string frame_id"####;
+    }
+
+    impl super::LogEntry for Log {
+        fn level(&self) -> Level {
+            match self.level {
+                0..Self::INFO => Level::DEBUG,
+                Self::INFO..Self::WARN => Level::INFO,
+                Self::WARN..Self::ERROR => Level::WARN,
+                _ => Level::ERROR,
+            }
+        }
+
+        fn node(&self) -> &str {
+            &self.name
+        }
+
+        fn msg(&self) -> &str {
+            &self.msg
+        }
+
+        fn file(&self) -> &str {
+            &self.file
+        }
+
+        fn function(&self) -> &str {
+            &self.function
+        }
+
+        fn line(&self) -> u32 {
+            self.line
+        }
+
+        fn stamp(&self) -> f64 {
+            self.header.stamp.secs as f64 + self.header.stamp.nsecs as f64 * 1e-9
+        }
+    }
+}
+
+/// The ROS2 log message, `rcl_interfaces/Log`.
+pub mod ros2 {
+    use crate::RosMessageType;
+    use serde::{Deserialize, Serialize};
+    use tracing::Level;
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Time {
+        pub sec: i32,
+        pub nanosec: u32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Log {
+        pub stamp: Time,
+        pub level: u8,
+        pub name: String,
+        pub msg: String,
+        pub file: String,
+        pub function: String,
+        pub line: u32,
+    }
+
+    impl Log {
+        pub const DEBUG: u8 = 10;
+        pub const INFO: u8 = 20;
+        pub const WARN: u8 = 30;
+        pub const ERROR: u8 = 40;
+        pub const FATAL: u8 = 50;
+    }
+
+    impl RosMessageType for Log {
+        const ROS_TYPE_NAME: &'static str = "rcl_interfaces/Log";
+    }
+
+    impl super::LogEntry for Log {
+        fn level(&self) -> Level {
+            match self.level {
+                0..Self::INFO => Level::DEBUG,
+                Self::INFO..Self::WARN => Level::INFO,
+                Self::WARN..Self::ERROR => Level::WARN,
+                _ => Level::ERROR,
+            }
+        }
+
+        fn node(&self) -> &str {
+            &self.name
+        }
+
+        fn msg(&self) -> &str {
+            &self.msg
+        }
+
+        fn file(&self) -> &str {
+            &self.file
+        }
+
+        fn function(&self) -> &str {
+            &self.function
+        }
+
+        fn line(&self) -> u32 {
+            self.line
+        }
+
+        fn stamp(&self) -> f64 {
+            self.stamp.sec as f64 + self.stamp.nanosec as f64 * 1e-9
+        }
+    }
+}
+
+/// Emits `entry` as a [tracing] event, for forwarding entries received some other way than a [RosoutBridge].
+pub fn forward(entry: &impl LogEntry) {
+    // The level of tracing's macros has to be known at compile time
+    macro_rules! emit {
+        ($level:expr) => {
+            tracing::event!(
+                target: "rosout",
+                $level,
+                node = entry.node(),
+                file = entry.file(),
+                function = entry.function(),
+                line = entry.line(),
+                stamp = entry.stamp(),
+                "{}",
+                entry.msg()
+            )
+        };
+    }
+    match entry.level() {
+        Level::TRACE => emit!(Level::TRACE),
+        Level::DEBUG => emit!(Level::DEBUG),
+        Level::INFO => emit!(Level::INFO),
+        Level::WARN => emit!(Level::WARN),
+        Level::ERROR => emit!(Level::ERROR),
+    }
+}
+
+/// Subscribes to the log entries of all nodes and forwards them into [tracing].
+pub struct RosoutBridge {
+    topic: String,
+    ignored: HashSet<String>,
+}
+
+impl Default for RosoutBridge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RosoutBridge {
+    /// Forwards the entries published on `/rosout`.
+    pub fn new() -> Self {
+        Self {
+            topic: "/rosout".to_string(),
+            ignored: HashSet::new(),
+        }
+    }
+
+    /// Forwards the entries published on `topic` instead, e.g. `/rosout_agg` to receive them through ROS1's rosout
+    /// node rather than directly from every node.
+    pub fn topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = topic.into();
+        self
+    }
+
+    /// Drops the entries logged by `node`, typically the application's own node when it also logs to `/rosout`.
+    pub fn ignore_node(mut self, node: impl Into<String>) -> Self {
+        self.ignored.insert(node.into());
+        self
+    }
+
+    /// Starts forwarding the entries received from `ros`, with `T` the log message of its ROS version.
+    pub async fn start<T: LogEntry>(self, ros: &impl TopicProvider) -> Result<RosoutHandle> {
+        let mut subscriber = ros.subscribe::<T>(self.topic.as_str()).await?;
+        let forwarded = Arc::new(AtomicU64::new(0));
+        let Self { topic, ignored } = self;
+        let task = tokio::spawn({
+            let forwarded = forwarded.clone();
+            async move {
+                loop {
+                    let entry = match subscriber.next().await {
+                        Ok(entry) => entry,
+                        Err(Error::Disconnected) => {
+                            tracing::warn!(
+                                "Subscriber for {topic} disconnected, no longer forwarding it"
+                            );
+                            return;
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to receive log entry on {topic}: {e}");
+                            continue;
+                        }
+                    };
+                    if ignored.contains(entry.node()) {
+                        continue;
+                    }
+                    forward(&entry);
+                    forwarded.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        });
+        Ok(RosoutHandle {
+            forwarded,
+            _task: task.into(),
+        })
+    }
+}
+
+/// A running [RosoutBridge], entries stop being forwarded when it is dropped.
+pub struct RosoutHandle {
+    forwarded: Arc<AtomicU64>,
+    _task: ChildTask<()>,
+}
+
+impl RosoutHandle {
+    /// The number of entries forwarded so far.
+    pub fn forwarded(&self) -> u64 {
+        self.forwarded.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Publish;
+    use roslibrust_mock::MockRos;
+    use std::{
+        io::Write,
+        sync::{Mutex, PoisonError},
+        time::Duration,
+    };
+
+    /// Collects what tracing writes to check the emitted events
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn entry(name: &str, level: u8, msg: &str) -> ros1::Log {
+        ros1::Log {
+            level,
+            name: name.to_string(),
+            msg: msg.to_string(),
+            file: "talker.cpp".to_string(),
+            function: "main".to_string(),
+            line: 42,
+            ..Default::default()
+        }
+    }
+
+    #[test_log::test]
+    fn forwards_with_structured_fields() {
+        let captured = Captured::default();
+        let subscriber = tracing_subscriber::fmt()
+            .json()
+            .with_writer({
+                let captured = captured.clone();
+                move || captured.clone()
+            })
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            forward(&entry("/talker", ros1::Log::WARN, "Battery low"));
+            forward(&ros2::Log {
+                level: ros2::Log::FATAL,
+                name: "listener".to_string(),
+                msg: "Lost connection".to_string(),
+                ..Default::default()
+            });
+        });
+
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        let events: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events[0]["level"], "WARN");
+        assert_eq!(events[0]["target"], "rosout");
+        assert_eq!(events[0]["fields"]["message"], "Battery low");
+        assert_eq!(events[0]["fields"]["node"], "/talker");
+        assert_eq!(events[0]["fields"]["file"], "talker.cpp");
+        assert_eq!(events[0]["fields"]["line"], 42);
+        assert_eq!(events[1]["level"], "ERROR");
+        assert_eq!(events[1]["fields"]["node"], "listener");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ignores_own_node() {
+        let ros = MockRos::new();
+        let bridge = RosoutBridge::new()
+            .ignore_node("/me")
+            .start::<ros1::Log>(&ros)
+            .await
+            .unwrap();
+        let publisher = ros.advertise::<ros1::Log>("/rosout").await.unwrap();
+        publisher
+            .publish(&entry("/me", ros1::Log::INFO, "Ignored"))
+            .await
+            .unwrap();
+        publisher
+            .publish(&entry("/talker", ros1::Log::INFO, "Forwarded"))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(bridge.forwarded(), 1);
+    }
+}
@@ -0,0 +1,405 @@
+//! Combines two backends into one, choosing which serves each topic and service by its name, see [Router].
+//!
+//! ```no_run
+//! use roslibrust::router::{Route, Router};
+//! use roslibrust::{Ros, TopicProvider};
+//! use roslibrust_test::ros1::sensor_msgs;
+//!
+//! async fn run(rosbridge: impl Ros, zenoh: impl Ros) -> roslibrust::Result<()> {
+//!     // Camera images are too large for rosbridge, everything else goes through it
+//!     let ros = Router::new(rosbridge, zenoh).route("/camera/*", Route::Secondary);
+//!     let mut images = ros.subscribe::<sensor_msgs::Image>("/camera/image_raw").await?;
+//!     let _image = roslibrust::Subscribe::next(&mut images).await?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    Capabilities, CapabilityProvider, GraphProvider, Publish, PublisherQos, Result, RosMessageType,
+    RosServiceType, Service, ServiceFn, ServiceInfo, ServiceProvider, Subscribe, ToServiceName,
+    ToTopicName, TopicInfo, TopicProvider,
+};
+use std::{future::Future, sync::Arc};
+
+/// Which of the backends of a [Router] serves a topic or service.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Route {
+    /// The first backend given to [Router::new]
+    Primary,
+    /// The second backend given to [Router::new]
+    Secondary,
+}
+
+/// A publisher, subscriber, service client or service server created by one of the backends of a [Router].
+#[derive(Debug)]
+pub enum Routed<A, B> {
+    Primary(A),
+    Secondary(B),
+}
+
+/// Presents two backends as a single one, e.g. camera topics through zenoh and everything else through rosbridge.
+///
+/// Each name is matched against the rules added with [Router::route] in the order they were added, the first
+/// matching rule picks the backend. Names matching no rule go to [Router::default_route], by default the primary.
+/// Rules are matched against names as they are passed in, so they are easiest to write for global names.
+///
+/// The router is itself a [crate::Ros], so it can be handed to anything generic over the backend, and it is cheap
+/// to clone if both backends are.
+#[derive(Clone)]
+pub struct Router<A, B> {
+    primary: A,
+    secondary: B,
+    rules: Arc<Vec<(String, Route)>>,
+    default: Route,
+}
+
+impl<A, B> Router<A, B> {
+    /// Combines `primary` and `secondary`, sending everything to `primary` until rules are added.
+    pub fn new(primary: A, secondary: B) -> Self {
+        Self {
+            primary,
+            secondary,
+            rules: Arc::new(vec![]),
+            default: Route::Primary,
+        }
+    }
+
+    /// Sends the topics and services whose names match `pattern` to `route`.
+    ///
+    /// In `pattern` a `*` stands for any characters, including `/`, so `/camera/*` covers everything
+    /// beneath `/camera`.
+    pub fn route(mut self, pattern: impl Into<String>, route: Route) -> Self {
+        Arc::make_mut(&mut self.rules).push((pattern.into(), route));
+        self
+    }
+
+    /// Sends the topics and services matching no rule to `route`, instead of to the primary backend.
+    pub fn default_route(mut self, route: Route) -> Self {
+        self.default = route;
+        self
+    }
+
+    /// The backend which serves `name`.
+    pub fn route_for(&self, name: &str) -> Route {
+        self.rules
+            .iter()
+            .find(|(pattern, _)| matches(pattern, name))
+            .map(|(_, route)| *route)
+            .unwrap_or(self.default)
+    }
+
+    /// The first backend given to [Router::new].
+    pub fn primary(&self) -> &A {
+        &self.primary
+    }
+
+    /// The second backend given to [Router::new].
+    pub fn secondary(&self) -> &B {
+        &self.secondary
+    }
+}
+
+impl<T, A: Publish<T>, B: Publish<T>> Publish<T> for Routed<A, B>
+where
+    T: RosMessageType,
+{
+    fn publish(&self, data: &T) -> impl Future<Output = Result<()>> + Send {
+        let publish = match self {
+            Routed::Primary(publisher) => Routed::Primary(publisher.publish(data)),
+            Routed::Secondary(publisher) => Routed::Secondary(publisher.publish(data)),
+        };
+        async move {
+            match publish {
+                Routed::Primary(publish) => publish.await,
+                Routed::Secondary(publish) => publish.await,
+            }
+        }
+    }
+}
+
+impl<T, A: Subscribe<T>, B: Subscribe<T>> Subscribe<T> for Routed<A, B>
+where
+    T: RosMessageType,
+{
+    fn next(&mut self) -> impl Future<Output = Result<T>> + Send {
+        let next = match self {
+            Routed::Primary(subscriber) => Routed::Primary(subscriber.next()),
+            Routed::Secondary(subscriber) => Routed::Secondary(subscriber.next()),
+        };
+        async move {
+            match next {
+                Routed::Primary(next) => next.await,
+                Routed::Secondary(next) => next.await,
+            }
+        }
+    }
+}
+
+impl<T, A: Service<T>, B: Service<T>> Service<T> for Routed<A, B>
+where
+    T: RosServiceType,
+{
+    fn call(&self, request: &T::Request) -> impl Future<Output = Result<T::Response>> + Send {
+        let call = match self {
+            Routed::Primary(client) => Routed::Primary(client.call(request)),
+            Routed::Secondary(client) => Routed::Secondary(client.call(request)),
+        };
+        async move {
+            match call {
+                Routed::Primary(call) => call.await,
+                Routed::Secondary(call) => call.await,
+            }
+        }
+    }
+}
+
+impl<A, B> TopicProvider for Router<A, B>
+where
+    A: TopicProvider + Send + Sync,
+    B: TopicProvider + Send + Sync,
+{
+    type Publisher<T: RosMessageType> = Routed<A::Publisher<T>, B::Publisher<T>>;
+    type Subscriber<T: RosMessageType> = Routed<A::Subscriber<T>, B::Subscriber<T>>;
+
+    async fn advertise<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Publisher<T>> {
+        let topic = topic.to_topic_name()?;
+        Ok(match self.route_for(topic.as_str()) {
+            Route::Primary => Routed::Primary(self.primary.advertise(topic).await?),
+            Route::Secondary => Routed::Secondary(self.secondary.advertise(topic).await?),
+        })
+    }
+
+    async fn advertise_with_qos<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+        qos: PublisherQos,
+    ) -> Result<Self::Publisher<T>> {
+        let topic = topic.to_topic_name()?;
+        Ok(match self.route_for(topic.as_str()) {
+            Route::Primary => Routed::Primary(self.primary.advertise_with_qos(topic, qos).await?),
+            Route::Secondary => {
+                Routed::Secondary(self.secondary.advertise_with_qos(topic, qos).await?)
+            }
+        })
+    }
+
+    async fn subscribe<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Subscriber<T>> {
+        let topic = topic.to_topic_name()?;
+        Ok(match self.route_for(topic.as_str()) {
+            Route::Primary => Routed::Primary(self.primary.subscribe(topic).await?),
+            Route::Secondary => Routed::Secondary(self.secondary.subscribe(topic).await?),
+        })
+    }
+}
+
+impl<A, B> ServiceProvider for Router<A, B>
+where
+    A: ServiceProvider + Send + Sync,
+    B: ServiceProvider + Send + Sync,
+{
+    type ServiceClient<T: RosServiceType> = Routed<A::ServiceClient<T>, B::ServiceClient<T>>;
+    type ServiceServer = Routed<A::ServiceServer, B::ServiceServer>;
+
+    async fn call_service<T: RosServiceType>(
+        &self,
+        topic: impl ToServiceName + Send,
+        request: T::Request,
+    ) -> Result<T::Response> {
+        let topic = topic.to_service_name()?;
+        match self.route_for(topic.as_str()) {
+            Route::Primary => self.primary.call_service::<T>(topic, request).await,
+            Route::Secondary => self.secondary.call_service::<T>(topic, request).await,
+        }
+    }
+
+    async fn service_client<T: RosServiceType + 'static>(
+        &self,
+        topic: impl ToServiceName + Send,
+    ) -> Result<Self::ServiceClient<T>> {
+        let topic = topic.to_service_name()?;
+        Ok(match self.route_for(topic.as_str()) {
+            Route::Primary => Routed::Primary(self.primary.service_client::<T>(topic).await?),
+            Route::Secondary => Routed::Secondary(self.secondary.service_client::<T>(topic).await?),
+        })
+    }
+
+    async fn advertise_service<T: RosServiceType + 'static, F>(
+        &self,
+        topic: impl ToServiceName + Send,
+        server: F,
+    ) -> Result<Self::ServiceServer>
+    where
+        F: ServiceFn<T>,
+    {
+        let topic = topic.to_service_name()?;
+        Ok(match self.route_for(topic.as_str()) {
+            Route::Primary => Routed::Primary(
+                self.primary
+                    .advertise_service::<T, F>(topic, server)
+                    .await?,
+            ),
+            Route::Secondary => Routed::Secondary(
+                self.secondary
+                    .advertise_service::<T, F>(topic, server)
+                    .await?,
+            ),
+        })
+    }
+}
+
+/// Lists the topics and services of both backends, leaving out those which aren't routed to the backend they
+/// were found on, as they can't be reached through the router.
+// The router only implements the traits both backends do, and only some of those it could
+impl<A, B> CapabilityProvider for Router<A, B>
+where
+    A: CapabilityProvider,
+    B: CapabilityProvider,
+{
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            raw_topics: false,
+            params: false,
+            sim_time: false,
+            ..self
+                .primary
+                .capabilities()
+                .intersection(&self.secondary.capabilities())
+        }
+    }
+}
+
+impl<A, B> GraphProvider for Router<A, B>
+where
+    A: GraphProvider + Send + Sync,
+    B: GraphProvider + Send + Sync,
+{
+    async fn topics(&self) -> Result<Vec<TopicInfo>> {
+        let mut topics: Vec<_> = self
+            .primary
+            .topics()
+            .await?
+            .into_iter()
+            .filter(|topic| self.route_for(&topic.name) == Route::Primary)
+            .collect();
+        topics.extend(
+            self.secondary
+                .topics()
+                .await?
+                .into_iter()
+                .filter(|topic| self.route_for(&topic.name) == Route::Secondary),
+        );
+        topics.sort();
+        Ok(topics)
+    }
+
+    async fn services(&self) -> Result<Vec<ServiceInfo>> {
+        let mut services: Vec<_> = self
+            .primary
+            .services()
+            .await?
+            .into_iter()
+            .filter(|service| self.route_for(&service.name) == Route::Primary)
+            .collect();
+        services.extend(
+            self.secondary
+                .services()
+                .await?
+                .into_iter()
+                .filter(|service| self.route_for(&service.name) == Route::Secondary),
+        );
+        services.sort();
+        Ok(services)
+    }
+}
+
+/// True if `name` matches `pattern`, where `*` matches any characters
+fn matches(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=name.len())
+                .filter(|&i| name.is_char_boundary(i))
+                .any(|i| matches(rest, &name[i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::{std_msgs, std_srvs};
+
+    #[test_log::test]
+    fn first_matching_rule_wins() {
+        let router = Router::new((), ())
+            .route("/camera/depth/*", Route::Primary)
+            .route("/camera/*", Route::Secondary);
+        assert_eq!(router.route_for("/camera/image_raw"), Route::Secondary);
+        assert_eq!(router.route_for("/camera/depth/points"), Route::Primary);
+        assert_eq!(router.route_for("/odom"), Route::Primary);
+        let router = router.default_route(Route::Secondary);
+        assert_eq!(router.route_for("/odom"), Route::Secondary);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn routes_topics_and_services_by_name() {
+        let primary = MockRos::new();
+        let secondary = MockRos::new();
+        let ros =
+            Router::new(primary.clone(), secondary.clone()).route("/camera/*", Route::Secondary);
+
+        let mut camera = ros
+            .subscribe::<std_msgs::String>("/camera/info")
+            .await
+            .unwrap();
+        let publisher = secondary
+            .advertise::<std_msgs::String>("/camera/info")
+            .await
+            .unwrap();
+        publisher
+            .publish(&std_msgs::String {
+                data: "secondary".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(camera.next().await.unwrap().data, "secondary");
+
+        let _server = ros
+            .advertise_service::<std_srvs::Trigger, _>("/reset", |_| {
+                Ok(std_srvs::TriggerResponse {
+                    success: true,
+                    message: "primary".to_string(),
+                })
+            })
+            .await
+            .unwrap();
+        let response = primary
+            .call_service::<std_srvs::Trigger>("/reset", std_srvs::TriggerRequest {})
+            .await
+            .unwrap();
+        assert_eq!(response.message, "primary");
+        assert!(secondary
+            .call_service::<std_srvs::Trigger>("/reset", std_srvs::TriggerRequest {})
+            .await
+            .is_err());
+
+        let services = ros.services().await.unwrap();
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].name, "/reset");
+
+        // The router supports what both mocks do, except for the traits it doesn't forward
+        let capabilities = ros.capabilities();
+        assert!(capabilities.service_servers && capabilities.graph);
+        assert!(!capabilities.params && !capabilities.raw_topics);
+    }
+}
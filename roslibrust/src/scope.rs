@@ -0,0 +1,386 @@
+//! Ties the lifetime of publishers, subscribers, services and tasks to a scope, see [RosScope].
+//!
+//! Everything created through a scope is torn down together when it closes: tasks are signalled and joined, then
+//! the handles are dropped, unadvertising and unsubscribing. Handles given out by the scope stop working once it
+//! closes, so nothing outlives it by accident, e.g. a test leaking a subscriber into the next test.
+//!
+//! ```no_run
+//! use roslibrust::scope::RosScope;
+//! use roslibrust::{Publish, Ros, Subscribe};
+//! use roslibrust_test::ros1::std_msgs;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     let scope = RosScope::new(ros);
+//!     let publisher = scope.advertise::<std_msgs::String>("/out").await?;
+//!     let mut subscriber = scope.subscribe::<std_msgs::String>("/in").await?;
+//!     scope.spawn("echo", |mut closed| async move {
+//!         loop {
+//!             tokio::select! {
+//!                 _ = closed.wait() => return Ok(()),
+//!                 msg = subscriber.next() => match msg {
+//!                     Ok(msg) => publisher.publish(&msg).await?,
+//!                     // Scoped handles fail once the scope closes
+//!                     Err(_) if closed.is_closed() => return Ok(()),
+//!                     Err(e) => return Err(e),
+//!                 },
+//!             }
+//!         }
+//!     });
+//!     tokio::signal::ctrl_c().await?;
+//!     // Joins the echo task, then unadvertises /out and unsubscribes from /in
+//!     scope.close().await;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    Error, Publish, Result, Ros, RosMessageType, RosServiceType, Service, ServiceFn, Subscribe,
+    ToServiceName, ToTopicName,
+};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{sync::watch, time::Instant};
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A signal handed to the tasks of a [RosScope] that resolves when the scope is closing.
+#[derive(Clone)]
+pub struct Closed {
+    receiver: watch::Receiver<bool>,
+}
+
+impl Closed {
+    /// True once the scope has started closing.
+    pub fn is_closed(&self) -> bool {
+        *self.receiver.borrow()
+    }
+
+    /// Waits until the scope starts closing.
+    pub async fn wait(&mut self) {
+        // An error means the scope was dropped, which is treated the same as closing
+        let _ = self.receiver.wait_for(|closed| *closed).await;
+    }
+}
+
+/// The handle behind a [Scoped], taken out and dropped by the scope when it closes
+struct Slot<H> {
+    handle: tokio::sync::Mutex<Option<H>>,
+}
+
+/// Lets the scope drop handles of any type
+trait Release: Send + Sync {
+    fn release(&self) -> BoxFuture<'_, ()>;
+
+    /// Drops the handle unless it is in use, returning whether it was dropped
+    fn try_release(&self) -> bool;
+}
+
+impl<H: Send> Release for Slot<H> {
+    fn release(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.handle.lock().await.take();
+        })
+    }
+
+    fn try_release(&self) -> bool {
+        match self.handle.try_lock() {
+            Ok(mut handle) => {
+                handle.take();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// A publisher, subscriber, service client or other handle owned by a [RosScope].
+///
+/// It is used like the handle it wraps, implementing [Publish], [Subscribe] or [Service] as that does. Once the scope
+/// closes the handle is dropped, calls in progress are cancelled and every call fails with [Error::Disconnected].
+/// Calls through one [Scoped] take turns, as the handles of the backends may not be shared between tasks.
+pub struct Scoped<H> {
+    slot: Arc<Slot<H>>,
+    closed: Closed,
+}
+
+/// Runs `call`, unless or until `closed` fires
+async fn until_closed<T>(closed: &Closed, call: impl Future<Output = Result<T>>) -> Result<T> {
+    let mut closed = closed.clone();
+    tokio::select! {
+        // A call which completed as the scope closed still reports its result
+        biased;
+        result = call => result,
+        _ = closed.wait() => Err(Error::Disconnected),
+    }
+}
+
+impl<T: RosMessageType, P: Publish<T> + Send> Publish<T> for Scoped<P> {
+    async fn publish(&self, data: &T) -> Result<()> {
+        until_closed(&self.closed, async {
+            let publisher = self.slot.handle.lock().await;
+            let publisher = publisher.as_ref().ok_or(Error::Disconnected)?;
+            publisher.publish(data).await
+        })
+        .await
+    }
+}
+
+impl<T: RosMessageType, S: Subscribe<T> + Send> Subscribe<T> for Scoped<S> {
+    async fn next(&mut self) -> Result<T> {
+        until_closed(&self.closed, async {
+            let mut subscriber = self.slot.handle.lock().await;
+            let subscriber = subscriber.as_mut().ok_or(Error::Disconnected)?;
+            subscriber.next().await
+        })
+        .await
+    }
+}
+
+impl<T: RosServiceType, C: Service<T> + Send> Service<T> for Scoped<C> {
+    async fn call(&self, request: &T::Request) -> Result<T::Response> {
+        until_closed(&self.closed, async {
+            let client = self.slot.handle.lock().await;
+            let client = client.as_ref().ok_or(Error::Disconnected)?;
+            client.call(request).await
+        })
+        .await
+    }
+}
+
+/// Owns the publishers, subscribers, services and tasks created through it, and tears them all down when it closes.
+///
+/// [RosScope::close] signals the tasks with [Closed] and waits for them to exit, aborting those still running after
+/// the close timeout, then drops the handles in the reverse order they were created. Dropping the scope without
+/// closing it aborts the tasks straight away and drops the handles not in use, the rest as soon as their calls are
+/// cancelled.
+pub struct RosScope<R: Ros> {
+    ros: R,
+    closed: watch::Sender<bool>,
+    close_timeout: Duration,
+    handles: Mutex<Vec<Arc<dyn Release>>>,
+    tasks: Mutex<Vec<(String, ChildTask<()>)>>,
+}
+
+impl<R: Ros> RosScope<R> {
+    /// Creates a scope creating its handles on `ros`.
+    pub fn new(ros: R) -> Self {
+        Self {
+            ros,
+            closed: watch::channel(false).0,
+            close_timeout: Duration::from_secs(5),
+            handles: Mutex::new(Vec::new()),
+            tasks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Sets how long [RosScope::close] waits for tasks to exit before aborting them, defaults to 5 seconds.
+    pub fn close_timeout(mut self, timeout: Duration) -> Self {
+        self.close_timeout = timeout;
+        self
+    }
+
+    /// The ros handle the scope creates its handles on
+    pub fn ros(&self) -> &R {
+        &self.ros
+    }
+
+    /// A signal resolving when the scope starts closing
+    pub fn closed(&self) -> Closed {
+        Closed {
+            receiver: self.closed.subscribe(),
+        }
+    }
+
+    /// Hands `handle` over to the scope, to be dropped when it closes. Useful for handles the scope can't create
+    /// itself, e.g. wrapped subscribers or cached service clients.
+    pub fn adopt<H: Send + 'static>(&self, handle: H) -> Scoped<H> {
+        let slot = Arc::new(Slot {
+            handle: tokio::sync::Mutex::new(Some(handle)),
+        });
+        self.handles.lock().unwrap().push(slot.clone());
+        Scoped {
+            slot,
+            closed: self.closed(),
+        }
+    }
+
+    /// Advertises `topic`, unadvertising it when the scope closes.
+    pub async fn advertise<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Scoped<R::Publisher<T>>> {
+        Ok(self.adopt(self.ros.advertise::<T>(topic).await?))
+    }
+
+    /// Subscribes to `topic`, unsubscribing when the scope closes.
+    pub async fn subscribe<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Scoped<R::Subscriber<T>>> {
+        Ok(self.adopt(self.ros.subscribe::<T>(topic).await?))
+    }
+
+    /// Creates a client of `service`, which stops working when the scope closes.
+    pub async fn service_client<T: RosServiceType + 'static>(
+        &self,
+        service: impl ToServiceName + Send,
+    ) -> Result<Scoped<R::ServiceClient<T>>> {
+        Ok(self.adopt(self.ros.service_client::<T>(service).await?))
+    }
+
+    /// Advertises `service`, which remains available until the scope closes.
+    pub async fn advertise_service<T: RosServiceType + 'static, F: ServiceFn<T>>(
+        &self,
+        service: impl ToServiceName + Send,
+        server: F,
+    ) -> Result<()> {
+        let server = self.ros.advertise_service::<T, F>(service, server).await?;
+        self.adopt(server);
+        Ok(())
+    }
+
+    /// Runs a task until it returns, logging its error if it fails. It is given the scope's [Closed] signal and
+    /// should exit when that fires, otherwise it is aborted after the close timeout.
+    pub fn spawn<F, Fut>(&self, name: &str, task: F)
+    where
+        F: FnOnce(Closed) -> Fut,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let run = task(self.closed());
+        let task_name = name.to_string();
+        let task = tokio::spawn(async move {
+            if let Err(e) = run.await {
+                error!("Task {task_name} of scope failed: {e}");
+            }
+        });
+        self.tasks
+            .lock()
+            .unwrap()
+            .push((name.to_string(), task.into()));
+    }
+
+    /// Closes the scope: signals its tasks and waits for them to exit, then drops its handles, newest first.
+    pub async fn close(self) {
+        self.closed.send_replace(true);
+        let deadline = Instant::now() + self.close_timeout;
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap());
+        for (name, mut task) in tasks {
+            match tokio::time::timeout_at(deadline, &mut task).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) if e.is_panic() => error!("Task {name} of scope panicked"),
+                Ok(Err(_)) => {}
+                Err(_) => warn!("Task {name} of scope didn't exit in time, aborting it"),
+            }
+        }
+        let handles = std::mem::take(&mut *self.handles.lock().unwrap());
+        for handle in handles.iter().rev() {
+            handle.release().await;
+        }
+    }
+}
+
+impl<R: Ros> Drop for RosScope<R> {
+    fn drop(&mut self) {
+        self.closed.send_replace(true);
+        // Dropping the tasks aborts them
+        self.tasks.lock().unwrap().clear();
+        let handles = std::mem::take(&mut *self.handles.lock().unwrap());
+        for handle in handles.into_iter().rev() {
+            if handle.try_release() {
+                continue;
+            }
+            // In use by a call, which the closed signal cancels shortly
+            if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+                runtime.spawn(async move { handle.release().await });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ServiceProvider, TopicProvider};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::{std_msgs, std_srvs};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn close_joins_tasks_and_drops_handles() {
+        let ros = MockRos::new();
+        let scope = RosScope::new(ros.clone());
+        let publisher = scope.advertise::<std_msgs::String>("/out").await.unwrap();
+        let mut subscriber = scope.subscribe::<std_msgs::String>("/in").await.unwrap();
+        scope
+            .advertise_service::<std_srvs::Trigger, _>("/status", |_| {
+                Ok(std_srvs::TriggerResponse::default())
+            })
+            .await
+            .unwrap();
+        let exited = Arc::new(AtomicBool::new(false));
+        let task_exited = exited.clone();
+        scope.spawn("echo", |mut closed| async move {
+            loop {
+                tokio::select! {
+                    _ = closed.wait() => break,
+                    msg = subscriber.next() => match msg {
+                        Ok(msg) => publisher.publish(&msg).await?,
+                        Err(Error::Disconnected) if closed.is_closed() => break,
+                        Err(e) => return Err(e),
+                    },
+                }
+            }
+            task_exited.store(true, Ordering::SeqCst);
+            Ok(())
+        });
+
+        let input = ros.advertise::<std_msgs::String>("/in").await.unwrap();
+        let mut output = ros.subscribe::<std_msgs::String>("/out").await.unwrap();
+        input
+            .publish(&std_msgs::String {
+                data: "hello".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(output.next().await.unwrap().data, "hello");
+        let client = scope
+            .service_client::<std_srvs::Trigger>("/status")
+            .await
+            .unwrap();
+        assert!(client.call(&std_srvs::TriggerRequest {}).await.is_ok());
+
+        scope.close().await;
+        assert!(exited.load(Ordering::SeqCst));
+        // The service went with the scope, as did the client
+        assert!(ros
+            .call_service::<std_srvs::Trigger>("/status", std_srvs::TriggerRequest {})
+            .await
+            .is_err());
+        assert!(matches!(
+            client.call(&std_srvs::TriggerRequest {}).await,
+            Err(Error::Disconnected)
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn dropping_cancels_waiting_subscribers() {
+        let ros = MockRos::new();
+        let scope = RosScope::new(ros.clone()).close_timeout(Duration::from_millis(100));
+        let mut subscriber = scope.subscribe::<std_msgs::String>("/in").await.unwrap();
+        let next = tokio::spawn(async move { subscriber.next().await });
+        // Let the subscriber start waiting before the scope goes away
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        drop(scope);
+        let result = tokio::time::timeout(Duration::from_secs(1), next)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(result, Err(Error::Disconnected)));
+    }
+}
@@ -0,0 +1,219 @@
+//! Caches the responses of idempotent services, e.g. map lookups or descriptions queried over and over by a UI,
+//! see [CachedServiceClient].
+//!
+//! ```no_run
+//! use roslibrust::service_cache::CachedServiceClient;
+//! use roslibrust::{Ros, Service, ServiceProvider};
+//! use roslibrust_test::ros1::std_srvs;
+//! use std::time::Duration;
+//!
+//! // The cache shares the client between calls, which the clients of all backends allow
+//! async fn run<R: Ros>(ros: R) -> roslibrust::Result<()>
+//! where
+//!     R::ServiceClient<std_srvs::Trigger>: Sync,
+//! {
+//!     let client = ros.service_client::<std_srvs::Trigger>("/describe").await?;
+//!     let describe = CachedServiceClient::new(client, Duration::from_secs(10)).max_entries(100);
+//!     // Only the first call reaches the service, the second is answered from the cache
+//!     let first = describe.call(&std_srvs::TriggerRequest {}).await?;
+//!     let second = describe.call(&std_srvs::TriggerRequest {}).await?;
+//!     assert_eq!(first, second);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Result, RosMessageType, RosServiceType, Service};
+use std::{collections::HashMap, marker::PhantomData, sync::Mutex, time::Duration};
+use tokio::time::Instant;
+
+/// The responses held, by the ROS1 serialized request, with when they were received
+type Responses<T> = HashMap<Vec<u8>, (Instant, <T as RosServiceType>::Response)>;
+
+/// Wraps any [Service] client, answering calls with the response to an earlier call with an equal request for as
+/// long as that response is younger than the time to live.
+///
+/// Requests are told apart by their ROS1 serialized form, so only use it for services whose response depends solely on
+/// the request. Failed calls aren't cached, and the cache itself implements [Service] so it can stand in for the
+/// client it wraps. Concurrent calls with the same uncached request each reach the service.
+pub struct CachedServiceClient<T: RosServiceType, S> {
+    client: S,
+    ttl: Duration,
+    max_entries: usize,
+    responses: Mutex<Responses<T>>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: RosServiceType, S: Service<T>> CachedServiceClient<T, S> {
+    /// Caches the responses of `client` for `ttl` after they were received.
+    pub fn new(client: S, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            max_entries: usize::MAX,
+            responses: Mutex::new(HashMap::new()),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Holds at most `max_entries` responses, at least one, dropping the oldest to make room, instead of any number.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+
+    /// The client the responses are cached for
+    pub fn client(&self) -> &S {
+        &self.client
+    }
+
+    /// Forgets the response to `request`, so the next call with it reaches the service.
+    pub fn invalidate(&self, request: &T::Request) {
+        if let Ok(key) = key::<T>(request) {
+            self.responses.lock().unwrap().remove(&key);
+        }
+    }
+
+    /// Forgets all responses, e.g. after the service announced that its data changed.
+    pub fn clear(&self) {
+        self.responses.lock().unwrap().clear();
+    }
+
+    /// The number of responses held which are still fresh
+    pub fn len(&self) -> usize {
+        let mut responses = self.responses.lock().unwrap();
+        self.remove_expired(&mut responses);
+        responses.len()
+    }
+
+    /// Whether no fresh responses are held
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn remove_expired(&self, responses: &mut Responses<T>) {
+        responses.retain(|_, (received, _)| received.elapsed() < self.ttl);
+    }
+}
+
+/// Serializes `request` to tell requests apart, in the ROS1 format as it keeps every bit of floats such as NaN
+fn key<T: RosServiceType>(request: &T::Request) -> Result<Vec<u8>> {
+    match request.ros1_encode() {
+        Some(bytes) => Ok(bytes),
+        None => roslibrust_serde_rosmsg::to_vec(request)
+            .map_err(|e| Error::SerializationError(e.to_string())),
+    }
+}
+
+impl<T: RosServiceType, S: Service<T> + Sync> Service<T> for CachedServiceClient<T, S> {
+    async fn call(&self, request: &T::Request) -> Result<T::Response> {
+        let key = key::<T>(request)?;
+        let cached = self
+            .responses
+            .lock()
+            .unwrap()
+            .get(&key)
+            .filter(|(received, _)| received.elapsed() < self.ttl)
+            .map(|(_, response)| response.clone());
+        if let Some(response) = cached {
+            return Ok(response);
+        }
+
+        let response = self.client.call(request).await?;
+
+        let mut responses = self.responses.lock().unwrap();
+        self.remove_expired(&mut responses);
+        if !responses.contains_key(&key) && responses.len() >= self.max_entries {
+            let oldest = responses
+                .iter()
+                .min_by_key(|(_, (received, _))| *received)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                responses.remove(&oldest);
+            }
+        }
+        responses.insert(key, (Instant::now(), response.clone()));
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ServiceProvider;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_srvs;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn answers_repeated_requests_from_the_cache() {
+        let ros = MockRos::new();
+        let _server = ros
+            .advertise_service::<std_srvs::SetBool, _>("/describe", |request| {
+                Ok(std_srvs::SetBoolResponse {
+                    success: request.data,
+                    message: format!("{}", request.data),
+                })
+            })
+            .await
+            .unwrap();
+        let client = ros
+            .service_client::<std_srvs::SetBool>("/describe")
+            .await
+            .unwrap();
+        let cache = CachedServiceClient::new(client, Duration::from_millis(200)).max_entries(1);
+        let calls = || ros.service_call_count("/describe").unwrap();
+        let on = std_srvs::SetBoolRequest { data: true };
+        let off = std_srvs::SetBoolRequest { data: false };
+
+        assert_eq!(cache.call(&on).await.unwrap().message, "true");
+        assert_eq!(cache.call(&on).await.unwrap().message, "true");
+        assert_eq!(calls(), 1);
+        assert_eq!(cache.len(), 1);
+
+        // A different request isn't answered from the cache, and displaces the oldest response
+        assert_eq!(cache.call(&off).await.unwrap().message, "false");
+        assert_eq!(calls(), 2);
+        cache.call(&on).await.unwrap();
+        assert_eq!(calls(), 3);
+
+        cache.invalidate(&on);
+        assert!(cache.is_empty());
+        cache.call(&on).await.unwrap();
+        assert_eq!(calls(), 4);
+
+        // Responses expire after the time to live
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(cache.is_empty());
+        cache.call(&on).await.unwrap();
+        assert_eq!(calls(), 5);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn failed_calls_are_not_cached() {
+        let ros = MockRos::new();
+        let server = ros
+            .advertise_service::<std_srvs::Trigger, _>("/describe", |_| {
+                Ok(std_srvs::TriggerResponse::default())
+            })
+            .await
+            .unwrap();
+        let client = ros
+            .service_client::<std_srvs::Trigger>("/describe")
+            .await
+            .unwrap();
+        drop(server);
+        let cache = CachedServiceClient::new(client, Duration::from_secs(10));
+        assert!(cache.call(&std_srvs::TriggerRequest {}).await.is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn tells_apart_requests_with_non_finite_floats() {
+        let request = |tolerance| roslibrust_test::ros1::nav_msgs::GetPlanRequest {
+            tolerance,
+            ..Default::default()
+        };
+        let nan = key::<roslibrust_test::ros1::nav_msgs::GetPlan>(&request(f32::NAN)).unwrap();
+        let inf = key::<roslibrust_test::ros1::nav_msgs::GetPlan>(&request(f32::INFINITY)).unwrap();
+        assert_ne!(nan, inf);
+    }
+}
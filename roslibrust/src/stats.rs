@@ -0,0 +1,260 @@
+//! Periodically persists the statistics of topics to a file, see [StatsRecorder].
+//!
+//! ```no_run
+//! use roslibrust::monitor::stamp_time;
+//! use roslibrust::stats::StatsRecorder;
+//! use roslibrust::stats_log::McapStatsLog;
+//! use roslibrust::{ClockProvider, Ros, Subscribe};
+//! use roslibrust_test::ros1::sensor_msgs;
+//! use std::time::Duration;
+//!
+//! async fn run(ros: impl Ros + ClockProvider + Sync + 'static) -> roslibrust::Result<()> {
+//!     let log = McapStatsLog::create("topic_stats.mcap").expect("Can't create stats file");
+//!     let recorder = StatsRecorder::start(log, Duration::from_secs(1));
+//!     let subscriber = ros.subscribe::<sensor_msgs::Imu>("/imu").await?;
+//!     let mut subscriber = recorder
+//!         .monitor("/imu", subscriber)
+//!         .with_clock(ros.clone())
+//!         .with_stamp(|imu| stamp_time(imu.header.stamp.secs, imu.header.stamp.nsecs));
+//!     loop {
+//!         let imu = subscriber.next().await?;
+//!         log::info!("Angular velocity {:?}", imu.angular_velocity);
+//!     }
+//! }
+//! ```
+
+use crate::{
+    monitor::{TopicMonitor, TopicStats},
+    stats_log::StatsLog,
+    ClockProvider, Result, RosMessageType, Subscribe,
+};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, SystemTime},
+};
+
+type Monitors = Arc<Mutex<BTreeMap<String, TopicMonitor>>>;
+type Now = Arc<dyn Fn() -> SystemTime + Send + Sync>;
+
+fn lock(monitors: &Monitors) -> MutexGuard<'_, BTreeMap<String, TopicMonitor>> {
+    monitors.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Measures the traffic of topics and appends their [TopicStats] to a [StatsLog] at a fixed interval.
+///
+/// Clones share the same topics and log. Recording stops once every clone is dropped, which also drops the log,
+/// finishing it.
+#[derive(Clone)]
+pub struct StatsRecorder {
+    monitors: Monitors,
+    _task: Arc<ChildTask<()>>,
+}
+
+impl StatsRecorder {
+    /// Writes the statistics of every topic measured so far to `log` every `interval`.
+    pub fn start(mut log: impl StatsLog + Send + 'static, interval: Duration) -> Self {
+        let monitors = Monitors::default();
+        let task = tokio::spawn({
+            let monitors = monitors.clone();
+            async move {
+                let mut ticks = tokio::time::interval(interval);
+                ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                // The first tick completes immediately, when nothing was measured yet
+                ticks.tick().await;
+                loop {
+                    ticks.tick().await;
+                    let time = SystemTime::now();
+                    let stats: Vec<_> = lock(&monitors)
+                        .iter()
+                        .map(|(topic, monitor)| (topic.clone(), monitor.stats()))
+                        .collect();
+                    let result = stats
+                        .iter()
+                        .try_for_each(|(topic, stats)| log.write(time, topic, stats))
+                        .and_then(|()| log.flush());
+                    if let Err(e) = result {
+                        warn!("Failed to write topic statistics: {e}");
+                    }
+                }
+            }
+        });
+        Self {
+            monitors,
+            _task: Arc::new(task.into()),
+        }
+    }
+
+    /// Wraps a subscriber of `topic` so every message it receives is measured.
+    pub fn monitor<S: Subscribe<T>, T: RosMessageType>(
+        &self,
+        topic: impl Into<String>,
+        inner: S,
+    ) -> Recorded<S, T> {
+        let topic = topic.into();
+        lock(&self.monitors).entry(topic.clone()).or_default();
+        Recorded {
+            inner,
+            topic,
+            monitors: self.monitors.clone(),
+            size: |msg| msg.ros1_encode().map_or(0, |bytes| bytes.len()),
+            stamp: None,
+            now: Arc::new(SystemTime::now),
+        }
+    }
+
+    /// Records into the monitor of `topic` directly, e.g. messages published or dropped.
+    pub fn record(&self, topic: &str, f: impl FnOnce(&mut TopicMonitor)) {
+        f(lock(&self.monitors).entry(topic.to_owned()).or_default())
+    }
+
+    /// The current statistics of every topic.
+    pub fn stats(&self) -> BTreeMap<String, TopicStats> {
+        lock(&self.monitors)
+            .iter()
+            .map(|(topic, monitor)| (topic.clone(), monitor.stats()))
+            .collect()
+    }
+}
+
+/// A subscriber whose messages are measured by a [StatsRecorder], see [StatsRecorder::monitor].
+pub struct Recorded<S, T> {
+    inner: S,
+    topic: String,
+    monitors: Monitors,
+    size: fn(&T) -> usize,
+    stamp: Option<fn(&T) -> Option<SystemTime>>,
+    now: Now,
+}
+
+impl<S, T> Recorded<S, T> {
+    /// Replaces how the size of each message is measured, see [crate::monitor::MonitoredSubscriber].
+    pub fn with_size(mut self, size: fn(&T) -> usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Records the latency of each message as the time since `stamp` returns, typically the stamp of its header
+    /// converted with [crate::monitor::stamp_time]. Messages for which it returns None aren't measured.
+    pub fn with_stamp(mut self, stamp: fn(&T) -> Option<SystemTime>) -> Self {
+        self.stamp = Some(stamp);
+        self
+    }
+
+    /// Measures latencies against `clock` instead of the wall clock, e.g. the backend so they follow simulated
+    /// time while `/use_sim_time` is set.
+    pub fn with_clock(mut self, clock: impl ClockProvider + Send + Sync + 'static) -> Self {
+        self.now = Arc::new(move || clock.now());
+        self
+    }
+
+    /// Returns the wrapped subscriber.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Subscribe<T> + Send, T: RosMessageType> Subscribe<T> for Recorded<S, T> {
+    async fn next(&mut self) -> Result<T> {
+        let msg = self.inner.next().await?;
+        let size = (self.size)(&msg);
+        let stamp = self.stamp.and_then(|stamp| stamp(&msg));
+        let received = (self.now)();
+        let mut monitors = lock(&self.monitors);
+        let monitor = monitors.entry(self.topic.clone()).or_default();
+        monitor.record(size);
+        if let Some(stamp) = stamp {
+            monitor.record_stamp(received, stamp);
+        }
+        Ok(msg)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{monitor::stamp_time, stats_log::CsvStatsLog, Publish, TopicProvider};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+    use std::io::{self, Write};
+
+    /// A writer whose output can be read while the recorder owns it
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn records_topic_stats() {
+        let ros = MockRos::new();
+        let output = Shared::default();
+        let recorder =
+            StatsRecorder::start(CsvStatsLog::new(output.clone()), Duration::from_millis(100));
+        let publisher = ros.advertise::<std_msgs::Header>("/stamped").await.unwrap();
+        let subscriber = ros.subscribe::<std_msgs::Header>("/stamped").await.unwrap();
+        let mut subscriber = recorder
+            .monitor("/stamped", subscriber)
+            .with_stamp(|_| Some(SystemTime::now() - Duration::from_millis(20)));
+
+        for seq in 0..3 {
+            publisher
+                .publish(&std_msgs::Header {
+                    seq,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            subscriber.next().await.unwrap();
+        }
+        recorder.record("/stamped", |monitor| monitor.record_dropped(2));
+        let stats = &recorder.stats()["/stamped"];
+        assert_eq!(stats.messages, 3);
+        assert_eq!(stats.dropped, 2);
+        assert!(stats.mean_latency.unwrap() >= Duration::from_millis(20));
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        let csv = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        let rows: Vec<_> = csv.lines().skip(1).collect();
+        assert!(!rows.is_empty(), "{csv}");
+        assert!(rows.iter().all(|row| row.contains(",/stamped,3,")), "{csv}");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn measures_latency_in_sim_time() {
+        let ros = MockRos::new();
+        ros.clock().advance(Duration::from_secs(10)).await;
+        let recorder = StatsRecorder::start(CsvStatsLog::new(io::sink()), Duration::from_secs(1));
+        let publisher = ros.advertise::<std_msgs::Header>("/stamped").await.unwrap();
+        let subscriber = ros.subscribe::<std_msgs::Header>("/stamped").await.unwrap();
+        let mut subscriber = recorder
+            .monitor("/stamped", subscriber)
+            .with_clock(ros.clone())
+            .with_stamp(|header| stamp_time(header.stamp.secs, header.stamp.nsecs));
+
+        // Stamped half a second of simulated time ago, far from the wall clock
+        let mut header = std_msgs::Header::default();
+        header.stamp.secs = 9;
+        header.stamp.nsecs = 500_000_000;
+        publisher.publish(&header).await.unwrap();
+        subscriber.next().await.unwrap();
+        // Unstamped messages aren't measured
+        publisher.publish(&Default::default()).await.unwrap();
+        subscriber.next().await.unwrap();
+
+        let stats = &recorder.stats()["/stamped"];
+        assert_eq!(stats.messages, 2);
+        assert_eq!(stats.latency_p50, Some(Duration::from_millis(500)));
+        assert_eq!(stats.max_latency, Some(Duration::from_millis(500)));
+    }
+}
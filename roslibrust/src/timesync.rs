@@ -0,0 +1,520 @@
+//! Estimates the offset and skew between the local clock and a remote ROS clock, see [TimeSync].
+//!
+//! ```no_run
+//! use roslibrust::timesync::{TimeSync, TimeSyncConfig};
+//! use roslibrust::{ClockProvider, Ros};
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     // The robot serves its time with timesync::advertise_time
+//!     let sync = TimeSync::from_service(&ros, "/robot/get_time", TimeSyncConfig::new()).await?;
+//!     // Stamps commands in the robot's time, rather than in this machine's
+//!     let stamp = sync.now();
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    ClockProvider, Result, RosMessageType, Service, ServiceProvider, Subscribe, ToServiceName,
+    ToTopicName, TopicProvider,
+};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, SystemTime},
+};
+
+/// Configures a [TimeSync].
+#[derive(Clone, Debug)]
+pub struct TimeSyncConfig {
+    window: usize,
+    interval: Duration,
+}
+
+impl Default for TimeSyncConfig {
+    fn default() -> Self {
+        Self {
+            window: 32,
+            interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl TimeSyncConfig {
+    /// Estimates from the last 32 samples, pinging a service every second.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Estimates from the last `samples` samples, at least two. Longer windows smooth out jitter, shorter ones
+    /// follow a remote clock which is adjusted, e.g. by NTP, more quickly.
+    pub fn window(mut self, samples: usize) -> Self {
+        self.window = samples.max(2);
+        self
+    }
+
+    /// Pings the service of [TimeSync::from_service] every `interval` instead of every second.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+/// The relation between the local clock and a remote one, see [TimeSync::estimate].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Estimate {
+    /// Seconds the remote clock is ahead of the local one at [Estimate::at], negative when it's behind
+    pub offset: f64,
+    /// How much faster the remote clock runs than the local one, e.g. 1e-5 when it gains 10µs every second
+    pub skew: f64,
+    /// The local time the offset was estimated for
+    pub at: SystemTime,
+    /// The number of samples the estimate is based on
+    pub samples: usize,
+}
+
+impl Estimate {
+    /// Converts a local time into the remote clock's time.
+    pub fn to_remote(&self, local: SystemTime) -> SystemTime {
+        let since = seconds_between(self.at, local);
+        add_seconds(local, self.offset + self.skew * since)
+    }
+
+    /// Converts a time of the remote clock into local time.
+    pub fn to_local(&self, remote: SystemTime) -> SystemTime {
+        let since = (seconds_between(self.at, remote) - self.offset) / (1.0 + self.skew);
+        add_seconds(self.at, since)
+    }
+}
+
+/// Signed seconds from `from` to `to`
+fn seconds_between(from: SystemTime, to: SystemTime) -> f64 {
+    match to.duration_since(from) {
+        Ok(duration) => duration.as_secs_f64(),
+        Err(e) => -e.duration().as_secs_f64(),
+    }
+}
+
+fn add_seconds(time: SystemTime, seconds: f64) -> SystemTime {
+    if seconds >= 0.0 {
+        time + Duration::from_secs_f64(seconds)
+    } else {
+        time - Duration::from_secs_f64(-seconds)
+    }
+}
+
+struct Sample {
+    // Seconds since the estimator's origin, keeping the regression precise
+    local: f64,
+    offset: f64,
+    round_trip: f64,
+}
+
+/// Fits a line through the offsets between the clocks over time
+struct Estimator {
+    window: usize,
+    origin: Option<SystemTime>,
+    samples: VecDeque<Sample>,
+}
+
+impl Estimator {
+    fn new(window: usize) -> Self {
+        Self {
+            window,
+            origin: None,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Adds that the remote clock read `remote` at `local`, learned in a round trip of `round_trip`
+    fn add(&mut self, local: SystemTime, remote: SystemTime, round_trip: Duration) {
+        let origin = *self.origin.get_or_insert(local);
+        if self.samples.len() >= self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(Sample {
+            local: seconds_between(origin, local),
+            offset: seconds_between(local, remote),
+            round_trip: round_trip.as_secs_f64(),
+        });
+    }
+
+    fn estimate(&self) -> Option<Estimate> {
+        let origin = self.origin?;
+        // Like NTP, trust the fastest round trips, slow ones were likely queued in one direction only
+        let fastest = self
+            .samples
+            .iter()
+            .map(|sample| sample.round_trip)
+            .fold(f64::INFINITY, f64::min);
+        let samples: Vec<_> = self
+            .samples
+            .iter()
+            .filter(|sample| sample.round_trip <= fastest * 2.0)
+            .collect();
+        let count = samples.len() as f64;
+        let mean_local = samples.iter().map(|sample| sample.local).sum::<f64>() / count;
+        let mean_offset = samples.iter().map(|sample| sample.offset).sum::<f64>() / count;
+        let (covariance, variance) = samples.iter().fold((0.0, 0.0), |(cov, var), sample| {
+            let local = sample.local - mean_local;
+            (
+                cov + local * (sample.offset - mean_offset),
+                var + local * local,
+            )
+        });
+        let skew = if variance > 0.0 {
+            covariance / variance
+        } else {
+            0.0
+        };
+        Some(Estimate {
+            offset: mean_offset,
+            skew,
+            at: add_seconds(origin, mean_local),
+            samples: samples.len(),
+        })
+    }
+}
+
+/// Keeps estimating how a remote ROS clock relates to the local one, providing the remote time as a [ClockProvider].
+///
+/// Clients of a robot over rosbridge often run on machines whose clocks are seconds apart from the robot's, so
+/// commands stamped with the local time look stale or from the future to the robot. Estimating the offset and skew
+/// of the robot's clock lets clients stamp in the robot's time instead.
+///
+/// Samples of the remote time come from one of:
+/// - [TimeSync::from_service] pinging the service of [advertise_time], most accurate as the latency of the
+///   transport is measured and accounted for, like in NTP.
+/// - [TimeSync::from_clock] following a `rosgraph_msgs/Clock` topic, e.g. `/clock` of a simulation.
+/// - [TimeSync::from_stamps] reading the stamps of any topic published as it is measured, e.g. sensor data.
+///
+/// The latter two can't measure the latency of the transport, so their offset includes it.
+///
+/// Until the first sample arrives the local time is used. Dropping the [TimeSync] stops sampling.
+pub struct TimeSync {
+    estimator: Arc<Mutex<Estimator>>,
+    _task: ChildTask<()>,
+}
+
+impl TimeSync {
+    fn estimator(config: &TimeSyncConfig) -> Arc<Mutex<Estimator>> {
+        Arc::new(Mutex::new(Estimator::new(config.window)))
+    }
+
+    /// Estimates the clock serving `service`, see [advertise_time], by calling it every
+    /// [TimeSyncConfig::interval].
+    pub async fn from_service<R>(
+        ros: &R,
+        service: impl ToServiceName + Send,
+        config: TimeSyncConfig,
+    ) -> Result<Self>
+    where
+        R: ServiceProvider + Send + Sync,
+    {
+        let service = service.to_service_name()?;
+        let client = ros.service_client::<msgs::GetTime>(&service).await?;
+        let estimator = Self::estimator(&config);
+        let task = tokio::spawn({
+            let estimator = estimator.clone();
+            async move {
+                let mut ticks = tokio::time::interval(config.interval);
+                ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                loop {
+                    ticks.tick().await;
+                    let sent = SystemTime::now();
+                    let response = client.call(&msgs::GetTimeRequest {}).await;
+                    let received = SystemTime::now();
+                    match response {
+                        Ok(response) => {
+                            let round_trip = received.duration_since(sent).unwrap_or_default();
+                            // Assume the request and response took equally long
+                            let local = sent + round_trip / 2;
+                            lock(&estimator).add(local, response.now.into(), round_trip);
+                        }
+                        Err(e) => debug!("Failed to get the time from {service}: {e}"),
+                    }
+                }
+            }
+        });
+        Ok(Self {
+            estimator,
+            _task: task.into(),
+        })
+    }
+
+    /// Estimates the clock published as `rosgraph_msgs/Clock` on `topic`.
+    pub async fn from_clock<R>(
+        ros: &R,
+        topic: impl ToTopicName + Send,
+        config: TimeSyncConfig,
+    ) -> Result<Self>
+    where
+        R: TopicProvider + Send + Sync,
+    {
+        Self::from_stamps(ros, topic, config, |clock: &msgs::Clock| {
+            Some(clock.clock.clone().into())
+        })
+        .await
+    }
+
+    /// Estimates the clock stamping the messages of `topic`, as read by `stamp`, e.g. from their header. Messages
+    /// `stamp` returns `None` for are skipped.
+    pub async fn from_stamps<T, R>(
+        ros: &R,
+        topic: impl ToTopicName + Send,
+        config: TimeSyncConfig,
+        stamp: impl Fn(&T) -> Option<SystemTime> + Send + 'static,
+    ) -> Result<Self>
+    where
+        T: RosMessageType,
+        R: TopicProvider + Send + Sync,
+    {
+        let topic = topic.to_topic_name()?;
+        let mut subscriber = ros.subscribe::<T>(&topic).await?;
+        let estimator = Self::estimator(&config);
+        let task = tokio::spawn({
+            let estimator = estimator.clone();
+            async move {
+                loop {
+                    match subscriber.next().await {
+                        Ok(msg) => {
+                            if let Some(remote) = stamp(&msg) {
+                                lock(&estimator).add(SystemTime::now(), remote, Duration::ZERO);
+                            }
+                        }
+                        Err(e) => {
+                            warn!("Stopped estimating the clock of {topic}: {e}");
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+        Ok(Self {
+            estimator,
+            _task: task.into(),
+        })
+    }
+
+    /// The current estimate, `None` until the first sample arrived.
+    pub fn estimate(&self) -> Option<Estimate> {
+        lock(&self.estimator).estimate()
+    }
+
+    /// Converts a local time into the remote clock's time, unchanged until the first sample arrived.
+    pub fn to_remote(&self, local: SystemTime) -> SystemTime {
+        match self.estimate() {
+            Some(estimate) => estimate.to_remote(local),
+            None => local,
+        }
+    }
+
+    /// Converts a time of the remote clock into local time, unchanged until the first sample arrived.
+    pub fn to_local(&self, remote: SystemTime) -> SystemTime {
+        match self.estimate() {
+            Some(estimate) => estimate.to_local(remote),
+            None => remote,
+        }
+    }
+}
+
+fn lock(estimator: &Mutex<Estimator>) -> MutexGuard<'_, Estimator> {
+    estimator.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// The remote time is the local time corrected by the estimate
+impl ClockProvider for TimeSync {
+    fn now(&self) -> SystemTime {
+        self.to_remote(SystemTime::now())
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        let skew = self.estimate().map(|estimate| estimate.skew).unwrap_or(0.0);
+        tokio::time::sleep(duration.div_f64((1.0 + skew).max(f64::EPSILON))).await
+    }
+}
+
+/// Serves the time of `clock` on `service`, for clients of [TimeSync::from_service] to estimate it.
+///
+/// Pass the backend itself to serve its ROS time.
+pub async fn advertise_time<R: ServiceProvider>(
+    ros: &R,
+    service: impl ToServiceName + Send,
+    clock: impl ClockProvider + Send + Sync + 'static,
+) -> Result<R::ServiceServer> {
+    ros.advertise_service::<msgs::GetTime, _>(service, move |_| {
+        Ok(msgs::GetTimeResponse {
+            now: clock.now().into(),
+        })
+    })
+    .await
+}
+
+/// The messages of [TimeSync], the time service is roslibrust's own as ROS has no standard one.
+pub mod msgs {
+    use crate::{RosMessageType, RosServiceType};
+    use serde::{Deserialize, Serialize};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Time {
+        pub secs: i32,
+        pub nsecs: i32,
+    }
+
+    impl From<SystemTime> for Time {
+        fn from(time: SystemTime) -> Self {
+            let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+            Self {
+                secs: since_epoch.as_secs() as i32,
+                nsecs: since_epoch.subsec_nanos() as i32,
+            }
+        }
+    }
+
+    impl From<Time> for SystemTime {
+        fn from(time: Time) -> Self {
+            UNIX_EPOCH + Duration::new(time.secs as u64, time.nsecs as u32)
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Clock {
+        pub clock: Time,
+    }
+
+    impl RosMessageType for Clock {
+        const ROS_TYPE_NAME: &'static str = "rosgraph_msgs/Clock";
+        const MD5SUM: &'static str = "a9c97c1d230cfc112e270351a944ee47";
+        const DEFINITION: &'static str = "time clock";
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct GetTimeRequest {}
+
+    impl RosMessageType for GetTimeRequest {
+        const ROS_TYPE_NAME: &'static str = "roslibrust_msgs/GetTimeRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "";
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct GetTimeResponse {
+        pub now: Time,
+    }
+
+    impl RosMessageType for GetTimeResponse {
+        const ROS_TYPE_NAME: &'static str = "roslibrust_msgs/GetTimeResponse";
+        const MD5SUM: &'static str = "aead9c8f9b8febb6c18542787a83309b";
+        const DEFINITION: &'static str = "time now";
+    }
+
+    pub struct GetTime {}
+
+    impl RosServiceType for GetTime {
+        const ROS_SERVICE_NAME: &'static str = "roslibrust_msgs/GetTime";
+        const MD5SUM: &'static str = "aead9c8f9b8febb6c18542787a83309b";
+        type Request = GetTimeRequest;
+        type Response = GetTimeResponse;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Publish;
+    use roslibrust_mock::MockRos;
+    use std::time::UNIX_EPOCH;
+
+    fn at(seconds: f64) -> SystemTime {
+        add_seconds(UNIX_EPOCH + Duration::from_secs(1_700_000_000), seconds)
+    }
+
+    #[test_log::test]
+    fn estimates_offset_and_skew() {
+        let mut estimator = Estimator::new(8);
+        assert!(estimator.estimate().is_none());
+        // The remote clock is 3s behind and gains 1ms every second
+        for i in 0..20 {
+            let local = i as f64;
+            estimator.add(at(local), at(local * 1.001 - 3.0), Duration::ZERO);
+        }
+        let estimate = estimator.estimate().unwrap();
+        assert_eq!(estimate.samples, 8);
+        assert!((estimate.skew - 0.001).abs() < 1e-9, "{estimate:?}");
+        let remote = estimate.to_remote(at(100.0));
+        assert!(seconds_between(remote, at(100.1 - 3.0)).abs() < 1e-6);
+        let local = estimate.to_local(remote);
+        assert!(seconds_between(local, at(100.0)).abs() < 1e-6);
+    }
+
+    #[test_log::test]
+    fn ignores_slow_round_trips() {
+        let mut estimator = Estimator::new(8);
+        for i in 0..4 {
+            estimator.add(at(i as f64), at(i as f64 + 2.0), Duration::from_millis(10));
+        }
+        // Queued on the way back, the remote time read is far older than the local time suggests
+        estimator.add(at(4.0), at(4.0 + 1.5), Duration::from_secs(1));
+        let estimate = estimator.estimate().unwrap();
+        assert_eq!(estimate.samples, 4);
+        assert!((estimate.offset - 2.0).abs() < 1e-9);
+    }
+
+    /// A clock 5 seconds ahead of the local one
+    struct Ahead;
+
+    impl ClockProvider for Ahead {
+        fn now(&self) -> SystemTime {
+            SystemTime::now() + Duration::from_secs(5)
+        }
+
+        async fn sleep(&self, duration: Duration) {
+            tokio::time::sleep(duration).await
+        }
+    }
+
+    fn assert_ahead(sync: &TimeSync) {
+        let ahead = seconds_between(SystemTime::now(), sync.now());
+        assert!((ahead - 5.0).abs() < 0.1, "{ahead}");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn follows_a_time_service() {
+        let ros = MockRos::new();
+        let _server = advertise_time(&ros, "/get_time", Ahead).await.unwrap();
+        let config = TimeSyncConfig::new().interval(Duration::from_millis(10));
+        let sync = TimeSync::from_service(&ros, "/get_time", config)
+            .await
+            .unwrap();
+        while sync.estimate().map_or(0, |estimate| estimate.samples) < 3 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_ahead(&sync);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn follows_a_clock_topic() {
+        let ros = MockRos::new();
+        let clock = ros.advertise::<msgs::Clock>("/clock").await.unwrap();
+        let sync = TimeSync::from_clock(&ros, "/clock", TimeSyncConfig::new())
+            .await
+            .unwrap();
+        // Local time until the first message
+        assert!(sync.estimate().is_none());
+        let local = SystemTime::now();
+        assert_eq!(sync.to_remote(local), local);
+
+        clock
+            .publish(&msgs::Clock {
+                clock: Ahead.now().into(),
+            })
+            .await
+            .unwrap();
+        while sync.estimate().is_none() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_ahead(&sync);
+    }
+}
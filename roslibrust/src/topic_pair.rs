@@ -0,0 +1,247 @@
+//! Calls over a pair of topics, for drivers which predate services and answer requests published on `X/request`
+//! on `X/response`, matched up by an id field, see [TopicPairClient].
+//!
+//! ```no_run
+//! use roslibrust::topic_pair::TopicPairClient;
+//! use roslibrust::Ros;
+//! use roslibrust_test::ros1::std_msgs;
+//! use std::time::Duration;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     // Stands in for a driver's command messages, which carry the id in their header's seq
+//!     let client = TopicPairClient::connect(
+//!         &ros,
+//!         "/gripper",
+//!         |request: &std_msgs::Header| request.seq,
+//!         |response: &std_msgs::Header| response.seq,
+//!     )
+//!     .await?
+//!     .timeout(Duration::from_secs(2));
+//!     let response = client.call(&std_msgs::Header { seq: 7, ..Default::default() }).await?;
+//!     log::info!("Gripper answered {}", response.frame_id);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Error, Publish, Result, RosMessageType, Subscribe, TopicProvider};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::oneshot;
+
+/// The callers waiting for a response by the id of their request, each numbered to tell apart calls reusing an id
+type Pending<K, Resp> = Arc<Mutex<HashMap<K, (u64, oneshot::Sender<Resp>)>>>;
+
+/// Publishes requests and waits for the response carrying the same id, as if calling a service.
+///
+/// The ids are read from the messages with the functions given on creation, the caller is responsible for giving
+/// each request in flight an id of its own. Responses nobody is waiting for, e.g. to requests which timed out or
+/// were sent by other nodes, are ignored. Any number of calls may be in flight at once.
+pub struct TopicPairClient<Req, Resp, K, P> {
+    publisher: P,
+    request_id: fn(&Req) -> K,
+    pending: Pending<K, Resp>,
+    calls: AtomicU64,
+    timeout: Duration,
+    _task: ChildTask<()>,
+}
+
+impl<Req, Resp, K, P> TopicPairClient<Req, Resp, K, P>
+where
+    Req: RosMessageType,
+    Resp: RosMessageType,
+    K: Eq + Hash + Clone + Send + 'static,
+    P: Publish<Req>,
+{
+    /// Publishes requests on `{topic}/request` and receives responses on `{topic}/response` on `ros`.
+    pub async fn connect<R>(
+        ros: &R,
+        topic: &str,
+        request_id: fn(&Req) -> K,
+        response_id: fn(&Resp) -> K,
+    ) -> Result<Self>
+    where
+        R: TopicProvider<Publisher<Req> = P>,
+    {
+        let topic = topic.trim_end_matches('/');
+        // Subscribe first so no response to an early request is missed
+        let subscriber = ros.subscribe::<Resp>(format!("{topic}/response")).await?;
+        let publisher = ros.advertise::<Req>(format!("{topic}/request")).await?;
+        Ok(Self::new(publisher, subscriber, request_id, response_id))
+    }
+
+    /// Publishes requests with `publisher` and receives responses with `subscriber`.
+    pub fn new(
+        publisher: P,
+        mut subscriber: impl Subscribe<Resp> + Send + 'static,
+        request_id: fn(&Req) -> K,
+        response_id: fn(&Resp) -> K,
+    ) -> Self {
+        let pending: Pending<K, Resp> = Arc::default();
+        let waiting = pending.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                let response = match subscriber.next().await {
+                    Ok(response) => response,
+                    Err(e) => {
+                        // Dropping the senders fails the calls in flight
+                        warn!("Receiving responses failed, no more calls can be answered: {e}");
+                        waiting.lock().unwrap().clear();
+                        return;
+                    }
+                };
+                let caller = waiting.lock().unwrap().remove(&response_id(&response));
+                match caller {
+                    // The caller may have just given up, which leaves nothing to do
+                    Some((_, caller)) => {
+                        let _ = caller.send(response);
+                    }
+                    None => trace!("Ignoring a response nobody is waiting for"),
+                }
+            }
+        });
+        Self {
+            publisher,
+            request_id,
+            pending,
+            calls: AtomicU64::new(0),
+            timeout: Duration::from_secs(5),
+            _task: task.into(),
+        }
+    }
+
+    /// Fails calls which haven't been answered after `timeout` with [Error::Timeout], instead of after 5 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Publishes `request` and waits for the response with its id.
+    ///
+    /// Fails with [Error::Unexpected] if a call with the same id is already in flight, and with
+    /// [Error::Disconnected] if the responses can no longer be received.
+    pub async fn call(&self, request: &Req) -> Result<Resp> {
+        let (sender, receiver) = oneshot::channel();
+        let id = (self.request_id)(request);
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+        {
+            let mut pending = self.pending.lock().unwrap();
+            if pending.contains_key(&id) {
+                return Err(Error::Unexpected(anyhow::anyhow!(
+                    "A call with the id of this request is already waiting for its response"
+                )));
+            }
+            pending.insert(id.clone(), (call, sender));
+        }
+        // Stops waiting when the call completes, fails or is cancelled
+        let _waiting = Waiting {
+            pending: &self.pending,
+            id,
+            call,
+        };
+        self.publisher.publish(request).await?;
+        match tokio::time::timeout(self.timeout, receiver).await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(_)) => Err(Error::Disconnected),
+            Err(_) => Err(Error::Timeout(format!(
+                "No response within {:?}",
+                self.timeout
+            ))),
+        }
+    }
+}
+
+/// Removes a caller from the pending calls when dropped
+struct Waiting<'a, K: Eq + Hash, Resp> {
+    pending: &'a Pending<K, Resp>,
+    id: K,
+    call: u64,
+}
+
+impl<K: Eq + Hash, Resp> Drop for Waiting<'_, K, Resp> {
+    fn drop(&mut self) {
+        let mut pending = self.pending.lock().unwrap();
+        // Once answered the id may already be in use by a new call
+        if pending
+            .get(&self.id)
+            .is_some_and(|(call, _)| *call == self.call)
+        {
+            pending.remove(&self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+
+    fn seq(header: &std_msgs::Header) -> u32 {
+        header.seq
+    }
+
+    fn header(seq: u32, frame_id: &str) -> std_msgs::Header {
+        std_msgs::Header {
+            seq,
+            frame_id: frame_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn matches_responses_to_requests() {
+        let ros = MockRos::new();
+        let client = TopicPairClient::connect(&ros, "/driver", seq, seq)
+            .await
+            .unwrap()
+            .timeout(Duration::from_millis(500));
+
+        // Answers requests in reverse order, ignoring the request with seq 0
+        let mut requests = ros
+            .subscribe::<std_msgs::Header>("/driver/request")
+            .await
+            .unwrap();
+        let responses = ros
+            .advertise::<std_msgs::Header>("/driver/response")
+            .await
+            .unwrap();
+        let _driver = ChildTask::from(tokio::spawn(async move {
+            let mut received = vec![];
+            while let Ok(request) = requests.next().await {
+                if request.seq == 0 {
+                    continue;
+                }
+                received.push(request);
+                if received.len() == 2 {
+                    for request in received.drain(..).rev() {
+                        let frame_id = format!("done {}", request.frame_id);
+                        responses
+                            .publish(&header(request.seq, &frame_id))
+                            .await
+                            .unwrap();
+                    }
+                }
+            }
+        }));
+
+        let (open, close) = (header(1, "open"), header(2, "close"));
+        let (first, second) = tokio::join!(client.call(&open), client.call(&close));
+        assert_eq!(first.unwrap().frame_id, "done open");
+        assert_eq!(second.unwrap().frame_id, "done close");
+
+        assert!(matches!(
+            client.call(&header(0, "ignored")).await,
+            Err(Error::Timeout(_))
+        ));
+        assert!(client.pending.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,510 @@
+//! Converts messages between the JSON of rosbridge and the binary format of ROS1, for gateways and log converters
+//! which pass messages between the two without handling them, see [json_to_ros1] and [Transcoder].
+//!
+//! Messages of a type known at compile time are converted through the generated type with [json_to_ros1] and
+//! [ros1_to_json]. Messages of a type only known at runtime, e.g. from the connection header of a ROS1 publisher or
+//! the message definition stored in a bag, are converted with a [Transcoder] built from their [MessageSchema].
+//!
+//! The binary format includes the length of the message at the front, as sent over TCPROS and returned by
+//! `serde_rosmsg::to_vec`. In JSON, `time` and `duration` are objects with `secs` and `nsecs` members as in ROS1's
+//! rosbridge, and byte arrays are arrays of numbers, though a [Transcoder] also accepts the base64 strings
+//! rosbridge_server sends them as.
+//!
+//! ```
+//! use roslibrust::schema::MessageSchema;
+//! use roslibrust::transcode::{json_to_ros1, Transcoder};
+//! use roslibrust_test::ros1::std_msgs;
+//! use serde_json::json;
+//!
+//! let json = json!({"seq": 1, "stamp": {"secs": 2, "nsecs": 3}, "frame_id": "map"});
+//! let bytes = json_to_ros1::<std_msgs::Header>(&json).unwrap();
+//!
+//! // The same, for a type only known at runtime
+//! let schema = MessageSchema::new("std_msgs/Header", "uint32 seq\ntime stamp\nstring frame_id");
+//! let transcoder = Transcoder::new(schema).unwrap();
+//! assert_eq!(transcoder.json_to_ros1(&json).unwrap(), bytes);
+//! assert_eq!(transcoder.ros1_to_json(&bytes).unwrap(), json);
+//! ```
+
+use crate::{
+    ros1_codec::{DecodeError, Reader, Ros1Decode, Ros1Encode},
+    schema::{MessageSchema, SchemaField},
+    Error, Result, RosMessageType,
+};
+use serde_json::{Map, Number, Value};
+
+/// Converts a message from JSON to the ROS1 format through its generated type, filling in missing fields with
+/// their defaults as the type's deserialization does.
+pub fn json_to_ros1<T: RosMessageType>(json: &Value) -> Result<Vec<u8>> {
+    let msg = T::deserialize(json).map_err(|e| {
+        Error::SerializationError(format!(
+            "Failed to read {} from JSON: {e}",
+            T::ROS_TYPE_NAME
+        ))
+    })?;
+    // As the ROS1 backend does, with the generated encoder if the type has one
+    match msg.ros1_encode() {
+        Some(bytes) => Ok(bytes),
+        None => roslibrust_serde_rosmsg::to_vec(&msg)
+            .map_err(|e| Error::SerializationError(e.to_string())),
+    }
+}
+
+/// Converts a message from the ROS1 format to JSON through its generated type.
+pub fn ros1_to_json<T: RosMessageType>(data: &[u8]) -> Result<Value> {
+    let msg: T = match T::ros1_decode(data) {
+        Some(msg) => msg.map_err(decode_error)?,
+        None => roslibrust_serde_rosmsg::from_slice(data)
+            .map_err(|e| Error::SerializationError(e.to_string()))?,
+    };
+    serde_json::to_value(&msg).map_err(|e| Error::SerializationError(e.to_string()))
+}
+
+fn decode_error(e: DecodeError) -> Error {
+    Error::SerializationError(e.to_string())
+}
+
+/// Converts messages of one type between JSON and the ROS1 format by the fields listed in its [MessageSchema].
+///
+/// Constants are left out of both formats. When converting from JSON, missing fields are filled in with zeros,
+/// empty strings and empty arrays, or arrays of such for fixed size arrays, and members which aren't fields are
+/// ignored. Values of the wrong type, e.g. a number out of the range of its field, are an error naming the field.
+#[derive(Clone, Debug)]
+pub struct Transcoder {
+    schema: MessageSchema,
+}
+
+impl Transcoder {
+    /// Creates a transcoder for the type of `schema`, which must include the fields of every message it contains.
+    pub fn new(schema: MessageSchema) -> Result<Self> {
+        if schema.fields().is_none() {
+            return Err(Error::SerializationError(format!(
+                "The definition of {} is unknown",
+                schema.type_name
+            )));
+        }
+        for (type_name, fields) in &schema.messages {
+            for field in fields.iter().filter(|field| field.constant.is_none()) {
+                let element = element_type(&field.field_type);
+                if !is_builtin(element) && !schema.messages.contains_key(element) {
+                    return Err(Error::SerializationError(format!(
+                        "The definition of {type_name} uses {element}, whose definition is missing"
+                    )));
+                }
+            }
+        }
+        Ok(Self { schema })
+    }
+
+    /// Creates a transcoder for a compiled-in type, for converting its messages without going through the type.
+    pub fn of<T: RosMessageType>() -> Result<Self> {
+        Self::new(MessageSchema::of::<T>())
+    }
+
+    /// The schema of the type converted
+    pub fn schema(&self) -> &MessageSchema {
+        &self.schema
+    }
+
+    /// Converts a message from JSON to the ROS1 format, including its length at the front.
+    pub fn json_to_ros1(&self, json: &Value) -> Result<Vec<u8>> {
+        // The length is filled in once known
+        let mut buf = vec![0; 4];
+        self.encode_message(&self.schema.type_name, Some(json), "", &mut buf)?;
+        let len = (buf.len() - 4) as u32;
+        buf[..4].copy_from_slice(&len.to_le_bytes());
+        Ok(buf)
+    }
+
+    /// Converts a message from the ROS1 format, including its length at the front, to JSON.
+    pub fn ros1_to_json(&self, data: &[u8]) -> Result<Value> {
+        let mut reader = Reader::new(data);
+        let len = reader.read_len().map_err(decode_error)?;
+        let mut reader = Reader::new(reader.take(len).map_err(decode_error)?);
+        let json = self.decode_message(&self.schema.type_name, &mut reader)?;
+        if reader.remaining() > 0 {
+            return Err(decode_error(DecodeError::Underflow(reader.remaining())));
+        }
+        Ok(json)
+    }
+
+    fn fields(&self, type_name: &str) -> impl Iterator<Item = &SchemaField> {
+        // Checked to be present by new()
+        self.schema.messages[type_name]
+            .iter()
+            .filter(|field| field.constant.is_none())
+    }
+
+    fn encode_message(
+        &self,
+        type_name: &str,
+        json: Option<&Value>,
+        path: &str,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        let members = match json {
+            None | Some(Value::Null) => None,
+            Some(Value::Object(members)) => Some(members),
+            Some(_) => return Err(wrong_type(path, type_name)),
+        };
+        for field in self.fields(type_name) {
+            let value = members.and_then(|members| members.get(&field.name));
+            let path = join(path, &field.name);
+            self.encode_field(&field.field_type, value, &path, buf)?;
+        }
+        Ok(())
+    }
+
+    fn encode_field(
+        &self,
+        field_type: &str,
+        value: Option<&Value>,
+        path: &str,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        let value = value.filter(|value| !value.is_null());
+        if let Some((element, len)) = array(field_type) {
+            return self.encode_array(element, len, value, path, buf);
+        }
+        match field_type {
+            "bool" => match value {
+                None => false.encode(buf),
+                Some(Value::Bool(value)) => value.encode(buf),
+                Some(_) => return Err(wrong_type(path, field_type)),
+            },
+            "int8" | "byte" => integer::<i8>(value, path, field_type)?.encode(buf),
+            "uint8" | "char" => integer::<u8>(value, path, field_type)?.encode(buf),
+            "int16" => integer::<i16>(value, path, field_type)?.encode(buf),
+            "uint16" => integer::<u16>(value, path, field_type)?.encode(buf),
+            "int32" => integer::<i32>(value, path, field_type)?.encode(buf),
+            "uint32" => integer::<u32>(value, path, field_type)?.encode(buf),
+            "int64" => integer::<i64>(value, path, field_type)?.encode(buf),
+            "uint64" => integer::<u64>(value, path, field_type)?.encode(buf),
+            "float32" => (float(value, path, field_type)? as f32).encode(buf),
+            "float64" => float(value, path, field_type)?.encode(buf),
+            "string" | "wstring" => match value {
+                None => String::new().encode(buf),
+                Some(Value::String(value)) => value.encode(buf),
+                Some(_) => return Err(wrong_type(path, field_type)),
+            },
+            "time" => {
+                let (secs, nsecs) = stamp(value, path, field_type)?;
+                u32::try_from(secs)
+                    .map_err(|_| wrong_type(path, field_type))?
+                    .encode(buf);
+                u32::try_from(nsecs)
+                    .map_err(|_| wrong_type(path, field_type))?
+                    .encode(buf);
+            }
+            "duration" => {
+                let (secs, nsecs) = stamp(value, path, field_type)?;
+                i32::try_from(secs)
+                    .map_err(|_| wrong_type(path, field_type))?
+                    .encode(buf);
+                i32::try_from(nsecs)
+                    .map_err(|_| wrong_type(path, field_type))?
+                    .encode(buf);
+            }
+            message => self.encode_message(message, value, path, buf)?,
+        }
+        Ok(())
+    }
+
+    fn encode_array(
+        &self,
+        element: &str,
+        len: Option<usize>,
+        value: Option<&Value>,
+        path: &str,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        // rosbridge_server sends byte arrays as base64
+        if let (Some(Value::String(encoded)), "uint8" | "char") = (value, element) {
+            let bytes = base64(encoded).ok_or_else(|| wrong_type(path, element))?;
+            match len {
+                Some(len) if bytes.len() != len => return Err(wrong_len(path, len)),
+                Some(_) => {}
+                None => (bytes.len() as u32).encode(buf),
+            }
+            buf.extend_from_slice(&bytes);
+            return Ok(());
+        }
+        let elements = match value {
+            None => &[][..],
+            Some(Value::Array(elements)) => elements.as_slice(),
+            Some(_) => return Err(wrong_type(path, element)),
+        };
+        match len {
+            Some(len) if !elements.is_empty() && elements.len() != len => {
+                return Err(wrong_len(path, len))
+            }
+            // Missing fixed size arrays are filled with defaults
+            Some(len) => {
+                for i in 0..len {
+                    let path = join(path, &i.to_string());
+                    self.encode_field(element, elements.get(i), &path, buf)?;
+                }
+            }
+            None => {
+                (elements.len() as u32).encode(buf);
+                for (i, value) in elements.iter().enumerate() {
+                    let path = join(path, &i.to_string());
+                    self.encode_field(element, Some(value), &path, buf)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn decode_message(&self, type_name: &str, reader: &mut Reader<'_>) -> Result<Value> {
+        let mut members = Map::new();
+        for field in self.fields(type_name) {
+            let value = self.decode_field(&field.field_type, reader)?;
+            members.insert(field.name.clone(), value);
+        }
+        Ok(Value::Object(members))
+    }
+
+    fn decode_field(&self, field_type: &str, reader: &mut Reader<'_>) -> Result<Value> {
+        if let Some((element, len)) = array(field_type) {
+            let len = match len {
+                Some(len) => len,
+                None => reader.read_len().map_err(decode_error)?,
+            };
+            if matches!(element, "uint8" | "char") {
+                let bytes = reader.take(len).map_err(decode_error)?;
+                return Ok(Value::Array(bytes.iter().map(|&b| b.into()).collect()));
+            }
+            let elements = (0..len)
+                .map(|_| self.decode_field(element, reader))
+                .collect::<Result<_>>()?;
+            return Ok(Value::Array(elements));
+        }
+        let value = match field_type {
+            "bool" => bool::decode(reader).map(Value::from),
+            "int8" | "byte" => i8::decode(reader).map(Value::from),
+            "uint8" | "char" => u8::decode(reader).map(Value::from),
+            "int16" => i16::decode(reader).map(Value::from),
+            "uint16" => u16::decode(reader).map(Value::from),
+            "int32" => i32::decode(reader).map(Value::from),
+            "uint32" => u32::decode(reader).map(Value::from),
+            "int64" => i64::decode(reader).map(Value::from),
+            "uint64" => u64::decode(reader).map(Value::from),
+            // JSON has no NaN or infinity, serde_json writes them as null too
+            "float32" => f32::decode(reader).map(|f| from_float(f.into())),
+            "float64" => f64::decode(reader).map(from_float),
+            "string" | "wstring" => String::decode(reader).map(Value::from),
+            "time" => decode_stamp::<u32>(reader),
+            "duration" => decode_stamp::<i32>(reader),
+            message => return self.decode_message(message, reader),
+        };
+        value.map_err(decode_error)
+    }
+}
+
+/// The base types of ROS1 messages, whose fields aren't listed in a schema
+fn is_builtin(field_type: &str) -> bool {
+    matches!(
+        field_type,
+        "bool"
+            | "byte"
+            | "char"
+            | "int8"
+            | "int16"
+            | "int32"
+            | "int64"
+            | "uint8"
+            | "uint16"
+            | "uint32"
+            | "uint64"
+            | "float32"
+            | "float64"
+            | "time"
+            | "duration"
+            | "string"
+            | "wstring"
+    )
+}
+
+fn element_type(field_type: &str) -> &str {
+    field_type.split('[').next().unwrap_or_default()
+}
+
+/// The element type of an array and its length if fixed, e.g. ("float64", Some(3)) for `float64[3]`
+fn array(field_type: &str) -> Option<(&str, Option<usize>)> {
+    let (element, len) = field_type.strip_suffix(']')?.split_once('[')?;
+    Some((element, len.parse().ok()))
+}
+
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+fn wrong_type(path: &str, field_type: &str) -> Error {
+    Error::SerializationError(format!("Field {path} isn't a valid {field_type}"))
+}
+
+fn wrong_len(path: &str, len: usize) -> Error {
+    Error::SerializationError(format!("Field {path} must have {len} elements"))
+}
+
+fn integer<T: TryFrom<i64> + TryFrom<u64> + Default>(
+    value: Option<&Value>,
+    path: &str,
+    field_type: &str,
+) -> Result<T> {
+    let Some(value) = value else {
+        return Ok(T::default());
+    };
+    let integer = match (value.as_u64(), value.as_i64()) {
+        (Some(value), _) => T::try_from(value).ok(),
+        (None, Some(value)) => T::try_from(value).ok(),
+        (None, None) => None,
+    };
+    integer.ok_or_else(|| wrong_type(path, field_type))
+}
+
+fn float(value: Option<&Value>, path: &str, field_type: &str) -> Result<f64> {
+    match value {
+        None => Ok(0.0),
+        Some(value) => value.as_f64().ok_or_else(|| wrong_type(path, field_type)),
+    }
+}
+
+fn from_float(value: f64) -> Value {
+    Number::from_f64(value).map_or(Value::Null, Value::Number)
+}
+
+/// The seconds and nanoseconds of a time or duration, accepting the names of the members in ROS2 as well
+fn stamp(value: Option<&Value>, path: &str, field_type: &str) -> Result<(i64, i64)> {
+    let Some(value) = value else {
+        return Ok((0, 0));
+    };
+    let Value::Object(members) = value else {
+        return Err(wrong_type(path, field_type));
+    };
+    let member = |names: &[&str]| -> Result<i64> {
+        let value = names.iter().find_map(|name| members.get(*name));
+        integer(value, path, field_type)
+    };
+    Ok((
+        member(&["secs", "sec"])?,
+        member(&["nsecs", "nsec", "nanosec"])?,
+    ))
+}
+
+fn decode_stamp<T: Ros1Decode + Into<Value>>(
+    reader: &mut Reader<'_>,
+) -> std::result::Result<Value, DecodeError> {
+    let secs = T::decode(reader)?;
+    let nsecs = T::decode(reader)?;
+    let mut members = Map::new();
+    members.insert("secs".to_string(), secs.into());
+    members.insert("nsecs".to_string(), nsecs.into());
+    Ok(Value::Object(members))
+}
+
+/// Decodes standard base64 with padding, None if `encoded` isn't valid base64
+fn base64(encoded: &str) -> Option<Vec<u8>> {
+    fn sextet(c: u8) -> Option<u32> {
+        Some(match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        } as u32)
+    }
+    let encoded = encoded.as_bytes();
+    if !encoded.len().is_multiple_of(4) {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(encoded.len() / 4 * 3);
+    for chunk in encoded.chunks(4) {
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 {
+            return None;
+        }
+        let mut group = 0;
+        for &c in &chunk[..4 - padding] {
+            group = (group << 6) | sextet(c)?;
+        }
+        group <<= 6 * padding;
+        bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_test::ros1::std_msgs;
+    use serde_json::json;
+
+    #[test]
+    fn typed_and_dynamic_agree() {
+        let json = json!({"seq": 7, "stamp": {"secs": 1, "nsecs": 500}, "frame_id": "base_link"});
+        let bytes = json_to_ros1::<std_msgs::Header>(&json).unwrap();
+        let transcoder = Transcoder::of::<std_msgs::Header>().unwrap();
+        assert_eq!(transcoder.json_to_ros1(&json).unwrap(), bytes);
+        assert_eq!(transcoder.ros1_to_json(&bytes).unwrap(), json);
+        assert_eq!(ros1_to_json::<std_msgs::Header>(&bytes).unwrap(), json);
+    }
+
+    #[test]
+    fn converts_arrays_and_defaults() {
+        let schema = MessageSchema::new(
+            "test_msgs/Blob",
+            "uint8 KIND=1\nuint8[] data\nfloat64[2] pair\nstring name\nPoint[] points\n\
+             ================================================================================\n\
+             MSG: test_msgs/Point\nint32 x\nint32 y",
+        );
+        let transcoder = Transcoder::new(schema).unwrap();
+        // Base64 bytes, and missing fields
+        let bytes = transcoder
+            .json_to_ros1(&json!({"data": "AQID", "points": [{"x": -1}], "unknown": true}))
+            .unwrap();
+        #[rustfmt::skip]
+        let expected = [
+            // Length of the message
+            39, 0, 0, 0,
+            3, 0, 0, 0, 1, 2, 3,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+            1, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0,
+        ];
+        assert_eq!(bytes, expected);
+        assert_eq!(
+            transcoder.ros1_to_json(&bytes).unwrap(),
+            json!({
+                "data": [1, 2, 3],
+                "pair": [0.0, 0.0],
+                "name": "",
+                "points": [{"x": -1, "y": 0}],
+            })
+        );
+
+        assert!(transcoder.json_to_ros1(&json!({"name": 5})).is_err());
+        assert!(transcoder.json_to_ros1(&json!({"pair": [1.0]})).is_err());
+        assert!(transcoder
+            .json_to_ros1(&json!({"points": [{"x": 1u64 << 40}]}))
+            .is_err());
+        assert!(transcoder.ros1_to_json(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Transcoder::new(MessageSchema::new("test_msgs/Blob", "Missing thing")).is_err());
+    }
+
+    #[test]
+    fn decodes_base64() {
+        assert_eq!(base64("AQID").unwrap(), [1, 2, 3]);
+        assert_eq!(base64("aGk=").unwrap(), b"hi");
+        assert_eq!(base64("aA==").unwrap(), b"h");
+        assert_eq!(base64("").unwrap(), b"");
+        assert!(base64("aA=").is_none());
+        assert!(base64("a!==").is_none());
+    }
+}
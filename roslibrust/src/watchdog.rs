@@ -0,0 +1,190 @@
+//! Detects topics going silent, e.g. a sensor which stopped publishing, see [WatchedSubscriber].
+//!
+//! ```no_run
+//! use roslibrust::watchdog::{Watched, WatchedSubscriber};
+//! use roslibrust::Ros;
+//! use roslibrust_test::ros1::sensor_msgs;
+//! use std::time::Duration;
+//!
+//! async fn run(ros: impl Ros) -> roslibrust::Result<()> {
+//!     let subscriber = ros.subscribe::<sensor_msgs::LaserScan>("/scan").await?;
+//!     let mut scans = WatchedSubscriber::new(subscriber, Duration::from_millis(500));
+//!     loop {
+//!         match scans.next_event().await? {
+//!             Watched::Message(scan) => log::info!("Got {} ranges", scan.ranges.len()),
+//!             Watched::Stale { since } => log::warn!("No scan for {since:?}, stopping"),
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::{Result, RosMessageType, Subscribe};
+use std::{marker::PhantomData, time::Duration};
+use tokio::time::Instant;
+
+type Callback = Box<dyn FnMut(Duration) + Send + 'static>;
+
+/// What [WatchedSubscriber::next_event] returns.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Watched<T> {
+    /// A message was received
+    Message(T),
+    /// No message was received within the timeout, returned once each time the topic goes silent
+    Stale {
+        /// How long it has been since the last message, or since the subscriber was created
+        since: Duration,
+    },
+}
+
+/// Wraps a subscriber to notice when no message arrives within a timeout.
+///
+/// The topic goes stale when no message was received within the timeout, counting from the last message or from
+/// when the subscriber was wrapped, and recovers when the next message arrives. Going stale is reported as a
+/// [Watched::Stale] event by [WatchedSubscriber::next_event] and to the [WatchedSubscriber::on_stale] callback,
+/// recovering to the [WatchedSubscriber::on_recovered] callback. Used as a [Subscribe], only messages are returned
+/// and staleness is only reported to the callbacks.
+///
+/// The wrapped subscriber's `next` must be cancel safe, as it is for all of roslibrust's backends.
+pub struct WatchedSubscriber<S, T> {
+    inner: S,
+    timeout: Duration,
+    last_message: Option<Instant>,
+    // When the topic last went quiet, the last message or when the subscriber was wrapped
+    quiet_since: Instant,
+    stale: bool,
+    on_stale: Option<Callback>,
+    on_recovered: Option<Callback>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<S: Subscribe<T>, T: RosMessageType> WatchedSubscriber<S, T> {
+    /// Wraps `inner`, reporting its topic as stale when no message arrives within `timeout`.
+    pub fn new(inner: S, timeout: Duration) -> Self {
+        Self {
+            inner,
+            timeout,
+            last_message: None,
+            quiet_since: Instant::now(),
+            stale: false,
+            on_stale: None,
+            on_recovered: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Calls `callback` with the time since the last message each time the topic goes stale.
+    pub fn on_stale(mut self, callback: impl FnMut(Duration) + Send + 'static) -> Self {
+        self.on_stale = Some(Box::new(callback));
+        self
+    }
+
+    /// Calls `callback` with how long the topic was silent each time a message arrives after it went stale.
+    pub fn on_recovered(mut self, callback: impl FnMut(Duration) + Send + 'static) -> Self {
+        self.on_recovered = Some(Box::new(callback));
+        self
+    }
+
+    /// True from when the topic goes stale until the next message arrives.
+    pub fn is_stale(&self) -> bool {
+        self.stale
+    }
+
+    /// When the last message was received, None if none were.
+    pub fn last_message(&self) -> Option<Instant> {
+        self.last_message
+    }
+
+    /// Returns the next message, or [Watched::Stale] if the topic goes stale first.
+    pub async fn next_event(&mut self) -> Result<Watched<T>> {
+        if self.stale {
+            // Already reported, so only a message can come next
+            let msg = self.inner.next().await?;
+            return Ok(Watched::Message(self.received(msg)));
+        }
+        let deadline = self.quiet_since + self.timeout;
+        tokio::select! {
+            msg = self.inner.next() => Ok(Watched::Message(self.received(msg?))),
+            _ = tokio::time::sleep_until(deadline) => {
+                self.stale = true;
+                let since = self.quiet_since.elapsed();
+                if let Some(on_stale) = &mut self.on_stale {
+                    on_stale(since);
+                }
+                Ok(Watched::Stale { since })
+            }
+        }
+    }
+
+    /// Returns the wrapped subscriber.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn received(&mut self, msg: T) -> T {
+        let now = Instant::now();
+        if self.stale {
+            self.stale = false;
+            if let Some(on_recovered) = &mut self.on_recovered {
+                on_recovered(now.duration_since(self.quiet_since));
+            }
+        }
+        self.last_message = Some(now);
+        self.quiet_since = now;
+        msg
+    }
+}
+
+impl<S: Subscribe<T> + Send, T: RosMessageType> Subscribe<T> for WatchedSubscriber<S, T> {
+    async fn next(&mut self) -> Result<T> {
+        loop {
+            if let Watched::Message(msg) = self.next_event().await? {
+                return Ok(msg);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Publish, TopicProvider};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::std_msgs;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reports_stale_topics() {
+        let ros = MockRos::new();
+        let publisher = ros.advertise::<std_msgs::String>("/data").await.unwrap();
+        let subscriber = ros.subscribe::<std_msgs::String>("/data").await.unwrap();
+        let events = Arc::new(Mutex::new(vec![]));
+        let mut watched = WatchedSubscriber::new(subscriber, Duration::from_millis(50))
+            .on_stale({
+                let events = events.clone();
+                move |_| events.lock().unwrap().push("stale")
+            })
+            .on_recovered({
+                let events = events.clone();
+                move |_| events.lock().unwrap().push("recovered")
+            });
+
+        // Silent from the start
+        let event = watched.next_event().await.unwrap();
+        assert!(matches!(event, Watched::Stale { since } if since >= Duration::from_millis(50)));
+        assert!(watched.is_stale());
+
+        let msg = std_msgs::String {
+            data: "hello".to_string(),
+        };
+        publisher.publish(&msg).await.unwrap();
+        assert_eq!(watched.next_event().await.unwrap(), Watched::Message(msg));
+        assert!(!watched.is_stale());
+        assert!(watched.last_message().is_some());
+
+        // Goes stale again once messages stop, without returning the event when used as a Subscribe
+        let next = tokio::time::timeout(Duration::from_millis(200), watched.next()).await;
+        assert!(next.is_err());
+        assert!(watched.is_stale());
+        assert_eq!(*events.lock().unwrap(), ["stale", "recovered", "stale"]);
+    }
+}
@@ -72,6 +72,73 @@ mod tests {
         assert!(res == vec![8, 0, 0, 0, 4, 0, 0, 0, 116, 101, 115, 116]);
     }
 
+    #[test_log::test(tokio::test)]
+    async fn test_check_topic_schema() {
+        let nh = NodeHandle::new("http://localhost:11311", "test_check_topic_schema")
+            .await
+            .unwrap();
+
+        let _publisher = nh
+            .advertise::<std_msgs::Header>("/test_check_topic_schema", 1, false)
+            .await
+            .unwrap();
+
+        let reports = nh
+            .check_topic_schema::<std_msgs::Header>("/test_check_topic_schema")
+            .await
+            .unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].0, "/test_check_topic_schema");
+        assert!(reports[0].1.is_compatible(), "{}", reports[0].1);
+
+        let reports = nh
+            .check_topic_schema::<std_msgs::String>("/test_check_topic_schema")
+            .await
+            .unwrap();
+        assert!(!reports[0].1.is_compatible());
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn test_connections() {
+        let nh = NodeHandle::new("http://localhost:11311", "test_connections")
+            .await
+            .unwrap();
+        let publisher = nh
+            .advertise::<std_msgs::String>("/test_connections", 1, false)
+            .await
+            .unwrap();
+        let mut subscriber = nh
+            .subscribe::<std_msgs::String>("/test_connections", 1)
+            .await
+            .unwrap();
+        // Publish until the subscriber has connected
+        loop {
+            publisher
+                .publish(&std_msgs::String {
+                    data: "test".to_owned(),
+                })
+                .await
+                .unwrap();
+            let next = timeout(tokio::time::Duration::from_millis(50), subscriber.next()).await;
+            if next.is_ok() {
+                break;
+            }
+        }
+
+        // The node is connected to itself, once in each direction
+        let connections = nh.connections();
+        assert_eq!(connections.len(), 2, "{connections:?}");
+        for connection in &connections {
+            assert_eq!(connection.topic, "/test_connections");
+            assert_eq!(connection.remote_header.caller_id, "/test_connections");
+            assert_eq!(connection.remote_header.topic_type, "std_msgs/String");
+            assert!(connection.messages >= 1);
+        }
+        let directions: Vec<_> = connections.iter().map(|c| c.direction).collect();
+        assert!(directions.contains(&roslibrust::ros1::Direction::Inbound));
+        assert!(directions.contains(&roslibrust::ros1::Direction::Outbound));
+    }
+
     #[test_log::test(tokio::test)]
     async fn test_latching() {
         let nh = NodeHandle::new("http://localhost:11311", "test_latching")
@@ -319,6 +386,60 @@ mod tests {
         debug!("Got 3");
     }
 
+    #[test_log::test(tokio::test)]
+    async fn service_any() {
+        const TIMEOUT: std::time::Duration = std::time::Duration::from_secs(1);
+        let nh = NodeHandle::new("http://localhost:11311", "/service_any")
+            .await
+            .unwrap();
+        let srv_definition = roslibrust::definitions::ServiceDefinition::parse(
+            "test_msgs/AddTwoInts",
+            "int64 a\nint64 b\n---\nint64 sum",
+        )
+        .unwrap();
+
+        // Serve the raw bytes: the body length, then a and b
+        let server_fn = |request: Vec<u8>| {
+            let a = i64::from_le_bytes(request[4..12].try_into().unwrap());
+            let b = i64::from_le_bytes(request[12..20].try_into().unwrap());
+            let mut response = 8u32.to_le_bytes().to_vec();
+            response.extend_from_slice(&(a + b).to_le_bytes());
+            Ok(response)
+        };
+        let _handle = nh
+            .advertise_service_any("/service_any/add_two", &srv_definition, server_fn)
+            .await
+            .unwrap();
+
+        // Generated clients can call it
+        let client = nh
+            .service_client::<test_msgs::AddTwoInts>("/service_any/add_two")
+            .await
+            .unwrap();
+        let call = timeout(
+            TIMEOUT,
+            client.call(&test_msgs::AddTwoIntsRequest { a: 1, b: 2 }),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(call.sum, 3);
+
+        // And so can raw clients
+        let client = nh
+            .service_client_any("/service_any/add_two", &srv_definition)
+            .await
+            .unwrap();
+        let mut request = 16u32.to_le_bytes().to_vec();
+        request.extend_from_slice(&2i64.to_le_bytes());
+        request.extend_from_slice(&3i64.to_le_bytes());
+        let response = timeout(TIMEOUT, client.call(&request))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(response[4..], 5i64.to_le_bytes());
+    }
+
     #[test_log::test(tokio::test)]
     async fn dropping_service_server_kill_correctly() {
         debug!("Getting node handle");
@@ -601,4 +722,39 @@ mod tests {
         assert!(!data.is_subscribed("/test_cleanup_sub", "/test_node_cleanup"));
         assert!(!data.is_service_provider("/test_cleanup_srv", "/test_node_cleanup"));
     }
+
+    #[test_log::test(tokio::test)]
+    async fn test_remapping() {
+        let nh = NodeHandle::new("http://localhost:11311", "test_remapping")
+            .await
+            .unwrap();
+        nh.remap("/test_remap_in", "/test_remap_out").unwrap();
+        assert!(nh.remap("bad name", "/test_remap_out").is_err());
+
+        // Advertised under the remapped name
+        let publisher = nh
+            .advertise::<std_msgs::String>("/test_remap_in", 1, true)
+            .await
+            .unwrap();
+        publisher
+            .publish(&std_msgs::String {
+                data: "remapped".to_owned(),
+            })
+            .await
+            .unwrap();
+
+        let other = NodeHandle::new("http://localhost:11311", "test_remapping_other")
+            .await
+            .unwrap();
+        let mut subscriber = other
+            .subscribe::<std_msgs::String>("/test_remap_out", 1)
+            .await
+            .unwrap();
+        let msg = timeout(tokio::time::Duration::from_secs(1), subscriber.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg.data, "remapped");
+    }
 }
@@ -0,0 +1,109 @@
+//! Serde helpers used by generated code to enforce the bounds of ROS2 bounded strings and sequences.
+//!
+//! Bounded fields are generated with the same Rust types as their unbounded equivalents, e.g. `string<=10` is
+//! still a [String], and are serialized identically. These helpers are applied with `#[serde(with = ...)]`
+//! so that a value exceeding its bound fails to serialize or deserialize instead of being silently sent.
+//!
+//! The bounds can also be checked directly, e.g. before publishing:
+//! ```
+//! use roslibrust_codegen::{BoundedSequence, BoundedString};
+//! assert!(BoundedString::<5>::check("hello").is_ok());
+//! assert!(BoundedSequence::<2>::check(&[1, 2, 3]).is_err());
+//! ```
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Enforces the bound of a `string<=N` field.
+pub struct BoundedString<const N: usize>;
+
+impl<const N: usize> BoundedString<N> {
+    /// Returns an error message if `value` is longer than `N` bytes
+    pub fn check(value: &str) -> Result<(), String> {
+        check_string(value, N)
+    }
+
+    pub fn serialize<S: Serializer>(value: &str, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::check(value).map_err(ser::Error::custom)?;
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::check(&value).map_err(de::Error::custom)?;
+        Ok(value)
+    }
+}
+
+/// Enforces the bound of a `T[<=N]` field.
+pub struct BoundedSequence<const N: usize>;
+
+impl<const N: usize> BoundedSequence<N> {
+    /// Returns an error message if `value` has more than `N` elements
+    pub fn check<T>(value: &[T]) -> Result<(), String> {
+        check_len(value.len(), N)
+    }
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &[T],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Self::check(value).map_err(ser::Error::custom)?;
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let value = Vec::<T>::deserialize(deserializer)?;
+        Self::check(&value).map_err(de::Error::custom)?;
+        Ok(value)
+    }
+}
+
+/// Enforces the bounds of an array of bounded strings e.g. `string<=M[<=N]`, `string<=M[]` or `string<=M[K]`.
+/// `N` is the maximum number of strings, and is `usize::MAX` for unbounded and fixed size arrays.
+pub struct BoundedStrings<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> BoundedStrings<N, M> {
+    /// Returns an error message if `value` has more than `N` strings, or any string is longer than `M` bytes
+    pub fn check<T: AsRef<[String]>>(value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        check_len(value.len(), N)?;
+        value.iter().try_for_each(|s| check_string(s, M))
+    }
+
+    pub fn serialize<T: AsRef<[String]> + Serialize, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Self::check(value).map_err(ser::Error::custom)?;
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: AsRef<[String]> + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        Self::check(&value).map_err(de::Error::custom)?;
+        Ok(value)
+    }
+}
+
+fn check_len(len: usize, max: usize) -> Result<(), String> {
+    if len > max {
+        return Err(format!(
+            "Bounded sequence has {len} elements, which exceeds its bound of {max}"
+        ));
+    }
+    Ok(())
+}
+
+fn check_string(value: &str, max: usize) -> Result<(), String> {
+    if value.len() > max {
+        return Err(format!(
+            "Bounded string {value:?} has length {}, which exceeds its bound of {max}",
+            value.len()
+        ));
+    }
+    Ok(())
+}
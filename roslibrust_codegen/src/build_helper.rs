@@ -0,0 +1,412 @@
+//! Helper for generating messages from a build.rs, an alternative to the `find_and_generate_ros_messages!` macro.
+//!
+//! Proc macros have no way to tell cargo to re-run them when a message file changes, while a build script can,
+//! so generating from build.rs gives both faster and more reliable incremental builds.
+//!
+//! ```no_run
+//! // In build.rs's main
+//! roslibrust_codegen::build_helper::BuildHelper::new()
+//!     .search_path("../assets/ros1_common_interfaces")
+//!     .include_package("std_msgs")
+//!     .include_package("geometry_msgs")
+//!     .generate()
+//!     .unwrap();
+//! ```
+//!
+//! ```ignore
+//! // src/lib.rs
+//! include!(concat!(env!("OUT_DIR"), "/messages.rs"));
+//! ```
+
+use crate::utils::{self, Package, RosVersion};
+use crate::{
+    bail, dependent_paths, find_ros_packages, generate_rust_ros_message_modules,
+    parse_ros_packages, resolve_dependency_graph, select_ros_files, tokenize_messages_and_services,
+    CodegenOptions, Error, ParsedRosFiles,
+};
+use proc_macro2::TokenStream;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+/// Builder for generating messages into `OUT_DIR` from a build script, see the [module docs](self).
+#[derive(Clone, Debug)]
+pub struct BuildHelper {
+    search_paths: Vec<PathBuf>,
+    use_ros_package_path: bool,
+    include_packages: BTreeSet<String>,
+    exclude_packages: BTreeSet<String>,
+    resolve_dependencies: bool,
+    dependency_paths: Vec<PathBuf>,
+    ros_version: Option<RosVersion>,
+    options: CodegenOptions,
+    out_dir: Option<PathBuf>,
+    file_name: String,
+}
+
+impl Default for BuildHelper {
+    fn default() -> Self {
+        BuildHelper {
+            search_paths: vec![],
+            use_ros_package_path: false,
+            include_packages: BTreeSet::new(),
+            exclude_packages: BTreeSet::new(),
+            resolve_dependencies: false,
+            dependency_paths: vec![],
+            ros_version: None,
+            options: CodegenOptions::default(),
+            out_dir: None,
+            file_name: "messages.rs".to_owned(),
+        }
+    }
+}
+
+impl BuildHelper {
+    /// Creates a helper with no search paths, which writes to `$OUT_DIR/messages.rs`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a path to search for ROS packages.
+    /// Relative paths are relative to the directory containing the crate's Cargo.toml.
+    pub fn search_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.search_paths.push(path.into());
+        self
+    }
+
+    /// Additionally searches the paths in the `ROS_PACKAGE_PATH` environment variable, off by default.
+    ///
+    /// We recommend explicit paths for reproducible builds, but when enabled the build script is also
+    /// re-run whenever `ROS_PACKAGE_PATH` changes.
+    pub fn use_ros_package_path(mut self, use_ros_package_path: bool) -> Self {
+        self.use_ros_package_path = use_ros_package_path;
+        self
+    }
+
+    /// Only generates the named packages, may be called multiple times to include several packages.
+    ///
+    /// Every package a generated message depends on must also be included, e.g. `std_msgs` for `Header`,
+    /// unless [BuildHelper::resolve_dependencies] is enabled.
+    pub fn include_package(mut self, package: impl Into<String>) -> Self {
+        self.include_packages.insert(package.into());
+        self
+    }
+
+    /// Skips generating the named package, even if it is found in a search path or included.
+    pub fn exclude_package(mut self, package: impl Into<String>) -> Self {
+        self.exclude_packages.insert(package.into());
+        self
+    }
+
+    /// Also generates the packages the included packages depend on, off by default.
+    ///
+    /// Dependencies are read from the `depend`, `build_depend`, `exec_depend` etc. tags of each package.xml and
+    /// resolved transitively, so including `nav_msgs` also pulls in `geometry_msgs` and `std_msgs`.
+    /// Dependencies are looked up in the search paths first and then in the [BuildHelper::dependency_path]s,
+    /// dependencies which aren't found anywhere (e.g. `message_runtime`) are skipped.
+    /// Excluded packages are never pulled in.
+    pub fn resolve_dependencies(mut self, resolve_dependencies: bool) -> Self {
+        self.resolve_dependencies = resolve_dependencies;
+        self
+    }
+
+    /// Adds a path which is only searched for the dependencies of the included packages, e.g. a shared
+    /// checkout of common_interfaces used as a cache of standard packages.
+    ///
+    /// Packages in these paths are only generated when [BuildHelper::resolve_dependencies] is enabled and an
+    /// included package depends on them.
+    pub fn dependency_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.dependency_paths.push(path.into());
+        self
+    }
+
+    /// Treats every package found as a `ros_version` package, instead of detecting the version from its package.xml.
+    ///
+    /// Detection is based on the package's build tool and may fail for packages with uncommon build setups.
+    pub fn ros_version(mut self, ros_version: RosVersion) -> Self {
+        self.ros_version = Some(ros_version);
+        self
+    }
+
+    /// Sets the options controlling the generated code.
+    pub fn options(mut self, options: CodegenOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the directory the code is written to, defaults to the `OUT_DIR` provided by cargo to build scripts.
+    pub fn out_dir(mut self, out_dir: impl Into<PathBuf>) -> Self {
+        self.out_dir = Some(out_dir.into());
+        self
+    }
+
+    /// Sets the name of the generated file, defaults to `messages.rs`.
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = file_name.into();
+        self
+    }
+
+    /// Generates the messages, writes them to the output file and prints a `cargo:rerun-if-changed`
+    /// line for every file the generated code was created from.
+    ///
+    /// Returns the path of the generated file.
+    pub fn generate(self) -> Result<PathBuf, Error> {
+        let out_dir = match self.out_dir.clone() {
+            Some(out_dir) => out_dir,
+            None => std::env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or_else(|| {
+                    Error::new(
+                        "OUT_DIR is not set, BuildHelper::generate must be called from a build script or given an out_dir",
+                    )
+                })?,
+        };
+        let (source, dependent_paths) = self.generate_source()?;
+
+        let dest_path = out_dir.join(&self.file_name);
+        std::fs::write(&dest_path, source.to_string()).map_err(|e| {
+            Error::with(
+                format!("Failed to write generated code to {dest_path:?}").as_str(),
+                e,
+            )
+        })?;
+
+        if self.use_ros_package_path {
+            println!("cargo:rerun-if-env-changed=ROS_PACKAGE_PATH");
+        }
+        // Service and action types share their file, so each path is only emitted once
+        for path in dependent_paths.into_iter().collect::<BTreeSet<_>>() {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+        Ok(dest_path)
+    }
+
+    /// Generates the messages without writing them, returning the source and the files it was created from.
+    pub fn generate_source(&self) -> Result<(TokenStream, Vec<PathBuf>), Error> {
+        let (messages, services, actions) = self.parse()?;
+        tokenize_messages_and_services(messages, services, actions, &self.options)
+    }
+
+    /// Same as [BuildHelper::generate_source], but generates each package separately, see
+    /// [crate::write_ros_message_modules] for writing them out.
+    pub fn generate_modules(&self) -> Result<(BTreeMap<String, TokenStream>, Vec<PathBuf>), Error> {
+        let (messages, services, actions) = self.parse()?;
+        let (messages, services) = resolve_dependency_graph(messages, services)?;
+        let dependent_paths = dependent_paths(&messages, &services, &actions);
+        let modules =
+            generate_rust_ros_message_modules(messages, services, actions, &self.options)?;
+        Ok((modules, dependent_paths))
+    }
+
+    /// Finds and parses the files of the selected packages
+    fn parse(&self) -> Result<ParsedRosFiles, Error> {
+        let mut search_paths = self.search_paths.clone();
+        if self.use_ros_package_path {
+            search_paths.extend(utils::get_search_paths());
+        }
+        let found = find_ros_packages(&search_paths)?;
+        let mut packages = found
+            .iter()
+            .filter(|package| self.is_selected(package))
+            .cloned()
+            .collect::<Vec<_>>();
+        if self.resolve_dependencies && !self.include_packages.is_empty() {
+            let mut candidates = found;
+            if !self.dependency_paths.is_empty() {
+                candidates.extend(find_ros_packages(&self.dependency_paths)?);
+            }
+            self.add_dependencies(&mut packages, &candidates)?;
+        }
+        let packages = packages
+            .into_iter()
+            .map(|package| Package {
+                version: self.ros_version.or(package.version),
+                ..package
+            })
+            .collect::<Vec<_>>();
+        if packages.is_empty() {
+            bail!(
+                "No ROS packages left after applying package filters, included: {:?}, excluded: {:?}, paths searched: {search_paths:?}",
+                self.include_packages,
+                self.exclude_packages
+            );
+        }
+        for package in &self.include_packages {
+            if !packages.iter().any(|p| &p.name == package) {
+                bail!("Package {package} was included but not found in paths searched: {search_paths:?}");
+            }
+        }
+
+        let (messages, services, actions) =
+            select_ros_files(parse_ros_packages(&packages)?, &self.options);
+        if messages.is_empty() && services.is_empty() {
+            bail!("Failed to find any services or messages while generating ROS message definitions, packages searched: {packages:?}");
+        }
+        Ok((messages, services, actions))
+    }
+
+    /// Transitively adds the dependencies of `packages` found in `candidates`, earlier candidates taking precedence
+    fn add_dependencies(
+        &self,
+        packages: &mut Vec<Package>,
+        candidates: &[Package],
+    ) -> Result<(), Error> {
+        let mut unresolved = packages.clone();
+        while let Some(package) = unresolved.pop() {
+            let dependencies = utils::get_package_dependencies(&package).map_err(|e| {
+                Error::with(
+                    format!("Failed to read the dependencies of {package:?}").as_str(),
+                    e,
+                )
+            })?;
+            for dependency in dependencies {
+                if self.exclude_packages.contains(&dependency)
+                    || packages.iter().any(|p| p.name == dependency)
+                {
+                    continue;
+                }
+                match candidates.iter().find(|p| p.name == dependency) {
+                    Some(found) => {
+                        log::debug!("Adding {dependency}, a dependency of {}", package.name);
+                        packages.push(found.clone());
+                        unresolved.push(found.clone());
+                    }
+                    None => log::debug!(
+                        "Dependency {dependency} of {} was not found, skipping it",
+                        package.name
+                    ),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_selected(&self, package: &Package) -> bool {
+        (self.include_packages.is_empty() || self.include_packages.contains(&package.name))
+            && !self.exclude_packages.contains(&package.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ROS2_TEST_MSGS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/ros2_test_msgs");
+    const ROS1_ROSAPI: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/ros1_common_interfaces/rosapi"
+    );
+
+    #[test_log::test]
+    fn generates_only_selected_packages() {
+        let helper = BuildHelper::new()
+            .search_path(ROS2_TEST_MSGS)
+            .search_path(ROS1_ROSAPI);
+
+        let (source, paths) = helper.clone().generate_source().unwrap();
+        let source = source.to_string();
+        assert!(source.contains("pub mod test_msgs"));
+        assert!(source.contains("pub mod rosapi"));
+        assert!(paths.iter().any(|p| p.ends_with("Defaults.msg")));
+
+        let (source, paths) = helper
+            .clone()
+            .include_package("test_msgs")
+            .generate_source()
+            .unwrap();
+        let source = source.to_string();
+        assert!(source.contains("pub mod test_msgs"));
+        assert!(!source.contains("pub mod rosapi"));
+        assert!(paths
+            .iter()
+            .all(|p| p.starts_with(std::path::Path::new(ROS2_TEST_MSGS).canonicalize().unwrap())));
+
+        let (source, _) = helper
+            .clone()
+            .exclude_package("test_msgs")
+            .generate_source()
+            .unwrap();
+        let source = source.to_string();
+        assert!(!source.contains("pub mod test_msgs"));
+        assert!(source.contains("pub mod rosapi"));
+
+        assert!(helper
+            .include_package("not_a_package")
+            .generate_source()
+            .is_err());
+    }
+
+    #[test_log::test]
+    fn overrides_ros_version() {
+        let helper = BuildHelper::new().search_path(ROS2_TEST_MSGS);
+        let (modules, _) = helper.generate_modules().unwrap();
+        // Only ROS2 messages are generated with a type hash
+        assert!(modules["test_msgs"].to_string().contains("TYPE_HASH"));
+        let (modules, _) = helper
+            .ros_version(RosVersion::ROS1)
+            .generate_modules()
+            .unwrap();
+        assert!(!modules["test_msgs"].to_string().contains("TYPE_HASH"));
+    }
+
+    #[test_log::test]
+    fn writes_to_out_dir() {
+        let out_dir = std::env::temp_dir().join("roslibrust_build_helper_test");
+        std::fs::create_dir_all(&out_dir).unwrap();
+        let path = BuildHelper::new()
+            .search_path(ROS2_TEST_MSGS)
+            .out_dir(&out_dir)
+            .file_name("test_msgs.rs")
+            .generate()
+            .unwrap();
+        assert_eq!(path, out_dir.join("test_msgs.rs"));
+        assert!(std::fs::read_to_string(path)
+            .unwrap()
+            .contains("pub mod test_msgs"));
+    }
+
+    #[test_log::test]
+    fn resolves_package_dependencies() {
+        // A workspace with a package depending on one from a separate cache of standard packages
+        let root = std::env::temp_dir().join("roslibrust_build_helper_dependencies_test");
+        let write_package = |dir: PathBuf, name: &str, depends: &str, msg: &str| {
+            std::fs::create_dir_all(dir.join("msg")).unwrap();
+            std::fs::write(
+                dir.join("package.xml"),
+                format!("<package format=\"2\"><name>{name}</name><buildtool_depend>catkin</buildtool_depend>{depends}</package>"),
+            )
+            .unwrap();
+            std::fs::write(dir.join("msg").join("Value.msg"), msg).unwrap();
+        };
+        write_package(
+            root.join("workspace/robot_msgs"),
+            "robot_msgs",
+            "<depend>base_msgs</depend><exec_depend>message_runtime</exec_depend>",
+            "base_msgs/Value value",
+        );
+        write_package(
+            root.join("workspace/unrelated_msgs"),
+            "unrelated_msgs",
+            "",
+            "int32 data",
+        );
+        write_package(root.join("cache/base_msgs"), "base_msgs", "", "int32 data");
+
+        let helper = BuildHelper::new()
+            .search_path(root.join("workspace"))
+            .dependency_path(root.join("cache"))
+            .include_package("robot_msgs");
+        // Without resolution the dependency is missing
+        assert!(helper.generate_source().is_err());
+
+        let helper = helper.resolve_dependencies(true);
+        let source = helper.generate_source().unwrap().0.to_string();
+        assert!(source.contains("pub mod robot_msgs"));
+        assert!(source.contains("pub mod base_msgs"));
+        assert!(!source.contains("pub mod unrelated_msgs"));
+
+        assert!(helper
+            .exclude_package("base_msgs")
+            .generate_source()
+            .is_err());
+    }
+}
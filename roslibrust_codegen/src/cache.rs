@@ -0,0 +1,277 @@
+//! Caching of generated code, used by the `find_and_generate_ros_messages!` macros so incremental builds don't
+//! reparse and regenerate every message.
+//!
+//! Unlike a build script a macro is re-run on every compile of the crate using it, even if no message changed.
+//! The cache stores the code generated for a set of search paths and options alongside a hash of the package and
+//! message files it was generated from, and reuses it as long as none of those files changed.
+//! Finding and hashing the files is much cheaper than parsing them and generating the code.
+//!
+//! [CodegenCache::from_env] keeps entries in memory, which helps long running processes such as rust-analyzer,
+//! and on disk in `roslibrust_codegen_cache` within the system's temporary directory. The directory can be changed
+//! with the `ROSLIBRUST_CODEGEN_CACHE_DIR` environment variable, and setting `ROSLIBRUST_CODEGEN_NO_CACHE` disables
+//! caching entirely.
+
+use crate::{
+    find_and_generate_ros_messages_without_ros_package_path_with_options, find_ros_packages, utils,
+    CodegenOptions, Error,
+};
+use proc_macro2::TokenStream;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// Sets the directory generated code is cached in
+pub const CACHE_DIR_ENV: &str = "ROSLIBRUST_CODEGEN_CACHE_DIR";
+/// Disables caching when set to any value
+pub const NO_CACHE_ENV: &str = "ROSLIBRUST_CODEGEN_NO_CACHE";
+
+// The generator's own source is part of the key, so that code generated by a different build of this crate
+// is never reused, even when working on it without changing its version
+const GENERATOR_SOURCES: &[&str] = &[
+    include_str!("lib.rs"),
+    include_str!("gen.rs"),
+    include_str!("options.rs"),
+    include_str!("type_hash.rs"),
+    include_str!("utils.rs"),
+    include_str!("parse/mod.rs"),
+    include_str!("parse/msg.rs"),
+    include_str!("parse/srv.rs"),
+    include_str!("parse/action.rs"),
+    include_str!("parse/idl.rs"),
+];
+
+lazy_static::lazy_static! {
+    // Entries by key, holding the hash of the files they were generated from and the generated code
+    static ref MEMORY_CACHE: Mutex<HashMap<String, (String, String)>> = Mutex::new(HashMap::new());
+}
+
+/// Where generated code is cached, see the [module docs](self).
+#[derive(Clone, Debug, Default)]
+pub struct CodegenCache {
+    dir: Option<PathBuf>,
+    memory: bool,
+}
+
+impl CodegenCache {
+    /// Creates a cache which stores entries as files in `dir`, creating it if needed.
+    pub fn in_dir(dir: impl Into<PathBuf>) -> Self {
+        CodegenCache {
+            dir: Some(dir.into()),
+            memory: false,
+        }
+    }
+
+    /// Creates the cache used by the macros, configured by the `ROSLIBRUST_CODEGEN_CACHE_DIR`
+    /// and `ROSLIBRUST_CODEGEN_NO_CACHE` environment variables.
+    pub fn from_env() -> Self {
+        if std::env::var_os(NO_CACHE_ENV).is_some() {
+            return CodegenCache::default();
+        }
+        let dir = std::env::var_os(CACHE_DIR_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| std::env::temp_dir().join("roslibrust_codegen_cache"));
+        CodegenCache {
+            dir: Some(dir),
+            memory: true,
+        }
+    }
+
+    /// Same as [crate::find_and_generate_ros_messages_without_ros_package_path_with_options], but reuses the code
+    /// generated by a previous call with the same search paths and options if none of the files changed.
+    pub fn find_and_generate_ros_messages_without_ros_package_path(
+        &self,
+        search_paths: Vec<PathBuf>,
+        options: &CodegenOptions,
+    ) -> Result<(TokenStream, Vec<PathBuf>), Error> {
+        if self.dir.is_none() && !self.memory {
+            return find_and_generate_ros_messages_without_ros_package_path_with_options(
+                search_paths,
+                options,
+            );
+        }
+
+        let key = cache_key(&search_paths, options);
+        let (files_hash, paths) = match hash_files(&search_paths) {
+            Ok(hashed) => hashed,
+            // Let generation report the error
+            Err(_) => {
+                return find_and_generate_ros_messages_without_ros_package_path_with_options(
+                    search_paths,
+                    options,
+                )
+            }
+        };
+
+        if let Some(source) = self.lookup(&key, &files_hash) {
+            match TokenStream::from_str(&source) {
+                Ok(source) => {
+                    log::debug!("Reusing cached code for {search_paths:?}");
+                    return Ok((source, paths));
+                }
+                Err(e) => log::warn!("Ignoring cached code which failed to parse: {e}"),
+            }
+        }
+
+        let (source, dependent_paths) =
+            find_and_generate_ros_messages_without_ros_package_path_with_options(
+                search_paths,
+                options,
+            )?;
+        self.store(&key, files_hash, source.to_string());
+        Ok((source, dependent_paths))
+    }
+
+    fn lookup(&self, key: &str, files_hash: &str) -> Option<String> {
+        if self.memory {
+            if let Some((hash, source)) = MEMORY_CACHE.lock().unwrap().get(key) {
+                if hash == files_hash {
+                    return Some(source.clone());
+                }
+            }
+        }
+        let dir = self.dir.as_ref()?;
+        let contents = std::fs::read_to_string(dir.join(format!("{key}.rs"))).ok()?;
+        let (header, source) = contents.split_once('\n')?;
+        if header.strip_prefix("// ") != Some(files_hash) {
+            return None;
+        }
+        if self.memory {
+            MEMORY_CACHE
+                .lock()
+                .unwrap()
+                .insert(key.to_owned(), (files_hash.to_owned(), source.to_owned()));
+        }
+        Some(source.to_owned())
+    }
+
+    fn store(&self, key: &str, files_hash: String, source: String) {
+        if let Some(dir) = &self.dir {
+            // Failing to cache only makes the next build slower, so isn't an error
+            let written = std::fs::create_dir_all(dir).and_then(|_| {
+                std::fs::write(
+                    dir.join(format!("{key}.rs")),
+                    format!("// {files_hash}\n{source}"),
+                )
+            });
+            if let Err(e) = written {
+                log::warn!("Failed to write generated code to the cache in {dir:?}: {e}");
+            }
+        }
+        if self.memory {
+            MEMORY_CACHE
+                .lock()
+                .unwrap()
+                .insert(key.to_owned(), (files_hash, source));
+        }
+    }
+}
+
+/// Identifies the code generated for a set of search paths and options, one entry is kept per key
+fn cache_key(search_paths: &[PathBuf], options: &CodegenOptions) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(env!("CARGO_PKG_VERSION"));
+    for source in GENERATOR_SOURCES {
+        hasher.update(source);
+    }
+    hasher.update(format!("{options:?}"));
+    for path in search_paths {
+        // Relative paths depend on where the compiler is run from
+        let path = path.canonicalize().unwrap_or_else(|_| path.clone());
+        hasher.update(path.to_string_lossy().as_bytes());
+        hasher.update([0]);
+    }
+    hex(hasher.finalize().as_slice())
+}
+
+/// Hashes the packages found in the search paths and the contents of their message files,
+/// returning the hash and the paths of the files
+fn hash_files(search_paths: &[PathBuf]) -> Result<(String, Vec<PathBuf>), Error> {
+    let mut hasher = Sha256::new();
+    let mut paths = vec![];
+    for package in find_ros_packages(search_paths)? {
+        hasher.update(format!(
+            "{}:{:?}:{:?}\n",
+            package.name, package.version, package.path
+        ));
+        let files = utils::get_message_files(&package).map_err(|e| {
+            Error::with(
+                format!("Unable to get paths to message files for {package:?}").as_str(),
+                e,
+            )
+        })?;
+        for file in files {
+            let contents = std::fs::read(&file)
+                .map_err(|e| Error::with(format!("Unable to read {file:?}").as_str(), e))?;
+            hasher.update(file.to_string_lossy().as_bytes());
+            hasher.update((contents.len() as u64).to_le_bytes());
+            hasher.update(&contents);
+            paths.push(file);
+        }
+    }
+    Ok((hex(hasher.finalize().as_slice()), paths))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test_log::test]
+    fn reuses_code_until_a_file_changes() {
+        let root = std::env::temp_dir().join("roslibrust_codegen_cache_test");
+        let _ = std::fs::remove_dir_all(&root);
+        let package = root.join("packages/cached_msgs");
+        std::fs::create_dir_all(package.join("msg")).unwrap();
+        std::fs::write(
+            package.join("package.xml"),
+            "<package format=\"2\"><name>cached_msgs</name><buildtool_depend>catkin</buildtool_depend></package>",
+        )
+        .unwrap();
+        std::fs::write(package.join("msg/Value.msg"), "int32 data").unwrap();
+
+        let cache = CodegenCache::in_dir(root.join("cache"));
+        let generate = || {
+            cache
+                .find_and_generate_ros_messages_without_ros_package_path(
+                    vec![root.join("packages")],
+                    &CodegenOptions::default(),
+                )
+                .unwrap()
+        };
+        let (source, paths) = generate();
+        assert!(source.to_string().contains("pub r#data : i32"));
+        assert!(paths.iter().any(|p| p.ends_with("Value.msg")));
+
+        // Replace the cached code to tell whether it is reused
+        let entries = std::fs::read_dir(root.join("cache"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        assert_eq!(entries.len(), 1);
+        let cached = std::fs::read_to_string(&entries[0]).unwrap();
+        let (header, _) = cached.split_once('\n').unwrap();
+        std::fs::write(&entries[0], format!("{header}\npub struct FromCache;")).unwrap();
+        let (source, paths) = generate();
+        assert_eq!(source.to_string(), "pub struct FromCache ;");
+        assert!(paths.iter().any(|p| p.ends_with("Value.msg")));
+
+        // Changing a message invalidates the entry
+        std::fs::write(package.join("msg/Value.msg"), "int64 data").unwrap();
+        let (source, _) = generate();
+        assert!(source.to_string().contains("pub r#data : i64"));
+
+        // As do different options
+        let (source, _) = cache
+            .find_and_generate_ros_messages_without_ros_package_path(
+                vec![root.join("packages")],
+                &CodegenOptions::new().derive("Eq"),
+            )
+            .unwrap();
+        assert!(source.to_string().contains("derive (Eq)"));
+    }
+}
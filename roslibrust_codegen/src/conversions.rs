@@ -0,0 +1,59 @@
+//! Helpers used by the conversions generated with [crate::CodegenOptions::r2r_conversions] and
+//! [crate::CodegenOptions::rosrust_conversions].
+//!
+//! Other crates don't always represent arrays the way we do, e.g. r2r represents fixed size arrays as `Vec`s.
+//! [collect] converts between any of the representations, so the generated code doesn't depend on them.
+
+/// A collection which can be built from its elements, used to convert array fields.
+pub trait FromElements {
+    type Element;
+
+    fn from_elements(elements: impl Iterator<Item = Self::Element>) -> Self;
+}
+
+impl<T> FromElements for Vec<T> {
+    type Element = T;
+
+    fn from_elements(elements: impl Iterator<Item = T>) -> Self {
+        elements.collect()
+    }
+}
+
+/// Missing elements are filled with their default value, and extra elements are dropped.
+impl<T: Default, const N: usize> FromElements for [T; N] {
+    type Element = T;
+
+    fn from_elements(mut elements: impl Iterator<Item = T>) -> Self {
+        std::array::from_fn(|_| elements.next().unwrap_or_default())
+    }
+}
+
+impl FromElements for bytes::Bytes {
+    type Element = u8;
+
+    fn from_elements(elements: impl Iterator<Item = u8>) -> Self {
+        elements.collect::<Vec<_>>().into()
+    }
+}
+
+/// Collects the elements of an array field into the array type of another field.
+pub fn collect<C: FromElements>(elements: impl IntoIterator<Item = C::Element>) -> C {
+    C::from_elements(elements.into_iter())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn collects_arrays() {
+        let vec: Vec<i64> = collect([1, 2, 3].into_iter().map(Into::into));
+        assert_eq!(vec, vec![1, 2, 3]);
+        let array: [u8; 3] = collect(vec![1, 2]);
+        assert_eq!(array, [1, 2, 0]);
+        let array: [u8; 1] = collect(vec![1, 2]);
+        assert_eq!(array, [1]);
+        let bytes: bytes::Bytes = collect(vec![1, 2]);
+        assert_eq!(bytes.as_ref(), &[1, 2]);
+    }
+}
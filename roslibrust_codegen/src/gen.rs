@@ -7,9 +7,33 @@ use syn::parse_quote;
 use crate::parse::convert_ros_type_to_rust_type;
 use crate::utils::RosVersion;
 use crate::{bail, Error};
-use crate::{ConstantInfo, FieldInfo, MessageFile, RosLiteral, ServiceFile};
+use crate::{
+    ByteArrayType, CodegenOptions, ConstantInfo, FieldInfo, MessageFile, ParsedActionFile,
+    ParsedMessageFile, RosLiteral, ServiceFile,
+};
 
-fn derive_attrs() -> Vec<syn::Attribute> {
+fn derive_attrs(options: &CodegenOptions) -> Vec<syn::Attribute> {
+    if options.no_std {
+        // Without roslibrust the crates are used directly, and serde is only derived when the
+        // generated code's crate has a serde feature enabled
+        return vec![
+            parse_quote! { #[derive(::smart_default::SmartDefault)] },
+            parse_quote! { #[derive(Debug)] },
+            parse_quote! { #[derive(Clone)] },
+            parse_quote! { #[derive(PartialEq)] },
+            parse_quote! { #[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize))] },
+        ];
+    }
+    if options.optional_serde {
+        return vec![
+            parse_quote! { #[cfg_attr(feature = "serde", derive(::roslibrust::codegen::Deserialize, ::roslibrust::codegen::Serialize))] },
+            parse_quote! { #[derive(::roslibrust::codegen::SmartDefault)] },
+            parse_quote! { #[derive(Debug)] },
+            parse_quote! { #[derive(Clone)] },
+            parse_quote! { #[derive(PartialEq)] },
+            parse_quote! { #[cfg_attr(feature = "serde", serde(crate = "::roslibrust::codegen::serde"))] },
+        ];
+    }
     vec![
         parse_quote! { #[derive(::roslibrust::codegen::Deserialize)] },
         parse_quote! { #[derive(::roslibrust::codegen::Serialize)] },
@@ -21,18 +45,370 @@ fn derive_attrs() -> Vec<syn::Attribute> {
     ]
 }
 
+/// Generates a `#[serde(...)]` field attribute, which is conditional on the serde feature for no_std code
+/// and with [CodegenOptions::optional_serde]
+fn serde_attr(options: &CodegenOptions, args: TokenStream) -> TokenStream {
+    if options.no_std || options.optional_serde {
+        quote! { #[cfg_attr(feature = "serde", serde(#args))] }
+    } else {
+        quote! { #[serde(#args)] }
+    }
+}
+
+/// Generates the attribute making implementations of roslibrust's traits conditional on the roslibrust feature
+/// for no_std code, as roslibrust itself requires std, or on the serde feature the traits require with
+/// [CodegenOptions::optional_serde]
+fn roslibrust_cfg(options: &CodegenOptions) -> TokenStream {
+    if options.no_std {
+        quote! { #[cfg(feature = "roslibrust")] }
+    } else if options.optional_serde {
+        quote! { #[cfg(feature = "serde")] }
+    } else {
+        quote! {}
+    }
+}
+
+/// Parses the additional derives and attributes from the options that apply to a message
+fn extra_attrs(
+    package: &str,
+    name: &str,
+    options: &CodegenOptions,
+) -> Result<Vec<syn::Attribute>, Error> {
+    let mut attrs = vec![];
+    for derive in options.derives_for(package, name) {
+        let path = syn::parse_str::<syn::Path>(derive).map_err(|e| {
+            Error::with(
+                format!("Invalid derive {derive:?} for {package}/{name} in codegen options")
+                    .as_str(),
+                e,
+            )
+        })?;
+        attrs.push(parse_quote! { #[derive(#path)] });
+    }
+    for attribute in options.attributes_for(package, name) {
+        let parsed = syn::parse::Parser::parse_str(syn::Attribute::parse_outer, attribute)
+            .map_err(|e| {
+                Error::with(
+                    format!(
+                        "Invalid attribute {attribute:?} for {package}/{name} in codegen options"
+                    )
+                    .as_str(),
+                    e,
+                )
+            })?;
+        attrs.extend(parsed);
+    }
+    Ok(attrs)
+}
+
+/// Whether a field is represented as Bytes rather than Vec<u8>, which is only done for unbounded byte arrays
+fn is_bytes_field(field: &FieldInfo, options: &CodegenOptions) -> bool {
+    options.byte_array_type == ByteArrayType::Bytes
+        && field.field_type.field_type == "uint8"
+        && field.field_type.array_info == Some(None)
+        && field.field_type.array_bound.is_none()
+}
+
+/// Generates the statement stepping a `reader` over a field of a ROS1 message without decoding it
+fn generate_ros1_skip(
+    field: &FieldInfo,
+    msg: &ParsedMessageFile,
+    options: &CodegenOptions,
+) -> Result<TokenStream, Error> {
+    if is_bytes_field(field, options) {
+        return Ok(quote! { ::roslibrust::ros1_codec::skip_bytes(reader)?; });
+    }
+    let (_, owned_type) =
+        generate_field_definition(field.clone(), &msg.package, RosVersion::ROS1, options)?;
+    Ok(quote! { <#owned_type as ::roslibrust::ros1_codec::Ros1Decode>::skip(reader)?; })
+}
+
+/// Generates the implementations of roslibrust's Ros1Encode and Ros1Decode for a message, and the functions
+/// of its RosMessageType implementation using them.
+fn generate_ros1_codec(
+    msg: &ParsedMessageFile,
+    options: &CodegenOptions,
+) -> Result<(TokenStream, TokenStream), Error> {
+    let struct_name = format_ident!("{}", msg.name);
+    let mut lens = vec![];
+    let mut encodes = vec![];
+    let mut decodes = vec![];
+    let mut skips = vec![];
+    for field in &msg.fields {
+        let name = format_ident!("r#{}", field.field_name);
+        skips.push(generate_ros1_skip(field, msg, options)?);
+        if is_bytes_field(field, options) {
+            // Bytes is from another crate, so can't implement the traits
+            lens.push(quote! { 4 + self.#name.len() });
+            encodes.push(quote! { ::roslibrust::ros1_codec::encode_bytes(&self.#name, buf); });
+            decodes.push(quote! { #name: ::roslibrust::ros1_codec::decode_bytes(reader)?.into() });
+        } else {
+            lens.push(quote! { ::roslibrust::ros1_codec::Ros1Encode::encoded_len(&self.#name) });
+            encodes
+                .push(quote! { ::roslibrust::ros1_codec::Ros1Encode::encode(&self.#name, buf); });
+            decodes.push(quote! { #name: ::roslibrust::ros1_codec::Ros1Decode::decode(reader)? });
+        }
+    }
+    let encoded_len = if lens.is_empty() {
+        quote! { 0 }
+    } else {
+        quote! { #(#lens)+* }
+    };
+    let impls = quote! {
+        #[allow(unused_variables)]
+        impl ::roslibrust::ros1_codec::Ros1Encode for #struct_name {
+            fn encoded_len(&self) -> usize {
+                #encoded_len
+            }
+
+            fn encode(&self, buf: &mut ::std::vec::Vec<u8>) {
+                #(#encodes)*
+            }
+        }
+
+        #[allow(unused_variables)]
+        impl ::roslibrust::ros1_codec::Ros1Decode for #struct_name {
+            fn decode(
+                reader: &mut ::roslibrust::ros1_codec::Reader<'_>,
+            ) -> ::core::result::Result<Self, ::roslibrust::ros1_codec::DecodeError> {
+                ::core::result::Result::Ok(Self {
+                    #(#decodes,)*
+                })
+            }
+
+            fn skip(
+                reader: &mut ::roslibrust::ros1_codec::Reader<'_>,
+            ) -> ::core::result::Result<(), ::roslibrust::ros1_codec::DecodeError> {
+                #(#skips)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    };
+    let fns = quote! {
+        fn ros1_encode(&self) -> ::core::option::Option<::std::vec::Vec<u8>> {
+            ::core::option::Option::Some(::roslibrust::ros1_codec::to_vec(self))
+        }
+
+        fn ros1_decode(
+            data: &[u8],
+        ) -> ::core::option::Option<::core::result::Result<Self, ::roslibrust::ros1_codec::DecodeError>> {
+            ::core::option::Option::Some(::roslibrust::ros1_codec::from_slice(data))
+        }
+    };
+    Ok((impls, fns))
+}
+
+/// Generates the lazily decoded view of a message for [CodegenOptions::ros1_lazy], a `{Name}Lazy<'a>` struct
+/// over the encoded body with a method per field, which skips the fields before it and decodes only that field.
+fn generate_ros1_lazy(
+    msg: &ParsedMessageFile,
+    options: &CodegenOptions,
+) -> Result<TokenStream, Error> {
+    let struct_name = format_ident!("{}", msg.name);
+    let lazy_name = format_ident!("{}Lazy", msg.name);
+    let mut skips = vec![];
+    let mut getters = vec![];
+    for field in &msg.fields {
+        let name = format_ident!("r#{}", field.field_name);
+        let (_, owned_type) =
+            generate_field_definition(field.clone(), &msg.package, RosVersion::ROS1, options)?;
+        let decode = if is_bytes_field(field, options) {
+            quote! { ::core::result::Result::Ok(::roslibrust::ros1_codec::decode_bytes(reader)?.into()) }
+        } else {
+            quote! { ::roslibrust::ros1_codec::Ros1Decode::decode(reader) }
+        };
+        let doc = format!(
+            " Decodes the `{}` field, skipping over the fields before it",
+            field.field_name
+        );
+        getters.push(quote! {
+            #[doc = #doc]
+            pub fn #name(
+                &self,
+            ) -> ::core::result::Result<#owned_type, ::roslibrust::ros1_codec::DecodeError> {
+                let reader = &mut ::roslibrust::ros1_codec::Reader::new(self.body);
+                #(#skips)*
+                #decode
+            }
+        });
+        skips.push(generate_ros1_skip(field, msg, options)?);
+    }
+    // Without fields there is nothing reading the body
+    let allow_unused = if msg.fields.is_empty() {
+        quote! { #[allow(dead_code)] }
+    } else {
+        quote! {}
+    };
+
+    let doc = format!(
+        " The lazily decoded view of [{}], decoding only the fields which are used, see `roslibrust::ros1_codec`",
+        msg.name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #allow_unused
+        #[derive(Debug, Clone, Copy)]
+        pub struct #lazy_name<'a> {
+            body: &'a [u8],
+        }
+
+        #[allow(non_snake_case)]
+        impl #lazy_name<'_> {
+            #(#getters)*
+        }
+
+        impl ::roslibrust::ros1_codec::Ros1Lazy for #struct_name {
+            type Lazy<'a> = #lazy_name<'a>;
+
+            fn lazy(body: &[u8]) -> #lazy_name<'_> {
+                #lazy_name { body }
+            }
+        }
+    })
+}
+
+/// Generates the borrowed form of a message for [CodegenOptions::ros1_borrowed], a `{Name}Ref<'a>` struct with
+/// strings and byte arrays referencing the data it is decoded from. Messages without either anywhere in them are
+/// their own borrowed form.
+fn generate_ros1_borrowed(
+    msg: &MessageFile,
+    options: &CodegenOptions,
+) -> Result<TokenStream, Error> {
+    let struct_name = format_ident!("{}", msg.parsed.name);
+    if !msg.has_borrowed_fields {
+        return Ok(quote! {
+            impl ::roslibrust::ros1_codec::Ros1Borrow for #struct_name {
+                type Borrowed<'a> = #struct_name;
+            }
+
+            impl<'a> ::roslibrust::ros1_codec::Ros1DecodeBorrowed<'a> for #struct_name {
+                type Owned = #struct_name;
+
+                fn decode_borrowed(
+                    reader: &mut ::roslibrust::ros1_codec::Reader<'a>,
+                ) -> ::core::result::Result<Self, ::roslibrust::ros1_codec::DecodeError> {
+                    ::roslibrust::ros1_codec::Ros1Decode::decode(reader)
+                }
+
+                fn to_owned(&self) -> #struct_name {
+                    ::core::clone::Clone::clone(self)
+                }
+            }
+        });
+    }
+
+    let borrowed_name = format_ident!("{}Ref", msg.parsed.name);
+    let mut fields = vec![];
+    let mut decodes = vec![];
+    let mut to_owned = vec![];
+    for field in &msg.parsed.fields {
+        let name = format_ident!("r#{}", field.field_name);
+        let element = match &field.field_type.package_name {
+            Some(pkg) => {
+                let path = if *pkg == msg.parsed.package {
+                    format!("self::{}", field.field_type.field_type)
+                } else {
+                    format!("{pkg}::{}", field.field_type.field_type)
+                };
+                let path = TokenStream::from_str(&path)
+                    .map_err(|e| Error::with(format!("Invalid type {path}").as_str(), e))?;
+                Some(quote! { <#path as ::roslibrust::ros1_codec::Ros1Borrow>::Borrowed<'a> })
+            }
+            None if field.field_type.field_type == "string" => Some(quote! { &'a str }),
+            None => None,
+        };
+        let borrowed_type = match (element, field.field_type.array_info) {
+            (Some(element), None) => element,
+            (Some(element), Some(None)) => quote! { ::std::vec::Vec<#element> },
+            (Some(element), Some(Some(len))) => quote! { [#element; #len] },
+            (None, Some(None)) if field.field_type.field_type == "uint8" => quote! { &'a [u8] },
+            (None, _) => {
+                // Other primitives are copied, so have the same type as in the message
+                let (_, owned_type) = generate_field_definition(
+                    field.clone(),
+                    &msg.parsed.package,
+                    RosVersion::ROS1,
+                    options,
+                )?;
+                fields.push(quote! { pub #name: #owned_type, });
+                decodes
+                    .push(quote! { #name: ::roslibrust::ros1_codec::Ros1Decode::decode(reader)? });
+                to_owned.push(quote! { #name: ::core::clone::Clone::clone(&self.#name) });
+                continue;
+            }
+        };
+        fields.push(quote! { pub #name: #borrowed_type, });
+        decodes.push(
+            quote! { #name: ::roslibrust::ros1_codec::Ros1DecodeBorrowed::decode_borrowed(reader)? },
+        );
+        let owned = quote! { ::roslibrust::ros1_codec::Ros1DecodeBorrowed::to_owned(&self.#name) };
+        if is_bytes_field(field, options) {
+            to_owned.push(quote! { #name: #owned.into() });
+        } else {
+            to_owned.push(quote! { #name: #owned });
+        }
+    }
+
+    let doc = format!(
+        " The borrowed form of [{}], referencing the data it was decoded from, see `roslibrust::ros1_codec`",
+        msg.parsed.name
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(non_snake_case)]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct #borrowed_name<'a> {
+            #(#fields)*
+        }
+
+        impl<'a> #borrowed_name<'a> {
+            /// Copies the referenced data into the message
+            pub fn to_owned(&self) -> #struct_name {
+                <Self as ::roslibrust::ros1_codec::Ros1DecodeBorrowed<'a>>::to_owned(self)
+            }
+        }
+
+        impl<'a> ::roslibrust::ros1_codec::Ros1DecodeBorrowed<'a> for #borrowed_name<'a> {
+            type Owned = #struct_name;
+
+            fn decode_borrowed(
+                reader: &mut ::roslibrust::ros1_codec::Reader<'a>,
+            ) -> ::core::result::Result<Self, ::roslibrust::ros1_codec::DecodeError> {
+                ::core::result::Result::Ok(Self {
+                    #(#decodes,)*
+                })
+            }
+
+            fn to_owned(&self) -> #struct_name {
+                #struct_name {
+                    #(#to_owned,)*
+                }
+            }
+        }
+
+        impl ::roslibrust::ros1_codec::Ros1Borrow for #struct_name {
+            type Borrowed<'a> = #borrowed_name<'a>;
+        }
+    })
+}
+
 /// Generates the service for a given service file
 /// The service definition defines a struct representing the service an an implementation
 /// of the RosServiceType trait for that struct
-pub fn generate_service(service: ServiceFile) -> Result<TokenStream, Error> {
+pub fn generate_service(
+    service: ServiceFile,
+    options: &CodegenOptions,
+) -> Result<TokenStream, Error> {
     let service_type_name = service.get_full_name();
     let service_md5sum = service.md5sum;
+    let type_hash = type_hash_const(&service.type_hash);
     let struct_name = format_ident!("{}", service.parsed.name);
     let request_name = format_ident!("{}", service.parsed.request_type.name);
     let response_name = format_ident!("{}", service.parsed.response_type.name);
 
-    let request_msg = generate_struct(service.request)?;
-    let response_msg = generate_struct(service.response)?;
+    let request_msg = generate_struct(service.request, options)?;
+    let response_msg = generate_struct(service.response, options)?;
+    let cfg = roslibrust_cfg(options);
     Ok(quote! {
 
         #request_msg
@@ -42,34 +418,145 @@ pub fn generate_service(service: ServiceFile) -> Result<TokenStream, Error> {
         pub struct #struct_name {
 
         }
+        #cfg
         impl ::roslibrust::RosServiceType for #struct_name {
             const ROS_SERVICE_NAME: &'static str = #service_type_name;
             const MD5SUM: &'static str = #service_md5sum;
+            #type_hash
             type Request = #request_name;
             type Response = #response_name;
         }
     })
 }
 
+/// Generates the action for a given action file
+/// The action definition defines a struct representing the action and an implementation
+/// of the RosActionType trait for that struct, the goal, result, feedback and wrapper types are
+/// generated separately as regular messages and services.
+pub fn generate_action(action: &ParsedActionFile, options: &CodegenOptions) -> TokenStream {
+    let cfg = roslibrust_cfg(options);
+    let action_type_name = action.get_full_name();
+    let struct_name = format_ident!("{}", action.name);
+    let goal_name = format_ident!("{}", action.goal_type.name);
+    let result_name = format_ident!("{}", action.result_type.name);
+    let feedback_name = format_ident!("{}", action.feedback_type.name);
+    quote! {
+        #[allow(dead_code)]
+        pub struct #struct_name {
+
+        }
+        #cfg
+        impl ::roslibrust::RosActionType for #struct_name {
+            const ROS_ACTION_NAME: &'static str = #action_type_name;
+            type Goal = #goal_name;
+            type Result = #result_name;
+            type Feedback = #feedback_name;
+        }
+    }
+}
+
+/// Generates the TYPE_HASH constant, which is left to its default for ROS1 types that have no type hash
+fn type_hash_const(type_hash: &str) -> TokenStream {
+    if type_hash.is_empty() {
+        return quote! {};
+    }
+    quote! { const TYPE_HASH: &'static str = #type_hash; }
+}
+
+/// Generates a doc attribute for each line of a comment from a message file
+fn doc_attrs(comment: &Option<String>) -> TokenStream {
+    let lines = comment
+        .iter()
+        .flat_map(|comment| comment.lines())
+        // Leading space matches the output of a /// comment
+        .map(|line| match line {
+            "" => String::new(),
+            line => format!(" {line}"),
+        });
+    quote! { #(#[doc = #lines])* }
+}
+
 /// Turns a string into a TokenStream that represents a raw string literal of the string
 pub fn generate_raw_string_literal(value: &str) -> TokenStream {
     let wrapped = format!("r####\"{}\"####", value);
     TokenStream::from_str(&wrapped).unwrap()
 }
 
-pub fn generate_struct(msg: MessageFile) -> Result<TokenStream, Error> {
+/// Converts a path to a std type used by generated code into the path of the same type in core or alloc
+fn no_std_path(rust_type: &str) -> String {
+    if let Some(integral_type) = rust_type.strip_prefix("::roslibrust::codegen::integral_types::") {
+        // Refers to the module generated by [generate_no_std_integral_types]
+        format!("integral_types::{integral_type}")
+    } else {
+        rust_type.replacen("::std::", "::alloc::", 1)
+    }
+}
+
+/// Generates an `integral_types` module for no_std code replacing roslibrust's [crate::Time] and [crate::Duration],
+/// which is declared alongside the packages' modules
+pub fn generate_no_std_integral_types() -> TokenStream {
+    quote! {
+        /// Matches the integral ROS time type, see roslibrust_codegen's Time
+        #[derive(Debug, Default, Clone, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize))]
+        pub struct Time {
+            // This alias is required for ros2 where field has been renamed
+            #[cfg_attr(feature = "serde", serde(alias = "sec"))]
+            pub secs: i32,
+            // This alias is required for ros2 where field has been renamed
+            #[cfg_attr(feature = "serde", serde(alias = "nanosec"))]
+            pub nsecs: i32,
+        }
+
+        /// Matches the integral ROS duration type, see roslibrust_codegen's Duration
+        #[derive(Debug, Default, Clone, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize))]
+        pub struct Duration {
+            pub sec: i32,
+            pub nsec: i32,
+        }
+    }
+}
+
+pub fn generate_struct(msg: MessageFile, options: &CodegenOptions) -> Result<TokenStream, Error> {
     let ros_type_name = msg.get_full_name();
-    let attrs = derive_attrs();
+    let mut attrs = derive_attrs(options);
+    attrs.extend(extra_attrs(&msg.parsed.package, &msg.parsed.name, options)?);
+    let version = msg.parsed.version.unwrap_or(RosVersion::ROS1);
+    let builder = if options.builders {
+        generate_builder(&msg, options)?
+    } else {
+        quote! {}
+    };
+    let constant_enums = if options.constant_enums {
+        generate_constant_enums(&msg.parsed, version)
+    } else {
+        quote! {}
+    };
+    // ROS2 messages aren't sent with the ROS1 backend, and would lose the bounds checks of their serde helpers
+    let (codec_impls, codec_fns) = if options.generates_ros1_codec() && version == RosVersion::ROS1
+    {
+        generate_ros1_codec(&msg.parsed, options)?
+    } else {
+        (quote! {}, quote! {})
+    };
+    let lazy = if options.ros1_lazy && version == RosVersion::ROS1 {
+        generate_ros1_lazy(&msg.parsed, options)?
+    } else {
+        quote! {}
+    };
+    let borrowed = if options.ros1_borrowed && version == RosVersion::ROS1 {
+        generate_ros1_borrowed(&msg, options)?
+    } else {
+        quote! {}
+    };
     let fields = msg
         .parsed
         .fields
         .into_iter()
         .map(|field| {
-            generate_field_definition(
-                field,
-                &msg.parsed.package,
-                msg.parsed.version.unwrap_or(RosVersion::ROS1),
-            )
+            generate_field_definition(field, &msg.parsed.package, version, options)
+                .map(|(definition, _)| definition)
         })
         .collect::<Result<Vec<TokenStream>, _>>()?;
 
@@ -81,30 +568,50 @@ pub fn generate_struct(msg: MessageFile) -> Result<TokenStream, Error> {
             generate_constant_field_definition(
                 constant,
                 msg.parsed.version.unwrap_or(RosVersion::ROS1),
+                options,
             )
         })
         .collect::<Result<Vec<TokenStream>, _>>()?;
 
     let struct_name = format_ident!("{}", msg.parsed.name);
     let md5sum = msg.md5sum;
+    let type_hash = type_hash_const(&msg.type_hash);
     let definition = msg.definition;
 
+    let doc = doc_attrs(&msg.parsed.comment);
     // Raw here is only used to make the generated code look better.
     let raw_message_definition = generate_raw_string_literal(&definition);
+    let cfg = roslibrust_cfg(options);
 
     let mut base = quote! {
+        #doc
         #[allow(non_snake_case)]
         #(#attrs )*
         pub struct #struct_name {
             #(#fields )*
         }
 
+        #cfg
         impl ::roslibrust::RosMessageType for #struct_name {
             const ROS_TYPE_NAME: &'static str = #ros_type_name;
             const MD5SUM: &'static str = #md5sum;
             const DEFINITION: &'static str = #raw_message_definition;
+            #type_hash
+            #codec_fns
         }
     };
+    base.extend(codec_impls);
+    base.extend(borrowed);
+    base.extend(lazy);
+
+    base.extend(quote! {
+        #[allow(unused)]
+        impl #struct_name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+        }
+    });
 
     // Only if we have constants append the impl
     if !constants.is_empty() {
@@ -115,28 +622,297 @@ pub fn generate_struct(msg: MessageFile) -> Result<TokenStream, Error> {
             }
         });
     }
+    base.extend(builder);
+    base.extend(constant_enums);
     Ok(base)
 }
 
+/// Generates `{Name}Builder` with a setter for each field, along with `{Name}::builder()` to create it
+fn generate_builder(msg: &MessageFile, options: &CodegenOptions) -> Result<TokenStream, Error> {
+    let fields = &msg.parsed.fields;
+    let version = msg.parsed.version.unwrap_or(RosVersion::ROS1);
+    if fields.iter().any(|field| field.field_name == "build") {
+        log::warn!(
+            "Not generating a builder for {}, its field named build would conflict with the builder's build()",
+            msg.get_full_name()
+        );
+        return Ok(quote! {});
+    }
+    let struct_name = format_ident!("{}", msg.parsed.name);
+    let builder_name = format_ident!("{}Builder", msg.parsed.name);
+    let builder_doc = format!(
+        "Builds a [{}] from its default value, one field at a time",
+        msg.parsed.name
+    );
+
+    let mut setters = fields
+        .iter()
+        .map(|field| {
+            let (_, field_type) =
+                generate_field_definition(field.clone(), &msg.parsed.package, version, options)?;
+            let field_name = format_ident!("r#{}", field.field_name);
+            Ok(quote! {
+                pub fn #field_name(mut self, #field_name: impl Into<#field_type>) -> Self {
+                    self.inner.#field_name = #field_name.into();
+                    self
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    // Messages with a header can set its most commonly set fields directly
+    let has_header_field = fields.iter().any(|field| {
+        field.field_name == "header"
+            && field.field_type.field_type == "Header"
+            && field.field_type.array_info.is_none()
+            && matches!(
+                field.field_type.package_name.as_deref(),
+                None | Some("std_msgs")
+            )
+    });
+    let is_unused = |name: &str| !fields.iter().any(|field| field.field_name == name);
+    if has_header_field && is_unused("frame_id") {
+        let string_type = if options.no_std {
+            quote! { ::alloc::string::String }
+        } else {
+            quote! { ::std::string::String }
+        };
+        setters.push(quote! {
+            pub fn frame_id(mut self, frame_id: impl Into<#string_type>) -> Self {
+                self.inner.header.frame_id = frame_id.into();
+                self
+            }
+        });
+    }
+    if has_header_field && is_unused("stamp") {
+        let ros_type = match version {
+            RosVersion::ROS1 => "time",
+            RosVersion::ROS2 => "builtin_interfaces/Time",
+        };
+        let stamp_type =
+            TokenStream::from_str(&primitive_rust_type(ros_type, version, options)?)
+                .map_err(|e| Error::with("Failed to parse the type of the header stamp", e))?;
+        setters.push(quote! {
+            pub fn stamp(mut self, stamp: impl Into<#stamp_type>) -> Self {
+                self.inner.header.stamp = stamp.into();
+                self
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #[derive(Clone, Debug, Default)]
+        pub struct #builder_name {
+            inner: #struct_name,
+        }
+
+        #[allow(unused, non_snake_case)]
+        impl #builder_name {
+            #(#setters )*
+
+            pub fn build(self) -> #struct_name {
+                self.inner
+            }
+        }
+
+        #[allow(unused)]
+        impl #struct_name {
+            pub fn builder() -> #builder_name {
+                // A field named default would shadow Default::default on the builder
+                #builder_name {
+                    inner: ::core::default::Default::default(),
+                }
+            }
+        }
+    })
+}
+
+/// Returns the Rust type of a primitive ROS type, respecting the options' time type and no_std
+fn primitive_rust_type(
+    ros_type: &str,
+    version: RosVersion,
+    options: &CodegenOptions,
+) -> Result<String, Error> {
+    if let Some(time_type) = options.time_type.rust_type(ros_type) {
+        return Ok(time_type.to_owned());
+    }
+    let rust_type = convert_ros_type_to_rust_type(version, ros_type)
+        .ok_or(Error::new(format!("No Rust type for {ros_type}")))?;
+    if options.no_std {
+        Ok(no_std_path(rust_type))
+    } else {
+        Ok(rust_type.to_owned())
+    }
+}
+
+/// A crate whose generated messages can be converted to and from ours
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Foreign {
+    /// See [CodegenOptions::r2r_conversions]
+    R2r,
+    /// See [CodegenOptions::rosrust_conversions]
+    Rosrust,
+}
+
+impl Foreign {
+    /// Converts a time or duration `value`, or returns None if the field isn't one
+    fn convert_time(
+        self,
+        field: &FieldInfo,
+        value: TokenStream,
+        to_foreign: bool,
+    ) -> Option<TokenStream> {
+        if field.field_type.package_name.is_some() {
+            return None;
+        }
+        let is_time = match field.field_type.field_type.as_str() {
+            "time" | "builtin_interfaces/Time" => true,
+            "duration" | "builtin_interfaces/Duration" => false,
+            _ => return None,
+        };
+        let ours = quote! { ::roslibrust::codegen::integral_types };
+        Some(match (self, is_time, to_foreign) {
+            (Foreign::R2r, true, true) => quote! {
+                ::r2r::builtin_interfaces::msg::Time { sec: #value.secs, nanosec: #value.nsecs as u32 }
+            },
+            (Foreign::R2r, true, false) => quote! {
+                #ours::Time { secs: #value.sec, nsecs: #value.nanosec as i32 }
+            },
+            (Foreign::R2r, false, true) => quote! {
+                ::r2r::builtin_interfaces::msg::Duration { sec: #value.sec, nanosec: #value.nsec as u32 }
+            },
+            (Foreign::R2r, false, false) => quote! {
+                #ours::Duration { sec: #value.sec, nsec: #value.nanosec as i32 }
+            },
+            (Foreign::Rosrust, true, true) => quote! {
+                ::rosrust::Time { sec: #value.secs as u32, nsec: #value.nsecs as u32 }
+            },
+            (Foreign::Rosrust, true, false) => quote! {
+                #ours::Time { secs: #value.sec as i32, nsecs: #value.nsec as i32 }
+            },
+            (Foreign::Rosrust, false, true) => quote! {
+                ::rosrust::Duration { sec: #value.sec, nsec: #value.nsec }
+            },
+            (Foreign::Rosrust, false, false) => quote! {
+                #ours::Duration { sec: #value.sec, nsec: #value.nsec }
+            },
+        })
+    }
+}
+
+/// Generates `From` conversions in both directions between a message and the equivalent `foreign_type`
+/// generated by another crate, only compiled with the crate's feature enabled
+pub(crate) fn generate_conversions(
+    msg: &MessageFile,
+    foreign: Foreign,
+    foreign_type: &str,
+) -> Result<TokenStream, Error> {
+    let struct_name = format_ident!("{}", msg.parsed.name);
+    let foreign_type = TokenStream::from_str(foreign_type)
+        .map_err(|e| Error::with(format!("Invalid type {foreign_type}").as_str(), e))?;
+    let feature = match foreign {
+        Foreign::R2r => "r2r",
+        Foreign::Rosrust => "rosrust",
+    };
+
+    let mut to_foreign = vec![];
+    let mut from_foreign = vec![];
+    for field in &msg.parsed.fields {
+        let field_name = format_ident!("r#{}", field.field_name);
+        // Both crates add an underscore to fields named after keywords, which aren't valid identifiers
+        let foreign_name = if syn::parse_str::<syn::Ident>(&field.field_name).is_ok() {
+            field_name.clone()
+        } else {
+            format_ident!("{}_", field.field_name)
+        };
+        // Primitives are the same type in both crates, only messages need converting
+        let is_message = field.field_type.package_name.is_some();
+        let convert = |value: TokenStream, to: bool| {
+            let element = quote! { element };
+            let time = foreign.convert_time(field, element.clone(), to);
+            match (field.field_type.array_info, time) {
+                (None, Some(_)) => foreign.convert_time(field, value, to).unwrap(),
+                (None, None) if is_message => quote! { ::core::convert::Into::into(#value) },
+                (None, None) => value,
+                (Some(_), Some(time)) => quote! {
+                    ::roslibrust::codegen::conversions::collect(
+                        #value.into_iter().map(|#element| #time)
+                    )
+                },
+                (Some(_), None) if is_message => quote! {
+                    ::roslibrust::codegen::conversions::collect(
+                        #value.into_iter().map(::core::convert::Into::into)
+                    )
+                },
+                (Some(_), None) => quote! { ::roslibrust::codegen::conversions::collect(#value) },
+            }
+        };
+        let to = convert(quote! { value.#field_name }, true);
+        to_foreign.push(quote! { #foreign_name: #to });
+        let from = convert(quote! { value.#foreign_name }, false);
+        from_foreign.push(quote! { #field_name: #from });
+    }
+    // ROS2 gives messages without fields a placeholder field, which is left to its default
+    let rest = if msg.parsed.fields.is_empty() && foreign == Foreign::R2r {
+        quote! { ..::core::default::Default::default() }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #[cfg(feature = #feature)]
+        impl ::core::convert::From<#struct_name> for #foreign_type {
+            #[allow(unused_variables)]
+            fn from(value: #struct_name) -> Self {
+                Self {
+                    #(#to_foreign, )*
+                    #rest
+                }
+            }
+        }
+
+        #[cfg(feature = #feature)]
+        impl ::core::convert::From<#foreign_type> for #struct_name {
+            #[allow(unused_variables)]
+            fn from(value: #foreign_type) -> Self {
+                Self {
+                    #(#from_foreign, )*
+                }
+            }
+        }
+    })
+}
+
+/// Generates the definition of a field, returning it along with the field's Rust type
 fn generate_field_definition(
     field: FieldInfo,
     msg_pkg: &str,
     version: RosVersion,
-) -> Result<TokenStream, Error> {
-    let rust_field_type = match field.field_type.package_name {
-        Some(ref pkg) => {
+    options: &CodegenOptions,
+) -> Result<(TokenStream, TokenStream), Error> {
+    // Time and duration fields may be represented as std or chrono types rather than our own
+    let time_type = match field.field_type.package_name {
+        Some(_) => None,
+        None => options.time_type.rust_type(&field.field_type.field_type),
+    };
+    let rust_field_type = match (time_type, &field.field_type.package_name) {
+        (Some(time_type), _) => time_type.to_owned(),
+        (None, Some(pkg)) => {
             if pkg.as_str() == msg_pkg {
                 format!("self::{}", field.field_type.field_type)
             } else {
                 format!("{}::{}", pkg, field.field_type.field_type)
             }
         }
-        None => convert_ros_type_to_rust_type(version, &field.field_type.field_type)
-            .ok_or(Error::new(format!("No Rust type for {}", field.field_type)))?
-            .to_owned(),
+        (None, None) => primitive_rust_type(&field.field_type.field_type, version, options)
+            .map_err(|_| Error::new(format!("No Rust type for {}", field.field_type)))?,
     };
+    let is_bytes = is_bytes_field(&field, options);
     // Wrap type in appropriate Vec or array wrapper based on array information
     let rust_field_type = match field.field_type.array_info {
+        Some(None) if is_bytes => "::roslibrust::codegen::bytes::Bytes".to_string(),
+        Some(None) if options.no_std => format!("::alloc::vec::Vec<{rust_field_type}>"),
         Some(None) => {
             format!("::std::vec::Vec<{rust_field_type}>")
         }
@@ -155,8 +931,18 @@ fn generate_field_definition(
             default_val,
             field.field_type.array_info,
             version,
+            options.no_std,
         )?;
-        if field.field_type.array_info.is_some() {
+        if is_bytes {
+            // Bytes can't be built with vec![], so convert from the Vec
+            let vec_code: syn::LitStr = syn::parse2(default_val).map_err(|e| {
+                Error::with("Failed to parse generated default value for Bytes field", e)
+            })?;
+            let default_val = format!("{}.into()", vec_code.value());
+            quote! {
+                #[default(_code = #default_val)]
+            }
+        } else if field.field_type.array_info.is_some() {
             // For vectors use smart_defaults "dynamic" style
             quote! {
                 #[default(_code = #default_val)]
@@ -167,15 +953,28 @@ fn generate_field_definition(
               #[default(#default_val)]
             }
         }
+    } else if time_type == Some("::std::time::SystemTime") {
+        // SystemTime has no Default, so default to the epoch like our own Time type does
+        let default_str = match field.field_type.array_info {
+            Some(Some(fixed_array_length)) => {
+                format!("[::std::time::SystemTime::UNIX_EPOCH; {fixed_array_length}]")
+            }
+            Some(None) => "::std::vec::Vec::new()".to_string(),
+            None => "::std::time::SystemTime::UNIX_EPOCH".to_string(),
+        };
+        quote! { #[default(_code = #default_str)] }
     } else {
         // Okay this is messy, so default isn't defined for fixed sized arrays > 32 in length
         // so we have to manually provide a default if one isn't provided for arrays that large
         if let Some(Some(fixed_array_length)) = field.field_type.array_info {
             if fixed_array_length > 32 {
-                // Doing some evil indirection here with the _code directive and Deafult::default()
-                // to generate the default value for a single member of the array type, and then
-                // broadcasting that with an array constant. I can't believe this works...
-                let default_str = format!("[Default::default(); {fixed_array_length}]");
+                // Using from_fn rather than [Default::default(); N] so that arrays of non Copy types
+                // such as strings and other messages also work
+                let default_str = if options.no_std {
+                    "::core::array::from_fn(|_| Default::default())"
+                } else {
+                    "::std::array::from_fn(|_| Default::default())"
+                };
                 quote! { #[default(_code = #default_str)]}
             } else {
                 quote! {}
@@ -188,31 +987,135 @@ fn generate_field_definition(
     // Until serde supports const generics we need to use serde_big_array for fixed size arrays
     // Larger than 32.
     const MAX_FIXED_ARRAY_LEN: usize = 32;
-    let serde_line = match field.field_type.array_info {
-        Some(None) => {
-            // Special case for Vec<u8>, which massively benefit from optimizations in serde_bytes
-            // This makes deserializing an Image ~97% faster
-            if field.field_type.field_type == "uint8" {
-                quote! { #[serde(with = "::roslibrust::codegen::serde_bytes")] }
-            } else {
-                quote! {}
+    let serde_line = if options.no_std {
+        // There are no helpers enforcing the bounds of ROS2 bounded fields without roslibrust,
+        // only the handling of byte arrays and large arrays is kept
+        match field.field_type.array_info {
+            Some(None) if field.field_type.field_type == "uint8" => {
+                serde_attr(options, quote! { with = "::serde_bytes" })
+            }
+            Some(Some(fixed_array_len)) if fixed_array_len > MAX_FIXED_ARRAY_LEN => {
+                serde_attr(options, quote! { with = "::serde_big_array::BigArray" })
             }
+            _ => quote! {},
         }
-        Some(Some(fixed_array_len)) if fixed_array_len > MAX_FIXED_ARRAY_LEN => {
-            quote! { #[serde(with = "::roslibrust::codegen::BigArray")] }
+    } else {
+        match (
+            field.field_type.array_info,
+            field.field_type.array_bound,
+            field.field_type.string_bound,
+        ) {
+            // Mapped time types are converted to and from the ROS types when serialized
+            (_, Some(_), _) if time_type.is_some() => {
+                bail!(
+                "Bounded sequences of time types can't be generated with a TimeType other than TimeType::Ros: {} {}",
+                field.field_type,
+                field.field_name
+            );
+            }
+            (None, _, _) if time_type.is_some() => serde_attr(
+                options,
+                quote! { with = "::roslibrust::codegen::AsRosTime" },
+            ),
+            (Some(None), _, _) if time_type.is_some() => serde_attr(
+                options,
+                quote! { with = "::roslibrust::codegen::AsRosTimeSeq" },
+            ),
+            (Some(Some(_)), _, _) if time_type.is_some() => serde_attr(
+                options,
+                quote! { with = "::roslibrust::codegen::AsRosTimeArray" },
+            ),
+            // ROS2 bounded strings and sequences are validated when serialized and deserialized
+            (None, _, Some(string_bound)) => {
+                let with = format!("::roslibrust::codegen::BoundedString::<{string_bound}>");
+                serde_attr(options, quote! { with = #with })
+            }
+            (Some(Some(fixed_array_len)), _, Some(_)) if fixed_array_len > MAX_FIXED_ARRAY_LEN => {
+                bail!(
+                "Fixed size arrays of bounded strings longer than {MAX_FIXED_ARRAY_LEN} are not supported: {} {}",
+                field.field_type,
+                field.field_name
+            );
+            }
+            (Some(_), array_bound, Some(string_bound)) => {
+                let array_bound = array_bound
+                    .map(|bound| bound.to_string())
+                    .unwrap_or("{ usize::MAX }".to_string());
+                let with = format!(
+                    "::roslibrust::codegen::BoundedStrings::<{array_bound}, {string_bound}>"
+                );
+                serde_attr(options, quote! { with = #with })
+            }
+            (Some(None), Some(array_bound), None) => {
+                let with = format!("::roslibrust::codegen::BoundedSequence::<{array_bound}>");
+                serde_attr(options, quote! { with = #with })
+            }
+            (Some(None), _, _) => {
+                // Special case for Vec<u8>, which massively benefit from optimizations in serde_bytes
+                // This makes deserializing an Image ~97% faster
+                // Bytes already serializes as bytes with no special handling
+                if field.field_type.field_type == "uint8" && !is_bytes {
+                    serde_attr(
+                        options,
+                        quote! { with = "::roslibrust::codegen::serde_bytes" },
+                    )
+                } else {
+                    quote! {}
+                }
+            }
+            (Some(Some(fixed_array_len)), _, _) if fixed_array_len > MAX_FIXED_ARRAY_LEN => {
+                serde_attr(options, quote! { with = "::roslibrust::codegen::BigArray" })
+            }
+            _ => quote! {},
         }
-        _ => quote! {},
     };
-    Ok(quote! {
+    // Renames for consumers of JSON who don't expect the ROS names
+    let mut naming = vec![];
+    let renamed = options
+        .rename_fields
+        .map(|case| case.apply(&field.field_name))
+        .filter(|renamed| *renamed != field.field_name);
+    if let Some(renamed) = &renamed {
+        let original = &field.field_name;
+        naming.push(serde_attr(
+            options,
+            quote! { rename = #renamed, alias = #original },
+        ));
+    }
+    if let Some(case) = options.alias_fields {
+        let alias = case.apply(&field.field_name);
+        if alias != field.field_name && Some(&alias) != renamed.as_ref() {
+            naming.push(serde_attr(options, quote! { alias = #alias }));
+        }
+    }
+    if options.skip_serializing_empty_arrays && field.field_type.array_info == Some(None) {
+        let is_empty = if is_bytes {
+            "::roslibrust::codegen::bytes::Bytes::is_empty"
+        } else if options.no_std {
+            "::alloc::vec::Vec::is_empty"
+        } else {
+            "::std::vec::Vec::is_empty"
+        };
+        naming.push(serde_attr(
+            options,
+            quote! { default, skip_serializing_if = #is_empty },
+        ));
+    }
+    let doc = doc_attrs(&field.comment);
+    let definition = quote! {
+        #doc
         #default_line
         #serde_line
+        #(#naming)*
         #property_line
-    })
+    };
+    Ok((definition, rust_field_type))
 }
 
 fn generate_constant_field_definition(
     constant: ConstantInfo,
     version: RosVersion,
+    options: &CodegenOptions,
 ) -> Result<TokenStream, Error> {
     let constant_name = format_ident!("r#{}", constant.constant_name);
     let constant_rust_type = convert_ros_type_to_rust_type(version, &constant.constant_type)
@@ -236,8 +1139,313 @@ fn generate_constant_field_definition(
         &constant.constant_value,
         None,
         version,
+        options.no_std,
     )?;
-    Ok(quote! { pub const #constant_name: #constant_rust_type = #constant_value; })
+    let doc = doc_attrs(&constant.comment);
+    Ok(quote! {
+        #doc
+        pub const #constant_name: #constant_rust_type = #constant_value;
+    })
+}
+
+/// Generates an enum for each family of integer constants describing a field, see [CodegenOptions::constant_enums].
+/// The enums are generated into a module named after the message, e.g. `battery_state::PowerSupplyStatus`.
+fn generate_constant_enums(msg: &ParsedMessageFile, version: RosVersion) -> TokenStream {
+    let integer_type = |ros_type: &str| {
+        convert_ros_type_to_rust_type(version, ros_type).filter(|rust_type| {
+            matches!(
+                *rust_type,
+                "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64"
+            )
+        })
+    };
+    let fields = msg
+        .fields
+        .iter()
+        .filter(|field| {
+            field.field_type.package_name.is_none() && field.field_type.array_info.is_none()
+        })
+        .filter_map(|field| Some((field, integer_type(&field.field_type.field_type)?)))
+        .collect::<Vec<_>>();
+    let constants = msg
+        .constants
+        .iter()
+        .filter_map(|constant| Some((constant, integer_type(&constant.constant_type)?)))
+        .collect::<Vec<_>>();
+
+    // Each constant describes at most one field, the fields annotated with `enum:` taking theirs first
+    let mut grouped = vec![false; constants.len()];
+    let mut groups = vec![];
+    for (index, (field, rust_type)) in fields.iter().enumerate() {
+        let Some(target) = field.comment.as_deref().and_then(enum_annotation) else {
+            continue;
+        };
+        // `enum: PREFIX_*` takes the constants with the prefix, `enum: CONSTANT` those in the constant's block
+        let members = match target.strip_suffix('*') {
+            Some(prefix) => take_constants(&constants, &mut grouped, rust_type, |constant| {
+                constant.constant_name.strip_prefix(prefix)
+            }),
+            None => {
+                let block = constants
+                    .iter()
+                    .find(|(constant, _)| constant.constant_name == target)
+                    .map(|(constant, _)| constant.block);
+                take_constants(&constants, &mut grouped, rust_type, |constant| {
+                    (Some(constant.block) == block).then_some(constant.constant_name.as_str())
+                })
+            }
+        };
+        if members.is_empty() {
+            log::warn!(
+                "The enum annotation of {}/{} {} names no constants of its type: {target}",
+                msg.package,
+                msg.name,
+                field.field_name
+            );
+            continue;
+        }
+        groups.push((index, *field, *rust_type, members));
+    }
+    // Then constants are grouped with the field of the same type whose name they are prefixed with,
+    // e.g. POWER_SUPPLY_STATUS_CHARGING with power_supply_status, preferring the longest prefix
+    let mut by_length = fields.iter().enumerate().collect::<Vec<_>>();
+    by_length.sort_by_key(|(_, (field, _))| std::cmp::Reverse(field.field_name.len()));
+    for (index, (field, rust_type)) in by_length {
+        if groups.iter().any(|(i, ..)| *i == index) {
+            continue;
+        }
+        let prefix = format!("{}_", field.field_name.to_uppercase());
+        let members = take_constants(&constants, &mut grouped, rust_type, |constant| {
+            constant.constant_name.strip_prefix(&prefix)
+        });
+        if !members.is_empty() {
+            groups.push((index, *field, *rust_type, members));
+        }
+    }
+    // Then with the field named by the comment heading their block, e.g. "# Power supply health constants"
+    for (index, (field, rust_type)) in fields.iter().enumerate() {
+        if groups.iter().any(|(i, ..)| *i == index) {
+            continue;
+        }
+        let name = field.field_name.replace('_', " ");
+        let block = constants
+            .iter()
+            .zip(&grouped)
+            .find(|((constant, constant_type), grouped)| {
+                !**grouped
+                    && constant_type == rust_type
+                    && constant.comment.as_deref().is_some_and(|comment| {
+                        let words = comment
+                            .to_lowercase()
+                            .split(|c: char| !c.is_alphanumeric())
+                            .filter(|word| !word.is_empty())
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        format!(" {words} ").contains(&format!(" {name} "))
+                    })
+            })
+            .map(|((constant, _), _)| constant.block);
+        if block.is_none() {
+            continue;
+        }
+        let members = take_constants(&constants, &mut grouped, rust_type, |constant| {
+            (Some(constant.block) == block).then_some(constant.constant_name.as_str())
+        });
+        groups.push((index, *field, *rust_type, members));
+    }
+    // Failing that, constants without a prefix describe the only field of their type, e.g. GoalStatus's status
+    if groups.is_empty() {
+        for (index, (field, rust_type)) in fields.iter().enumerate() {
+            let has_only_field_of_type = fields.iter().filter(|(_, t)| t == rust_type).count() == 1;
+            let members = constants
+                .iter()
+                .filter(|(_, constant_type)| constant_type == rust_type)
+                .map(|(constant, _)| (*constant, constant.constant_name.as_str()))
+                .collect::<Vec<_>>();
+            if has_only_field_of_type && !members.is_empty() {
+                groups.push((index, *field, *rust_type, members));
+            }
+        }
+    }
+    groups.sort_by_key(|(index, ..)| *index);
+
+    let enums = groups
+        .into_iter()
+        .filter_map(|(_, field, rust_type, members)| {
+            let enum_name = pascal_case(&field.field_name);
+            // Words shared by all of the names are left out, e.g. COVARIANCE_TYPE_ of NavSatFix's constants
+            let mut shared = members[0].1.split_inclusive('_').collect::<Vec<_>>();
+            shared.pop();
+            for (_, name) in &members {
+                let words = name.split_inclusive('_').collect::<Vec<_>>();
+                let common = shared.iter().zip(&words).take_while(|(a, b)| a == b).count();
+                shared.truncate(common.min(words.len() - 1));
+            }
+            let shared_len = if members.len() > 1 { shared.concat().len() } else { 0 };
+            let variants = members
+                .iter()
+                .map(|(constant, name)| (constant, &name[shared_len..]))
+                .map(|(constant, name)| {
+                    let value = constant.constant_value.inner.trim().parse::<i128>().ok()?;
+                    let variant = syn::parse_str::<syn::Ident>(&pascal_case(name)).ok()?;
+                    Some((variant, value, doc_attrs(&constant.comment)))
+                })
+                .collect::<Option<Vec<_>>>();
+            let mut values = variants.iter().flatten().map(|(_, value, _)| value).collect::<Vec<_>>();
+            values.sort();
+            values.dedup();
+            match variants {
+                // Enums can't have two variants with the same value
+                Some(variants) if values.len() == variants.len() => {
+                    Some((enum_name, rust_type, variants))
+                }
+                _ => {
+                    log::debug!(
+                        "Not generating an enum for the constants of {}/{} {}, as they have repeated or unsupported values or names",
+                        msg.package, msg.name, field.field_name
+                    );
+                    None
+                }
+            }
+        })
+        .map(|(enum_name, rust_type, variants)| {
+            let enum_name = format_ident!("{enum_name}");
+            let rust_type = format_ident!("{rust_type}");
+            let enum_doc = format!("The values of [super::{}]'s `{}` field", msg.name, enum_name);
+            let definitions = variants.iter().map(|(variant, value, doc)| {
+                let value = TokenStream::from_str(&value.to_string()).unwrap();
+                quote! {
+                    #doc
+                    #variant = #value,
+                }
+            });
+            let names = variants.iter().map(|(variant, _, _)| variant).collect::<Vec<_>>();
+            let values = variants
+                .iter()
+                .map(|(_, value, _)| TokenStream::from_str(&value.to_string()).unwrap());
+            let count = names.len();
+            quote! {
+                #[doc = #enum_doc]
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                #[repr(#rust_type)]
+                pub enum #enum_name {
+                    #(#definitions)*
+                }
+
+                impl #enum_name {
+                    /// Every variant, in the order of the constants
+                    pub const ALL: [Self; #count] = [#(Self::#names,)*];
+
+                    /// Returns true if `value` is one of the constants
+                    pub fn is_valid(value: #rust_type) -> bool {
+                        <Self as ::core::convert::TryFrom<#rust_type>>::try_from(value).is_ok()
+                    }
+                }
+
+                impl ::core::convert::From<#enum_name> for #rust_type {
+                    fn from(value: #enum_name) -> Self {
+                        value as #rust_type
+                    }
+                }
+
+                impl ::core::convert::TryFrom<#rust_type> for #enum_name {
+                    type Error = #rust_type;
+
+                    /// Returns the value back if it isn't one of the constants
+                    fn try_from(value: #rust_type) -> ::core::result::Result<Self, #rust_type> {
+                        // Qualified in case an enum is itself named Result
+                        match value {
+                            #(#values => ::core::result::Result::Ok(Self::#names),)*
+                            _ => ::core::result::Result::Err(value),
+                        }
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    if enums.is_empty() {
+        return quote! {};
+    }
+    let module_name = snake_case(&msg.name);
+    let module_name = syn::parse_str::<syn::Ident>(&module_name)
+        .unwrap_or_else(|_| format_ident!("r#{module_name}"));
+    let module_doc = format!("Enums of the constants of [super::{}]", msg.name);
+    quote! {
+        #[doc = #module_doc]
+        pub mod #module_name {
+            #(#enums)*
+        }
+    }
+}
+
+/// Returns the target of an `enum: PREFIX_*` or `enum: CONSTANT` line in a field's comment
+fn enum_annotation(comment: &str) -> Option<&str> {
+    comment.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix("enum")?;
+        let target = match rest.strip_prefix(':') {
+            Some(target) => target,
+            None if rest.starts_with(char::is_whitespace) => rest,
+            None => return None,
+        };
+        let mut words = target.split_whitespace();
+        words.next().filter(|_| words.next().is_none())
+    })
+}
+
+/// Takes the constants of `rust_type` not in a group yet for which `name` returns the name of the variant
+fn take_constants<'a>(
+    constants: &[(&'a ConstantInfo, &str)],
+    grouped: &mut [bool],
+    rust_type: &str,
+    name: impl Fn(&'a ConstantInfo) -> Option<&'a str>,
+) -> Vec<(&'a ConstantInfo, &'a str)> {
+    let mut members = vec![];
+    for ((constant, constant_type), grouped) in constants.iter().zip(grouped) {
+        if *grouped || *constant_type != rust_type {
+            continue;
+        }
+        if let Some(name) = name(constant) {
+            *grouped = true;
+            members.push((*constant, name));
+        }
+    }
+    members
+}
+
+/// Converts a snake case or screaming snake case name to pascal case, e.g. `NOT_CHARGING` to `NotCharging`
+pub(crate) fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Converts a pascal case name to snake case, e.g. `BatteryState` to `battery_state` and `GoalID` to `goal_id`
+pub(crate) fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
 }
 
 pub fn generate_mod(
@@ -246,19 +1454,47 @@ pub fn generate_mod(
     all_pkgs: &[String],
 ) -> TokenStream {
     let mod_name = format_ident!("{}", &pkg_name);
+    let contents = generate_mod_contents(&pkg_name, struct_definitions, all_pkgs);
+
+    quote! {
+        #[allow(unused_imports)]
+        pub mod #mod_name {
+            #contents
+        }
+    }
+}
+
+/// Generates the body of a package's module, which imports all other packages from its parent module
+pub fn generate_mod_contents(
+    pkg_name: &str,
+    struct_definitions: Vec<TokenStream>,
+    all_pkgs: &[String],
+) -> TokenStream {
     let all_pkgs = all_pkgs
         .iter()
-        .filter(|item| item.as_str() != pkg_name.as_str())
+        .filter(|item| item.as_str() != pkg_name)
         .map(|pkg| format_ident!("{}", pkg))
         .collect::<Vec<_>>();
 
     quote! {
-        #[allow(unused_imports)]
-        pub mod #mod_name {
-            #(use super::#all_pkgs; )*
+        #(use super::#all_pkgs; )*
 
-            #(#struct_definitions )*
-        }
+        #(#struct_definitions )*
+    }
+}
+
+/// Generates a mod.rs declaring a module for each package, whose contents are in a file of the same name
+pub fn generate_mod_file(all_pkgs: &[String]) -> TokenStream {
+    let all_pkgs = all_pkgs
+        .iter()
+        .map(|pkg| format_ident!("{}", pkg))
+        .collect::<Vec<_>>();
+
+    quote! {
+        #(
+            #[allow(unused_imports)]
+            pub mod #all_pkgs;
+        )*
     }
 }
 
@@ -267,9 +1503,10 @@ fn ros_literal_to_rust_literal(
     literal: &RosLiteral,
     array_info: Option<Option<usize>>,
     version: RosVersion,
+    no_std: bool,
 ) -> Result<TokenStream, Error> {
     // TODO: The naming of all the functions under this tree seems inaccurate
-    parse_ros_value(ros_type, &literal.inner, array_info, version)
+    parse_ros_value(ros_type, &literal.inner, array_info, version, no_std)
 }
 
 // Converts a ROS string to a literal value
@@ -277,13 +1514,21 @@ fn ros_literal_to_rust_literal(
 // Wraps a serde_json deserialize call with our style of error handling.
 fn generic_parse_value<T: DeserializeOwned + ToTokens + std::fmt::Debug>(
     value: &str,
-    is_vec: bool,
+    array_info: Option<Option<usize>>,
+    no_std: bool,
 ) -> Result<TokenStream, Error> {
-    if is_vec {
+    if let Some(fixed_length) = array_info {
         let parsed: Vec<T> = serde_json::from_str(value).map_err(|e|
             Error::with(format!("Failed to parse a literal value in a message file to the corresponding rust type: {value} to {}", std::any::type_name::<T>()).as_str(), e)
         )?;
-        let vec_str = format!("vec!{parsed:?}");
+        let vec_str = match fixed_length {
+            Some(fixed_length) => {
+                check_fixed_length(value, parsed.len(), fixed_length)?;
+                format!("{parsed:?}")
+            }
+            None if no_std => format!("::alloc::vec!{parsed:?}"),
+            None => format!("vec!{parsed:?}"),
+        };
         Ok(quote! { #vec_str })
     } else {
         let parsed: T = serde_json::from_str(value).map_err(|e|
@@ -293,6 +1538,46 @@ fn generic_parse_value<T: DeserializeOwned + ToTokens + std::fmt::Debug>(
     }
 }
 
+fn check_fixed_length(value: &str, len: usize, fixed_length: usize) -> Result<(), Error> {
+    if len != fixed_length {
+        bail!("Default value {value} has {len} elements, but the array has a fixed length of {fixed_length}");
+    }
+    Ok(())
+}
+
+/// Parses the value of a string array e.g. `["first", 'second']`, ROS2 allows either quote style and
+/// does not escape strings so the contents are taken verbatim up to the closing quote.
+fn parse_string_array(value: &str) -> Result<Vec<String>, Error> {
+    let Some(inner) = value
+        .trim()
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+    else {
+        bail!("String array value must be enclosed in square brackets: {value}");
+    };
+    let mut parsed = vec![];
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            ',' => {}
+            c if c.is_whitespace() => {}
+            '\'' | '"' => {
+                let mut element = String::new();
+                loop {
+                    match chars.next() {
+                        Some(end) if end == c => break,
+                        Some(other) => element.push(other),
+                        None => bail!("Unterminated string in string array value: {value}"),
+                    }
+                }
+                parsed.push(element);
+            }
+            _ => bail!("String array value {value} contains an element not enclosed in single or double quotes"),
+        }
+    }
+    Ok(parsed)
+}
+
 /// For a given, which is either a ROS constant or default, parse the constant and convert it into a rust TokenStream
 /// which represents the same literal value. This handles frustrating edge cases that are not well documented features
 /// in either ROS1 or ROS2 such as:
@@ -303,35 +1588,45 @@ fn generic_parse_value<T: DeserializeOwned + ToTokens + std::fmt::Debug>(
 /// Note: No idea of "constant arrays" are intended to be supported in ROS...
 /// `ros_type` -- Expects the string key of the determined rust type to hold the value. Should come from one of the type map constants.
 /// `value` -- Expects the trimmed string containing only the value expression
-/// `is_vec` -- True iff the type is an array type
+/// `array_info` -- Array information of the type, fixed size arrays produce an array literal and must match in length
+/// `no_std` -- Whether the literal must compile without the std prelude
 /// TODO I'd like this to take FieldType, but want it to also work with constants...
 fn parse_ros_value(
     ros_type: &str,
     value: &str,
     array_info: Option<Option<usize>>,
     version: RosVersion,
+    no_std: bool,
 ) -> Result<TokenStream, Error> {
-    let is_vec = array_info.is_some();
+    // Without std the ToString trait isn't in scope
+    let to_string = if no_std {
+        "::alloc::string::ToString::to_string(x)"
+    } else {
+        "x.to_string()"
+    };
     match ros_type {
-        "bool" => generic_parse_value::<bool>(value, is_vec),
-        "float64" => generic_parse_value::<f64>(value, is_vec),
-        "float32" => generic_parse_value::<f32>(value, is_vec),
-        "uint8" | "char" | "byte" => generic_parse_value::<u8>(value, is_vec),
-        "int8" => generic_parse_value::<i8>(value, is_vec),
-        "uint16" => generic_parse_value::<u16>(value, is_vec),
-        "int16" => generic_parse_value::<i16>(value, is_vec),
-        "uint32" => generic_parse_value::<u32>(value, is_vec),
-        "int32" => generic_parse_value::<i32>(value, is_vec),
-        "uint64" => generic_parse_value::<u64>(value, is_vec),
-        "int64" => generic_parse_value::<i64>(value, is_vec),
+        "bool" => generic_parse_value::<bool>(value, array_info, no_std),
+        "float64" => generic_parse_value::<f64>(value, array_info, no_std),
+        "float32" => generic_parse_value::<f32>(value, array_info, no_std),
+        "uint8" | "char" | "byte" => generic_parse_value::<u8>(value, array_info, no_std),
+        "int8" => generic_parse_value::<i8>(value, array_info, no_std),
+        "uint16" => generic_parse_value::<u16>(value, array_info, no_std),
+        "int16" => generic_parse_value::<i16>(value, array_info, no_std),
+        "uint32" => generic_parse_value::<u32>(value, array_info, no_std),
+        "int32" => generic_parse_value::<i32>(value, array_info, no_std),
+        "uint64" => generic_parse_value::<u64>(value, array_info, no_std),
+        "int64" => generic_parse_value::<i64>(value, array_info, no_std),
         "string" => {
             // String is a special case because of quotes and to_string()
-            if is_vec {
-                // TODO there is a bug here, no idea how I should be attempting to convert / escape single quotes here...
-                let parsed: Vec<String> = serde_json::from_str(value).map_err(|e|
-                    Error::with(format!("Failed to parse a literal value in a message file to the corresponding rust type: {value} to Vec<String>").as_str(), e)
-                )?;
-                let vec_str = format!("{parsed:?}.iter().map(|x| x.to_string()).collect()");
+            if let Some(fixed_length) = array_info {
+                let parsed = parse_string_array(value)?;
+                let vec_str = match fixed_length {
+                    Some(fixed_length) => {
+                        check_fixed_length(value, parsed.len(), fixed_length)?;
+                        format!("{parsed:?}.map(|x| {to_string})")
+                    }
+                    None => format!("{parsed:?}.iter().map(|x| {to_string}).collect()"),
+                };
                 Ok(quote! { #vec_str })
             } else {
                 match version {
@@ -1,5 +1,6 @@
 use simple_error::{bail, SimpleError};
 
+use roslibrust_common::ros1_codec::{DecodeError, Reader, Ros1Decode, Ros1Encode};
 use roslibrust_common::RosMessageType;
 
 /// Matches the integral ros1 type time, with extensions for ease of use
@@ -131,6 +132,47 @@ impl TryFrom<Duration> for std::time::Duration {
     }
 }
 
+// Time and Duration are always two 32 bit integers on the wire
+macro_rules! impl_ros1_codec {
+    ($t:ty, $secs:ident, $nsecs:ident) => {
+        impl Ros1Encode for $t {
+            fn encoded_len(&self) -> usize {
+                8
+            }
+
+            fn encode(&self, buf: &mut Vec<u8>) {
+                self.$secs.encode(buf);
+                self.$nsecs.encode(buf);
+            }
+
+            fn encoded_len_of_slice(items: &[Self]) -> usize {
+                items.len() * 8
+            }
+        }
+
+        impl Ros1Decode for $t {
+            fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+                Ok(Self {
+                    $secs: Ros1Decode::decode(reader)?,
+                    $nsecs: Ros1Decode::decode(reader)?,
+                })
+            }
+
+            fn skip(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+                Self::skip_n(reader, 1)
+            }
+
+            fn skip_n(reader: &mut Reader<'_>, len: usize) -> Result<(), DecodeError> {
+                // Both are a pair of i32s
+                <[i32; 2]>::skip_n(reader, len)
+            }
+        }
+    };
+}
+
+impl_ros1_codec!(Time, secs, nsecs);
+impl_ros1_codec!(Duration, sec, nsec);
+
 /// Conversion from chrono::DateTime<chrono::Utc> to our internal Time type
 #[cfg(feature = "chrono")]
 impl TryFrom<chrono::DateTime<chrono::Utc>> for Time {
@@ -14,7 +14,7 @@ use quote::quote;
 use simple_error::{bail, SimpleError as Error};
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::{Debug, Display};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use utils::Package;
 
 mod gen;
@@ -27,11 +27,26 @@ use utils::RosVersion;
 pub mod integral_types;
 pub use integral_types::*;
 
+pub mod bounded;
+pub mod build_helper;
+pub mod cache;
+pub mod conversions;
+mod options;
+pub mod proto;
+pub mod time_serde;
+mod type_hash;
+pub use bounded::{BoundedSequence, BoundedString, BoundedStrings}; // Used in generated code for ROS2 bounded fields
+pub use options::{ByteArrayType, CodegenOptions, FieldCase, TimeType};
+pub use time_serde::{AsRosTime, AsRosTimeArray, AsRosTimeSeq, RosTimeRepr}; // Used in generated code when a TimeType is selected
+
 // These pub use statements are here to be able to export the dependencies of the generated code
 // so that crates using this crate don't need to add these dependencies themselves.
 // Our generated code should find these exports.
 // Modeled from: https://users.rust-lang.org/t/proc-macros-using-third-party-crate/42465/4
 pub use ::serde;
+pub use bytes; // Used in generated code when ByteArrayType::Bytes is selected
+#[cfg(feature = "chrono")]
+pub use chrono; // Used in generated code when TimeType::Chrono is selected
 pub use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use serde_big_array::BigArray; // Used in generated code for large fixed sized arrays
 pub use serde_bytes;
@@ -44,24 +59,32 @@ pub use smart_default::SmartDefault; // Used in generated code for default value
 pub struct MessageFile {
     pub(crate) parsed: ParsedMessageFile,
     pub(crate) md5sum: String,
+    // The ROS2 type hash of the message, empty for ROS1 messages
+    pub(crate) type_hash: String,
     // This is the expanded definition of the message for use in message_definition field of
     // a connection header.
     // See how https://wiki.ros.org/ROS/TCPROS references gendeps --cat
     // See https://wiki.ros.org/roslib/gentools for an example of the output
     pub(crate) definition: String,
     pub(crate) is_fixed_length: bool,
+    // Whether the message or any of its dependencies has a string or byte array, which its borrowed form references
+    pub(crate) has_borrowed_fields: bool,
 }
 
 impl MessageFile {
     fn resolve(parsed: ParsedMessageFile, graph: &BTreeMap<String, MessageFile>) -> Option<Self> {
         let md5sum = Self::compute_md5sum(&parsed, graph)?;
+        let type_hash = type_hash::message_type_hash(&parsed, graph)?;
         let definition = Self::compute_full_definition(&parsed, graph)?;
         let is_fixed_length = Self::determine_if_fixed_length(&parsed, graph)?;
+        let has_borrowed_fields = Self::determine_if_has_borrowed_fields(&parsed, graph)?;
         Some(MessageFile {
             parsed,
             md5sum,
+            type_hash,
             definition,
             is_fixed_length,
+            has_borrowed_fields,
         })
     }
 
@@ -81,6 +104,11 @@ impl MessageFile {
         self.md5sum.as_str()
     }
 
+    /// Returns the ROS2 type hash e.g. "RIHS01_df66...", which is empty for ROS1 messages
+    pub fn get_type_hash(&self) -> &str {
+        self.type_hash.as_str()
+    }
+
     pub fn get_fields(&self) -> &[FieldInfo] {
         &self.parsed.fields
     }
@@ -142,27 +170,43 @@ impl MessageFile {
         Some(md5sum_content)
     }
 
-    /// Returns the set of all referenced non-intrinsic field types in this type or any of its dependencies
-    fn get_unique_field_types(
+    /// Returns all referenced non-intrinsic field types in this type or any of its dependencies
+    /// Types are returned once each, in the depth first order of their first appearance, matching gentools
+    fn get_all_dependencies(
         parsed: &ParsedMessageFile,
         graph: &BTreeMap<String, MessageFile>,
-    ) -> Option<BTreeSet<String>> {
-        let mut unique_field_types = BTreeSet::new();
+    ) -> Option<Vec<String>> {
+        let mut dependencies = vec![];
         for field in &parsed.fields {
             let field_type = field.field_type.field_type.as_str();
             if is_intrinsic_type(parsed.version.unwrap_or(RosVersion::ROS1), field_type) {
                 continue;
             }
-            let sub_message = graph.get(field.get_full_name().as_str())?;
+            let field_full_name = field.get_full_name();
+            if dependencies.contains(&field_full_name) {
+                continue;
+            }
+            let Some(sub_message) = graph.get(field_full_name.as_str()) else {
+                log::error!(
+                    "Unable to find message type: {field_full_name:?}, while computing full definition of {}",
+                    parsed.get_full_name()
+                );
+                return None;
+            };
             // Note: need to add both the field that is referenced AND its sub-dependencies
-            unique_field_types.insert(field.get_full_name());
-            let mut sub_deps = Self::get_unique_field_types(&sub_message.parsed, graph)?;
-            unique_field_types.append(&mut sub_deps);
+            let sub_dependencies = Self::get_all_dependencies(&sub_message.parsed, graph)?;
+            dependencies.push(field_full_name);
+            for sub_dependency in sub_dependencies {
+                if !dependencies.contains(&sub_dependency) {
+                    dependencies.push(sub_dependency);
+                }
+            }
         }
-        Some(unique_field_types)
+        Some(dependencies)
     }
 
     /// Computes the full definition of the message, including all referenced custom types
+    /// Each dependency's own definition is included exactly once, as is produced by `gendeps --cat`
     /// For reference see: https://wiki.ros.org/roslib/gentools
     /// Implementation in gentools: https://github.com/strawlab/ros/blob/c3a8785f9d9551cc05cd74000c6536e2244bb1b1/core/roslib/src/roslib/gentools.py#L245
     fn compute_full_definition(
@@ -173,17 +217,12 @@ impl MessageFile {
         definition_content.push_str(&format!("{}\n", parsed.source.trim()));
         let sep: &str =
             "================================================================================\n";
-        for field in Self::get_unique_field_types(parsed, graph)? {
-            let Some(sub_message) = graph.get(&field) else {
-                log::error!(
-                    "Unable to find message type: {field:?}, while computing full definition of {}",
-                    parsed.get_full_name()
-                );
-                return None;
-            };
+        for dependency in Self::get_all_dependencies(parsed, graph)? {
+            // Presence in the graph was checked while collecting dependencies
+            let sub_message = graph.get(&dependency)?;
             definition_content.push_str(sep);
             definition_content.push_str(&format!("MSG: {}\n", sub_message.get_full_name()));
-            definition_content.push_str(&format!("{}\n", sub_message.get_definition().trim()));
+            definition_content.push_str(&format!("{}\n", sub_message.parsed.source.trim()));
         }
         // Remove trailing \n added by concatenation logic
         definition_content.pop();
@@ -215,6 +254,28 @@ impl MessageFile {
         }
         Some(true)
     }
+
+    fn determine_if_has_borrowed_fields(
+        parsed: &ParsedMessageFile,
+        graph: &BTreeMap<String, MessageFile>,
+    ) -> Option<bool> {
+        for field in &parsed.fields {
+            if field.field_type.package_name.is_none() {
+                if field.field_type.field_type == "string"
+                    || (field.field_type.field_type == "uint8"
+                        && field.field_type.array_info == Some(None))
+                {
+                    return Some(true);
+                }
+            } else {
+                let field_msg = graph.get(field.get_full_name().as_str())?;
+                if Self::determine_if_has_borrowed_fields(&field_msg.parsed, graph)? {
+                    return Some(true);
+                }
+            }
+        }
+        Some(false)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -223,6 +284,7 @@ pub struct ServiceFile {
     pub(crate) request: MessageFile,
     pub(crate) response: MessageFile,
     pub(crate) md5sum: String,
+    pub(crate) type_hash: String,
 }
 
 impl ServiceFile {
@@ -232,11 +294,13 @@ impl ServiceFile {
             MessageFile::resolve(parsed.response_type.clone(), graph),
         ) {
             let md5sum = Self::compute_md5sum(&parsed, graph)?;
+            let type_hash = type_hash::service_type_hash(&parsed, graph)?;
             Some(ServiceFile {
                 parsed,
                 request,
                 response,
                 md5sum,
+                type_hash,
             })
         } else {
             log::error!("Unable to resolve dependencies in service: {parsed:#?}");
@@ -268,6 +332,11 @@ impl ServiceFile {
         self.md5sum.clone()
     }
 
+    /// Returns the ROS2 type hash, which is empty for ROS1 services
+    pub fn get_type_hash(&self) -> &str {
+        self.type_hash.as_str()
+    }
+
     fn compute_md5sum(
         parsed: &ParsedServiceFile,
         graph: &BTreeMap<String, MessageFile>,
@@ -320,14 +389,24 @@ pub struct FieldType {
     // Is Some(None) if it's an array type of variable size or Some(Some(N))
     // if it's an array type of fixed size.
     pub array_info: Option<Option<usize>>,
+    // Maximum length of a bounded sequence e.g. Some(3) for `int32[<=3]`, only exists in ROS2
+    // Bounded sequences have array_info of Some(None)
+    pub array_bound: Option<usize>,
+    // Maximum length of a bounded string e.g. Some(10) for `string<=10`, only exists in ROS2
+    pub string_bound: Option<usize>,
 }
 
 impl std::fmt::Display for FieldType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.array_info {
-            Some(Some(n)) => f.write_fmt(format_args!("{}[{}]", self.field_type, n)),
-            Some(None) => f.write_fmt(format_args!("{}[]", self.field_type)),
-            None => f.write_fmt(format_args!("{}", self.field_type)),
+        f.write_str(&self.field_type)?;
+        if let Some(bound) = self.string_bound {
+            f.write_fmt(format_args!("<={bound}"))?;
+        }
+        match (self.array_info, self.array_bound) {
+            (Some(Some(n)), _) => f.write_fmt(format_args!("[{n}]")),
+            (Some(None), Some(bound)) => f.write_fmt(format_args!("[<={bound}]")),
+            (Some(None), None) => f.write_str("[]"),
+            (None, _) => Ok(()),
         }
     }
 }
@@ -339,6 +418,8 @@ pub struct FieldInfo {
     pub field_name: String,
     // Exists if this is a ros2 message field with a default value
     pub default: Option<RosLiteral>,
+    /// The comments above and after the field in the message file
+    pub comment: Option<String>,
 }
 
 // Because TokenStream doesn't impl PartialEq we have to do it manually for FieldInfo
@@ -367,6 +448,11 @@ pub struct ConstantInfo {
     pub constant_type: String,
     pub constant_name: String,
     pub constant_value: RosLiteral,
+    /// The comments above and after the constant in the message file
+    pub comment: Option<String>,
+    /// Counts the blocks of constants before this one's in the message file, blocks being separated by empty
+    /// lines or fields. ROS2 interfaces such as BatteryState group related constants into blocks.
+    pub block: usize,
 }
 
 // Because TokenStream doesn't impl PartialEq we have to do it manually for ConstantInfo
@@ -386,10 +472,18 @@ impl PartialEq for ConstantInfo {
 /// found in ROS_PACKAGE_PATH environment variable.
 pub fn find_and_generate_ros_messages(
     additional_search_paths: Vec<PathBuf>,
+) -> Result<(TokenStream, Vec<PathBuf>), Error> {
+    find_and_generate_ros_messages_with_options(additional_search_paths, &CodegenOptions::default())
+}
+
+/// Same as [find_and_generate_ros_messages], but with [CodegenOptions] controlling the generated code.
+pub fn find_and_generate_ros_messages_with_options(
+    additional_search_paths: Vec<PathBuf>,
+    options: &CodegenOptions,
 ) -> Result<(TokenStream, Vec<PathBuf>), Error> {
     let mut ros_package_paths = utils::get_search_paths();
     ros_package_paths.extend(additional_search_paths);
-    find_and_generate_ros_messages_without_ros_package_path(ros_package_paths)
+    find_and_generate_ros_messages_without_ros_package_path_with_options(ros_package_paths, options)
 }
 
 /// Searches a list of paths for ROS packages and generates struct definitions
@@ -402,28 +496,79 @@ pub fn find_and_generate_ros_messages(
 pub fn find_and_generate_ros_messages_without_ros_package_path(
     search_paths: Vec<PathBuf>,
 ) -> Result<(TokenStream, Vec<PathBuf>), Error> {
-    let (messages, services, actions) = find_and_parse_ros_messages(&search_paths)?;
+    find_and_generate_ros_messages_without_ros_package_path_with_options(
+        search_paths,
+        &CodegenOptions::default(),
+    )
+}
+
+/// Same as [find_and_generate_ros_messages_without_ros_package_path], but with [CodegenOptions] controlling
+/// the generated code.
+pub fn find_and_generate_ros_messages_without_ros_package_path_with_options(
+    search_paths: Vec<PathBuf>,
+    options: &CodegenOptions,
+) -> Result<(TokenStream, Vec<PathBuf>), Error> {
+    let (messages, services, actions) =
+        select_ros_files(find_and_parse_ros_messages(&search_paths)?, options);
     if messages.is_empty() && services.is_empty() {
         // I'm considering this an error for now, but I could see this one being debateable
         // As it stands there is not good way for us to manually produce a warning, so I'd rather fail loud
         bail!("Failed to find any services or messages while generating ROS message definitions, paths searched: {search_paths:?}");
     }
-    tokenize_messages_and_services(messages, services, actions)
+    tokenize_messages_and_services(messages, services, actions, options)
+}
+
+/// Same as [find_and_generate_ros_messages_without_ros_package_path_with_options], but generates each package
+/// separately to be written to its own file, see [generate_rust_ros_message_modules] and [write_ros_message_modules].
+///
+/// ```no_run
+/// use roslibrust_codegen::{find_and_generate_ros_message_modules, write_ros_message_modules, CodegenOptions};
+///
+/// let (modules, _dependent_paths) = find_and_generate_ros_message_modules(
+///     vec!["assets/ros1_common_interfaces".into()],
+///     &CodegenOptions::default(),
+/// )
+/// .unwrap();
+/// write_ros_message_modules("src/messages", modules).unwrap();
+/// ```
+pub fn find_and_generate_ros_message_modules(
+    search_paths: Vec<PathBuf>,
+    options: &CodegenOptions,
+) -> Result<(BTreeMap<String, TokenStream>, Vec<PathBuf>), Error> {
+    let (messages, services, actions) =
+        select_ros_files(find_and_parse_ros_messages(&search_paths)?, options);
+    if messages.is_empty() && services.is_empty() {
+        bail!("Failed to find any services or messages while generating ROS message definitions, paths searched: {search_paths:?}");
+    }
+    let (messages, services) = resolve_dependency_graph(messages, services)?;
+    let dependent_paths = dependent_paths(&messages, &services, &actions);
+    let modules = generate_rust_ros_message_modules(messages, services, actions, options)?;
+    Ok((modules, dependent_paths))
 }
 
 /// Generates source code and list of depnendent file system paths
-fn tokenize_messages_and_services(
+pub(crate) fn tokenize_messages_and_services(
     messages: Vec<ParsedMessageFile>,
     services: Vec<ParsedServiceFile>,
     actions: Vec<ParsedActionFile>,
+    options: &CodegenOptions,
 ) -> Result<(TokenStream, Vec<PathBuf>), Error> {
     let (messages, services) = resolve_dependency_graph(messages, services)?;
+    let dependent_paths = dependent_paths(&messages, &services, &actions);
+    let source = generate_rust_ros_message_definitions(messages, services, actions, options)?;
+    Ok((source, dependent_paths))
+}
+
+/// Returns the paths of the files the messages, services and actions were parsed from
+pub(crate) fn dependent_paths(
+    messages: &[MessageFile],
+    services: &[ServiceFile],
+    actions: &[ParsedActionFile],
+) -> Vec<PathBuf> {
     let msg_iter = messages.iter().map(|m| m.parsed.path.clone());
     let srv_iter = services.iter().map(|s| s.parsed.path.clone());
     let action_iter = actions.iter().map(|a| a.path.clone());
-    let dependent_paths = msg_iter.chain(srv_iter).chain(action_iter).collect();
-    let source = generate_rust_ros_message_definitions(messages, services)?;
-    Ok((source, dependent_paths))
+    msg_iter.chain(srv_iter).chain(action_iter).collect()
 }
 
 /// Generates struct definitions and implementations for message and service files
@@ -446,7 +591,7 @@ pub fn generate_ros_messages_for_packages(
     if messages.is_empty() && services.is_empty() {
         bail!("Failed to find any services or messages while generating ROS message definitions, packages searched: {packages:?}")
     }
-    tokenize_messages_and_services(messages, services, actions)
+    tokenize_messages_and_services(messages, services, actions, &CodegenOptions::default())
 }
 
 /// Searches a list of paths for ROS packages to find their associated message
@@ -457,7 +602,7 @@ pub fn generate_ros_messages_for_packages(
 /// * `search_paths` - A list of paths to search.
 ///
 pub fn find_and_parse_ros_messages(
-    search_paths: &Vec<PathBuf>,
+    search_paths: &[PathBuf],
 ) -> Result<
     (
         Vec<ParsedMessageFile>,
@@ -466,8 +611,14 @@ pub fn find_and_parse_ros_messages(
     ),
     Error,
 > {
+    let packages = find_ros_packages(search_paths)?;
+    parse_ros_packages(&packages)
+}
+
+/// Searches a list of paths for ROS packages, returning each package found once
+pub(crate) fn find_ros_packages(search_paths: &[PathBuf]) -> Result<Vec<Package>, Error> {
     let search_paths  = search_paths
-        .into_iter()
+        .iter()
         .map(|path| {
             path.canonicalize().map_err(
             |e| {
@@ -488,7 +639,97 @@ pub fn find_and_parse_ros_messages(
             std::env::current_dir().unwrap()
         );
     }
+    Ok(packages)
+}
+
+/// The messages, services and actions parsed from a set of files
+pub(crate) type ParsedRosFiles = (
+    Vec<ParsedMessageFile>,
+    Vec<ParsedServiceFile>,
+    Vec<ParsedActionFile>,
+);
+
+/// Keeps the messages, services and actions selected by [CodegenOptions::include] and [CodegenOptions::exclude],
+/// along with all of the messages they depend on
+pub(crate) fn select_ros_files(files: ParsedRosFiles, options: &CodegenOptions) -> ParsedRosFiles {
+    let (messages, services, actions) = files;
+    if !options.has_filters() {
+        return (messages, services, actions);
+    }
+
+    let actions = actions
+        .into_iter()
+        .filter(|action| options.is_included(&action.package, &action.name))
+        .collect::<Vec<_>>();
+    // Actions are made of messages and services, which are kept with them
+    let mut selected_messages = actions
+        .iter()
+        .flat_map(|action| action.messages())
+        .map(|msg| msg.get_full_name())
+        .collect::<BTreeSet<_>>();
+    let mut selected_services = actions
+        .iter()
+        .flat_map(|action| action.services())
+        .map(|srv| srv.get_full_name())
+        .collect::<BTreeSet<_>>();
+    selected_messages.extend(
+        messages
+            .iter()
+            .filter(|msg| options.is_included(&msg.package, &msg.name))
+            .map(|msg| msg.get_full_name()),
+    );
+    selected_services.extend(
+        services
+            .iter()
+            .filter(|srv| options.is_included(&srv.package, &srv.name))
+            .map(|srv| srv.get_full_name()),
+    );
+    let services = services
+        .into_iter()
+        .filter(|srv| selected_services.contains(&srv.get_full_name()))
+        .collect::<Vec<_>>();
+
+    // Follow the fields of everything selected so far to the messages they depend on
+    let messages_by_name = messages
+        .iter()
+        .map(|msg| (msg.get_full_name(), msg))
+        .collect::<BTreeMap<_, _>>();
+    let mut unvisited = selected_messages.iter().cloned().collect::<Vec<_>>();
+    unvisited.extend(services.iter().flat_map(|srv| {
+        [&srv.request_type, &srv.response_type]
+            .into_iter()
+            .flat_map(|msg| msg.fields.iter())
+            .filter(|field| !is_primitive(field))
+            .map(|field| field.get_full_name())
+    }));
+    while let Some(name) = unvisited.pop() {
+        selected_messages.insert(name.clone());
+        // Missing dependencies are reported when resolving the dependency graph
+        if let Some(msg) = messages_by_name.get(&name) {
+            unvisited.extend(
+                msg.fields
+                    .iter()
+                    .filter(|field| !is_primitive(field))
+                    .map(|field| field.get_full_name())
+                    .filter(|name| !selected_messages.contains(name)),
+            );
+        }
+    }
+    let messages = messages
+        .into_iter()
+        .filter(|msg| selected_messages.contains(&msg.get_full_name()))
+        .collect();
+    (messages, services, actions)
+}
+
+fn is_primitive(field: &FieldInfo) -> bool {
+    let field_type = field.field_type.field_type.as_str();
+    ROS_TYPE_TO_RUST_TYPE_MAP.contains_key(field_type)
+        || ROS_2_TYPE_TO_RUST_TYPE_MAP.contains_key(field_type)
+}
 
+/// Parses all of the message, service and action files in the given packages
+pub(crate) fn parse_ros_packages(packages: &[Package]) -> Result<ParsedRosFiles, Error> {
     let message_files = packages
         .iter()
         .flat_map(|pkg| {
@@ -520,18 +761,117 @@ pub fn find_and_parse_ros_messages(
 ///
 /// * `messages` - Collection of ROS message definition data.
 /// * `services` - Collection of ROS service definition data.
+/// * `actions` - Collection of ROS action definition data, their messages and services must be included in `messages` and `services`.
+/// * `options` - Options controlling the generated code.
 pub fn generate_rust_ros_message_definitions(
     messages: Vec<MessageFile>,
     services: Vec<ServiceFile>,
+    actions: Vec<ParsedActionFile>,
+    options: &CodegenOptions,
 ) -> Result<TokenStream, Error> {
+    let modules_to_struct_definitions =
+        generate_package_definitions(messages, services, actions, options)?;
+    // Now generate modules to wrap all of the TokenStreams in a module for each package
+    let all_pkgs = modules_to_struct_definitions
+        .keys()
+        .cloned()
+        .collect::<Vec<String>>();
+    let module_definitions = modules_to_struct_definitions
+        .into_iter()
+        .map(|(pkg, struct_defs)| generate_mod(pkg, struct_defs, &all_pkgs[..]))
+        .collect::<Vec<TokenStream>>();
+
+    Ok(quote! {
+        #(#module_definitions)*
+
+    })
+}
+
+/// Same as [generate_rust_ros_message_definitions], but generates each package separately so they can be
+/// written to their own files, e.g. with [write_ros_message_modules].
+///
+/// Returns the contents of each package's module keyed by package name. Packages refer to each other
+/// through `super::`, so all of the modules must be declared side by side in the same parent module.
+pub fn generate_rust_ros_message_modules(
+    messages: Vec<MessageFile>,
+    services: Vec<ServiceFile>,
+    actions: Vec<ParsedActionFile>,
+    options: &CodegenOptions,
+) -> Result<BTreeMap<String, TokenStream>, Error> {
+    let modules_to_struct_definitions =
+        generate_package_definitions(messages, services, actions, options)?;
+    let all_pkgs = modules_to_struct_definitions
+        .keys()
+        .cloned()
+        .collect::<Vec<String>>();
+    Ok(modules_to_struct_definitions
+        .into_iter()
+        .map(|(pkg, struct_defs)| {
+            let contents = generate_mod_contents(&pkg, struct_defs, &all_pkgs[..]);
+            (pkg, contents)
+        })
+        .collect())
+}
+
+/// Generates the definitions of all messages, services and actions grouped by package
+fn generate_package_definitions(
+    messages: Vec<MessageFile>,
+    services: Vec<ServiceFile>,
+    actions: Vec<ParsedActionFile>,
+    options: &CodegenOptions,
+) -> Result<BTreeMap<String, Vec<TokenStream>>, Error> {
+    if options.no_std
+        && (options.byte_array_type != ByteArrayType::Vec || options.time_type != TimeType::Ros)
+    {
+        bail!("ByteArrayType::Bytes and TimeTypes other than TimeType::Ros require roslibrust, and can't be generated with no_std");
+    }
+    if (options.r2r_conversions || options.rosrust_conversions)
+        && (options.no_std || options.time_type != TimeType::Ros)
+    {
+        bail!("Conversions to r2r and rosrust types can't be generated with no_std or TimeTypes other than TimeType::Ros");
+    }
+    if options.proto_module.is_some() && (options.no_std || options.time_type != TimeType::Ros) {
+        bail!("Conversions to protobuf types can't be generated with no_std or TimeTypes other than TimeType::Ros");
+    }
+    if options.generates_ros1_codec() && (options.no_std || options.time_type != TimeType::Ros) {
+        bail!(
+            "The ROS1 codec can't be generated with no_std or TimeTypes other than TimeType::Ros"
+        );
+    }
+    let foreign_types = foreign_types(&messages, &services, &actions, options);
+    let conversions = |message: &MessageFile| {
+        let foreign = match foreign_types.get(&message.get_full_name()) {
+            Some((foreign, foreign_type)) => generate_conversions(message, *foreign, foreign_type)?,
+            None => quote! {},
+        };
+        let proto = match &options.proto_module {
+            Some(module) => proto::generate_proto_conversions(message, module)?,
+            None => quote! {},
+        };
+        Ok::<_, Error>(quote! { #foreign #proto })
+    };
     let mut modules_to_struct_definitions: BTreeMap<String, Vec<TokenStream>> = BTreeMap::new();
 
+    // Packages generated in another crate are only needed for the md5sums and definitions of their dependents
+    let is_generated = |package: &str| options.extern_path(package).is_none();
+    let messages = messages
+        .into_iter()
+        .filter(|message| is_generated(&message.parsed.package));
+    let services = services
+        .into_iter()
+        .filter(|service| is_generated(&service.parsed.package));
+    let actions = actions
+        .into_iter()
+        .filter(|action| is_generated(&action.package));
+
     // Convert messages files into rust token streams and insert them into BTree organized by package
     messages
         .into_iter()
         .map(|message| {
             let pkg_name = message.parsed.package.clone();
-            let definition = generate_struct(message)?;
+            let conversions = conversions(&message)?;
+            let definition = generate_struct(message, options)?;
+            let definition = quote! { #definition #conversions };
             if let Some(entry) = modules_to_struct_definitions.get_mut(&pkg_name) {
                 entry.push(definition);
             } else {
@@ -545,7 +885,10 @@ pub fn generate_rust_ros_message_definitions(
         .into_iter()
         .map(|service| {
             let pkg_name = service.parsed.package.clone();
-            let definition = generate_service(service)?;
+            let request_conversions = conversions(&service.request)?;
+            let response_conversions = conversions(&service.response)?;
+            let definition = generate_service(service, options)?;
+            let definition = quote! { #definition #request_conversions #response_conversions };
             if let Some(entry) = modules_to_struct_definitions.get_mut(&pkg_name) {
                 entry.push(definition);
             } else {
@@ -554,20 +897,160 @@ pub fn generate_rust_ros_message_definitions(
             Ok(())
         })
         .collect::<Result<(), Error>>()?;
-    // Now generate modules to wrap all of the TokenStreams in a module for each package
-    let all_pkgs = modules_to_struct_definitions
-        .keys()
-        .cloned()
-        .collect::<Vec<String>>();
-    let module_definitions = modules_to_struct_definitions
-        .into_iter()
-        .map(|(pkg, struct_defs)| generate_mod(pkg, struct_defs, &all_pkgs[..]))
-        .collect::<Vec<TokenStream>>();
+    // And for actions
+    for action in actions {
+        let definition = generate_action(&action, options);
+        modules_to_struct_definitions
+            .entry(action.package.clone())
+            .or_default()
+            .push(definition);
+    }
+    // Each module imports the packages generated in other crates alongside the generated ones
+    let extern_imports = options
+        .extern_packages()
+        .map(|(package, path)| {
+            let package = quote::format_ident!("{package}");
+            let path: syn::Path = syn::parse_str(path)
+                .map_err(|e| Error::with(format!("Invalid module path {path}").as_str(), e))?;
+            Ok(quote! { use #path as #package; })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    for definitions in modules_to_struct_definitions.values_mut() {
+        definitions.splice(0..0, extern_imports.iter().cloned());
+    }
+    // Without roslibrust the integral types are generated as if they were another package
+    if options.no_std {
+        if modules_to_struct_definitions.contains_key("integral_types") {
+            bail!("A package named integral_types can't be generated with no_std, as the module name is used for the generated Time and Duration types");
+        }
+        modules_to_struct_definitions.insert(
+            "integral_types".to_owned(),
+            vec![generate_no_std_integral_types()],
+        );
+    }
+    Ok(modules_to_struct_definitions)
+}
 
-    Ok(quote! {
-        #(#module_definitions)*
+/// Returns the crate and path of the foreign type each message converts to and from by full name,
+/// see [CodegenOptions::r2r_conversions] and [CodegenOptions::rosrust_conversions]
+fn foreign_types(
+    messages: &[MessageFile],
+    services: &[ServiceFile],
+    actions: &[ParsedActionFile],
+    options: &CodegenOptions,
+) -> BTreeMap<String, (Foreign, String)> {
+    let mut foreign_types = BTreeMap::new();
+    if options.r2r_conversions {
+        // r2r nests the goal, result and feedback of actions, and its wrappers aren't equivalent to ours
+        let mut wrappers = BTreeSet::new();
+        for action in actions {
+            if let ActionWrappers::Ros2 {
+                send_goal_type,
+                get_result_type,
+                feedback_message_type,
+            } = &action.wrappers
+            {
+                for (msg, kind) in [
+                    (&action.goal_type, "Goal"),
+                    (&action.result_type, "Result"),
+                    (&action.feedback_type, "Feedback"),
+                ] {
+                    foreign_types.insert(
+                        msg.get_full_name(),
+                        (
+                            Foreign::R2r,
+                            format!("::r2r::{}::action::{}::{kind}", action.package, action.name),
+                        ),
+                    );
+                }
+                wrappers.insert(feedback_message_type.get_full_name());
+                wrappers.insert(send_goal_type.get_full_name());
+                wrappers.insert(get_result_type.get_full_name());
+            }
+        }
+        for msg in messages {
+            let name = msg.get_full_name();
+            if msg.parsed.version == Some(RosVersion::ROS2)
+                && !foreign_types.contains_key(&name)
+                && !wrappers.contains(&name)
+            {
+                let foreign_type =
+                    format!("::r2r::{}::msg::{}", msg.parsed.package, msg.parsed.name);
+                foreign_types.insert(name, (Foreign::R2r, foreign_type));
+            }
+        }
+        for srv in services {
+            if srv.request.parsed.version == Some(RosVersion::ROS2)
+                && !wrappers.contains(&srv.get_full_name())
+            {
+                let srv_type = format!("::r2r::{}::srv::{}", srv.parsed.package, srv.parsed.name);
+                foreign_types.insert(
+                    srv.request.get_full_name(),
+                    (Foreign::R2r, format!("{srv_type}::Request")),
+                );
+                foreign_types.insert(
+                    srv.response.get_full_name(),
+                    (Foreign::R2r, format!("{srv_type}::Response")),
+                );
+            }
+        }
+    }
+    if options.rosrust_conversions {
+        for msg in messages {
+            if msg.parsed.version != Some(RosVersion::ROS2) {
+                let foreign_type =
+                    format!("::rosrust_msg::{}::{}", msg.parsed.package, msg.parsed.name);
+                foreign_types.insert(msg.get_full_name(), (Foreign::Rosrust, foreign_type));
+            }
+        }
+        for srv in services {
+            if srv.request.parsed.version != Some(RosVersion::ROS2) {
+                let srv_type =
+                    format!("::rosrust_msg::{}::{}", srv.parsed.package, srv.parsed.name);
+                foreign_types.insert(
+                    srv.request.get_full_name(),
+                    (Foreign::Rosrust, format!("{srv_type}Req")),
+                );
+                foreign_types.insert(
+                    srv.response.get_full_name(),
+                    (Foreign::Rosrust, format!("{srv_type}Res")),
+                );
+            }
+        }
+    }
+    foreign_types
+}
 
-    })
+/// Writes the modules returned by [generate_rust_ros_message_modules] to `dir`, one `{package}.rs` file per package
+/// and a `mod.rs` declaring them, so the generated code can be committed and included with e.g. `mod messages;`.
+///
+/// The files are not formatted, running `rustfmt {dir}/mod.rs` afterwards formats all of them.
+/// Files of packages that are no longer generated are not removed.
+pub fn write_ros_message_modules(
+    dir: impl AsRef<Path>,
+    modules: BTreeMap<String, TokenStream>,
+) -> Result<(), Error> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir).map_err(|e| {
+        Error::with(
+            format!("Failed to create directory {dir:?} for generated code").as_str(),
+            e,
+        )
+    })?;
+    let write = |path: PathBuf, contents: String| {
+        std::fs::write(&path, contents).map_err(|e| {
+            Error::with(
+                format!("Failed to write generated code to {path:?}").as_str(),
+                e,
+            )
+        })
+    };
+    let packages = modules.keys().cloned().collect::<Vec<_>>();
+    write(dir.join("mod.rs"), generate_mod_file(&packages).to_string())?;
+    for (package, contents) in modules {
+        write(dir.join(format!("{package}.rs")), contents.to_string())?;
+    }
+    Ok(())
 }
 
 struct MessageMetadata {
@@ -648,19 +1131,13 @@ pub fn resolve_dependency_graph(
 /// The returned collection will contain all messages files including those buried with the
 /// service or action files, and will have fully expanded and resolved referenced types in other packages.
 /// * `msg_paths` -- List of tuple (Package, Path to File) for each file to parse
-fn parse_ros_files(
-    msg_paths: Vec<(Package, PathBuf)>,
-) -> Result<
-    (
-        Vec<ParsedMessageFile>,
-        Vec<ParsedServiceFile>,
-        Vec<ParsedActionFile>,
-    ),
-    Error,
-> {
+fn parse_ros_files(msg_paths: Vec<(Package, PathBuf)>) -> Result<ParsedRosFiles, Error> {
     let mut parsed_messages = Vec::new();
     let mut parsed_services = Vec::new();
     let mut parsed_actions = Vec::new();
+    // ROS2 installs both the .msg and generated .idl for each interface, so .idl definitions are only
+    // used when no other definition of the same type was found
+    let mut idl_files = Vec::new();
     for (pkg, path) in msg_paths {
         let contents = std::fs::read_to_string(&path).map_err(|e| {
             Error::with(
@@ -689,26 +1166,67 @@ fn parse_ros_files(
             }
             "action" => {
                 let action = parse_ros_action_file(&contents, name, &pkg, &path)?;
-                parsed_actions.push(action.clone());
-                parsed_messages.push(action.action_type);
-                parsed_messages.push(action.action_goal_type);
-                parsed_messages.push(action.goal_type);
-                parsed_messages.push(action.action_result_type);
-                parsed_messages.push(action.result_type);
-                parsed_messages.push(action.action_feedback_type);
-                parsed_messages.push(action.feedback_type);
+                parsed_messages.extend(action.messages());
+                parsed_services.extend(action.services());
+                parsed_actions.push(action);
+            }
+            "idl" => {
+                idl_files.push(parse_ros_idl_file(&contents, name, &pkg, &path)?);
             }
             _ => {
                 log::error!("File extension not recognized as a ROS file: {path:?}");
             }
         }
     }
+    for idl in idl_files {
+        match idl {
+            ParsedIdlFile::Message(msg) => {
+                if !parsed_messages
+                    .iter()
+                    .any(|m| m.get_full_name() == msg.get_full_name())
+                {
+                    parsed_messages.push(msg);
+                }
+            }
+            ParsedIdlFile::Service(srv) => {
+                if !parsed_services
+                    .iter()
+                    .any(|s| s.get_full_name() == srv.get_full_name())
+                {
+                    parsed_services.push(srv);
+                }
+            }
+            ParsedIdlFile::Action(action) => {
+                if !parsed_actions
+                    .iter()
+                    .any(|a| a.get_full_name() == action.get_full_name())
+                {
+                    parsed_messages.extend(action.messages());
+                    parsed_services.extend(action.services());
+                    parsed_actions.push(action);
+                }
+            }
+        }
+    }
     Ok((parsed_messages, parsed_services, parsed_actions))
 }
 
 #[cfg(test)]
 mod test {
-    use crate::find_and_generate_ros_messages;
+    use crate::proto;
+    use crate::utils::{Package, RosVersion};
+    use crate::{
+        find_and_generate_ros_message_modules, find_and_generate_ros_messages,
+        find_and_generate_ros_messages_without_ros_package_path,
+        find_and_generate_ros_messages_without_ros_package_path_with_options,
+        write_ros_message_modules,
+    };
+    use crate::{
+        generate_rust_ros_message_definitions, parse_ros_message_file, resolve_dependency_graph,
+        ByteArrayType, CodegenOptions, FieldCase, TimeType,
+    };
+    use proc_macro2::TokenStream;
+    use quote::quote;
 
     /// Confirms we don't panic on ros1 parsing
     #[test_log::test]
@@ -768,4 +1286,677 @@ mod test {
         assert!(!source.is_empty());
         assert!(!paths.is_empty());
     }
+
+    /// Confirms each package can be written to its own file
+    #[test_log::test]
+    fn generate_one_module_per_package() {
+        let paths = vec![
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/ros2_test_msgs").into(),
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../assets/ros1_common_interfaces/rosapi"
+            )
+            .into(),
+        ];
+        let (modules, dependent_paths) =
+            find_and_generate_ros_message_modules(paths.clone(), &CodegenOptions::default())
+                .unwrap();
+        assert_eq!(
+            modules.keys().collect::<Vec<_>>(),
+            vec!["rosapi", "test_msgs"]
+        );
+        assert!(!dependent_paths.is_empty());
+        assert!(modules["test_msgs"]
+            .to_string()
+            .contains("use super :: rosapi ;"));
+
+        // The modules are the same code as is generated in one go
+        let (source, _) = find_and_generate_ros_messages_without_ros_package_path(paths).unwrap();
+        let single_file = modules
+            .iter()
+            .map(|(package, contents)| {
+                let package = quote::format_ident!("{package}");
+                quote! {
+                    #[allow(unused_imports)]
+                    pub mod #package {
+                        #contents
+                    }
+                }
+            })
+            .collect::<TokenStream>();
+        assert_eq!(single_file.to_string(), source.to_string());
+
+        let dir = std::env::temp_dir().join("roslibrust_module_per_package_test");
+        write_ros_message_modules(&dir, modules).unwrap();
+        let mod_rs = std::fs::read_to_string(dir.join("mod.rs")).unwrap();
+        assert!(mod_rs.contains("pub mod rosapi ;"));
+        assert!(mod_rs.contains("pub mod test_msgs ;"));
+        assert!(std::fs::read_to_string(dir.join("test_msgs.rs"))
+            .unwrap()
+            .contains("pub struct Defaults"));
+    }
+
+    /// Confirms no_std code only refers to core and alloc, with serde and roslibrust behind features
+    #[test_log::test]
+    fn generate_no_std() {
+        let paths = vec![
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/ros2_test_msgs").into(),
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../assets/ros1_common_interfaces/rosapi"
+            )
+            .into(),
+        ];
+        let options = CodegenOptions::new().no_std(true);
+        let (modules, _) = find_and_generate_ros_message_modules(paths.clone(), &options).unwrap();
+        assert_eq!(
+            modules.keys().collect::<Vec<_>>(),
+            vec!["integral_types", "rosapi", "test_msgs"]
+        );
+        let source = modules.values().map(|m| m.to_string()).collect::<String>();
+        assert!(!source.contains(":: std ::"));
+        assert!(!source.contains("::std::"));
+        assert!(!source.contains("roslibrust :: codegen"));
+        assert!(source.contains(":: alloc :: string :: String"));
+        assert!(source.contains("pub r#time : integral_types :: Time"));
+        assert!(source.contains(
+            "# [cfg_attr (feature = \"serde\" , derive (:: serde :: Deserialize , :: serde :: Serialize))]"
+        ));
+        assert!(source
+            .contains("# [cfg (feature = \"roslibrust\")] impl :: roslibrust :: RosMessageType"));
+
+        let options = options.byte_array_type(ByteArrayType::Bytes);
+        assert!(find_and_generate_ros_message_modules(paths, &options).is_err());
+    }
+
+    /// Confirms every use of serde is behind the serde feature with optional_serde
+    #[test_log::test]
+    fn generate_optional_serde() {
+        let paths = vec![
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/ros2_test_msgs").into(),
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../assets/ros1_common_interfaces/rosapi"
+            )
+            .into(),
+        ];
+        let options = CodegenOptions::new().optional_serde(true);
+        let (source, _) =
+            find_and_generate_ros_messages_without_ros_package_path_with_options(paths, &options)
+                .unwrap();
+        let source = source.to_string();
+        assert!(!source.contains("# [serde"));
+        assert!(!source.contains("# [derive (:: roslibrust :: codegen :: Deserialize"));
+        assert!(source.contains(
+            "# [cfg_attr (feature = \"serde\" , derive (:: roslibrust :: codegen :: Deserialize , :: roslibrust :: codegen :: Serialize))]"
+        ));
+        assert!(source.contains("# [cfg_attr (feature = \"serde\" , serde (with = \"::roslibrust::codegen::BoundedString::<5>\"))]"));
+        assert!(
+            source.contains("# [cfg (feature = \"serde\")] impl :: roslibrust :: RosMessageType")
+        );
+        assert!(
+            source.contains("# [cfg (feature = \"serde\")] impl :: roslibrust :: RosServiceType")
+        );
+    }
+
+    /// Confirms packages generated in another crate are imported instead of generated
+    #[test_log::test]
+    fn generate_with_extern_packages() {
+        let paths = vec![
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../assets/ros1_common_interfaces/std_msgs"
+            )
+            .into(),
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../assets/ros1_common_interfaces/common_msgs/geometry_msgs"
+            )
+            .into(),
+        ];
+        let (generated, _) =
+            find_and_generate_ros_messages_without_ros_package_path(paths.clone()).unwrap();
+        let options = CodegenOptions::new().extern_package("std_msgs", "::shared_msgs::std_msgs");
+        let (modules, _) = find_and_generate_ros_message_modules(paths, &options).unwrap();
+        assert_eq!(modules.keys().collect::<Vec<_>>(), vec!["geometry_msgs"]);
+        let source = modules["geometry_msgs"].to_string();
+        assert!(source.contains("use :: shared_msgs :: std_msgs as std_msgs ;"));
+        assert!(!source.contains("use super :: std_msgs"));
+        assert!(!source.contains("pub struct Header"));
+        // Messages depending on the extern package are unchanged
+        let pose_stamped = |source: &str| {
+            let start = source.find("pub struct PoseStamped").unwrap();
+            source[start..start + 300].to_string()
+        };
+        assert_eq!(pose_stamped(&source), pose_stamped(&generated.to_string()));
+        assert!(source.contains("std_msgs :: Header"));
+
+        let options = CodegenOptions::new().extern_package("std_msgs", "not a path");
+        assert!(find_and_generate_ros_message_modules(
+            vec![concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../assets/ros1_common_interfaces/std_msgs"
+            )
+            .into()],
+            &options
+        )
+        .is_err());
+    }
+
+    /// Confirms only the selected interfaces and their dependencies are generated
+    #[test_log::test]
+    fn generate_selected_interfaces() {
+        let paths = vec![
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/ros2_test_msgs").into(),
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../assets/ros1_common_interfaces/rosapi"
+            )
+            .into(),
+        ];
+        // MessageDetails responds with TypeDefs, which are generated despite being excluded
+        let options = CodegenOptions::new()
+            .include("rosapi/MessageDetails")
+            .include("test_msgs/*Arrays")
+            .exclude("rosapi/TypeDef");
+        let (source, dependent_paths) =
+            find_and_generate_ros_messages_without_ros_package_path_with_options(
+                paths.clone(),
+                &options,
+            )
+            .unwrap();
+        let source = source.to_string();
+        assert!(source.contains("pub struct MessageDetailsResponse"));
+        assert!(source.contains("pub struct TypeDef"));
+        // As are the Defaults in FixedArrays
+        assert!(source.contains("pub struct FixedArrays"));
+        assert!(source.contains("pub struct Defaults"));
+        assert!(!source.contains("pub struct GetTimeRequest"));
+        assert!(!source.contains("pub struct Bounded"));
+        assert_eq!(dependent_paths.len(), 4);
+
+        let options = CodegenOptions::new().exclude("rosapi");
+        let (modules, _) = find_and_generate_ros_message_modules(paths.clone(), &options).unwrap();
+        assert_eq!(modules.keys().collect::<Vec<_>>(), vec!["test_msgs"]);
+
+        // Selecting nothing is an error like finding nothing
+        let options = CodegenOptions::new().include("not_a_package");
+        assert!(
+            find_and_generate_ros_messages_without_ros_package_path_with_options(paths, &options)
+                .is_err()
+        );
+    }
+
+    /// Confirms uint8[] fields are generated as Bytes when requested
+    #[test_log::test]
+    fn generate_bytes_for_byte_arrays() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS2),
+        };
+        let msg = parse_ros_message_file(
+            "uint8[] data\nuint8[] defaulted [1, 2]\nuint8[4] fixed\nint8[] signed",
+            "Payload",
+            &pkg,
+            "./Payload.msg".as_ref(),
+        )
+        .unwrap();
+        let (messages, services) = resolve_dependency_graph(vec![msg], vec![]).unwrap();
+
+        let options = CodegenOptions::new().byte_array_type(ByteArrayType::Bytes);
+        let source = generate_rust_ros_message_definitions(messages, services, vec![], &options)
+            .unwrap()
+            .to_string();
+        assert!(source.contains("pub r#data : :: roslibrust :: codegen :: bytes :: Bytes"));
+        assert!(source.contains("vec![1, 2].into()"));
+        assert!(source.contains("pub r#fixed : [u8 ; 4]"));
+        assert!(source.contains("pub r#signed : :: std :: vec :: Vec < i8 >"));
+        assert!(!source.contains("serde_bytes"));
+    }
+
+    /// Confirms integer constants describing a field are grouped into an enum
+    #[test_log::test]
+    fn generate_constant_enums() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let generate = |definition: &str| {
+            let msg =
+                parse_ros_message_file(definition, "RobotState", &pkg, "./RobotState.msg".as_ref())
+                    .unwrap();
+            let (messages, services) = resolve_dependency_graph(vec![msg], vec![]).unwrap();
+            let options = CodegenOptions::new().constant_enums(true);
+            generate_rust_ros_message_definitions(messages, services, vec![], &options)
+                .unwrap()
+                .to_string()
+        };
+
+        let source = generate(
+            "uint8 MODE_IDLE=0\nuint8 MODE_NOT_MOVING=1\nint8 LEVEL_LOW=-1\nint8 LEVEL_HIGH=1\nstring MODE_NAME=idle\nuint8 mode\nint8 level",
+        );
+        assert!(source.contains("pub mod robot_state"));
+        assert!(source.contains("# [repr (u8)] pub enum Mode { Idle = 0 , NotMoving = 1 , }"));
+        assert!(source.contains("# [repr (i8)] pub enum Level { Low = - 1 , High = 1 , }"));
+        assert!(source.contains("impl :: core :: convert :: TryFrom < u8 > for Mode"));
+        // The constants are still generated
+        assert!(source.contains("pub const r#MODE_IDLE : u8 = 0"));
+
+        // Without prefixes the constants describe the only field of their type, leaving out shared words
+        let source = generate("uint8 STATE_OK=0\nuint8 STATE_ERROR=1\nuint8 status\nfloat32 x");
+        assert!(source.contains("pub enum Status { Ok = 0 , Error = 1 , }"));
+
+        // Repeated values can't be enum variants
+        let source = generate("uint8 MODE_ADD=0\nuint8 MODE_MODIFY=0\nuint8 mode");
+        assert!(!source.contains("pub enum"));
+
+        // Annotated fields take the constants of a block, or with a prefix
+        let source = generate(
+            "int32 ARROW=0\nint32 CUBE=1\n\nint32 ADD=0\nint32 DELETE=2\n\nint32 FLAG_A=1\nint32 FLAG_B=2\nint32 id\nint32 type # enum: ARROW\nint32 action # enum: ADD\nint32 flags # enum: FLAG_*",
+        );
+        assert!(source.contains("pub enum Type { Arrow = 0 , Cube = 1 , }"));
+        assert!(source.contains("pub enum Action { Add = 0 , Delete = 2 , }"));
+        assert!(source.contains("pub enum Flags { A = 1 , B = 2 , }"));
+        assert!(!source.contains("pub enum Id"));
+        assert!(source.contains("pub fn is_valid (value : i32) -> bool"));
+
+        // Blocks of constants describe the field named in their heading
+        let source = generate(
+            "# Charge state constants\nuint8 CHARGE_UNKNOWN=0\nuint8 CHARGE_CHARGING=1\n\nuint8 OTHER=3\nuint8 charge_state\nuint8 level",
+        );
+        assert!(source.contains(
+            "pub enum ChargeState { # [doc = \" Charge state constants\"] Unknown = 0 , Charging = 1 , }"
+        ));
+        assert!(!source.contains("pub enum Level"));
+    }
+
+    /// Confirms fields can be renamed or aliased, and empty arrays skipped for JSON consumers
+    #[test_log::test]
+    fn generate_serde_field_names() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let generate = |options: CodegenOptions| {
+            let msg = parse_ros_message_file(
+                "string frame_id\nfloat64 x\nint32[] sample_values\nint32[2] limits",
+                "Sample",
+                &pkg,
+                "./Sample.msg".as_ref(),
+            )
+            .unwrap();
+            let (messages, services) = resolve_dependency_graph(vec![msg], vec![]).unwrap();
+            generate_rust_ros_message_definitions(messages, services, vec![], &options)
+                .unwrap()
+                .to_string()
+        };
+
+        let source = generate(CodegenOptions::new());
+        assert!(!source.contains("alias"));
+        assert!(!source.contains("skip_serializing_if"));
+
+        let source = generate(CodegenOptions::new().rename_fields(FieldCase::CamelCase));
+        assert!(source
+            .contains("# [serde (rename = \"frameId\" , alias = \"frame_id\")] pub r#frame_id"));
+        // Names which don't change aren't renamed
+        assert!(!source.contains("rename = \"x\""));
+
+        let source = generate(
+            CodegenOptions::new()
+                .alias_fields(FieldCase::PascalCase)
+                .skip_serializing_empty_arrays(true),
+        );
+        assert!(source.contains("# [serde (alias = \"FrameId\")] pub r#frame_id"));
+        assert!(source.contains("# [serde (alias = \"X\")] pub r#x"));
+        assert!(source.contains(
+            "# [serde (default , skip_serializing_if = \"::std::vec::Vec::is_empty\")] pub r#sample_values"
+        ));
+        // Fixed size arrays are always serialized
+        assert_eq!(source.matches("skip_serializing_if").count(), 1);
+    }
+
+    /// Confirms the ROS1 codec is only generated for ROS1 messages, and handles Bytes fields itself
+    #[test_log::test]
+    fn generate_ros1_codec() {
+        let generate = |version: RosVersion, options: &CodegenOptions| {
+            let pkg = Package {
+                name: "test_pkg".to_string(),
+                path: "./not_a_path".into(),
+                version: Some(version),
+            };
+            let msg = parse_ros_message_file(
+                "string name\nuint8[] data\nfloat32[4] values",
+                "Blob",
+                &pkg,
+                "./Blob.msg".as_ref(),
+            )
+            .unwrap();
+            let (messages, services) = resolve_dependency_graph(vec![msg], vec![]).unwrap();
+            generate_rust_ros_message_definitions(messages, services, vec![], options)
+                .map(|source| source.to_string())
+        };
+
+        let options = CodegenOptions::new()
+            .ros1_codec(true)
+            .byte_array_type(ByteArrayType::Bytes);
+        let source = generate(RosVersion::ROS1, &options).unwrap();
+        assert!(source.contains("impl :: roslibrust :: ros1_codec :: Ros1Encode for Blob"));
+        assert!(source.contains("impl :: roslibrust :: ros1_codec :: Ros1Decode for Blob"));
+        assert!(source.contains("fn ros1_encode (& self)"));
+        assert!(source
+            .contains("r#data : :: roslibrust :: ros1_codec :: decode_bytes (reader) ? . into ()"));
+        assert!(source
+            .contains("r#values : :: roslibrust :: ros1_codec :: Ros1Decode :: decode (reader) ?"));
+
+        assert!(!generate(RosVersion::ROS2, &options)
+            .unwrap()
+            .contains("ros1_codec"));
+        assert!(!generate(RosVersion::ROS1, &CodegenOptions::new())
+            .unwrap()
+            .contains("ros1_codec"));
+        assert!(generate(RosVersion::ROS1, &options.no_std(true)).is_err());
+    }
+
+    /// Confirms borrowed forms reference strings and byte arrays, and only exist for messages containing them
+    #[test_log::test]
+    fn generate_ros1_borrowed() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let point =
+            parse_ros_message_file("float64 x", "Point", &pkg, "./Point.msg".as_ref()).unwrap();
+        let blob = parse_ros_message_file(
+            "string name\nstring[] tags\nuint8[] data\nfloat32[4] values\nPoint[] points",
+            "Blob",
+            &pkg,
+            "./Blob.msg".as_ref(),
+        )
+        .unwrap();
+        let (messages, services) = resolve_dependency_graph(vec![point, blob], vec![]).unwrap();
+        let options = CodegenOptions::new().ros1_borrowed(true);
+        let source = generate_rust_ros_message_definitions(messages, services, vec![], &options)
+            .unwrap()
+            .to_string();
+        assert!(source.contains("pub struct BlobRef < 'a >"));
+        assert!(source.contains("pub r#name : & 'a str"));
+        assert!(source.contains("pub r#tags : :: std :: vec :: Vec < & 'a str >"));
+        assert!(source.contains("pub r#data : & 'a [u8]"));
+        assert!(source.contains("pub r#values : [f32 ; 4]"));
+        assert!(source.contains("pub r#points : :: std :: vec :: Vec < < self :: Point as :: roslibrust :: ros1_codec :: Ros1Borrow > :: Borrowed < 'a > >"));
+        // Implies the codec
+        assert!(source.contains("impl :: roslibrust :: ros1_codec :: Ros1Decode for Blob"));
+        // Point has nothing to borrow
+        assert!(!source.contains("PointRef"));
+        assert!(source.contains("type Borrowed < 'a > = Point ;"));
+    }
+
+    /// Confirms lazy views decode each field after skipping the ones before it
+    #[test_log::test]
+    fn generate_ros1_lazy() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let empty = parse_ros_message_file("", "Empty", &pkg, "./Empty.msg".as_ref()).unwrap();
+        let blob = parse_ros_message_file(
+            "time stamp\nuint8[] data\nstring name",
+            "Blob",
+            &pkg,
+            "./Blob.msg".as_ref(),
+        )
+        .unwrap();
+        let (messages, services) = resolve_dependency_graph(vec![empty, blob], vec![]).unwrap();
+        let options = CodegenOptions::new()
+            .ros1_lazy(true)
+            .byte_array_type(ByteArrayType::Bytes);
+        let source = generate_rust_ros_message_definitions(messages, services, vec![], &options)
+            .unwrap()
+            .to_string();
+        assert!(source.contains("pub struct BlobLazy < 'a >"));
+        assert!(source.contains("type Lazy < 'a > = BlobLazy < 'a > ;"));
+        assert!(source.contains("pub fn r#stamp"));
+        assert!(source.contains("let reader = & mut :: roslibrust :: ros1_codec :: Reader :: new (self . body) ; < :: roslibrust :: codegen :: integral_types :: Time as :: roslibrust :: ros1_codec :: Ros1Decode > :: skip (reader) ? ; :: roslibrust :: ros1_codec :: skip_bytes (reader) ? ; :: roslibrust :: ros1_codec :: Ros1Decode :: decode (reader)"));
+        // Implies the codec, which skips whole messages
+        assert!(source.contains("impl :: roslibrust :: ros1_codec :: Ros1Decode for Blob"));
+        assert!(source.contains("fn skip ("));
+        assert!(source.contains("pub struct EmptyLazy < 'a >"));
+    }
+
+    /// Confirms conversions to r2r and rosrust types are generated for messages of the matching ROS version
+    #[test_log::test]
+    fn generate_foreign_conversions() {
+        let paths = vec![
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/ros2_test_msgs").into(),
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../assets/ros1_common_interfaces/rosapi"
+            )
+            .into(),
+        ];
+        let options = CodegenOptions::new()
+            .r2r_conversions(true)
+            .rosrust_conversions(true);
+        let (source, _) =
+            find_and_generate_ros_messages_without_ros_package_path_with_options(paths, &options)
+                .unwrap();
+        let source = source.to_string();
+        assert!(source.contains(
+            "# [cfg (feature = \"r2r\")] impl :: core :: convert :: From < Defaults > for :: r2r :: test_msgs :: msg :: Defaults"
+        ));
+        assert!(source.contains(
+            "# [cfg (feature = \"rosrust\")] impl :: core :: convert :: From < :: rosrust_msg :: rosapi :: GetTimeRes > for GetTimeResponse"
+        ));
+        // Keywords are suffixed with an underscore
+        assert!(source.contains("type_ : value . r#type"));
+        assert!(source.contains("r#type : value . type_"));
+        assert!(source.contains(":: rosrust :: Time { sec : value . r#time . secs as u32"));
+        // Each message only converts to the crate of its ROS version
+        assert!(!source.contains(":: rosrust_msg :: test_msgs"));
+        assert!(!source.contains(":: r2r :: rosapi"));
+
+        let options = options.time_type(TimeType::Std);
+        assert!(generate_rust_ros_message_definitions(vec![], vec![], vec![], &options).is_err());
+    }
+
+    /// Confirms protobuf definitions and conversions to prost's types mirror the messages
+    #[test_log::test]
+    fn generate_proto_mirror() {
+        let std_msgs = Package {
+            name: "std_msgs".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let header = parse_ros_message_file(
+            "uint32 seq\ntime stamp\nstring frame_id",
+            "Header",
+            &std_msgs,
+            "./Header.msg".as_ref(),
+        )
+        .unwrap();
+        let test_pkg = Package {
+            name: "test_pkg".to_string(),
+            ..std_msgs
+        };
+        let image = parse_ros_message_file(
+            "Header header\nint8 level\nuint8[] data\nfloat32[3] scale\nstring type",
+            "Image",
+            &test_pkg,
+            "./Image.msg".as_ref(),
+        )
+        .unwrap();
+        let (messages, services) = resolve_dependency_graph(vec![header, image], vec![]).unwrap();
+
+        let files = proto::generate_proto_files(&messages, &services).unwrap();
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            ["roslibrust", "std_msgs", "test_pkg"]
+        );
+        let image_proto = &files["test_pkg"];
+        assert!(image_proto.contains("package ros.test_pkg;"));
+        assert!(image_proto.contains("import \"std_msgs.proto\";"));
+        assert!(image_proto.contains(".ros.std_msgs.Header header = 1;"));
+        assert!(image_proto.contains("int32 level = 2;"));
+        assert!(image_proto.contains("bytes data = 3;"));
+        assert!(image_proto.contains("repeated float scale = 4;"));
+        assert!(image_proto.contains("string type = 5;"));
+        assert!(files["std_msgs"].contains(".ros.roslibrust.Time stamp = 2;"));
+
+        let options = CodegenOptions::new().proto_conversions("crate::proto::ros");
+        let source = generate_rust_ros_message_definitions(messages, services, vec![], &options)
+            .unwrap()
+            .to_string();
+        assert!(source.contains(
+            "impl :: core :: convert :: From < Image > for crate :: proto :: ros :: test_pkg :: Image"
+        ));
+        assert!(source.contains(
+            "header : :: core :: option :: Option :: Some (:: core :: convert :: Into :: into (value . r#header))"
+        ));
+        assert!(source.contains("r#level : value . level as i8"));
+        assert!(source.contains("r#type : value . r#type"));
+        assert!(
+            source.contains("crate :: proto :: ros :: roslibrust :: Time { secs : element . secs")
+        );
+
+        let options = options.time_type(TimeType::Std);
+        assert!(generate_rust_ros_message_definitions(vec![], vec![], vec![], &options).is_err());
+    }
+
+    /// Confirms builders have a setter per field, and header shortcuts when the message has a header
+    #[test_log::test]
+    fn generate_builders() {
+        let std_msgs = Package {
+            name: "std_msgs".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let header = parse_ros_message_file(
+            "uint32 seq\ntime stamp\nstring frame_id",
+            "Header",
+            &std_msgs,
+            "./Header.msg".as_ref(),
+        )
+        .unwrap();
+        let test_pkg = Package {
+            name: "test_pkg".to_string(),
+            ..std_msgs
+        };
+        let stamped = parse_ros_message_file(
+            "Header header\nfloat64 value",
+            "Stamped",
+            &test_pkg,
+            "./Stamped.msg".as_ref(),
+        )
+        .unwrap();
+        let (messages, services) = resolve_dependency_graph(vec![header, stamped], vec![]).unwrap();
+
+        let options = CodegenOptions::new().builders(true);
+        let source = generate_rust_ros_message_definitions(messages, services, vec![], &options)
+            .unwrap()
+            .to_string();
+        assert!(source.contains("pub struct StampedBuilder"));
+        assert!(source.contains("pub fn builder () -> StampedBuilder"));
+        assert!(source.contains("pub fn r#value (mut self , r#value : impl Into < f64 >)"));
+        assert!(source.contains(
+            "pub fn frame_id (mut self , frame_id : impl Into < :: std :: string :: String >)"
+        ));
+        assert!(source.contains(
+            "pub fn stamp (mut self , stamp : impl Into < :: roslibrust :: codegen :: integral_types :: Time >)"
+        ));
+        // The header itself has stamp and frame_id fields so only gets their setters
+        assert_eq!(source.matches("pub fn frame_id").count(), 1);
+        assert_eq!(source.matches("pub fn r#frame_id").count(), 1);
+    }
+
+    /// Confirms time and duration fields use the selected types and are converted when serialized
+    #[test_log::test]
+    fn generate_mapped_time_types() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let msg = parse_ros_message_file(
+            "time stamp\nduration[] timeouts\ntime[40] history",
+            "Stamped",
+            &pkg,
+            "./Stamped.msg".as_ref(),
+        )
+        .unwrap();
+        let generate = |time_type| {
+            let (messages, services) = resolve_dependency_graph(vec![msg.clone()], vec![]).unwrap();
+            let options = CodegenOptions::new().time_type(time_type);
+            generate_rust_ros_message_definitions(messages, services, vec![], &options)
+                .unwrap()
+                .to_string()
+        };
+
+        let source = generate(TimeType::Std);
+        assert!(source.contains("pub r#stamp : :: std :: time :: SystemTime"));
+        assert!(
+            source.contains("pub r#timeouts : :: std :: vec :: Vec <:: std :: time :: Duration >")
+        );
+        assert!(source.contains("pub r#history : [:: std :: time :: SystemTime ; 40]"));
+        assert!(source.contains("[::std::time::SystemTime::UNIX_EPOCH; 40]"));
+        assert!(source.contains("\"::roslibrust::codegen::AsRosTime\""));
+        assert!(source.contains("\"::roslibrust::codegen::AsRosTimeSeq\""));
+        assert!(source.contains("\"::roslibrust::codegen::AsRosTimeArray\""));
+        assert!(!source.contains("integral_types"));
+
+        let source = generate(TimeType::Chrono);
+        assert!(source.contains("pub r#stamp : :: roslibrust :: codegen :: chrono :: DateTime <"));
+        assert!(!source.contains("UNIX_EPOCH"));
+
+        let source = generate(TimeType::Ros);
+        assert!(source.contains("pub r#stamp : :: roslibrust :: codegen :: integral_types :: Time"));
+        assert!(!source.contains("AsRosTime"));
+    }
+
+    /// Confirms extra derives and attributes are applied to the selected messages
+    #[test_log::test]
+    fn generate_extra_derives_and_attributes() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let parse = |name: &str| {
+            parse_ros_message_file("int32 data", name, &pkg, "./Msg.msg".as_ref()).unwrap()
+        };
+        let (messages, services) =
+            resolve_dependency_graph(vec![parse("Selected"), parse("Other")], vec![]).unwrap();
+
+        let options = CodegenOptions::new()
+            .derive("Eq")
+            .derive_for("test_pkg/Selected", "Copy")
+            .derive_for("other_pkg", "Hash")
+            .attribute_for("test_pkg", "#[serde(deny_unknown_fields)]");
+        let source = generate_rust_ros_message_definitions(messages, services, vec![], &options)
+            .unwrap()
+            .to_string();
+        // The attributes of a struct are between the end of the previous item and its name
+        let attrs_of = |name: &str| {
+            let end = source.find(&format!("pub struct {name}")).unwrap();
+            let start = source[..end].rfind("non_snake_case").unwrap();
+            source[start..end].to_string()
+        };
+        let selected = attrs_of("Selected");
+        let other = attrs_of("Other");
+        assert!(selected.contains("derive (Eq)") && other.contains("derive (Eq)"));
+        assert!(selected.contains("derive (Copy)") && !other.contains("derive (Copy)"));
+        assert!(!source.contains("Hash"));
+        assert!(selected.contains("deny_unknown_fields") && other.contains("deny_unknown_fields"));
+
+        let options = CodegenOptions::new().derive("not a path");
+        let (messages, services) =
+            resolve_dependency_graph(vec![parse("Selected")], vec![]).unwrap();
+        assert!(
+            generate_rust_ros_message_definitions(messages, services, vec![], &options).is_err()
+        );
+    }
 }
@@ -0,0 +1,329 @@
+//! `roslibrust-codegen` generates Rust types from ROS message, service and action files, for use in build
+//! pipelines or to check the generated code into a repository instead of generating it in a build.rs or macro.
+//!
+//! Install with `cargo install roslibrust_codegen --features cli`, then run e.g.
+//! `roslibrust-codegen ./ros_packages --package std_msgs --derive Eq -o src/messages.rs`.
+
+use clap::{Parser, ValueEnum};
+use roslibrust_codegen::build_helper::BuildHelper;
+use roslibrust_codegen::utils::RosVersion;
+use roslibrust_codegen::{
+    write_ros_message_modules, ByteArrayType, CodegenOptions, FieldCase, TimeType,
+};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Parser, Debug)]
+#[command(about = "Generates Rust types for ROS messages, services and actions")]
+struct Args {
+    /// Paths to search for ROS packages
+    search_paths: Vec<PathBuf>,
+    /// Also search the paths in ROS_PACKAGE_PATH
+    #[arg(long)]
+    ros_package_path: bool,
+    /// Where to write the code, a file for the single-file layout or a directory for module-per-package
+    #[arg(long, short)]
+    output: PathBuf,
+    /// How the generated code is split into files
+    #[arg(long, value_enum, default_value_t = Layout::SingleFile)]
+    layout: Layout,
+    /// Treat every package as this ROS version, instead of detecting it from each package.xml
+    #[arg(long, value_enum)]
+    ros_version: Option<Version>,
+    /// Only generate this package, may be repeated
+    #[arg(long = "package", short)]
+    packages: Vec<String>,
+    /// Don't generate this package, may be repeated
+    #[arg(long)]
+    exclude: Vec<String>,
+    /// Also generate the packages the selected packages depend on according to their package.xml
+    #[arg(long)]
+    with_dependencies: bool,
+    /// Path only searched for dependencies of the selected packages, may be repeated
+    #[arg(long)]
+    dependency_path: Vec<PathBuf>,
+    /// Only generate the interfaces matching this selector and their dependencies, e.g. geometry_msgs/Pose*, may be repeated
+    #[arg(long)]
+    include: Vec<String>,
+    /// Don't generate the interfaces matching this selector unless they are a dependency, may be repeated
+    #[arg(long)]
+    omit: Vec<String>,
+    /// Use the types of a package generated in another crate, as PACKAGE=PATH e.g. std_msgs=::shared_msgs::std_msgs
+    #[arg(long, value_parser = parse_selected)]
+    extern_package: Vec<(String, String)>,
+    /// Add a derive to every message, may be repeated
+    #[arg(long)]
+    derive: Vec<String>,
+    /// Add a derive to the messages of a package or a single message, as SELECTOR=DERIVE e.g. std_msgs/Header=Eq
+    #[arg(long, value_parser = parse_selected)]
+    derive_for: Vec<(String, String)>,
+    /// Add an attribute to every message, may be repeated
+    #[arg(long)]
+    attribute: Vec<String>,
+    /// Add an attribute to the messages of a package or a single message, as SELECTOR=ATTRIBUTE
+    #[arg(long, value_parser = parse_selected)]
+    attribute_for: Vec<(String, String)>,
+    /// Generate uint8[] fields as bytes::Bytes instead of Vec<u8>
+    #[arg(long)]
+    bytes: bool,
+    /// The Rust types used for time and duration fields
+    #[arg(long, value_enum, default_value_t = Time::Ros)]
+    time_type: Time,
+    /// Generate code for no_std crates, see CodegenOptions::no_std
+    #[arg(long)]
+    no_std: bool,
+    /// Only derive serde when the generated code's crate has a serde feature enabled, see CodegenOptions::optional_serde
+    #[arg(long)]
+    optional_serde: bool,
+    /// Generate a ROS1 encoder and decoder for each ROS1 message, see CodegenOptions::ros1_codec
+    #[arg(long)]
+    ros1_codec: bool,
+    /// Generate a borrowed form of each ROS1 message decoded without copying, see CodegenOptions::ros1_borrowed
+    #[arg(long)]
+    ros1_borrowed: bool,
+    /// Generate a lazily decoded view of each ROS1 message, see CodegenOptions::ros1_lazy
+    #[arg(long)]
+    ros1_lazy: bool,
+    /// Generate a builder for each message, see CodegenOptions::builders
+    #[arg(long)]
+    builders: bool,
+    /// Generate enums for families of integer constants, see CodegenOptions::constant_enums
+    #[arg(long)]
+    constant_enums: bool,
+    /// Generate conversions to and from r2r's types behind the r2r feature, see CodegenOptions::r2r_conversions
+    #[arg(long)]
+    r2r_conversions: bool,
+    /// Generate conversions to and from rosrust_msg's types behind the rosrust feature
+    #[arg(long)]
+    rosrust_conversions: bool,
+    /// Generate conversions to and from the prost types under this module, see CodegenOptions::proto_conversions
+    #[arg(long, value_name = "MODULE")]
+    proto_conversions: Option<String>,
+    /// Rename fields to this casing in JSON, see CodegenOptions::rename_fields
+    #[arg(long, value_enum)]
+    rename_fields: Option<Case>,
+    /// Also accept fields in this casing in JSON, see CodegenOptions::alias_fields
+    #[arg(long, value_enum)]
+    alias_fields: Option<Case>,
+    /// Leave out empty variable length arrays in JSON, which breaks the binary ROS formats
+    #[arg(long)]
+    skip_empty_arrays: bool,
+    /// Don't format the generated code with rustfmt
+    #[arg(long)]
+    no_format: bool,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Layout {
+    /// All packages in a single file, each in its own module
+    SingleFile,
+    /// One file per package and a mod.rs declaring them
+    ModulePerPackage,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Version {
+    Ros1,
+    Ros2,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Time {
+    /// roslibrust's own Time and Duration
+    Ros,
+    /// std::time::SystemTime and std::time::Duration
+    Std,
+    /// chrono::DateTime<Utc> and chrono::Duration, requires roslibrust_codegen's chrono feature
+    Chrono,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Case {
+    Camel,
+    Pascal,
+    Kebab,
+    ScreamingSnake,
+}
+
+impl From<Case> for FieldCase {
+    fn from(case: Case) -> Self {
+        match case {
+            Case::Camel => FieldCase::CamelCase,
+            Case::Pascal => FieldCase::PascalCase,
+            Case::Kebab => FieldCase::KebabCase,
+            Case::ScreamingSnake => FieldCase::ScreamingSnakeCase,
+        }
+    }
+}
+
+fn parse_selected(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((selector, item)) if !selector.is_empty() && !item.is_empty() => {
+            Ok((selector.to_owned(), item.to_owned()))
+        }
+        _ => Err(String::from("Expected format: 'SELECTOR=VALUE'")),
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    env_logger::init();
+    let path = run(Args::parse())?;
+    log::info!("Generated code written to {}", path.display());
+    Ok(())
+}
+
+/// Generates and writes the code, returning the path of the file written or the mod.rs of the modules
+fn run(args: Args) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut options = CodegenOptions::new()
+        .time_type(match args.time_type {
+            Time::Ros => TimeType::Ros,
+            Time::Std => TimeType::Std,
+            Time::Chrono => TimeType::Chrono,
+        })
+        .no_std(args.no_std)
+        .optional_serde(args.optional_serde)
+        .ros1_codec(args.ros1_codec)
+        .ros1_borrowed(args.ros1_borrowed)
+        .ros1_lazy(args.ros1_lazy)
+        .builders(args.builders)
+        .constant_enums(args.constant_enums)
+        .r2r_conversions(args.r2r_conversions)
+        .rosrust_conversions(args.rosrust_conversions)
+        .skip_serializing_empty_arrays(args.skip_empty_arrays);
+    if let Some(module) = args.proto_conversions {
+        options = options.proto_conversions(module);
+    }
+    if let Some(case) = args.rename_fields {
+        options = options.rename_fields(case.into());
+    }
+    if let Some(case) = args.alias_fields {
+        options = options.alias_fields(case.into());
+    }
+    if args.bytes {
+        options = options.byte_array_type(ByteArrayType::Bytes);
+    }
+    for selector in args.include {
+        options = options.include(selector);
+    }
+    for selector in args.omit {
+        options = options.exclude(selector);
+    }
+    for (package, path) in args.extern_package {
+        options = options.extern_package(package, path);
+    }
+    for derive in args.derive {
+        options = options.derive(derive);
+    }
+    for (selector, derive) in args.derive_for {
+        options = options.derive_for(selector, derive);
+    }
+    for attribute in args.attribute {
+        options = options.attribute(attribute);
+    }
+    for (selector, attribute) in args.attribute_for {
+        options = options.attribute_for(selector, attribute);
+    }
+
+    let mut helper = BuildHelper::new()
+        .use_ros_package_path(args.ros_package_path)
+        .resolve_dependencies(args.with_dependencies)
+        .options(options);
+    for path in args.search_paths {
+        helper = helper.search_path(path);
+    }
+    for path in args.dependency_path {
+        helper = helper.dependency_path(path);
+    }
+    for package in args.packages {
+        helper = helper.include_package(package);
+    }
+    for package in args.exclude {
+        helper = helper.exclude_package(package);
+    }
+    if let Some(version) = args.ros_version {
+        helper = helper.ros_version(match version {
+            Version::Ros1 => RosVersion::ROS1,
+            Version::Ros2 => RosVersion::ROS2,
+        });
+    }
+
+    // The file rustfmt is run on, which for a module per package also formats the modules it declares
+    let root_file = match args.layout {
+        Layout::SingleFile => {
+            let (source, _) = helper.generate_source()?;
+            if let Some(parent) = args.output.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&args.output, source.to_string())?;
+            args.output
+        }
+        Layout::ModulePerPackage => {
+            let (modules, _) = helper.generate_modules()?;
+            write_ros_message_modules(&args.output, modules)?;
+            args.output.join("mod.rs")
+        }
+    };
+    if !args.no_format {
+        format_file(&root_file);
+    }
+    Ok(root_file)
+}
+
+fn format_file(path: &Path) {
+    match Command::new("rustfmt")
+        .arg("--edition=2021")
+        .arg(path)
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        Ok(status) => log::warn!("rustfmt failed with {status}, generated code is not formatted"),
+        Err(e) => log::warn!("Unable to run rustfmt, generated code is not formatted: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_selected_values() {
+        assert_eq!(
+            parse_selected("std_msgs/Header=Eq"),
+            Ok(("std_msgs/Header".to_owned(), "Eq".to_owned()))
+        );
+        assert_eq!(
+            parse_selected(r#"std_msgs=#[serde(rename_all = "camelCase")]"#),
+            Ok((
+                "std_msgs".to_owned(),
+                r#"#[serde(rename_all = "camelCase")]"#.to_owned()
+            ))
+        );
+        assert!(parse_selected("Eq").is_err());
+        assert!(parse_selected("=Eq").is_err());
+    }
+
+    #[test]
+    fn generates_module_per_package() {
+        let output = std::env::temp_dir().join("roslibrust_codegen_cli_test");
+        let args = Args::try_parse_from([
+            "roslibrust-codegen",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/ros2_test_msgs"),
+            "--layout",
+            "module-per-package",
+            "--ros-version",
+            "ros1",
+            "--derive-for",
+            "test_msgs/Defaults=PartialOrd",
+            "--no-format",
+            "-o",
+            output.to_str().unwrap(),
+        ])
+        .unwrap();
+        assert_eq!(run(args).unwrap(), output.join("mod.rs"));
+
+        let test_msgs = std::fs::read_to_string(output.join("test_msgs.rs")).unwrap();
+        assert!(test_msgs.contains("PartialOrd"));
+        // Overriding the version to ROS1 leaves out the ROS2 type hashes
+        assert!(!test_msgs.contains("TYPE_HASH"));
+    }
+}
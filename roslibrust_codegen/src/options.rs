@@ -0,0 +1,531 @@
+//! Options controlling the shape of generated code, see [CodegenOptions].
+
+use std::collections::BTreeMap;
+
+/// The Rust type used to represent variable length `uint8[]` fields.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ByteArrayType {
+    /// `Vec<u8>`, the default
+    #[default]
+    Vec,
+    /// `bytes::Bytes`, which is cheap to clone so large payloads such as images and point clouds
+    /// can be passed along a pipeline without copying
+    Bytes,
+}
+
+/// The Rust types used to represent `time` and `duration` fields, and ROS2's `builtin_interfaces/Time` and
+/// `builtin_interfaces/Duration` fields.
+///
+/// Whichever type is chosen the fields are sent as the ROS types, converting while serializing and deserializing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeType {
+    /// [crate::Time] and [crate::Duration], the default, which can represent every ROS value
+    #[default]
+    Ros,
+    /// [std::time::SystemTime] and [std::time::Duration]
+    ///
+    /// Values which std can't represent, such as negative durations, fail to deserialize.
+    Std,
+    /// `chrono::DateTime<chrono::Utc>` and `chrono::Duration`
+    ///
+    /// The generated code requires the `chrono` feature of roslibrust_codegen to be enabled.
+    Chrono,
+}
+
+impl TimeType {
+    /// Returns the Rust type replacing the ROS time or duration type `ros_type`, if it is one and isn't kept
+    pub(crate) fn rust_type(self, ros_type: &str) -> Option<&'static str> {
+        let is_time = match ros_type {
+            "time" | "builtin_interfaces/Time" => true,
+            "duration" | "builtin_interfaces/Duration" => false,
+            _ => return None,
+        };
+        match (self, is_time) {
+            (TimeType::Ros, _) => None,
+            (TimeType::Std, true) => Some("::std::time::SystemTime"),
+            (TimeType::Std, false) => Some("::std::time::Duration"),
+            (TimeType::Chrono, true) => {
+                Some("::roslibrust::codegen::chrono::DateTime<::roslibrust::codegen::chrono::Utc>")
+            }
+            (TimeType::Chrono, false) => Some("::roslibrust::codegen::chrono::Duration"),
+        }
+    }
+}
+
+/// A casing fields can be renamed to or aliased as in serde, e.g. for JSON consumers expecting camelCase.
+///
+/// Names are converted the same way as by serde's `rename_all`, which expects snake_case field names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldCase {
+    /// e.g. `frameId`
+    CamelCase,
+    /// e.g. `FrameId`
+    PascalCase,
+    /// e.g. `frame-id`
+    KebabCase,
+    /// e.g. `FRAME_ID`
+    ScreamingSnakeCase,
+}
+
+impl FieldCase {
+    /// Converts a snake_case field name to this casing
+    pub(crate) fn apply(self, name: &str) -> String {
+        match self {
+            FieldCase::PascalCase => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for c in name.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
+                    }
+                }
+                pascal
+            }
+            FieldCase::CamelCase => {
+                let pascal = FieldCase::PascalCase.apply(name);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            FieldCase::KebabCase => name.replace('_', "-"),
+            FieldCase::ScreamingSnakeCase => name.to_ascii_uppercase(),
+        }
+    }
+}
+
+/// Options for code generation, passed to [crate::find_and_generate_ros_messages_with_options] and friends.
+///
+/// ```
+/// use roslibrust_codegen::{ByteArrayType, CodegenOptions};
+///
+/// let options = CodegenOptions::new()
+///     .byte_array_type(ByteArrayType::Bytes)
+///     .derive("schemars::JsonSchema")
+///     .derive_for("std_msgs/Header", "Eq")
+///     .attribute_for("geometry_msgs", "#[serde(deny_unknown_fields)]");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CodegenOptions {
+    pub(crate) byte_array_type: ByteArrayType,
+    pub(crate) time_type: TimeType,
+    pub(crate) no_std: bool,
+    pub(crate) builders: bool,
+    pub(crate) optional_serde: bool,
+    pub(crate) ros1_codec: bool,
+    pub(crate) ros1_borrowed: bool,
+    pub(crate) ros1_lazy: bool,
+    pub(crate) constant_enums: bool,
+    pub(crate) rename_fields: Option<FieldCase>,
+    pub(crate) alias_fields: Option<FieldCase>,
+    pub(crate) skip_serializing_empty_arrays: bool,
+    pub(crate) r2r_conversions: bool,
+    pub(crate) rosrust_conversions: bool,
+    pub(crate) proto_module: Option<String>,
+    // Additional derives and attributes, with the selector they apply to or None for all types
+    extra_derives: Vec<(Option<String>, String)>,
+    extra_attributes: Vec<(Option<String>, String)>,
+    // Selectors of the messages, services and actions to generate, see CodegenOptions::include
+    include: Vec<String>,
+    exclude: Vec<String>,
+    // Paths of the modules of packages generated in another crate, see CodegenOptions::extern_package
+    extern_packages: BTreeMap<String, String>,
+}
+
+impl CodegenOptions {
+    /// Creates the default options, which generate the same code as the functions without options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the type used for variable length `uint8[]` fields.
+    /// Bounded sequences such as `uint8[<=10]` always use `Vec<u8>`.
+    pub fn byte_array_type(mut self, byte_array_type: ByteArrayType) -> Self {
+        self.byte_array_type = byte_array_type;
+        self
+    }
+
+    /// Sets the types used for time and duration fields.
+    pub fn time_type(mut self, time_type: TimeType) -> Self {
+        self.time_type = time_type;
+        self
+    }
+
+    /// Generates code which compiles in a `#![no_std]` crate with `extern crate alloc`, off by default.
+    ///
+    /// This allows sharing message definitions with embedded firmware. No_std code does not depend on roslibrust:
+    /// - `String` and `Vec` come from `alloc`, and `Default` is derived with the `smart-default` crate, which must be
+    ///   a dependency of the crate the code is generated in.
+    /// - Serde is only derived when that crate's `serde` feature is enabled, which must then enable the `serde`
+    ///   (with the `derive` and `alloc` features), `serde_bytes` and `serde-big-array` dependencies.
+    /// - roslibrust's message, service and action traits are only implemented when that crate's `roslibrust` feature
+    ///   is enabled, which requires std.
+    /// - `time` and `duration` fields use `Time` and `Duration` structs generated into an `integral_types` module
+    ///   alongside the packages' modules.
+    ///
+    /// The bounds of ROS2 bounded strings and sequences are not enforced, and [ByteArrayType::Bytes] and
+    /// [TimeType]s other than [TimeType::Ros] are not supported.
+    pub fn no_std(mut self, no_std: bool) -> Self {
+        self.no_std = no_std;
+        self
+    }
+
+    /// Only derives serde for the generated types when the `serde` feature of the crate they are generated in is
+    /// enabled, off by default.
+    ///
+    /// This saves compiling the serde implementations of every message in crates which only use the plain structs,
+    /// e.g. to convert them into their own CDR or protobuf types. The crate must declare a `serde` feature, which
+    /// needs no dependencies as serde is used through roslibrust. roslibrust's message, service and action traits
+    /// require serde, and so are also only implemented with the feature enabled.
+    ///
+    /// No_std code always makes serde optional, see [CodegenOptions::no_std].
+    pub fn optional_serde(mut self, optional_serde: bool) -> Self {
+        self.optional_serde = optional_serde;
+        self
+    }
+
+    /// Generates a dedicated ROS1 encoder and decoder for each ROS1 message, off by default.
+    ///
+    /// The ROS1 backend then uses it instead of serde to send and receive messages, which writes fields directly
+    /// into a buffer of the precomputed size and copies arrays of primitives in bulk, rather than element by element
+    /// for messages made of large arrays such as images and point clouds. See `roslibrust::ros1_codec` for details.
+    ///
+    /// Can't be used with no_std, or [TimeType]s other than [TimeType::Ros].
+    pub fn ros1_codec(mut self, ros1_codec: bool) -> Self {
+        self.ros1_codec = ros1_codec;
+        self
+    }
+
+    /// Generates a borrowed form of each ROS1 message which can be decoded without copying its strings and byte
+    /// arrays, off by default. Implies [CodegenOptions::ros1_codec].
+    ///
+    /// A message with a string or byte array anywhere in it gets a `{Name}Ref<'a>` struct, with `&'a str` and
+    /// `&'a [u8]` fields referencing the data it was decoded from and `to_owned()` converting it into the message.
+    /// Other messages are their own borrowed form. With the ROS1 backend, subscribers' `next_encoded()` returns the
+    /// received data for decoding this way, which avoids copying e.g. the pixels of each image received.
+    /// See `roslibrust::ros1_codec` for details.
+    pub fn ros1_borrowed(mut self, ros1_borrowed: bool) -> Self {
+        self.ros1_borrowed = ros1_borrowed;
+        self
+    }
+
+    /// Generates a lazily decoded view of each ROS1 message, off by default. Implies [CodegenOptions::ros1_codec].
+    ///
+    /// Each message gets a `{Name}Lazy<'a>` struct over its encoded data, with a method per field which decodes
+    /// only that field, stepping over the ones before it using the lengths in the data. Consumers filtering large
+    /// messages on e.g. their header then only decode the messages they keep, with
+    /// `subscriber.next_encoded()` and `encoded.lazy()?.header()?` on the ROS1 backend.
+    /// See `roslibrust::ros1_codec` for details.
+    pub fn ros1_lazy(mut self, ros1_lazy: bool) -> Self {
+        self.ros1_lazy = ros1_lazy;
+        self
+    }
+
+    /// Whether the ROS1 codec is generated, which the borrowed and lazy forms are built on
+    pub(crate) fn generates_ros1_codec(&self) -> bool {
+        self.ros1_codec || self.ros1_borrowed || self.ros1_lazy
+    }
+
+    /// Generates a builder for each message, off by default.
+    ///
+    /// `{Name}::builder()` starts from the message's default value and has a setter for each field accepting
+    /// anything convertible into the field's type, finished with `build()`. Messages with a `header` also get
+    /// `frame_id()` and `stamp()`, setting the header's fields:
+    /// `PoseStamped::builder().frame_id("map").pose(pose).build()`.
+    pub fn builders(mut self, builders: bool) -> Self {
+        self.builders = builders;
+        self
+    }
+
+    /// Renames fields to `case` when serialized, and accepts both the new and the ROS names when deserialized.
+    ///
+    /// Only the JSON sent through rosbridge is affected, the ROS1 and ROS2 binary formats don't include field names.
+    /// Note rosbridge and other ROS nodes expect the ROS names, so this is only useful for other consumers.
+    pub fn rename_fields(mut self, case: FieldCase) -> Self {
+        self.rename_fields = Some(case);
+        self
+    }
+
+    /// Also accepts field names in `case` when deserialized, while still serializing the ROS names.
+    pub fn alias_fields(mut self, case: FieldCase) -> Self {
+        self.alias_fields = Some(case);
+        self
+    }
+
+    /// Leaves out empty variable length arrays when serialized, which are then defaulted when deserialized,
+    /// off by default.
+    ///
+    /// This is only useful for JSON, and breaks the ROS1 and ROS2 binary formats which require every field.
+    pub fn skip_serializing_empty_arrays(mut self, skip: bool) -> Self {
+        self.skip_serializing_empty_arrays = skip;
+        self
+    }
+
+    /// Generates an enum for each family of integer constants in a message, off by default.
+    ///
+    /// Constants prefixed with the name of a field of the same type are grouped into an enum named after
+    /// the field, e.g. BatteryState's `POWER_SUPPLY_STATUS_*` constants describing `power_supply_status` become
+    /// `battery_state::PowerSupplyStatus`. For messages without such prefixes the constants of a type describe the
+    /// only field of that type, e.g. GoalStatus's constants become `goal_status::Status`.
+    ///
+    /// Constants can also be grouped explicitly with an `enum:` line in a field's comment, `# enum: PREFIX_*`
+    /// taking the constants with the prefix and `# enum: CONSTANT` the block of constants, separated by empty lines,
+    /// that `CONSTANT` is in. Annotated fields are grouped before prefixes are looked at, and a block of constants
+    /// whose heading comment names an unmatched field describes it, e.g. `# Power supply health constants`.
+    ///
+    /// The enums convert to the field's type with `From` and from it with `TryFrom`, which returns the value back
+    /// if it isn't one of the constants, and list their variants in `ALL` with `is_valid` checking values.
+    /// The constants themselves are still generated, and no enum is generated for constants with repeated values.
+    pub fn constant_enums(mut self, constant_enums: bool) -> Self {
+        self.constant_enums = constant_enums;
+        self
+    }
+
+    /// Generates `From` conversions between ROS2 messages and the equivalent types generated by r2r, off by default.
+    ///
+    /// The conversions are only compiled when the `r2r` feature of the crate the code is generated in is enabled,
+    /// which must then depend on r2r. Messages convert to `r2r::{package}::msg::{Name}`, service requests and
+    /// responses to `r2r::{package}::srv::{Service}::Request` and `Response`, and the goals, results and feedback of
+    /// actions to `r2r::{package}::action::{Action}::Goal`, `Result` and `Feedback`. Fields named after Rust keywords
+    /// are expected with a trailing underscore e.g. `type_`, and [TimeType]s other than [TimeType::Ros] are not
+    /// supported.
+    pub fn r2r_conversions(mut self, r2r_conversions: bool) -> Self {
+        self.r2r_conversions = r2r_conversions;
+        self
+    }
+
+    /// Generates `From` conversions between ROS1 messages and the equivalent types of rosrust's `rosrust_msg`
+    /// crate, off by default.
+    ///
+    /// The conversions are only compiled when the `rosrust` feature of the crate the code is generated in is enabled,
+    /// which must then depend on rosrust and rosrust_msg. Messages convert to `rosrust_msg::{package}::{Name}` and
+    /// service requests and responses to `rosrust_msg::{package}::{Service}Req` and `{Service}Res`.
+    /// As with [CodegenOptions::r2r_conversions] fields named after Rust keywords are expected with a trailing
+    /// underscore, and [TimeType]s other than [TimeType::Ros] are not supported.
+    pub fn rosrust_conversions(mut self, rosrust_conversions: bool) -> Self {
+        self.rosrust_conversions = rosrust_conversions;
+        self
+    }
+
+    /// Generates `From` conversions between messages and the types prost generates from the `.proto` files of
+    /// [crate::proto::generate_proto_files], off by default.
+    ///
+    /// `module` is the path of the module holding the `ros` protobuf package, whose `{package}` submodules contain
+    /// the types prost generates from `{package}.proto`, e.g. `"crate::proto::ros"`. Messages and service requests
+    /// and responses convert to `{module}::{package}::{Name}`, with prost's naming of types and fields. Integers
+    /// widened by protobuf are truncated when converted back, and [TimeType]s other than [TimeType::Ros] are not
+    /// supported.
+    pub fn proto_conversions(mut self, module: impl Into<String>) -> Self {
+        self.proto_module = Some(module.into());
+        self
+    }
+
+    /// Only generates the messages, services and actions matching `selector`, plus the messages they depend on.
+    /// May be called multiple times, everything found is generated if nothing is included.
+    ///
+    /// Selectors are either a package name e.g. `"sensor_msgs"` or a full name e.g. `"geometry_msgs/Pose"`,
+    /// and may contain `*` and `?` wildcards e.g. `"geometry_msgs/Pose*"` or `"*_msgs/*Stamped"`.
+    /// Services and actions are selected by their own names, which generates all of their types.
+    pub fn include(mut self, selector: impl Into<String>) -> Self {
+        self.include.push(selector.into());
+        self
+    }
+
+    /// Doesn't generate the messages, services and actions matching `selector`, see [CodegenOptions::include].
+    ///
+    /// Excluded messages are still generated if an included message, service, or action depends on them.
+    pub fn exclude(mut self, selector: impl Into<String>) -> Self {
+        self.exclude.push(selector.into());
+        self
+    }
+
+    /// Uses the types of `package` generated in another crate instead of generating them, e.g.
+    /// `.extern_package("my_msgs", "::my_msgs_crate::my_msgs")` for a crate shared by several workspace members.
+    ///
+    /// `path` is the module the other crate generated the package's types in. Generated packages refer to the
+    /// package's types through it, so they have the same type as in other crates using the shared crate, rather than
+    /// a duplicate definition each. The package's files must still be found in the search paths, as the md5sums
+    /// and definitions of the messages depending on them are computed from them. Both crates should be generated
+    /// from the same files, and with options producing the same types.
+    pub fn extern_package(mut self, package: impl Into<String>, path: impl Into<String>) -> Self {
+        self.extern_packages.insert(package.into(), path.into());
+        self
+    }
+
+    /// Returns the module path of `package` if it is generated in another crate
+    pub(crate) fn extern_path(&self, package: &str) -> Option<&str> {
+        self.extern_packages.get(package).map(String::as_str)
+    }
+
+    /// Returns the packages generated in other crates and their module paths
+    pub(crate) fn extern_packages(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.extern_packages
+            .iter()
+            .map(|(package, path)| (package.as_str(), path.as_str()))
+    }
+
+    /// Returns true if any includes or excludes were given
+    pub(crate) fn has_filters(&self) -> bool {
+        !self.include.is_empty() || !self.exclude.is_empty()
+    }
+
+    /// Returns true if the interface `package/name` passes the includes and excludes
+    pub(crate) fn is_included(&self, package: &str, name: &str) -> bool {
+        let matches = |selector: &String| match selector.split_once('/') {
+            Some((selector_package, selector_name)) => {
+                glob_match(selector_package, package) && glob_match(selector_name, name)
+            }
+            None => glob_match(selector, package),
+        };
+        (self.include.is_empty() || self.include.iter().any(matches))
+            && !self.exclude.iter().any(matches)
+    }
+
+    /// Adds a derive to every generated message, e.g. `"schemars::JsonSchema"`.
+    ///
+    /// The derived trait's crate must be a dependency of the crate the code is generated in.
+    pub fn derive(mut self, derive: impl Into<String>) -> Self {
+        self.extra_derives.push((None, derive.into()));
+        self
+    }
+
+    /// Adds a derive to the messages matching `selector`, which is either a package name e.g. `"std_msgs"`
+    /// or a full message name e.g. `"std_msgs/Header"`.
+    ///
+    /// Service requests and responses are selected as `"{package}/{Service}Request"` and `"{package}/{Service}Response"`.
+    /// Deriving traits such as `Copy` or `Eq` is only legal for messages whose fields all implement them.
+    pub fn derive_for(mut self, selector: impl Into<String>, derive: impl Into<String>) -> Self {
+        self.extra_derives
+            .push((Some(selector.into()), derive.into()));
+        self
+    }
+
+    /// Adds an attribute to every generated message, e.g. `"#[serde(deny_unknown_fields)]"`.
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.extra_attributes.push((None, attribute.into()));
+        self
+    }
+
+    /// Adds an attribute to the messages matching `selector`, see [CodegenOptions::derive_for].
+    pub fn attribute_for(
+        mut self,
+        selector: impl Into<String>,
+        attribute: impl Into<String>,
+    ) -> Self {
+        self.extra_attributes
+            .push((Some(selector.into()), attribute.into()));
+        self
+    }
+
+    /// Returns the additional derives for the message `package/name`
+    pub(crate) fn derives_for<'a>(
+        &'a self,
+        package: &'a str,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        select(&self.extra_derives, package, name)
+    }
+
+    /// Returns the additional attributes for the message `package/name`
+    pub(crate) fn attributes_for<'a>(
+        &'a self,
+        package: &'a str,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        select(&self.extra_attributes, package, name)
+    }
+}
+
+fn select<'a>(
+    items: &'a [(Option<String>, String)],
+    package: &'a str,
+    name: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    items
+        .iter()
+        .filter(move |(selector, _)| match selector {
+            None => true,
+            Some(selector) => match selector.split_once('/') {
+                Some((selector_package, selector_name)) => {
+                    selector_package == package && selector_name == name
+                }
+                None => selector == package,
+            },
+        })
+        .map(|(_, item)| item.as_str())
+}
+
+/// Matches `value` against `pattern`, where `*` matches any number of characters and `?` matches one
+fn glob_match(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+    let (mut p, mut v) = (0, 0);
+    // Where to resume after the last *, trying to match one more character with it
+    let mut backtrack = None;
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    v = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn converts_field_case() {
+        assert_eq!(FieldCase::CamelCase.apply("frame_id"), "frameId");
+        assert_eq!(FieldCase::CamelCase.apply("D"), "d");
+        assert_eq!(FieldCase::PascalCase.apply("frame_id"), "FrameId");
+        assert_eq!(FieldCase::KebabCase.apply("frame_id"), "frame-id");
+        assert_eq!(FieldCase::ScreamingSnakeCase.apply("frame_id"), "FRAME_ID");
+    }
+
+    #[test]
+    fn selects_included_interfaces() {
+        assert!(glob_match("Pose*", "PoseStamped"));
+        assert!(glob_match("*Stamped", "PoseStamped"));
+        assert!(glob_match("P?se", "Pose"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("Pose", "PoseStamped"));
+        assert!(!glob_match("*Stamped", "Pose"));
+
+        let options = CodegenOptions::new();
+        assert!(!options.has_filters());
+        assert!(options.is_included("std_msgs", "Header"));
+
+        let options = CodegenOptions::new()
+            .include("geometry_msgs/Pose*")
+            .include("std_srvs")
+            .exclude("*/*WithCovariance*");
+        assert!(options.is_included("geometry_msgs", "Pose"));
+        assert!(options.is_included("geometry_msgs", "PoseStamped"));
+        assert!(!options.is_included("geometry_msgs", "PoseWithCovarianceStamped"));
+        assert!(!options.is_included("geometry_msgs", "Twist"));
+        assert!(options.is_included("std_srvs", "Trigger"));
+        assert!(!options.is_included("std_msgs", "Header"));
+    }
+}
@@ -1,26 +1,90 @@
-use crate::parse::ParsedMessageFile;
-use crate::{bail, Error, Package};
+use crate::parse::{ParsedMessageFile, ParsedServiceFile};
+use crate::{bail, Error, Package, RosVersion};
 use std::path::{Path, PathBuf};
 
-use super::parse_ros_message_file;
+use super::{parse_ros_message_file, parse_ros_service_file};
 
+/// Describes all information for a single action file
 #[derive(Clone, Debug)]
 pub struct ParsedActionFile {
     pub name: String,
     pub package: String,
-    pub action_type: ParsedMessageFile,
+    // The names of these types will be auto generated as {name}Goal, {name}Result and {name}Feedback
     pub goal_type: ParsedMessageFile,
     pub result_type: ParsedMessageFile,
     pub feedback_type: ParsedMessageFile,
-    pub action_goal_type: ParsedMessageFile,
-    pub action_result_type: ParsedMessageFile,
-    pub action_feedback_type: ParsedMessageFile,
+    /// The types used to carry the goal, result and feedback over the wire, these differ between ROS1 and ROS2
+    pub wrappers: ActionWrappers,
     /// The contents of the action file this instance was parsed from
     pub source: String,
     /// The path where the message was found
     pub path: PathBuf,
 }
 
+/// The additional types generated for an action, based on the ROS version of its package
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)] // Only one is created per action file
+pub enum ActionWrappers {
+    /// actionlib's messages, each wrapping the goal, result or feedback with a header and goal id / status
+    Ros1 {
+        action_type: ParsedMessageFile,
+        action_goal_type: ParsedMessageFile,
+        action_result_type: ParsedMessageFile,
+        action_feedback_type: ParsedMessageFile,
+    },
+    /// The services and feedback topic message making up a ROS2 action
+    Ros2 {
+        send_goal_type: ParsedServiceFile,
+        get_result_type: ParsedServiceFile,
+        feedback_message_type: ParsedMessageFile,
+    },
+}
+
+impl ParsedActionFile {
+    pub fn get_full_name(&self) -> String {
+        format!("{}/{}", self.package, self.name)
+    }
+
+    /// Returns all of the messages defined by this action, including wrapper messages
+    pub fn messages(&self) -> Vec<ParsedMessageFile> {
+        let mut messages = vec![
+            self.goal_type.clone(),
+            self.result_type.clone(),
+            self.feedback_type.clone(),
+        ];
+        match &self.wrappers {
+            ActionWrappers::Ros1 {
+                action_type,
+                action_goal_type,
+                action_result_type,
+                action_feedback_type,
+            } => messages.extend([
+                action_type.clone(),
+                action_goal_type.clone(),
+                action_result_type.clone(),
+                action_feedback_type.clone(),
+            ]),
+            ActionWrappers::Ros2 {
+                feedback_message_type,
+                ..
+            } => messages.push(feedback_message_type.clone()),
+        }
+        messages
+    }
+
+    /// Returns all of the services defined by this action, only ROS2 actions are made up of services
+    pub fn services(&self) -> Vec<ParsedServiceFile> {
+        match &self.wrappers {
+            ActionWrappers::Ros1 { .. } => vec![],
+            ActionWrappers::Ros2 {
+                send_goal_type,
+                get_result_type,
+                ..
+            } => vec![send_goal_type.clone(), get_result_type.clone()],
+        }
+    }
+}
+
 pub fn parse_ros_action_file(
     data: &str,
     name: &str,
@@ -76,10 +140,23 @@ pub fn parse_ros_action_file(
             .skip(second_dash_line + 1)
             .fold(String::new(), str_accumulator);
 
+        let wrappers = match package.version.unwrap_or(RosVersion::ROS1) {
+            RosVersion::ROS1 => ActionWrappers::Ros1 {
+                action_type: generate_action_msg(name, package, path)?,
+                action_goal_type: generate_action_goal_msg(name, package, path)?,
+                action_result_type: generate_action_result_msg(name, package, path)?,
+                action_feedback_type: generate_action_feedback_msg(name, package, path)?,
+            },
+            RosVersion::ROS2 => ActionWrappers::Ros2 {
+                send_goal_type: generate_send_goal_srv(name, package, path)?,
+                get_result_type: generate_get_result_srv(name, package, path)?,
+                feedback_message_type: generate_feedback_message_msg(name, package, path)?,
+            },
+        };
+
         Ok(ParsedActionFile {
             name: name.to_owned(),
             package: package.name.clone(),
-            action_type: generate_action_msg(name, package, path)?,
             goal_type: parse_ros_message_file(
                 &goal_str,
                 format!("{name}Goal").as_str(),
@@ -98,9 +175,7 @@ pub fn parse_ros_action_file(
                 package,
                 path,
             )?,
-            action_goal_type: generate_action_goal_msg(name, package, path)?,
-            action_result_type: generate_action_result_msg(name, package, path)?,
-            action_feedback_type: generate_action_feedback_msg(name, package, path)?,
+            wrappers,
             source: data.to_owned(),
             path: path.to_owned(),
         })
@@ -185,3 +260,130 @@ actionlib_msgs/GoalStatus status
         path,
     )
 }
+
+// The ROS2 wrappers are defined by rosidl, see:
+// https://design.ros2.org/articles/actions.html and rosidl_parser's action definition
+fn generate_send_goal_srv(
+    name: &str,
+    package: &Package,
+    path: &Path,
+) -> Result<ParsedServiceFile, Error> {
+    let source = format!(
+        r#"
+unique_identifier_msgs/UUID goal_id
+{name}Goal goal
+---
+bool accepted
+builtin_interfaces/Time stamp
+        "#
+    );
+
+    parse_ros_service_file(&source, format!("{name}SendGoal").as_str(), package, path)
+}
+
+fn generate_get_result_srv(
+    name: &str,
+    package: &Package,
+    path: &Path,
+) -> Result<ParsedServiceFile, Error> {
+    let source = format!(
+        r#"
+unique_identifier_msgs/UUID goal_id
+---
+int8 status
+{name}Result result
+        "#
+    );
+
+    parse_ros_service_file(&source, format!("{name}GetResult").as_str(), package, path)
+}
+
+fn generate_feedback_message_msg(
+    name: &str,
+    package: &Package,
+    path: &Path,
+) -> Result<ParsedMessageFile, Error> {
+    let source = format!(
+        r#"
+unique_identifier_msgs/UUID goal_id
+{name}Feedback feedback
+        "#
+    );
+
+    parse_ros_message_file(
+        &source,
+        format!("{name}FeedbackMessage").as_str(),
+        package,
+        path,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FIBONACCI: &str = r#"
+#goal definition
+int32 order
+---
+#result definition
+int32[] sequence
+---
+#feedback
+int32[] sequence
+"#;
+
+    fn package(version: RosVersion) -> Package {
+        Package {
+            name: "actionlib_tutorials".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(version),
+        }
+    }
+
+    #[test_log::test]
+    fn parse_ros1_action() {
+        let action = parse_ros_action_file(
+            FIBONACCI,
+            "Fibonacci",
+            &package(RosVersion::ROS1),
+            Path::new("./Fibonacci.action"),
+        )
+        .unwrap();
+        assert_eq!(action.get_full_name(), "actionlib_tutorials/Fibonacci");
+        assert_eq!(action.goal_type.name, "FibonacciGoal");
+        assert_eq!(action.goal_type.fields[0].field_name, "order");
+        assert_eq!(action.result_type.name, "FibonacciResult");
+        assert_eq!(action.feedback_type.name, "FibonacciFeedback");
+        let names: Vec<_> = action.messages().into_iter().map(|m| m.name).collect();
+        assert!(names.contains(&"FibonacciActionGoal".to_string()));
+        assert!(names.contains(&"FibonacciAction".to_string()));
+        assert!(action.services().is_empty());
+    }
+
+    #[test_log::test]
+    fn parse_ros2_action() {
+        let action = parse_ros_action_file(
+            FIBONACCI,
+            "Fibonacci",
+            &package(RosVersion::ROS2),
+            Path::new("./Fibonacci.action"),
+        )
+        .unwrap();
+        let names: Vec<_> = action.messages().into_iter().map(|m| m.name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "FibonacciGoal",
+                "FibonacciResult",
+                "FibonacciFeedback",
+                "FibonacciFeedbackMessage"
+            ]
+        );
+        let services = action.services();
+        assert_eq!(services[0].name, "FibonacciSendGoal");
+        assert_eq!(services[0].request_type.fields[1].field_name, "goal");
+        assert_eq!(services[1].name, "FibonacciGetResult");
+        assert_eq!(services[1].response_type.fields[0].field_name, "status");
+    }
+}
@@ -0,0 +1,764 @@
+//! Support for parsing ROS2 `.idl` interface files.
+//!
+//! Rather than duplicating all of the type handling of the `.msg` parser, the structs within an IDL file are
+//! translated into the equivalent `.msg` / `.srv` / `.action` definition and handed to the existing parsers.
+//! This keeps generated code (and md5sums) identical whether a type comes from a `.msg` or `.idl` file.
+//!
+//! Only the subset of IDL produced by `rosidl_adapter` and commonly hand written for ROS2 is supported:
+//! modules, structs, constants (in `<Type>_Constants` modules), typedefs of fixed size arrays, sequences,
+//! bounded strings, and the `@default` annotation. Enums and unions have no `.msg` equivalent and are rejected.
+
+use crate::parse::{
+    parse_ros_action_file, parse_ros_message_file, parse_ros_service_file, ParsedActionFile,
+    ParsedMessageFile, ParsedServiceFile,
+};
+use crate::utils::{Package, RosVersion};
+use crate::{bail, Error};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// The interface found within an `.idl` file
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum ParsedIdlFile {
+    Message(ParsedMessageFile),
+    Service(ParsedServiceFile),
+    Action(ParsedActionFile),
+}
+
+/// Parses the contents of an `.idl` file and returns the message, service, or action it defines.
+/// * `data` -- Actual contents of the file
+/// * `name` -- Name of the file excluding the extension, e.g. 'Header'
+/// * `package` -- Package the file was found within, IDL files are always parsed as ROS2
+/// * `path` -- Path to the file, used for error messages and recorded in the result
+pub fn parse_ros_idl_file(
+    data: &str,
+    name: &str,
+    package: &Package,
+    path: &Path,
+) -> Result<ParsedIdlFile, Error> {
+    let package = Package {
+        version: Some(RosVersion::ROS2),
+        ..package.clone()
+    };
+    let tokens = tokenize(data).map_err(|e| idl_error(path, e))?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        typedefs: HashMap::new(),
+        structs: HashMap::new(),
+        constants: HashMap::new(),
+    };
+    parser.parse_definitions().map_err(|e| idl_error(path, e))?;
+
+    if parser.structs.contains_key(name) {
+        let definition = parser.definition(name)?;
+        let msg = parse_ros_message_file(&definition, name, &package, path)?;
+        return Ok(ParsedIdlFile::Message(msg));
+    }
+    let request = format!("{name}_Request");
+    let response = format!("{name}_Response");
+    if parser.structs.contains_key(&request) && parser.structs.contains_key(&response) {
+        let definition = format!(
+            "{}---\n{}",
+            parser.definition(&request)?,
+            parser.definition(&response)?
+        );
+        let srv = parse_ros_service_file(&definition, name, &package, path)?;
+        return Ok(ParsedIdlFile::Service(srv));
+    }
+    let goal = format!("{name}_Goal");
+    let result = format!("{name}_Result");
+    let feedback = format!("{name}_Feedback");
+    if [&goal, &result, &feedback]
+        .iter()
+        .all(|s| parser.structs.contains_key(*s))
+    {
+        let definition = format!(
+            "{}---\n{}---\n{}",
+            parser.definition(&goal)?,
+            parser.definition(&result)?,
+            parser.definition(&feedback)?
+        );
+        let action = parse_ros_action_file(&definition, name, &package, path)?;
+        return Ok(ParsedIdlFile::Action(action));
+    }
+    bail!(
+        "IDL file {} does not define a struct {name}, a service {name}_Request / {name}_Response, or an action {name}_Goal / {name}_Result / {name}_Feedback",
+        path.display()
+    )
+}
+
+fn idl_error(path: &Path, msg: String) -> Error {
+    Error::new(format!(
+        "Failed to parse IDL file {}: {msg}",
+        path.display()
+    ))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Str(String),
+    Punct(char),
+    Scope,
+}
+
+impl Token {
+    /// Text of the token as it should appear in a `.msg` value expression
+    fn text(&self) -> String {
+        match self {
+            Token::Ident(s) => match s.as_str() {
+                "TRUE" | "True" => "true".to_owned(),
+                "FALSE" | "False" => "false".to_owned(),
+                _ => s.clone(),
+            },
+            Token::Number(s) => s.clone(),
+            Token::Str(s) => quote_string(s),
+            Token::Punct(c) => c.to_string(),
+            Token::Scope => "::".to_owned(),
+        }
+    }
+}
+
+/// Quotes a string the way the ROS2 `.msg` parser expects, strings are not escaped so we pick quotes not in the value
+fn quote_string(s: &str) -> String {
+    if s.contains('"') {
+        format!("'{s}'")
+    } else {
+        format!("\"{s}\"")
+    }
+}
+
+/// Splits IDL source into tokens, dropping comments and preprocessor lines
+fn tokenize(data: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = data.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    let mut line_start = true;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            line_start = true;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == '#' && line_start {
+            // Preprocessor directive e.g. #include, skip the line
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        line_start = false;
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            if i >= chars.len() {
+                return Err("Unterminated block comment".to_owned());
+            }
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            let mut value = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => return Err("Unterminated string literal".to_owned()),
+                    Some(&q) if q == c => break,
+                    Some('\\') => {
+                        i += 1;
+                        match chars.get(i) {
+                            Some('n') => value.push('\n'),
+                            Some('t') => value.push('\t'),
+                            Some(&other) => value.push(other),
+                            None => return Err("Unterminated string literal".to_owned()),
+                        }
+                    }
+                    Some(&other) => value.push(other),
+                }
+                i += 1;
+            }
+            i += 1;
+            // Adjacent string literals are concatenated
+            if let Some(Token::Str(prev)) = tokens.last_mut() {
+                prev.push_str(&value);
+            } else {
+                tokens.push(Token::Str(value));
+            }
+        } else if c.is_ascii_digit()
+            || (c == '.' && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()))
+        {
+            let start = i;
+            let is_hex = c == '0' && matches!(chars.get(i + 1), Some('x' | 'X'));
+            i += 1;
+            while i < chars.len() {
+                let c = chars[i];
+                let exponent_sign =
+                    !is_hex && (c == '+' || c == '-') && matches!(chars[i - 1], 'e' | 'E');
+                if c.is_ascii_alphanumeric() || c == '.' || exponent_sign {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c == ':' && chars.get(i + 1) == Some(&':') {
+            tokens.push(Token::Scope);
+            i += 2;
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ArraySpec {
+    Fixed(usize),
+    Unbounded,
+    Bounded(usize),
+}
+
+/// A type expressed in `.msg` terms
+#[derive(Clone, Debug)]
+struct IdlType {
+    base: String,
+    string_bound: Option<usize>,
+    array: Option<ArraySpec>,
+}
+
+impl IdlType {
+    fn scalar(base: &str) -> Self {
+        IdlType {
+            base: base.to_owned(),
+            string_bound: None,
+            array: None,
+        }
+    }
+
+    fn with_array(self, array: ArraySpec) -> Result<Self, String> {
+        if self.array.is_some() {
+            return Err(format!(
+                "Nested arrays / sequences of {} are not supported",
+                self.base
+            ));
+        }
+        Ok(IdlType {
+            array: Some(array),
+            ..self
+        })
+    }
+
+    /// The type as it would be written in a `.msg` file
+    fn to_msg_type(&self) -> String {
+        let mut s = self.base.clone();
+        if let Some(bound) = self.string_bound {
+            s.push_str(&format!("<={bound}"));
+        }
+        match self.array {
+            Some(ArraySpec::Fixed(n)) => s.push_str(&format!("[{n}]")),
+            Some(ArraySpec::Unbounded) => s.push_str("[]"),
+            Some(ArraySpec::Bounded(n)) => s.push_str(&format!("[<={n}]")),
+            None => {}
+        }
+        s
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    typedefs: HashMap<String, IdlType>,
+    /// Struct name -> field lines in `.msg` syntax
+    structs: HashMap<String, Vec<String>>,
+    /// Constants module name -> constant lines in `.msg` syntax
+    constants: HashMap<String, Vec<String>>,
+}
+
+/// Member names rosidl inserts into empty structs, as IDL does not allow them
+const EMPTY_STRUCT_PLACEHOLDER: &str = "structure_needs_at_least_one_member";
+
+impl Parser {
+    /// Returns the `.msg` equivalent definition of a parsed struct, including its constants
+    fn definition(&self, name: &str) -> Result<String, Error> {
+        let mut lines = self
+            .constants
+            .get(&format!("{name}_Constants"))
+            .cloned()
+            .unwrap_or_default();
+        lines.extend(self.structs.get(name).cloned().unwrap_or_default());
+        if let Some(line) = lines.iter().find(|line| line.contains('#')) {
+            bail!("IDL definition of {name} contains a '#' which cannot be represented in a ROS message definition: {line}");
+        }
+        Ok(lines.iter().map(|line| format!("{line}\n")).collect())
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or("Unexpected end of file")?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        match self.next()? {
+            Token::Punct(p) if p == c => Ok(()),
+            other => Err(format!("Expected '{c}' but found {other:?}")),
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_ident(&mut self, ident: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Ident(s)) if s == ident) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        match self.next()? {
+            Token::Ident(s) => Ok(s),
+            other => Err(format!("Expected an identifier but found {other:?}")),
+        }
+    }
+
+    fn size(&mut self) -> Result<usize, String> {
+        match self.next()? {
+            Token::Number(n) => n.parse().map_err(|e| format!("Invalid size {n:?}: {e}")),
+            other => Err(format!("Expected a size but found {other:?}")),
+        }
+    }
+
+    /// Parses definitions until the end of file or the closing brace of the enclosing module
+    fn parse_definitions(&mut self) -> Result<(), String> {
+        while let Some(token) = self.peek() {
+            if *token == Token::Punct('}') {
+                break;
+            }
+            self.parse_definition()?;
+        }
+        Ok(())
+    }
+
+    fn parse_definition(&mut self) -> Result<(), String> {
+        // Annotations on anything other than struct members have no meaning for us
+        self.parse_annotations()?;
+        let keyword = self.ident()?;
+        match keyword.as_str() {
+            "module" => {
+                let name = self.ident()?;
+                self.expect('{')?;
+                if let Some(module) = name.strip_suffix("_Constants") {
+                    let constants = self.parse_constants()?;
+                    self.constants
+                        .entry(format!("{module}_Constants"))
+                        .or_default()
+                        .extend(constants);
+                } else {
+                    self.parse_definitions()?;
+                }
+                self.expect('}')?;
+            }
+            "struct" => {
+                let name = self.ident()?;
+                self.expect('{')?;
+                let mut fields = vec![];
+                while !self.eat('}') {
+                    fields.extend(self.parse_member()?);
+                }
+                self.structs.insert(name, fields);
+            }
+            "const" => {
+                // Constants outside of a _Constants module have nowhere to go in a message, but are still valid IDL
+                self.parse_constant()?;
+                return Ok(());
+            }
+            "typedef" => {
+                let idl_type = self.parse_type()?;
+                let name = self.ident()?;
+                let idl_type = self.parse_dimensions(idl_type)?;
+                self.typedefs.insert(name, idl_type);
+            }
+            "enum" | "union" | "interface" | "exception" => {
+                return Err(format!(
+                    "IDL {keyword} definitions are not supported in ROS interfaces"
+                ));
+            }
+            other => return Err(format!("Unexpected keyword {other:?}")),
+        }
+        self.expect(';')
+    }
+
+    fn parse_constants(&mut self) -> Result<Vec<String>, String> {
+        let mut constants = vec![];
+        while !matches!(self.peek(), Some(Token::Punct('}')) | None) {
+            self.parse_annotations()?;
+            if !self.eat_ident("const") {
+                return Err(format!(
+                    "Expected only constants within a _Constants module, found {:?}",
+                    self.peek()
+                ));
+            }
+            constants.push(self.parse_constant()?);
+        }
+        Ok(constants)
+    }
+
+    /// Parses a constant after the `const` keyword, returning it in `.msg` syntax
+    fn parse_constant(&mut self) -> Result<String, String> {
+        let idl_type = self.parse_type()?;
+        let name = self.ident()?;
+        self.expect('=')?;
+        let mut value = vec![];
+        while !self.eat(';') {
+            value.push(self.next()?);
+        }
+        let value = render_value(&value, &idl_type)?;
+        Ok(format!("{} {name}={value}", idl_type.to_msg_type()))
+    }
+
+    /// Parses a struct member, returning one `.msg` field line per declarator
+    fn parse_member(&mut self) -> Result<Vec<String>, String> {
+        let annotations = self.parse_annotations()?;
+        let idl_type = self.parse_type()?;
+        let mut fields = vec![];
+        loop {
+            let name = self.ident()?;
+            let field_type = self.parse_dimensions(idl_type.clone())?;
+            let default = match annotations.get("default") {
+                Some(value) => format!(" {}", render_value(value, &field_type)?),
+                None => String::new(),
+            };
+            if name != EMPTY_STRUCT_PLACEHOLDER {
+                fields.push(format!("{} {name}{default}", field_type.to_msg_type()));
+            }
+            if !self.eat(',') {
+                break;
+            }
+        }
+        self.expect(';')?;
+        Ok(fields)
+    }
+
+    /// Parses any annotations, returning the value of each by annotation name
+    fn parse_annotations(&mut self) -> Result<HashMap<String, Vec<Token>>, String> {
+        let mut annotations = HashMap::new();
+        while self.eat('@') {
+            let name = self.ident()?;
+            let mut value = vec![];
+            if self.eat('(') {
+                let mut depth = 0;
+                loop {
+                    let token = self.next()?;
+                    match token {
+                        Token::Punct('(') => depth += 1,
+                        Token::Punct(')') if depth == 0 => break,
+                        Token::Punct(')') => depth -= 1,
+                        _ => {}
+                    }
+                    value.push(token);
+                }
+            }
+            // Only interested in the "value" parameter e.g. @default (value=5)
+            if let Some(idx) = value
+                .windows(2)
+                .position(|w| w[0] == Token::Ident("value".to_owned()) && w[1] == Token::Punct('='))
+            {
+                value.drain(..idx + 2);
+                if let Some(end) = value.iter().position(|t| *t == Token::Punct(',')) {
+                    value.truncate(end);
+                }
+            }
+            annotations.insert(name, value);
+        }
+        Ok(annotations)
+    }
+
+    /// Applies any `[N]` array declarators following a name
+    fn parse_dimensions(&mut self, mut idl_type: IdlType) -> Result<IdlType, String> {
+        while self.eat('[') {
+            let size = self.size()?;
+            self.expect(']')?;
+            idl_type = idl_type.with_array(ArraySpec::Fixed(size))?;
+        }
+        Ok(idl_type)
+    }
+
+    fn parse_type(&mut self) -> Result<IdlType, String> {
+        let first = self.ident()?;
+        let base = match first.as_str() {
+            "sequence" => {
+                self.expect('<')?;
+                let inner = self.parse_type()?;
+                let array = if self.eat(',') {
+                    ArraySpec::Bounded(self.size()?)
+                } else {
+                    ArraySpec::Unbounded
+                };
+                self.expect('>')?;
+                return inner.with_array(array);
+            }
+            "string" => {
+                let mut idl_type = IdlType::scalar("string");
+                if self.eat('<') {
+                    idl_type.string_bound = Some(self.size()?);
+                    self.expect('>')?;
+                }
+                return Ok(idl_type);
+            }
+            "wstring" | "wchar" | "any" | "fixed" => {
+                return Err(format!("IDL type {first} is not supported"));
+            }
+            "unsigned" => {
+                if self.eat_ident("short") {
+                    "uint16"
+                } else if self.eat_ident("long") {
+                    if self.eat_ident("long") {
+                        "uint64"
+                    } else {
+                        "uint32"
+                    }
+                } else {
+                    return Err(format!("Unexpected type after unsigned: {:?}", self.peek()));
+                }
+            }
+            "long" => {
+                if self.eat_ident("long") {
+                    "int64"
+                } else if self.eat_ident("double") {
+                    return Err("IDL type long double is not supported".to_owned());
+                } else {
+                    "int32"
+                }
+            }
+            "short" => "int16",
+            "boolean" => "bool",
+            "octet" => "byte",
+            "float" => "float32",
+            "double" => "float64",
+            "char" | "int8" | "uint8" | "int16" | "uint16" | "int32" | "uint32" | "int64"
+            | "uint64" => first.as_str(),
+            _ => {
+                // Scoped names e.g. geometry_msgs::msg::Point, or a typedef
+                let mut segments = vec![first.clone()];
+                while self.peek() == Some(&Token::Scope) {
+                    self.pos += 1;
+                    segments.push(self.ident()?);
+                }
+                let name = segments.last().unwrap();
+                if let Some(alias) = self.typedefs.get(name) {
+                    return Ok(alias.clone());
+                }
+                return Ok(match segments.as_slice() {
+                    [_] => IdlType::scalar(name),
+                    [package, .., name] => IdlType::scalar(&format!("{package}/{name}")),
+                    [] => unreachable!(),
+                });
+            }
+        };
+        Ok(IdlType::scalar(base))
+    }
+}
+
+/// Renders the tokens of a constant or default value as a `.msg` value expression
+fn render_value(tokens: &[Token], idl_type: &IdlType) -> Result<String, String> {
+    match tokens {
+        [] => Err(format!("Missing value for {}", idl_type.to_msg_type())),
+        [Token::Str(s)] if idl_type.array.is_some() => {
+            // rosidl writes array defaults as a string containing a python tuple e.g. "(1, 2, 3)"
+            let inner = tokenize(s)?;
+            let inner = match inner.as_slice() {
+                [Token::Punct('(' | '['), items @ .., Token::Punct(')' | ']')] => items,
+                items => items,
+            };
+            let elements = inner
+                .split(|t| *t == Token::Punct(','))
+                .filter(|element| !element.is_empty())
+                .map(|element| match element {
+                    // The .msg parser reads string arrays as JSON
+                    [Token::Str(s)] => serde_json::to_string(s).unwrap(),
+                    element => element.iter().map(Token::text).collect(),
+                })
+                .collect::<Vec<String>>();
+            Ok(format!("[{}]", elements.join(", ")))
+        }
+        [Token::Str(s)] if idl_type.base != "string" => Ok(s.clone()),
+        tokens => Ok(tokens.iter().map(Token::text).collect()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_ros_idl_file, ParsedIdlFile};
+    use crate::utils::{Package, RosVersion};
+
+    fn test_pkg() -> Package {
+        Package {
+            name: "test_msgs".to_string(),
+            path: "./not_a_path".into(),
+            version: None,
+        }
+    }
+
+    #[test_log::test]
+    fn parse_idl_message() {
+        let idl = r#"
+// generated from rosidl_adapter/resource/msg.idl.em
+#include "geometry_msgs/msg/Point.idl"
+
+module test_msgs {
+  module msg {
+    typedef double double__9[9];
+    module Example_Constants {
+      const uint8 MODE_A = 1;
+      const string NAME = "example";
+    };
+    @verbatim (language="comment", text=
+      "An example message" "\n"
+      "spanning lines")
+    struct Example {
+      @default (value=TRUE)
+      boolean enabled;
+      @default (value=-1.5e3)
+      double scale;
+      @default (value="hello")
+      string<10> name;
+      @default (value="(1, 2, 3)")
+      sequence<int32, 3> small;
+      @default (value="('a', 'b')")
+      sequence<string> names;
+      double__9 covariance;
+      geometry_msgs::msg::Point points[2];
+      unsigned long long count, total; /* multiple declarators */
+    };
+  };
+};
+"#;
+        let parsed =
+            parse_ros_idl_file(idl, "Example", &test_pkg(), "./Example.idl".as_ref()).unwrap();
+        let ParsedIdlFile::Message(msg) = parsed else {
+            panic!("Expected a message, got {parsed:?}");
+        };
+        assert_eq!(msg.version, Some(RosVersion::ROS2));
+        assert_eq!(
+            msg.source,
+            r#"uint8 MODE_A=1
+string NAME="example"
+bool enabled true
+float64 scale -1.5e3
+string<=10 name "hello"
+int32[<=3] small [1, 2, 3]
+string[] names ["a", "b"]
+float64[9] covariance
+geometry_msgs/Point[2] points
+uint64 count
+uint64 total
+"#
+        );
+        assert_eq!(msg.constants.len(), 2);
+        assert_eq!(msg.fields.len(), 9);
+        assert_eq!(msg.fields[2].field_type.string_bound, Some(10));
+        assert_eq!(msg.fields[3].field_type.array_bound, Some(3));
+        assert_eq!(msg.fields[5].field_type.array_info, Some(Some(9)));
+        assert_eq!(
+            msg.fields[6].field_type.package_name.as_deref(),
+            Some("geometry_msgs")
+        );
+    }
+
+    #[test_log::test]
+    fn parse_idl_service_and_action() {
+        let idl = r#"
+module test_msgs {
+  module srv {
+    struct AddTwoInts_Request {
+      int64 a;
+      int64 b;
+    };
+    struct AddTwoInts_Response {
+      int64 sum;
+    };
+  };
+};
+"#;
+        let parsed =
+            parse_ros_idl_file(idl, "AddTwoInts", &test_pkg(), "./AddTwoInts.idl".as_ref())
+                .unwrap();
+        let ParsedIdlFile::Service(srv) = parsed else {
+            panic!("Expected a service, got {parsed:?}");
+        };
+        assert_eq!(srv.request_type.name, "AddTwoIntsRequest");
+        assert_eq!(srv.request_type.fields.len(), 2);
+        assert_eq!(srv.response_type.name, "AddTwoIntsResponse");
+
+        let idl = r#"
+module test_msgs {
+  module action {
+    struct Fibonacci_Goal {
+      int32 order;
+    };
+    struct Fibonacci_Result {
+      sequence<int32> sequence;
+    };
+    struct Fibonacci_Feedback {
+      uint8 structure_needs_at_least_one_member;
+    };
+  };
+};
+"#;
+        let parsed =
+            parse_ros_idl_file(idl, "Fibonacci", &test_pkg(), "./Fibonacci.idl".as_ref()).unwrap();
+        let ParsedIdlFile::Action(action) = parsed else {
+            panic!("Expected an action, got {parsed:?}");
+        };
+        assert_eq!(action.goal_type.fields.len(), 1);
+        assert_eq!(action.result_type.fields.len(), 1);
+        assert!(action.feedback_type.fields.is_empty());
+    }
+
+    #[test_log::test]
+    fn parse_idl_rejects_unsupported() {
+        let pkg = test_pkg();
+        let enum_idl = "module test_msgs { module msg { enum Color { RED, GREEN }; }; };";
+        assert!(parse_ros_idl_file(enum_idl, "Color", &pkg, "./Color.idl".as_ref()).is_err());
+        let nested = "module test_msgs { module msg { struct Nested { sequence<sequence<int32>> data; }; }; };";
+        assert!(parse_ros_idl_file(nested, "Nested", &pkg, "./Nested.idl".as_ref()).is_err());
+        let missing = "module test_msgs { module msg { struct Other { int32 data; }; }; };";
+        assert!(parse_ros_idl_file(missing, "Missing", &pkg, "./Missing.idl".as_ref()).is_err());
+    }
+}
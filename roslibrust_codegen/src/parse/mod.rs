@@ -4,7 +4,9 @@ use crate::{ConstantInfo, FieldInfo, FieldType};
 use std::collections::HashMap;
 
 mod action;
-pub use action::{parse_ros_action_file, ParsedActionFile};
+pub use action::{parse_ros_action_file, ActionWrappers, ParsedActionFile};
+mod idl;
+pub use idl::{parse_ros_idl_file, ParsedIdlFile};
 mod msg;
 pub use msg::{parse_ros_message_file, ParsedMessageFile};
 mod srv;
@@ -105,6 +107,7 @@ fn parse_field(line: &str, pkg: &Package, msg_name: &str) -> Result<FieldInfo, E
         field_type,
         field_name: field_name.to_string(),
         default,
+        comment: None,
     })
 }
 
@@ -128,6 +131,8 @@ fn parse_constant_field(line: &str, pkg: &Package) -> Result<ConstantInfo, Error
         constant_type,
         constant_name,
         constant_value: constant_value.into(),
+        comment: None,
+        block: 0,
     })
 }
 
@@ -139,6 +144,12 @@ fn strip_comments(line: &str) -> &str {
     line
 }
 
+/// Returns the text of the comment on a line, without the leading #'s and whitespace.
+fn comment_text(line: &str) -> Option<&str> {
+    let token = line.find('#')?;
+    Some(line[token..].trim_start_matches('#').trim())
+}
+
 //TODO it is a little scary that this function appears infallible?
 fn parse_field_type(type_str: &str, array_info: Option<Option<usize>>, pkg: &Package) -> FieldType {
     let items = type_str.split('/').collect::<Vec<&str>>();
@@ -161,6 +172,8 @@ fn parse_field_type(type_str: &str, array_info: Option<Option<usize>>, pkg: &Pac
             source_package: pkg.name.clone(),
             field_type: items[0].to_string(),
             array_info,
+            array_bound: None,
+            string_bound: None,
         }
     } else {
         // If there is more than one item there is a package redirect
@@ -172,6 +185,8 @@ fn parse_field_type(type_str: &str, array_info: Option<Option<usize>>, pkg: &Pac
                 source_package: pkg.name.clone(),
                 field_type: type_str.to_string(),
                 array_info,
+                array_bound: None,
+                string_bound: None,
             }
         } else {
             FieldType {
@@ -179,47 +194,67 @@ fn parse_field_type(type_str: &str, array_info: Option<Option<usize>>, pkg: &Pac
                 source_package: pkg.name.clone(),
                 field_type: items[1].to_string(),
                 array_info,
+                array_bound: None,
+                string_bound: None,
             }
         }
     }
 }
 
 /// Determines the type of a field
-/// `type_str` -- Expects the part of the line containing all type information (up to the first space), e.g. "int32[3>=]"
+/// `type_str` -- Expects the part of the line containing all type information (up to the first space), e.g. "int32[<=3]"
 /// `pkg` -- Reference to package this type is within, used for version information and determining relative types
 fn parse_type(type_str: &str, pkg: &Package) -> Result<FieldType, Error> {
     // Handle array logic
     let open_bracket_idx = type_str.find('[');
     let close_bracket_idx = type_str.find(']');
-    match (open_bracket_idx, close_bracket_idx) {
-        (Some(o), Some(c)) => {
-            // After having stripped array information, parse the remainder of the type
-            let array_size = if c - o == 1 {
+    let (base_type, array_info, array_bound) = match (open_bracket_idx, close_bracket_idx) {
+        (Some(o), Some(c)) if o < c => {
+            let size_str = type_str[(o + 1)..c].trim();
+            if size_str.is_empty() {
                 // No size specified
-                None
+                (&type_str[..o], Some(None), None)
+            } else if let Some(bound_str) = size_str.strip_prefix("<=") {
+                // Bounded sequence, ROS2 only
+                (
+                    &type_str[..o],
+                    Some(None),
+                    Some(parse_size(bound_str, type_str)?),
+                )
             } else {
-                let fixed_size_str = &type_str[(o + 1)..c];
-                let fixed_size = fixed_size_str.parse::<usize>().map_err(|err| {
-                    Error::new(format!(
-                        "Unable to parse size of the array: {type_str}, defaulting to 0: {err}"
-                    ))
-                });
-                // TODO we don't currently handle "array limits" in ROS2, so for now we're ejecting this error
-                // To make this function complete we need to handle clauses like '<=3'
-                // None of this really matters at current time, because we don't generate fixed size array types yet anyway
-                let fixed_size = fixed_size.unwrap_or(0);
-                Some(fixed_size)
-            };
-            Ok(parse_field_type(&type_str[..o], Some(array_size), pkg))
+                (
+                    &type_str[..o],
+                    Some(Some(parse_size(size_str, type_str)?)),
+                    None,
+                )
+            }
         }
         (None, None) => {
             // Not an array parse normally
-            Ok(parse_field_type(type_str, None, pkg))
+            (type_str, None, None)
         }
         _ => {
             bail!("Found malformed type: {type_str} in package {pkg:?}. Likely file is invalid.");
         }
-    }
+    };
+    // Handle bounded strings e.g. "string<=10", ROS2 only
+    let (base_type, string_bound) = match base_type.split_once("<=") {
+        Some((base_type, bound_str)) => (base_type, Some(parse_size(bound_str, type_str)?)),
+        None => (base_type, None),
+    };
+    let mut field_type = parse_field_type(base_type, array_info, pkg);
+    field_type.array_bound = array_bound;
+    field_type.string_bound = string_bound;
+    Ok(field_type)
+}
+
+/// Parses the size of an array or bound of a sequence / string
+fn parse_size(size_str: &str, type_str: &str) -> Result<usize, Error> {
+    size_str.trim().parse::<usize>().map_err(|err| {
+        Error::new(format!(
+            "Unable to parse size {size_str:?} in type: {type_str}: {err}"
+        ))
+    })
 }
 
 #[cfg(test)]
@@ -241,4 +276,89 @@ mod test {
         let parsed = parse_type(line, &pkg).unwrap();
         assert_eq!(parsed.array_info, Some(Some(9)));
     }
+
+    #[test_log::test]
+    fn parse_type_handles_bounds() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS2),
+        };
+        let parsed = parse_type("int32[<=3]", &pkg).unwrap();
+        assert_eq!(parsed.array_info, Some(None));
+        assert_eq!(parsed.array_bound, Some(3));
+        assert_eq!(parsed.to_string(), "int32[<=3]");
+
+        let parsed = parse_type("string<=10[2]", &pkg).unwrap();
+        assert_eq!(parsed.field_type, "string");
+        assert_eq!(parsed.package_name, None);
+        assert_eq!(parsed.string_bound, Some(10));
+        assert_eq!(parsed.array_info, Some(Some(2)));
+        assert_eq!(parsed.to_string(), "string<=10[2]");
+
+        assert!(parse_type("int32[<=x]", &pkg).is_err());
+    }
+
+    #[test_log::test]
+    fn parse_message_comments() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let data = r#"
+# A battery reading
+#
+## with details
+
+# Voltage in volts
+float32 voltage # measured at the terminals
+float32 current
+uint8 FULL=1 # The battery is full
+# A detached comment
+
+uint8 level
+"#;
+        let parsed =
+            crate::parse_ros_message_file(data, "Battery", &pkg, "./Battery.msg".as_ref()).unwrap();
+        assert_eq!(
+            parsed.comment.as_deref(),
+            Some("A battery reading\n\nwith details")
+        );
+        let comments = parsed
+            .fields
+            .iter()
+            .map(|field| field.comment.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            comments,
+            vec![
+                Some("Voltage in volts\nmeasured at the terminals"),
+                None,
+                None
+            ]
+        );
+        assert_eq!(
+            parsed.constants[0].comment.as_deref(),
+            Some("The battery is full")
+        );
+    }
+
+    #[test_log::test]
+    fn parse_constant_blocks() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS2),
+        };
+        let data = "uint8 A=0\n# heading\nuint8 B=1\n\nuint8 C=2\nuint8 x\nuint8 D=3\nuint8 E=4";
+        let parsed =
+            crate::parse_ros_message_file(data, "Blocks", &pkg, "./Blocks.msg".as_ref()).unwrap();
+        let blocks = parsed
+            .constants
+            .iter()
+            .map(|constant| constant.block)
+            .collect::<Vec<_>>();
+        assert_eq!(blocks, vec![0, 0, 1, 2, 2]);
+    }
 }
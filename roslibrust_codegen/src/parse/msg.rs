@@ -1,4 +1,4 @@
-use crate::parse::{parse_constant_field, parse_field, strip_comments};
+use crate::parse::{comment_text, parse_constant_field, parse_field, strip_comments};
 use crate::Error;
 use crate::{ConstantInfo, FieldInfo, Package, RosVersion};
 use std::path::{Path, PathBuf};
@@ -11,6 +11,8 @@ pub struct ParsedMessageFile {
     pub fields: Vec<FieldInfo>,
     pub constants: Vec<ConstantInfo>,
     pub version: Option<RosVersion>,
+    /// The comment block at the top of the message file, separated from the first field by an empty line
+    pub comment: Option<String>,
     /// The contents of the message file this instance was parsed from
     pub source: String,
     /// The path where the message was found
@@ -44,13 +46,32 @@ pub fn parse_ros_message_file(
 ) -> Result<ParsedMessageFile, Error> {
     let mut fields = vec![];
     let mut constants = vec![];
+    let mut comment = None;
+    // Comment lines since the last empty line or member, which document the next member
+    let mut pending_comment = vec![];
+    let mut block = 0;
+    // Whether an empty line or field was seen since the last constant, starting a new block of constants
+    let mut ends_block = false;
 
-    for line in data.lines() {
-        let line = strip_comments(line).trim();
+    for full_line in data.lines() {
+        let line = strip_comments(full_line).trim();
         if line.is_empty() {
-            // Comment only line skip
+            match comment_text(full_line) {
+                Some(text) => pending_comment.push(text),
+                None => {
+                    ends_block = true;
+                    // An empty line ends the comment block, the first block in the file documents the message
+                    if fields.is_empty() && constants.is_empty() && comment.is_none() {
+                        comment = join_comment(&pending_comment);
+                    }
+                    pending_comment.clear();
+                }
+            }
             continue;
         }
+        pending_comment.extend(comment_text(full_line));
+        let member_comment = join_comment(&pending_comment);
+        pending_comment.clear();
         // Determine if we're looking at a constant or a field
         let sep = line.find(' ').ok_or(
             Error::new(
@@ -61,19 +82,43 @@ pub fn parse_ros_message_file(
         let equal_after_sep = line[sep..].find('=');
         if equal_after_sep.is_some() {
             // Since we found an equal sign after a space, this must be a constant
-            constants.push(parse_constant_field(line, package)?)
+            if ends_block && !constants.is_empty() {
+                block += 1;
+            }
+            ends_block = false;
+            constants.push(ConstantInfo {
+                comment: member_comment,
+                block,
+                ..parse_constant_field(line, package)?
+            })
         } else {
             // Is regular field
-            fields.push(parse_field(line, package, name)?);
+            ends_block = true;
+            fields.push(FieldInfo {
+                comment: member_comment,
+                ..parse_field(line, package, name)?
+            });
         }
     }
+    // A message that is only a comment is documented by it
+    if fields.is_empty() && constants.is_empty() && comment.is_none() {
+        comment = join_comment(&pending_comment);
+    }
     Ok(ParsedMessageFile {
         fields,
         constants,
         name: name.to_owned(),
         package: package.name.clone(),
         version: package.version,
+        comment,
         source: data.to_owned(),
         path: path.to_owned(),
     })
 }
+
+/// Joins the lines of a comment block, returning None if it has no text
+fn join_comment(lines: &[&str]) -> Option<String> {
+    let start = lines.iter().position(|line| !line.is_empty())?;
+    let end = lines.iter().rposition(|line| !line.is_empty())?;
+    Some(lines[start..=end].join("\n"))
+}
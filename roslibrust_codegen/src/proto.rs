@@ -0,0 +1,315 @@
+//! Generates protobuf definitions mirroring ROS messages, see [generate_proto_files], and the conversions between
+//! our generated types and the types prost generates from them, see [crate::CodegenOptions::proto_conversions].
+//!
+//! Each ROS package becomes a `ros.{package}` protobuf package, with one message per ROS message and service
+//! request and response, and a protobuf service with a single `Call` rpc per ROS service. Fields keep their
+//! ROS names and are numbered in the order they're declared. As protobuf has no smaller integers, 8 and 16 bit
+//! integers are widened to 32 bits, except for `uint8` arrays which become `bytes`. Time and duration fields
+//! refer to messages in the shared `ros.roslibrust` package.
+
+use crate::gen::{pascal_case, snake_case};
+use crate::{bail, Error, FieldInfo, MessageFile, ServiceFile};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Name of the file, and last part of the package, holding the time and duration messages
+const BUILTIN: &str = "roslibrust";
+
+const BUILTIN_PROTO: &str = r#"// Generated by roslibrust_codegen, do not edit
+syntax = "proto3";
+
+package ros.roslibrust;
+
+message Time {
+  int32 secs = 1;
+  int32 nsecs = 2;
+}
+
+message Duration {
+  int32 sec = 1;
+  int32 nsec = 2;
+}
+"#;
+
+/// How a field is represented in protobuf, which determines how it's converted
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Time,
+    Duration,
+    Message,
+    /// `uint8` arrays, which are `bytes` in protobuf
+    Bytes,
+    /// Integers smaller than 32 bits
+    Widened,
+    /// Everything else has the same type in both
+    Same,
+}
+
+impl Kind {
+    fn of(field: &FieldInfo) -> Kind {
+        let field_type = &field.field_type;
+        if field_type.package_name.is_some() {
+            return Kind::Message;
+        }
+        match field_type.field_type.as_str() {
+            "time" | "builtin_interfaces/Time" => Kind::Time,
+            "duration" | "builtin_interfaces/Duration" => Kind::Duration,
+            "uint8" | "byte" | "char" if field_type.array_info.is_some() => Kind::Bytes,
+            "int8" | "uint8" | "byte" | "char" | "int16" | "uint16" => Kind::Widened,
+            _ => Kind::Same,
+        }
+    }
+}
+
+/// Returns the protobuf type of a field, without `repeated`
+fn proto_type(field: &FieldInfo) -> Result<String, Error> {
+    let field_type = &field.field_type;
+    if let Some(package) = &field_type.package_name {
+        return Ok(format!(".ros.{package}.{}", field_type.field_type));
+    }
+    Ok(match field_type.field_type.as_str() {
+        "time" | "builtin_interfaces/Time" => format!(".ros.{BUILTIN}.Time"),
+        "duration" | "builtin_interfaces/Duration" => format!(".ros.{BUILTIN}.Duration"),
+        "bool" => "bool".to_owned(),
+        "int8" | "int16" | "int32" => "int32".to_owned(),
+        "uint8" | "byte" | "char" | "uint16" | "uint32" => "uint32".to_owned(),
+        "int64" => "int64".to_owned(),
+        "uint64" => "uint64".to_owned(),
+        "float32" => "float".to_owned(),
+        "float64" => "double".to_owned(),
+        "string" => "string".to_owned(),
+        other => bail!("No protobuf type for {other}"),
+    })
+}
+
+/// Appends the protobuf definition of a message to `proto`, recording the packages it refers to in `imports`
+fn write_message(
+    proto: &mut String,
+    msg: &MessageFile,
+    imports: &mut BTreeSet<String>,
+) -> Result<(), Error> {
+    writeln!(proto, "\nmessage {} {{", msg.parsed.name).unwrap();
+    for (i, field) in msg.parsed.fields.iter().enumerate() {
+        let field_type = &field.field_type;
+        match (&field_type.package_name, Kind::of(field)) {
+            (Some(package), _) => imports.insert(package.clone()),
+            (None, Kind::Time | Kind::Duration) => imports.insert(BUILTIN.to_owned()),
+            _ => false,
+        };
+        let proto_type = match (Kind::of(field), field_type.array_info) {
+            (Kind::Bytes, _) => "bytes".to_owned(),
+            (_, Some(_)) => format!("repeated {}", proto_type(field)?),
+            (_, None) => proto_type(field)?,
+        };
+        writeln!(proto, "  {proto_type} {} = {};", field.field_name, i + 1).unwrap();
+    }
+    writeln!(proto, "}}").unwrap();
+    Ok(())
+}
+
+/// Generates `.proto` files mirroring the given messages and services, so robot data can be forwarded to
+/// gRPC or other protobuf based systems. Returns the contents of each file keyed by its name without extension,
+/// one per package plus `roslibrust` holding the time and duration messages, see [write_proto_files].
+///
+/// Compiling the files with prost and enabling [crate::CodegenOptions::proto_conversions] provides `From`
+/// conversions between the two sets of types.
+pub fn generate_proto_files(
+    messages: &[MessageFile],
+    services: &[ServiceFile],
+) -> Result<BTreeMap<String, String>, Error> {
+    let mut packages: BTreeMap<&str, (Vec<&MessageFile>, Vec<&ServiceFile>)> = BTreeMap::new();
+    for msg in messages {
+        packages.entry(&msg.parsed.package).or_default().0.push(msg);
+    }
+    for srv in services {
+        packages.entry(&srv.parsed.package).or_default().1.push(srv);
+    }
+
+    let mut files = BTreeMap::new();
+    files.insert(BUILTIN.to_owned(), BUILTIN_PROTO.to_owned());
+    for (package, (mut messages, mut services)) in packages {
+        if package == BUILTIN {
+            bail!("A package named {BUILTIN} can't be generated as protobuf, as the name is used for the time and duration messages");
+        }
+        messages.sort_by(|a, b| a.parsed.name.cmp(&b.parsed.name));
+        services.sort_by(|a, b| a.parsed.name.cmp(&b.parsed.name));
+
+        let mut body = String::new();
+        let mut imports = BTreeSet::new();
+        for msg in messages {
+            write_message(&mut body, msg, &mut imports)?;
+        }
+        for srv in services {
+            write_message(&mut body, &srv.request, &mut imports)?;
+            write_message(&mut body, &srv.response, &mut imports)?;
+            writeln!(
+                body,
+                "\nservice {} {{\n  rpc Call({}) returns ({});\n}}",
+                srv.parsed.name, srv.request.parsed.name, srv.response.parsed.name
+            )
+            .unwrap();
+        }
+        imports.remove(package);
+
+        let mut proto = String::from("// Generated by roslibrust_codegen, do not edit\n");
+        writeln!(proto, "syntax = \"proto3\";\n\npackage ros.{package};").unwrap();
+        if !imports.is_empty() {
+            proto.push('\n');
+        }
+        for import in imports {
+            writeln!(proto, "import \"{import}.proto\";").unwrap();
+        }
+        proto.push_str(&body);
+        files.insert(package.to_owned(), proto);
+    }
+    Ok(files)
+}
+
+/// Writes the files returned by [generate_proto_files] to `dir` as `{name}.proto`.
+pub fn write_proto_files(
+    dir: impl AsRef<Path>,
+    files: BTreeMap<String, String>,
+) -> Result<(), Error> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir).map_err(|e| {
+        Error::with(
+            format!("Failed to create directory {dir:?} for protobuf files").as_str(),
+            e,
+        )
+    })?;
+    for (name, contents) in files {
+        let path = dir.join(format!("{name}.proto"));
+        std::fs::write(&path, contents).map_err(|e| {
+            Error::with(
+                format!("Failed to write protobuf file {path:?}").as_str(),
+                e,
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Returns the identifier prost uses for `name`, escaping keywords the way it does
+fn prost_ident(name: &str) -> syn::Ident {
+    match name {
+        // Keywords which can't be raw identifiers are suffixed instead
+        "self" | "super" | "crate" | "Self" => format_ident!("{name}_"),
+        _ if syn::parse_str::<syn::Ident>(name).is_ok() => format_ident!("{name}"),
+        _ => format_ident!("r#{name}"),
+    }
+}
+
+/// Generates `From` conversions in both directions between a message and the type prost generates for it
+/// from the files of [generate_proto_files], found under `module`
+pub(crate) fn generate_proto_conversions(
+    msg: &MessageFile,
+    module: &str,
+) -> Result<TokenStream, Error> {
+    let struct_name = format_ident!("{}", msg.parsed.name);
+    let parse = |path: String| {
+        TokenStream::from_str(&path)
+            .map_err(|e| Error::with(format!("Invalid protobuf module {module}").as_str(), e))
+    };
+    let proto_type = parse(format!(
+        "{module}::{}::{}",
+        msg.parsed.package,
+        pascal_case(&snake_case(&msg.parsed.name))
+    ))?;
+    let builtin = parse(format!("{module}::{BUILTIN}"))?;
+    let ours = quote! { ::roslibrust::codegen::integral_types };
+    let collect = quote! { ::roslibrust::codegen::conversions::collect };
+
+    let mut to_proto = vec![];
+    let mut from_proto = vec![];
+    for field in &msg.parsed.fields {
+        let field_name = format_ident!("r#{}", field.field_name);
+        let proto_name = prost_ident(&snake_case(&field.field_name));
+        let is_array = field.field_type.array_info.is_some();
+        let element = quote! { element };
+
+        let (to, from) = match Kind::of(field) {
+            kind @ (Kind::Time | Kind::Duration) => {
+                let (name, secs, nsecs) = match kind {
+                    Kind::Time => (quote! { Time }, quote! { secs }, quote! { nsecs }),
+                    _ => (quote! { Duration }, quote! { sec }, quote! { nsec }),
+                };
+                let to_element =
+                    quote! { #builtin::#name { #secs: #element.#secs, #nsecs: #element.#nsecs } };
+                let from_element =
+                    quote! { #ours::#name { #secs: #element.#secs, #nsecs: #element.#nsecs } };
+                if is_array {
+                    (
+                        quote! { value.#field_name.into_iter().map(|#element| #to_element).collect() },
+                        quote! { #collect(value.#proto_name.into_iter().map(|#element| #from_element)) },
+                    )
+                } else {
+                    (
+                        quote! { ::core::option::Option::Some({ let #element = value.#field_name; #to_element }) },
+                        quote! { { let #element = value.#proto_name.unwrap_or_default(); #from_element } },
+                    )
+                }
+            }
+            Kind::Message if is_array => (
+                quote! { value.#field_name.into_iter().map(::core::convert::Into::into).collect() },
+                quote! { #collect(value.#proto_name.into_iter().map(::core::convert::Into::into)) },
+            ),
+            Kind::Message => (
+                quote! { ::core::option::Option::Some(::core::convert::Into::into(value.#field_name)) },
+                quote! { value.#proto_name.map(::core::convert::Into::into).unwrap_or_default() },
+            ),
+            Kind::Widened => {
+                let rust_type = match field.field_type.field_type.as_str() {
+                    "int8" => quote! { i8 },
+                    "int16" => quote! { i16 },
+                    "uint16" => quote! { u16 },
+                    _ => quote! { u8 },
+                };
+                // Values too large for the ROS field are truncated
+                if is_array {
+                    (
+                        quote! { value.#field_name.into_iter().map(::core::convert::Into::into).collect() },
+                        quote! { #collect(value.#proto_name.into_iter().map(|#element| #element as #rust_type)) },
+                    )
+                } else {
+                    (
+                        quote! { ::core::convert::Into::into(value.#field_name) },
+                        quote! { value.#proto_name as #rust_type },
+                    )
+                }
+            }
+            Kind::Bytes | Kind::Same if is_array => (
+                quote! { #collect(value.#field_name) },
+                quote! { #collect(value.#proto_name) },
+            ),
+            Kind::Bytes | Kind::Same => {
+                (quote! { value.#field_name }, quote! { value.#proto_name })
+            }
+        };
+        to_proto.push(quote! { #proto_name: #to });
+        from_proto.push(quote! { #field_name: #from });
+    }
+
+    Ok(quote! {
+        impl ::core::convert::From<#struct_name> for #proto_type {
+            #[allow(unused_variables)]
+            fn from(value: #struct_name) -> Self {
+                Self {
+                    #(#to_proto, )*
+                }
+            }
+        }
+
+        impl ::core::convert::From<#proto_type> for #struct_name {
+            #[allow(unused_variables)]
+            fn from(value: #proto_type) -> Self {
+                Self {
+                    #(#from_proto, )*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,230 @@
+//! Serde helpers used by generated code when [crate::TimeType] maps `time` and `duration` fields to std or chrono types.
+//!
+//! The fields are still sent as the ROS [Time] and [Duration] structs. These helpers are applied with
+//! `#[serde(with = ...)]` and convert to and from the ROS types while serializing and deserializing, a value which
+//! can't be represented by the other type (e.g. a negative duration as a [std::time::Duration]) fails to
+//! serialize or deserialize.
+//!
+//! ```
+//! use roslibrust_codegen::RosTimeRepr;
+//! let time = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(1500);
+//! let ros_time = time.to_ros().unwrap();
+//! assert_eq!((ros_time.secs, ros_time.nsecs), (1, 500_000_000));
+//! ```
+
+use crate::{Duration, Time};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use simple_error::SimpleError;
+
+/// A Rust time type which is sent as one of the ROS time types.
+pub trait RosTimeRepr: Sized {
+    /// The ROS type the value is sent as, [Time] or [Duration]
+    type Ros: Serialize + for<'de> Deserialize<'de>;
+
+    fn to_ros(&self) -> Result<Self::Ros, SimpleError>;
+
+    fn from_ros(ros: Self::Ros) -> Result<Self, SimpleError>;
+}
+
+impl RosTimeRepr for std::time::SystemTime {
+    type Ros = Time;
+
+    fn to_ros(&self) -> Result<Time, SimpleError> {
+        Time::try_from(*self)
+    }
+
+    fn from_ros(ros: Time) -> Result<Self, SimpleError> {
+        ros.try_into()
+    }
+}
+
+impl RosTimeRepr for std::time::Duration {
+    type Ros = Duration;
+
+    fn to_ros(&self) -> Result<Duration, SimpleError> {
+        Duration::try_from(*self)
+    }
+
+    fn from_ros(ros: Duration) -> Result<Self, SimpleError> {
+        ros.try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl RosTimeRepr for chrono::DateTime<chrono::Utc> {
+    type Ros = Time;
+
+    fn to_ros(&self) -> Result<Time, SimpleError> {
+        Time::try_from(*self)
+    }
+
+    fn from_ros(ros: Time) -> Result<Self, SimpleError> {
+        ros.try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl RosTimeRepr for chrono::Duration {
+    type Ros = Duration;
+
+    fn to_ros(&self) -> Result<Duration, SimpleError> {
+        Duration::try_from(*self)
+    }
+
+    fn from_ros(ros: Duration) -> Result<Self, SimpleError> {
+        ros.try_into()
+    }
+}
+
+fn to_ros_vec<T: RosTimeRepr>(values: &[T]) -> Result<Vec<T::Ros>, SimpleError> {
+    values.iter().map(T::to_ros).collect()
+}
+
+fn from_ros_vec<T: RosTimeRepr>(values: Vec<T::Ros>) -> Result<Vec<T>, SimpleError> {
+    values.into_iter().map(T::from_ros).collect()
+}
+
+/// Converts a single time field.
+pub struct AsRosTime;
+
+impl AsRosTime {
+    pub fn serialize<T: RosTimeRepr, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value
+            .to_ros()
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: RosTimeRepr, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::from_ros(T::Ros::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Converts a `time[]` field.
+pub struct AsRosTimeSeq;
+
+impl AsRosTimeSeq {
+    pub fn serialize<T: RosTimeRepr, S: Serializer>(
+        values: &[T],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        to_ros_vec(values)
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: RosTimeRepr, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        from_ros_vec(Vec::<T::Ros>::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Converts a `time[N]` field, which is sent as a tuple like other fixed size arrays.
+pub struct AsRosTimeArray;
+
+impl AsRosTimeArray {
+    pub fn serialize<T: RosTimeRepr, S: Serializer, const N: usize>(
+        values: &[T; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use ser::SerializeTuple;
+        let values = to_ros_vec(values).map_err(ser::Error::custom)?;
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for value in &values {
+            tuple.serialize_element(value)?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, T: RosTimeRepr, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[T; N], D::Error> {
+        struct ArrayVisitor<R, const N: usize>(std::marker::PhantomData<R>);
+
+        impl<'de, R: Deserialize<'de>, const N: usize> de::Visitor<'de> for ArrayVisitor<R, N> {
+            type Value = Vec<R>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(formatter, "an array of length {N}")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<R>, A::Error> {
+                let mut values = Vec::with_capacity(N);
+                for i in 0..N {
+                    values.push(
+                        seq.next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(i, &self))?,
+                    );
+                }
+                Ok(values)
+            }
+        }
+
+        let values = deserializer
+            .deserialize_tuple(N, ArrayVisitor::<T::Ros, N>(std::marker::PhantomData))?;
+        let values = from_ros_vec(values).map_err(de::Error::custom)?;
+        // The visitor always produces exactly N values
+        values
+            .try_into()
+            .map_err(|_| de::Error::custom("array length mismatch"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Stamped {
+        #[serde(with = "AsRosTime")]
+        stamp: std::time::SystemTime,
+        #[serde(with = "AsRosTimeSeq")]
+        timeouts: Vec<std::time::Duration>,
+        #[serde(with = "AsRosTimeArray")]
+        window: [std::time::Duration; 2],
+    }
+
+    #[test_log::test]
+    fn sent_as_ros_types() {
+        let stamped = Stamped {
+            stamp: std::time::SystemTime::UNIX_EPOCH + std::time::Duration::new(5, 10),
+            timeouts: vec![std::time::Duration::from_secs(1)],
+            window: [
+                std::time::Duration::from_millis(1),
+                std::time::Duration::from_millis(2),
+            ],
+        };
+        let json = serde_json::to_value(&stamped).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "stamp": {"secs": 5, "nsecs": 10},
+                "timeouts": [{"sec": 1, "nsec": 0}],
+                "window": [{"sec": 0, "nsec": 1_000_000}, {"sec": 0, "nsec": 2_000_000}],
+            })
+        );
+        assert_eq!(serde_json::from_value::<Stamped>(json).unwrap(), stamped);
+
+        // Negative durations can't be represented by std
+        let negative = serde_json::json!({
+            "stamp": {"secs": 5, "nsecs": 10},
+            "timeouts": [{"sec": -1, "nsec": 0}],
+            "window": [{"sec": 0, "nsec": 0}, {"sec": 0, "nsec": 0}],
+        });
+        assert!(serde_json::from_value::<Stamped>(negative).is_err());
+
+        // Fixed size arrays must have the right length
+        let short = serde_json::json!({
+            "stamp": {"secs": 5, "nsecs": 10},
+            "timeouts": [],
+            "window": [{"sec": 0, "nsec": 0}],
+        });
+        assert!(serde_json::from_value::<Stamped>(short).is_err());
+    }
+}
@@ -0,0 +1,377 @@
+//! Computes the type hashes ROS2 uses to check that both ends of a connection agree on a type.
+//!
+//! Since Iron, rosidl generates a `RIHS01_` prefixed sha256 of a JSON "type description" for every message and
+//! service, listing its fields and the fields of every type it references. This reproduces that computation,
+//! see REP 2011 and `rosidl_generator_type_description` for the reference implementation.
+
+use crate::parse::{ParsedMessageFile, ParsedServiceFile};
+use crate::utils::RosVersion;
+use crate::{FieldInfo, MessageFile};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+// Field type ids from type_description_interfaces/msg/FieldType
+const NESTED_TYPE: u8 = 1;
+const UINT8: u8 = 3;
+const INT32: u8 = 6;
+const UINT32: u8 = 7;
+const INT64: u8 = 8;
+const STRING: u8 = 17;
+const BOUNDED_STRING: u8 = 21;
+const ARRAY_OFFSET: u8 = 48;
+const BOUNDED_SEQUENCE_OFFSET: u8 = 96;
+const UNBOUNDED_SEQUENCE_OFFSET: u8 = 144;
+
+/// The fields of a single type in a type description
+#[derive(Clone, Debug)]
+struct Field {
+    name: String,
+    type_id: u8,
+    capacity: usize,
+    string_capacity: usize,
+    nested_type_name: String,
+}
+
+impl Field {
+    fn new(name: &str, type_id: u8) -> Self {
+        Field {
+            name: name.to_owned(),
+            type_id,
+            capacity: 0,
+            string_capacity: 0,
+            nested_type_name: String::new(),
+        }
+    }
+
+    fn nested(name: &str, type_name: String) -> Self {
+        Field {
+            nested_type_name: type_name,
+            ..Field::new(name, NESTED_TYPE)
+        }
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"name": "{}", "type": {{"type_id": {}, "capacity": {}, "string_capacity": {}, "nested_type_name": "{}"}}}}"#,
+            self.name, self.type_id, self.capacity, self.string_capacity, self.nested_type_name
+        )
+    }
+}
+
+/// A type being described, and all of the types it references keyed by their full name
+#[derive(Default)]
+struct TypeDescription {
+    type_name: String,
+    fields: Vec<Field>,
+    referenced: BTreeMap<String, Vec<Field>>,
+}
+
+impl TypeDescription {
+    /// Returns the `RIHS01_` hash of the description
+    fn hash(&self) -> String {
+        let referenced = self
+            .referenced
+            .iter()
+            .map(|(type_name, fields)| individual_json(type_name, fields))
+            .collect::<Vec<_>>()
+            .join(", ");
+        // Matches python's json.dumps with separators=(', ', ': '), which is what rosidl hashes
+        let json = format!(
+            r#"{{"type_description": {}, "referenced_type_descriptions": [{referenced}]}}"#,
+            individual_json(&self.type_name, &self.fields)
+        );
+        let hash = Sha256::digest(json.as_bytes());
+        let hex = hash.iter().map(|b| format!("{b:02x}")).collect::<String>();
+        format!("RIHS01_{hex}")
+    }
+}
+
+fn individual_json(type_name: &str, fields: &[Field]) -> String {
+    let fields = fields
+        .iter()
+        .map(Field::to_json)
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(r#"{{"type_name": "{type_name}", "fields": [{fields}]}}"#)
+}
+
+/// Computes the type hash of a ROS2 message, returns an empty string for ROS1 messages
+pub(crate) fn message_type_hash(
+    parsed: &ParsedMessageFile,
+    graph: &BTreeMap<String, MessageFile>,
+) -> Option<String> {
+    if parsed.version != Some(RosVersion::ROS2) {
+        return Some(String::new());
+    }
+    let mut description = TypeDescription {
+        type_name: ros2_type_name(&parsed.package, &parsed.name, &parsed.path),
+        ..Default::default()
+    };
+    description.fields = describe_message(parsed, graph, &mut description.referenced)?;
+    Some(description.hash())
+}
+
+/// Computes the type hash of a ROS2 service, returns an empty string for ROS1 services
+///
+/// The hash covers the request and response, as well as the `{Name}_Event` message rosidl generates for
+/// service introspection which is described here as it is not a real message we generate.
+pub(crate) fn service_type_hash(
+    parsed: &ParsedServiceFile,
+    graph: &BTreeMap<String, MessageFile>,
+) -> Option<String> {
+    if parsed.request_type.version != Some(RosVersion::ROS2) {
+        return Some(String::new());
+    }
+    let type_name = ros2_type_name(&parsed.package, &parsed.name, &parsed.request_type.path);
+    let mut referenced = BTreeMap::new();
+    let mut message_name = |message: &ParsedMessageFile| -> Option<String> {
+        let type_name = ros2_type_name(&message.package, &message.name, &message.path);
+        let fields = describe_message(message, graph, &mut referenced)?;
+        referenced.insert(type_name.clone(), fields);
+        Some(type_name)
+    };
+    let request_name = message_name(&parsed.request_type)?;
+    let response_name = message_name(&parsed.response_type)?;
+
+    let event_name = format!("{type_name}_Event");
+    let bounded_one = |field: Field| Field {
+        type_id: field.type_id + BOUNDED_SEQUENCE_OFFSET,
+        capacity: 1,
+        ..field
+    };
+    referenced.insert(
+        event_name.clone(),
+        vec![
+            Field::nested("info", "service_msgs/msg/ServiceEventInfo".to_owned()),
+            bounded_one(Field::nested("request", request_name.clone())),
+            bounded_one(Field::nested("response", response_name.clone())),
+        ],
+    );
+    referenced.insert(
+        "service_msgs/msg/ServiceEventInfo".to_owned(),
+        vec![
+            Field::new("event_type", UINT8),
+            Field::nested("stamp", "builtin_interfaces/msg/Time".to_owned()),
+            Field {
+                capacity: 16,
+                ..Field::new("client_gid", UINT8 + ARRAY_OFFSET)
+            },
+            Field::new("sequence_number", INT64),
+        ],
+    );
+    referenced.insert("builtin_interfaces/msg/Time".to_owned(), builtin_fields());
+
+    let description = TypeDescription {
+        type_name,
+        fields: vec![
+            Field::nested("request_message", request_name),
+            Field::nested("response_message", response_name),
+            Field::nested("event_message", event_name),
+        ],
+        referenced,
+    };
+    Some(description.hash())
+}
+
+/// Describes the fields of a message, adding every type it references to `referenced`
+fn describe_message(
+    parsed: &ParsedMessageFile,
+    graph: &BTreeMap<String, MessageFile>,
+    referenced: &mut BTreeMap<String, Vec<Field>>,
+) -> Option<Vec<Field>> {
+    if parsed.fields.is_empty() {
+        // rosidl adds a placeholder member to empty structures as they aren't valid in C
+        return Some(vec![Field::new(
+            "structure_needs_at_least_one_member",
+            UINT8,
+        )]);
+    }
+    parsed
+        .fields
+        .iter()
+        .map(|field| describe_field(field, graph, referenced))
+        .collect()
+}
+
+fn describe_field(
+    field: &FieldInfo,
+    graph: &BTreeMap<String, MessageFile>,
+    referenced: &mut BTreeMap<String, Vec<Field>>,
+) -> Option<Field> {
+    let field_type = &field.field_type;
+    let mut description = match (&field_type.package_name, field_type.field_type.as_str()) {
+        (None, builtin @ ("builtin_interfaces/Time" | "builtin_interfaces/Duration")) => {
+            let type_name = builtin.replace('/', "/msg/");
+            referenced.insert(type_name.clone(), builtin_fields());
+            Field::nested(&field.field_name, type_name)
+        }
+        (None, "string") => match field_type.string_bound {
+            Some(bound) => Field {
+                string_capacity: bound,
+                ..Field::new(&field.field_name, BOUNDED_STRING)
+            },
+            None => Field::new(&field.field_name, STRING),
+        },
+        (None, primitive) => Field::new(&field.field_name, primitive_type_id(primitive)?),
+        (Some(_), _) => {
+            let full_name = field.get_full_name();
+            let Some(sub_message) = graph.get(&full_name) else {
+                log::error!(
+                    "Unable to find message type: {full_name:?}, while computing type hash"
+                );
+                return None;
+            };
+            let sub_message = &sub_message.parsed;
+            let type_name =
+                ros2_type_name(&sub_message.package, &sub_message.name, &sub_message.path);
+            if !referenced.contains_key(&type_name) {
+                let fields = describe_message(sub_message, graph, referenced)?;
+                referenced.insert(type_name.clone(), fields);
+            }
+            Field::nested(&field.field_name, type_name)
+        }
+    };
+    match (field_type.array_info, field_type.array_bound) {
+        (Some(Some(size)), _) => {
+            description.type_id += ARRAY_OFFSET;
+            description.capacity = size;
+        }
+        (Some(None), Some(bound)) => {
+            description.type_id += BOUNDED_SEQUENCE_OFFSET;
+            description.capacity = bound;
+        }
+        (Some(None), None) => description.type_id += UNBOUNDED_SEQUENCE_OFFSET,
+        (None, _) => {}
+    }
+    Some(description)
+}
+
+/// Both builtin_interfaces/Time and builtin_interfaces/Duration have the same fields
+fn builtin_fields() -> Vec<Field> {
+    vec![Field::new("sec", INT32), Field::new("nanosec", UINT32)]
+}
+
+fn primitive_type_id(ros_type: &str) -> Option<u8> {
+    Some(match ros_type {
+        "int8" => 2,
+        // char is an alias of uint8 in ROS2 .msg files, unlike IDL's char
+        "uint8" | "char" => UINT8,
+        "int16" => 4,
+        "uint16" => 5,
+        "int32" => INT32,
+        "uint32" => UINT32,
+        "int64" => INT64,
+        "uint64" => 9,
+        "float32" => 10,
+        "float64" => 11,
+        "bool" => 15,
+        "byte" => 16,
+        _ => {
+            log::error!("Unable to compute type hash for unknown primitive type: {ros_type:?}");
+            return None;
+        }
+    })
+}
+
+/// Returns the name rosidl gives a type e.g. "std_msgs/msg/Header".
+///
+/// The interface kind and the rosidl name of types generated from services and actions are recovered
+/// from the file the type was parsed from, e.g. `AddTwoIntsRequest` from `AddTwoInts.srv` is
+/// "example_interfaces/srv/AddTwoInts_Request".
+fn ros2_type_name(package: &str, name: &str, path: &Path) -> String {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("msg");
+    let kind = match extension {
+        "srv" | "action" => extension,
+        // .idl files are placed in the directory of their kind
+        "idl" => path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|p| p.to_str())
+            .filter(|p| matches!(*p, "srv" | "action"))
+            .unwrap_or("msg"),
+        _ => "msg",
+    };
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or(name);
+    let suffix = match name.strip_prefix(stem) {
+        Some(suffix) if kind != "msg" => suffix,
+        _ => return format!("{package}/{kind}/{name}"),
+    };
+    let suffix = match ["Request", "Response"]
+        .into_iter()
+        .find_map(|part| Some((suffix.strip_suffix(part)?, part)))
+    {
+        Some(("", part)) => format!("_{part}"),
+        Some((service, part)) => format!("_{service}_{part}"),
+        None if suffix.is_empty() => String::new(),
+        None => format!("_{suffix}"),
+    };
+    format!("{package}/{kind}/{stem}{suffix}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::Package;
+    use crate::{parse_ros_message_file, resolve_dependency_graph};
+    use std::path::PathBuf;
+
+    #[test_log::test]
+    fn type_names_match_rosidl() {
+        let path = |p: &str| PathBuf::from(format!("/pkg/{p}"));
+        assert_eq!(
+            ros2_type_name("std_msgs", "Header", &path("msg/Header.msg")),
+            "std_msgs/msg/Header"
+        );
+        assert_eq!(
+            ros2_type_name("pkg", "AddTwoIntsRequest", &path("srv/AddTwoInts.srv")),
+            "pkg/srv/AddTwoInts_Request"
+        );
+        assert_eq!(
+            ros2_type_name("pkg", "AddTwoInts", &path("srv/AddTwoInts.srv")),
+            "pkg/srv/AddTwoInts"
+        );
+        assert_eq!(
+            ros2_type_name(
+                "pkg",
+                "FibonacciSendGoalResponse",
+                &path("action/Fibonacci.action")
+            ),
+            "pkg/action/Fibonacci_SendGoal_Response"
+        );
+        assert_eq!(
+            ros2_type_name(
+                "pkg",
+                "FibonacciFeedbackMessage",
+                &path("action/Fibonacci.action")
+            ),
+            "pkg/action/Fibonacci_FeedbackMessage"
+        );
+        assert_eq!(
+            ros2_type_name("pkg", "TriggerRequest", &path("srv/Trigger.idl")),
+            "pkg/srv/Trigger_Request"
+        );
+    }
+
+    #[test_log::test]
+    fn string_hash_matches_rosidl() {
+        let package = Package {
+            name: "std_msgs".to_owned(),
+            path: PathBuf::from("/std_msgs"),
+            version: Some(RosVersion::ROS2),
+        };
+        let parsed = parse_ros_message_file(
+            "string data",
+            "String",
+            &package,
+            &PathBuf::from("/std_msgs/msg/String.msg"),
+        )
+        .unwrap();
+        let (messages, _) = resolve_dependency_graph(vec![parsed], vec![]).unwrap();
+        // Hash generated by rosidl for std_msgs/msg/String
+        assert_eq!(
+            messages[0].type_hash,
+            "RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18"
+        );
+    }
+}
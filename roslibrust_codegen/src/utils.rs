@@ -128,6 +128,7 @@ pub fn get_message_files(pkg: &Package) -> io::Result<Vec<PathBuf>> {
         .into_iter()
         .chain(message_files_from_path(pkg.path.as_path(), "srv")?.into_iter())
         .chain(message_files_from_path(pkg.path.as_path(), "action")?.into_iter())
+        .chain(message_files_from_path(pkg.path.as_path(), "idl")?)
         .collect())
 }
 
@@ -268,6 +269,55 @@ fn parse_ros_package_info(
     }
 }
 
+/// Returns the names of the packages a package depends on according to its package.xml.
+///
+/// Read from the `depend`, `build_depend`, `build_export_depend`, `exec_depend` and ROS1 `run_depend` tags,
+/// build tool and test dependencies are left out. Conditions on dependencies are not evaluated, so a dependency
+/// only applying to another ROS version is still returned.
+pub fn get_package_dependencies(package: &Package) -> io::Result<Vec<String>> {
+    use std::fs::File;
+    use std::io::BufReader;
+    use xml::reader::{EventReader, ParserConfig, XmlEvent};
+    const DEPEND_TAGS: [&str; 5] = [
+        "depend",
+        "build_depend",
+        "build_export_depend",
+        "exec_depend",
+        "run_depend",
+    ];
+
+    let file = File::open(package.path.join("package.xml"))?;
+    let parser = EventReader::new_with_config(
+        BufReader::new(file),
+        ParserConfig {
+            trim_whitespace: true,
+            ignore_comments: true,
+            ..Default::default()
+        },
+    );
+
+    let mut in_depend = false;
+    let mut dependencies: Vec<String> = vec![];
+    for e in parser {
+        match e {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                in_depend = DEPEND_TAGS.contains(&name.local_name.as_str());
+            }
+            Ok(XmlEvent::EndElement { .. }) => {
+                in_depend = false;
+            }
+            Ok(XmlEvent::Characters(data)) if in_depend && !dependencies.contains(&data) => {
+                dependencies.push(data);
+            }
+            Err(e) => {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, e));
+            }
+            _ => {}
+        }
+    }
+    Ok(dependencies)
+}
+
 #[cfg(test)]
 mod test {
     use crate::utils;
@@ -304,4 +354,32 @@ mod test {
         let deduplicated = utils::deduplicate_packages(packages);
         assert_eq!(deduplicated.len(), 3);
     }
+
+    #[test]
+    fn reads_package_dependencies() {
+        let dir = std::env::temp_dir().join("roslibrust_package_dependencies_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("package.xml"),
+            r#"<package format="3">
+  <name>nav_msgs</name>
+  <buildtool_depend>ament_cmake</buildtool_depend>
+  <depend>geometry_msgs</depend>
+  <build_depend>std_msgs</build_depend>
+  <exec_depend>std_msgs</exec_depend>
+  <!-- <depend>commented_out</depend> -->
+  <test_depend>ament_lint_auto</test_depend>
+</package>"#,
+        )
+        .unwrap();
+        let package = utils::Package {
+            name: "nav_msgs".into(),
+            path: dir,
+            version: Some(utils::RosVersion::ROS2),
+        };
+        assert_eq!(
+            utils::get_package_dependencies(&package).unwrap(),
+            vec!["geometry_msgs".to_owned(), "std_msgs".to_owned()]
+        );
+    }
 }
@@ -1,12 +1,18 @@
 use proc_macro::TokenStream;
+use roslibrust_codegen::CodegenOptions;
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::{parse_macro_input, Token};
 
+mod params;
+
 struct RosLibRustMessagePaths {
     paths: Vec<std::path::PathBuf>,
+    options: CodegenOptions,
 }
 
-/// Parses a comma-separated list of str literals specifying paths.
+/// Parses a comma-separated list of str literals specifying paths, optionally followed by
+/// `include = [...]` and `exclude = [...]` lists of str literals selecting the interfaces to generate.
 impl Parse for RosLibRustMessagePaths {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut paths = vec![];
@@ -18,7 +24,32 @@ impl Parse for RosLibRustMessagePaths {
                 break;
             }
         }
-        Ok(Self { paths })
+        let mut options = CodegenOptions::default();
+        while !input.is_empty() {
+            let name = input.parse::<syn::Ident>()?;
+            let add: fn(CodegenOptions, String) -> CodegenOptions = match name.to_string().as_str()
+            {
+                "include" => CodegenOptions::include,
+                "exclude" => CodegenOptions::exclude,
+                _ => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "Expected `include` or `exclude`",
+                    ))
+                }
+            };
+            input.parse::<Token![=]>()?;
+            let content;
+            syn::bracketed!(content in input);
+            let selectors = Punctuated::<syn::LitStr, Token![,]>::parse_terminated(&content)?;
+            for selector in selectors {
+                options = add(options, selector.value());
+            }
+            if input.parse::<Token![,]>().is_err() {
+                break;
+            }
+        }
+        Ok(Self { paths, options })
     }
 }
 
@@ -28,19 +59,21 @@ impl Parse for RosLibRustMessagePaths {
 ///
 /// In addition to provided paths, this will search paths found in the environment
 /// variable ROS_PACKAGE_PATH.
+///
+/// The generated code is cached and reused until a message file changes, see
+/// `roslibrust_codegen::cache` for where it is stored and how to disable caching.
+///
+/// Only the interfaces used can be generated by following the paths with `include` and `exclude` lists,
+/// which select packages or messages, services and actions and may contain `*` and `?` wildcards, e.g.
+/// `find_and_generate_ros_messages!("assets", include = ["std_msgs", "geometry_msgs/Pose*"], exclude = ["*/*Stamped"])`.
+/// Messages an included interface depends on are always generated, see `CodegenOptions::include` for details.
 #[proc_macro]
 pub fn find_and_generate_ros_messages(input_stream: TokenStream) -> TokenStream {
-    // Note: there is not currently a way for proc_macros to indicate that they need to be re-generated
-    // We discard the "dependent_paths" part of the response here...
-    let RosLibRustMessagePaths { paths } =
+    let RosLibRustMessagePaths { paths, options } =
         parse_macro_input!(input_stream as RosLibRustMessagePaths);
-    match roslibrust_codegen::find_and_generate_ros_messages(paths) {
-        Ok((source, _dependent_paths)) => source.into(),
-        Err(e) => {
-            let error_msg = e.to_string();
-            quote::quote!(compile_error!(#error_msg);).into()
-        }
-    }
+    let mut search_paths = roslibrust_codegen::utils::get_search_paths();
+    search_paths.extend(paths);
+    generate(search_paths, &options)
 }
 
 /// Similar to `find_and_generate_ros_messages`, but does not search the
@@ -49,9 +82,16 @@ pub fn find_and_generate_ros_messages(input_stream: TokenStream) -> TokenStream
 pub fn find_and_generate_ros_messages_without_ros_package_path(
     input_stream: TokenStream,
 ) -> TokenStream {
-    let RosLibRustMessagePaths { paths } =
+    let RosLibRustMessagePaths { paths, options } =
         parse_macro_input!(input_stream as RosLibRustMessagePaths);
-    match roslibrust_codegen::find_and_generate_ros_messages_without_ros_package_path(paths) {
+    generate(paths, &options)
+}
+
+/// Generates the code for the search paths, reusing cached code when no message file changed
+fn generate(search_paths: Vec<std::path::PathBuf>, options: &CodegenOptions) -> TokenStream {
+    let generated = roslibrust_codegen::cache::CodegenCache::from_env()
+        .find_and_generate_ros_messages_without_ros_package_path(search_paths, options);
+    match generated {
         // Note: there is not currently a way for proc_macros to indicate that they need to be re-generated
         // We discard the "dependent_paths" part of the response here...
         Ok((source, _dependent_paths)) => source.into(),
@@ -61,3 +101,15 @@ pub fn find_and_generate_ros_messages_without_ros_package_path(
         }
     }
 }
+
+/// Derives `RosParams`, binding a struct with named fields to a namespace of parameters.
+///
+/// See `roslibrust::RosParams` for the `#[ros_params(...)]` and `#[param(...)]` attributes accepted.
+/// The generated code refers to `::roslibrust`, `#[ros_params(crate = "roslibrust_common")]` changes that path.
+#[proc_macro_derive(RosParams, attributes(ros_params, param))]
+pub fn derive_ros_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    params::derive(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -0,0 +1,253 @@
+//! Implementation of `#[derive(RosParams)]`, see `roslibrust_common::RosParams` for the attributes it accepts.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Lit, Meta, NestedMeta, Result};
+
+/// A field of the struct and how it is read from its parameter
+struct Field {
+    ident: syn::Ident,
+    ty: syn::Type,
+    /// Name of the parameter within the namespace
+    param: String,
+    /// Value used when the parameter isn't set, the field is required without one
+    default: Option<TokenStream>,
+    min: Option<TokenStream>,
+    max: Option<TokenStream>,
+    nested: bool,
+    optional: bool,
+}
+
+/// The `key = value` pairs and bare keys within every `#[<attr>(...)]`
+fn metas(attrs: &[syn::Attribute], attr: &str) -> Result<Vec<Meta>> {
+    let mut metas = vec![];
+    for attribute in attrs.iter().filter(|a| a.path.is_ident(attr)) {
+        match attribute.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(meta) => metas.push(meta),
+                        NestedMeta::Lit(lit) => {
+                            return Err(Error::new_spanned(lit, "Expected `key = value` or `key`"))
+                        }
+                    }
+                }
+            }
+            meta => return Err(Error::new_spanned(meta, format!("Expected #[{attr}(...)]"))),
+        }
+    }
+    Ok(metas)
+}
+
+fn key(meta: &Meta) -> String {
+    meta.path()
+        .get_ident()
+        .map(ToString::to_string)
+        .unwrap_or_default()
+}
+
+fn string(lit: &Lit) -> Result<String> {
+    match lit {
+        Lit::Str(s) => Ok(s.value()),
+        lit => Err(Error::new_spanned(lit, "Expected a string")),
+    }
+}
+
+/// Literals are used as they are, strings are parsed as expressions, e.g. `min = "-1.5"`
+fn expr(lit: &Lit) -> Result<TokenStream> {
+    match lit {
+        Lit::Str(s) => {
+            let expr: syn::Expr = s.parse()?;
+            Ok(quote!(#expr))
+        }
+        lit => Ok(quote!(#lit)),
+    }
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+impl Field {
+    fn parse(field: &syn::Field) -> Result<Self> {
+        let ident = field.ident.clone().expect("Only named fields are parsed");
+        let optional = is_option(&field.ty);
+        let mut parsed = Self {
+            param: ident.to_string(),
+            ident,
+            ty: field.ty.clone(),
+            default: optional.then(|| quote!(::core::option::Option::None)),
+            min: None,
+            max: None,
+            nested: false,
+            optional,
+        };
+        for meta in metas(&field.attrs, "param")? {
+            match (key(&meta).as_str(), &meta) {
+                ("name", Meta::NameValue(nv)) => parsed.param = string(&nv.lit)?,
+                ("default", Meta::Path(_)) => {
+                    parsed.default = Some(quote!(::core::default::Default::default()))
+                }
+                ("default", Meta::NameValue(nv)) => parsed.default = Some(expr(&nv.lit)?),
+                ("min", Meta::NameValue(nv)) => parsed.min = Some(expr(&nv.lit)?),
+                ("max", Meta::NameValue(nv)) => parsed.max = Some(expr(&nv.lit)?),
+                ("nested", Meta::Path(_)) => parsed.nested = true,
+                _ => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "Expected `name = \"...\"`, `default`, `default = ...`, `min = ...`, `max = ...` or `nested`",
+                    ))
+                }
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn load(&self, krate: &syn::Path) -> TokenStream {
+        let Self {
+            ident, ty, param, ..
+        } = self;
+        if self.nested {
+            return quote! {
+                #ident: <#ty as #krate::RosParams>::load_from(ros, &format!("{}/{}", namespace, #param)).await?
+            };
+        }
+        let missing = match &self.default {
+            Some(default) => quote!(#default),
+            None => quote! {
+                return Err(#krate::Error::SerializationError(format!("Parameter {name} is not set")))
+            },
+        };
+        quote! {
+            #ident: {
+                let name = format!("{}/{}", namespace, #param);
+                match #krate::ParamProvider::get_param::<#ty>(ros, name.as_str()).await? {
+                    Some(value) => value,
+                    None => #missing,
+                }
+            }
+        }
+    }
+
+    fn validate(&self, krate: &syn::Path) -> TokenStream {
+        let Self { ident, param, .. } = self;
+        if self.nested {
+            return quote!(#krate::RosParams::validate(&self.#ident)?;);
+        }
+        let check = |limit: &Option<TokenStream>, violated: TokenStream, description: &str| {
+            limit.as_ref().map(|limit| {
+                let message = format!("Parameter {param} is {{:?}}, {description} {{:?}}");
+                quote! {
+                    if *value #violated #limit {
+                        return Err(#krate::Error::SerializationError(format!(#message, value, #limit)));
+                    }
+                }
+            })
+        };
+        let min = check(&self.min, quote!(<), "below its minimum");
+        let max = check(&self.max, quote!(>), "above its maximum");
+        if min.is_none() && max.is_none() {
+            return quote!();
+        }
+        if self.optional {
+            quote! {
+                if let Some(value) = &self.#ident {
+                    #min
+                    #max
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let value = &self.#ident;
+                    #min
+                    #max
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let mut namespace = None;
+    let mut validate = None;
+    let mut krate: syn::Path = syn::parse_quote!(::roslibrust);
+    for meta in metas(&input.attrs, "ros_params")? {
+        match (key(&meta).as_str(), &meta) {
+            ("namespace", Meta::NameValue(nv)) => namespace = Some(string(&nv.lit)?),
+            ("validate", Meta::NameValue(nv)) => {
+                validate = Some(syn::parse_str::<syn::Path>(&string(&nv.lit)?)?)
+            }
+            ("crate", Meta::NameValue(nv)) => {
+                krate = syn::parse_str::<syn::Path>(&string(&nv.lit)?)?
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "Expected `namespace = \"...\"`, `validate = \"...\"` or `crate = \"...\"`",
+                ))
+            }
+        }
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "RosParams can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "RosParams can only be derived for structs",
+            ))
+        }
+    };
+    let fields = fields
+        .iter()
+        .map(Field::parse)
+        .collect::<Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let namespace = namespace.map(|namespace| quote!(const NAMESPACE: &'static str = #namespace;));
+    let loads = fields.iter().map(|field| field.load(&krate));
+    let checks = fields.iter().map(|field| field.validate(&krate));
+    let validate = validate
+        .map(|validate| quote!(#validate(self).map_err(#krate::Error::SerializationError)?;));
+
+    Ok(quote! {
+        impl #impl_generics #krate::RosParams for #name #ty_generics #where_clause {
+            #namespace
+
+            async fn load_from<P: #krate::ParamProvider + Sync>(
+                ros: &P,
+                namespace: &str,
+            ) -> #krate::Result<Self> {
+                let namespace = namespace.trim_end_matches('/');
+                let value = Self {
+                    #(#loads,)*
+                };
+                #krate::RosParams::validate(&value)?;
+                Ok(value)
+            }
+
+            fn validate(&self) -> #krate::Result<()> {
+                #(#checks)*
+                #validate
+                Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,268 @@
+//! The lifecycle of an action goal as a typed state machine, see [GoalState] and [GoalStateMachine].
+//!
+//! Action servers only report the current status of each goal, at the rate they publish it, so a client can miss
+//! the states a goal passes through quickly. [GoalStateMachine] turns the reported statuses into the sequence of
+//! [GoalTransition]s the goal went through, filling in the states it skipped, so e.g. a UI showing a goal's
+//! progress sees it become active before it succeeds even when the server finished it between two status updates.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+/// The state of an action goal, following actionlib's `actionlib_msgs/GoalStatus`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GoalState {
+    /// The goal has yet to be processed by the action server
+    Pending,
+    /// The goal is being processed by the action server
+    Active,
+    /// The goal was asked to cancel after it started executing, and has not yet completed
+    Preempting,
+    /// The goal was asked to cancel before it started executing, and the server has not yet confirmed it
+    Recalling,
+    /// The goal was canceled after it started executing
+    Preempted,
+    /// The goal was canceled before it started executing
+    Recalled,
+    /// The goal was achieved
+    Succeeded,
+    /// The goal failed during execution
+    Aborted,
+    /// The server refused to process the goal, e.g. because it was invalid
+    Rejected,
+    /// The server stopped reporting the goal before it completed
+    Lost,
+}
+
+impl GoalState {
+    /// The state of a `status` code of `actionlib_msgs/GoalStatus`, None for codes actionlib doesn't define
+    pub fn from_status(status: u8) -> Option<GoalState> {
+        Some(match status {
+            0 => GoalState::Pending,
+            1 => GoalState::Active,
+            2 => GoalState::Preempted,
+            3 => GoalState::Succeeded,
+            4 => GoalState::Aborted,
+            5 => GoalState::Rejected,
+            6 => GoalState::Preempting,
+            7 => GoalState::Recalling,
+            8 => GoalState::Recalled,
+            9 => GoalState::Lost,
+            _ => return None,
+        })
+    }
+
+    /// The `status` code of `actionlib_msgs/GoalStatus` for this state
+    pub fn status(&self) -> u8 {
+        match self {
+            GoalState::Pending => 0,
+            GoalState::Active => 1,
+            GoalState::Preempted => 2,
+            GoalState::Succeeded => 3,
+            GoalState::Aborted => 4,
+            GoalState::Rejected => 5,
+            GoalState::Preempting => 6,
+            GoalState::Recalling => 7,
+            GoalState::Recalled => 8,
+            GoalState::Lost => 9,
+        }
+    }
+
+    /// Whether the goal is finished, after which its state no longer changes
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            GoalState::Preempted
+                | GoalState::Recalled
+                | GoalState::Succeeded
+                | GoalState::Aborted
+                | GoalState::Rejected
+                | GoalState::Lost
+        )
+    }
+
+    /// The states a goal can move to directly from this one, in the order gaps are filled in
+    pub fn successors(&self) -> &'static [GoalState] {
+        use GoalState::*;
+        match self {
+            Pending => &[Active, Recalling, Rejected, Recalled, Lost],
+            Active => &[Preempting, Succeeded, Aborted, Lost],
+            Recalling => &[Preempting, Recalled, Rejected, Lost],
+            Preempting => &[Preempted, Succeeded, Aborted, Lost],
+            Preempted | Recalled | Succeeded | Aborted | Rejected | Lost => &[],
+        }
+    }
+}
+
+impl fmt::Display for GoalState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            GoalState::Pending => "PENDING",
+            GoalState::Active => "ACTIVE",
+            GoalState::Preempting => "PREEMPTING",
+            GoalState::Recalling => "RECALLING",
+            GoalState::Preempted => "PREEMPTED",
+            GoalState::Recalled => "RECALLED",
+            GoalState::Succeeded => "SUCCEEDED",
+            GoalState::Aborted => "ABORTED",
+            GoalState::Rejected => "REJECTED",
+            GoalState::Lost => "LOST",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A goal moving from one state to another, produced by [GoalStateMachine::update].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GoalTransition {
+    pub from: GoalState,
+    pub to: GoalState,
+    /// Whether the goal passed through `to` without the server reporting it, filled in to reach a later state
+    pub inferred: bool,
+}
+
+/// Tracks the state of one goal from the statuses reported for it.
+#[derive(Clone, Debug)]
+pub struct GoalStateMachine {
+    state: GoalState,
+}
+
+impl Default for GoalStateMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GoalStateMachine {
+    /// A goal which was just sent, and is [GoalState::Pending] until the server reports otherwise.
+    pub fn new() -> Self {
+        Self {
+            state: GoalState::Pending,
+        }
+    }
+
+    pub fn state(&self) -> GoalState {
+        self.state
+    }
+
+    /// Moves the goal to the reported `state`, returning the transitions it went through to get there.
+    ///
+    /// States the goal skipped are filled in, marked as [GoalTransition::inferred]. Reports which can't follow the
+    /// current state, such as a status update delayed past a later one, are stale and return no transitions.
+    pub fn update(&mut self, state: GoalState) -> Vec<GoalTransition> {
+        let Some(path) = Self::path(self.state, state) else {
+            return vec![];
+        };
+        let mut from = self.state;
+        let transitions = path
+            .iter()
+            .map(|to| {
+                let transition = GoalTransition {
+                    from,
+                    to: *to,
+                    inferred: *to != state,
+                };
+                from = *to;
+                transition
+            })
+            .collect();
+        self.state = state;
+        transitions
+    }
+
+    /// Moves the goal to the reported `status` code of `actionlib_msgs/GoalStatus`, see [GoalStateMachine::update].
+    /// Unknown codes return no transitions.
+    pub fn update_status(&mut self, status: u8) -> Vec<GoalTransition> {
+        match GoalState::from_status(status) {
+            Some(state) => self.update(state),
+            None => vec![],
+        }
+    }
+
+    /// The shortest sequence of states leading from `from` to `to`, excluding `from`.
+    /// None if `to` can't be reached or is `from` itself.
+    fn path(from: GoalState, to: GoalState) -> Option<Vec<GoalState>> {
+        // The graph has ten states, a breadth first search recording each state's predecessor is plenty
+        let mut previous: Vec<(GoalState, GoalState)> = vec![];
+        let mut queue = VecDeque::from([from]);
+        while let Some(state) = queue.pop_front() {
+            for next in state.successors() {
+                if *next == from || previous.iter().any(|(seen, _)| seen == next) {
+                    continue;
+                }
+                previous.push((*next, state));
+                if *next == to {
+                    let mut path = vec![to];
+                    let mut current = to;
+                    while let Some((_, before)) = previous.iter().find(|(seen, _)| *seen == current)
+                    {
+                        if *before == from {
+                            break;
+                        }
+                        path.push(*before);
+                        current = *before;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                queue.push_back(*next);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_in_skipped_states() {
+        let mut goal = GoalStateMachine::new();
+        assert_eq!(goal.update(GoalState::Pending), vec![]);
+        // Finished between two status updates
+        assert_eq!(
+            goal.update(GoalState::Succeeded),
+            vec![
+                GoalTransition {
+                    from: GoalState::Pending,
+                    to: GoalState::Active,
+                    inferred: true
+                },
+                GoalTransition {
+                    from: GoalState::Active,
+                    to: GoalState::Succeeded,
+                    inferred: false
+                }
+            ]
+        );
+        assert!(goal.state().is_terminal());
+        // A delayed report of an earlier state is ignored
+        assert_eq!(goal.update_status(1), vec![]);
+        assert_eq!(goal.state(), GoalState::Succeeded);
+
+        let mut goal = GoalStateMachine::new();
+        let states: Vec<_> = goal
+            .update(GoalState::Preempted)
+            .iter()
+            .map(|transition| transition.to)
+            .collect();
+        assert_eq!(
+            states,
+            [
+                GoalState::Active,
+                GoalState::Preempting,
+                GoalState::Preempted
+            ]
+        );
+
+        let mut goal = GoalStateMachine::new();
+        assert_eq!(goal.update_status(7).len(), 1);
+        assert_eq!(goal.update_status(8).len(), 1);
+        assert_eq!(goal.state(), GoalState::Recalled);
+        assert_eq!(goal.update_status(42), vec![]);
+
+        for status in 0..10 {
+            assert_eq!(GoalState::from_status(status).unwrap().status(), status);
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Describes which optional features a backend supports, see [CapabilityProvider].
+
+use crate::{Error, Result};
+
+/// The optional features of a backend, returned by [CapabilityProvider::capabilities].
+///
+/// Generic code can check these up front rather than failing part way through an operation the backend can't
+/// perform. Backends build theirs from [Capabilities::default], which supports nothing, so fields added later
+/// are unsupported until the backend opts in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    /// Publishers can be latched with [crate::PublisherQos::latching], sending their last message to subscribers
+    /// which connect later
+    pub latching: bool,
+    /// The backend implements [crate::RawTopicProvider] for publishing and subscribing without a compiled-in type
+    pub raw_topics: bool,
+    /// Services can be called
+    pub service_clients: bool,
+    /// Services can be advertised
+    pub service_servers: bool,
+    /// Actions can be used, e.g. actionlib's goal, feedback and result topics
+    pub actions: bool,
+    /// Publishers and subscribers can be configured with ROS2 quality of service settings
+    pub qos: bool,
+    /// The backend implements [crate::ParamProvider]
+    pub params: bool,
+    /// The backend implements [crate::GraphProvider]
+    pub graph: bool,
+    /// The backend's [crate::ClockProvider] can follow simulated time rather than the wall clock
+    pub sim_time: bool,
+}
+
+impl Capabilities {
+    /// The capabilities both `self` and `other` have, e.g. for a combination of backends either of which may be used
+    pub fn intersection(&self, other: &Capabilities) -> Capabilities {
+        Capabilities {
+            latching: self.latching && other.latching,
+            raw_topics: self.raw_topics && other.raw_topics,
+            service_clients: self.service_clients && other.service_clients,
+            service_servers: self.service_servers && other.service_servers,
+            actions: self.actions && other.actions,
+            qos: self.qos && other.qos,
+            params: self.params && other.params,
+            graph: self.graph && other.graph,
+            sim_time: self.sim_time && other.sim_time,
+        }
+    }
+
+    /// The names of the capabilities in `required` this lacks, in the order of the fields
+    pub fn missing(&self, required: &Capabilities) -> Vec<&'static str> {
+        let Capabilities {
+            latching,
+            raw_topics,
+            service_clients,
+            service_servers,
+            actions,
+            qos,
+            params,
+            graph,
+            sim_time,
+        } = *required;
+        [
+            ("latching", latching, self.latching),
+            ("raw_topics", raw_topics, self.raw_topics),
+            ("service_clients", service_clients, self.service_clients),
+            ("service_servers", service_servers, self.service_servers),
+            ("actions", actions, self.actions),
+            ("qos", qos, self.qos),
+            ("params", params, self.params),
+            ("graph", graph, self.graph),
+            ("sim_time", sim_time, self.sim_time),
+        ]
+        .into_iter()
+        .filter(|(_, required, supported)| *required && !supported)
+        .map(|(name, _, _)| name)
+        .collect()
+    }
+
+    /// Returns [Error::Unsupported] naming the missing capabilities unless this has all of `required`
+    pub fn require(&self, required: &Capabilities) -> Result<()> {
+        let missing = self.missing(required);
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Unsupported(missing.join(", ")))
+        }
+    }
+}
+
+/// Reports the optional features a backend supports, see [Capabilities].
+pub trait CapabilityProvider {
+    fn capabilities(&self) -> Capabilities;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_missing_capabilities() {
+        let backend = Capabilities {
+            latching: true,
+            raw_topics: true,
+            service_clients: true,
+            ..Default::default()
+        };
+        let required = Capabilities {
+            raw_topics: true,
+            service_servers: true,
+            params: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            backend.missing(&required),
+            vec!["service_servers", "params"]
+        );
+        assert!(matches!(
+            backend.require(&required),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(backend.require(&Capabilities::default()).is_ok());
+
+        let other = Capabilities {
+            latching: true,
+            service_servers: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            backend.intersection(&other),
+            Capabilities {
+                latching: true,
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Backends queue received messages and service requests in channels between their network tasks and the user's
+//! code. A queue which fills up because it isn't read fast enough drops messages, trading loss for bounded memory.
+//!
+//! How each backend uses [ChannelConfig]:
+//! - roslibrust_rosbridge: `subscriber_queue_size` is the size of each subscriber's queue, 1000 by default.
+//!   When it is full the oldest message is dropped.
+//! - roslibrust_zenoh: `subscriber_queue_size` is the size of each subscriber's queue, zenoh's default of 256 if
+//!   unset, which holds back zenoh when full rather than dropping. `service_queue_size` bounds the requests queued
+//!   for each service server, unbounded by default, with new requests dropped when it is full.
+//! - roslibrust_mock: `subscriber_queue_size` is the size of each topic's channel, 10 by default, with the oldest
+//!   messages dropped for subscribers which fall behind.
+//! - roslibrust_ros1: the sizes are given by the `queue_size` of each `advertise` and `subscribe` call instead.
+//!
+//! Each backend counts the messages it drops in [ChannelMetrics], shared by all handles to the same connection.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Sizes of a backend's internal channels, see the [module docs](self) for how each backend uses them.
+/// Sizes which aren't set keep the backend's default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelConfig {
+    /// Messages queued for each subscriber which hasn't read them yet
+    pub subscriber_queue_size: Option<usize>,
+    /// Requests queued for each service server which hasn't handled them yet
+    pub service_queue_size: Option<usize>,
+}
+
+/// Counts of the messages a backend dropped because a channel was full, shared between clones
+#[derive(Debug, Clone, Default)]
+pub struct ChannelMetrics {
+    counters: Arc<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    subscriber_dropped: AtomicU64,
+    publisher_dropped: AtomicU64,
+    service_dropped: AtomicU64,
+}
+
+impl ChannelMetrics {
+    /// Received messages dropped before a subscriber read them
+    pub fn subscriber_dropped(&self) -> u64 {
+        self.counters.subscriber_dropped.load(Ordering::Relaxed)
+    }
+
+    /// Published messages dropped before they were sent to a subscriber
+    pub fn publisher_dropped(&self) -> u64 {
+        self.counters.publisher_dropped.load(Ordering::Relaxed)
+    }
+
+    /// Service requests dropped before a service server handled them
+    pub fn service_dropped(&self) -> u64 {
+        self.counters.service_dropped.load(Ordering::Relaxed)
+    }
+
+    /// Adds to [ChannelMetrics::subscriber_dropped], called by backends
+    pub fn record_subscriber_dropped(&self, count: u64) {
+        self.counters
+            .subscriber_dropped
+            .fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Adds to [ChannelMetrics::publisher_dropped], called by backends
+    pub fn record_publisher_dropped(&self, count: u64) {
+        self.counters
+            .publisher_dropped
+            .fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Adds to [ChannelMetrics::service_dropped], called by backends
+    pub fn record_service_dropped(&self, count: u64) {
+        self.counters
+            .service_dropped
+            .fetch_add(count, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,381 @@
+//! Message, service and action definitions parsed at runtime, for calling and serving interfaces whose types weren't
+//! available at compile time, see [ServiceDefinition] and [ActionDefinition].
+
+use crate::md5sum;
+use anyhow::{bail, Error};
+
+/// Separates the sections of an expanded definition as printed by `gendeps --cat`
+const DEPENDENCY_SEPARATOR: &str =
+    "================================================================================\n";
+
+/// Separates the request and response of a .srv file, and the goal, result and feedback of a .action file
+const SECTION_SEPARATOR: &str = "---";
+
+// The messages actionlib wraps the goal, result and feedback of an action in depend on these
+const HEADER_DEFINITION: &str = "uint32 seq\ntime stamp\nstring frame_id";
+const GOAL_ID_DEFINITION: &str = "time stamp\nstring id";
+const GOAL_STATUS_DEFINITION: &str = "GoalID goal_id
+uint8 status
+uint8 PENDING         = 0
+uint8 ACTIVE          = 1
+uint8 PREEMPTED       = 2
+uint8 SUCCEEDED       = 3
+uint8 ABORTED         = 4
+uint8 REJECTED        = 5
+uint8 PREEMPTING      = 6
+uint8 RECALLING       = 7
+uint8 RECALLED        = 8
+uint8 LOST            = 9
+string text";
+
+/// A message type with its expanded definition and md5sum, the equivalent of a generated
+/// [crate::RosMessageType] for the raw topic APIs of the backends, such as ROS1's `advertise_any`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageDefinition {
+    name: String,
+    definition: String,
+    md5sum: String,
+}
+
+impl MessageDefinition {
+    /// Creates the definition of the type `name` from its expanded definition in the `gendeps --cat` format,
+    /// which unlike [md5sum::from_message_definition] may be empty.
+    pub fn new(name: &str, definition: &str) -> Result<Self, Error> {
+        let md5sum = format!(
+            "{:x}",
+            md5::compute(md5sum::expanded_definition(name, definition)?.trim_end())
+        );
+        Ok(Self {
+            name: name.to_owned(),
+            definition: definition.to_owned(),
+            md5sum,
+        })
+    }
+
+    /// The full name of the type, e.g. "std_msgs/String"
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The expanded definition in the `gendeps --cat` format
+    pub fn definition(&self) -> &str {
+        &self.definition
+    }
+
+    pub fn md5sum(&self) -> &str {
+        &self.md5sum
+    }
+}
+
+/// The definition of a ROS1 service parsed from the text of its .srv file.
+///
+/// Fields of message types need the definitions of those types, which follow the .srv file's text in the
+/// `gendeps --cat` format, each headed by a separator line and `MSG: <type>`:
+/// ```
+/// use roslibrust_common::definitions::ServiceDefinition;
+///
+/// let srv = ServiceDefinition::parse(
+///     "my_srvs/Move",
+///     "geometry_msgs/Point target
+/// ---
+/// bool success
+/// ================================================================================
+/// MSG: geometry_msgs/Point
+/// float64 x
+/// float64 y
+/// float64 z",
+/// )
+/// .unwrap();
+/// assert_eq!(srv.request().name(), "my_srvs/MoveRequest");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceDefinition {
+    name: String,
+    request: MessageDefinition,
+    response: MessageDefinition,
+    md5sum: String,
+}
+
+impl ServiceDefinition {
+    /// Parses the definition of the service type `name`, e.g. "std_srvs/SetBool"
+    pub fn parse(name: &str, definition: &str) -> Result<Self, Error> {
+        let ([request, response], dependencies) = split_sections(name, definition)?;
+        let request = expand(request, &dependencies);
+        let response = expand(response, &dependencies);
+        let md5sum = md5sum::from_service_definition(name, &request, &response)?;
+        Ok(Self {
+            name: name.to_owned(),
+            request: MessageDefinition::new(&format!("{name}Request"), &request)?,
+            response: MessageDefinition::new(&format!("{name}Response"), &response)?,
+            md5sum,
+        })
+    }
+
+    /// The full name of the service type, e.g. "std_srvs/SetBool"
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn request(&self) -> &MessageDefinition {
+        &self.request
+    }
+
+    pub fn response(&self) -> &MessageDefinition {
+        &self.response
+    }
+
+    /// The md5sum of the service, which differs from those of its request and response
+    pub fn md5sum(&self) -> &str {
+        &self.md5sum
+    }
+}
+
+/// The definition of a ROS1 action parsed from the text of its .action file, in the same format as
+/// [ServiceDefinition::parse] takes for services.
+///
+/// Along with the goal, result and feedback it provides the definitions of the messages actionlib sends them in,
+/// e.g. "actionlib_tutorials/FibonacciActionGoal" on the goal topic of a Fibonacci action server, to be published
+/// and subscribed to with the raw topic APIs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionDefinition {
+    name: String,
+    goal: MessageDefinition,
+    result: MessageDefinition,
+    feedback: MessageDefinition,
+    action_goal: MessageDefinition,
+    action_result: MessageDefinition,
+    action_feedback: MessageDefinition,
+}
+
+impl ActionDefinition {
+    /// Parses the definition of the action type `name`, e.g. "actionlib_tutorials/Fibonacci"
+    pub fn parse(name: &str, definition: &str) -> Result<Self, Error> {
+        let ([goal, result, feedback], dependencies) = split_sections(name, definition)?;
+        let short_name = name.rsplit('/').next().unwrap_or(name);
+        let part = |suffix: &str, root: &str| {
+            let definition = expand(root, &dependencies);
+            MessageDefinition::new(&format!("{name}{suffix}"), &definition)
+        };
+        // The messages actionlib sends each part in, as generated by genaction
+        let wrapper = |suffix: &str, root: &str, status: bool| {
+            let mut wrapper_dependencies = vec![
+                format!("MSG: std_msgs/Header\n{HEADER_DEFINITION}"),
+                format!("MSG: actionlib_msgs/GoalID\n{GOAL_ID_DEFINITION}"),
+            ];
+            let second_field = if status {
+                wrapper_dependencies.push(format!(
+                    "MSG: actionlib_msgs/GoalStatus\n{GOAL_STATUS_DEFINITION}"
+                ));
+                "actionlib_msgs/GoalStatus status"
+            } else {
+                "actionlib_msgs/GoalID goal_id"
+            };
+            wrapper_dependencies.push(format!("MSG: {name}{suffix}\n{}", root.trim()));
+            wrapper_dependencies.extend(dependencies.iter().cloned());
+            let field = suffix.to_lowercase();
+            let definition = expand(
+                &format!("Header header\n{second_field}\n{short_name}{suffix} {field}"),
+                &wrapper_dependencies,
+            );
+            MessageDefinition::new(&format!("{name}Action{suffix}"), &definition)
+        };
+        Ok(Self {
+            name: name.to_owned(),
+            goal: part("Goal", goal)?,
+            result: part("Result", result)?,
+            feedback: part("Feedback", feedback)?,
+            action_goal: wrapper("Goal", goal, false)?,
+            action_result: wrapper("Result", result, true)?,
+            action_feedback: wrapper("Feedback", feedback, true)?,
+        })
+    }
+
+    /// The full name of the action type, e.g. "actionlib_tutorials/Fibonacci"
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn goal(&self) -> &MessageDefinition {
+        &self.goal
+    }
+
+    pub fn result(&self) -> &MessageDefinition {
+        &self.result
+    }
+
+    pub fn feedback(&self) -> &MessageDefinition {
+        &self.feedback
+    }
+
+    /// The message on the action's `goal` topic, the goal with a header and goal id
+    pub fn action_goal(&self) -> &MessageDefinition {
+        &self.action_goal
+    }
+
+    /// The message on the action's `result` topic, the result with a header and goal status
+    pub fn action_result(&self) -> &MessageDefinition {
+        &self.action_result
+    }
+
+    /// The message on the action's `feedback` topic, the feedback with a header and goal status
+    pub fn action_feedback(&self) -> &MessageDefinition {
+        &self.action_feedback
+    }
+}
+
+/// Splits the text of a .srv or .action file into its N sections and the dependency sections following it
+fn split_sections<'a, const N: usize>(
+    name: &str,
+    definition: &'a str,
+) -> Result<([&'a str; N], Vec<String>), Error> {
+    let mut sections = definition.split(DEPENDENCY_SEPARATOR);
+    let root = sections.next().unwrap_or_default();
+    let dependencies = sections
+        .map(|section| section.trim_end().to_owned())
+        .collect::<Vec<_>>();
+
+    // Find the separator lines, the sections being the text in between
+    let mut bounds = vec![0];
+    let mut offset = 0;
+    for line in root.split_inclusive('\n') {
+        if line.trim() == SECTION_SEPARATOR {
+            bounds.push(offset);
+            bounds.push(offset + line.len());
+        }
+        offset += line.len();
+    }
+    bounds.push(root.len());
+    let parts = bounds
+        .chunks(2)
+        .map(|bounds| &root[bounds[0]..bounds[1]])
+        .collect::<Vec<_>>();
+    match parts.try_into() {
+        Ok(parts) => Ok((parts, dependencies)),
+        Err(parts) => bail!(
+            "Definition of {name} has {} sections separated by '{SECTION_SEPARATOR}', expected {N}",
+            parts.len()
+        ),
+    }
+}
+
+/// Appends the dependency sections to a root definition in the `gendeps --cat` format
+fn expand(root: &str, dependencies: &[String]) -> String {
+    let mut definition = root.trim().to_owned();
+    let mut listed = vec![];
+    for dependency in dependencies {
+        // Wrappers list their own dependencies ahead of those of the action, which may repeat them
+        let first_line = dependency.lines().next();
+        if listed.contains(&first_line) {
+            continue;
+        }
+        listed.push(first_line);
+        definition.push('\n');
+        definition.push_str(DEPENDENCY_SEPARATOR);
+        definition.push_str(dependency);
+    }
+    definition
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_services() {
+        let srv = ServiceDefinition::parse(
+            "std_srvs/SetBool",
+            "bool data # e.g. for hardware enabling / disabling\n---\n\
+             bool success   # indicate successful run of triggered service\n\
+             string message # informational, e.g. for error messages\n",
+        )
+        .unwrap();
+        assert_eq!(srv.name(), "std_srvs/SetBool");
+        assert_eq!(srv.md5sum(), "09fb03525b03e7ea1fd3992bafd87e16");
+        assert_eq!(srv.request().name(), "std_srvs/SetBoolRequest");
+        assert_eq!(srv.request().md5sum(), "8b94c1b53db61fb6aed406028ad6332a");
+        assert_eq!(srv.response().md5sum(), "937c9679a518e3a18d831e57125ea522");
+
+        let srv = ServiceDefinition::parse("std_srvs/Trigger", "---\nbool success\nstring message")
+            .unwrap();
+        assert_eq!(srv.md5sum(), "937c9679a518e3a18d831e57125ea522");
+        assert_eq!(srv.request().definition(), "");
+
+        // Dependencies follow the .srv text
+        let srv = ServiceDefinition::parse(
+            "nav_msgs/GetPlan",
+            "geometry_msgs/PoseStamped start\ngeometry_msgs/PoseStamped goal\nfloat32 tolerance\n---\n\
+             nav_msgs/Path plan\n\
+             ================================================================================\n\
+             MSG: geometry_msgs/PoseStamped\nHeader header\nPose pose\n\
+             ================================================================================\n\
+             MSG: std_msgs/Header\nuint32 seq\ntime stamp\nstring frame_id\n\
+             ================================================================================\n\
+             MSG: geometry_msgs/Pose\nPoint position\nQuaternion orientation\n\
+             ================================================================================\n\
+             MSG: geometry_msgs/Point\nfloat64 x\nfloat64 y\nfloat64 z\n\
+             ================================================================================\n\
+             MSG: geometry_msgs/Quaternion\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n\
+             ================================================================================\n\
+             MSG: nav_msgs/Path\nHeader header\ngeometry_msgs/PoseStamped[] poses\n",
+        )
+        .unwrap();
+        assert_eq!(srv.md5sum(), "421c8ea4d21c6c9db7054b4bbdf1e024");
+
+        ServiceDefinition::parse("std_srvs/Empty", "").unwrap_err();
+        ServiceDefinition::parse("std_srvs/Empty", "---\n---\n").unwrap_err();
+        ServiceDefinition::parse("nav_msgs/GetPlan", "nav_msgs/Path plan\n---\n").unwrap_err();
+    }
+
+    #[test]
+    fn parses_actions() {
+        let action = ActionDefinition::parse(
+            "nav_msgs/GetMap",
+            "# Get the map as a nav_msgs/OccupancyGrid\n---\nnav_msgs/OccupancyGrid map\n---\n# no feedback\n\
+             ================================================================================\n\
+             MSG: nav_msgs/OccupancyGrid\nHeader header\nMapMetaData info\nint8[] data\n\
+             ================================================================================\n\
+             MSG: std_msgs/Header\nuint32 seq\ntime stamp\nstring frame_id\n\
+             ================================================================================\n\
+             MSG: nav_msgs/MapMetaData\ntime map_load_time\nfloat32 resolution\nuint32 width\nuint32 height\n\
+             geometry_msgs/Pose origin\n\
+             ================================================================================\n\
+             MSG: geometry_msgs/Pose\nPoint position\nQuaternion orientation\n\
+             ================================================================================\n\
+             MSG: geometry_msgs/Point\nfloat64 x\nfloat64 y\nfloat64 z\n\
+             ================================================================================\n\
+             MSG: geometry_msgs/Quaternion\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n",
+        )
+        .unwrap();
+        assert_eq!(action.name(), "nav_msgs/GetMap");
+        assert_eq!(action.goal().md5sum(), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(action.result().md5sum(), "6cdd0a18e0aff5b0a3ca2326a89b54ff");
+        assert_eq!(
+            action.feedback().md5sum(),
+            "d41d8cd98f00b204e9800998ecf8427e"
+        );
+        assert_eq!(action.action_goal().name(), "nav_msgs/GetMapActionGoal");
+        assert_eq!(
+            action.action_goal().md5sum(),
+            "4b30be6cd12b9e72826df56b481f40e0"
+        );
+        assert_eq!(
+            action.action_result().md5sum(),
+            "ac66e5b9a79bb4bbd33dab245236c892"
+        );
+        assert_eq!(
+            action.action_feedback().md5sum(),
+            "aae20e09065c3809e8a8e87c4c8953fd"
+        );
+        // The wrappers are valid definitions for publishing on the action's topics
+        assert_eq!(
+            md5sum::from_message_definition(
+                action.action_result().name(),
+                action.action_result().definition()
+            )
+            .unwrap(),
+            "ac66e5b9a79bb4bbd33dab245236c892"
+        );
+
+        ActionDefinition::parse("nav_msgs/GetMap", "---\n").unwrap_err();
+    }
+}
@@ -38,6 +38,9 @@ pub enum Error {
     /// When a topic name is used that isn't a valid topic name.
     #[error("Name does not meet ROS requirements: {0}")]
     InvalidName(String),
+    /// When something is requested that the backend can't provide, see [Capabilities] for what each supports.
+    #[error("Backend does not support {0}")]
+    Unsupported(String),
     /// Backends are free to return this error if they encounter any error that doesn't cleanly fit in the other categories.
     #[error(transparent)]
     Unexpected(#[from] anyhow::Error),
@@ -62,6 +65,24 @@ pub trait RosMessageType:
     /// The definition from the msg, srv, or action file
     /// This field is optional, and only needed when using ros1 native communication
     const DEFINITION: &'static str = "";
+
+    /// The ROS2 type hash of the message and its dependencies e.g. "RIHS01_df66..."
+    /// This field is optional, and empty for ros1 messages
+    const TYPE_HASH: &'static str = "";
+
+    /// Encodes the message in the ROS1 wire format including its length, without going through serde
+    /// This function is optional, and returns None for types without a [ros1_codec] implementation
+    /// in which case backends fall back to serde
+    fn ros1_encode(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Decodes the message from the ROS1 wire format including its length, without going through serde
+    /// This function is optional, and returns None for types without a [ros1_codec] implementation
+    /// in which case backends fall back to serde
+    fn ros1_decode(_data: &[u8]) -> Option<std::result::Result<Self, ros1_codec::DecodeError>> {
+        None
+    }
 }
 
 // This special impl allows for services with no args / returns
@@ -80,12 +101,30 @@ pub trait RosServiceType: 'static + Send + Sync {
     const ROS_SERVICE_NAME: &'static str;
     /// The computed md5sum of the message file and its dependencies
     const MD5SUM: &'static str;
+    /// The ROS2 type hash of the service, this field is optional and empty for ros1 services
+    const TYPE_HASH: &'static str = "";
     /// The type of data being sent in the request
     type Request: RosMessageType;
     /// The type of the data
     type Response: RosMessageType;
 }
 
+/// Represents a ROS action type definition corresponding to a `.action` file.
+///
+/// Typically this trait will not be implemented by hand but instead be generated by using [roslibrust's codegen functionality][<https://docs.rs/roslibrust/latest/roslibrust/codegen>].
+/// Codegen additionally generates the types used to transport the action, actionlib's `{Name}ActionGoal` etc. messages
+/// for ROS1, and the `{Name}SendGoal` / `{Name}GetResult` services and `{Name}FeedbackMessage` message for ROS2.
+pub trait RosActionType: 'static + Send + Sync {
+    /// Name of the ros action e.g. `actionlib_tutorials/Fibonacci`
+    const ROS_ACTION_NAME: &'static str;
+    /// The type of the goal sent to start the action
+    type Goal: RosMessageType;
+    /// The type of the result returned when the action completes
+    type Result: RosMessageType;
+    /// The type of the feedback published while the action is in progress
+    type Feedback: RosMessageType;
+}
+
 // Note: service Fn is currently defined here as it used by ros1 and roslibrust impls
 /// This trait describes a function which can validly act as a ROS service
 /// server with roslibrust. We're really just using this as a trait alias
@@ -125,10 +164,64 @@ impl RosMessageType for ShapeShifter {
     const DEFINITION: &'static str = "";
 }
 
+/// Contains validated [TopicName] and [ServiceName] types and the [Remappings] table used to resolve them
+pub mod names;
+pub use names::*;
+
+/// Contains the [ros1_codec::Ros1Encode] and [ros1_codec::Ros1Decode] traits generated code can implement
+/// to be serialized by the ROS1 backend without serde
+pub mod ros1_codec;
+
+/// Contains [ChannelConfig] sizing the channels backends queue messages in, and the [ChannelMetrics] counting
+/// the messages dropped when they are full
+pub mod channels;
+pub use channels::{ChannelConfig, ChannelMetrics};
+
+/// Contains [monitor::TopicMonitor] measuring the rate, jitter, bandwidth and drops of a topic like
+/// `rostopic hz` and `rostopic bw`
+pub mod monitor;
+
+/// Contains [mcap::McapWriter] writing MCAP files, shared by the loggers and recorders which produce them
+pub mod mcap;
+
+/// Contains [stats_log::StatsLog] persisting the statistics of [monitor::TopicMonitor]s to CSV or MCAP files
+pub mod stats_log;
+
 /// Contains functions for calculating md5sums of message definitions
 /// These functions are needed both in roslibrust_ros1 and roslibrust_codegen so they're in this crate
 pub mod md5sum;
 
+/// Contains message, service and action definitions parsed at runtime, for interfaces unknown at compile time
+pub mod definitions;
+
+/// Contains [schema::MessageSchema] for comparing the compiled-in definition of a message type with the one seen on
+/// the graph, explaining md5sum mismatches
+pub mod schema;
+
+/// Contains [Capabilities] describing which optional features a backend supports
+pub mod capabilities;
+pub use capabilities::{Capabilities, CapabilityProvider};
+
+/// Contains [TaskRegistry] listing the background tasks a backend is running, to find leaked ones
+pub mod tasks;
+pub use tasks::{TaskInfo, TaskRegistry};
+
+/// Contains [actions::GoalStateMachine] tracking the [actions::GoalState] of an action goal from the statuses its
+/// server reports
+pub mod actions;
+
+/// Contains [primitives::Bool], [primitives::Float64] and the other single field messages of `std_msgs`, for
+/// publishing and subscribing to basic topics without codegen
+pub mod primitives;
+
+/// Contains [stamped::Cache] keeping the recent messages of a topic by their stamps, and [stamped::Interpolate]
+/// looking up the state at a time between them
+pub mod stamped;
+
+/// Contains helpers running the tasks and timers of backends built on tokio for applications using other executors
+#[cfg(feature = "runtime")]
+pub mod runtime;
+
 /// Contains the generic traits represent a pubsub system and service system
 /// These traits will be implemented for specific backends to provides access to "ROS Like" functionality
 pub mod traits;
@@ -0,0 +1,528 @@
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MAGIC: &[u8] = b"\x89MCAP0\r\n";
+const HEADER: u8 = 0x01;
+const FOOTER: u8 = 0x02;
+const SCHEMA: u8 = 0x03;
+const CHANNEL: u8 = 0x04;
+const MESSAGE: u8 = 0x05;
+const CHUNK: u8 = 0x06;
+const DATA_END: u8 = 0x0F;
+
+/// How the messages of an [McapWriter] are compressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// Chunks are written uncompressed
+    #[default]
+    None,
+    /// Chunks are compressed with LZ4, requires the `lz4` feature
+    #[cfg(feature = "lz4")]
+    Lz4,
+}
+
+/// Messages buffered until a chunk is full
+struct Chunk {
+    records: Vec<u8>,
+    start: u64,
+    end: u64,
+}
+
+impl Default for Chunk {
+    fn default() -> Self {
+        Self {
+            records: vec![],
+            start: u64::MAX,
+            end: 0,
+        }
+    }
+}
+
+/// Counts the bytes passing through to the wrapped writer
+struct Counting<W> {
+    inner: W,
+    written: u64,
+}
+
+impl<W: Write> Write for Counting<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A minimal writer of MCAP files, the container format used by Foxglove and ROS2's rosbag2.
+///
+/// The file isn't indexed, which readers like Foxglove handle by scanning it. It is finished when the writer is
+/// dropped or [McapWriter::finish]ed, a file which wasn't finished e.g. because the process crashed is missing its
+/// footer, which `mcap recover` restores.
+pub struct McapWriter<W: Write> {
+    // None once finished
+    writer: Option<Counting<W>>,
+    schemas: u16,
+    // The next sequence number of each channel, indexed by id - 1
+    sequences: Vec<u32>,
+    compression: Compression,
+    chunk_size: usize,
+    chunk: Chunk,
+}
+
+impl<W: Write> McapWriter<W> {
+    /// Writes to `writer`, starting with the file's header naming the `profile` of its channels e.g. `ros1`,
+    /// which may be empty.
+    pub fn new(writer: W, profile: &str) -> io::Result<Self> {
+        let mut writer = Counting {
+            inner: writer,
+            written: 0,
+        };
+        writer.write_all(MAGIC)?;
+        let mut header = Vec::new();
+        put_string(&mut header, profile);
+        put_string(&mut header, "roslibrust");
+        put_record(&mut writer, HEADER, &header)?;
+        Ok(Self {
+            writer: Some(writer),
+            schemas: 0,
+            sequences: vec![],
+            compression: Compression::None,
+            chunk_size: 0,
+            chunk: Chunk::default(),
+        })
+    }
+
+    /// Groups messages into chunks of about `chunk_size` bytes before compression, compressed with `compression`,
+    /// instead of writing each message on its own.
+    pub fn with_chunks(mut self, compression: Compression, chunk_size: usize) -> Self {
+        self.compression = compression;
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Writes a schema, e.g. the definition of a ROS message type with the `ros1msg` encoding, returning its id.
+    pub fn add_schema(&mut self, name: &str, encoding: &str, data: &[u8]) -> io::Result<u16> {
+        let id = self
+            .schemas
+            .checked_add(1)
+            .ok_or_else(|| io::Error::other("Too many schemas for an MCAP file"))?;
+        let mut record = Vec::new();
+        record.extend_from_slice(&id.to_le_bytes());
+        put_string(&mut record, name);
+        put_string(&mut record, encoding);
+        put_bytes(&mut record, data);
+        put_record(self.writer()?, SCHEMA, &record)?;
+        self.schemas = id;
+        Ok(id)
+    }
+
+    /// Writes a channel carrying `topic`'s messages encoded with `message_encoding` e.g. `ros1`, returning its id.
+    pub fn add_channel(
+        &mut self,
+        schema_id: u16,
+        topic: &str,
+        message_encoding: &str,
+    ) -> io::Result<u16> {
+        let id = u16::try_from(self.sequences.len() + 1)
+            .map_err(|_| io::Error::other("Too many channels for an MCAP file"))?;
+        let mut record = Vec::new();
+        record.extend_from_slice(&id.to_le_bytes());
+        record.extend_from_slice(&schema_id.to_le_bytes());
+        put_string(&mut record, topic);
+        put_string(&mut record, message_encoding);
+        // No metadata
+        record.extend_from_slice(&0u32.to_le_bytes());
+        put_record(self.writer()?, CHANNEL, &record)?;
+        self.sequences.push(0);
+        Ok(id)
+    }
+
+    /// Writes a message on the channel `channel_id`, received at `log_time` and sent at `publish_time`.
+    pub fn write_message(
+        &mut self,
+        channel_id: u16,
+        log_time: SystemTime,
+        publish_time: SystemTime,
+        data: &[u8],
+    ) -> io::Result<()> {
+        let sequence = self
+            .sequences
+            .get_mut(usize::from(channel_id).wrapping_sub(1))
+            .ok_or_else(|| io::Error::other(format!("Unknown MCAP channel {channel_id}")))?;
+        let log_time = since_epoch(log_time);
+        let mut record = Vec::with_capacity(22 + data.len());
+        record.extend_from_slice(&channel_id.to_le_bytes());
+        record.extend_from_slice(&sequence.to_le_bytes());
+        record.extend_from_slice(&log_time.to_le_bytes());
+        record.extend_from_slice(&since_epoch(publish_time).to_le_bytes());
+        record.extend_from_slice(data);
+        *sequence = sequence.wrapping_add(1);
+
+        if self.chunk_size == 0 {
+            return put_record(self.writer()?, MESSAGE, &record);
+        }
+        put_record(&mut self.chunk.records, MESSAGE, &record)?;
+        self.chunk.start = self.chunk.start.min(log_time);
+        self.chunk.end = self.chunk.end.max(log_time);
+        if self.chunk.records.len() >= self.chunk_size {
+            self.write_chunk()?;
+        }
+        Ok(())
+    }
+
+    /// The size of the file so far, including messages waiting to be compressed.
+    pub fn size(&self) -> u64 {
+        self.writer.as_ref().map(|w| w.written).unwrap_or_default()
+            + self.chunk.records.len() as u64
+    }
+
+    /// Writes any buffered messages and flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.write_chunk()?;
+        self.writer()?.flush()
+    }
+
+    /// Writes the end of the file and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_end()?;
+        Ok(self.writer.take().expect("Only taken when finishing").inner)
+    }
+
+    fn write_chunk(&mut self) -> io::Result<()> {
+        if self.chunk.records.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::take(&mut self.chunk);
+        let uncompressed_size = chunk.records.len() as u64;
+        let (compression, records) = match self.compression {
+            Compression::None => ("", chunk.records),
+            #[cfg(feature = "lz4")]
+            Compression::Lz4 => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                encoder.write_all(&chunk.records)?;
+                ("lz4", encoder.finish().map_err(io::Error::other)?)
+            }
+        };
+        let mut record = Vec::with_capacity(48 + records.len());
+        record.extend_from_slice(&chunk.start.to_le_bytes());
+        record.extend_from_slice(&chunk.end.to_le_bytes());
+        record.extend_from_slice(&uncompressed_size.to_le_bytes());
+        // A crc of 0 means it wasn't computed
+        record.extend_from_slice(&0u32.to_le_bytes());
+        put_string(&mut record, compression);
+        record.extend_from_slice(&(records.len() as u64).to_le_bytes());
+        record.extend_from_slice(&records);
+        put_record(self.writer()?, CHUNK, &record)
+    }
+
+    fn write_end(&mut self) -> io::Result<()> {
+        if self.writer.is_none() {
+            return Ok(());
+        }
+        self.write_chunk()?;
+        let writer = self.writer()?;
+        // A crc of 0 means it wasn't computed, likewise for the summary which isn't written
+        put_record(writer, DATA_END, &0u32.to_le_bytes())?;
+        put_record(writer, FOOTER, &[0; 20])?;
+        writer.write_all(MAGIC)?;
+        writer.flush()
+    }
+
+    fn writer(&mut self) -> io::Result<&mut Counting<W>> {
+        self.writer
+            .as_mut()
+            .ok_or_else(|| io::Error::other("McapWriter was already finished"))
+    }
+}
+
+impl<W: Write> Drop for McapWriter<W> {
+    fn drop(&mut self) {
+        // Like BufWriter, errors can't be reported when dropped, finish reports them
+        let _ = self.write_end();
+    }
+}
+
+/// A channel of an MCAP file along with its schema, see [read_messages].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct McapChannel {
+    pub topic: String,
+    /// The encoding of the channel's messages, e.g. `ros1`
+    pub message_encoding: String,
+    /// The name of the schema, e.g. `std_msgs/String`, empty if the channel has none
+    pub schema_name: String,
+    /// The encoding of the schema, e.g. `ros1msg`, empty if the schema isn't known
+    pub schema_encoding: String,
+    /// The schema itself, e.g. the full definition of a ROS message type
+    pub schema: Vec<u8>,
+}
+
+/// A message read from an MCAP file, see [read_messages].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct McapMessage {
+    pub channel: Arc<McapChannel>,
+    pub log_time: SystemTime,
+    pub publish_time: SystemTime,
+    pub data: Vec<u8>,
+}
+
+/// Reads every message of an MCAP file, sorted by log time, e.g. to play it back.
+///
+/// The whole file is read into memory. Chunks compressed with LZ4 require the `lz4` feature, other compressions
+/// aren't supported. Indexes and the summary aren't needed, so unfinished files can be read too.
+pub fn read_messages(mut reader: impl Read) -> io::Result<Vec<McapMessage>> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    let records = data
+        .strip_prefix(MAGIC)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Not an MCAP file"))?;
+    let mut state = ReadState::default();
+    state.read_records(records)?;
+    // The sort is stable, so messages logged at the same time stay in the order they were written
+    state.messages.sort_by_key(|message| message.log_time);
+    Ok(state.messages)
+}
+
+/// The schemas and channels seen so far while reading a file, and its messages
+#[derive(Default)]
+struct ReadState {
+    // Name, encoding and data by id
+    schemas: HashMap<u16, (String, String, Vec<u8>)>,
+    channels: HashMap<u16, Arc<McapChannel>>,
+    messages: Vec<McapMessage>,
+}
+
+impl ReadState {
+    fn read_records(&mut self, mut records: &[u8]) -> io::Result<()> {
+        // A file ends with its magic, which isn't a complete record
+        while records.len() >= 9 {
+            let mut header = Fields(&records[..9]);
+            let opcode = header.u8()?;
+            let length = usize::try_from(header.u64()?).map_err(invalid)?;
+            let body = records[9..].get(..length).ok_or_else(|| {
+                // Unfinished files may end in the middle of a record
+                io::Error::new(io::ErrorKind::UnexpectedEof, "Truncated MCAP record")
+            })?;
+            records = &records[9 + length..];
+            let mut fields = Fields(body);
+            match opcode {
+                SCHEMA => {
+                    let id = fields.u16()?;
+                    let name = fields.string()?;
+                    let encoding = fields.string()?;
+                    let data = fields.bytes()?.to_vec();
+                    self.schemas.insert(id, (name, encoding, data));
+                }
+                CHANNEL => {
+                    let id = fields.u16()?;
+                    let schema_id = fields.u16()?;
+                    let topic = fields.string()?;
+                    let message_encoding = fields.string()?;
+                    let (schema_name, schema_encoding, schema) =
+                        self.schemas.get(&schema_id).cloned().unwrap_or_default();
+                    self.channels.insert(
+                        id,
+                        Arc::new(McapChannel {
+                            topic,
+                            message_encoding,
+                            schema_name,
+                            schema_encoding,
+                            schema,
+                        }),
+                    );
+                }
+                MESSAGE => {
+                    let channel_id = fields.u16()?;
+                    let _sequence = fields.u32()?;
+                    let log_time = fields.u64()?;
+                    let publish_time = fields.u64()?;
+                    let channel = self.channels.get(&channel_id).ok_or_else(|| {
+                        invalid(format!("Message on unknown MCAP channel {channel_id}"))
+                    })?;
+                    self.messages.push(McapMessage {
+                        channel: channel.clone(),
+                        log_time: UNIX_EPOCH + Duration::from_nanos(log_time),
+                        publish_time: UNIX_EPOCH + Duration::from_nanos(publish_time),
+                        data: fields.0.to_vec(),
+                    });
+                }
+                CHUNK => {
+                    let _start = fields.u64()?;
+                    let _end = fields.u64()?;
+                    let uncompressed_size = fields.u64()?;
+                    let _crc = fields.u32()?;
+                    let compression = fields.string()?;
+                    let length = usize::try_from(fields.u64()?).map_err(invalid)?;
+                    let compressed = fields.take(length)?;
+                    match compression.as_str() {
+                        "" => self.read_records(compressed)?,
+                        #[cfg(feature = "lz4")]
+                        "lz4" => {
+                            let mut records = Vec::with_capacity(uncompressed_size as usize);
+                            lz4_flex::frame::FrameDecoder::new(compressed)
+                                .read_to_end(&mut records)?;
+                            self.read_records(&records)?;
+                        }
+                        other => {
+                            return Err(invalid(format!(
+                                "Unsupported MCAP chunk compression {other:?}, {uncompressed_size} bytes skipped"
+                            )))
+                        }
+                    }
+                }
+                // Everything after the data is the summary, which isn't needed
+                DATA_END | FOOTER => break,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+/// The fields of a record, read from the front
+struct Fields<'a>(&'a [u8]);
+
+impl<'a> Fields<'a> {
+    fn take(&mut self, length: usize) -> io::Result<&'a [u8]> {
+        if self.0.len() < length {
+            return Err(invalid("MCAP record is shorter than its fields"));
+        }
+        let (field, rest) = self.0.split_at(length);
+        self.0 = rest;
+        Ok(field)
+    }
+
+    fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn bytes(&mut self) -> io::Result<&'a [u8]> {
+        let length = self.u32()? as usize;
+        self.take(length)
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        String::from_utf8(self.bytes()?.to_vec()).map_err(invalid)
+    }
+}
+
+fn since_epoch(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_nanos() as u64
+}
+
+fn put_string(buf: &mut Vec<u8>, value: &str) {
+    put_bytes(buf, value.as_bytes());
+}
+
+fn put_bytes(buf: &mut Vec<u8>, value: &[u8]) {
+    buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buf.extend_from_slice(value);
+}
+
+fn put_record(writer: &mut impl Write, opcode: u8, body: &[u8]) -> io::Result<()> {
+    writer.write_all(&[opcode])?;
+    writer.write_all(&(body.len() as u64).to_le_bytes())?;
+    writer.write_all(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(mut data: &[u8]) -> Vec<(u8, &[u8])> {
+        let mut records = vec![];
+        while !data.is_empty() {
+            let opcode = data[0];
+            let len = u64::from_le_bytes(data[1..9].try_into().unwrap()) as usize;
+            records.push((opcode, &data[9..9 + len]));
+            data = &data[9 + len..];
+        }
+        records
+    }
+
+    #[test]
+    fn writes_chunks() {
+        let mut writer = McapWriter::new(Vec::new(), "ros1")
+            .unwrap()
+            .with_chunks(Compression::None, 1);
+        let schema = writer
+            .add_schema("std_msgs/UInt8", "ros1msg", b"uint8 data")
+            .unwrap();
+        let channel = writer.add_channel(schema, "/count", "ros1").unwrap();
+        let time = UNIX_EPOCH + Duration::from_secs(2);
+        writer.write_message(channel, time, time, &[7]).unwrap();
+        // Unknown channels are rejected
+        assert!(writer.write_message(9, time, time, &[7]).is_err());
+        let data = writer.finish().unwrap();
+
+        let body = &data[MAGIC.len()..data.len() - MAGIC.len()];
+        let opcodes: Vec<_> = records(body).iter().map(|(opcode, _)| *opcode).collect();
+        assert_eq!(opcodes, [HEADER, SCHEMA, CHANNEL, CHUNK, DATA_END, FOOTER]);
+        let chunk = records(body)[3].1;
+        assert_eq!(&chunk[0..8], &2_000_000_000u64.to_le_bytes());
+        let messages = records(&chunk[40..]);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].0, MESSAGE);
+        assert_eq!(&messages[0].1[22..], &[7]);
+    }
+
+    #[test]
+    fn reads_what_it_writes() {
+        let mut writer = McapWriter::new(Vec::new(), "ros1")
+            .unwrap()
+            .with_chunks(Compression::None, 64);
+        let schema = writer
+            .add_schema("std_msgs/UInt8", "ros1msg", b"uint8 data")
+            .unwrap();
+        let count = writer.add_channel(schema, "/count", "ros1").unwrap();
+        let raw = writer.add_channel(0, "/raw", "").unwrap();
+        let time = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+        writer.write_message(count, time(2), time(1), &[2]).unwrap();
+        writer.write_message(raw, time(1), time(1), &[1]).unwrap();
+        writer.write_message(count, time(3), time(3), &[3]).unwrap();
+        let data = writer.finish().unwrap();
+
+        let messages = read_messages(&data[..]).unwrap();
+        let read: Vec<_> = messages
+            .iter()
+            .map(|m| (m.channel.topic.as_str(), m.log_time, m.data.as_slice()))
+            .collect();
+        // Sorted by log time
+        assert_eq!(
+            read,
+            [
+                ("/raw", time(1), &[1][..]),
+                ("/count", time(2), &[2][..]),
+                ("/count", time(3), &[3][..])
+            ]
+        );
+        assert_eq!(messages[1].publish_time, time(1));
+        assert_eq!(messages[1].channel.schema_name, "std_msgs/UInt8");
+        assert_eq!(messages[1].channel.schema, b"uint8 data");
+        assert_eq!(messages[0].channel.schema_name, "");
+
+        assert!(read_messages(&b"not an mcap file"[..]).is_err());
+    }
+}
@@ -11,24 +11,62 @@ pub fn from_message_definition(msg_name: &str, full_def: &str) -> Result<String,
         bail!("empty input definition");
     }
 
+    let sub_messages = split_definition(msg_name, full_def)?;
+    if sub_messages[msg_name].is_empty() {
+        bail!("empty cleaned root definition");
+    }
+
+    // TODO MAJOR(carter): I'd like to convert this loop to a recursive function where we pass in the map of hashes
+    // and update them as we go, this tripple loop is stinky to my eye.
+    // TODO(carter) we should be able to do this in close to one pass if we iterate the full_def backwards
+    let mut hashed = HashMap::new();
+    let hash = message_definition_to_md5sum_recursive(msg_name, &sub_messages, &mut hashed)?;
+
+    Ok(hash)
+}
+
+/// Calculates the md5sum of a service from the expanded definitions of its request and response,
+/// each in the `gendeps --cat` format taken by [from_message_definition].
+/// Unlike messages either may be empty, as is the request of std_srvs/Trigger.
+pub fn from_service_definition(
+    srv_name: &str,
+    request_def: &str,
+    response_def: &str,
+) -> Result<String, Error> {
+    let request = expanded_definition(&format!("{srv_name}Request"), request_def)?;
+    let response = expanded_definition(&format!("{srv_name}Response"), response_def)?;
+    let mut md5sum_context = md5::Context::new();
+    md5sum_context.consume(request.trim_end().as_bytes());
+    md5sum_context.consume(response.trim_end().as_bytes());
+    Ok(format!("{:x}", md5sum_context.compute()))
+}
+
+/// Returns the text hashed into the md5sum of a message, the root definition with the types of its fields
+/// replaced by their md5sums. Empty definitions are allowed, giving an empty text.
+pub(crate) fn expanded_definition(msg_name: &str, full_def: &str) -> Result<String, Error> {
+    let sub_messages = split_definition(msg_name, full_def)?;
+    let mut hashed = HashMap::new();
+    expand_definition_recursive(msg_name, &sub_messages, &mut hashed)
+}
+
+/// Splits an expanded message definition into the cleaned definitions of the message and each of its dependencies
+fn split_definition<'a>(
+    msg_name: &'a str,
+    full_def: &'a str,
+) -> Result<HashMap<&'a str, String>, Error> {
     // Split the full definition into sections per message
     let sep: &str =
         "================================================================================\n";
     let sections = full_def.split(sep).collect::<Vec<&str>>();
     if sections.is_empty() {
-        // Carter: this error is impossible, split only gives empty iterator when input string is empty
-        // which we've already checked for above
+        // Carter: this error is impossible, split always gives at least one item, even for an empty string
         bail!("empty sections");
     }
 
     // Split the overall definition into separate sub-messages sorted by message type (including package name)
     let mut sub_messages: HashMap<&str, String> = HashMap::new();
     // Note: the first section doesn't contain the "MSG: <type>" line so we don't need to strip it here
-    let clean_root = clean_msg(sections[0]);
-    if clean_root.is_empty() {
-        bail!("empty cleaned root definition");
-    }
-    sub_messages.insert(msg_name, clean_root);
+    sub_messages.insert(msg_name, clean_msg(sections[0]));
 
     for section in &sections[1..] {
         let line0 = section.lines().next().ok_or(anyhow!("empty section"))?;
@@ -48,13 +86,7 @@ pub fn from_message_definition(msg_name: &str, full_def: &str) -> Result<String,
         sub_messages.insert(section_type, body);
     }
 
-    // TODO MAJOR(carter): I'd like to convert this loop to a recursive function where we pass in the map of hashes
-    // and update them as we go, this tripple loop is stinky to my eye.
-    // TODO(carter) we should be able to do this in close to one pass if we iterate the full_def backwards
-    let mut hashed = HashMap::new();
-    let hash = message_definition_to_md5sum_recursive(msg_name, &sub_messages, &mut hashed)?;
-
-    Ok(hash)
+    Ok(sub_messages)
 }
 
 /// Calculates the hash of the specified message type by recursively calling itself on all dependencies
@@ -64,6 +96,22 @@ fn message_definition_to_md5sum_recursive(
     msg_type: &str,
     defs: &HashMap<&str, String>,
     hashes: &mut HashMap<String, String>,
+) -> Result<String, Error> {
+    let field_def = expand_definition_recursive(msg_type, defs, hashes)?;
+    let md5sum = md5::compute(field_def.trim_end().as_bytes());
+    let md5sum_text = format!("{md5sum:x}");
+    // Insert our hash into the cache before we return
+    hashes.insert(msg_type.to_string(), md5sum_text.clone());
+
+    Ok(md5sum_text)
+}
+
+/// Replaces the types of the fields of the specified message type with their hashes, giving the text that is hashed
+/// Uses the same defs and hashes as [message_definition_to_md5sum_recursive]
+fn expand_definition_recursive(
+    msg_type: &str,
+    defs: &HashMap<&str, String>,
+    hashes: &mut HashMap<String, String>,
 ) -> Result<String, Error> {
     let base_types: HashSet<String> = HashSet::from_iter(
         [
@@ -116,13 +164,7 @@ fn message_definition_to_md5sum_recursive(
         }
         field_def += &format!("{line}\n");
     }
-    field_def = field_def.trim().to_string();
-    let md5sum = md5::compute(field_def.trim_end().as_bytes());
-    let md5sum_text = format!("{md5sum:x}");
-    // Insert our hash into the cache before we return
-    hashes.insert(msg_type.to_string(), md5sum_text.clone());
-
-    Ok(md5sum_text)
+    Ok(field_def.trim().to_string())
 }
 
 /// Taking in a message definition
@@ -728,6 +770,31 @@ uint32 count     # How many elements in the field
         }
     }
 
+    /// Confirm service md5sums match the ones generated for std_srvs and roscpp_tutorials
+    #[test]
+    fn srv_def_to_md5() {
+        let md5sum =
+            from_service_definition("std_srvs/Trigger", "", "bool success\nstring message")
+                .unwrap();
+        assert_eq!(md5sum, "937c9679a518e3a18d831e57125ea522");
+
+        let md5sum = from_service_definition(
+            "std_srvs/SetBool",
+            "bool data # e.g. for hardware enabling / disabling",
+            "bool success   # indicate successful run of triggered service\nstring message # informational, e.g. for error messages",
+        )
+        .unwrap();
+        assert_eq!(md5sum, "09fb03525b03e7ea1fd3992bafd87e16");
+
+        let md5sum =
+            from_service_definition("roscpp_tutorials/TwoInts", "int64 a\nint64 b", "int64 sum")
+                .unwrap();
+        assert_eq!(md5sum, "6a2e34150c00229791cc89ff309fff21");
+
+        // Dependencies of the request have to be included
+        from_service_definition("fake_srvs/Missing", "Point point", "").unwrap_err();
+    }
+
     // Basic test of clean_msg function
     #[test]
     fn clean_msg_test() {
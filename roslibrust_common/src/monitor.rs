@@ -0,0 +1,439 @@
+use crate::{ClockProvider, Result, RosMessageType, Subscribe};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Measures the traffic on a topic the way `rostopic hz` and `rostopic bw` do: the rate, the jitter of the time
+/// between messages, the bandwidth, and the number of messages dropped, as well as their latency if it is recorded.
+///
+/// The monitor doesn't subscribe by itself, each message received is passed to [TopicMonitor::record] with its size
+/// in bytes, so it works with typed subscribers as well as raw ones. [MonitoredSubscriber] does this for any
+/// [Subscribe]. Statistics are computed over the messages received within the window, 10 seconds by default.
+#[derive(Clone, Debug)]
+pub struct TopicMonitor {
+    window: Duration,
+    // When each message within the window was received, and its size
+    samples: VecDeque<(Instant, usize)>,
+    // When each latency within the window was recorded, and the latency
+    latencies: VecDeque<(Instant, Duration)>,
+    messages: u64,
+    bytes: u64,
+    dropped: u64,
+    last_sequence: Option<u32>,
+}
+
+impl Default for TopicMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TopicMonitor {
+    /// Creates a monitor with a 10 second window.
+    pub fn new() -> Self {
+        Self::with_window(Duration::from_secs(10))
+    }
+
+    /// Creates a monitor computing its statistics over the messages received within `window`.
+    pub fn with_window(window: Duration) -> Self {
+        Self {
+            window,
+            samples: VecDeque::new(),
+            latencies: VecDeque::new(),
+            messages: 0,
+            bytes: 0,
+            dropped: 0,
+            last_sequence: None,
+        }
+    }
+
+    /// Records a message of `size` bytes received now.
+    pub fn record(&mut self, size: usize) {
+        self.record_at(Instant::now(), size)
+    }
+
+    /// Records a message of `size` bytes received at `at`, which must not be earlier than the previous message.
+    pub fn record_at(&mut self, at: Instant, size: usize) {
+        self.messages += 1;
+        self.bytes += size as u64;
+        self.samples.push_back((at, size));
+        self.prune(at);
+    }
+
+    /// Records the sequence number of a message, e.g. the `seq` of a ROS1 `std_msgs/Header`, counting the numbers
+    /// skipped since the previous one as dropped. A number lower than the previous one is taken as the publisher
+    /// restarting.
+    pub fn record_sequence(&mut self, sequence: u32) {
+        if let Some(last) = self.last_sequence {
+            if sequence > last {
+                self.dropped += u64::from(sequence - last - 1);
+            }
+        }
+        self.last_sequence = Some(sequence);
+    }
+
+    /// Records the latency of a message received now, e.g. the time since the stamp of its header.
+    pub fn record_latency(&mut self, latency: Duration) {
+        self.record_latency_at(Instant::now(), latency)
+    }
+
+    /// Records the latency of a message received at `at`, which must not be earlier than the previous latency.
+    pub fn record_latency_at(&mut self, at: Instant, latency: Duration) {
+        self.latencies.push_back((at, latency));
+        self.prune(at);
+    }
+
+    /// Records the latency of a message received now at ROS time `received` with the header stamp `stamp`.
+    /// Both must come from the same clock, e.g. [ClockProvider::now] which follows `/clock` in simulation.
+    ///
+    /// Stamps later than `received` aren't recorded, they result from clocks of different machines disagreeing,
+    /// or from a simulation's clock being reset.
+    pub fn record_stamp(&mut self, received: SystemTime, stamp: SystemTime) {
+        if let Ok(latency) = received.duration_since(stamp) {
+            self.record_latency(latency);
+        }
+    }
+
+    /// Returns the latency below which the fraction `quantile` of the latencies within the window are, e.g. 0.99
+    /// for the 99th percentile. None if no latencies were recorded within the window.
+    pub fn latency_percentile(&self, quantile: f64) -> Option<Duration> {
+        let now = Instant::now();
+        let mut latencies = self.window_latencies(now);
+        latencies.sort_unstable();
+        percentile(&latencies, quantile)
+    }
+
+    fn window_latencies(&self, now: Instant) -> Vec<Duration> {
+        self.latencies
+            .iter()
+            .filter(|(at, _)| now.saturating_duration_since(*at) <= self.window)
+            .map(|(_, latency)| *latency)
+            .collect()
+    }
+
+    /// Counts messages known to be dropped, e.g. reported by a subscriber which fell behind.
+    pub fn record_dropped(&mut self, count: u64) {
+        self.dropped += count;
+    }
+
+    /// Forgets every message recorded so far.
+    pub fn reset(&mut self) {
+        *self = Self::with_window(self.window);
+    }
+
+    /// Returns the statistics as of now.
+    pub fn stats(&self) -> TopicStats {
+        self.stats_at(Instant::now())
+    }
+
+    /// Returns the statistics as of `now`, only considering the messages received within the window before it.
+    pub fn stats_at(&self, now: Instant) -> TopicStats {
+        let samples: Vec<_> = self
+            .samples
+            .iter()
+            .filter(|(at, _)| now.saturating_duration_since(*at) <= self.window)
+            .collect();
+        let periods: Vec<Duration> = samples
+            .windows(2)
+            .map(|pair| pair[1].0.saturating_duration_since(pair[0].0))
+            .collect();
+        let span = match (samples.first(), samples.last()) {
+            (Some((first, _)), Some((last, _))) => last.saturating_duration_since(*first),
+            _ => Duration::ZERO,
+        };
+
+        let mut stats = TopicStats {
+            messages: self.messages,
+            bytes: self.bytes,
+            dropped: self.dropped,
+            window_messages: samples.len(),
+            since_last: self
+                .samples
+                .back()
+                .map(|(at, _)| now.saturating_duration_since(*at)),
+            ..Default::default()
+        };
+        if !samples.is_empty() {
+            let window_bytes: usize = samples.iter().map(|(_, size)| size).sum();
+            stats.mean_size = window_bytes as f64 / samples.len() as f64;
+        }
+        let mut latencies = self.window_latencies(now);
+        latencies.sort_unstable();
+        if !latencies.is_empty() {
+            stats.mean_latency = Some(latencies.iter().sum::<Duration>() / latencies.len() as u32);
+            stats.max_latency = latencies.last().copied();
+            stats.latency_p50 = percentile(&latencies, 0.5);
+            stats.latency_p90 = percentile(&latencies, 0.9);
+            stats.latency_p99 = percentile(&latencies, 0.99);
+        }
+        if !periods.is_empty() && !span.is_zero() {
+            // Like rostopic, the first message of the window only marks its start
+            let span = span.as_secs_f64();
+            let mean = span / periods.len() as f64;
+            let variance = periods
+                .iter()
+                .map(|period| (period.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / periods.len() as f64;
+            let window_bytes: usize = samples[1..].iter().map(|(_, size)| size).sum();
+            stats.rate_hz = periods.len() as f64 / span;
+            stats.bandwidth = window_bytes as f64 / span;
+            stats.min_period = periods.iter().min().copied();
+            stats.max_period = periods.iter().max().copied();
+            stats.mean_period = Some(Duration::from_secs_f64(mean));
+            stats.jitter = Some(Duration::from_secs_f64(variance.sqrt()));
+        }
+        stats
+    }
+
+    /// Drops the samples which have left the window
+    fn prune(&mut self, now: Instant) {
+        while self
+            .samples
+            .front()
+            .is_some_and(|(at, _)| now.saturating_duration_since(*at) > self.window)
+        {
+            self.samples.pop_front();
+        }
+        while self
+            .latencies
+            .front()
+            .is_some_and(|(at, _)| now.saturating_duration_since(*at) > self.window)
+        {
+            self.latencies.pop_front();
+        }
+    }
+}
+
+/// The nearest rank percentile of sorted values
+fn percentile(sorted: &[Duration], quantile: f64) -> Option<Duration> {
+    let rank = (quantile.clamp(0.0, 1.0) * sorted.len() as f64).ceil() as usize;
+    sorted.get(rank.saturating_sub(1)).copied()
+}
+
+/// Converts the stamp of a header to a [SystemTime], e.g. `stamp_time(stamp.secs, stamp.nsecs)` for ROS1 and
+/// `stamp_time(stamp.sec, stamp.nanosec)` for ROS2. Returns None for stamps of zero, which mean the message
+/// wasn't stamped, and for negative stamps.
+pub fn stamp_time(secs: impl Into<i64>, nsecs: impl Into<i64>) -> Option<SystemTime> {
+    let (secs, nsecs) = (secs.into(), nsecs.into());
+    if secs < 0 || nsecs < 0 || (secs == 0 && nsecs == 0) {
+        return None;
+    }
+    Some(
+        SystemTime::UNIX_EPOCH
+            + Duration::from_secs(secs as u64)
+            + Duration::from_nanos(nsecs as u64),
+    )
+}
+
+/// The statistics of a [TopicMonitor]. Rates and periods are computed from the messages within the window,
+/// and are zero or None when fewer than two were received in it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TopicStats {
+    /// Messages received since the monitor was created
+    pub messages: u64,
+    /// Bytes received since the monitor was created
+    pub bytes: u64,
+    /// Messages dropped since the monitor was created, see [TopicMonitor::record_sequence]
+    pub dropped: u64,
+    /// Messages received within the window
+    pub window_messages: usize,
+    /// Messages per second
+    pub rate_hz: f64,
+    /// Bytes per second
+    pub bandwidth: f64,
+    /// Average size of the messages in bytes
+    pub mean_size: f64,
+    pub min_period: Option<Duration>,
+    pub max_period: Option<Duration>,
+    pub mean_period: Option<Duration>,
+    /// Standard deviation of the time between messages
+    pub jitter: Option<Duration>,
+    /// Time since the last message was received, None if none were
+    pub since_last: Option<Duration>,
+    /// Average of the latencies recorded within the window, see [TopicMonitor::record_latency]
+    pub mean_latency: Option<Duration>,
+    pub max_latency: Option<Duration>,
+    /// Median of the latencies recorded within the window
+    pub latency_p50: Option<Duration>,
+    pub latency_p90: Option<Duration>,
+    pub latency_p99: Option<Duration>,
+}
+
+/// A [Subscribe] which records every message it receives in a [TopicMonitor].
+///
+/// The size of typed messages isn't known after they are deserialized, by default it is measured by encoding them
+/// again with [RosMessageType::ros1_encode], or counted as zero for types without a ROS1 codec.
+/// [MonitoredSubscriber::with_size] replaces this with a cheaper estimate.
+pub struct MonitoredSubscriber<S, T> {
+    inner: S,
+    monitor: TopicMonitor,
+    size: fn(&T) -> usize,
+    latency: Option<Latency<T>>,
+}
+
+/// How [MonitoredSubscriber] measures latency, see [MonitoredSubscriber::with_latency]
+struct Latency<T> {
+    now: Box<dyn Fn() -> SystemTime + Send + Sync>,
+    stamp: fn(&T) -> Option<SystemTime>,
+}
+
+impl<S: Subscribe<T>, T: RosMessageType> MonitoredSubscriber<S, T> {
+    /// Wraps `inner`, recording its messages in a monitor with a 10 second window.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            monitor: TopicMonitor::new(),
+            size: |msg| msg.ros1_encode().map_or(0, |bytes| bytes.len()),
+            latency: None,
+        }
+    }
+
+    /// Replaces the monitor, e.g. to change its window.
+    pub fn with_monitor(mut self, monitor: TopicMonitor) -> Self {
+        self.monitor = monitor;
+        self
+    }
+
+    /// Replaces how the size of each message is measured.
+    pub fn with_size(mut self, size: fn(&T) -> usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Records the latency of each message, the time `clock` received it at minus the stamp `stamp` returns,
+    /// typically with [stamp_time] from its header. Messages for which `stamp` returns None aren't measured.
+    ///
+    /// Pass the backend as `clock` so latencies are measured against simulated time while `/use_sim_time` is set.
+    pub fn with_latency(
+        mut self,
+        clock: impl ClockProvider + Send + Sync + 'static,
+        stamp: fn(&T) -> Option<SystemTime>,
+    ) -> Self {
+        self.latency = Some(Latency {
+            now: Box::new(move || clock.now()),
+            stamp,
+        });
+        self
+    }
+
+    /// The statistics of the messages received so far.
+    pub fn stats(&self) -> TopicStats {
+        self.monitor.stats()
+    }
+
+    /// The monitor messages are recorded in, e.g. to [TopicMonitor::record_sequence] of received messages.
+    pub fn monitor_mut(&mut self) -> &mut TopicMonitor {
+        &mut self.monitor
+    }
+
+    /// Returns the wrapped subscriber.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Subscribe<T> + Send, T: RosMessageType> Subscribe<T> for MonitoredSubscriber<S, T> {
+    async fn next(&mut self) -> Result<T> {
+        let msg = self.inner.next().await?;
+        self.monitor.record((self.size)(&msg));
+        if let Some(latency) = &self.latency {
+            if let Some(stamp) = (latency.stamp)(&msg) {
+                self.monitor.record_stamp((latency.now)(), stamp);
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_rate_and_bandwidth() {
+        let start = Instant::now();
+        let mut monitor = TopicMonitor::with_window(Duration::from_secs(1));
+        assert_eq!(monitor.stats_at(start).rate_hz, 0.0);
+
+        // 10Hz with one late message
+        for (i, ms) in [0, 100, 200, 300, 450, 500].into_iter().enumerate() {
+            monitor.record_at(start + Duration::from_millis(ms), 100);
+            monitor.record_sequence(i as u32 * 2);
+        }
+        let stats = monitor.stats_at(start + Duration::from_millis(500));
+        assert_eq!(stats.messages, 6);
+        assert_eq!(stats.bytes, 600);
+        assert_eq!(stats.window_messages, 6);
+        assert!((stats.rate_hz - 10.0).abs() < 1e-9);
+        assert!((stats.bandwidth - 1000.0).abs() < 1e-9);
+        assert_eq!(stats.mean_size, 100.0);
+        assert_eq!(stats.min_period, Some(Duration::from_millis(50)));
+        assert_eq!(stats.max_period, Some(Duration::from_millis(150)));
+        assert!(stats.jitter.unwrap() > Duration::ZERO);
+        assert_eq!(stats.dropped, 5);
+        assert_eq!(stats.since_last, Some(Duration::ZERO));
+        assert_eq!(stats.mean_latency, None);
+        monitor.record_latency_at(
+            start + Duration::from_millis(500),
+            Duration::from_millis(10),
+        );
+        monitor.record_latency_at(
+            start + Duration::from_millis(500),
+            Duration::from_millis(30),
+        );
+        let stats = monitor.stats_at(start + Duration::from_millis(500));
+        assert_eq!(stats.mean_latency, Some(Duration::from_millis(20)));
+        assert_eq!(stats.max_latency, Some(Duration::from_millis(30)));
+        assert_eq!(stats.latency_p50, Some(Duration::from_millis(10)));
+        assert_eq!(stats.latency_p99, Some(Duration::from_millis(30)));
+
+        // Messages older than the window are no longer considered
+        let stats = monitor.stats_at(start + Duration::from_millis(1350));
+        assert_eq!(stats.window_messages, 2);
+        assert_eq!(stats.messages, 6);
+        let stats = monitor.stats_at(start + Duration::from_secs(3));
+        assert_eq!(stats.window_messages, 0);
+        assert_eq!(stats.rate_hz, 0.0);
+        assert_eq!(stats.since_last, Some(Duration::from_millis(2500)));
+        assert_eq!(stats.max_latency, None);
+
+        // A publisher restarting isn't counted as drops
+        monitor.record_sequence(0);
+        monitor.record_sequence(1);
+        assert_eq!(monitor.stats_at(start).dropped, 5);
+        monitor.reset();
+        assert_eq!(monitor.stats_at(start), TopicStats::default());
+    }
+
+    #[test]
+    fn measures_latency_percentiles() {
+        let mut monitor = TopicMonitor::new();
+        let received = stamp_time(100, 0).unwrap();
+        for ms in 1..=100 {
+            monitor.record_stamp(received, received - Duration::from_millis(ms));
+        }
+        // Stamped after it was received, e.g. by a node with a clock ahead of ours
+        monitor.record_stamp(received, received + Duration::from_millis(5));
+
+        let stats = monitor.stats();
+        assert_eq!(stats.latency_p50, Some(Duration::from_millis(50)));
+        assert_eq!(stats.latency_p90, Some(Duration::from_millis(90)));
+        assert_eq!(stats.latency_p99, Some(Duration::from_millis(99)));
+        assert_eq!(stats.max_latency, Some(Duration::from_millis(100)));
+        assert_eq!(
+            monitor.latency_percentile(0.25),
+            Some(Duration::from_millis(25))
+        );
+        assert_eq!(
+            monitor.latency_percentile(0.0),
+            Some(Duration::from_millis(1))
+        );
+
+        assert_eq!(stamp_time(0, 0), None);
+        assert_eq!(stamp_time(-1, 0), None);
+        assert_eq!(
+            stamp_time(1i32, 500_000_000u32),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_millis(1500))
+        );
+    }
+}
@@ -0,0 +1,438 @@
+//! Validated ROS graph names used for topics and services.
+//!
+//! See <https://wiki.ros.org/Names> for the naming rules these types enforce.
+//!
+//! All of the generic traits in this crate accept anything implementing [ToTopicName] or [ToServiceName],
+//! which is implemented for `&str` and `String`, so existing code passing string literals continues to work,
+//! while invalid names are rejected up front with [Error::InvalidName] instead of failing deep in a backend.
+
+use crate::{Error, Result};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// Checks that a name satisfies the ROS graph naming rules:
+///  - First character is an alpha character, tilde (~) or forward slash (/)
+///  - Subsequent characters are alphanumeric, underscores (_) or forward slashes (/)
+///  - No empty segments (e.g. "a//b") and no trailing slash
+fn validate(name: &str) -> Result<()> {
+    let invalid = |reason: &str| Err(Error::InvalidName(format!("{name:?} {reason}")));
+
+    let mut chars = name.chars();
+    match chars.next() {
+        None => return invalid("is empty"),
+        Some(c) if c.is_ascii_alphabetic() || c == '/' || c == '~' => {}
+        Some(_) => return invalid("must start with a letter, '/' or '~'"),
+    }
+    if let Some(c) = chars.find(|c| !(c.is_ascii_alphanumeric() || *c == '_' || *c == '/')) {
+        return invalid(&format!("contains invalid character {c:?}"));
+    }
+
+    // A private name may optionally be written as "~/name"
+    let body = name
+        .strip_prefix('~')
+        .map(|rest| rest.strip_prefix('/').unwrap_or(rest))
+        .unwrap_or(name);
+    let body = body.strip_prefix('/').unwrap_or(body);
+    if body.is_empty() {
+        return invalid("has no name after its prefix");
+    }
+    if body.split('/').any(|segment| segment.is_empty()) {
+        return invalid("contains an empty segment or trailing '/'");
+    }
+    Ok(())
+}
+
+/// Resolves a (valid) name to a global name.
+///  - Global names ("/foo") are returned unchanged
+///  - Relative names ("foo") are resolved in the namespace of the node
+///  - Private names ("~foo") are resolved inside the node's own name
+///
+/// `node_name` is the fully qualified name of the node e.g. "/wg/node2".
+/// If `node_name` is None there is no node to resolve private names against and they are rejected.
+fn resolve(name: &str, node_name: Option<&str>) -> Result<String> {
+    if name.starts_with('/') {
+        return Ok(name.to_string());
+    }
+    if let Some(private) = name.strip_prefix('~') {
+        let private = private.strip_prefix('/').unwrap_or(private);
+        return match node_name {
+            Some(node_name) => Ok(format!("{}/{private}", node_name.trim_end_matches('/'))),
+            None => Err(Error::InvalidName(format!(
+                "Private name {name:?} can't be resolved without a node name"
+            ))),
+        };
+    }
+    let namespace = node_name
+        .and_then(|node_name| node_name.rsplit_once('/'))
+        .map(|(namespace, _base)| namespace)
+        .unwrap_or("");
+    Ok(format!("{namespace}/{name}"))
+}
+
+macro_rules! graph_name {
+    ($(#[$attr:meta])* $name:ident, $kind:literal) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name {
+            inner: String,
+        }
+
+        impl $name {
+            #[doc = concat!("Creates a new ", $kind, " name, returning [Error::InvalidName] if it does not meet ROS naming rules.")]
+            pub fn new(name: impl Into<String>) -> Result<Self> {
+                let name = name.into();
+                validate(&name)?;
+                Ok(Self { inner: name })
+            }
+
+            /// Returns the name as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.inner
+            }
+
+            /// True if this is a global name (starts with '/').
+            pub fn is_global(&self) -> bool {
+                self.inner.starts_with('/')
+            }
+
+            /// True if this is a private name (starts with '~').
+            pub fn is_private(&self) -> bool {
+                self.inner.starts_with('~')
+            }
+
+            /// Resolves this name to a global name relative to the fully qualified name of a node e.g. "/wg/node2".
+            ///
+            /// Examples pulled from <http://wiki.ros.org/Names>, for node "/wg/node2":
+            ///  - "bar" -> "/wg/bar"
+            ///  - "/bar" -> "/bar"
+            ///  - "~bar" -> "/wg/node2/bar"
+            pub fn resolve_to_global(&self, node_name: &str) -> Result<Self> {
+                let node_name = if node_name.starts_with('/') {
+                    node_name.to_string()
+                } else {
+                    format!("/{node_name}")
+                };
+                Self::new(resolve(&self.inner, Some(&node_name))?)
+            }
+
+            /// Resolves this name to a global name for backends that have no concept of a node.
+            ///
+            /// Relative names are resolved in the root namespace ("bar" -> "/bar"), and private names are rejected.
+            pub fn resolve_to_root(&self) -> Result<Self> {
+                Self::new(resolve(&self.inner, None)?)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.inner.fmt(f)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.inner
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = Error;
+            fn from_str(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = Error;
+            fn try_from(value: &str) -> Result<Self> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = Error;
+            fn try_from(value: String) -> Result<Self> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> String {
+                value.inner
+            }
+        }
+    };
+}
+
+graph_name!(
+    /// A validated ROS topic name.
+    ///
+    /// The name may be global ("/foo"), relative ("foo") or private ("~foo"),
+    /// backends are responsible for resolving it with [TopicName::resolve_to_global] or [TopicName::resolve_to_root].
+    TopicName,
+    "topic"
+);
+
+graph_name!(
+    /// A validated ROS service name.
+    ///
+    /// The name may be global ("/foo"), relative ("foo") or private ("~foo"),
+    /// backends are responsible for resolving it with [ServiceName::resolve_to_global] or [ServiceName::resolve_to_root].
+    ServiceName,
+    "service"
+);
+
+graph_name!(
+    /// A validated ROS parameter name.
+    ///
+    /// The name may be global ("/foo"), relative ("foo") or private ("~foo"),
+    /// backends are responsible for resolving it with [ParamName::resolve_to_global] or [ParamName::resolve_to_root].
+    ParamName,
+    "parameter"
+);
+
+/// Conversion into a [TopicName], this is the argument type accepted by [crate::TopicProvider].
+///
+/// This is implemented for `&str` and `String` so string literals can be used directly.
+pub trait ToTopicName {
+    fn to_topic_name(self) -> Result<TopicName>;
+}
+
+/// Conversion into a [ServiceName], this is the argument type accepted by [crate::ServiceProvider].
+///
+/// This is implemented for `&str` and `String` so string literals can be used directly.
+pub trait ToServiceName {
+    fn to_service_name(self) -> Result<ServiceName>;
+}
+
+/// Conversion into a [ParamName], this is the argument type accepted by [crate::ParamProvider].
+///
+/// This is implemented for `&str` and `String` so string literals can be used directly.
+pub trait ToParamName {
+    fn to_param_name(self) -> Result<ParamName>;
+}
+
+macro_rules! impl_to_name {
+    ($trait:ident, $fn:ident, $name:ident) => {
+        impl $trait for $name {
+            fn $fn(self) -> Result<$name> {
+                Ok(self)
+            }
+        }
+
+        impl $trait for &$name {
+            fn $fn(self) -> Result<$name> {
+                Ok(self.clone())
+            }
+        }
+
+        impl $trait for &str {
+            fn $fn(self) -> Result<$name> {
+                $name::new(self)
+            }
+        }
+
+        impl $trait for String {
+            fn $fn(self) -> Result<$name> {
+                $name::new(self)
+            }
+        }
+
+        impl $trait for &String {
+            fn $fn(self) -> Result<$name> {
+                $name::new(self.as_str())
+            }
+        }
+    };
+}
+
+impl_to_name!(ToTopicName, to_topic_name, TopicName);
+impl_to_name!(ToServiceName, to_service_name, ServiceName);
+impl_to_name!(ToParamName, to_param_name, ParamName);
+
+/// A table of name remappings in the style of ROS's `from:=to` command line arguments.
+///
+/// Remappings are matched against fully resolved (global) names, both sides of a remapping are resolved
+/// when it is applied so relative remappings behave the same way they do in roscpp / rospy.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Remappings {
+    map: BTreeMap<String, String>,
+}
+
+impl Remappings {
+    /// Creates an empty remapping table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses remappings from command line style arguments e.g. `["chatter:=/robot1/chatter"]`.
+    ///
+    /// Arguments not containing `:=` are ignored, as are ROS special keys starting with `__` (e.g. `__name:=foo`).
+    pub fn from_args<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut remappings = Self::new();
+        for arg in args {
+            let Some((from, to)) = arg.as_ref().split_once(":=") else {
+                continue;
+            };
+            if from.starts_with("__") {
+                continue;
+            }
+            remappings.insert(from, to)?;
+        }
+        Ok(remappings)
+    }
+
+    /// Adds a remapping from one name to another, both names must be valid ROS names.
+    pub fn insert(&mut self, from: &str, to: &str) -> Result<()> {
+        validate(from)?;
+        validate(to)?;
+        self.map.insert(from.to_string(), to.to_string());
+        Ok(())
+    }
+
+    /// True if no remappings are present.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Resolves `name` to a global name (per [TopicName::resolve_to_global]) and applies any matching remapping.
+    ///
+    /// If `node_name` is None names are resolved as described in [TopicName::resolve_to_root].
+    pub fn resolve(&self, name: &str, node_name: Option<&str>) -> Result<String> {
+        match self.lookup(name, node_name)? {
+            Some(remapped) => Ok(remapped),
+            None => resolve(name, node_name),
+        }
+    }
+
+    /// The global name a remapping sends `name` to, or None if no remapping matches it.
+    pub fn lookup(&self, name: &str, node_name: Option<&str>) -> Result<Option<String>> {
+        validate(name)?;
+        let resolved = resolve(name, node_name)?;
+        for (from, to) in &self.map {
+            if resolve(from, node_name)? == resolved {
+                return resolve(to, node_name).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Resolves and remaps a topic name, see [Remappings::resolve].
+    pub fn resolve_topic(
+        &self,
+        name: impl ToTopicName,
+        node_name: Option<&str>,
+    ) -> Result<TopicName> {
+        let name = name.to_topic_name()?;
+        TopicName::new(self.resolve(name.as_str(), node_name)?)
+    }
+
+    /// Resolves and remaps a service name, see [Remappings::resolve].
+    pub fn resolve_service(
+        &self,
+        name: impl ToServiceName,
+        node_name: Option<&str>,
+    ) -> Result<ServiceName> {
+        let name = name.to_service_name()?;
+        ServiceName::new(self.resolve(name.as_str(), node_name)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_validation() {
+        assert!(TopicName::new("base").is_ok());
+        assert!(TopicName::new("a").is_ok());
+        assert!(TopicName::new("relative/name").is_ok());
+        assert!(TopicName::new("/global/name").is_ok());
+        assert!(TopicName::new("~private/name").is_ok());
+        assert!(TopicName::new("~/private/name").is_ok());
+
+        // These are invalid names
+        assert!(TopicName::new("").is_err());
+        assert!(TopicName::new("/").is_err());
+        assert!(TopicName::new("~").is_err());
+        assert!(TopicName::new("~~").is_err());
+        assert!(TopicName::new("_leading").is_err());
+        assert!(TopicName::new("trailing/").is_err());
+        assert!(TopicName::new("double//slash").is_err());
+        assert!(TopicName::new("has space").is_err());
+        assert!(matches!(
+            ServiceName::new("ba-d"),
+            Err(Error::InvalidName(_))
+        ));
+    }
+
+    // Examples pulled from http://wiki.ros.org/Names
+    #[test]
+    fn name_resolution() {
+        let resolve = |name: &str, node: &str| {
+            TopicName::new(name)
+                .unwrap()
+                .resolve_to_global(node)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(resolve("bar", "/node1"), "/bar");
+        assert_eq!(resolve("/bar", "/node1"), "/bar");
+        assert_eq!(resolve("~bar", "/node1"), "/node1/bar");
+        assert_eq!(resolve("bar", "/wg/node2"), "/wg/bar");
+        assert_eq!(resolve("~bar", "/wg/node2"), "/wg/node2/bar");
+        assert_eq!(resolve("foo/bar", "/wg/node3"), "/wg/foo/bar");
+        assert_eq!(resolve("~/foo/bar", "/wg/node3"), "/wg/node3/foo/bar");
+        assert_eq!(resolve("bar", "node1"), "/bar");
+
+        let root = ServiceName::new("add_two_ints").unwrap().resolve_to_root();
+        assert_eq!(root.unwrap().as_str(), "/add_two_ints");
+        assert!(ServiceName::new("~private")
+            .unwrap()
+            .resolve_to_root()
+            .is_err());
+    }
+
+    #[test]
+    fn remapping() {
+        let remap = Remappings::from_args([
+            "my_node",
+            "__name:=other",
+            "chatter:=/robot1/chatter",
+            "/cmd_vel:=cmd_vel_safe",
+        ])
+        .unwrap();
+        assert_eq!(
+            remap
+                .resolve_topic("chatter", Some("/wg/node"))
+                .unwrap()
+                .as_str(),
+            "/robot1/chatter"
+        );
+        assert_eq!(
+            remap.resolve_topic("chatter", None).unwrap().as_str(),
+            "/robot1/chatter"
+        );
+        assert_eq!(
+            remap
+                .resolve_topic("/cmd_vel", Some("/ns/node"))
+                .unwrap()
+                .as_str(),
+            "/ns/cmd_vel_safe"
+        );
+        assert_eq!(
+            remap.resolve_service("/other", None).unwrap().as_str(),
+            "/other"
+        );
+        assert_eq!(remap.lookup("other", None).unwrap(), None);
+        assert_eq!(
+            remap.lookup("/cmd_vel", None).unwrap().as_deref(),
+            Some("/cmd_vel_safe")
+        );
+        assert!(Remappings::from_args(["bad name:=foo"]).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+//! Built-in copies of the single field messages of `std_msgs`, e.g. [Bool] and [Float64], and [Empty].
+//!
+//! Quick tools and tests often only need to publish or subscribe to a topic carrying a number or a string. These
+//! types have the same names, md5sums and definitions as the ones generated from `std_msgs`, so they talk to any ROS1
+//! node using `std_msgs` without running codegen or having the package's sources around:
+//! ```no_run
+//! # async fn f(ros: impl roslibrust_common::TopicProvider) -> roslibrust_common::Result<()> {
+//! use roslibrust_common::primitives::Float64;
+//! use roslibrust_common::Publish;
+//!
+//! let publisher = ros.advertise::<Float64>("/speed").await?;
+//! publisher.publish(&Float64 { data: 1.5 }).await?;
+//! # Ok(())
+//! # }
+//! ```
+//! Applications which already generate `std_msgs` should keep using the generated types, which are otherwise
+//! identical. ROS2 type hashes aren't provided, so these types are for ROS1 and rosbridge.
+
+use crate::RosMessageType;
+
+/// Defines a message with a single field named `data`, like the primitive messages of `std_msgs`
+macro_rules! primitive_message {
+    ($(#[$attr:meta])* $name:ident, $field:ty, $ros_type:literal, $md5sum:literal) => {
+        $(#[$attr])*
+        #[derive(::serde::Deserialize, ::serde::Serialize, Debug, Default, Clone, PartialEq)]
+        pub struct $name {
+            pub data: $field,
+        }
+
+        impl RosMessageType for $name {
+            const ROS_TYPE_NAME: &'static str = concat!("std_msgs/", stringify!($name));
+            const MD5SUM: &'static str = $md5sum;
+            const DEFINITION: &'static str = concat!($ros_type, " data");
+        }
+
+        impl From<$field> for $name {
+            fn from(data: $field) -> Self {
+                Self { data }
+            }
+        }
+    };
+}
+
+/// `std_msgs/Empty`, a message without any fields, e.g. for triggering something over a topic
+#[derive(::serde::Deserialize, ::serde::Serialize, Debug, Default, Clone, PartialEq)]
+pub struct Empty {}
+
+impl RosMessageType for Empty {
+    const ROS_TYPE_NAME: &'static str = "std_msgs/Empty";
+    const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+    const DEFINITION: &'static str = "";
+}
+
+primitive_message!(
+    /// `std_msgs/Bool`
+    Bool, bool, "bool", "8b94c1b53db61fb6aed406028ad6332a"
+);
+primitive_message!(
+    /// `std_msgs/String`
+    String, std::string::String, "string", "992ce8a1687cec8c8bd883ec73ca41d1"
+);
+primitive_message!(
+    /// `std_msgs/Int8`
+    Int8, i8, "int8", "27ffa0c9c4b8fb8492252bcad9e5c57b"
+);
+primitive_message!(
+    /// `std_msgs/UInt8`
+    UInt8, u8, "uint8", "7c8164229e7d2c17eb95e9231617fdee"
+);
+primitive_message!(
+    /// `std_msgs/Int16`
+    Int16, i16, "int16", "8524586e34fbd7cb1c08c5f5f1ca0e57"
+);
+primitive_message!(
+    /// `std_msgs/UInt16`
+    UInt16, u16, "uint16", "1df79edf208b629fe6b81923a544552d"
+);
+primitive_message!(
+    /// `std_msgs/Int32`
+    Int32, i32, "int32", "da5909fbe378aeaf85e547e830cc1bb7"
+);
+primitive_message!(
+    /// `std_msgs/UInt32`
+    UInt32, u32, "uint32", "304a39449588c7f8ce2df6e8001c5fce"
+);
+primitive_message!(
+    /// `std_msgs/Int64`
+    Int64, i64, "int64", "34add168574510e6e17f5d23ecc077ef"
+);
+primitive_message!(
+    /// `std_msgs/UInt64`
+    UInt64, u64, "uint64", "1b2a79973e8bf53d7b53acb71299cb57"
+);
+primitive_message!(
+    /// `std_msgs/Float32`
+    Float32, f32, "float32", "73fcbf46b49191e672908e50842a83d4"
+);
+primitive_message!(
+    /// `std_msgs/Float64`
+    Float64, f64, "float64", "fdb28210bfa9d7c91146260178d9a584"
+);
+
+impl From<&str> for String {
+    fn from(data: &str) -> Self {
+        Self {
+            data: data.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::md5sum;
+
+    fn check<T: RosMessageType + PartialEq>(message: T) {
+        let md5sum = md5sum::from_message_definition(T::ROS_TYPE_NAME, T::DEFINITION).unwrap();
+        assert_eq!(md5sum, T::MD5SUM, "md5sum of {}", T::ROS_TYPE_NAME);
+        let bytes = roslibrust_serde_rosmsg::to_vec(&message).unwrap();
+        assert_eq!(
+            roslibrust_serde_rosmsg::from_slice::<T>(&bytes).unwrap(),
+            message
+        );
+    }
+
+    #[test]
+    fn matches_std_msgs() {
+        check(Bool::from(true));
+        check(String::from("hello"));
+        check(Int8::from(-8));
+        check(UInt8::from(8));
+        check(Int16::from(-16));
+        check(UInt16::from(16));
+        check(Int32::from(-32));
+        check(UInt32::from(32));
+        check(Int64::from(-64));
+        check(UInt64::from(64));
+        check(Float32::from(3.2));
+        check(Float64::from(6.4));
+
+        // An empty message is serialized as just its length
+        let bytes = roslibrust_serde_rosmsg::to_vec(&Empty {}).unwrap();
+        assert_eq!(bytes, [0, 0, 0, 0]);
+        assert_eq!(Empty::MD5SUM, format!("{:x}", md5::compute("")));
+    }
+}
@@ -0,0 +1,692 @@
+//! An encoder and decoder for ROS1's wire format which doesn't go through serde.
+//!
+//! Backends serialize messages with serde by default, which handles each element of an array on its own and grows
+//! the output as it goes. Code generated with `CodegenOptions::ros1_codec` instead implements [Ros1Encode] and
+//! [Ros1Decode], reading and writing each field directly into a buffer allocated once from [Ros1Encode::encoded_len],
+//! and copying arrays of primitives in bulk. The ROS1 backend uses these implementations through
+//! [crate::RosMessageType::ros1_encode] and [crate::RosMessageType::ros1_decode] when they're available.
+//!
+//! The bytes are the same as serde_rosmsg's: little endian primitives, strings and variable length arrays prefixed
+//! with their length as a u32, and fixed size arrays without a length.
+//!
+//! Code generated with `CodegenOptions::ros1_borrowed` additionally implements [Ros1Borrow], giving each message
+//! a borrowed form decoded with [Ros1DecodeBorrowed]. Its strings and byte arrays reference the received data instead
+//! of being copied, and `to_owned()` converts it into the message when it needs to outlive the data.
+//! The ROS1 backend's `Subscriber::next_encoded` returns received messages as [Encoded] for decoding this way.
+//!
+//! Code generated with `CodegenOptions::ros1_lazy` additionally implements [Ros1Lazy], giving each message a
+//! [LazyMessage] view with a method per field which decodes only that field. The fields before it are skipped with
+//! [Ros1Decode::skip], which steps over fixed size values and length prefixed strings and arrays without decoding
+//! them, so e.g. a consumer filtering on the `header` of large messages doesn't pay for decoding the ones it drops.
+
+use std::marker::PhantomData;
+
+/// Errors decoding a message, the equivalent of serde_rosmsg's errors
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The data ended before the message did
+    #[error("Unexpected end of data, needed {needed} more bytes")]
+    UnexpectedEnd { needed: usize },
+    /// The message ended before the length at the front of the data
+    #[error("Message was shorter than its length, {0} bytes were left over")]
+    Underflow(usize),
+    /// A string wasn't valid UTF-8
+    #[error("Invalid UTF-8 in string: {0}")]
+    InvalidUtf8(#[from] std::str::Utf8Error),
+}
+
+/// Reads the fields of a message from ROS1 encoded data
+#[derive(Debug)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Reads from the start of data, which must not include the length of the message
+    pub fn new(data: &'a [u8]) -> Self {
+        Reader { data }
+    }
+
+    /// Consumes the next `len` bytes
+    pub fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if len > self.data.len() {
+            return Err(DecodeError::UnexpectedEnd {
+                needed: len - self.data.len(),
+            });
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    /// Reads the length of a string or variable length array
+    pub fn read_len(&mut self) -> Result<usize, DecodeError> {
+        Ok(u32::decode(self)? as usize)
+    }
+
+    /// The number of bytes which haven't been read
+    pub fn remaining(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// Types which can be written in the ROS1 wire format
+pub trait Ros1Encode {
+    /// The exact number of bytes [Ros1Encode::encode] writes
+    fn encoded_len(&self) -> usize;
+
+    /// Appends the encoded value to `buf`
+    fn encode(&self, buf: &mut Vec<u8>);
+
+    /// The encoded length of the elements of an array, overridden by fixed size types to skip the iteration
+    fn encoded_len_of_slice(items: &[Self]) -> usize
+    where
+        Self: Sized,
+    {
+        items.iter().map(Ros1Encode::encoded_len).sum()
+    }
+
+    /// Appends the elements of an array, overridden by primitives to copy them in bulk
+    fn encode_slice(items: &[Self], buf: &mut Vec<u8>)
+    where
+        Self: Sized,
+    {
+        for item in items {
+            item.encode(buf);
+        }
+    }
+}
+
+/// Types which can be read from the ROS1 wire format
+pub trait Ros1Decode: Sized {
+    /// Reads a value from the front of the reader
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError>;
+
+    /// Reads the `len` elements of an array, overridden by primitives to copy them in bulk
+    fn decode_vec(reader: &mut Reader<'_>, len: usize) -> Result<Vec<Self>, DecodeError> {
+        // The length comes from the data, so isn't trusted to allocate up front
+        let mut items = Vec::with_capacity(len.min(reader.remaining()));
+        for _ in 0..len {
+            items.push(Self::decode(reader)?);
+        }
+        Ok(items)
+    }
+
+    /// Steps over a value without keeping it, overridden where the encoded length can be found without decoding
+    fn skip(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+        Self::decode(reader).map(drop)
+    }
+
+    /// Steps over the `len` elements of an array, overridden by fixed size types to skip them at once
+    fn skip_n(reader: &mut Reader<'_>, len: usize) -> Result<(), DecodeError> {
+        for _ in 0..len {
+            Self::skip(reader)?;
+        }
+        Ok(())
+    }
+}
+
+/// Encodes a message including its length at the front, like `serde_rosmsg::to_vec`
+pub fn to_vec<T: Ros1Encode>(msg: &T) -> Vec<u8> {
+    let len = msg.encoded_len();
+    let mut buf = Vec::with_capacity(len + 4);
+    (len as u32).encode(&mut buf);
+    msg.encode(&mut buf);
+    buf
+}
+
+/// Decodes a message which has its length at the front, like `serde_rosmsg::from_slice`
+pub fn from_slice<T: Ros1Decode>(data: &[u8]) -> Result<T, DecodeError> {
+    decode_body(data, T::decode)
+}
+
+/// Decodes the borrowed form of a message which has its length at the front, referencing `data`
+pub fn from_slice_borrowed<'a, T: Ros1DecodeBorrowed<'a>>(
+    data: &'a [u8],
+) -> Result<T, DecodeError> {
+    decode_body(data, T::decode_borrowed)
+}
+
+/// Decodes the body following the length at the front of the data, which must be fully used
+fn decode_body<'a, T>(
+    data: &'a [u8],
+    decode: impl FnOnce(&mut Reader<'a>) -> Result<T, DecodeError>,
+) -> Result<T, DecodeError> {
+    let mut reader = Reader::new(body(data)?);
+    let msg = decode(&mut reader)?;
+    if reader.remaining() > 0 {
+        return Err(DecodeError::Underflow(reader.remaining()));
+    }
+    Ok(msg)
+}
+
+/// The body following the length at the front of the data
+fn body(data: &[u8]) -> Result<&[u8], DecodeError> {
+    let mut reader = Reader::new(data);
+    let len = reader.read_len()?;
+    reader.take(len)
+}
+
+/// Views a message which has its length at the front as a [LazyMessage], without decoding any of its fields
+pub fn from_slice_lazy<T: Ros1Lazy>(data: &[u8]) -> Result<LazyMessage<'_, T>, DecodeError> {
+    Ok(T::lazy(body(data)?))
+}
+
+/// Implemented by messages with a [LazyMessage] view decoding their fields on demand, see the [module docs](self)
+pub trait Ros1Lazy: Ros1Decode {
+    /// The view, with a method per field decoding it from the body
+    type Lazy<'a>: Copy;
+
+    /// Views the body of an encoded message, which follows its length
+    fn lazy(body: &[u8]) -> Self::Lazy<'_>;
+}
+
+/// The view of the encoded message `T` decoding its fields on demand, e.g. `lazy.header()?` decodes only the header
+pub type LazyMessage<'a, T> = <T as Ros1Lazy>::Lazy<'a>;
+
+/// Types which can be read from the ROS1 wire format while referencing the data, see the [module docs](self)
+pub trait Ros1DecodeBorrowed<'a>: Sized {
+    /// The type this converts into when it needs to outlive the data
+    type Owned;
+
+    /// Reads a value from the front of the reader
+    fn decode_borrowed(reader: &mut Reader<'a>) -> Result<Self, DecodeError>;
+
+    /// Copies the referenced data
+    fn to_owned(&self) -> Self::Owned;
+}
+
+/// Implemented by messages with a borrowed form, which is the message itself when it has no strings or byte arrays
+pub trait Ros1Borrow: Sized {
+    type Borrowed<'a>: Ros1DecodeBorrowed<'a, Owned = Self>;
+}
+
+/// An encoded message as received, which can be decoded without copying its strings and byte arrays
+#[derive(Debug, Clone)]
+pub struct Encoded<T> {
+    data: Vec<u8>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Encoded<T> {
+    /// Wraps ROS1 encoded data which has its length at the front
+    pub fn new(data: Vec<u8>) -> Self {
+        Encoded {
+            data,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The encoded data, including its length at the front
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Decodes the borrowed form of the message referencing the data
+    pub fn decode_borrowed(&self) -> Result<T::Borrowed<'_>, DecodeError>
+    where
+        T: Ros1Borrow,
+    {
+        from_slice_borrowed(&self.data)
+    }
+
+    /// Decodes the message, copying the data
+    pub fn decode(&self) -> Result<T, DecodeError>
+    where
+        T: Ros1Decode,
+    {
+        from_slice(&self.data)
+    }
+
+    /// Views the message without decoding it, so only the fields which are used get decoded
+    pub fn lazy(&self) -> Result<LazyMessage<'_, T>, DecodeError>
+    where
+        T: Ros1Lazy,
+    {
+        from_slice_lazy::<T>(&self.data)
+    }
+}
+
+/// Encodes a byte array, used for fields of types which can be viewed as a slice such as `bytes::Bytes`
+pub fn encode_bytes(bytes: &[u8], buf: &mut Vec<u8>) {
+    (bytes.len() as u32).encode(buf);
+    buf.extend_from_slice(bytes);
+}
+
+/// Decodes a byte array, used for fields of types which can be converted from a `Vec<u8>` such as `bytes::Bytes`
+pub fn decode_bytes(reader: &mut Reader<'_>) -> Result<Vec<u8>, DecodeError> {
+    Vec::decode(reader)
+}
+
+/// Steps over a byte array, the counterpart of [decode_bytes]
+pub fn skip_bytes(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+    Vec::<u8>::skip(reader)
+}
+
+/// Steps over `len` values which are each `size` bytes
+fn skip_fixed(reader: &mut Reader<'_>, size: usize, len: usize) -> Result<(), DecodeError> {
+    reader.take(
+        len.checked_mul(size)
+            .ok_or(DecodeError::UnexpectedEnd { needed: usize::MAX })?,
+    )?;
+    Ok(())
+}
+
+macro_rules! impl_primitive {
+    ($($t:ty),*) => {$(
+        impl Ros1Encode for $t {
+            fn encoded_len(&self) -> usize {
+                std::mem::size_of::<$t>()
+            }
+
+            fn encode(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn encoded_len_of_slice(items: &[Self]) -> usize {
+                std::mem::size_of_val(items)
+            }
+
+            fn encode_slice(items: &[Self], buf: &mut Vec<u8>) {
+                // Written as a copy per fixed size chunk, which compiles to a memcpy on little endian targets
+                const SIZE: usize = std::mem::size_of::<$t>();
+                let start = buf.len();
+                buf.resize(start + items.len() * SIZE, 0);
+                for (chunk, item) in buf[start..].chunks_exact_mut(SIZE).zip(items) {
+                    chunk.copy_from_slice(&item.to_le_bytes());
+                }
+            }
+        }
+
+        impl Ros1Decode for $t {
+            fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+                let bytes = reader.take(std::mem::size_of::<$t>())?;
+                Ok(<$t>::from_le_bytes(bytes.try_into().unwrap()))
+            }
+
+            fn decode_vec(reader: &mut Reader<'_>, len: usize) -> Result<Vec<Self>, DecodeError> {
+                const SIZE: usize = std::mem::size_of::<$t>();
+                let bytes = reader.take(len.checked_mul(SIZE).ok_or(DecodeError::UnexpectedEnd {
+                    needed: usize::MAX,
+                })?)?;
+                Ok(bytes
+                    .chunks_exact(SIZE)
+                    .map(|chunk| <$t>::from_le_bytes(chunk.try_into().unwrap()))
+                    .collect())
+            }
+
+            fn skip(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+                skip_fixed(reader, std::mem::size_of::<$t>(), 1)
+            }
+
+            fn skip_n(reader: &mut Reader<'_>, len: usize) -> Result<(), DecodeError> {
+                skip_fixed(reader, std::mem::size_of::<$t>(), len)
+            }
+        }
+    )*};
+}
+
+impl_primitive!(i8, i16, i32, i64, u16, u32, u64, f32, f64);
+
+impl Ros1Encode for u8 {
+    fn encoded_len(&self) -> usize {
+        1
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(*self);
+    }
+
+    fn encoded_len_of_slice(items: &[Self]) -> usize {
+        items.len()
+    }
+
+    fn encode_slice(items: &[Self], buf: &mut Vec<u8>) {
+        buf.extend_from_slice(items);
+    }
+}
+
+impl Ros1Decode for u8 {
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(reader.take(1)?[0])
+    }
+
+    fn decode_vec(reader: &mut Reader<'_>, len: usize) -> Result<Vec<Self>, DecodeError> {
+        Ok(reader.take(len)?.to_vec())
+    }
+
+    fn skip(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+        skip_fixed(reader, 1, 1)
+    }
+
+    fn skip_n(reader: &mut Reader<'_>, len: usize) -> Result<(), DecodeError> {
+        skip_fixed(reader, 1, len)
+    }
+}
+
+impl Ros1Encode for bool {
+    fn encoded_len(&self) -> usize {
+        1
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+
+    fn encoded_len_of_slice(items: &[Self]) -> usize {
+        items.len()
+    }
+}
+
+impl Ros1Decode for bool {
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(u8::decode(reader)? != 0)
+    }
+
+    fn skip(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+        skip_fixed(reader, 1, 1)
+    }
+
+    fn skip_n(reader: &mut Reader<'_>, len: usize) -> Result<(), DecodeError> {
+        skip_fixed(reader, 1, len)
+    }
+}
+
+impl Ros1Encode for String {
+    fn encoded_len(&self) -> usize {
+        4 + self.len()
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_bytes(self.as_bytes(), buf);
+    }
+}
+
+impl Ros1Decode for String {
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        String::from_utf8(decode_bytes(reader)?).map_err(|e| e.utf8_error().into())
+    }
+
+    /// Skipped strings aren't checked to be valid UTF-8
+    fn skip(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+        skip_bytes(reader)
+    }
+}
+
+impl<T: Ros1Encode> Ros1Encode for Vec<T> {
+    fn encoded_len(&self) -> usize {
+        4 + T::encoded_len_of_slice(self)
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        (self.len() as u32).encode(buf);
+        T::encode_slice(self, buf);
+    }
+}
+
+impl<T: Ros1Decode> Ros1Decode for Vec<T> {
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        let len = reader.read_len()?;
+        T::decode_vec(reader, len)
+    }
+
+    fn skip(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+        let len = reader.read_len()?;
+        T::skip_n(reader, len)
+    }
+}
+
+impl<T: Ros1Encode, const N: usize> Ros1Encode for [T; N] {
+    fn encoded_len(&self) -> usize {
+        T::encoded_len_of_slice(self)
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        T::encode_slice(self, buf);
+    }
+}
+
+impl<T: Ros1Decode, const N: usize> Ros1Decode for [T; N] {
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        let items = T::decode_vec(reader, N)?;
+        // decode_vec always returns N items
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("Decoded the wrong number of array elements")))
+    }
+
+    fn skip(reader: &mut Reader<'_>) -> Result<(), DecodeError> {
+        T::skip_n(reader, N)
+    }
+}
+
+impl<'a> Ros1DecodeBorrowed<'a> for &'a str {
+    type Owned = String;
+
+    fn decode_borrowed(reader: &mut Reader<'a>) -> Result<Self, DecodeError> {
+        let len = reader.read_len()?;
+        Ok(std::str::from_utf8(reader.take(len)?)?)
+    }
+
+    fn to_owned(&self) -> String {
+        (*self).to_owned()
+    }
+}
+
+impl<'a> Ros1DecodeBorrowed<'a> for &'a [u8] {
+    type Owned = Vec<u8>;
+
+    fn decode_borrowed(reader: &mut Reader<'a>) -> Result<Self, DecodeError> {
+        let len = reader.read_len()?;
+        reader.take(len)
+    }
+
+    fn to_owned(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl<'a, T: Ros1DecodeBorrowed<'a>> Ros1DecodeBorrowed<'a> for Vec<T> {
+    type Owned = Vec<T::Owned>;
+
+    fn decode_borrowed(reader: &mut Reader<'a>) -> Result<Self, DecodeError> {
+        let len = reader.read_len()?;
+        decode_borrowed_vec(reader, len)
+    }
+
+    fn to_owned(&self) -> Vec<T::Owned> {
+        self.iter().map(T::to_owned).collect()
+    }
+}
+
+impl<'a, T: Ros1DecodeBorrowed<'a>, const N: usize> Ros1DecodeBorrowed<'a> for [T; N] {
+    type Owned = [T::Owned; N];
+
+    fn decode_borrowed(reader: &mut Reader<'a>) -> Result<Self, DecodeError> {
+        let items = decode_borrowed_vec(reader, N)?;
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("Decoded the wrong number of array elements")))
+    }
+
+    fn to_owned(&self) -> [T::Owned; N] {
+        std::array::from_fn(|i| T::to_owned(&self[i]))
+    }
+}
+
+fn decode_borrowed_vec<'a, T: Ros1DecodeBorrowed<'a>>(
+    reader: &mut Reader<'a>,
+    len: usize,
+) -> Result<Vec<T>, DecodeError> {
+    // The length comes from the data, so isn't trusted to allocate up front
+    let mut items = Vec::with_capacity(len.min(reader.remaining()));
+    for _ in 0..len {
+        items.push(T::decode_borrowed(reader)?);
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Sample {
+        flag: bool,
+        name: String,
+        values: Vec<f32>,
+        bytes: Vec<u8>,
+        fixed: [i16; 3],
+        names: Vec<String>,
+    }
+
+    impl Ros1Encode for Sample {
+        fn encoded_len(&self) -> usize {
+            self.flag.encoded_len()
+                + self.name.encoded_len()
+                + self.values.encoded_len()
+                + self.bytes.encoded_len()
+                + self.fixed.encoded_len()
+                + self.names.encoded_len()
+        }
+
+        fn encode(&self, buf: &mut Vec<u8>) {
+            self.flag.encode(buf);
+            self.name.encode(buf);
+            self.values.encode(buf);
+            self.bytes.encode(buf);
+            self.fixed.encode(buf);
+            self.names.encode(buf);
+        }
+    }
+
+    impl Ros1Decode for Sample {
+        fn decode(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+            Ok(Sample {
+                flag: Ros1Decode::decode(reader)?,
+                name: Ros1Decode::decode(reader)?,
+                values: Ros1Decode::decode(reader)?,
+                bytes: Ros1Decode::decode(reader)?,
+                fixed: Ros1Decode::decode(reader)?,
+                names: Ros1Decode::decode(reader)?,
+            })
+        }
+    }
+
+    /// Written the way `CodegenOptions::ros1_lazy` generates the view
+    #[derive(Clone, Copy)]
+    struct SampleLazy<'a> {
+        body: &'a [u8],
+    }
+
+    impl SampleLazy<'_> {
+        fn names(&self) -> Result<Vec<String>, DecodeError> {
+            let mut reader = Reader::new(self.body);
+            <bool as Ros1Decode>::skip(&mut reader)?;
+            <String as Ros1Decode>::skip(&mut reader)?;
+            <Vec<f32> as Ros1Decode>::skip(&mut reader)?;
+            <Vec<u8> as Ros1Decode>::skip(&mut reader)?;
+            <[i16; 3] as Ros1Decode>::skip(&mut reader)?;
+            Ros1Decode::decode(&mut reader)
+        }
+    }
+
+    impl Ros1Lazy for Sample {
+        type Lazy<'a> = SampleLazy<'a>;
+
+        fn lazy(body: &[u8]) -> SampleLazy<'_> {
+            SampleLazy { body }
+        }
+    }
+
+    /// Confirms the encoding matches serde_rosmsg's
+    #[test]
+    fn matches_serde_rosmsg() {
+        let sample = Sample {
+            flag: true,
+            name: "hello".to_string(),
+            values: vec![1.5, -2.0],
+            bytes: vec![1, 2, 3],
+            fixed: [1, -1, 300],
+            names: vec!["a".to_string(), "".to_string()],
+        };
+        let encoded = to_vec(&sample);
+        assert_eq!(encoded, roslibrust_serde_rosmsg::to_vec(&sample).unwrap());
+        assert_eq!(encoded.len(), sample.encoded_len() + 4);
+        assert_eq!(from_slice::<Sample>(&encoded).unwrap(), sample);
+    }
+
+    #[test]
+    fn decodes_borrowed() {
+        let names = vec!["a".to_string(), "bc".to_string()];
+        let bytes = vec![1u8, 2, 3];
+        let mut encoded = vec![];
+        names.encode(&mut encoded);
+        bytes.encode(&mut encoded);
+        let mut reader = Reader::new(&encoded);
+        let borrowed_names = Vec::<&str>::decode_borrowed(&mut reader).unwrap();
+        let borrowed_bytes = <&[u8]>::decode_borrowed(&mut reader).unwrap();
+        assert_eq!(borrowed_names, vec!["a", "bc"]);
+        // The bytes reference the encoded data rather than a copy
+        assert_eq!(
+            borrowed_bytes.as_ptr(),
+            encoded[encoded.len() - 3..].as_ptr()
+        );
+        assert_eq!(Ros1DecodeBorrowed::to_owned(&borrowed_names), names);
+        assert_eq!(Ros1DecodeBorrowed::to_owned(&borrowed_bytes), bytes);
+
+        let encoded = Encoded::<String>::new(to_vec(&"hello".to_string()));
+        assert_eq!(encoded.decode().unwrap(), "hello");
+        assert_eq!(
+            from_slice_borrowed::<&str>(encoded.as_bytes()).unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn decodes_lazily() {
+        let sample = Sample {
+            flag: false,
+            name: "hello".to_string(),
+            values: vec![1.0; 100],
+            bytes: vec![7; 1000],
+            fixed: [1, 2, 3],
+            names: vec!["a".to_string(), "bc".to_string()],
+        };
+        let encoded = Encoded::<Sample>::new(to_vec(&sample));
+        assert_eq!(encoded.lazy().unwrap().names().unwrap(), sample.names);
+        assert_eq!(encoded.decode().unwrap(), sample);
+
+        let mut truncated = to_vec(&sample);
+        truncated.truncate(20);
+        truncated[..4].copy_from_slice(&16u32.to_le_bytes());
+        let lazy = from_slice_lazy::<Sample>(&truncated).unwrap();
+        assert!(matches!(
+            lazy.names(),
+            Err(DecodeError::UnexpectedEnd { .. })
+        ));
+
+        // Skipping steps over strings without checking them
+        let invalid = to_vec(&vec![0xffu8]);
+        let mut reader = Reader::new(&invalid[4..]);
+        String::skip(&mut reader).unwrap();
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        let encoded = to_vec(&vec![1u32, 2]);
+        assert_eq!(
+            from_slice::<Vec<u32>>(&encoded[..encoded.len() - 1]),
+            Err(DecodeError::UnexpectedEnd { needed: 1 })
+        );
+        assert_eq!(
+            from_slice::<[u32; 1]>(&encoded),
+            Err(DecodeError::Underflow(8))
+        );
+        // A huge length doesn't allocate
+        let huge = to_vec(&u32::MAX);
+        assert!(from_slice::<Vec<String>>(&huge).is_err());
+        assert!(from_slice::<String>(&to_vec(&vec![0xffu8])).is_err());
+    }
+}
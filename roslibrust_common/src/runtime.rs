@@ -0,0 +1,120 @@
+//! Lets backends built on tokio be used by applications running another executor, such as async-std or smol.
+//!
+//! The rosbridge and zenoh backends spawn their background tasks on a tokio runtime, and wait on tokio's timers.
+//! When a backend is created outside of a tokio runtime, [handle] starts a shared runtime on background threads to
+//! run them on instead, and [sleep] and [timeout] run their timers there. The futures a backend hands back wake
+//! whichever executor polls them, so the application never has to enter a tokio runtime itself:
+//! ```no_run
+//! # async fn f() -> roslibrust_common::Result<()> {
+//! // Inside e.g. smol::block_on or #[async_std::main]
+//! let handle = roslibrust_common::runtime::handle();
+//! assert_eq!(handle.spawn(async { 42 }).await.unwrap(), 42);
+//! roslibrust_common::runtime::sleep(std::time::Duration::from_millis(10)).await;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Error, Result};
+use std::future::Future;
+use std::pin::pin;
+use std::sync::OnceLock;
+use std::task::Poll;
+use std::time::Duration;
+use tokio::runtime::{Builder, Handle, Runtime};
+
+/// The tokio runtime of the calling task, or the shared background runtime when called outside of one.
+pub fn handle() -> Handle {
+    Handle::try_current().unwrap_or_else(|_| background().handle().clone())
+}
+
+/// Whether the caller is running outside of a tokio runtime, and backends fall back to the background runtime
+pub fn is_background() -> bool {
+    Handle::try_current().is_err()
+}
+
+/// Started the first time it is needed and kept for the rest of the process, as dropping a runtime from within
+/// one of its own tasks panics
+fn background() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("roslibrust-runtime")
+            .enable_all()
+            .build()
+            .expect("Failed to start roslibrust's background tokio runtime")
+    })
+}
+
+/// Waits for `duration`, on the background runtime's timer when called outside of a tokio runtime.
+pub async fn sleep(duration: Duration) {
+    if is_background() {
+        // The timer only fails to complete if the runtime shuts down, which the background runtime never does
+        let _ = background()
+            .spawn(async move { tokio::time::sleep(duration).await })
+            .await;
+    } else {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// Runs `future` to completion, failing with [Error::Timeout] if it takes longer than `duration`.
+pub async fn timeout<F: Future>(duration: Duration, future: F) -> Result<F::Output> {
+    let elapsed = || Error::Timeout(format!("Timed out after {duration:?}"));
+    if !is_background() {
+        return tokio::time::timeout(duration, future)
+            .await
+            .map_err(|_| elapsed());
+    }
+    let mut future = pin!(future);
+    let mut timer = pin!(sleep(duration));
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        timer.as_mut().poll(cx).map(|()| Err(elapsed()))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::task::{Context, Wake, Waker};
+    use std::thread::Thread;
+
+    /// A minimal executor standing in for one which isn't tokio
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct Unpark(Thread);
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+        let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+        let mut context = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+            std::thread::park();
+        }
+    }
+
+    #[test]
+    fn runs_outside_of_tokio() {
+        assert!(is_background());
+        block_on(async {
+            assert_eq!(handle().spawn(async { 42 }).await.unwrap(), 42);
+            sleep(Duration::from_millis(5)).await;
+            assert_eq!(
+                timeout(Duration::from_secs(5), async { 1 }).await.unwrap(),
+                1
+            );
+            let slow = timeout(Duration::from_millis(5), sleep(Duration::from_secs(5))).await;
+            assert!(matches!(slow, Err(Error::Timeout(_))));
+        });
+    }
+}
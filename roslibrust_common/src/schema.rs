@@ -0,0 +1,767 @@
+use crate::RosMessageType;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::{Display, Formatter};
+
+/// Types which aren't messages, and so are compared by name only
+const BASE_TYPES: [&str; 17] = [
+    "bool", "byte", "char", "int8", "int16", "int32", "int64", "uint8", "uint16", "uint32",
+    "uint64", "float32", "float64", "time", "duration", "string", "wstring",
+];
+
+/// A field or constant of a message, as written in its definition
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaField {
+    /// The type of the field with the package of messages filled in, e.g. `std_msgs/Header` or `float64[3]`
+    pub field_type: String,
+    pub name: String,
+    /// The value of constants, None for fields
+    pub constant: Option<String>,
+}
+
+impl SchemaField {
+    /// The type of the elements of the field, without any array suffix
+    fn element_type(&self) -> &str {
+        self.field_type.split('[').next().unwrap_or_default()
+    }
+}
+
+/// The schema of a message type: its name, checksums, and the fields of it and every message it contains.
+///
+/// Schemas are built from the compiled-in types with [MessageSchema::of], or from what is seen on the graph with
+/// [MessageSchema::new], such as the type, md5sum and definition of a ROS1 connection header.
+/// [MessageSchema::compare] them to find out why two types are incompatible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageSchema {
+    /// The full name of the type, e.g. `std_msgs/Header`
+    pub type_name: String,
+    pub md5sum: Option<String>,
+    pub type_hash: Option<String>,
+    /// The full definition in the format of `gendeps --cat`, see [RosMessageType::DEFINITION]
+    pub definition: String,
+    /// The fields of the message and of the messages it contains, by full type name
+    pub messages: BTreeMap<String, Vec<SchemaField>>,
+}
+
+impl MessageSchema {
+    /// Creates the schema of a type from its full definition, computing the md5sum from it.
+    /// Lines of the definition which aren't fields or constants are skipped, and an empty definition gives
+    /// a schema without fields, as sent by publishers which don't share their definition.
+    pub fn new(type_name: impl Into<String>, definition: impl Into<String>) -> Self {
+        let type_name = type_name.into();
+        let definition = definition.into();
+        let md5sum = crate::md5sum::from_message_definition(&type_name, &definition).ok();
+        let messages = parse_definition(&type_name, &definition);
+        Self {
+            type_name,
+            md5sum,
+            type_hash: None,
+            definition,
+            messages,
+        }
+    }
+
+    /// The schema of a compiled-in message type
+    pub fn of<T: RosMessageType>() -> Self {
+        let schema = Self::new(T::ROS_TYPE_NAME, T::DEFINITION).with_md5sum(T::MD5SUM);
+        match T::TYPE_HASH {
+            "" => schema,
+            type_hash => schema.with_type_hash(type_hash),
+        }
+    }
+
+    /// Replaces the md5sum computed from the definition with the one advertised, `*` is treated as unknown
+    pub fn with_md5sum(mut self, md5sum: impl Into<String>) -> Self {
+        let md5sum = md5sum.into();
+        self.md5sum = (md5sum != "*" && !md5sum.is_empty()).then_some(md5sum);
+        self
+    }
+
+    /// Sets the ROS2 type hash, e.g. `RIHS01_...`
+    pub fn with_type_hash(mut self, type_hash: impl Into<String>) -> Self {
+        self.type_hash = Some(type_hash.into());
+        self
+    }
+
+    /// The fields and constants of the message itself, None if its definition isn't known
+    pub fn fields(&self) -> Option<&[SchemaField]> {
+        self.messages.get(&self.type_name).map(Vec::as_slice)
+    }
+
+    /// Compares this schema, taken to be the local one, with `remote`, listing every difference between the two.
+    ///
+    /// The fields of both messages are compared by name, as are the fields of messages they both contain under
+    /// the same field. Nested messages whose type differs are reported once without comparing their fields.
+    pub fn compare(&self, remote: &MessageSchema) -> SchemaReport {
+        let mut differences = vec![];
+        if self.type_name != remote.type_name {
+            differences.push(SchemaDifference::TypeName {
+                local: self.type_name.clone(),
+                remote: remote.type_name.clone(),
+            });
+        }
+        if let (Some(local), Some(remote)) = (&self.md5sum, &remote.md5sum) {
+            if local != remote {
+                differences.push(SchemaDifference::Md5sum {
+                    local: local.clone(),
+                    remote: remote.clone(),
+                });
+            }
+        }
+        if let (Some(local), Some(remote)) = (&self.type_hash, &remote.type_hash) {
+            if local != remote {
+                differences.push(SchemaDifference::TypeHash {
+                    local: local.clone(),
+                    remote: remote.clone(),
+                });
+            }
+        }
+
+        // Walk the messages both contain in the same place, starting from the messages themselves
+        let mut visited = BTreeSet::new();
+        let mut queue = VecDeque::from([(self.type_name.clone(), remote.type_name.clone())]);
+        while let Some((local_type, remote_type)) = queue.pop_front() {
+            if !visited.insert((local_type.clone(), remote_type.clone())) {
+                continue;
+            }
+            let (local_fields, remote_fields) = match (
+                self.messages.get(&local_type),
+                remote.messages.get(&remote_type),
+            ) {
+                (Some(local_fields), Some(remote_fields)) => (local_fields, remote_fields),
+                (local_fields, _) => {
+                    let (message, missing_from) = match local_fields {
+                        Some(_) => (remote_type, Side::Remote),
+                        None => (local_type, Side::Local),
+                    };
+                    differences.push(SchemaDifference::MissingDefinition {
+                        message,
+                        missing_from,
+                    });
+                    continue;
+                }
+            };
+
+            for (index, local_field) in local_fields.iter().enumerate() {
+                let Some(remote_field) = remote_fields.iter().find(|f| f.name == local_field.name)
+                else {
+                    match renamed(local_fields, remote_fields, index) {
+                        Some(remote_field) => {
+                            differences.push(SchemaDifference::RenamedField {
+                                message: local_type.clone(),
+                                local: local_field.name.clone(),
+                                remote: remote_field.name.clone(),
+                            });
+                            if !BASE_TYPES.contains(&local_field.element_type()) {
+                                queue.push_back((
+                                    local_field.element_type().to_owned(),
+                                    remote_field.element_type().to_owned(),
+                                ));
+                            }
+                        }
+                        None => differences.push(SchemaDifference::MissingField {
+                            message: local_type.clone(),
+                            field: local_field.name.clone(),
+                            missing_from: Side::Remote,
+                        }),
+                    }
+                    continue;
+                };
+                if local_field.field_type != remote_field.field_type
+                    || local_field.constant.is_some() != remote_field.constant.is_some()
+                {
+                    differences.push(SchemaDifference::FieldType {
+                        message: local_type.clone(),
+                        field: local_field.name.clone(),
+                        local: local_field.field_type.clone(),
+                        remote: remote_field.field_type.clone(),
+                    });
+                } else if local_field.constant != remote_field.constant {
+                    differences.push(SchemaDifference::ConstantValue {
+                        message: local_type.clone(),
+                        constant: local_field.name.clone(),
+                        local: local_field.constant.clone().unwrap_or_default(),
+                        remote: remote_field.constant.clone().unwrap_or_default(),
+                    });
+                } else if !BASE_TYPES.contains(&local_field.element_type()) {
+                    queue.push_back((
+                        local_field.element_type().to_owned(),
+                        remote_field.element_type().to_owned(),
+                    ));
+                }
+            }
+            for (index, remote_field) in remote_fields.iter().enumerate() {
+                if !local_fields.iter().any(|f| f.name == remote_field.name)
+                    && renamed(remote_fields, local_fields, index).is_none()
+                {
+                    differences.push(SchemaDifference::MissingField {
+                        message: local_type.clone(),
+                        field: remote_field.name.clone(),
+                        missing_from: Side::Local,
+                    });
+                }
+            }
+            // Fields present in both but serialized in a different order
+            let order = |fields: &[SchemaField], other: &[SchemaField]| {
+                fields
+                    .iter()
+                    .filter(|f| f.constant.is_none() && other.iter().any(|o| o.name == f.name))
+                    .map(|f| f.name.clone())
+                    .collect::<Vec<_>>()
+            };
+            if order(local_fields, remote_fields) != order(remote_fields, local_fields) {
+                differences.push(SchemaDifference::FieldOrder {
+                    message: local_type.clone(),
+                });
+            }
+        }
+
+        SchemaReport {
+            local: self.type_name.clone(),
+            remote: remote.type_name.clone(),
+            differences,
+        }
+    }
+
+    /// Displays the message as an indented tree of its fields, with the fields of the messages it contains
+    /// beneath them, e.g. to show a type to users or in logs.
+    ///
+    /// ```
+    /// # use roslibrust_common::schema::MessageSchema;
+    /// let schema = MessageSchema::new("geometry_msgs/Point", "float64 x\nfloat64 y\nfloat64 z");
+    /// assert_eq!(
+    ///     schema.pretty().to_string(),
+    ///     "geometry_msgs/Point\n  float64 x\n  float64 y\n  float64 z\n"
+    /// );
+    /// ```
+    pub fn pretty(&self) -> PrettySchema<'_> {
+        PrettySchema { schema: self }
+    }
+
+    /// Diffs the [MessageSchema::pretty] trees of this schema, taken to be the old one, and `new` line by line,
+    /// showing where they differ in context. [MessageSchema::compare] explains the same differences.
+    pub fn diff(&self, new: &MessageSchema) -> SchemaDiff {
+        let old = self.pretty().to_string();
+        let new = new.pretty().to_string();
+        let old: Vec<_> = old.lines().collect();
+        let new: Vec<_> = new.lines().collect();
+
+        // The longest common subsequence of lines, from the ends towards the start
+        let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+        for i in (0..old.len()).rev() {
+            for j in (0..new.len()).rev() {
+                common[i][j] = if old[i] == new[j] {
+                    common[i + 1][j + 1] + 1
+                } else {
+                    common[i + 1][j].max(common[i][j + 1])
+                };
+            }
+        }
+        let mut lines = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < old.len() || j < new.len() {
+            let (change, text) = if i < old.len() && j < new.len() && old[i] == new[j] {
+                i += 1;
+                j += 1;
+                (LineChange::Same, old[i - 1])
+            } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+                i += 1;
+                (LineChange::Removed, old[i - 1])
+            } else {
+                j += 1;
+                (LineChange::Added, new[j - 1])
+            };
+            lines.push(DiffLine {
+                change,
+                text: text.to_owned(),
+            });
+        }
+        SchemaDiff { lines }
+    }
+}
+
+/// The field of `other` which the field at `index` of `fields` was renamed to: one of the same type in the same
+/// place, whose name only `other` has
+fn renamed<'a>(
+    fields: &[SchemaField],
+    other: &'a [SchemaField],
+    index: usize,
+) -> Option<&'a SchemaField> {
+    let field = &fields[index];
+    let candidate = other.get(index)?;
+    (candidate.field_type == field.field_type
+        && candidate.constant == field.constant
+        && !other.iter().any(|f| f.name == field.name)
+        && !fields.iter().any(|f| f.name == candidate.name))
+    .then_some(candidate)
+}
+
+/// Displays a [MessageSchema] as a tree, see [MessageSchema::pretty]
+pub struct PrettySchema<'a> {
+    schema: &'a MessageSchema,
+}
+
+impl PrettySchema<'_> {
+    fn fmt_fields(
+        &self,
+        f: &mut Formatter<'_>,
+        message: &str,
+        parents: &mut Vec<String>,
+    ) -> std::fmt::Result {
+        let Some(fields) = self.schema.messages.get(message) else {
+            return Ok(());
+        };
+        // A message can't contain itself, but a malformed definition could claim so
+        if parents.iter().any(|parent| parent == message) {
+            return Ok(());
+        }
+        parents.push(message.to_owned());
+        for field in fields {
+            write!(f, "{:indent$}", "", indent = parents.len() * 2)?;
+            match &field.constant {
+                Some(value) => writeln!(f, "{} {}={value}", field.field_type, field.name)?,
+                None => {
+                    writeln!(f, "{} {}", field.field_type, field.name)?;
+                    self.fmt_fields(f, field.element_type(), parents)?;
+                }
+            }
+        }
+        parents.pop();
+        Ok(())
+    }
+}
+
+impl Display for PrettySchema<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.schema.type_name)?;
+        self.fmt_fields(f, &self.schema.type_name, &mut vec![])
+    }
+}
+
+/// Whether a line of a [SchemaDiff] is in both schemas or only one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineChange {
+    Same,
+    Removed,
+    Added,
+}
+
+/// A line of a [SchemaDiff]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiffLine {
+    pub change: LineChange,
+    pub text: String,
+}
+
+/// The result of [MessageSchema::diff], which displays like a unified diff with `-` before removed lines and `+`
+/// before added ones
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaDiff {
+    pub lines: Vec<DiffLine>,
+}
+
+impl SchemaDiff {
+    /// True when the schemas print the same
+    pub fn is_empty(&self) -> bool {
+        self.lines
+            .iter()
+            .all(|line| line.change == LineChange::Same)
+    }
+}
+
+impl Display for SchemaDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for line in &self.lines {
+            let prefix = match line.change {
+                LineChange::Same => ' ',
+                LineChange::Removed => '-',
+                LineChange::Added => '+',
+            };
+            writeln!(f, "{prefix} {}", line.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Which of the two compared schemas something is missing from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Local,
+    Remote,
+}
+
+impl Display for Side {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Side::Local => write!(f, "locally"),
+            Side::Remote => write!(f, "remotely"),
+        }
+    }
+}
+
+/// A difference between two schemas found by [MessageSchema::compare]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaDifference {
+    TypeName {
+        local: String,
+        remote: String,
+    },
+    Md5sum {
+        local: String,
+        remote: String,
+    },
+    TypeHash {
+        local: String,
+        remote: String,
+    },
+    /// The definition of a message isn't known on one side, so its fields couldn't be compared
+    MissingDefinition {
+        message: String,
+        missing_from: Side,
+    },
+    /// A field or constant only exists on one side
+    MissingField {
+        message: String,
+        field: String,
+        missing_from: Side,
+    },
+    /// A field or constant has another name, but the same type and place, on the remote side
+    RenamedField {
+        message: String,
+        local: String,
+        remote: String,
+    },
+    /// A field has a different type, or is a constant on only one side
+    FieldType {
+        message: String,
+        field: String,
+        local: String,
+        remote: String,
+    },
+    ConstantValue {
+        message: String,
+        constant: String,
+        local: String,
+        remote: String,
+    },
+    /// The fields both sides have are serialized in a different order
+    FieldOrder {
+        message: String,
+    },
+}
+
+impl Display for SchemaDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaDifference::TypeName { local, remote } => {
+                write!(f, "type is {local} locally but {remote} remotely")
+            }
+            SchemaDifference::Md5sum { local, remote } => {
+                write!(f, "md5sum is {local} locally but {remote} remotely")
+            }
+            SchemaDifference::TypeHash { local, remote } => {
+                write!(f, "type hash is {local} locally but {remote} remotely")
+            }
+            SchemaDifference::MissingDefinition {
+                message,
+                missing_from,
+            } => write!(f, "definition of {message} is not known {missing_from}"),
+            SchemaDifference::MissingField {
+                message,
+                field,
+                missing_from,
+            } => write!(f, "{message} has no field {field} {missing_from}"),
+            SchemaDifference::RenamedField {
+                message,
+                local,
+                remote,
+            } => write!(f, "field {local} of {message} is named {remote} remotely"),
+            SchemaDifference::FieldType {
+                message,
+                field,
+                local,
+                remote,
+            } => write!(
+                f,
+                "field {field} of {message} is {local} locally but {remote} remotely"
+            ),
+            SchemaDifference::ConstantValue {
+                message,
+                constant,
+                local,
+                remote,
+            } => write!(
+                f,
+                "constant {constant} of {message} is {local} locally but {remote} remotely"
+            ),
+            SchemaDifference::FieldOrder { message } => {
+                write!(f, "fields of {message} are in a different order")
+            }
+        }
+    }
+}
+
+/// The result of [MessageSchema::compare], which displays as a human-readable report
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaReport {
+    pub local: String,
+    pub remote: String,
+    pub differences: Vec<SchemaDifference>,
+}
+
+impl SchemaReport {
+    /// True when no differences were found
+    pub fn is_compatible(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl Display for SchemaReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_compatible() {
+            return write!(f, "{} is compatible with {}", self.local, self.remote);
+        }
+        write!(f, "{} is incompatible with {}:", self.local, self.remote)?;
+        for difference in &self.differences {
+            write!(f, "\n  - {difference}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits a full definition into the fields of each message it contains
+fn parse_definition(type_name: &str, definition: &str) -> BTreeMap<String, Vec<SchemaField>> {
+    let mut messages = BTreeMap::new();
+    let mut current = type_name.to_owned();
+    let mut fields = vec![];
+    for line in definition.lines() {
+        let line = line.trim();
+        if line.starts_with("==========") {
+            messages.insert(std::mem::take(&mut current), std::mem::take(&mut fields));
+            continue;
+        }
+        if let Some(name) = line.strip_prefix("MSG: ") {
+            current = name.trim().to_owned();
+            continue;
+        }
+        let package = current.split('/').next().unwrap_or_default();
+        if let Some(field) = parse_line(line, package) {
+            fields.push(field);
+        }
+    }
+    if !fields.is_empty() || !definition.is_empty() {
+        messages.insert(current, fields);
+    }
+    messages
+}
+
+/// Parses a field or constant, filling in the package of message types
+fn parse_line(line: &str, package: &str) -> Option<SchemaField> {
+    let (line, constant) = match line.split_once('=') {
+        // Comments are part of string constants
+        Some((declaration, value)) if !declaration.contains('#') => {
+            (declaration, Some(value.trim().to_owned()))
+        }
+        _ => (line.split('#').next().unwrap_or_default(), None),
+    };
+    let mut tokens = line.split_whitespace();
+    let field_type = tokens.next()?;
+    let name = tokens.next()?.to_owned();
+    let constant = match constant {
+        Some(value) if field_type != "string" => Some(
+            value
+                .split('#')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_owned(),
+        ),
+        constant => constant,
+    };
+
+    let (element_type, array) = match field_type.find('[') {
+        Some(i) => field_type.split_at(i),
+        None => (field_type, ""),
+    };
+    let element_type = if BASE_TYPES.contains(&element_type) || element_type.contains('/') {
+        element_type.to_owned()
+    } else if element_type == "Header" {
+        "std_msgs/Header".to_owned()
+    } else {
+        format!("{package}/{element_type}")
+    };
+    Some(SchemaField {
+        field_type: format!("{element_type}{array}"),
+        name,
+        constant,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEP: &str =
+        "================================================================================";
+
+    fn header(stamp: &str) -> String {
+        format!("{SEP}\nMSG: std_msgs/Header\nuint32 seq\n{stamp} stamp\nstring frame_id\n")
+    }
+
+    #[test]
+    fn parses_definitions() {
+        let schema = MessageSchema::new(
+            "test_msgs/Stamped",
+            format!(
+                "# A comment\nuint8 OK=0 # ok\nstring NAME=a # b\nHeader header\nPoint[3] points # c\n{}{SEP}\nMSG: test_msgs/Point\nfloat64 x 0\n",
+                header("time")
+            ),
+        );
+        assert!(schema.md5sum.is_some());
+        assert_eq!(
+            schema.fields().unwrap(),
+            [
+                SchemaField {
+                    field_type: "uint8".to_owned(),
+                    name: "OK".to_owned(),
+                    constant: Some("0".to_owned()),
+                },
+                SchemaField {
+                    field_type: "string".to_owned(),
+                    name: "NAME".to_owned(),
+                    constant: Some("a # b".to_owned()),
+                },
+                SchemaField {
+                    field_type: "std_msgs/Header".to_owned(),
+                    name: "header".to_owned(),
+                    constant: None,
+                },
+                SchemaField {
+                    field_type: "test_msgs/Point[3]".to_owned(),
+                    name: "points".to_owned(),
+                    constant: None,
+                },
+            ]
+        );
+        assert_eq!(schema.messages.len(), 3);
+        assert_eq!(schema.messages["test_msgs/Point"][0].name, "x");
+
+        let unknown = MessageSchema::new("test_msgs/Stamped", "").with_md5sum("*");
+        assert_eq!(unknown.md5sum, None);
+        assert_eq!(unknown.fields(), None);
+    }
+
+    #[test]
+    fn reports_differences() {
+        let local = MessageSchema::new(
+            "test_msgs/Stamped",
+            format!(
+                "uint8 OK=0\nHeader header\nfloat64 a\nint32 b\n{}",
+                header("time")
+            ),
+        );
+        assert!(local.compare(&local).is_compatible());
+
+        let remote = MessageSchema::new(
+            "test_msgs/Stamped",
+            format!(
+                "uint8 OK=1\nHeader header\nint32 b\nfloat32 a\nstring c\n{}",
+                header("builtin_interfaces/Time")
+            ),
+        )
+        .with_md5sum("0123456789abcdef0123456789abcdef");
+        let report = local.compare(&remote);
+        assert!(!report.is_compatible());
+        assert_eq!(
+            report.differences[1..],
+            [
+                SchemaDifference::ConstantValue {
+                    message: "test_msgs/Stamped".to_owned(),
+                    constant: "OK".to_owned(),
+                    local: "0".to_owned(),
+                    remote: "1".to_owned(),
+                },
+                SchemaDifference::FieldType {
+                    message: "test_msgs/Stamped".to_owned(),
+                    field: "a".to_owned(),
+                    local: "float64".to_owned(),
+                    remote: "float32".to_owned(),
+                },
+                SchemaDifference::MissingField {
+                    message: "test_msgs/Stamped".to_owned(),
+                    field: "c".to_owned(),
+                    missing_from: Side::Local,
+                },
+                SchemaDifference::FieldOrder {
+                    message: "test_msgs/Stamped".to_owned(),
+                },
+                SchemaDifference::FieldType {
+                    message: "std_msgs/Header".to_owned(),
+                    field: "stamp".to_owned(),
+                    local: "time".to_owned(),
+                    remote: "builtin_interfaces/Time".to_owned(),
+                },
+            ]
+        );
+        assert!(matches!(
+            report.differences[0],
+            SchemaDifference::Md5sum { .. }
+        ));
+        let text = report.to_string();
+        assert!(text.starts_with("test_msgs/Stamped is incompatible with test_msgs/Stamped:"));
+        assert!(text.contains(
+            "\n  - field a of test_msgs/Stamped is float64 locally but float32 remotely"
+        ));
+
+        let renamed = MessageSchema::new(
+            "test_msgs/Stamped",
+            format!(
+                "uint8 OK=0\nHeader header\nfloat64 alpha\nint32 b\n{}",
+                header("time")
+            ),
+        );
+        assert_eq!(
+            local.compare(&renamed).differences[1..],
+            [SchemaDifference::RenamedField {
+                message: "test_msgs/Stamped".to_owned(),
+                local: "a".to_owned(),
+                remote: "alpha".to_owned(),
+            }]
+        );
+
+        let report = local.compare(&MessageSchema::new("test_msgs/Stamped", "").with_md5sum("*"));
+        assert_eq!(
+            report.differences,
+            [SchemaDifference::MissingDefinition {
+                message: "test_msgs/Stamped".to_owned(),
+                missing_from: Side::Remote,
+            }]
+        );
+    }
+
+    #[test]
+    fn pretty_prints_and_diffs() {
+        let old = MessageSchema::new(
+            "test_msgs/Stamped",
+            format!("uint8 OK=0\nHeader header\nfloat64 a\n{}", header("time")),
+        );
+        assert_eq!(
+            old.pretty().to_string(),
+            "test_msgs/Stamped\n  uint8 OK=0\n  std_msgs/Header header\n    uint32 seq\n    time stamp\n    string frame_id\n  float64 a\n"
+        );
+        assert!(old.diff(&old).is_empty());
+
+        let new = MessageSchema::new(
+            "test_msgs/Stamped",
+            format!(
+                "uint8 OK=0\nHeader header\nfloat32 a\nstring c\n{}",
+                header("time")
+            ),
+        );
+        let diff = old.diff(&new);
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.to_string(),
+            "  test_msgs/Stamped\n    uint8 OK=0\n    std_msgs/Header header\n      uint32 seq\n      time stamp\n      string frame_id\n-   float64 a\n+   float32 a\n+   string c\n"
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! Looks up the state of a stamped topic at any time, interpolating between messages, see [Cache] and [Interpolate].
+//!
+//! Estimation pipelines often need e.g. the pose of a robot at the stamp of a camera image, which falls between the
+//! stamps of two pose messages. A [Cache] keeps the recent messages of a topic ordered by the stamps of their
+//! headers, like the Cache of message_filters, and [Cache::at] blends the two messages around a time with
+//! [Interpolate]. Generated message types implement [Interpolate] using [lerp] for positions and velocities and
+//! [slerp] for orientations:
+//! ```
+//! use roslibrust_common::monitor::stamp_time;
+//! use roslibrust_common::stamped::{lerp, slerp, Cache, Interpolate};
+//!
+//! // Stands in for a generated geometry_msgs/PoseStamped
+//! #[derive(Clone)]
+//! struct PoseStamped {
+//!     secs: u32,
+//!     nsecs: u32,
+//!     position: [f64; 3],
+//!     orientation: [f64; 4],
+//! }
+//!
+//! impl Interpolate for PoseStamped {
+//!     fn interpolate(&self, other: &Self, t: f64) -> Self {
+//!         // The stamp isn't used by the cache once the message is added
+//!         PoseStamped {
+//!             position: self.position.interpolate(&other.position, t),
+//!             orientation: slerp(self.orientation, other.orientation, t),
+//!             ..self.clone()
+//!         }
+//!     }
+//! }
+//!
+//! let mut poses = Cache::new(100, |pose: &PoseStamped| stamp_time(pose.secs, pose.nsecs));
+//! poses.add(PoseStamped { secs: 10, nsecs: 0, position: [0.0; 3], orientation: [0.0, 0.0, 0.0, 1.0] });
+//! poses.add(PoseStamped { secs: 11, nsecs: 0, position: [2.0, 0.0, 0.0], orientation: [0.0, 0.0, 0.0, 1.0] });
+//! let pose = poses.at(stamp_time(10, 500_000_000).unwrap()).unwrap();
+//! assert_eq!(pose.position, [1.0, 0.0, 0.0]);
+//! assert_eq!(lerp(0.0, 2.0, 0.25), 0.5);
+//! ```
+
+use std::collections::VecDeque;
+use std::time::SystemTime;
+
+/// Values which can be blended between two instances, e.g. the poses or twists of a robot at two times.
+pub trait Interpolate {
+    /// Returns the value `t` of the way from `self` to `other`, `self` for `t` = 0 and `other` for `t` = 1.
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        lerp(*self, *other, t)
+    }
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        lerp(f64::from(*self), f64::from(*other), t) as f32
+    }
+}
+
+// Blends each component, e.g. of a position or a velocity
+impl<const N: usize> Interpolate for [f64; N] {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        std::array::from_fn(|i| lerp(self[i], other[i], t))
+    }
+}
+
+/// Linear interpolation, `a` for `t` = 0 and `b` for `t` = 1
+pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Spherical linear interpolation of two unit quaternions in the x, y, z, w order of `geometry_msgs/Quaternion`,
+/// rotating at a constant rate along the shortest way from `a` to `b`. The result is normalized.
+pub fn slerp(a: [f64; 4], b: [f64; 4], t: f64) -> [f64; 4] {
+    let dot: f64 = a.iter().zip(&b).map(|(a, b)| a * b).sum();
+    // q and -q are the same rotation, going to whichever is closer takes the shorter way around
+    let (b, dot) = if dot < 0.0 {
+        (b.map(|c| -c), -dot)
+    } else {
+        (b, dot)
+    };
+    // For nearly equal rotations the sine below vanishes, where linear interpolation is just as accurate
+    if dot > 0.9995 {
+        return normalize(a.interpolate(&b, t));
+    }
+    let angle = dot.acos();
+    let sin = angle.sin();
+    let weight_a = ((1.0 - t) * angle).sin() / sin;
+    let weight_b = (t * angle).sin() / sin;
+    normalize(std::array::from_fn(|i| weight_a * a[i] + weight_b * b[i]))
+}
+
+fn normalize(q: [f64; 4]) -> [f64; 4] {
+    let norm = q.iter().map(|c| c * c).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return q;
+    }
+    q.map(|c| c / norm)
+}
+
+/// Keeps the most recent messages of a topic ordered by their stamps, the equivalent of message_filters' Cache.
+///
+/// Messages may be added out of order, they are sorted by the stamp returned by the function given to
+/// [Cache::new], typically the stamp of their header converted with [crate::monitor::stamp_time]. Once more than
+/// the capacity are held, the oldest stamped message is dropped.
+#[derive(Clone, Debug)]
+pub struct Cache<T> {
+    capacity: usize,
+    stamp: fn(&T) -> Option<SystemTime>,
+    messages: VecDeque<(SystemTime, T)>,
+}
+
+impl<T> Cache<T> {
+    /// Creates a cache holding up to `capacity` messages, at least one, ordered by `stamp`.
+    pub fn new(capacity: usize, stamp: fn(&T) -> Option<SystemTime>) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            stamp,
+            messages: VecDeque::new(),
+        }
+    }
+
+    /// Adds a message, returning false if it wasn't added because it has no stamp. A message with the same stamp
+    /// as one already held is placed after it.
+    pub fn add(&mut self, msg: T) -> bool {
+        let Some(stamp) = (self.stamp)(&msg) else {
+            return false;
+        };
+        let index = self
+            .messages
+            .partition_point(|(existing, _)| *existing <= stamp);
+        self.messages.insert(index, (stamp, msg));
+        if self.messages.len() > self.capacity {
+            self.messages.pop_front();
+        }
+        true
+    }
+
+    /// The number of messages held
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Whether no messages are held
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// The stamp of the oldest message held
+    pub fn oldest_stamp(&self) -> Option<SystemTime> {
+        self.messages.front().map(|(stamp, _)| *stamp)
+    }
+
+    /// The stamp of the newest message held
+    pub fn newest_stamp(&self) -> Option<SystemTime> {
+        self.messages.back().map(|(stamp, _)| *stamp)
+    }
+
+    /// The messages stamped from `start` to `end` inclusive, oldest first
+    pub fn interval(&self, start: SystemTime, end: SystemTime) -> impl Iterator<Item = &T> {
+        self.messages
+            .iter()
+            .skip_while(move |(stamp, _)| *stamp < start)
+            .take_while(move |(stamp, _)| *stamp <= end)
+            .map(|(_, msg)| msg)
+    }
+
+    /// The newest message stamped at or before `time`
+    pub fn before(&self, time: SystemTime) -> Option<&T> {
+        let index = self.messages.partition_point(|(stamp, _)| *stamp <= time);
+        index.checked_sub(1).map(|index| &self.messages[index].1)
+    }
+
+    /// The oldest message stamped at or after `time`
+    pub fn after(&self, time: SystemTime) -> Option<&T> {
+        let index = self.messages.partition_point(|(stamp, _)| *stamp < time);
+        self.messages.get(index).map(|(_, msg)| msg)
+    }
+}
+
+impl<T: Interpolate + Clone> Cache<T> {
+    /// The state at `time`, interpolated between the messages stamped right before and after it.
+    ///
+    /// Returns None when `time` is outside of the stamps held, as extrapolating would be guesswork. A message
+    /// stamped exactly at `time` is returned as is.
+    pub fn at(&self, time: SystemTime) -> Option<T> {
+        let index = self.messages.partition_point(|(stamp, _)| *stamp < time);
+        let (after_stamp, after) = self.messages.get(index)?;
+        if *after_stamp == time {
+            return Some(after.clone());
+        }
+        let (before_stamp, before) = &self.messages[index.checked_sub(1)?];
+        let span = after_stamp.duration_since(*before_stamp).ok()?;
+        let elapsed = time.duration_since(*before_stamp).ok()?;
+        Some(before.interpolate(after, elapsed.as_secs_f64() / span.as_secs_f64()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn at(millis: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_millis(millis)
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Twist {
+        millis: u64,
+        linear: [f64; 3],
+    }
+
+    impl Interpolate for Twist {
+        fn interpolate(&self, other: &Self, t: f64) -> Self {
+            Twist {
+                millis: 0,
+                linear: self.linear.interpolate(&other.linear, t),
+            }
+        }
+    }
+
+    fn twist(millis: u64, x: f64) -> Twist {
+        Twist {
+            millis,
+            linear: [x, 0.0, 0.0],
+        }
+    }
+
+    #[test]
+    fn interpolates_between_stamps() {
+        let mut cache = Cache::new(3, |twist: &Twist| {
+            (twist.millis > 0).then(|| at(twist.millis))
+        });
+        assert!(cache.add(twist(200, 2.0)));
+        // Out of order, and without a stamp
+        assert!(cache.add(twist(100, 1.0)));
+        assert!(!cache.add(twist(0, 5.0)));
+        assert!(cache.add(twist(400, 0.0)));
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.oldest_stamp(), Some(at(100)));
+
+        assert_eq!(cache.at(at(150)).unwrap().linear, [1.5, 0.0, 0.0]);
+        assert_eq!(cache.at(at(300)).unwrap().linear, [1.0, 0.0, 0.0]);
+        assert_eq!(cache.at(at(200)), Some(twist(200, 2.0)));
+        assert_eq!(cache.at(at(50)), None);
+        assert_eq!(cache.at(at(500)), None);
+
+        assert_eq!(cache.before(at(399)), Some(&twist(200, 2.0)));
+        assert_eq!(cache.after(at(201)), Some(&twist(400, 0.0)));
+        assert_eq!(cache.interval(at(100), at(200)).count(), 2);
+
+        // The oldest is dropped beyond the capacity
+        cache.add(twist(500, 1.0));
+        assert_eq!(cache.oldest_stamp(), Some(at(200)));
+        assert_eq!(cache.newest_stamp(), Some(at(500)));
+    }
+
+    #[test]
+    fn slerps_the_short_way() {
+        let identity = [0.0, 0.0, 0.0, 1.0];
+        // 90 degrees about z
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        let quarter_turn = [0.0, 0.0, half, half];
+        let q = slerp(identity, quarter_turn, 0.5);
+        let expected = [
+            0.0,
+            0.0,
+            (std::f64::consts::PI / 8.0).sin(),
+            (std::f64::consts::PI / 8.0).cos(),
+        ];
+        for (q, expected) in q.iter().zip(expected) {
+            assert!((q - expected).abs() < 1e-9, "{q:?}");
+        }
+
+        // The negated quaternion is the same rotation, so the result is too
+        let negated = quarter_turn.map(|c| -c);
+        let q_negated = slerp(identity, negated, 0.5);
+        for (q, expected) in q_negated.iter().zip(expected) {
+            assert!((q - expected).abs() < 1e-9, "{q_negated:?}");
+        }
+
+        assert_eq!(slerp(identity, identity, 0.3), identity);
+        assert_eq!(lerp(1.0, 3.0, 0.5), 2.0);
+    }
+}
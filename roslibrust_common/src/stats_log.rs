@@ -0,0 +1,312 @@
+use crate::mcap::McapWriter;
+use crate::monitor::TopicStats;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Persists [TopicStats] as a time series, for analyzing after the fact what the traffic of a node looked like
+/// e.g. during an incident in the field.
+///
+/// [CsvStatsLog] writes a CSV file for spreadsheets and scripts, [McapStatsLog] an MCAP file which can be opened
+/// in Foxglove next to a recording of the topics themselves.
+pub trait StatsLog {
+    /// Appends the statistics of `topic` as of `time`.
+    fn write(&mut self, time: SystemTime, topic: &str, stats: &TopicStats) -> io::Result<()>;
+
+    /// Flushes the entries written so far to the underlying writer.
+    fn flush(&mut self) -> io::Result<()>;
+}
+
+/// A field of [TopicStats] as written by the logs
+enum Value {
+    Count(u64),
+    Number(f64),
+    // In seconds, None when unknown
+    Seconds(Option<Duration>),
+}
+
+/// The fields written for each entry, in order
+fn fields(stats: &TopicStats) -> [(&'static str, Value); 17] {
+    [
+        ("messages", Value::Count(stats.messages)),
+        ("bytes", Value::Count(stats.bytes)),
+        ("dropped", Value::Count(stats.dropped)),
+        (
+            "window_messages",
+            Value::Count(stats.window_messages as u64),
+        ),
+        ("rate_hz", Value::Number(stats.rate_hz)),
+        ("bandwidth", Value::Number(stats.bandwidth)),
+        ("mean_size", Value::Number(stats.mean_size)),
+        ("min_period", Value::Seconds(stats.min_period)),
+        ("max_period", Value::Seconds(stats.max_period)),
+        ("mean_period", Value::Seconds(stats.mean_period)),
+        ("jitter", Value::Seconds(stats.jitter)),
+        ("since_last", Value::Seconds(stats.since_last)),
+        ("mean_latency", Value::Seconds(stats.mean_latency)),
+        ("max_latency", Value::Seconds(stats.max_latency)),
+        ("latency_p50", Value::Seconds(stats.latency_p50)),
+        ("latency_p90", Value::Seconds(stats.latency_p90)),
+        ("latency_p99", Value::Seconds(stats.latency_p99)),
+    ]
+}
+
+fn since_epoch(time: SystemTime) -> Duration {
+    time.duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+/// Writes one CSV row per entry, with the time in seconds since the epoch, the topic, and the fields of
+/// [TopicStats] with durations in seconds. Durations which are unknown are left empty.
+pub struct CsvStatsLog<W: Write> {
+    writer: W,
+    wrote_header: bool,
+}
+
+impl CsvStatsLog<BufWriter<File>> {
+    /// Creates or truncates the file at `path`.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> CsvStatsLog<W> {
+    /// Writes to `writer`, starting with a header row.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            wrote_header: false,
+        }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> StatsLog for CsvStatsLog<W> {
+    fn write(&mut self, time: SystemTime, topic: &str, stats: &TopicStats) -> io::Result<()> {
+        let fields = fields(stats);
+        if !self.wrote_header {
+            let names: Vec<_> = fields.iter().map(|(name, _)| *name).collect();
+            writeln!(self.writer, "time,topic,{}", names.join(","))?;
+            self.wrote_header = true;
+        }
+        // Topic names can't contain commas or quotes, other names are quoted to be safe
+        let topic = if topic.contains([',', '"', '\n']) {
+            format!("\"{}\"", topic.replace('"', "\"\""))
+        } else {
+            topic.to_string()
+        };
+        write!(self.writer, "{},{topic}", since_epoch(time).as_secs_f64())?;
+        for (_, value) in fields {
+            match value {
+                Value::Count(count) => write!(self.writer, ",{count}")?,
+                Value::Number(number) => write!(self.writer, ",{number}")?,
+                Value::Seconds(Some(duration)) => {
+                    write!(self.writer, ",{}", duration.as_secs_f64())?
+                }
+                Value::Seconds(None) => write!(self.writer, ",")?,
+            }
+        }
+        writeln!(self.writer)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Writes an MCAP file with a JSON encoded channel per topic, named `/stats` followed by the topic
+/// e.g. `/stats/chatter`, whose messages have the fields of [TopicStats] with durations in seconds.
+///
+/// The file isn't indexed, which readers like Foxglove handle by scanning it. It is finished when the log is dropped
+/// or [McapStatsLog::finish]ed, a file which wasn't finished e.g. because the process crashed is missing its footer,
+/// which `mcap recover` restores.
+pub struct McapStatsLog<W: Write> {
+    writer: McapWriter<W>,
+    schema_id: u16,
+    // The id of the channel of each topic
+    channels: BTreeMap<String, u16>,
+}
+
+impl McapStatsLog<BufWriter<File>> {
+    /// Creates or truncates the file at `path`.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write> McapStatsLog<W> {
+    /// Writes to `writer`, starting with the file's header and the schema of the entries.
+    pub fn new(writer: W) -> io::Result<Self> {
+        let mut writer = McapWriter::new(writer, "")?;
+        let properties: Vec<_> = fields(&TopicStats::default())
+            .iter()
+            .map(|(name, value)| {
+                let kind = match value {
+                    Value::Count(_) => r#""integer""#,
+                    Value::Number(_) => r#""number""#,
+                    Value::Seconds(_) => r#"["number","null"]"#,
+                };
+                format!(r#""{name}":{{"type":{kind}}}"#)
+            })
+            .collect();
+        let schema = format!(
+            r#"{{"type":"object","properties":{{{}}}}}"#,
+            properties.join(",")
+        );
+        let schema_id =
+            writer.add_schema("roslibrust.TopicStats", "jsonschema", schema.as_bytes())?;
+
+        Ok(Self {
+            writer,
+            schema_id,
+            channels: BTreeMap::new(),
+        })
+    }
+
+    /// Writes the end of the file and returns the underlying writer.
+    pub fn finish(self) -> io::Result<W> {
+        self.writer.finish()
+    }
+}
+
+impl<W: Write> StatsLog for McapStatsLog<W> {
+    fn write(&mut self, time: SystemTime, topic: &str, stats: &TopicStats) -> io::Result<()> {
+        let channel_id = match self.channels.get(topic) {
+            Some(id) => *id,
+            None => {
+                let id =
+                    self.writer
+                        .add_channel(self.schema_id, &format!("/stats{topic}"), "json")?;
+                self.channels.insert(topic.to_string(), id);
+                id
+            }
+        };
+
+        let values: Vec<_> = fields(stats)
+            .into_iter()
+            .map(|(name, value)| match value {
+                Value::Count(count) => format!(r#""{name}":{count}"#),
+                // JSON has no representation of NaN or infinity
+                Value::Number(number) if !number.is_finite() => format!(r#""{name}":null"#),
+                Value::Number(number) => format!(r#""{name}":{number}"#),
+                Value::Seconds(Some(duration)) => {
+                    format!(r#""{name}":{}"#, duration.as_secs_f64())
+                }
+                Value::Seconds(None) => format!(r#""{name}":null"#),
+            })
+            .collect();
+        let data = format!("{{{}}}", values.join(","));
+        self.writer
+            .write_message(channel_id, time, time, data.as_bytes())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MCAP_MAGIC: &[u8] = b"\x89MCAP0\r\n";
+    const MCAP_HEADER: u8 = 0x01;
+    const MCAP_FOOTER: u8 = 0x02;
+    const MCAP_SCHEMA: u8 = 0x03;
+    const MCAP_CHANNEL: u8 = 0x04;
+    const MCAP_MESSAGE: u8 = 0x05;
+    const MCAP_DATA_END: u8 = 0x0F;
+
+    fn stats() -> TopicStats {
+        TopicStats {
+            messages: 20,
+            bytes: 2000,
+            rate_hz: 10.0,
+            mean_period: Some(Duration::from_millis(100)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn writes_csv() {
+        let mut log = CsvStatsLog::new(Vec::new());
+        let time = UNIX_EPOCH + Duration::from_millis(1500);
+        log.write(time, "/chatter", &stats()).unwrap();
+        log.write(time, "/a,b", &TopicStats::default()).unwrap();
+        let csv = String::from_utf8(log.into_inner()).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "time,topic,messages,bytes,dropped,window_messages,rate_hz,bandwidth,mean_size,min_period,\
+                max_period,mean_period,jitter,since_last,mean_latency,max_latency,latency_p50,latency_p90,latency_p99",
+                "1.5,/chatter,20,2000,0,0,10,0,0,,,0.1,,,,,,,",
+                "1.5,\"/a,b\",0,0,0,0,0,0,0,,,,,,,,,,",
+            ]
+        );
+    }
+
+    /// Splits the records after the leading magic into their opcodes and bodies
+    fn records(mut data: &[u8]) -> Vec<(u8, &[u8])> {
+        let mut records = Vec::new();
+        while data.len() > MCAP_MAGIC.len() {
+            let length = u64::from_le_bytes(data[1..9].try_into().unwrap()) as usize;
+            records.push((data[0], &data[9..9 + length]));
+            data = &data[9 + length..];
+        }
+        assert_eq!(data, MCAP_MAGIC);
+        records
+    }
+
+    #[test]
+    fn writes_mcap() {
+        let mut log = McapStatsLog::new(Vec::new()).unwrap();
+        let time = UNIX_EPOCH + Duration::from_secs(2);
+        log.write(time, "/chatter", &stats()).unwrap();
+        log.write(time, "/chatter", &stats()).unwrap();
+        log.write(time, "/scan", &TopicStats::default()).unwrap();
+        let data = log.finish().unwrap();
+
+        assert_eq!(&data[..MCAP_MAGIC.len()], MCAP_MAGIC);
+        let records = records(&data[MCAP_MAGIC.len()..]);
+        let opcodes: Vec<_> = records.iter().map(|(opcode, _)| *opcode).collect();
+        assert_eq!(
+            opcodes,
+            [
+                MCAP_HEADER,
+                MCAP_SCHEMA,
+                MCAP_CHANNEL,
+                MCAP_MESSAGE,
+                MCAP_MESSAGE,
+                MCAP_CHANNEL,
+                MCAP_MESSAGE,
+                MCAP_DATA_END,
+                MCAP_FOOTER
+            ]
+        );
+
+        // Channel 1 is /stats/chatter
+        let channel = records[2].1;
+        assert_eq!(&channel[..2], &1u16.to_le_bytes());
+        assert_eq!(&channel[8..22], b"/stats/chatter");
+        // Second message of channel 1, logged 2 seconds after the epoch
+        let message = records[4].1;
+        assert_eq!(&message[..2], &1u16.to_le_bytes());
+        assert_eq!(&message[2..6], &1u32.to_le_bytes());
+        assert_eq!(&message[6..14], &2_000_000_000u64.to_le_bytes());
+        let json = std::str::from_utf8(&message[22..]).unwrap();
+        assert!(
+            json.starts_with(r#"{"messages":20,"bytes":2000,"#),
+            "{json}"
+        );
+        assert!(
+            json.contains(r#""mean_period":0.1,"jitter":null"#),
+            "{json}"
+        );
+    }
+}
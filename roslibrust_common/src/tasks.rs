@@ -0,0 +1,116 @@
+//! Backends run their network I/O in background tasks: one per connection to a publisher, subscriber, or service,
+//! plus tasks which accept connections or clean up after dropped handles. A [TaskRegistry] keeps track of which
+//! of them are running, to find ones which outlive the handles which started them.
+//!
+//! The ros1 and rosbridge backends also name their tasks after the same description, which `tokio-console` shows
+//! when their `tokio-console` feature is enabled and the application is built with `RUSTFLAGS="--cfg tokio_unstable"`.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::time::Instant;
+
+/// A background task started by a backend, see [TaskRegistry::tasks].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskInfo {
+    /// Unique within the registry, in the order the tasks were started
+    pub id: u64,
+    /// What the task does, e.g. `ros1 subscriber /chatter from http://robot:34567/`
+    pub name: String,
+    pub started: Instant,
+}
+
+#[derive(Debug, Default)]
+struct Tasks {
+    next_id: u64,
+    running: BTreeMap<u64, TaskInfo>,
+}
+
+/// The background tasks a backend is running, shared between clones.
+#[derive(Debug, Clone, Default)]
+pub struct TaskRegistry {
+    tasks: Arc<Mutex<Tasks>>,
+}
+
+impl TaskRegistry {
+    /// The tasks currently running, oldest first.
+    pub fn tasks(&self) -> Vec<TaskInfo> {
+        self.lock().running.values().cloned().collect()
+    }
+
+    /// Wraps the future of a task so it is listed under `name` until it completes or is dropped, called by backends.
+    pub fn track<F: Future>(
+        &self,
+        name: impl Into<String>,
+        future: F,
+    ) -> impl Future<Output = F::Output> {
+        let guard = self.register(name.into());
+        async move {
+            let _guard = guard;
+            future.await
+        }
+    }
+
+    fn register(&self, name: String) -> TaskGuard {
+        let mut tasks = self.lock();
+        let id = tasks.next_id;
+        tasks.next_id += 1;
+        tasks.running.insert(
+            id,
+            TaskInfo {
+                id,
+                name,
+                started: Instant::now(),
+            },
+        );
+        TaskGuard {
+            tasks: Arc::downgrade(&self.tasks),
+            id,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Tasks> {
+        self.tasks.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Removes a task from its registry when the task's future is dropped
+struct TaskGuard {
+    tasks: Weak<Mutex<Tasks>>,
+    id: u64,
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        if let Some(tasks) = self.tasks.upgrade() {
+            tasks
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .running
+                .remove(&self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    #[test]
+    fn lists_running_tasks() {
+        let registry = TaskRegistry::default();
+        let first = registry.track("first", async {});
+        let second = registry.track("second", std::future::pending::<()>());
+        let names: Vec<_> = registry.tasks().into_iter().map(|t| t.name).collect();
+        assert_eq!(names, ["first", "second"]);
+
+        // Completed and dropped tasks are no longer listed
+        let mut context = Context::from_waker(Waker::noop());
+        assert_eq!(pin!(first).poll(&mut context), Poll::Ready(()));
+        assert_eq!(registry.tasks()[0].name, "second");
+        drop(second);
+        assert!(registry.tasks().is_empty());
+    }
+}
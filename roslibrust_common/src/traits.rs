@@ -1,6 +1,9 @@
 use std::future::Future;
 
-use crate::{Result, RosMessageType, RosServiceType, ServiceFn};
+use crate::{
+    schema::MessageSchema, Error, Result, RosMessageType, RosServiceType, ServiceFn, ToParamName,
+    ToServiceName, ToTopicName,
+};
 
 /// Indicates that something is a publisher and has our expected publish
 /// Implementors of this trait are expected to auto-cleanup the publisher when dropped
@@ -23,7 +26,8 @@ pub trait Subscribe<T: RosMessageType> {
 /// This trait generically describes the capability of something to act as an async interface to a set of topics
 ///
 /// This trait is largely based on ROS concepts, but could be extended to other protocols / concepts.
-/// Fundamentally, it assumes that topics are uniquely identified by a string name (likely an ASCII assumption is buried in here...).
+/// Fundamentally, it assumes that topics are uniquely identified by a name, see [crate::TopicName].
+/// Topic names can be passed as `&str` or `String`, and are validated before the backend is contacted.
 /// It assumes topics only carry one data type, but is not expected to enforce that.
 /// It assumes that all actions can fail due to a variety of causes, and by network interruption specifically.
 pub trait TopicProvider {
@@ -38,7 +42,7 @@ pub trait TopicProvider {
     /// The returned publisher is expected to be "self de-registering", where dropping the publisher results in the appropriate unadvertise operation.
     fn advertise<T: RosMessageType>(
         &self,
-        topic: &str,
+        topic: impl ToTopicName + Send,
     ) -> impl Future<Output = Result<Self::Publisher<T>>> + Send;
 
     /// Subscribes to a topic and returns a type specific subscriber to use.
@@ -46,8 +50,83 @@ pub trait TopicProvider {
     /// The returned subscriber is expected to be "self de-registering", where dropping the subscriber results in the appropriate unsubscribe operation.
     fn subscribe<T: RosMessageType>(
         &self,
-        topic: &str,
+        topic: impl ToTopicName + Send,
     ) -> impl Future<Output = Result<Self::Subscriber<T>>> + Send;
+
+    /// Advertises a topic like [TopicProvider::advertise], with the quality of service settings in `qos`.
+    ///
+    /// Backends which can't provide a requested setting return an error rather than silently ignoring it, see
+    /// [crate::Capabilities] for which they support. The default implementation supports none.
+    fn advertise_with_qos<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+        qos: PublisherQos,
+    ) -> impl Future<Output = Result<Self::Publisher<T>>> + Send {
+        let advertise = self.advertise::<T>(topic);
+        async move {
+            if qos.latching {
+                return Err(Error::Unsupported("latching publishers".to_string()));
+            }
+            advertise.await
+        }
+    }
+}
+
+/// Quality of service settings of a publisher, see [TopicProvider::advertise_with_qos].
+///
+/// The default is what [TopicProvider::advertise] provides, settings are enabled with struct update syntax:
+/// ```
+/// # use roslibrust_common::PublisherQos;
+/// let qos = PublisherQos {
+///     latching: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PublisherQos {
+    /// Sends the last message published to subscribers which connect later, like a latched ROS1 publisher or a
+    /// ROS2 publisher with transient local durability. Backends without native latching may emulate it.
+    pub latching: bool,
+}
+
+/// Indicates that something is a subscriber receiving messages still serialized, see [RawTopicProvider].
+pub trait RawSubscribe {
+    /// Waits for the next message, returning it encoded as described by [RawTopicProvider::ENCODING].
+    fn next_raw(&mut self) -> impl Future<Output = Result<Vec<u8>>> + Send;
+}
+
+/// Indicates that something is a publisher sending messages already serialized, see [RawTopicProvider].
+pub trait RawPublish {
+    /// Publishes a message encoded as described by [RawTopicProvider::ENCODING].
+    fn publish_raw(&self, data: &[u8]) -> impl Future<Output = Result<()>> + Send;
+}
+
+/// Describes the capability of subscribing and publishing to a topic without knowing its type at compile time,
+/// e.g. to record it or play it back.
+///
+/// Only backends which can learn the type of a topic from the graph implement this trait.
+pub trait RawTopicProvider {
+    type RawPublisher: RawPublish + Send + Sync + 'static;
+    type RawSubscriber: RawSubscribe + Send + 'static;
+
+    /// The encoding of the messages of raw publishers and subscribers, named like the message encodings of
+    /// MCAP files, e.g. `ros1` for the ROS1 wire format without the leading length.
+    const ENCODING: &'static str;
+
+    /// Advertises a topic of the type described by `schema`, as returned by [RawTopicProvider::subscribe_raw].
+    fn advertise_raw(
+        &self,
+        topic: impl ToTopicName + Send,
+        schema: &MessageSchema,
+    ) -> impl Future<Output = Result<Self::RawPublisher>> + Send;
+
+    /// Subscribes to a topic of any type, returning the schema of its messages along with the subscriber.
+    ///
+    /// Fails if the type of the topic can't be determined, e.g. because nothing publishes it yet.
+    fn subscribe_raw(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> impl Future<Output = Result<(MessageSchema, Self::RawSubscriber)>> + Send;
 }
 
 /// Defines what it means to be something that is callable as a service
@@ -56,6 +135,8 @@ pub trait Service<T: RosServiceType> {
 }
 
 /// This trait is analogous to TopicProvider, but instead provides the capability to create service servers and service clients
+///
+/// Service names can be passed as `&str` or `String`, and are validated before the backend is contacted, see [crate::ServiceName].
 pub trait ServiceProvider {
     type ServiceClient<T: RosServiceType>: Service<T> + Send + 'static;
     type ServiceServer: Send + 'static;
@@ -63,7 +144,7 @@ pub trait ServiceProvider {
     /// A "oneshot" service call good for low frequency calls or where the service_provider may not always be available.
     fn call_service<T: RosServiceType>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         request: T::Request,
     ) -> impl Future<Output = Result<T::Response>> + Send;
 
@@ -72,7 +153,7 @@ pub trait ServiceProvider {
     /// Dropping the returned client will perform all needed cleanup.
     fn service_client<T: RosServiceType + 'static>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
     ) -> impl Future<Output = Result<Self::ServiceClient<T>>> + Send;
 
     /// Advertise a service function to be available for clients to call.
@@ -85,13 +166,137 @@ pub trait ServiceProvider {
     ///  - See [roslibrust/examples/ros1_async_service_server.rs](https://github.com/RosLibRust/roslibrust/blob/master/roslibrust/examples/ros1_async_service_server.rs) for an async example of using this function.
     fn advertise_service<T: RosServiceType + 'static, F>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         server: F,
     ) -> impl Future<Output = Result<Self::ServiceServer>> + Send
     where
         F: ServiceFn<T>;
 }
 
+/// Provides access to ROS time, which may be wall clock time or a simulated / virtual clock.
+///
+/// Generic code should use this trait, rather than `std::time::SystemTime` and `tokio::time::sleep` directly,
+/// for anything that should follow ROS time so that it behaves correctly in simulation and in tests.
+pub trait ClockProvider {
+    /// Returns the current ROS time.
+    fn now(&self) -> std::time::SystemTime;
+
+    /// Waits until `duration` of ROS time has elapsed.
+    fn sleep(&self, duration: std::time::Duration) -> impl Future<Output = ()> + Send;
+}
+
+/// Provides access to a ROS parameter server.
+///
+/// Parameters are stored in a tree keyed by their namespaced names, so setting "/robot/wheel/radius" also creates the
+/// "/robot" and "/robot/wheel" namespaces, and getting a namespace returns all of the parameters beneath it as a map.
+/// Values are converted with serde, so any type which can represent the parameter's value can be used to read it.
+pub trait ParamProvider {
+    /// Gets the value of a parameter, returning `Ok(None)` if it is not set.
+    ///
+    /// Returns [crate::Error::SerializationError] if the parameter can't be represented as `T`.
+    fn get_param<T: serde::de::DeserializeOwned>(
+        &self,
+        name: impl ToParamName + Send,
+    ) -> impl Future<Output = Result<Option<T>>> + Send;
+
+    /// Sets the value of a parameter, replacing any existing value or namespace with the same name.
+    fn set_param<T: serde::Serialize + Sync>(
+        &self,
+        name: impl ToParamName + Send,
+        value: &T,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Returns true if a parameter or namespace with the given name is set.
+    fn has_param(&self, name: impl ToParamName + Send)
+        -> impl Future<Output = Result<bool>> + Send;
+
+    /// Deletes a parameter, or a namespace and all parameters beneath it.
+    /// Deleting a parameter which isn't set is not an error.
+    fn delete_param(
+        &self,
+        name: impl ToParamName + Send,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Returns the full names of all parameters which are set, in sorted order.
+    fn list_params(&self) -> impl Future<Output = Result<Vec<String>>> + Send;
+}
+
+/// A configuration struct bound to a namespace of parameters, typically derived with `#[derive(RosParams)]`.
+///
+/// Each field is read from the parameter of the same name within the namespace, e.g. `frame_rate` from
+/// `/camera/frame_rate`. The derive accepts these attributes:
+/// - `#[ros_params(namespace = "/camera")]` on the struct sets [RosParams::NAMESPACE].
+/// - `#[ros_params(validate = "path::to::fn")]` on the struct additionally checks the loaded struct with a
+///   `fn(&Self) -> Result<(), String>`.
+/// - `#[param(name = "fps")]` reads a field from a parameter of another name.
+/// - `#[param(default)]` or `#[param(default = "30")]` gives a field a value for when its parameter isn't set,
+///   `Option` fields are None when theirs isn't set.
+/// - `#[param(min = 1, max = 120)]` limits the value of a field.
+/// - `#[param(nested)]` loads a field which is itself [RosParams] from the namespace of the field.
+///
+/// ```ignore
+/// #[derive(RosParams, Clone, Debug, PartialEq)]
+/// #[ros_params(namespace = "/camera")]
+/// struct CameraConfig {
+///     #[param(default = "30", min = 1, max = 120)]
+///     frame_rate: u32,
+///     frame_id: String,
+///     exposure: Option<f64>,
+/// }
+///
+/// let config = CameraConfig::load(&ros).await?;
+/// ```
+pub trait RosParams: Sized + Send {
+    /// The namespace [RosParams::load] reads from, empty for the root namespace if none was given.
+    const NAMESPACE: &'static str = "";
+
+    /// Reads every field from the parameters within `namespace`, then checks them with [RosParams::validate].
+    ///
+    /// Returns [crate::Error::SerializationError] if a parameter without a default isn't set, can't be
+    /// represented as the type of its field, or fails validation.
+    fn load_from<P: ParamProvider + Sync>(
+        ros: &P,
+        namespace: &str,
+    ) -> impl Future<Output = Result<Self>> + Send;
+
+    /// Reads every field from the parameters within [RosParams::NAMESPACE], see [RosParams::load_from].
+    fn load<P: ParamProvider + Sync>(ros: &P) -> impl Future<Output = Result<Self>> + Send {
+        Self::load_from(ros, Self::NAMESPACE)
+    }
+
+    /// Checks the limits of each field, returning [crate::Error::SerializationError] for the first one violated.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A topic known to a [GraphProvider].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TopicInfo {
+    /// Fully resolved name of the topic
+    pub name: String,
+    /// Type of the topic e.g. "std_msgs/String"
+    pub msg_type: String,
+}
+
+/// A service known to a [GraphProvider].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServiceInfo {
+    /// Fully resolved name of the service
+    pub name: String,
+    /// Type of the service e.g. "std_srvs/Trigger", may be empty if the backend can't determine it
+    pub srv_type: String,
+}
+
+/// Provides introspection of the ROS graph, i.e. which topics and services currently exist.
+pub trait GraphProvider {
+    /// Returns all topics currently known to the backend, sorted by name.
+    fn topics(&self) -> impl Future<Output = Result<Vec<TopicInfo>>> + Send;
+
+    /// Returns all currently advertised services, sorted by name.
+    fn services(&self) -> impl Future<Output = Result<Vec<ServiceInfo>>> + Send;
+}
+
 /// Represents all "standard" ROS functionality generically supported by roslibrust
 ///
 /// Implementors of this trait behave like typical ROS1 node handles.
@@ -0,0 +1,607 @@
+//! A server for the [Foxglove WebSocket protocol](https://github.com/foxglove/ws-protocol) which lets Foxglove Studio
+//! connect directly to a Rust process and view the messages it is handling, without a rosbridge_server in the middle.
+//!
+//! It is not recommended to depend on this crate directly, but instead access it via roslibrust with the `foxglove` feature enabled.
+//!
+//! Channels are advertised with the ROS1 message definitions embedded in types generated by roslibrust's codegen,
+//! and messages are sent to Foxglove in the ROS1 wire format. Channels in other encodings (e.g. CDR) can be
+//! published with [FoxgloveServer::advertise_raw] by supplying already encoded payloads.
+//!
+//! ```no_run
+//! // Normally accessed as roslibrust::{Result, TopicProvider}
+//! use roslibrust_common::{Result, TopicProvider};
+//! // Normally you'd use generated types from roslibrust::codegen
+//! use roslibrust_test::ros1::*;
+//!
+//! async fn show_in_foxglove(ros: impl TopicProvider) -> Result<()> {
+//!     // Foxglove Studio can now open a "Foxglove WebSocket" connection to ws://localhost:8765
+//!     let server = roslibrust_foxglove::FoxgloveServer::bind("0.0.0.0:8765", "my_robot").await?;
+//!
+//!     // Forward everything received from a roslibrust subscription
+//!     let subscriber = ros.subscribe::<std_msgs::String>("/chatter").await?;
+//!     let _forwarding = server.forward("/chatter", subscriber);
+//!
+//!     // Or send messages to Foxglove directly
+//!     let channel = server.advertise::<std_msgs::String>("/status");
+//!     channel.send(&std_msgs::String { data: "Hello, Foxglove!".to_string() })?;
+//!     # Ok(())
+//! }
+//! ```
+
+use abort_on_drop::ChildTask;
+use futures::{SinkExt, StreamExt};
+use roslibrust_common::{Error, Result, RosMessageType, Subscribe};
+use serde::Deserialize;
+use serde_json::json;
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU32, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::mpsc,
+};
+use tokio_tungstenite::tungstenite::{
+    handshake::server::{ErrorResponse, Request, Response},
+    http::{header::SEC_WEBSOCKET_PROTOCOL, HeaderValue, StatusCode},
+    Message,
+};
+
+/// The websocket subprotocol clients must request to talk to the server
+pub const SUBPROTOCOL: &str = "foxglove.websocket.v1";
+
+/// Number of messages queued for a client before further messages to it are dropped
+const CLIENT_QUEUE_SIZE: usize = 100;
+
+/// Leading byte of the binary frames carrying message data
+const MESSAGE_DATA_OPCODE: u8 = 0x01;
+
+/// Describes how the messages on a channel are encoded, and the schema Foxglove uses to decode them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelSchema {
+    /// Encoding of each message e.g. "ros1" or "cdr"
+    pub encoding: String,
+    /// Name of the message type e.g. "std_msgs/String"
+    pub schema_name: String,
+    /// Encoding of the schema itself e.g. "ros1msg" or "ros2msg"
+    pub schema_encoding: String,
+    /// The schema, for ROS types the full message definition including its dependencies
+    pub schema: String,
+}
+
+impl ChannelSchema {
+    /// Schema for messages sent in the ROS1 wire format, described by the type's ROS1 message definition
+    pub fn ros1<T: RosMessageType>() -> Self {
+        Self {
+            encoding: "ros1".to_string(),
+            schema_name: T::ROS_TYPE_NAME.to_string(),
+            schema_encoding: "ros1msg".to_string(),
+            schema: T::DEFINITION.to_string(),
+        }
+    }
+
+    /// Schema for messages sent CDR encoded, described by the type's ROS2 message definition
+    pub fn cdr<T: RosMessageType>() -> Self {
+        Self {
+            encoding: "cdr".to_string(),
+            schema_name: T::ROS_TYPE_NAME.to_string(),
+            schema_encoding: "ros2msg".to_string(),
+            schema: T::DEFINITION.to_string(),
+        }
+    }
+}
+
+/// Messages sent by Foxglove clients that we act on
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+enum ClientOp {
+    Subscribe {
+        subscriptions: Vec<ClientSubscription>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Unsubscribe { subscription_ids: Vec<u32> },
+    // Covers the optional parts of the protocol we haven't advertised capabilities for
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClientSubscription {
+    id: u32,
+    channel_id: u32,
+}
+
+struct ClientState {
+    sender: mpsc::Sender<Message>,
+    // Maps the client's subscription ids to our channel ids
+    subscriptions: HashMap<u32, u32>,
+}
+
+impl ClientState {
+    fn send(&self, message: Message) {
+        if let Err(mpsc::error::TrySendError::Full(_)) = self.sender.try_send(message) {
+            log::debug!("Foxglove client is falling behind, dropping message");
+        }
+    }
+
+    /// Reports a problem with a request back to the client
+    fn send_error(&self, message: String) {
+        log::warn!("{message}");
+        self.send(Message::Text(
+            json!({"op": "status", "level": 2, "message": message}).to_string(),
+        ));
+    }
+}
+
+#[derive(Default)]
+struct ServerState {
+    // Advertisement for each channel keyed by its id
+    channels: HashMap<u32, serde_json::Value>,
+    clients: HashMap<u64, ClientState>,
+}
+
+struct Shared {
+    name: String,
+    state: Mutex<ServerState>,
+    next_channel_id: AtomicU32,
+    next_client_id: AtomicU64,
+}
+
+impl Shared {
+    fn handle_client_op(&self, client_id: u64, text: &str) {
+        let op = match serde_json::from_str::<ClientOp>(text) {
+            Ok(op) => op,
+            Err(err) => {
+                log::warn!("Failed to parse message from Foxglove client: {err}");
+                return;
+            }
+        };
+        let mut state = self.state.lock().unwrap();
+        let ServerState { channels, clients } = &mut *state;
+        let Some(client) = clients.get_mut(&client_id) else {
+            return;
+        };
+        match op {
+            ClientOp::Subscribe { subscriptions } => {
+                for subscription in subscriptions {
+                    if channels.contains_key(&subscription.channel_id) {
+                        client
+                            .subscriptions
+                            .insert(subscription.id, subscription.channel_id);
+                    } else {
+                        client.send_error(format!(
+                            "Foxglove client subscribed to unknown channel {}",
+                            subscription.channel_id
+                        ));
+                    }
+                }
+            }
+            ClientOp::Unsubscribe { subscription_ids } => {
+                for id in subscription_ids {
+                    client.subscriptions.remove(&id);
+                }
+            }
+            ClientOp::Unsupported => {
+                log::debug!("Ignoring unsupported message from Foxglove client: {text}");
+            }
+        }
+    }
+}
+
+/// A server Foxglove Studio can connect to with a "Foxglove WebSocket" connection.
+///
+/// Messages are only sent to clients which have subscribed to a channel, so advertising many channels is cheap.
+/// Dropping the server stops accepting new connections and closes the existing ones.
+pub struct FoxgloveServer {
+    shared: Arc<Shared>,
+    local_addr: SocketAddr,
+    _accept_task: ChildTask<()>,
+}
+
+impl FoxgloveServer {
+    /// Starts listening for Foxglove clients on the given address.
+    ///
+    /// `name` is shown to users of Foxglove Studio as the name of the server.
+    pub async fn bind(addr: impl ToSocketAddrs, name: &str) -> Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let shared = Arc::new(Shared {
+            name: name.to_string(),
+            state: Mutex::new(ServerState::default()),
+            next_channel_id: AtomicU32::new(1),
+            next_client_id: AtomicU64::new(1),
+        });
+        let accept_task = tokio::spawn(Self::accept_task(listener, Arc::downgrade(&shared)));
+        Ok(Self {
+            shared,
+            local_addr,
+            _accept_task: accept_task.into(),
+        })
+    }
+
+    /// The address the server is listening on, useful when bound to port 0
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Advertises a channel to all connected and future clients which sends messages of type T in the ROS1 wire format
+    ///
+    /// The channel is unadvertised when the returned [Channel] is dropped.
+    pub fn advertise<T: RosMessageType>(&self, topic: &str) -> Channel<T> {
+        Channel {
+            raw: self.advertise_raw(topic, ChannelSchema::ros1::<T>()),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Advertises a channel whose messages are already encoded as described by `schema`
+    ///
+    /// The channel is unadvertised when the returned [RawChannel] is dropped.
+    pub fn advertise_raw(&self, topic: &str, schema: ChannelSchema) -> RawChannel {
+        let id = self.shared.next_channel_id.fetch_add(1, Ordering::Relaxed);
+        let advertisement = json!({
+            "id": id,
+            "topic": topic,
+            "encoding": schema.encoding,
+            "schemaName": schema.schema_name,
+            "schemaEncoding": schema.schema_encoding,
+            "schema": schema.schema,
+        });
+        let message = json!({"op": "advertise", "channels": [&advertisement]}).to_string();
+
+        let mut state = self.shared.state.lock().unwrap();
+        state.channels.insert(id, advertisement);
+        for client in state.clients.values() {
+            client.send(Message::Text(message.clone()));
+        }
+        RawChannel {
+            id,
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Advertises a channel for `topic` and sends every message the subscriber receives on it
+    ///
+    /// Forwarding stops when the returned task is dropped or the subscriber reports it is disconnected.
+    pub fn forward<T, S>(&self, topic: &str, mut subscriber: S) -> ChildTask<()>
+    where
+        T: RosMessageType,
+        S: Subscribe<T> + Send + 'static,
+    {
+        let channel = self.advertise::<T>(topic);
+        let topic = topic.to_string();
+        tokio::spawn(async move {
+            loop {
+                match subscriber.next().await {
+                    Ok(msg) => {
+                        if let Err(err) = channel.send(&msg) {
+                            log::error!("Failed to forward message on {topic} to Foxglove: {err}");
+                        }
+                    }
+                    Err(Error::Disconnected) => {
+                        log::debug!(
+                            "Subscriber for {topic} disconnected, no longer forwarding to Foxglove"
+                        );
+                        break;
+                    }
+                    Err(err) => {
+                        log::error!(
+                            "Failed to receive message on {topic} to forward to Foxglove: {err}"
+                        );
+                    }
+                }
+            }
+        })
+        .into()
+    }
+
+    async fn accept_task(listener: TcpListener, shared: std::sync::Weak<Shared>) {
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(connection) => connection,
+                Err(err) => {
+                    log::error!("Failed to accept Foxglove client connection: {err}");
+                    continue;
+                }
+            };
+            let Some(shared) = shared.upgrade() else {
+                break;
+            };
+            log::debug!("Foxglove client connected from {peer}");
+            tokio::spawn(Self::client_task(shared, stream, peer));
+        }
+    }
+
+    async fn client_task(shared: Arc<Shared>, stream: TcpStream, peer: SocketAddr) {
+        let ws = match tokio_tungstenite::accept_hdr_async(stream, negotiate_subprotocol).await {
+            Ok(ws) => ws,
+            Err(err) => {
+                log::warn!("Failed websocket handshake with Foxglove client {peer}: {err}");
+                return;
+            }
+        };
+        let (mut write, mut read) = ws.split();
+        let (sender, mut receiver) = mpsc::channel(CLIENT_QUEUE_SIZE);
+        let client_id = shared.next_client_id.fetch_add(1, Ordering::Relaxed);
+
+        // Registering under the same lock we read the channels with means no advertisement can be missed,
+        // later ones are queued behind these introductions
+        let introductions = {
+            let mut state = shared.state.lock().unwrap();
+            state.clients.insert(
+                client_id,
+                ClientState {
+                    sender,
+                    subscriptions: HashMap::new(),
+                },
+            );
+            let channels: Vec<_> = state.channels.values().cloned().collect();
+            [
+                json!({
+                    "op": "serverInfo",
+                    "name": shared.name,
+                    "capabilities": [],
+                    "supportedEncodings": [],
+                    "metadata": {},
+                }),
+                json!({"op": "advertise", "channels": channels}),
+            ]
+        };
+
+        for introduction in introductions {
+            if let Err(err) = write.send(Message::Text(introduction.to_string())).await {
+                log::warn!("Failed to send to Foxglove client {peer}: {err}");
+                shared.state.lock().unwrap().clients.remove(&client_id);
+                return;
+            }
+        }
+
+        loop {
+            tokio::select! {
+                outgoing = receiver.recv() => {
+                    let Some(message) = outgoing else {
+                        break;
+                    };
+                    if let Err(err) = write.send(message).await {
+                        log::warn!("Failed to send to Foxglove client {peer}: {err}");
+                        break;
+                    }
+                }
+                incoming = read.next() => match incoming {
+                    Some(Ok(Message::Text(text))) => shared.handle_client_op(client_id, &text),
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Err(err)) => {
+                        log::warn!("Connection to Foxglove client {peer} failed: {err}");
+                        break;
+                    }
+                    // Pings are answered by tungstenite, and clients have no reason to send us binary data
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+        log::debug!("Foxglove client {peer} disconnected");
+        shared.state.lock().unwrap().clients.remove(&client_id);
+    }
+}
+
+impl Drop for FoxgloveServer {
+    fn drop(&mut self) {
+        // Dropping the senders ends the client tasks, closing their connections
+        self.shared.state.lock().unwrap().clients.clear();
+    }
+}
+
+/// Accepts websocket connections which request the Foxglove subprotocol
+// The signature is dictated by tungstenite's handshake callback
+#[allow(clippy::result_large_err)]
+fn negotiate_subprotocol(
+    request: &Request,
+    mut response: Response,
+) -> std::result::Result<Response, ErrorResponse> {
+    let requested = request
+        .headers()
+        .get_all(SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|protocol| protocol.trim() == SUBPROTOCOL);
+    if !requested {
+        let mut error = ErrorResponse::new(Some(format!(
+            "Clients must request the {SUBPROTOCOL} subprotocol"
+        )));
+        *error.status_mut() = StatusCode::BAD_REQUEST;
+        return Err(error);
+    }
+    response.headers_mut().insert(
+        SEC_WEBSOCKET_PROTOCOL,
+        HeaderValue::from_static(SUBPROTOCOL),
+    );
+    Ok(response)
+}
+
+/// A channel advertised to Foxglove clients carrying already encoded messages
+///
+/// Created with [FoxgloveServer::advertise_raw], the channel is unadvertised when this is dropped.
+pub struct RawChannel {
+    id: u32,
+    shared: Arc<Shared>,
+}
+
+impl RawChannel {
+    /// The id Foxglove clients refer to this channel by
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Sends an encoded message to every client subscribed to this channel, timestamped with the current time
+    ///
+    /// Messages to clients which aren't keeping up are dropped rather than waited on.
+    pub fn send(&self, payload: &[u8]) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+        let state = self.shared.state.lock().unwrap();
+        for client in state.clients.values() {
+            for (subscription_id, channel_id) in &client.subscriptions {
+                if *channel_id == self.id {
+                    client.send(Message::Binary(message_data(
+                        *subscription_id,
+                        timestamp,
+                        payload,
+                    )));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for RawChannel {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.channels.remove(&self.id);
+        let message = json!({"op": "unadvertise", "channelIds": [self.id]}).to_string();
+        for client in state.clients.values_mut() {
+            client
+                .subscriptions
+                .retain(|_, channel_id| *channel_id != self.id);
+            client.send(Message::Text(message.clone()));
+        }
+    }
+}
+
+/// A channel advertised to Foxglove clients carrying messages of type T in the ROS1 wire format
+///
+/// Created with [FoxgloveServer::advertise], the channel is unadvertised when this is dropped.
+pub struct Channel<T: RosMessageType> {
+    raw: RawChannel,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: RosMessageType> Channel<T> {
+    /// The id Foxglove clients refer to this channel by
+    pub fn id(&self) -> u32 {
+        self.raw.id()
+    }
+
+    /// Sends a message to every client subscribed to this channel, timestamped with the current time
+    pub fn send(&self, msg: &T) -> Result<()> {
+        let bytes = match msg.ros1_encode() {
+            Some(bytes) => bytes,
+            None => roslibrust_serde_rosmsg::to_vec(msg)
+                .map_err(|err| Error::SerializationError(err.to_string()))?,
+        };
+        // Foxglove expects the message without the length ROS1 puts in front of it
+        self.raw.send(&bytes[4..]);
+        Ok(())
+    }
+}
+
+/// Builds the binary frame carrying a message to a client's subscription
+fn message_data(subscription_id: u32, timestamp: u64, payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(1 + 4 + 8 + payload.len());
+    frame.push(MESSAGE_DATA_OPCODE);
+    frame.extend_from_slice(&subscription_id.to_le_bytes());
+    frame.extend_from_slice(&timestamp.to_le_bytes());
+    frame.extend_from_slice(payload);
+    frame
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_test::ros1::std_msgs;
+    use tokio_tungstenite::{
+        tungstenite::client::IntoClientRequest, MaybeTlsStream, WebSocketStream,
+    };
+
+    type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+    async fn connect(server: &FoxgloveServer) -> Client {
+        let mut request = format!("ws://{}", server.local_addr())
+            .into_client_request()
+            .unwrap();
+        request.headers_mut().insert(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static(SUBPROTOCOL),
+        );
+        tokio_tungstenite::connect_async(request).await.unwrap().0
+    }
+
+    async fn next_op(client: &mut Client) -> serde_json::Value {
+        match client.next().await.unwrap().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("Expected a text message, got {other:?}"),
+        }
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn streams_subscribed_channels() {
+        let server = FoxgloveServer::bind("127.0.0.1:0", "test").await.unwrap();
+        let channel = server.advertise::<std_msgs::String>("/chatter");
+        let mut client = connect(&server).await;
+
+        let server_info = next_op(&mut client).await;
+        assert_eq!(server_info["op"], "serverInfo");
+        assert_eq!(server_info["name"], "test");
+        let advertise = next_op(&mut client).await;
+        assert_eq!(advertise["op"], "advertise");
+        let advertised = &advertise["channels"][0];
+        assert_eq!(advertised["id"], channel.id());
+        assert_eq!(advertised["topic"], "/chatter");
+        assert_eq!(advertised["encoding"], "ros1");
+        assert_eq!(advertised["schemaName"], "std_msgs/String");
+        assert_eq!(advertised["schemaEncoding"], "ros1msg");
+
+        client
+            .send(Message::Text(
+                json!({"op": "subscribe", "subscriptions": [{"id": 7, "channelId": channel.id()}]})
+                    .to_string(),
+            ))
+            .await
+            .unwrap();
+
+        // Keep sending until the server has seen our subscription
+        let msg = std_msgs::String {
+            data: "hello".to_string(),
+        };
+        let frame = loop {
+            channel.send(&msg).unwrap();
+            let next = tokio::time::timeout(std::time::Duration::from_millis(10), client.next());
+            if let Ok(Some(Ok(Message::Binary(frame)))) = next.await {
+                break frame;
+            }
+        };
+        assert_eq!(frame[0], MESSAGE_DATA_OPCODE);
+        assert_eq!(frame[1..5], 7u32.to_le_bytes());
+        let expected = roslibrust_serde_rosmsg::to_vec(&msg).unwrap();
+        assert_eq!(frame[13..], expected[4..]);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn advertises_channels_to_connected_clients() {
+        let server = FoxgloveServer::bind("127.0.0.1:0", "test").await.unwrap();
+        let mut client = connect(&server).await;
+        assert_eq!(next_op(&mut client).await["op"], "serverInfo");
+        assert_eq!(next_op(&mut client).await["channels"], json!([]));
+
+        let channel = server.advertise_raw("/cdr", ChannelSchema::cdr::<std_msgs::String>());
+        let advertise = next_op(&mut client).await;
+        assert_eq!(advertise["op"], "advertise");
+        assert_eq!(advertise["channels"][0]["encoding"], "cdr");
+        assert_eq!(advertise["channels"][0]["schemaEncoding"], "ros2msg");
+
+        let id = channel.id();
+        drop(channel);
+        let unadvertise = next_op(&mut client).await;
+        assert_eq!(unadvertise["op"], "unadvertise");
+        assert_eq!(unadvertise["channelIds"], json!([id]));
+    }
+}
@@ -0,0 +1,22 @@
+//! Pre-generated types for the standard ROS message packages: std_msgs, geometry_msgs, sensor_msgs, nav_msgs etc.
+//!
+//! For using the standard types without a ROS installation or a checkout of the message definitions.
+//! For messages of your own packages, or to customize the generated code, use roslibrust_codegen instead.
+//!
+//! ```
+//! use roslibrust_interfaces::ros1::std_msgs;
+//!
+//! let header = std_msgs::Header {
+//!     frame_id: "base_link".to_owned(),
+//!     ..Default::default()
+//! };
+//! ```
+//!
+//! The ROS1 and ROS2 types are in separate modules, behind the `ros1` and `ros2` features which are both on by default.
+//! The generated code is checked in, and regenerated from the `assets` directory of the roslibrust repository
+//! by running `cargo run --bin roslibrust_test`.
+
+#[cfg(feature = "ros1")]
+pub mod ros1;
+#[cfg(feature = "ros2")]
+pub mod ros2;
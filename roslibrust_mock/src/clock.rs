@@ -0,0 +1,51 @@
+//! A virtual clock for [crate::MockRos] driven by tokio's (pausable) clock.
+//!
+//! When the tokio clock is paused (e.g. `#[tokio::test(start_paused = true)]`), time only moves when
+//! [MockClock::advance] is called or when the runtime has no other work to do, so timers, timeouts and delayed
+//! message delivery (see [crate::FaultConfig]) all step deterministically.
+
+use std::time::{Duration, SystemTime};
+use tokio::time::Instant;
+
+/// The virtual ROS clock of a [crate::MockRos], obtained via [crate::MockRos::clock].
+///
+/// All clones of a clock observe the same time.
+#[derive(Clone, Debug)]
+pub struct MockClock {
+    // ROS time corresponding to `start_instant`
+    start_time: SystemTime,
+    start_instant: Instant,
+}
+
+impl MockClock {
+    /// Creates a clock which reads `start_time` now, and advances with tokio's clock.
+    pub(crate) fn new(start_time: SystemTime) -> Self {
+        Self {
+            start_time,
+            start_instant: Instant::now(),
+        }
+    }
+
+    /// Returns the current virtual ROS time.
+    pub fn now(&self) -> SystemTime {
+        self.start_time + self.start_instant.elapsed()
+    }
+
+    /// Moves virtual time forward by `duration`, firing any timers that become due along the way.
+    ///
+    /// # Panics
+    /// Panics if the tokio clock is not paused, see [tokio::time::pause].
+    pub async fn advance(&self, duration: Duration) {
+        tokio::time::advance(duration).await;
+    }
+
+    /// Moves virtual time forward to `time`, does nothing if `time` is in the past.
+    ///
+    /// # Panics
+    /// Panics if the tokio clock is not paused, see [tokio::time::pause].
+    pub async fn advance_to(&self, time: SystemTime) {
+        if let Ok(duration) = time.duration_since(self.now()) {
+            self.advance(duration).await;
+        }
+    }
+}
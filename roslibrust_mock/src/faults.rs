@@ -0,0 +1,190 @@
+//! Fault injection for [crate::MockRos], used to test robustness logic against delayed, reordered and lost messages.
+//!
+//! All randomness comes from a seeded generator owned by the [crate::MockRos], so a given seed and sequence of
+//! publishes always produces the same faults. Delays are implemented with [tokio::time] so they can be driven
+//! deterministically with `tokio::time::pause()`.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::broadcast as Channel;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+/// Describes the faults to inject on a single topic, see [crate::MockRos::set_faults].
+///
+/// ```
+/// use roslibrust_mock::FaultConfig;
+/// use std::time::Duration;
+///
+/// let faults = FaultConfig::new()
+///     .latency(Duration::from_millis(20))
+///     .jitter(Duration::from_millis(10))
+///     .drop_probability(0.1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FaultConfig {
+    latency: Duration,
+    jitter: Duration,
+    drop_probability: f64,
+    allow_reordering: bool,
+}
+
+impl FaultConfig {
+    /// Creates a config which injects no faults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fixed delay applied to every message delivered on the topic.
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Additional random delay drawn uniformly from `[0, jitter)` for each message.
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Probability in `[0, 1]` that any given message is silently dropped.
+    pub fn drop_probability(mut self, probability: f64) -> Self {
+        self.drop_probability = probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// If true each message is delivered as soon as its own delay elapses, so jitter can reorder messages.
+    /// By default messages are delivered in publish order, with a message waiting for any delayed message before it.
+    pub fn allow_reordering(mut self, allow: bool) -> Self {
+        self.allow_reordering = allow;
+        self
+    }
+}
+
+/// A small deterministic PRNG (splitmix64), we don't need anything stronger for test fault injection.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+struct TopicFaults {
+    config: FaultConfig,
+    // Delivery time of the last message queued on this topic, used to preserve ordering
+    last_delivery: Option<Instant>,
+    // Lazily created worker which delivers messages in order
+    ordered_queue: Option<mpsc::UnboundedSender<(Instant, Vec<u8>)>>,
+}
+
+/// What should happen to a single published message
+pub(crate) enum Delivery {
+    /// Deliver the message immediately
+    Now(Vec<u8>),
+    /// The message was dropped
+    Dropped,
+    /// The message has been scheduled for delayed delivery
+    Scheduled,
+}
+
+/// Shared fault state for all topics of a [crate::MockRos].
+#[derive(Clone)]
+pub(crate) struct Faults {
+    inner: Arc<Mutex<FaultsInner>>,
+}
+
+struct FaultsInner {
+    rng: Rng,
+    topics: BTreeMap<String, TopicFaults>,
+}
+
+impl Faults {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(FaultsInner {
+                rng: Rng(seed),
+                topics: BTreeMap::new(),
+            })),
+        }
+    }
+
+    pub(crate) fn set(&self, topic: String, config: FaultConfig) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.topics.insert(
+            topic,
+            TopicFaults {
+                config,
+                last_delivery: None,
+                ordered_queue: None,
+            },
+        );
+    }
+
+    pub(crate) fn clear(&self, topic: &str) {
+        self.inner.lock().unwrap().topics.remove(topic);
+    }
+
+    /// Decides the fate of a message published on `topic`, scheduling it for later delivery on `sender` if delayed.
+    pub(crate) fn deliver(
+        &self,
+        topic: &str,
+        sender: &Channel::Sender<Vec<u8>>,
+        data: Vec<u8>,
+    ) -> Delivery {
+        let mut inner = self.inner.lock().unwrap();
+        let FaultsInner { rng, topics } = &mut *inner;
+        let Some(faults) = topics.get_mut(topic) else {
+            return Delivery::Now(data);
+        };
+        let config = &faults.config;
+
+        if config.drop_probability > 0.0 && rng.next_f64() < config.drop_probability {
+            return Delivery::Dropped;
+        }
+        let jitter = config.jitter.mul_f64(rng.next_f64());
+        let delay = config.latency + jitter;
+        // Once anything has been delayed on an ordered topic everything goes through the queue to keep ordering
+        if delay.is_zero() && (config.allow_reordering || faults.last_delivery.is_none()) {
+            return Delivery::Now(data);
+        }
+
+        let mut deliver_at = Instant::now() + delay;
+        if config.allow_reordering {
+            let sender = sender.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep_until(deliver_at).await;
+                let _ = sender.send(data);
+            });
+        } else {
+            if let Some(last) = faults.last_delivery {
+                deliver_at = deliver_at.max(last);
+            }
+            faults.last_delivery = Some(deliver_at);
+            let queue = faults.ordered_queue.get_or_insert_with(|| {
+                let (tx, mut rx) = mpsc::unbounded_channel::<(Instant, Vec<u8>)>();
+                let sender = sender.clone();
+                tokio::spawn(async move {
+                    while let Some((deliver_at, data)) = rx.recv().await {
+                        tokio::time::sleep_until(deliver_at).await;
+                        let _ = sender.send(data);
+                    }
+                });
+                tx
+            });
+            let _ = queue.send((deliver_at, data));
+        }
+        Delivery::Scheduled
+    }
+}
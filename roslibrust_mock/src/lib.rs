@@ -28,6 +28,7 @@
 //! ```
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::sync::RwLock as SyncRwLock;
 
 use roslibrust_common::*;
 
@@ -36,6 +37,20 @@ use tokio::sync::RwLock;
 
 use log::*;
 
+mod clock;
+pub use clock::MockClock;
+mod faults;
+pub use faults::FaultConfig;
+use faults::{Delivery, Faults};
+mod params;
+use params::Params;
+mod recording;
+pub use recording::MockEvent;
+use recording::Recorder;
+mod scenario;
+use scenario::Prepared;
+pub use scenario::{Scenario, ScenarioTypes};
+
 type TypeErasedCallback = Arc<
     dyn Fn(Vec<u8>) -> std::result::Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>>
         + Send
@@ -45,21 +60,383 @@ type TypeErasedCallback = Arc<
 
 /// A mock ROS implementation that can be substituted for any roslibrust backend in unit tests.
 ///
-/// Implements [TopicProvider] and [ServiceProvider] to provide basic ros functionality.
+/// Implements [TopicProvider] and [ServiceProvider] to provide basic ros functionality,
+/// as well as [ParamProvider] and [GraphProvider] backed by an in memory parameter server and topic / service registry.
 #[derive(Clone)]
 pub struct MockRos {
     // We could probably achieve some fancier type erasure than actually serializing the data
     // but this ends up being pretty simple
-    topics: Arc<RwLock<BTreeMap<String, (Channel::Sender<Vec<u8>>, Channel::Receiver<Vec<u8>>)>>>,
-    services: Arc<RwLock<BTreeMap<String, TypeErasedCallback>>>,
+    topics: Arc<RwLock<BTreeMap<String, TopicEntry>>>,
+    // A sync lock is used for services so that the server handle can de-register itself on drop
+    services: ServiceMap,
+    faults: Faults,
+    clock: MockClock,
+    recorder: Recorder,
+    params: Params,
+    channel_config: ChannelConfig,
+    metrics: ChannelMetrics,
+}
+
+/// The default size of each topic's channel, see [MockRos::channel_config]
+const QUEUE_SIZE: usize = 10;
+
+struct TopicEntry {
+    sender: Channel::Sender<Vec<u8>>,
+    // Held so the channel stays open while there are no subscribers
+    receiver: Channel::Receiver<Vec<u8>>,
+    // Type of the first publisher or subscriber on the topic
+    msg_type: RosType,
+}
+
+struct ServiceEntry {
+    srv_type: RosType,
+    callback: TypeErasedCallback,
+}
+
+/// The name and md5sum of a message or service type, used to check that everything on a topic or service agrees
+/// on its type the same way a real backend would.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RosType {
+    name: String,
+    md5sum: String,
+}
+
+impl RosType {
+    fn message<T: RosMessageType>() -> Self {
+        Self {
+            name: T::ROS_TYPE_NAME.to_string(),
+            md5sum: T::MD5SUM.to_string(),
+        }
+    }
+
+    fn service<T: RosServiceType>() -> Self {
+        Self {
+            name: T::ROS_SERVICE_NAME.to_string(),
+            md5sum: T::MD5SUM.to_string(),
+        }
+    }
+
+    /// True for [ShapeShifter], which accepts any type like in ROS1
+    fn is_wildcard(&self) -> bool {
+        self.name == "*"
+    }
+
+    /// Returns [Error::SerializationError], as ROS1 does for an md5sum mismatch, if `other` doesn't match this type.
+    ///
+    /// Types without an md5sum (e.g. ROS2 types) are compared by name only.
+    fn check(&self, name: &str, other: &RosType) -> Result<()> {
+        let md5_matches = self.md5sum.is_empty()
+            || other.md5sum.is_empty()
+            || self.md5sum == "*"
+            || other.md5sum == "*"
+            || self.md5sum == other.md5sum;
+        if self.is_wildcard() || other.is_wildcard() || (self.name == other.name && md5_matches) {
+            return Ok(());
+        }
+        Err(Error::SerializationError(format!(
+            "Type mismatch on {name}: existing type is {self} but {other} was requested"
+        )))
+    }
+}
+
+impl std::fmt::Display for RosType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.md5sum.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{} ({})", self.name, self.md5sum)
+        }
+    }
+}
+
+type ServiceMap = Arc<SyncRwLock<BTreeMap<String, ServiceEntry>>>;
+
+/// Returns the callback for `service`, failing if it doesn't exist or is of a different type
+fn lookup_service(
+    services: &ServiceMap,
+    service: &str,
+    srv_type: &RosType,
+) -> Result<TypeErasedCallback> {
+    let services = services.read().unwrap();
+    let entry = services.get(service).ok_or(Error::Disconnected)?;
+    entry.srv_type.check(service, srv_type)?;
+    Ok(entry.callback.clone())
 }
 
 impl MockRos {
     pub fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Creates a new mock using `seed` for all randomness in fault injection, see [MockRos::set_faults].
+    pub fn with_seed(seed: u64) -> Self {
         Self {
             topics: Arc::new(RwLock::new(BTreeMap::new())),
-            services: Arc::new(RwLock::new(BTreeMap::new())),
+            services: Arc::new(SyncRwLock::new(BTreeMap::new())),
+            faults: Faults::new(seed),
+            // Virtual time starts at zero, matching a simulator's /clock
+            clock: MockClock::new(std::time::UNIX_EPOCH),
+            recorder: Recorder::default(),
+            params: Params::default(),
+            channel_config: ChannelConfig::default(),
+            metrics: ChannelMetrics::default(),
+        }
+    }
+
+    /// Configures the size of the channels of topics created afterwards with `subscriber_queue_size`,
+    /// 10 messages by default. Subscribers which fall further behind miss the oldest messages, which are counted
+    /// in [MockRos::channel_metrics].
+    pub fn channel_config(mut self, channel_config: ChannelConfig) -> Self {
+        self.channel_config = channel_config;
+        self
+    }
+
+    /// Counts of the messages subscribers missed by falling behind, shared by all clones of this mock
+    pub fn channel_metrics(&self) -> &ChannelMetrics {
+        &self.metrics
+    }
+
+    /// Returns the virtual clock used by this mock's [ClockProvider] implementation.
+    ///
+    /// ```
+    /// # use roslibrust_common::ClockProvider;
+    /// # use roslibrust_mock::MockRos;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// #[tokio::test(start_paused = true)]
+    /// async fn my_test() {
+    ///     let ros = MockRos::new();
+    ///     ros.clock().advance(Duration::from_secs(10)).await;
+    ///     assert_eq!(ros.now(), UNIX_EPOCH + Duration::from_secs(10));
+    /// }
+    /// ```
+    pub fn clock(&self) -> MockClock {
+        self.clock.clone()
+    }
+
+    /// Injects faults (delay, jitter, reordering, drops) into all messages subsequently published on `topic`.
+    /// Replaces any faults previously configured for the topic.
+    ///
+    /// ```
+    /// # use roslibrust_common::*;
+    /// # use roslibrust_mock::{FaultConfig, MockRos};
+    /// # use roslibrust_test::ros1::std_msgs;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ros = MockRos::with_seed(42);
+    /// ros.set_faults("/scan", FaultConfig::new().drop_probability(0.5))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_faults(&self, topic: impl ToTopicName, faults: FaultConfig) -> Result<()> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        self.faults.set(topic.into(), faults);
+        Ok(())
+    }
+
+    /// Removes any faults configured for `topic` with [MockRos::set_faults].
+    pub fn clear_faults(&self, topic: impl ToTopicName) -> Result<()> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        self.faults.clear(topic.as_str());
+        Ok(())
+    }
+
+    /// Returns every message published on `topic` (via any publisher) in the order they were published.
+    ///
+    /// Messages are recorded when published, so this includes messages dropped by fault injection.
+    ///
+    /// ```
+    /// # use roslibrust_common::*;
+    /// # use roslibrust_mock::MockRos;
+    /// # use roslibrust_test::ros1::std_msgs;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ros = MockRos::new();
+    /// let publisher = ros.advertise::<std_msgs::String>("/chatter").await?;
+    /// publisher.publish(&std_msgs::String { data: "hi".to_string() }).await?;
+    /// let published = ros.published_on::<std_msgs::String>("chatter")?;
+    /// assert_eq!(published[0].data, "hi");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn published_on<T: RosMessageType>(&self, topic: impl ToTopicName) -> Result<Vec<T>> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        self.recorder
+            .data(|e| matches!(e, MockEvent::Published { topic: t, .. } if t == topic.as_str()))
+            .iter()
+            .map(|data| {
+                bincode::deserialize(data).map_err(|e| Error::SerializationError(e.to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns the number of messages published on `topic`.
+    pub fn publish_count(&self, topic: impl ToTopicName) -> Result<usize> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        Ok(self
+            .recorder
+            .count(|e| matches!(e, MockEvent::Published { topic: t, .. } if t == topic.as_str())))
+    }
+
+    /// Returns the number of subscribers that have been created for `topic`.
+    pub fn subscription_count(&self, topic: impl ToTopicName) -> Result<usize> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        Ok(self
+            .recorder
+            .count(|e| matches!(e, MockEvent::Subscribed { topic: t, .. } if t == topic.as_str())))
+    }
+
+    /// Returns the requests of every call made to `service` in the order they were made.
+    pub fn service_calls<T: RosServiceType>(
+        &self,
+        service: impl ToServiceName,
+    ) -> Result<Vec<T::Request>> {
+        let service = service.to_service_name()?.resolve_to_root()?;
+        self.recorder
+            .data(
+                |e| matches!(e, MockEvent::ServiceCalled { service: s, .. } if s == service.as_str()),
+            )
+            .iter()
+            .map(|data| {
+                bincode::deserialize(data).map_err(|e| Error::SerializationError(e.to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns the number of calls made to `service`.
+    pub fn service_call_count(&self, service: impl ToServiceName) -> Result<usize> {
+        let service = service.to_service_name()?.resolve_to_root()?;
+        Ok(self.recorder.count(
+            |e| matches!(e, MockEvent::ServiceCalled { service: s, .. } if s == service.as_str()),
+        ))
+    }
+
+    /// Returns every recorded interaction with this mock, in order, with the virtual time it happened at.
+    ///
+    /// Useful for asserting on the ordering of a node's actions, e.g. that a service was called before publishing.
+    pub fn events(&self) -> Vec<(std::time::SystemTime, MockEvent)> {
+        self.recorder.events()
+    }
+
+    /// Discards all recorded events.
+    pub fn clear_recording(&self) {
+        self.recorder.clear();
+    }
+
+    /// Replays `scenario`, returning once its last step has been played.
+    ///
+    /// Step times are relative to when this is called and follow tokio's clock, so with a paused clock an
+    /// entire scenario plays out instantly while remaining deterministic. Scripted messages go through the
+    /// same path as any other publish, so they are affected by [MockRos::set_faults] and recorded.
+    ///
+    /// All names and messages in the scenario are checked before playback starts, and an error is returned
+    /// without playing any step if one is invalid.
+    ///
+    /// ```
+    /// # use roslibrust_common::*;
+    /// # use roslibrust_mock::{MockRos, Scenario};
+    /// # use roslibrust_test::ros1::std_msgs;
+    /// # use std::time::Duration;
+    /// #[tokio::test(start_paused = true)]
+    /// async fn my_test() {
+    ///     let ros = MockRos::new();
+    ///     let mut sub = ros.subscribe::<std_msgs::String>("/chatter").await.unwrap();
+    ///     let scenario = Scenario::new()
+    ///         .publish(Duration::from_secs(1), "/chatter", &std_msgs::String { data: "hi".to_string() });
+    ///     ros.play(&scenario).await.unwrap();
+    ///     assert_eq!(sub.next().await.unwrap().data, "hi");
+    /// }
+    /// ```
+    pub async fn play(&self, scenario: &Scenario) -> Result<()> {
+        // Validate everything up front so a bad scenario doesn't partially play
+        let steps = scenario.prepare()?;
+        let start = tokio::time::Instant::now();
+        for (at, action) in steps {
+            tokio::time::sleep_until(start + at).await;
+            match action {
+                Prepared::Publish {
+                    topic,
+                    msg_type,
+                    data,
+                } => {
+                    self.raw_publisher(&topic, &msg_type).await?.publish(data)?;
+                }
+                Prepared::Respond {
+                    service,
+                    srv_type,
+                    response,
+                } => {
+                    let callback: TypeErasedCallback = Arc::new(move |_| Ok(response.clone()));
+                    self.services.write().unwrap().insert(
+                        service.clone(),
+                        ServiceEntry {
+                            srv_type: srv_type.clone(),
+                            callback,
+                        },
+                    );
+                    self.recorder.record(
+                        MockEvent::ServiceAdvertised {
+                            service: service.clone(),
+                            srv_type: srv_type.name.clone(),
+                        },
+                        self.clock.now(),
+                        None,
+                    );
+                    debug!("Scenario advertised service {service}");
+                }
+            }
         }
+        Ok(())
+    }
+
+    /// Returns the channel for the already resolved `topic`, creating it if needed.
+    ///
+    /// Fails if the topic already exists with a different type.
+    async fn channel(
+        &self,
+        topic: &str,
+        msg_type: &RosType,
+    ) -> Result<(Channel::Sender<Vec<u8>>, Channel::Receiver<Vec<u8>>)> {
+        let mut topics = self.topics.write().await;
+        let entry = match topics.entry(topic.to_string()) {
+            std::collections::btree_map::Entry::Occupied(entry) => {
+                let entry = entry.into_mut();
+                entry.msg_type.check(topic, msg_type)?;
+                // A topic created by a ShapeShifter takes the type of the first concrete user
+                if entry.msg_type.is_wildcard() {
+                    entry.msg_type = msg_type.clone();
+                }
+                debug!("Using existing channel for topic {}", topic);
+                entry
+            }
+            std::collections::btree_map::Entry::Vacant(entry) => {
+                // Create a new channel
+                let queue_size = self
+                    .channel_config
+                    .subscriber_queue_size
+                    .unwrap_or(QUEUE_SIZE);
+                let (sender, receiver) = Channel::channel(queue_size);
+                debug!("Created new channel for topic {}", topic);
+                entry.insert(TopicEntry {
+                    sender,
+                    receiver,
+                    msg_type: msg_type.clone(),
+                })
+            }
+        };
+        Ok((entry.sender.clone(), entry.receiver.resubscribe()))
+    }
+
+    /// Returns an untyped publisher for the already resolved `topic`.
+    async fn raw_publisher(&self, topic: &str, msg_type: &RosType) -> Result<RawPublisher> {
+        let (sender, _) = self.channel(topic, msg_type).await?;
+        Ok(RawPublisher {
+            topic: topic.to_string(),
+            sender,
+            msg_type: msg_type.name.clone(),
+            faults: self.faults.clone(),
+            recorder: self.recorder.clone(),
+            clock: self.clock.clone(),
+        })
     }
 }
 
@@ -69,62 +446,227 @@ impl TopicProvider for MockRos {
     type Publisher<T: RosMessageType> = MockPublisher<T>;
     type Subscriber<T: RosMessageType> = MockSubscriber<T>;
 
-    async fn advertise<T: RosMessageType>(&self, topic: &str) -> Result<Self::Publisher<T>> {
-        // Check if we already have this channel
-        {
-            let topics = self.topics.read().await;
-            if let Some((sender, _)) = topics.get(topic) {
-                debug!("Issued new publisher to existing topic {}", topic);
-                return Ok(MockPublisher {
-                    sender: sender.clone(),
-                    _marker: Default::default(),
-                });
-            }
-        } // Drop read lock here
-          // Create a new channel
-        let tx_rx = Channel::channel(10);
-        let tx_copy = tx_rx.0.clone();
-        let mut topics = self.topics.write().await;
-        topics.insert(topic.to_string(), tx_rx);
-        debug!("Created new publisher and channel for topic {}", topic);
+    async fn advertise<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Publisher<T>> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        let topic = topic.as_str();
+        self.recorder.record(
+            MockEvent::Advertised {
+                topic: topic.to_string(),
+                msg_type: T::ROS_TYPE_NAME.to_string(),
+            },
+            self.clock.now(),
+            None,
+        );
         Ok(MockPublisher {
-            sender: tx_copy,
+            inner: self.raw_publisher(topic, &RosType::message::<T>()).await?,
             _marker: Default::default(),
         })
     }
 
     async fn subscribe<T: RosMessageType>(
         &self,
-        topic: &str,
+        topic: impl ToTopicName + Send,
     ) -> roslibrust_common::Result<Self::Subscriber<T>> {
-        // Check if we already have this channel
-        {
-            let topics = self.topics.read().await;
-            if let Some((_, receiver)) = topics.get(topic) {
-                debug!("Issued new subscriber to existing topic {}", topic);
-                return Ok(MockSubscriber {
-                    receiver: receiver.resubscribe(),
-                    _marker: Default::default(),
-                });
-            }
-        } // Drop read lock here
-          // Create a new channel
-        let tx_rx = Channel::channel(10);
-        let rx_copy = tx_rx.1.resubscribe();
-        let mut topics = self.topics.write().await;
-        topics.insert(topic.to_string(), tx_rx);
-        debug!("Created new subscriber and channel for topic {}", topic);
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        let topic = topic.as_str();
+        self.recorder.record(
+            MockEvent::Subscribed {
+                topic: topic.to_string(),
+                msg_type: T::ROS_TYPE_NAME.to_string(),
+            },
+            self.clock.now(),
+            None,
+        );
+        let (_, receiver) = self.channel(topic, &RosType::message::<T>()).await?;
         Ok(MockSubscriber {
-            receiver: rx_copy,
+            receiver,
+            metrics: self.metrics.clone(),
             _marker: Default::default(),
         })
     }
 }
 
+// The mock only knows the name and md5sum of a topic's type, so schemas have no definition
+impl RawTopicProvider for MockRos {
+    type RawPublisher = MockRawPublisher;
+    type RawSubscriber = MockRawSubscriber;
+
+    const ENCODING: &'static str = "bincode";
+
+    async fn advertise_raw(
+        &self,
+        topic: impl ToTopicName + Send,
+        schema: &schema::MessageSchema,
+    ) -> Result<Self::RawPublisher> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        let topic = topic.as_str();
+        let msg_type = RosType {
+            name: schema.type_name.clone(),
+            md5sum: schema.md5sum.clone().unwrap_or_default(),
+        };
+        self.recorder.record(
+            MockEvent::Advertised {
+                topic: topic.to_string(),
+                msg_type: msg_type.name.clone(),
+            },
+            self.clock.now(),
+            None,
+        );
+        Ok(MockRawPublisher {
+            inner: self.raw_publisher(topic, &msg_type).await?,
+        })
+    }
+
+    async fn subscribe_raw(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<(schema::MessageSchema, Self::RawSubscriber)> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        let topic = topic.as_str();
+        let msg_type = match self.topics.read().await.get(topic) {
+            Some(entry) if !entry.msg_type.is_wildcard() => entry.msg_type.clone(),
+            _ => {
+                return Err(Error::ServerError(format!(
+                    "The type of {topic} isn't known yet"
+                )))
+            }
+        };
+        self.recorder.record(
+            MockEvent::Subscribed {
+                topic: topic.to_string(),
+                msg_type: msg_type.name.clone(),
+            },
+            self.clock.now(),
+            None,
+        );
+        let (_, receiver) = self.channel(topic, &msg_type).await?;
+        let schema = schema::MessageSchema::new(msg_type.name, "").with_md5sum(msg_type.md5sum);
+        Ok((
+            schema,
+            MockRawSubscriber {
+                topic: topic.to_string(),
+                receiver,
+                metrics: self.metrics.clone(),
+            },
+        ))
+    }
+}
+
+// Publishers of the mock don't latch
+impl CapabilityProvider for MockRos {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            raw_topics: true,
+            service_clients: true,
+            service_servers: true,
+            params: true,
+            graph: true,
+            sim_time: true,
+            ..Default::default()
+        }
+    }
+}
+
+// Time in the mock follows tokio's clock, so it is virtual whenever tokio's clock is paused
+impl ClockProvider for MockRos {
+    fn now(&self) -> std::time::SystemTime {
+        self.clock.now()
+    }
+
+    async fn sleep(&self, duration: std::time::Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
+
+// Parameters are stored in memory and shared between clones of the mock
+impl ParamProvider for MockRos {
+    async fn get_param<T: serde::de::DeserializeOwned>(
+        &self,
+        name: impl ToParamName + Send,
+    ) -> Result<Option<T>> {
+        let name = name.to_param_name()?.resolve_to_root()?;
+        self.params
+            .get(name.as_str())
+            .map(|value| {
+                serde_json::from_value(value).map_err(|e| {
+                    Error::SerializationError(format!("Failed to convert parameter {name}: {e}"))
+                })
+            })
+            .transpose()
+    }
+
+    async fn set_param<T: serde::Serialize + Sync>(
+        &self,
+        name: impl ToParamName + Send,
+        value: &T,
+    ) -> Result<()> {
+        let name = name.to_param_name()?.resolve_to_root()?;
+        let value =
+            serde_json::to_value(value).map_err(|e| Error::SerializationError(e.to_string()))?;
+        self.params.set(name.as_str(), value);
+        debug!("Set parameter {name}");
+        Ok(())
+    }
+
+    async fn has_param(&self, name: impl ToParamName + Send) -> Result<bool> {
+        let name = name.to_param_name()?.resolve_to_root()?;
+        Ok(self.params.get(name.as_str()).is_some())
+    }
+
+    async fn delete_param(&self, name: impl ToParamName + Send) -> Result<()> {
+        let name = name.to_param_name()?.resolve_to_root()?;
+        self.params.delete(name.as_str());
+        Ok(())
+    }
+
+    async fn list_params(&self) -> Result<Vec<String>> {
+        Ok(self.params.names())
+    }
+}
+
+// Topics are never removed from the mock, so every topic ever advertised or subscribed to is reported
+impl GraphProvider for MockRos {
+    async fn topics(&self) -> Result<Vec<TopicInfo>> {
+        // BTreeMap iteration is already sorted by name
+        Ok(self
+            .topics
+            .read()
+            .await
+            .iter()
+            .map(|(name, entry)| TopicInfo {
+                name: name.clone(),
+                msg_type: entry.msg_type.name.clone(),
+            })
+            .collect())
+    }
+
+    async fn services(&self) -> Result<Vec<ServiceInfo>> {
+        Ok(self
+            .services
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(name, entry)| ServiceInfo {
+                name: name.clone(),
+                srv_type: entry.srv_type.name.clone(),
+            })
+            .collect())
+    }
+}
+
 /// The handle type returned by calling [MockRos::service_client].
 /// Represents a ROS service connection and allows the service to be called multiple times.
+///
+/// Like a real backend the client looks up the service on each call, so calls fail with [Error::Disconnected]
+/// once the server has been dropped, and will reach a new server if the service is re-advertised.
 pub struct MockServiceClient<T: RosServiceType> {
-    callback: TypeErasedCallback,
+    name: String,
+    services: ServiceMap,
+    recorder: Recorder,
+    clock: MockClock,
     _marker: std::marker::PhantomData<T>,
 }
 
@@ -133,14 +675,23 @@ impl<T: RosServiceType> Service<T> for MockServiceClient<T> {
         // Serialize incoming data
         let data =
             bincode::serialize(request).map_err(|e| Error::SerializationError(e.to_string()))?;
+        self.recorder.record(
+            MockEvent::ServiceCalled {
+                service: self.name.clone(),
+                srv_type: T::ROS_SERVICE_NAME.to_string(),
+            },
+            self.clock.now(),
+            Some(data.clone()),
+        );
+
+        let callback = lookup_service(&self.services, &self.name, &RosType::service::<T>())?;
 
-        let callback = self.callback.clone();
         // Wrap in a spawn_blocking to uphold trait expectations.
         // Actual service call happens here
         let response = tokio::task::spawn_blocking(move || (callback)(data))
             .await
             .map_err(|_e| Error::Disconnected)?
-            .map_err(|e| Error::SerializationError(e.to_string()))?;
+            .map_err(|e| Error::ServerError(e.to_string()))?;
 
         // Deserialize response
         let response = bincode::deserialize(&response[..])
@@ -149,13 +700,35 @@ impl<T: RosServiceType> Service<T> for MockServiceClient<T> {
     }
 }
 
+/// The handle type returned by calling [MockRos::advertise_service].
+/// The service remains available until this handle is dropped.
+pub struct MockServiceServer {
+    name: String,
+    callback: TypeErasedCallback,
+    services: ServiceMap,
+}
+
+impl Drop for MockServiceServer {
+    fn drop(&mut self) {
+        let mut services = self.services.write().unwrap();
+        // Only remove the service if it hasn't since been replaced by another server
+        if services
+            .get(&self.name)
+            .is_some_and(|entry| Arc::ptr_eq(&entry.callback, &self.callback))
+        {
+            debug!("Unadvertised service {}", self.name);
+            services.remove(&self.name);
+        }
+    }
+}
+
 impl ServiceProvider for MockRos {
     type ServiceClient<T: RosServiceType> = MockServiceClient<T>;
-    type ServiceServer = ();
+    type ServiceServer = MockServiceServer;
 
     async fn call_service<T: RosServiceType>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         request: T::Request,
     ) -> roslibrust_common::Result<T::Response> {
         let client = self.service_client::<T>(topic).await?;
@@ -164,26 +737,28 @@ impl ServiceProvider for MockRos {
 
     async fn service_client<T: RosServiceType + 'static>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
     ) -> roslibrust_common::Result<Self::ServiceClient<T>> {
-        let services = self.services.read().await;
-        if let Some(callback) = services.get(topic) {
-            return Ok(MockServiceClient {
-                callback: callback.clone(),
-                _marker: Default::default(),
-            });
-        }
-        Err(Error::Disconnected)
+        let topic = topic.to_service_name()?.resolve_to_root()?;
+        lookup_service(&self.services, topic.as_str(), &RosType::service::<T>())?;
+        Ok(MockServiceClient {
+            name: topic.into(),
+            services: self.services.clone(),
+            recorder: self.recorder.clone(),
+            clock: self.clock.clone(),
+            _marker: Default::default(),
+        })
     }
 
     async fn advertise_service<T: RosServiceType + 'static, F>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         server: F,
     ) -> roslibrust_common::Result<Self::ServiceServer>
     where
         F: ServiceFn<T>,
     {
+        let topic = topic.to_service_name()?.resolve_to_root()?;
         // Type erase the service function here
         let erased_closure = move |message: Vec<u8>| -> std::result::Result<
             Vec<u8>,
@@ -196,19 +771,70 @@ impl ServiceProvider for MockRos {
                 .map_err(|e| Error::SerializationError(e.to_string()))?;
             Ok(bytes)
         };
-        let erased_closure = Arc::new(erased_closure);
-        let mut services = self.services.write().await;
-        services.insert(topic.to_string(), erased_closure);
+        let erased_closure: TypeErasedCallback = Arc::new(erased_closure);
+        let previous = self.services.write().unwrap().insert(
+            topic.to_string(),
+            ServiceEntry {
+                srv_type: RosType::service::<T>(),
+                callback: erased_closure.clone(),
+            },
+        );
+        if previous.is_some() {
+            warn!("Service {topic} was re-advertised, replacing the existing server");
+        }
+        self.recorder.record(
+            MockEvent::ServiceAdvertised {
+                service: topic.to_string(),
+                srv_type: T::ROS_SERVICE_NAME.to_string(),
+            },
+            self.clock.now(),
+            None,
+        );
+        debug!("Advertised service {topic}");
+
+        Ok(MockServiceServer {
+            name: topic.into(),
+            callback: erased_closure,
+            services: self.services.clone(),
+        })
+    }
+}
+
+/// Publishes already serialized messages, shared by [MockPublisher] and scenario playback.
+struct RawPublisher {
+    topic: String,
+    sender: Channel::Sender<Vec<u8>>,
+    msg_type: String,
+    faults: Faults,
+    recorder: Recorder,
+    clock: MockClock,
+}
 
-        // We technically need to hand back a token that shuts the service down here
-        // But we haven't implemented that yet in this mock
+impl RawPublisher {
+    fn publish(&self, data: Vec<u8>) -> Result<()> {
+        self.recorder.record(
+            MockEvent::Published {
+                topic: self.topic.clone(),
+                msg_type: self.msg_type.clone(),
+            },
+            self.clock.now(),
+            Some(data.clone()),
+        );
+        match self.faults.deliver(&self.topic, &self.sender, data) {
+            Delivery::Now(data) => {
+                self.sender.send(data).map_err(|_e| Error::Disconnected)?;
+                debug!("Sent data on topic {}", self.topic);
+            }
+            Delivery::Dropped => debug!("Dropped message on topic {}", self.topic),
+            Delivery::Scheduled => debug!("Delayed message on topic {}", self.topic),
+        }
         Ok(())
     }
 }
 
 /// The publisher type returned by calling [MockRos::advertise].
 pub struct MockPublisher<T: RosMessageType> {
-    sender: Channel::Sender<Vec<u8>>,
+    inner: RawPublisher,
     _marker: std::marker::PhantomData<T>,
 }
 
@@ -216,25 +842,20 @@ impl<T: RosMessageType> Publish<T> for MockPublisher<T> {
     async fn publish(&self, data: &T) -> roslibrust_common::Result<()> {
         let data =
             bincode::serialize(data).map_err(|e| Error::SerializationError(e.to_string()))?;
-        self.sender.send(data).map_err(|_e| Error::Disconnected)?;
-        debug!("Sent data on topic {}", T::ROS_TYPE_NAME);
-        Ok(())
+        self.inner.publish(data)
     }
 }
 
 /// The subscriber type returned by calling [MockRos::subscribe].
 pub struct MockSubscriber<T: RosMessageType> {
     receiver: Channel::Receiver<Vec<u8>>,
+    metrics: ChannelMetrics,
     _marker: std::marker::PhantomData<T>,
 }
 
 impl<T: RosMessageType> Subscribe<T> for MockSubscriber<T> {
     async fn next(&mut self) -> roslibrust_common::Result<T> {
-        let data = self
-            .receiver
-            .recv()
-            .await
-            .map_err(|_| Error::Disconnected)?;
+        let data = recv(&mut self.receiver, &self.metrics, T::ROS_TYPE_NAME).await?;
         let msg = bincode::deserialize(&data[..])
             .map_err(|e| Error::SerializationError(e.to_string()))?;
         debug!("Received data on topic {}", T::ROS_TYPE_NAME);
@@ -242,6 +863,51 @@ impl<T: RosMessageType> Subscribe<T> for MockSubscriber<T> {
     }
 }
 
+/// The publisher type returned by calling [MockRos::advertise_raw], publishing messages serialized with bincode.
+pub struct MockRawPublisher {
+    inner: RawPublisher,
+}
+
+impl RawPublish for MockRawPublisher {
+    async fn publish_raw(&self, data: &[u8]) -> roslibrust_common::Result<()> {
+        self.inner.publish(data.to_vec())
+    }
+}
+
+/// The subscriber type returned by calling [MockRos::subscribe_raw], receiving messages serialized with bincode.
+pub struct MockRawSubscriber {
+    topic: String,
+    receiver: Channel::Receiver<Vec<u8>>,
+    metrics: ChannelMetrics,
+}
+
+impl RawSubscribe for MockRawSubscriber {
+    async fn next_raw(&mut self) -> roslibrust_common::Result<Vec<u8>> {
+        let data = recv(&mut self.receiver, &self.metrics, &self.topic).await?;
+        debug!("Received raw data on topic {}", self.topic);
+        Ok(data)
+    }
+}
+
+/// Waits for the next message of a subscriber, `name` identifies it in logs
+async fn recv(
+    receiver: &mut Channel::Receiver<Vec<u8>>,
+    metrics: &ChannelMetrics,
+    name: &str,
+) -> Result<Vec<u8>> {
+    loop {
+        match receiver.recv().await {
+            Ok(data) => return Ok(data),
+            // Falling behind skips to the oldest message still queued, like a real subscriber's full queue
+            Err(Channel::error::RecvError::Lagged(count)) => {
+                warn!("Subscriber on topic {name} fell behind, {count} messages were dropped");
+                metrics.record_subscriber_dropped(count);
+            }
+            Err(Channel::error::RecvError::Closed) => return Err(Error::Disconnected),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,6 +938,30 @@ mod tests {
         assert_eq!(msg, received_msg);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_mock_channel_config() {
+        let mock_ros = MockRos::new().channel_config(ChannelConfig {
+            subscriber_queue_size: Some(2),
+            ..Default::default()
+        });
+        let pub_handle = mock_ros
+            .advertise::<std_msgs::UInt8>("test_topic")
+            .await
+            .unwrap();
+        let mut sub_handle = mock_ros
+            .subscribe::<std_msgs::UInt8>("test_topic")
+            .await
+            .unwrap();
+        for data in 0..5 {
+            pub_handle.publish(&std_msgs::UInt8 { data }).await.unwrap();
+        }
+
+        // Only the last two messages fit in the queue
+        assert_eq!(sub_handle.next().await.unwrap().data, 3);
+        assert_eq!(sub_handle.next().await.unwrap().data, 4);
+        assert_eq!(mock_ros.clone().channel_metrics().subscriber_dropped(), 3);
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_mock_services() {
         let mock_topics = MockRos::new();
@@ -283,7 +973,7 @@ mod tests {
             })
         };
 
-        mock_topics
+        let _server = mock_topics
             .advertise_service::<std_srvs::SetBool, _>("test_service", server_fn)
             .await
             .unwrap();
@@ -300,6 +990,421 @@ mod tests {
         assert_eq!(response.message, "You set my bool!");
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_mock_service_lifecycle() {
+        let mock_ros = MockRos::new();
+
+        // Calling a service that doesn't exist fails like a real backend would
+        let result = mock_ros
+            .call_service::<std_srvs::Trigger>("/trigger", std_srvs::TriggerRequest {})
+            .await;
+        assert!(matches!(result, Err(Error::Disconnected)));
+
+        let server = mock_ros
+            .advertise_service::<std_srvs::Trigger, _>("/trigger", |_| {
+                Ok(std_srvs::TriggerResponse {
+                    success: true,
+                    message: "first".to_string(),
+                })
+            })
+            .await
+            .unwrap();
+        let client = mock_ros
+            .service_client::<std_srvs::Trigger>("trigger")
+            .await
+            .unwrap();
+        let response = client.call(&std_srvs::TriggerRequest {}).await.unwrap();
+        assert_eq!(response.message, "first");
+
+        // Server errors are reported to the caller
+        let failing = mock_ros
+            .advertise_service::<std_srvs::Trigger, _>("/trigger", |_| Err("nope".into()))
+            .await
+            .unwrap();
+        let result = client.call(&std_srvs::TriggerRequest {}).await;
+        assert!(matches!(result, Err(Error::ServerError(_))));
+
+        // Dropping a replaced server does not remove the service, dropping the active one does
+        std::mem::drop(server);
+        assert!(client.call(&std_srvs::TriggerRequest {}).await.is_err());
+        assert!(mock_ros
+            .service_client::<std_srvs::Trigger>("/trigger")
+            .await
+            .is_ok());
+        std::mem::drop(failing);
+        let result = client.call(&std_srvs::TriggerRequest {}).await;
+        assert!(matches!(result, Err(Error::Disconnected)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_mock_fault_injection() {
+        let mock_ros = MockRos::with_seed(7);
+        mock_ros
+            .set_faults(
+                "/delayed",
+                FaultConfig::new()
+                    .latency(std::time::Duration::from_millis(100))
+                    .jitter(std::time::Duration::from_millis(50)),
+            )
+            .unwrap();
+        mock_ros
+            .set_faults("/lossy", FaultConfig::new().drop_probability(0.5))
+            .unwrap();
+
+        let publisher = mock_ros
+            .advertise::<std_msgs::UInt32>("/delayed")
+            .await
+            .unwrap();
+        let mut subscriber = mock_ros
+            .subscribe::<std_msgs::UInt32>("/delayed")
+            .await
+            .unwrap();
+        let start = tokio::time::Instant::now();
+        for i in 0..5 {
+            publisher
+                .publish(&std_msgs::UInt32 { data: i })
+                .await
+                .unwrap();
+        }
+        // Messages are delayed, but delivered in order
+        for i in 0..5 {
+            assert_eq!(subscriber.next().await.unwrap().data, i);
+        }
+        assert!(start.elapsed() >= std::time::Duration::from_millis(100));
+
+        // With a fixed seed, the drop pattern is fixed, but some messages get through and some don't
+        let publisher = mock_ros
+            .advertise::<std_msgs::UInt32>("/lossy")
+            .await
+            .unwrap();
+        let mut subscriber = mock_ros
+            .subscribe::<std_msgs::UInt32>("/lossy")
+            .await
+            .unwrap();
+        for i in 0..8 {
+            publisher
+                .publish(&std_msgs::UInt32 { data: i })
+                .await
+                .unwrap();
+        }
+        let mut received = vec![];
+        while let Ok(Ok(msg)) =
+            tokio::time::timeout(std::time::Duration::from_millis(10), subscriber.next()).await
+        {
+            received.push(msg.data);
+        }
+        assert!(!received.is_empty() && received.len() < 8);
+
+        // Clearing faults restores normal delivery
+        mock_ros.clear_faults("/lossy").unwrap();
+        publisher
+            .publish(&std_msgs::UInt32 { data: 99 })
+            .await
+            .unwrap();
+        assert_eq!(subscriber.next().await.unwrap().data, 99);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_mock_virtual_time() {
+        use std::time::Duration;
+        let mock_ros = MockRos::new();
+        let clock = mock_ros.clock();
+        assert_eq!(mock_ros.now(), std::time::UNIX_EPOCH);
+
+        mock_ros
+            .set_faults(
+                "/delayed",
+                FaultConfig::new().latency(Duration::from_secs(5)),
+            )
+            .unwrap();
+        let publisher = mock_ros
+            .advertise::<std_msgs::UInt32>("/delayed")
+            .await
+            .unwrap();
+        let mut subscriber = mock_ros
+            .subscribe::<std_msgs::UInt32>("/delayed")
+            .await
+            .unwrap();
+        publisher
+            .publish(&std_msgs::UInt32 { data: 1 })
+            .await
+            .unwrap();
+
+        // Nothing is delivered until virtual time reaches the delivery time
+        clock.advance(Duration::from_secs(4)).await;
+        let early = tokio::time::timeout(Duration::ZERO, subscriber.next()).await;
+        assert!(early.is_err());
+        clock
+            .advance_to(std::time::UNIX_EPOCH + Duration::from_secs(5))
+            .await;
+        assert_eq!(subscriber.next().await.unwrap().data, 1);
+
+        // Sleeping via the ClockProvider follows virtual time
+        mock_ros.sleep(Duration::from_secs(60)).await;
+        assert_eq!(
+            mock_ros.now(),
+            std::time::UNIX_EPOCH + Duration::from_secs(65)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_mock_recording() {
+        let mock_ros = MockRos::new();
+        let _server = mock_ros
+            .advertise_service::<std_srvs::SetBool, _>("/enable", |request| {
+                Ok(std_srvs::SetBoolResponse {
+                    success: request.data,
+                    message: String::new(),
+                })
+            })
+            .await
+            .unwrap();
+        let publisher = mock_ros
+            .advertise::<std_msgs::String>("chatter")
+            .await
+            .unwrap();
+        let _subscriber = mock_ros
+            .subscribe::<std_msgs::String>("/chatter")
+            .await
+            .unwrap();
+
+        mock_ros
+            .call_service::<std_srvs::SetBool>("enable", std_srvs::SetBoolRequest { data: true })
+            .await
+            .unwrap();
+        for data in ["a", "b"] {
+            publisher
+                .publish(&std_msgs::String {
+                    data: data.to_string(),
+                })
+                .await
+                .unwrap();
+        }
+
+        let published = mock_ros
+            .published_on::<std_msgs::String>("/chatter")
+            .unwrap();
+        assert_eq!(
+            published.into_iter().map(|m| m.data).collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        assert_eq!(mock_ros.publish_count("/chatter").unwrap(), 2);
+        assert_eq!(mock_ros.publish_count("/other").unwrap(), 0);
+        assert_eq!(mock_ros.subscription_count("/chatter").unwrap(), 1);
+        assert_eq!(mock_ros.service_call_count("/enable").unwrap(), 1);
+        let calls = mock_ros
+            .service_calls::<std_srvs::SetBool>("/enable")
+            .unwrap();
+        assert!(calls[0].data);
+
+        // The service call happened before the first publish
+        let events: Vec<_> = mock_ros.events().into_iter().map(|(_, e)| e).collect();
+        let called = events
+            .iter()
+            .position(|e| matches!(e, MockEvent::ServiceCalled { .. }))
+            .unwrap();
+        let published = events
+            .iter()
+            .position(|e| matches!(e, MockEvent::Published { .. }))
+            .unwrap();
+        assert!(called < published);
+
+        mock_ros.clear_recording();
+        assert!(mock_ros.events().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_mock_scenario() {
+        use std::time::Duration;
+        let mock_ros = MockRos::new();
+        let mut subscriber = mock_ros
+            .subscribe::<std_msgs::String>("/chatter")
+            .await
+            .unwrap();
+
+        let types = ScenarioTypes::new()
+            .message::<std_msgs::String>()
+            .service::<std_srvs::SetBool>();
+        let scenario = Scenario::from_json(
+            r#"{ "steps": [
+                { "at": 2.0, "publish": { "topic": "chatter", "type": "std_msgs/String", "msg": { "data": "second" } } },
+                { "at": 1.0, "publish": { "topic": "/chatter", "type": "std_msgs/String", "msg": { "data": "first" } } },
+                { "at": 1.5, "respond": { "service": "/enable", "type": "std_srvs/SetBool", "response": { "success": true, "message": "ok" } } }
+            ] }"#,
+            &types,
+        )
+        .unwrap();
+        assert_eq!(scenario.duration(), Duration::from_secs(2));
+
+        let start = tokio::time::Instant::now();
+        mock_ros.play(&scenario).await.unwrap();
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+
+        assert_eq!(subscriber.next().await.unwrap().data, "first");
+        assert_eq!(subscriber.next().await.unwrap().data, "second");
+        let response = mock_ros
+            .call_service::<std_srvs::SetBool>("/enable", std_srvs::SetBoolRequest { data: false })
+            .await
+            .unwrap();
+        assert_eq!(response.message, "ok");
+
+        // Unregistered types and invalid names are rejected before anything is played
+        assert!(Scenario::from_yaml(
+            "steps: [{ at: 0, publish: { topic: /a, type: std_msgs/Int32, msg: { data: 1 } } }]",
+            &types
+        )
+        .is_err());
+        let invalid = Scenario::new()
+            .publish(Duration::ZERO, "/chatter", &std_msgs::String::default())
+            .publish(Duration::ZERO, "1nvalid", &std_msgs::String::default());
+        assert!(mock_ros.play(&invalid).await.is_err());
+        assert_eq!(mock_ros.publish_count("/chatter").unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_mock_params() {
+        let mock_ros = MockRos::new();
+        mock_ros.set_param("/robot/name", &"robbie").await.unwrap();
+        mock_ros
+            .set_param("robot/wheel/radius", &0.25f64)
+            .await
+            .unwrap();
+        mock_ros.set_param("/rate", &10).await.unwrap();
+
+        assert_eq!(
+            mock_ros.get_param::<String>("/robot/name").await.unwrap(),
+            Some("robbie".to_string())
+        );
+        assert_eq!(
+            mock_ros
+                .get_param::<f64>("/robot/wheel/radius")
+                .await
+                .unwrap(),
+            Some(0.25)
+        );
+        assert_eq!(mock_ros.get_param::<i32>("/missing").await.unwrap(), None);
+        // Wrong type is an error rather than None
+        assert!(mock_ros.get_param::<String>("/rate").await.is_err());
+
+        // Namespaces can be read as a whole
+        #[derive(serde::Deserialize)]
+        struct Wheel {
+            radius: f64,
+        }
+        let wheel: Wheel = mock_ros.get_param("/robot/wheel").await.unwrap().unwrap();
+        assert_eq!(wheel.radius, 0.25);
+        assert!(mock_ros.has_param("/robot").await.unwrap());
+
+        assert_eq!(
+            mock_ros.list_params().await.unwrap(),
+            vec!["/rate", "/robot/name", "/robot/wheel/radius"]
+        );
+
+        mock_ros.delete_param("/robot/wheel").await.unwrap();
+        assert!(!mock_ros.has_param("/robot/wheel/radius").await.unwrap());
+        assert!(mock_ros.has_param("/robot/name").await.unwrap());
+        // Deleting a missing parameter is fine
+        mock_ros.delete_param("/robot/wheel").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mock_graph() {
+        let mock_ros = MockRos::new();
+        let _publisher = mock_ros
+            .advertise::<std_msgs::String>("/chatter")
+            .await
+            .unwrap();
+        let _subscriber = mock_ros
+            .subscribe::<std_msgs::Float32>("/battery")
+            .await
+            .unwrap();
+        let server = mock_ros
+            .advertise_service::<std_srvs::SetBool, _>("/enable", |_| {
+                Ok(std_srvs::SetBoolResponse::default())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            mock_ros.topics().await.unwrap(),
+            vec![
+                TopicInfo {
+                    name: "/battery".to_string(),
+                    msg_type: "std_msgs/Float32".to_string()
+                },
+                TopicInfo {
+                    name: "/chatter".to_string(),
+                    msg_type: "std_msgs/String".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            mock_ros.services().await.unwrap(),
+            vec![ServiceInfo {
+                name: "/enable".to_string(),
+                srv_type: "std_srvs/SetBool".to_string()
+            }]
+        );
+        drop(server);
+        assert!(mock_ros.services().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mock_type_conformance() {
+        use std::time::Duration;
+        let mock_ros = MockRos::new();
+        let _publisher = mock_ros
+            .advertise::<std_msgs::String>("/chatter")
+            .await
+            .unwrap();
+
+        // Mismatched publishers and subscribers are rejected like in ROS1
+        let result = mock_ros.advertise::<std_msgs::Float32>("/chatter").await;
+        assert!(matches!(result, Err(Error::SerializationError(_))));
+        let result = mock_ros.subscribe::<std_msgs::Float32>("chatter").await;
+        assert!(matches!(result, Err(Error::SerializationError(_))));
+        // Matching types and ShapeShifter are fine
+        mock_ros
+            .subscribe::<std_msgs::String>("/chatter")
+            .await
+            .unwrap();
+        mock_ros
+            .subscribe::<ShapeShifter>("/chatter")
+            .await
+            .unwrap();
+
+        // A topic created by a ShapeShifter takes the first concrete type
+        mock_ros.subscribe::<ShapeShifter>("/any").await.unwrap();
+        mock_ros
+            .advertise::<std_msgs::Float32>("/any")
+            .await
+            .unwrap();
+        assert!(mock_ros
+            .advertise::<std_msgs::String>("/any")
+            .await
+            .is_err());
+
+        // Same for scenario playback
+        let scenario =
+            Scenario::new().publish(Duration::ZERO, "/chatter", &std_msgs::Float32 { data: 1.0 });
+        assert!(mock_ros.play(&scenario).await.is_err());
+
+        let _server = mock_ros
+            .advertise_service::<std_srvs::SetBool, _>("/enable", |_| {
+                Ok(std_srvs::SetBoolResponse::default())
+            })
+            .await
+            .unwrap();
+        let result = mock_ros
+            .call_service::<std_srvs::Trigger>("/enable", std_srvs::TriggerRequest {})
+            .await;
+        assert!(matches!(result, Err(Error::SerializationError(_))));
+        mock_ros
+            .call_service::<std_srvs::SetBool>("/enable", std_srvs::SetBoolRequest { data: true })
+            .await
+            .unwrap();
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_mock_node() {
         // Proves that MockRos impls the Ros trait (via auto impl in roslibrust_common)
@@ -0,0 +1,91 @@
+//! In memory parameter server backing [crate::MockRos]'s [roslibrust_common::ParamProvider] implementation.
+
+use std::sync::{Arc, RwLock};
+
+use serde_json::{Map, Value};
+
+/// Parameters stored as a tree of JSON objects keyed by name segment, mirroring how the ROS1 parameter server
+/// treats namespaces as dictionaries.
+#[derive(Clone, Default)]
+pub(crate) struct Params {
+    root: Arc<RwLock<Map<String, Value>>>,
+}
+
+/// Splits a resolved name like "/a/b" into its segments
+fn segments(name: &str) -> Vec<&str> {
+    name.split('/').filter(|s| !s.is_empty()).collect()
+}
+
+impl Params {
+    pub(crate) fn get(&self, name: &str) -> Option<Value> {
+        let root = self.root.read().unwrap();
+        let mut segments = segments(name).into_iter();
+        let Some(first) = segments.next() else {
+            return Some(Value::Object(root.clone()));
+        };
+        let mut value = root.get(first)?;
+        for segment in segments {
+            value = value.as_object()?.get(segment)?;
+        }
+        Some(value.clone())
+    }
+
+    pub(crate) fn set(&self, name: &str, value: Value) {
+        let mut root = self.root.write().unwrap();
+        let segments = segments(name);
+        let Some((last, parents)) = segments.split_last() else {
+            // Setting the root namespace replaces everything
+            *root = match value {
+                Value::Object(map) => map,
+                _ => Map::new(),
+            };
+            return;
+        };
+        let mut map = &mut *root;
+        for segment in parents {
+            let entry = map
+                .entry(segment.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+            // Setting a parameter beneath a non-namespace value replaces that value
+            if !entry.is_object() {
+                *entry = Value::Object(Map::new());
+            }
+            map = entry.as_object_mut().unwrap();
+        }
+        map.insert(last.to_string(), value);
+    }
+
+    pub(crate) fn delete(&self, name: &str) {
+        let mut root = self.root.write().unwrap();
+        let segments = segments(name);
+        let Some((last, parents)) = segments.split_last() else {
+            root.clear();
+            return;
+        };
+        let mut map = &mut *root;
+        for segment in parents {
+            match map.get_mut(*segment).and_then(Value::as_object_mut) {
+                Some(child) => map = child,
+                None => return,
+            }
+        }
+        map.remove(*last);
+    }
+
+    /// Returns the full names of all leaf parameters in sorted order
+    pub(crate) fn names(&self) -> Vec<String> {
+        fn collect(prefix: &str, map: &Map<String, Value>, names: &mut Vec<String>) {
+            for (key, value) in map {
+                let name = format!("{prefix}/{key}");
+                match value {
+                    Value::Object(child) => collect(&name, child, names),
+                    _ => names.push(name),
+                }
+            }
+        }
+        let mut names = Vec::new();
+        collect("", &self.root.read().unwrap(), &mut names);
+        names.sort();
+        names
+    }
+}
@@ -0,0 +1,77 @@
+//! Records all traffic through a [crate::MockRos] so tests can assert on what a node did.
+
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+/// A single interaction with a [crate::MockRos], as returned by [crate::MockRos::events].
+///
+/// Topic and service names are fully resolved (e.g. "chatter" is recorded as "/chatter").
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MockEvent {
+    /// A publisher was created for `topic`
+    Advertised { topic: String, msg_type: String },
+    /// A subscriber was created for `topic`
+    Subscribed { topic: String, msg_type: String },
+    /// A message was published to `topic`
+    Published { topic: String, msg_type: String },
+    /// A service server was advertised
+    ServiceAdvertised { service: String, srv_type: String },
+    /// A service was called (whether or not the call succeeded)
+    ServiceCalled { service: String, srv_type: String },
+}
+
+struct Record {
+    event: MockEvent,
+    // Virtual time the event happened at
+    time: SystemTime,
+    // Serialized message or request, for Published and ServiceCalled events
+    data: Option<Vec<u8>>,
+}
+
+/// Shared, append only log of [MockEvent]s
+#[derive(Clone, Default)]
+pub(crate) struct Recorder {
+    records: Arc<Mutex<Vec<Record>>>,
+}
+
+impl Recorder {
+    pub(crate) fn record(&self, event: MockEvent, time: SystemTime, data: Option<Vec<u8>>) {
+        self.records
+            .lock()
+            .unwrap()
+            .push(Record { event, time, data });
+    }
+
+    pub(crate) fn events(&self) -> Vec<(SystemTime, MockEvent)> {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| (r.time, r.event.clone()))
+            .collect()
+    }
+
+    /// Returns the data of all records matching `filter`
+    pub(crate) fn data(&self, filter: impl Fn(&MockEvent) -> bool) -> Vec<Vec<u8>> {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| filter(&r.event))
+            .filter_map(|r| r.data.clone())
+            .collect()
+    }
+
+    pub(crate) fn count(&self, filter: impl Fn(&MockEvent) -> bool) -> usize {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| filter(&r.event))
+            .count()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+}
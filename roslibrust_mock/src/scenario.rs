@@ -0,0 +1,353 @@
+//! Scripted scenarios of timed incoming messages and service responses, replayed with [crate::MockRos::play].
+//!
+//! Scenarios can be built programmatically with [Scenario::publish] and [Scenario::respond], or loaded from
+//! YAML / JSON files with [Scenario::from_yaml] and [Scenario::from_json], which makes it easy to write
+//! table driven tests where each case is a different scenario run against the same node.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use roslibrust_common::{
+    Error, Result, RosMessageType, RosServiceType, ToServiceName, ToTopicName,
+};
+use serde::Deserialize;
+
+use crate::RosType;
+
+/// A single timed action in a [Scenario]
+#[derive(Clone, Debug)]
+pub(crate) struct Step {
+    /// Offset from the start of playback
+    pub(crate) at: Duration,
+    pub(crate) action: Action,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum Action {
+    /// Publish a serialized message on a topic
+    Publish {
+        topic: String,
+        msg_type: RosType,
+        data: std::result::Result<Vec<u8>, String>,
+    },
+    /// Start responding to all calls of a service with a serialized response
+    Respond {
+        service: String,
+        srv_type: RosType,
+        response: std::result::Result<Vec<u8>, String>,
+    },
+}
+
+/// A validated [Action] ready to be played
+pub(crate) enum Prepared {
+    Publish {
+        topic: String,
+        msg_type: RosType,
+        data: Vec<u8>,
+    },
+    Respond {
+        service: String,
+        srv_type: RosType,
+        response: Vec<u8>,
+    },
+}
+
+/// A script of timed incoming messages and service responses.
+///
+/// Times are offsets from the start of playback, steps with the same time are played in the order they were added.
+///
+/// ```
+/// use roslibrust_mock::Scenario;
+/// use roslibrust_test::ros1::{std_msgs, std_srvs};
+/// use std::time::Duration;
+///
+/// let scenario = Scenario::new()
+///     .publish(Duration::ZERO, "/battery", &std_msgs::Float32 { data: 12.4 })
+///     .respond::<std_srvs::Trigger>(
+///         Duration::from_secs(1),
+///         "/dock",
+///         std_srvs::TriggerResponse { success: true, message: "docked".to_string() },
+///     )
+///     .publish(Duration::from_secs(2), "/battery", &std_msgs::Float32 { data: 11.1 });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Scenario {
+    steps: Vec<Step>,
+}
+
+impl Scenario {
+    /// Creates an empty scenario.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publishes `msg` on `topic` at offset `at` from the start of playback.
+    pub fn publish<T: RosMessageType>(
+        mut self,
+        at: Duration,
+        topic: impl Into<String>,
+        msg: &T,
+    ) -> Self {
+        self.steps.push(Step {
+            at,
+            action: Action::Publish {
+                topic: topic.into(),
+                msg_type: RosType::message::<T>(),
+                data: bincode::serialize(msg).map_err(|e| e.to_string()),
+            },
+        });
+        self
+    }
+
+    /// From offset `at` onwards all calls to `service` are answered with `response`.
+    ///
+    /// This replaces any server previously advertised for the service, and the scripted server stays
+    /// available after playback finishes until the service is re-advertised or the mock is dropped.
+    pub fn respond<T: RosServiceType>(
+        mut self,
+        at: Duration,
+        service: impl Into<String>,
+        response: T::Response,
+    ) -> Self {
+        self.steps.push(Step {
+            at,
+            action: Action::Respond {
+                service: service.into(),
+                srv_type: RosType::service::<T>(),
+                response: bincode::serialize(&response).map_err(|e| e.to_string()),
+            },
+        });
+        self
+    }
+
+    /// Returns the total duration of the scenario, i.e. the offset of its last step.
+    pub fn duration(&self) -> Duration {
+        self.steps
+            .iter()
+            .map(|step| step.at)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Validates the scenario, returning its steps sorted by time with fully resolved names.
+    /// Steps at the same time keep the order they were added in.
+    pub(crate) fn prepare(&self) -> Result<Vec<(Duration, Prepared)>> {
+        let mut steps = self
+            .steps
+            .iter()
+            .map(|step| {
+                let action = match &step.action {
+                    Action::Publish {
+                        topic,
+                        msg_type,
+                        data,
+                    } => Prepared::Publish {
+                        topic: topic.to_topic_name()?.resolve_to_root()?.into(),
+                        msg_type: msg_type.clone(),
+                        data: data.clone().map_err(Error::SerializationError)?,
+                    },
+                    Action::Respond {
+                        service,
+                        srv_type,
+                        response,
+                    } => Prepared::Respond {
+                        service: service.to_service_name()?.resolve_to_root()?.into(),
+                        srv_type: srv_type.clone(),
+                        response: response.clone().map_err(Error::SerializationError)?,
+                    },
+                };
+                Ok((step.at, action))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        steps.sort_by_key(|(at, _)| *at);
+        Ok(steps)
+    }
+
+    /// Parses a scenario from YAML, see [Scenario::from_json] for the format.
+    ///
+    /// ```
+    /// use roslibrust_mock::{Scenario, ScenarioTypes};
+    /// use roslibrust_test::ros1::{std_msgs, std_srvs};
+    ///
+    /// let types = ScenarioTypes::new()
+    ///     .message::<std_msgs::String>()
+    ///     .service::<std_srvs::Trigger>();
+    /// let scenario = Scenario::from_yaml(
+    ///     r#"
+    /// steps:
+    ///   - at: 0.5
+    ///     publish:
+    ///       topic: /chatter
+    ///       type: std_msgs/String
+    ///       msg: { data: hello }
+    ///   - at: 1.0
+    ///     respond:
+    ///       service: /trigger
+    ///       type: std_srvs/Trigger
+    ///       response: { success: true, message: "" }
+    /// "#,
+    ///     &types,
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn from_yaml(yaml: &str, types: &ScenarioTypes) -> Result<Self> {
+        let file: ScenarioFile =
+            serde_yaml::from_str(yaml).map_err(|e| Error::SerializationError(e.to_string()))?;
+        file.into_scenario(types)
+    }
+
+    /// Parses a scenario from JSON.
+    ///
+    /// The scenario is an object with a list of `steps`, each with an `at` time in seconds and either a
+    /// `publish` or `respond` action. Messages and responses are given in their serde representation, and
+    /// their `type` must have been registered in `types`.
+    ///
+    /// ```json
+    /// {
+    ///   "steps": [
+    ///     { "at": 0.5, "publish": { "topic": "/chatter", "type": "std_msgs/String", "msg": { "data": "hello" } } },
+    ///     { "at": 1.0, "respond": { "service": "/trigger", "type": "std_srvs/Trigger", "response": { "success": true, "message": "" } } }
+    ///   ]
+    /// }
+    /// ```
+    pub fn from_json(json: &str, types: &ScenarioTypes) -> Result<Self> {
+        let file: ScenarioFile =
+            serde_json::from_str(json).map_err(|e| Error::SerializationError(e.to_string()))?;
+        file.into_scenario(types)
+    }
+}
+
+type Converter = fn(serde_json::Value) -> std::result::Result<Vec<u8>, String>;
+
+/// The message and service types which may appear in a scenario file.
+///
+/// Scenario files only name their types, so each type used must be registered to tell the mock how to
+/// convert it into the binary representation used on topics.
+#[derive(Clone, Debug, Default)]
+pub struct ScenarioTypes {
+    messages: BTreeMap<String, (RosType, Converter)>,
+    // Keyed by service type, converting the service's response
+    services: BTreeMap<String, (RosType, Converter)>,
+}
+
+impl ScenarioTypes {
+    /// Creates an empty set of types.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers message type `T` under its [RosMessageType::ROS_TYPE_NAME].
+    pub fn message<T: RosMessageType>(mut self) -> Self {
+        self.messages.insert(
+            T::ROS_TYPE_NAME.to_string(),
+            (RosType::message::<T>(), convert::<T>),
+        );
+        self
+    }
+
+    /// Registers service type `T` under its [RosServiceType::ROS_SERVICE_NAME].
+    pub fn service<T: RosServiceType>(mut self) -> Self {
+        self.services.insert(
+            T::ROS_SERVICE_NAME.to_string(),
+            (RosType::service::<T>(), convert::<T::Response>),
+        );
+        self
+    }
+}
+
+fn convert<T: RosMessageType>(value: serde_json::Value) -> std::result::Result<Vec<u8>, String> {
+    let msg: T = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    bincode::serialize(&msg).map_err(|e| e.to_string())
+}
+
+#[derive(Deserialize)]
+struct ScenarioFile {
+    steps: Vec<StepFile>,
+}
+
+#[derive(Deserialize)]
+struct StepFile {
+    /// Seconds from the start of playback
+    at: f64,
+    #[serde(flatten)]
+    action: ActionFile,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ActionFile {
+    Publish {
+        topic: String,
+        #[serde(rename = "type")]
+        msg_type: String,
+        msg: serde_json::Value,
+    },
+    Respond {
+        service: String,
+        #[serde(rename = "type")]
+        srv_type: String,
+        response: serde_json::Value,
+    },
+}
+
+impl ScenarioFile {
+    fn into_scenario(self, types: &ScenarioTypes) -> Result<Scenario> {
+        let steps = self
+            .steps
+            .into_iter()
+            .map(|step| {
+                let at = Duration::try_from_secs_f64(step.at).map_err(|e| {
+                    Error::SerializationError(format!("Invalid step time {}: {e}", step.at))
+                })?;
+                let action = match step.action {
+                    ActionFile::Publish {
+                        topic,
+                        msg_type,
+                        msg,
+                    } => {
+                        let (ros_type, converter) =
+                            types.messages.get(&msg_type).ok_or_else(|| {
+                                Error::SerializationError(format!(
+                                    "Message type {msg_type} is not registered in ScenarioTypes"
+                                ))
+                            })?;
+                        let data = converter(msg).map_err(|e| {
+                            Error::SerializationError(format!(
+                                "Invalid {msg_type} message for {topic}: {e}"
+                            ))
+                        })?;
+                        Action::Publish {
+                            topic,
+                            msg_type: ros_type.clone(),
+                            data: Ok(data),
+                        }
+                    }
+                    ActionFile::Respond {
+                        service,
+                        srv_type,
+                        response,
+                    } => {
+                        let (ros_type, converter) =
+                            types.services.get(&srv_type).ok_or_else(|| {
+                                Error::SerializationError(format!(
+                                    "Service type {srv_type} is not registered in ScenarioTypes"
+                                ))
+                            })?;
+                        let response = converter(response).map_err(|e| {
+                            Error::SerializationError(format!(
+                                "Invalid {srv_type} response for {service}: {e}"
+                            ))
+                        })?;
+                        Action::Respond {
+                            service,
+                            srv_type: ros_type.clone(),
+                            response: Ok(response),
+                        }
+                    }
+                };
+                Ok(Step { at, action })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Scenario { steps })
+    }
+}
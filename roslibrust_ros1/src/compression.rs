@@ -0,0 +1,218 @@
+//! Compression of the messages sent over TCPROS connections between roslibrust nodes, see [Compression].
+
+use std::io;
+
+/// The connection header field a subscriber requests compression with, and a publisher confirms it with.
+/// Other ROS1 implementations ignore it and keep sending uncompressed messages.
+pub(crate) const HEADER_FIELD: &str = "roslibrust_compression";
+
+/// The largest message a compressed frame may decompress to
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+pub(crate) const MAX_DECOMPRESSED_SIZE: u64 = 1 << 30;
+
+/// How the messages of a topic are compressed on the way from publisher to subscriber.
+///
+/// A subscriber asks for compression with [crate::SocketOptions::compression], and publishers which are roslibrust
+/// nodes built with the algorithm's feature compress each message they send it. Any other publisher sends
+/// uncompressed messages as usual, so the option is safe to set on subscribers of mixed graphs:
+/// ```no_run
+/// # #[cfg(feature = "zstd")]
+/// # async fn f(nh: roslibrust_ros1::NodeHandle) -> Result<(), roslibrust_ros1::NodeError> {
+/// use roslibrust_ros1::{Compression, SocketOptions};
+/// use roslibrust_test::ros1::sensor_msgs;
+///
+/// // Over the robot's radio link to the base station
+/// let options = SocketOptions::new().compression(Compression::Zstd);
+/// let subscriber = nh
+///     .subscribe_with_options::<sensor_msgs::PointCloud2>("/points", 10, options)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+/// Lz4 is fast enough to pay off on most links, while zstd compresses further at a higher cost, suiting slow links.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Compression {
+    #[default]
+    None,
+    /// LZ4 block compression, requires the `lz4` feature
+    #[cfg(feature = "lz4")]
+    Lz4,
+    /// Zstandard compression at its default level, requires the `zstd` feature
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+impl Compression {
+    /// The name of the algorithm in the connection header, None when not compressing
+    pub fn name(&self) -> Option<&'static str> {
+        match self {
+            Compression::None => None,
+            #[cfg(feature = "lz4")]
+            Compression::Lz4 => Some("lz4"),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Some("zstd"),
+        }
+    }
+
+    /// The algorithm named in a connection header, None when it isn't supported by this build
+    pub fn from_name(name: &str) -> Option<Compression> {
+        match name {
+            #[cfg(feature = "lz4")]
+            "lz4" => Some(Compression::Lz4),
+            #[cfg(feature = "zstd")]
+            "zstd" => Some(Compression::Zstd),
+            _ => None,
+        }
+    }
+
+    /// The compression a publisher uses for a subscriber which asked for `requested`
+    pub(crate) fn negotiate(requested: Option<&str>) -> Compression {
+        requested
+            .and_then(|name| {
+                let compression = Compression::from_name(name);
+                if compression.is_none() {
+                    log::warn!("Subscriber requested unsupported compression {name}, sending uncompressed messages");
+                }
+                compression
+            })
+            .unwrap_or_default()
+    }
+
+    /// Compresses a serialized message, which has its length at the front, into a frame with the compressed length
+    /// at the front
+    pub(crate) fn compress(&self, message: &[u8]) -> io::Result<Vec<u8>> {
+        let compressed: Option<Vec<u8>> = match self {
+            Compression::None => None,
+            #[cfg(feature = "lz4")]
+            Compression::Lz4 => Some(lz4_flex::block::compress_prepend_size(message)),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Some(zstd::bulk::compress(
+                message,
+                zstd::DEFAULT_COMPRESSION_LEVEL,
+            )?),
+        };
+        let Some(compressed) = compressed else {
+            return Ok(message.to_vec());
+        };
+        let mut frame = Vec::with_capacity(compressed.len() + 4);
+        frame.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        frame.extend_from_slice(&compressed);
+        Ok(frame)
+    }
+
+    /// Restores the message of a frame as read by [crate::tcpros::receive_body].
+    ///
+    /// Fails without decompressing when the frame claims to decompress to more than [MAX_DECOMPRESSED_SIZE] bytes,
+    /// so a corrupt or hostile frame can't exhaust memory.
+    pub(crate) fn decompress(&self, frame: Vec<u8>) -> io::Result<Vec<u8>> {
+        #[cfg(any(feature = "lz4", feature = "zstd"))]
+        let compressed = &frame[4..];
+        let message: Option<Vec<u8>> = match self {
+            Compression::None => None,
+            #[cfg(feature = "lz4")]
+            Compression::Lz4 => {
+                let size = compressed
+                    .get(..4)
+                    .map(|size| u32::from_le_bytes(size.try_into().unwrap()) as u64)
+                    .ok_or_else(|| invalid_data("Compressed frame is missing its size"))?;
+                check_size(size)?;
+                Some(
+                    lz4_flex::block::decompress_size_prepended(compressed)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                )
+            }
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => {
+                let size = zstd::zstd_safe::get_frame_content_size(compressed)
+                    .ok()
+                    .flatten()
+                    .ok_or_else(|| invalid_data("Compressed frame is missing its size"))?;
+                check_size(size)?;
+                Some(zstd::bulk::decompress(compressed, size as usize)?)
+            }
+        };
+        let Some(message) = message else {
+            return Ok(frame);
+        };
+        if message.len() < 4 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Decompressed message is missing its length",
+            ));
+        }
+        Ok(message)
+    }
+}
+
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+fn check_size(size: u64) -> io::Result<()> {
+    if size > MAX_DECOMPRESSED_SIZE {
+        return Err(invalid_data(&format!(
+            "Compressed frame decompresses to {size} bytes, over the limit of {MAX_DECOMPRESSED_SIZE}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test_log::test]
+    fn round_trips_messages() {
+        let mut message = 1000u32.to_le_bytes().to_vec();
+        message.extend(std::iter::repeat(b"point cloud ").flatten().take(1000));
+
+        assert_eq!(Compression::negotiate(None), Compression::None);
+        assert_eq!(Compression::negotiate(Some("brotli")), Compression::None);
+        let frame = Compression::None.compress(&message).unwrap();
+        assert_eq!(Compression::None.decompress(frame).unwrap(), message);
+
+        #[allow(unused_mut)]
+        let mut compressions: Vec<Compression> = vec![];
+        #[cfg(feature = "lz4")]
+        compressions.push(Compression::Lz4);
+        #[cfg(feature = "zstd")]
+        compressions.push(Compression::Zstd);
+        for compression in compressions {
+            let name = compression.name().unwrap();
+            assert_eq!(Compression::negotiate(Some(name)), compression);
+            let frame = compression.compress(&message).unwrap();
+            assert!(frame.len() < message.len() / 4);
+            let length = u32::from_le_bytes(frame[..4].try_into().unwrap()) as usize;
+            assert_eq!(length, frame.len() - 4);
+            assert_eq!(compression.decompress(frame).unwrap(), message);
+        }
+    }
+
+    #[cfg(any(feature = "lz4", feature = "zstd"))]
+    #[test_log::test]
+    fn rejects_oversized_frames() {
+        let oversized = (MAX_DECOMPRESSED_SIZE + 1) as usize;
+
+        #[cfg(feature = "lz4")]
+        {
+            // Claims a decompressed size over the limit, followed by garbage
+            let mut frame = vec![0; 4];
+            frame.extend_from_slice(&(oversized as u32).to_le_bytes());
+            frame.extend_from_slice(&[0; 16]);
+            let err = Compression::Lz4.decompress(frame).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+
+        #[cfg(feature = "zstd")]
+        {
+            // Just a frame header: the magic number, a descriptor for an 8 byte content size, and the size
+            let mut frame = vec![0; 4];
+            frame.extend_from_slice(&[0x28, 0xb5, 0x2f, 0xfd, 0xe0]);
+            frame.extend_from_slice(&(oversized as u64).to_le_bytes());
+            let err = Compression::Zstd.decompress(frame).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+}
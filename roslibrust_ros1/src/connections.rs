@@ -0,0 +1,356 @@
+//! Keeps track of a node's TCPROS connections to publishers and subscribers, see [crate::NodeHandle::connections].
+
+use crate::tcpros::ConnectionHeader;
+use abort_on_drop::ChildTask;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+use std::time::SystemTime;
+
+/// Which way messages flow on a connection
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// From a publisher to a subscription of this node
+    Inbound,
+    /// From a publication of this node to a subscriber
+    Outbound,
+}
+
+/// A TCPROS connection of a node, see [crate::NodeHandle::connections].
+#[derive(Clone, Debug)]
+pub struct ConnectionInfo {
+    /// Unique within the node, in the order the connections were made
+    pub id: u64,
+    pub direction: Direction,
+    pub topic: String,
+    /// The xmlrpc uri of the publisher for inbound connections, the address of the subscriber for outbound ones
+    pub peer: String,
+    /// The header this node sent
+    pub local_header: ConnectionHeader,
+    /// The header the peer sent
+    pub remote_header: ConnectionHeader,
+    /// The bytes of messages sent or received, including their length prefixes
+    pub bytes: u64,
+    pub messages: u64,
+    pub connected: SystemTime,
+}
+
+struct Connection {
+    info: ConnectionInfo,
+    stats: Arc<Stats>,
+}
+
+#[derive(Default)]
+struct Stats {
+    bytes: AtomicU64,
+    messages: AtomicU64,
+}
+
+#[derive(Default)]
+struct Connections {
+    next_id: u64,
+    open: BTreeMap<u64, Connection>,
+}
+
+/// The open connections of a node, shared by its handles, publications and subscriptions
+#[derive(Clone, Default)]
+pub(crate) struct ConnectionRegistry {
+    connections: Arc<Mutex<Connections>>,
+}
+
+impl ConnectionRegistry {
+    /// The open connections, oldest first
+    pub(crate) fn connections(&self) -> Vec<ConnectionInfo> {
+        self.lock()
+            .open
+            .values()
+            .map(|connection| ConnectionInfo {
+                bytes: connection.stats.bytes.load(Ordering::Relaxed),
+                messages: connection.stats.messages.load(Ordering::Relaxed),
+                ..connection.info.clone()
+            })
+            .collect()
+    }
+
+    /// Lists a connection until the returned guard is dropped
+    pub(crate) fn register(
+        &self,
+        direction: Direction,
+        peer: String,
+        local_header: ConnectionHeader,
+        remote_header: ConnectionHeader,
+    ) -> ConnectionGuard {
+        let mut connections = self.lock();
+        let id = connections.next_id;
+        connections.next_id += 1;
+        let stats = Arc::new(Stats::default());
+        connections.open.insert(
+            id,
+            Connection {
+                info: ConnectionInfo {
+                    id,
+                    direction,
+                    topic: local_header.topic.clone().unwrap_or_default(),
+                    peer,
+                    local_header,
+                    remote_header,
+                    bytes: 0,
+                    messages: 0,
+                    connected: SystemTime::now(),
+                },
+                stats: stats.clone(),
+            },
+        );
+        ConnectionGuard {
+            connections: Arc::downgrade(&self.connections),
+            id,
+            stats,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Connections> {
+        self.connections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Counts the traffic of a connection, and removes it from its registry when the connection's task ends
+pub(crate) struct ConnectionGuard {
+    connections: Weak<Mutex<Connections>>,
+    id: u64,
+    stats: Arc<Stats>,
+}
+
+impl ConnectionGuard {
+    pub(crate) fn record(&self, messages: u64, bytes: usize) {
+        self.stats.messages.fetch_add(messages, Ordering::Relaxed);
+        self.stats.bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        if let Some(connections) = self.connections.upgrade() {
+            connections
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .open
+                .remove(&self.id);
+        }
+    }
+}
+
+/// Publishes the connections of a node as diagnostics until dropped, see [crate::NodeHandle::publish_connections].
+pub struct ConnectionDiagnostics {
+    _task: ChildTask<()>,
+}
+
+impl ConnectionDiagnostics {
+    pub(crate) fn new(task: ChildTask<()>) -> Self {
+        Self { _task: task }
+    }
+}
+
+/// Describes every connection as the status of a `diagnostic_msgs/DiagnosticArray`
+pub(crate) fn diagnostics(
+    node_name: &str,
+    connections: &[ConnectionInfo],
+    stamp: SystemTime,
+) -> diagnostic_msgs::DiagnosticArray {
+    let since_epoch = stamp
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let status = connections
+        .iter()
+        .map(|connection| {
+            let direction = match connection.direction {
+                Direction::Inbound => "from",
+                Direction::Outbound => "to",
+            };
+            let remote = &connection.remote_header;
+            let value = |key: &str, value: String| diagnostic_msgs::KeyValue {
+                key: key.to_owned(),
+                value,
+            };
+            diagnostic_msgs::DiagnosticStatus {
+                level: diagnostic_msgs::DiagnosticStatus::OK,
+                name: format!("{node_name}: connection {}", connection.id),
+                message: format!("{} {direction} {}", connection.topic, connection.peer),
+                hardware_id: String::new(),
+                values: vec![
+                    value("direction", format!("{:?}", connection.direction)),
+                    value("topic", connection.topic.clone()),
+                    value("peer", connection.peer.clone()),
+                    value("peer_node", remote.caller_id.clone()),
+                    value("type", remote.topic_type.clone()),
+                    value("md5sum", remote.md5sum.clone().unwrap_or_default()),
+                    value("latching", remote.latching.to_string()),
+                    value("tcp_nodelay", remote.tcp_nodelay.to_string()),
+                    value("bytes", connection.bytes.to_string()),
+                    value("messages", connection.messages.to_string()),
+                ],
+            }
+        })
+        .collect();
+    diagnostic_msgs::DiagnosticArray {
+        header: diagnostic_msgs::Header {
+            seq: 0,
+            stamp: diagnostic_msgs::Time {
+                secs: since_epoch.as_secs() as i32,
+                nsecs: since_epoch.subsec_nanos() as i32,
+            },
+            frame_id: String::new(),
+        },
+        status,
+    }
+}
+
+/// The `diagnostic_msgs/DiagnosticArray` published by [crate::NodeHandle::publish_connections].
+pub mod diagnostic_msgs {
+    use roslibrust_common::RosMessageType;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Time {
+        pub secs: i32,
+        pub nsecs: i32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct Header {
+        pub seq: u32,
+        pub stamp: Time,
+        pub frame_id: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct KeyValue {
+        pub key: String,
+        pub value: String,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct DiagnosticStatus {
+        pub level: u8,
+        pub name: String,
+        pub message: String,
+        pub hardware_id: String,
+        pub values: Vec<KeyValue>,
+    }
+
+    impl DiagnosticStatus {
+        pub const OK: u8 = 0;
+        pub const WARN: u8 = 1;
+        pub const ERROR: u8 = 2;
+        pub const STALE: u8 = 3;
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+    pub struct DiagnosticArray {
+        pub header: Header,
+        pub status: Vec<DiagnosticStatus>,
+    }
+
+    impl RosMessageType for DiagnosticArray {
+        const ROS_TYPE_NAME: &'static str = "diagnostic_msgs/DiagnosticArray";
+        const MD5SUM: &'static str = "60810da900de1dd6ddd437c3503511da";
+        const DEFINITION: &'static str = r####"# This message is used to send diagnostic information about the state of the robot
+Header header #for timestamp
+DiagnosticStatus[] status # an array of components being reported on
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+#
+# sequence ID: consecutively increasing ID
+uint32 seq
+#Two-integer timestamp that is expressed as:
+# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')
+# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')
+# time-handling sugar is provided by the client library
+time stamp
+#Frame this data is associated with
+string frame_id
+================================================================================
+MSG: diagnostic_msgs/DiagnosticStatus
+# This message holds the status of an individual component of the robot.
+#
+
+# Possible levels of operations
+byte OK=0
+byte WARN=1
+byte ERROR=2
+byte STALE=3
+
+byte level # level of operation enumerated above
+string name # a description of the test/component reporting
+string message # a description of the status
+string hardware_id # a hardware unique string
+KeyValue[] values # an array of values associated with the status
+================================================================================
+MSG: diagnostic_msgs/KeyValue
+string key # what to label this value when viewing
+string value # a value to track over time"####;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn header(caller_id: &str) -> ConnectionHeader {
+        ConnectionHeader {
+            caller_id: caller_id.to_owned(),
+            latching: false,
+            msg_definition: String::new(),
+            md5sum: Some("992ce8a1687cec8c8bd883ec73ca41d1".to_owned()),
+            service: None,
+            topic: Some("/chatter".to_owned()),
+            topic_type: "std_msgs/String".to_owned(),
+            tcp_nodelay: false,
+            persistent: None,
+            compression: None,
+        }
+    }
+
+    #[test_log::test]
+    fn lists_open_connections() {
+        let registry = ConnectionRegistry::default();
+        let inbound = registry.register(
+            Direction::Inbound,
+            "http://talker:1234/".to_owned(),
+            header("/listener"),
+            header("/talker"),
+        );
+        let outbound = registry.register(
+            Direction::Outbound,
+            "10.0.0.2:5678".to_owned(),
+            header("/listener"),
+            header("/echo"),
+        );
+        inbound.record(2, 20);
+        inbound.record(1, 5);
+
+        let connections = registry.connections();
+        assert_eq!(connections.len(), 2);
+        assert_eq!(connections[0].topic, "/chatter");
+        assert_eq!(connections[0].remote_header.caller_id, "/talker");
+        assert_eq!((connections[0].messages, connections[0].bytes), (3, 25));
+        assert_eq!(connections[1].direction, Direction::Outbound);
+
+        let diagnostics = diagnostics("/listener", &connections, SystemTime::now());
+        assert_eq!(diagnostics.status.len(), 2);
+        assert_eq!(
+            diagnostics.status[0].message,
+            "/chatter from http://talker:1234/"
+        );
+
+        // Closed connections are no longer listed
+        drop(outbound);
+        assert_eq!(registry.connections().len(), 1);
+        drop(inbound);
+        assert!(registry.connections().is_empty());
+    }
+}
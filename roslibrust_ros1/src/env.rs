@@ -0,0 +1,225 @@
+//! The configuration a node takes from ROS's environment variables, see [RosEnv].
+
+use roslibrust_common::names::Remappings;
+use std::net::Ipv4Addr;
+use std::path::PathBuf;
+
+/// The master uri used when ROS_MASTER_URI isn't set
+pub const DEFAULT_MASTER_URI: &str = "http://localhost:11311";
+
+/// Configures a node the way ROS's environment variables do, see <https://wiki.ros.org/ROS/EnvironmentVariables>.
+///
+/// [RosEnv::from_env] reads the variables, after which each setting can be overridden. [crate::NodeHandle::new]
+/// accepts a [RosEnv], or a master uri for which the other settings are read from the environment:
+/// ```no_run
+/// # async fn f() -> Result<(), roslibrust_ros1::NodeError> {
+/// use roslibrust_ros1::{NodeHandle, RosEnv};
+/// let nh = NodeHandle::new(RosEnv::from_env().namespace("/robot1"), "my_node").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RosEnv {
+    master_uri: String,
+    namespace: String,
+    ip: Option<String>,
+    hostname: Option<String>,
+    log_dir: Option<PathBuf>,
+    use_sim_time: bool,
+    remappings: Remappings,
+}
+
+impl Default for RosEnv {
+    fn default() -> Self {
+        Self {
+            master_uri: DEFAULT_MASTER_URI.to_owned(),
+            namespace: "/".to_owned(),
+            ip: None,
+            hostname: None,
+            log_dir: None,
+            use_sim_time: false,
+            remappings: Remappings::new(),
+        }
+    }
+}
+
+impl RosEnv {
+    /// The defaults ROS uses when none of its environment variables are set, without reading them.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads ROS_MASTER_URI, ROS_NAMESPACE, ROS_IP, ROS_HOSTNAME, and ROS_LOG_DIR falling back to ROS_HOME/log and
+    /// then ~/.ros/log. Variables which aren't set or are empty keep their defaults.
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let var = |name: &str| var(name).filter(|value| !value.is_empty());
+        let mut env = Self::default();
+        if let Some(master_uri) = var("ROS_MASTER_URI") {
+            env = env.master_uri(master_uri);
+        }
+        if let Some(namespace) = var("ROS_NAMESPACE") {
+            env = env.namespace(namespace);
+        }
+        env.ip = var("ROS_IP");
+        env.hostname = var("ROS_HOSTNAME");
+        env.log_dir = var("ROS_LOG_DIR")
+            .map(PathBuf::from)
+            .or_else(|| var("ROS_HOME").map(|home| PathBuf::from(home).join("log")))
+            .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".ros").join("log")));
+        env
+    }
+
+    /// The uri of the master, e.g. "http://my_host_name:11311"
+    pub fn master_uri(mut self, master_uri: impl Into<String>) -> Self {
+        self.master_uri = master_uri.into();
+        self
+    }
+
+    /// The namespace nodes with relative names are created in, e.g. "/robot1" making "my_node" "/robot1/my_node".
+    /// Relative topic and service names of the node are resolved in the namespace as well.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        let namespace = namespace.into();
+        self.namespace = format!("/{}", namespace.trim_matches('/'));
+        self
+    }
+
+    /// The address the node advertises itself at and listens on, taking precedence over [RosEnv::hostname].
+    pub fn ip(mut self, ip: Ipv4Addr) -> Self {
+        self.ip = Some(ip.to_string());
+        self
+    }
+
+    /// The name the node advertises itself at, listening on the address it resolves to. Without an ip or hostname
+    /// the name of the computer is used.
+    pub fn hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = Some(hostname.into());
+        self
+    }
+
+    /// The directory logs should be written to
+    pub fn log_dir(mut self, log_dir: impl Into<PathBuf>) -> Self {
+        self.log_dir = Some(log_dir.into());
+        self
+    }
+
+    /// Whether the node's components should default to following /clock rather than the wall clock, false unless
+    /// set. ROS reads this from the `/use_sim_time` parameter rather than the environment.
+    pub fn use_sim_time(mut self, use_sim_time: bool) -> Self {
+        self.use_sim_time = use_sim_time;
+        self
+    }
+
+    /// Remappings applied to the names of the node's topics and services, e.g. from the node's command line
+    /// arguments with [Remappings::from_args]. More can be added to a running node with [crate::NodeHandle::remap].
+    pub fn remappings(mut self, remappings: Remappings) -> Self {
+        self.remappings = remappings;
+        self
+    }
+
+    pub fn get_master_uri(&self) -> &str {
+        &self.master_uri
+    }
+
+    pub fn get_namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn get_ip(&self) -> Option<&str> {
+        self.ip.as_deref()
+    }
+
+    pub fn get_hostname(&self) -> Option<&str> {
+        self.hostname.as_deref()
+    }
+
+    /// None if none of ROS_LOG_DIR, ROS_HOME and HOME were set
+    pub fn get_log_dir(&self) -> Option<&std::path::Path> {
+        self.log_dir.as_deref()
+    }
+
+    pub fn get_use_sim_time(&self) -> bool {
+        self.use_sim_time
+    }
+
+    pub fn get_remappings(&self) -> &Remappings {
+        &self.remappings
+    }
+
+    /// Resolves the name of a node, placing relative names in the namespace
+    pub(crate) fn node_name(&self, name: &str) -> String {
+        match (name.starts_with('/'), self.namespace.as_str()) {
+            (true, _) => name.to_owned(),
+            (false, "/") => format!("/{name}"),
+            (false, namespace) => format!("{namespace}/{name}"),
+        }
+    }
+}
+
+/// Uses the master with this uri, reading the other settings from the environment
+impl From<&str> for RosEnv {
+    fn from(master_uri: &str) -> Self {
+        Self::from_env().master_uri(master_uri)
+    }
+}
+
+impl From<&String> for RosEnv {
+    fn from(master_uri: &String) -> Self {
+        master_uri.as_str().into()
+    }
+}
+
+impl From<String> for RosEnv {
+    fn from(master_uri: String) -> Self {
+        Self::from_env().master_uri(master_uri)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test_log::test]
+    fn reads_environment_variables() {
+        let vars = HashMap::from([
+            ("ROS_MASTER_URI", "http://robot:11311"),
+            ("ROS_NAMESPACE", "robot1/"),
+            ("ROS_IP", "10.0.0.2"),
+            ("ROS_HOSTNAME", ""),
+            ("ROS_HOME", "/var/ros"),
+            ("HOME", "/home/robot"),
+        ]);
+        let env = RosEnv::from_vars(|name| vars.get(name).map(|value| value.to_string()));
+        assert_eq!(env.get_master_uri(), "http://robot:11311");
+        assert_eq!(env.get_namespace(), "/robot1");
+        assert_eq!(env.get_ip(), Some("10.0.0.2"));
+        // Empty variables are treated as unset
+        assert_eq!(env.get_hostname(), None);
+        assert_eq!(env.get_log_dir(), Some("/var/ros/log".as_ref()));
+        assert!(!env.get_use_sim_time());
+
+        assert_eq!(env.node_name("talker"), "/robot1/talker");
+        assert_eq!(env.node_name("/talker"), "/talker");
+
+        let env = RosEnv::from_vars(|_| None);
+        assert_eq!(env, RosEnv::new());
+        assert_eq!(env.get_master_uri(), DEFAULT_MASTER_URI);
+        assert_eq!(env.node_name("talker"), "/talker");
+    }
+
+    #[test_log::test]
+    fn keeps_remappings() {
+        let remappings = Remappings::from_args(["prog", "chatter:=/robot1/chatter"]).unwrap();
+        let env = RosEnv::new().remappings(remappings);
+        assert_eq!(
+            env.get_remappings()
+                .resolve("chatter", Some("/talker"))
+                .unwrap(),
+            "/robot1/chatter"
+        );
+    }
+}
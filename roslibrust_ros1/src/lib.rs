@@ -31,16 +31,41 @@
 
 use roslibrust_common::Error;
 use roslibrust_common::{
-    Publish, RosMessageType, RosServiceType, Service, ServiceFn, ServiceProvider, Subscribe,
-    TopicProvider,
+    schema::MessageSchema, Capabilities, CapabilityProvider, ClockProvider, GraphProvider, Publish,
+    PublisherQos, RawPublish, RawSubscribe, RawTopicProvider, RosMessageType, RosServiceType,
+    Service, ServiceFn, ServiceInfo, ServiceProvider, Subscribe, TaskRegistry, ToServiceName,
+    ToTopicName, TopicInfo, TopicProvider,
 };
 
+/// [compression] module contains the compression of messages sent between roslibrust nodes
+pub mod compression;
+pub use compression::Compression;
+
+/// [connections] module contains the listing of a node's TCPROS connections
+pub mod connections;
+pub use connections::{ConnectionInfo, Direction};
+
+/// [env] module contains the configuration a node reads from ROS's environment variables
+pub mod env;
+pub use env::RosEnv;
+
 /// [master_client] module contains code for calling xmlrpc functions on the master
 mod master_client;
 pub use master_client::*;
 
+/// [master_cache] module contains the optional cache of master lookups
+pub mod master_cache;
+pub use master_cache::MasterCacheConfig;
+
 mod names;
 
+/// [masterless] module contains the experimental discovery of nodes without a master
+pub mod masterless;
+pub use masterless::MasterlessConfig;
+
+/// [mirror] module contains [mirror::NamespaceMirror] republishing a namespace under another prefix
+pub mod mirror;
+
 /// [node] module contains the central Node and NodeHandle APIs
 mod node;
 pub use node::*;
@@ -49,12 +74,23 @@ mod publisher;
 pub use publisher::Publisher;
 pub use publisher::PublisherAny;
 mod service_client;
-pub use service_client::ServiceClient;
+pub use service_client::{
+    ProviderPolicy, ServiceClient, ServiceClientAny, ServiceClientOptions, ServiceResponseReader,
+};
 mod subscriber;
-pub use subscriber::Subscriber;
+pub use subscriber::{DecodeErrorPolicy, LagPolicy, Subscriber, SubscriberAny};
 mod service_server;
 pub use service_server::ServiceServer;
+mod service_middleware;
+pub use service_middleware::{
+    RateLimit, RequestLogger, ServiceMiddleware, ServiceOptions, ServiceRequest, ValidateCaller,
+};
 mod tcpros;
+pub use tcpros::ConnectionHeader;
+
+/// [socket] module contains the socket options of publisher and subscriber connections
+pub mod socket;
+pub use socket::SocketOptions;
 
 /// Provides a common type alias for type erased service server functions.
 /// Internally we use this type to store collections of server functions.
@@ -63,6 +99,45 @@ pub(crate) type TypeErasedCallback = dyn Fn(Vec<u8>) -> Result<Vec<u8>, Box<dyn
     + Sync
     + 'static;
 
+/// Spawns a node's internal tasks on the runtime it was created with, listing them in its [TaskRegistry]
+#[derive(Clone, Debug)]
+pub(crate) struct Spawner {
+    pub(crate) registry: TaskRegistry,
+    runtime: tokio::runtime::Handle,
+}
+
+impl Spawner {
+    pub(crate) fn new(runtime: tokio::runtime::Handle) -> Self {
+        Self {
+            registry: TaskRegistry::default(),
+            runtime,
+        }
+    }
+
+    /// Spawns a task, listed and named for tokio-console under `name`
+    #[cfg(not(all(tokio_unstable, feature = "tokio-console")))]
+    pub(crate) fn spawn<F>(&self, name: String, future: F) -> tokio::task::JoinHandle<F::Output>
+    where
+        F: std::future::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.runtime.spawn(self.registry.track(name, future))
+    }
+
+    /// Spawns a task, listed and named for tokio-console under `name`
+    #[cfg(all(tokio_unstable, feature = "tokio-console"))]
+    pub(crate) fn spawn<F>(&self, name: String, future: F) -> tokio::task::JoinHandle<F::Output>
+    where
+        F: std::future::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        tokio::task::Builder::new()
+            .name(&name)
+            .spawn_on(self.registry.track(name.clone(), future), &self.runtime)
+            .expect("Failed to spawn task")
+    }
+}
+
 // Implement the generic roslibrust trait
 impl TopicProvider for crate::NodeHandle {
     type Publisher<T: RosMessageType> = crate::Publisher<T>;
@@ -70,20 +145,138 @@ impl TopicProvider for crate::NodeHandle {
 
     async fn advertise<T: RosMessageType>(
         &self,
-        topic: &str,
+        topic: impl ToTopicName + Send,
     ) -> roslibrust_common::Result<Self::Publisher<T>> {
+        let topic = topic.to_topic_name()?;
         // TODO MAJOR: consider promoting queue size, making unlimited default
-        self.advertise::<T>(topic.as_ref(), 10, false)
+        self.advertise::<T>(topic.as_str(), 10, false)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn advertise_with_qos<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+        qos: PublisherQos,
+    ) -> roslibrust_common::Result<Self::Publisher<T>> {
+        let topic = topic.to_topic_name()?;
+        self.advertise::<T>(topic.as_str(), 10, qos.latching)
             .await
             .map_err(|e| e.into())
     }
 
     async fn subscribe<T: RosMessageType>(
         &self,
-        topic: &str,
+        topic: impl ToTopicName + Send,
     ) -> roslibrust_common::Result<Self::Subscriber<T>> {
+        let topic = topic.to_topic_name()?;
         // TODO MAJOR: consider promoting queue size, making unlimited default
-        self.subscribe(topic, 10).await.map_err(|e| e.into())
+        self.subscribe(topic.as_str(), 10)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+impl RawSubscribe for crate::SubscriberAny {
+    async fn next_raw(&mut self) -> roslibrust_common::Result<Vec<u8>> {
+        match self.next().await {
+            // Messages arrive with their length in front, which MCAP's ros1 encoding leaves out
+            Some(Ok(mut data)) if data.len() >= 4 => {
+                data.drain(..4);
+                Ok(data)
+            }
+            Some(Ok(data)) => Err(Error::SerializationError(format!(
+                "Received a message of {} bytes, too short to hold its length",
+                data.len()
+            ))),
+            Some(Err(e)) => Err(Error::Unexpected(anyhow::anyhow!(
+                "Subscriber got error: {e:?}"
+            ))),
+            None => Err(Error::Unexpected(anyhow::anyhow!(
+                "Channel closed, something was dropped?"
+            ))),
+        }
+    }
+}
+
+impl RawPublish for crate::PublisherAny {
+    async fn publish_raw(&self, data: &[u8]) -> roslibrust_common::Result<()> {
+        // The wire format has the length of the message in front
+        let mut message = Vec::with_capacity(data.len() + 4);
+        message.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        message.extend_from_slice(data);
+        self.publish(&message)
+            .await
+            .map_err(|e| Error::SerializationError(e.to_string()))
+    }
+}
+
+// The type of a topic is taken from the connection header of its first reachable publisher
+impl RawTopicProvider for crate::NodeHandle {
+    type RawPublisher = crate::PublisherAny;
+    type RawSubscriber = crate::SubscriberAny;
+
+    const ENCODING: &'static str = "ros1";
+
+    async fn advertise_raw(
+        &self,
+        topic: impl ToTopicName + Send,
+        schema: &MessageSchema,
+    ) -> roslibrust_common::Result<Self::RawPublisher> {
+        let topic = topic.to_topic_name()?;
+        Ok(self
+            .advertise_any(
+                topic.as_str(),
+                &schema.type_name,
+                &schema.definition,
+                10,
+                false,
+            )
+            .await?)
+    }
+
+    async fn subscribe_raw(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> roslibrust_common::Result<(MessageSchema, Self::RawSubscriber)> {
+        let topic = topic.to_topic_name()?;
+        let Some((_, schema)) = self.topic_schemas(topic.as_str()).await?.into_iter().next() else {
+            return Err(Error::ServerError(format!(
+                "No reachable publisher of {topic} to learn its type from"
+            )));
+        };
+        let subscriber = self.subscribe_any(topic.as_str(), 10).await?;
+        Ok((schema, subscriber))
+    }
+}
+
+// Service types aren't known to the master, so they are left empty
+impl GraphProvider for crate::NodeHandle {
+    async fn topics(&self) -> roslibrust_common::Result<Vec<TopicInfo>> {
+        let master = self.master_client().await?;
+        let mut topics: Vec<_> = master
+            .get_topic_types()
+            .await
+            .map_err(NodeError::from)?
+            .into_iter()
+            .map(|(name, msg_type)| TopicInfo { name, msg_type })
+            .collect();
+        topics.sort();
+        Ok(topics)
+    }
+
+    async fn services(&self) -> roslibrust_common::Result<Vec<ServiceInfo>> {
+        let master = self.master_client().await?;
+        let state = master.get_system_state().await.map_err(NodeError::from)?;
+        let mut services: Vec<_> = state
+            .services()
+            .map(|name| ServiceInfo {
+                name: name.to_string(),
+                srv_type: String::new(),
+            })
+            .collect();
+        services.sort();
+        Ok(services)
     }
 }
 
@@ -99,36 +292,66 @@ impl ServiceProvider for crate::NodeHandle {
 
     async fn call_service<T: RosServiceType>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         request: T::Request,
     ) -> roslibrust_common::Result<T::Response> {
+        let topic = topic.to_service_name()?;
         // TODO should have a more optimized version of this...
-        let client = self.service_client::<T>(topic).await?;
+        let client = self.service_client::<T>(topic.as_str()).await?;
         client.call(&request).await
     }
 
     async fn service_client<T: RosServiceType + 'static>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
     ) -> roslibrust_common::Result<Self::ServiceClient<T>> {
+        let topic = topic.to_service_name()?;
         // TODO bad error mapping here...
-        self.service_client::<T>(topic).await.map_err(|e| e.into())
+        self.service_client::<T>(topic.as_str())
+            .await
+            .map_err(|e| e.into())
     }
 
     async fn advertise_service<T: RosServiceType + 'static, F>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         server: F,
     ) -> roslibrust_common::Result<Self::ServiceServer>
     where
         F: ServiceFn<T>,
     {
-        self.advertise_service::<T, F>(topic, server)
+        let topic = topic.to_service_name()?;
+        self.advertise_service::<T, F>(topic.as_str(), server)
             .await
             .map_err(|e| e.into())
     }
 }
 
+// Without a master the node can't query the graph
+impl CapabilityProvider for crate::NodeHandle {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            latching: true,
+            raw_topics: true,
+            service_clients: true,
+            service_servers: true,
+            graph: !self.masterless,
+            ..Default::default()
+        }
+    }
+}
+
+// The ros1 backend does not yet support simulated time, ROS time is wall clock time
+impl ClockProvider for crate::NodeHandle {
+    fn now(&self) -> std::time::SystemTime {
+        std::time::SystemTime::now()
+    }
+
+    async fn sleep(&self, duration: std::time::Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
+
 impl<T: RosMessageType> Subscribe<T> for crate::Subscriber<T> {
     async fn next(&mut self) -> roslibrust_common::Result<T> {
         let res = crate::Subscriber::next(self).await;
@@ -0,0 +1,169 @@
+//! A cache in front of the rosmaster's API, see [crate::NodeHandle::new_with_master_cache].
+//!
+//! Each service client a node creates looks up its service with the master, so applications creating many
+//! short-lived service clients, or calling `ServiceProvider::call_service` in a loop, make a master round trip for
+//! every call. With the cache the uri of a service is looked up once per [MasterCacheConfig::lookup_ttl] and shared
+//! by every client of the node. A cached uri is forgotten as soon as connecting to it fails, so a restarted service
+//! provider is found again on the next call.
+//!
+//! Subscriptions don't need caching, a node registers each topic with the master once however many subscribers it
+//! creates for it, and learns of new publishers through the master's `publisherUpdate` calls.
+
+use crate::{MasterClient, RosMasterError};
+use log::*;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Configures the cache of [crate::NodeHandle::new_with_master_cache].
+#[derive(Clone, Debug)]
+pub struct MasterCacheConfig {
+    lookup_ttl: Duration,
+}
+
+impl Default for MasterCacheConfig {
+    fn default() -> Self {
+        Self {
+            lookup_ttl: Duration::from_secs(5),
+        }
+    }
+}
+
+impl MasterCacheConfig {
+    /// Keeps looked up services for 5 seconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the uri of a looked up service for `ttl` before asking the master again.
+    pub fn lookup_ttl(mut self, ttl: Duration) -> Self {
+        self.lookup_ttl = ttl;
+        self
+    }
+}
+
+/// Values which expire a fixed time after they were inserted
+struct TtlMap {
+    ttl: Duration,
+    entries: HashMap<String, (String, Instant)>,
+}
+
+impl TtlMap {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, key: &str, now: Instant) -> Option<String> {
+        match self.entries.get(key) {
+            Some((value, inserted)) if now.duration_since(*inserted) < self.ttl => {
+                Some(value.clone())
+            }
+            Some(_) => {
+                self.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&mut self, key: String, value: String, now: Instant) {
+        self.entries.insert(key, (value, now));
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.entries.remove(key);
+    }
+}
+
+/// A [MasterClient] answering repeated service lookups from a cache
+#[derive(Clone)]
+pub(crate) struct MasterCache {
+    client: MasterClient,
+    services: Arc<Mutex<TtlMap>>,
+}
+
+impl MasterCache {
+    pub(crate) fn new(client: MasterClient, config: MasterCacheConfig) -> Self {
+        Self {
+            client,
+            services: Arc::new(Mutex::new(TtlMap::new(config.lookup_ttl))),
+        }
+    }
+
+    pub(crate) fn client(&self) -> &MasterClient {
+        &self.client
+    }
+
+    fn services(&self) -> MutexGuard<'_, TtlMap> {
+        self.services.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) async fn lookup_service(&self, service: &str) -> Result<String, RosMasterError> {
+        if let Some(uri) = self.services().get(service, Instant::now()) {
+            trace!("Found {service} at {uri} in the master cache");
+            return Ok(uri);
+        }
+        let uri = self.client.lookup_service(service).await?;
+        self.services()
+            .insert(service.to_owned(), uri.clone(), Instant::now());
+        Ok(uri)
+    }
+
+    /// Drops the cached uri of `service`, e.g. after failing to connect to it
+    pub(crate) fn forget_service(&self, service: &str) {
+        self.services().remove(service);
+    }
+
+    pub(crate) async fn register_service(
+        &self,
+        service: String,
+        service_uri: String,
+    ) -> Result<(), RosMasterError> {
+        self.client
+            .register_service(service.clone(), service_uri.clone())
+            .await?;
+        // Our own services resolve to us without asking the master
+        self.services().insert(service, service_uri, Instant::now());
+        Ok(())
+    }
+
+    pub(crate) async fn unregister_service(
+        &self,
+        service: &str,
+        service_uri: String,
+    ) -> Result<bool, RosMasterError> {
+        self.forget_service(service);
+        self.client.unregister_service(service, service_uri).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test_log::test]
+    fn entries_expire_after_ttl() {
+        let start = Instant::now();
+        let mut map = TtlMap::new(Duration::from_secs(5));
+        map.insert("/add_two_ints".to_owned(), "rosrpc://a:1".to_owned(), start);
+        assert_eq!(
+            map.get("/add_two_ints", start + Duration::from_secs(4)),
+            Some("rosrpc://a:1".to_owned())
+        );
+        assert_eq!(
+            map.get("/add_two_ints", start + Duration::from_secs(5)),
+            None
+        );
+        // Expired entries are dropped once seen
+        assert!(map.entries.is_empty());
+
+        map.insert("/reset".to_owned(), "rosrpc://b:2".to_owned(), start);
+        map.remove("/reset");
+        assert_eq!(map.get("/reset", start), None);
+    }
+}
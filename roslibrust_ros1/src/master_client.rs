@@ -16,6 +16,8 @@ pub enum RosMasterError {
     HostIoError(#[from] hyper::Error),
     #[error("Failed to determine a valid ip address on which to host the nodes xmlrpc server, check that one of ROS_IP, ROS_HOSTNAME or the computer's hostname resolve to a valid Ipv4 address {0}")]
     HostIpResolutionFailure(String),
+    #[error("No node announced the service {0}, nodes in masterless mode can only find services announced by other masterless nodes")]
+    NotAnnounced(String),
 }
 
 /// A client that exposes the API hosted by the [rosmaster](http://wiki.ros.org/ROS/Master_API)
@@ -48,6 +50,23 @@ pub struct SystemState {
 }
 
 impl SystemState {
+    /// Returns the names of the nodes registered as publishers of a given topic.
+    pub fn publishers_of(&self, topic: &str) -> &[String] {
+        self.publishers
+            .iter()
+            .find(|entry| entry.topic.eq(topic))
+            .map(|entry| entry.nodes.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the names of all services with a provider.
+    pub fn services(&self) -> impl Iterator<Item = &str> {
+        self.service_providers
+            .iter()
+            .filter(|entry| !entry.nodes.is_empty())
+            .map(|entry| entry.topic.as_str())
+    }
+
     /// Helper function for checking if a node is registered as a publisher of a given topic.
     /// Returns true iff the node is a publisher of that topic
     pub fn is_publishing(&self, topic: &str, node: &str) -> bool {
@@ -0,0 +1,430 @@
+//! An experimental mode in which nodes find each other without a rosmaster, see [crate::NodeHandle::new_masterless].
+//!
+//! Every node periodically multicasts an announcement of its XML-RPC uri, its publications and its services over UDP.
+//! Subscribers connect to the publishers announced for their topics over TCPROS as they would after asking a master,
+//! so publishers, subscribers and services work unchanged. Masterless nodes don't see nodes using a master and vice
+//! versa, and the parameter server and graph queries, which are provided by the master, aren't available.
+
+use crate::{node::actor::NodeServerHandle, RosMasterError, Spawner};
+use abort_on_drop::ChildTask;
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    io,
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+use tokio::net::UdpSocket;
+
+/// Prefixes every announcement, so other traffic on the group is ignored
+const MAGIC: &[u8] = b"roslibrust-masterless-1";
+
+/// Configures how masterless nodes find each other, see [crate::NodeHandle::new_masterless].
+///
+/// Nodes only see each other when they use the same multicast group.
+#[derive(Clone, Debug)]
+pub struct MasterlessConfig {
+    group: SocketAddrV4,
+    interface: Ipv4Addr,
+    interval: Duration,
+}
+
+impl Default for MasterlessConfig {
+    fn default() -> Self {
+        Self {
+            group: SocketAddrV4::new(Ipv4Addr::new(239, 255, 11, 31), 11411),
+            interface: Ipv4Addr::UNSPECIFIED,
+            interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl MasterlessConfig {
+    /// Announces on 239.255.11.31:11411 every second, on the default multicast interface.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Announces to and listens on the multicast group `group`, e.g. to separate robots sharing a network.
+    pub fn group(mut self, group: SocketAddrV4) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Joins the multicast group on the interface with the address `interface`, e.g. the one matching ROS_IP.
+    pub fn interface(mut self, interface: Ipv4Addr) -> Self {
+        self.interface = interface;
+        self
+    }
+
+    /// Announces every `interval` instead. New publishers are announced immediately, the interval bounds how long a
+    /// node joining late takes to find them. Nodes not heard from for three intervals are forgotten.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+struct Entry {
+    name: String,
+    // The type of a publication, the uri of a service
+    value: String,
+}
+
+/// What a node announces about itself
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+struct Announcement {
+    node: String,
+    uri: String,
+    publications: Vec<Entry>,
+    services: Vec<Entry>,
+}
+
+impl Announcement {
+    fn encode(&self) -> io::Result<Vec<u8>> {
+        let body = roslibrust_serde_rosmsg::to_vec(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        Ok([MAGIC, &body].concat())
+    }
+
+    fn decode(datagram: &[u8]) -> Option<Self> {
+        let body = datagram.strip_prefix(MAGIC)?;
+        roslibrust_serde_rosmsg::from_slice(body).ok()
+    }
+
+    fn publishes(&self, topic: &str) -> bool {
+        self.publications.iter().any(|entry| entry.name == topic)
+    }
+}
+
+#[derive(Default)]
+struct Local {
+    publications: BTreeMap<String, String>,
+    services: BTreeMap<String, String>,
+    subscriptions: BTreeSet<String>,
+}
+
+struct Peer {
+    last_seen: Instant,
+    announcement: Announcement,
+}
+
+struct State {
+    node: String,
+    uri: String,
+    group: SocketAddrV4,
+    timeout: Duration,
+    socket: UdpSocket,
+    local: Mutex<Local>,
+    peers: Mutex<HashMap<String, Peer>>,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl State {
+    async fn announce(&self) {
+        let announcement = {
+            let local = lock(&self.local);
+            let entries = |map: &BTreeMap<String, String>| {
+                map.iter()
+                    .map(|(name, value)| Entry {
+                        name: name.clone(),
+                        value: value.clone(),
+                    })
+                    .collect()
+            };
+            Announcement {
+                node: self.node.clone(),
+                uri: self.uri.clone(),
+                publications: entries(&local.publications),
+                services: entries(&local.services),
+            }
+        };
+        let result = match announcement.encode() {
+            Ok(datagram) => self.socket.send_to(&datagram, self.group).await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            warn!(
+                "Failed to announce node {} to {}: {e}",
+                self.node, self.group
+            );
+        }
+    }
+
+    /// The announcements of the nodes heard from recently
+    fn peers(&self) -> Vec<Announcement> {
+        let mut peers = lock(&self.peers);
+        peers.retain(|_, peer| peer.last_seen.elapsed() < self.timeout);
+        peers
+            .values()
+            .map(|peer| peer.announcement.clone())
+            .collect()
+    }
+
+    fn receive(&self, announcement: Announcement, from: SocketAddr, node: &NodeServerHandle) {
+        trace!("Got announcement from {} at {from}", announcement.node);
+        let subscribed: Vec<_> = {
+            let local = lock(&self.local);
+            announcement
+                .publications
+                .iter()
+                .filter(|entry| local.subscriptions.contains(&entry.name))
+                .map(|entry| entry.name.clone())
+                .collect()
+        };
+        // Passed on with every announcement rather than only new ones, in case the subscription was still being
+        // created, the node ignores publishers it is already connected to
+        for topic in subscribed {
+            if node
+                .set_peer_publishers(topic, vec![announcement.uri.clone()])
+                .is_err()
+            {
+                return;
+            }
+        }
+        lock(&self.peers).insert(
+            announcement.node.clone(),
+            Peer {
+                last_seen: Instant::now(),
+                announcement,
+            },
+        );
+    }
+}
+
+/// Announces a node and listens to the announcements of others, taking the place of the master
+#[derive(Clone)]
+pub(crate) struct Discovery {
+    state: Arc<State>,
+    interval: Duration,
+    _tasks: Arc<[ChildTask<()>; 2]>,
+}
+
+impl Discovery {
+    /// Starts announcing `node_name` reachable at `client_uri`, passing the publishers announced for its topics to
+    /// `node`. Must be called within the runtime of `spawner`.
+    pub(crate) fn new(
+        config: MasterlessConfig,
+        node_name: &str,
+        client_uri: &str,
+        node: NodeServerHandle,
+        spawner: &Spawner,
+    ) -> io::Result<Self> {
+        let socket = Self::bind(&config)?;
+        let state = Arc::new(State {
+            node: node_name.to_owned(),
+            uri: client_uri.to_owned(),
+            group: config.group,
+            timeout: config.interval * 3,
+            socket,
+            local: Mutex::default(),
+            peers: Mutex::default(),
+        });
+
+        let announcer = spawner.spawn(format!("ros1 masterless announcer {node_name}"), {
+            let state = state.clone();
+            let interval = config.interval;
+            async move {
+                let mut ticks = tokio::time::interval(interval);
+                loop {
+                    ticks.tick().await;
+                    state.announce().await;
+                }
+            }
+        });
+        let listener = spawner.spawn(format!("ros1 masterless listener {}", config.group), {
+            let state = state.clone();
+            async move {
+                let mut buf = vec![0; u16::MAX as usize];
+                loop {
+                    match state.socket.recv_from(&mut buf).await {
+                        Ok((len, from)) => match Announcement::decode(&buf[..len]) {
+                            Some(announcement) => state.receive(announcement, from, &node),
+                            None => {
+                                debug!("Ignoring datagram from {from} which isn't an announcement")
+                            }
+                        },
+                        Err(e) => {
+                            warn!("Failed to receive announcements on {}: {e}", state.group);
+                            tokio::time::sleep(Duration::from_millis(100)).await;
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            state,
+            interval: config.interval,
+            _tasks: Arc::new([announcer.into(), listener.into()]),
+        })
+    }
+
+    /// Binds a socket to the group's port, shared with the other nodes on this host
+    fn bind(config: &MasterlessConfig) -> io::Result<UdpSocket> {
+        use socket2::{Domain, Protocol, Socket, Type};
+        let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+        socket.set_reuse_address(true)?;
+        #[cfg(unix)]
+        socket.set_reuse_port(true)?;
+        socket.bind(&SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, config.group.port()).into())?;
+        socket.join_multicast_v4(config.group.ip(), &config.interface)?;
+        if !config.interface.is_unspecified() {
+            socket.set_multicast_if_v4(&config.interface)?;
+        }
+        // Nodes on this host hear each other through the loopback
+        socket.set_multicast_loop_v4(true)?;
+        socket.set_nonblocking(true)?;
+        UdpSocket::from_std(socket.into())
+    }
+
+    pub(crate) fn client_uri(&self) -> &str {
+        &self.state.uri
+    }
+
+    /// Returns the uris of the publishers of `topic` announced so far
+    pub(crate) fn register_subscriber(&self, topic: &str) -> Vec<String> {
+        lock(&self.state.local)
+            .subscriptions
+            .insert(topic.to_owned());
+        self.state
+            .peers()
+            .into_iter()
+            .filter(|peer| peer.publishes(topic))
+            .map(|peer| peer.uri)
+            .collect()
+    }
+
+    pub(crate) fn unregister_subscriber(&self, topic: &str) -> bool {
+        lock(&self.state.local).subscriptions.remove(topic)
+    }
+
+    pub(crate) async fn register_publisher(&self, topic: &str, topic_type: &str) {
+        lock(&self.state.local)
+            .publications
+            .insert(topic.to_owned(), topic_type.to_owned());
+        self.state.announce().await;
+    }
+
+    pub(crate) async fn unregister_publisher(&self, topic: &str) -> bool {
+        let removed = lock(&self.state.local).publications.remove(topic).is_some();
+        self.state.announce().await;
+        removed
+    }
+
+    pub(crate) async fn register_service(&self, service: String, service_uri: String) {
+        lock(&self.state.local)
+            .services
+            .insert(service, service_uri);
+        self.state.announce().await;
+    }
+
+    pub(crate) async fn unregister_service(&self, service: &str) -> bool {
+        let removed = lock(&self.state.local).services.remove(service).is_some();
+        self.state.announce().await;
+        removed
+    }
+
+    /// Returns the uri of the service, waiting up to one interval for it to be announced
+    pub(crate) async fn lookup_service(&self, service: &str) -> Result<String, RosMasterError> {
+        let mut uris = self.lookup_service_providers(service).await?;
+        Ok(uris.swap_remove(0))
+    }
+
+    /// Returns the uris of every node announcing the service, waiting up to one interval for one to be announced
+    pub(crate) async fn lookup_service_providers(
+        &self,
+        service: &str,
+    ) -> Result<Vec<String>, RosMasterError> {
+        let find = || -> Vec<String> {
+            self.state
+                .peers()
+                .into_iter()
+                .flat_map(|peer| peer.services)
+                .filter(|entry| entry.name == service)
+                .map(|entry| entry.value)
+                .collect()
+        };
+        let uris = find();
+        if !uris.is_empty() {
+            return Ok(uris);
+        }
+        tokio::time::sleep(self.interval).await;
+        let uris = find();
+        if uris.is_empty() {
+            return Err(RosMasterError::NotAnnounced(service.to_owned()));
+        }
+        Ok(uris)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test_log::test]
+    fn announcements_round_trip() {
+        let announcement = Announcement {
+            node: "/talker".to_owned(),
+            uri: "http://robot:34567/".to_owned(),
+            publications: vec![Entry {
+                name: "/chatter".to_owned(),
+                value: "std_msgs/String".to_owned(),
+            }],
+            services: vec![],
+        };
+        let datagram = announcement.encode().unwrap();
+        assert_eq!(Announcement::decode(&datagram), Some(announcement));
+        assert_eq!(Announcement::decode(b"M-SEARCH * HTTP/1.1"), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn nodes_find_each_other() {
+        use crate::NodeHandle;
+        use roslibrust_test::ros1::std_msgs;
+
+        let config = MasterlessConfig::new()
+            .group(SocketAddrV4::new(Ipv4Addr::new(239, 255, 11, 32), 11412))
+            .interval(Duration::from_millis(100));
+        let talker = NodeHandle::new_masterless("/masterless_talker", config.clone())
+            .await
+            .unwrap();
+        let listener = NodeHandle::new_masterless("/masterless_listener", config)
+            .await
+            .unwrap();
+
+        let publisher = talker
+            .advertise::<std_msgs::String>("/masterless_chatter", 1, false)
+            .await
+            .unwrap();
+        let mut subscriber = listener
+            .subscribe::<std_msgs::String>("/masterless_chatter", 1)
+            .await
+            .unwrap();
+
+        let received = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                publisher
+                    .publish(&std_msgs::String {
+                        data: "hello".to_owned(),
+                    })
+                    .await
+                    .unwrap();
+                if let Ok(Some(msg)) =
+                    tokio::time::timeout(Duration::from_millis(100), subscriber.next()).await
+                {
+                    break msg.unwrap();
+                }
+            }
+        })
+        .await
+        .expect("Listener never found the talker");
+        assert_eq!(received.data, "hello");
+    }
+}
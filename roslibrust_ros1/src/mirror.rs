@@ -0,0 +1,304 @@
+//! Republishes every topic in a namespace under another prefix, e.g. to aggregate the topics of several robots
+//! on one master, see [NamespaceMirror].
+//!
+//! ```no_run
+//! use roslibrust_ros1::{mirror::NamespaceMirror, NodeHandle};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let robot = NodeHandle::new("http://robot1:11311", "/fleet_mirror").await?;
+//!     let base = NodeHandle::new("http://localhost:11311", "/fleet_mirror").await?;
+//!     // /robot1/odom on the robot's master is published as /fleet/robot1/odom on the base station's
+//!     let mirror = NamespaceMirror::new("/robot1")
+//!         .to("/fleet/robot1")
+//!         .start(&robot, &base)
+//!         .await?;
+//!     tokio::signal::ctrl_c().await?;
+//!     log::info!("Mirrored {:?}", mirror.topics());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    names::{InvalidNameError, Name},
+    MasterClient, NodeError, NodeHandle,
+};
+use abort_on_drop::ChildTask;
+use log::*;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+/// Mirrors a namespace of one node's master onto another prefix, on the same master or a different one.
+///
+/// Topics are forwarded as raw bytes with [NodeHandle::subscribe_any] and [NodeHandle::advertise_any], so no message
+/// types need to be compiled in. Each topic is advertised with the type and definition its publishers send in their
+/// connection headers. The master is polled for the topics in the namespace, so topics appearing later are mirrored
+/// within one [NamespaceMirror::poll_interval], and topics which are no longer published are unadvertised.
+pub struct NamespaceMirror {
+    namespace: String,
+    prefix: String,
+    poll_interval: Duration,
+    queue_size: usize,
+    exclude: Vec<String>,
+}
+
+impl NamespaceMirror {
+    /// Mirrors the topics beneath `namespace`, e.g. `/robot1` for `/robot1/odom` and `/robot1/camera/image`.
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            prefix: String::new(),
+            poll_interval: Duration::from_secs(1),
+            queue_size: 10,
+            exclude: vec![],
+        }
+    }
+
+    /// Replaces `namespace` with `prefix` in the names of the mirrored topics. By default the topics keep their names,
+    /// which requires mirroring onto a different master.
+    pub fn to(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Checks the master for new topics every `interval` instead of every second.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Queues up to `queue_size` messages per topic instead of 10, see [NodeHandle::subscribe].
+    pub fn queue_size(mut self, queue_size: usize) -> Self {
+        self.queue_size = queue_size;
+        self
+    }
+
+    /// Doesn't mirror `topic`, given by its full name within the namespace, e.g. a high bandwidth camera topic.
+    pub fn exclude(mut self, topic: impl Into<String>) -> Self {
+        self.exclude.push(topic.into());
+        self
+    }
+
+    /// Starts mirroring the namespace from the master of `from` onto the master of `to`, which may be the same node.
+    ///
+    /// Mirroring onto the same master requires a prefix outside of the namespace, so the mirrored topics aren't
+    /// mirrored again.
+    pub async fn start(
+        self,
+        from: &NodeHandle,
+        to: &NodeHandle,
+    ) -> Result<MirrorHandle, NodeError> {
+        let namespace = Name::new(self.namespace.trim_end_matches('/'))?.to_string();
+        let prefix = match self.prefix.trim_end_matches('/') {
+            "" => namespace.clone(),
+            prefix => Name::new(prefix)?.to_string(),
+        };
+        let master_uri = from.inner.get_master_uri().await?;
+        if master_uri == to.inner.get_master_uri().await? && within(&prefix, &namespace) {
+            return Err(NodeError::InvalidName(InvalidNameError(format!(
+                "Can't mirror {namespace} onto {prefix} on the same master, use NamespaceMirror::to for a prefix outside of it"
+            ))));
+        }
+        let master = MasterClient::new(
+            master_uri,
+            from.inner.get_client_uri().await?,
+            from.name.to_string(),
+        )
+        .await?;
+
+        let mirrored = Mirrored::default();
+        let state = Mirror {
+            namespace,
+            prefix,
+            queue_size: self.queue_size,
+            exclude: self.exclude,
+            master,
+            from: from.clone(),
+            to: to.clone(),
+            mirrored: mirrored.clone(),
+        };
+        let task_name = format!("ros1 mirror {} to {}", state.namespace, state.prefix);
+        let interval = self.poll_interval;
+        let task = from.inner.spawner.spawn(task_name, async move {
+            let mut ticks = tokio::time::interval(interval);
+            ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut forwards = BTreeMap::new();
+            loop {
+                ticks.tick().await;
+                state.update(&mut forwards).await;
+            }
+        });
+        Ok(MirrorHandle {
+            mirrored,
+            _task: task.into(),
+        })
+    }
+}
+
+/// True if `name` is `namespace` or beneath it
+fn within(name: &str, namespace: &str) -> bool {
+    name.strip_prefix(namespace)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// The name `topic` in `namespace` is mirrored as beneath `prefix`
+fn rename(topic: &str, namespace: &str, prefix: &str) -> String {
+    format!("{prefix}{}", &topic[namespace.len()..])
+}
+
+/// The topics being mirrored, mapped to the names they are mirrored as
+type Mirrored = Arc<Mutex<BTreeMap<String, String>>>;
+
+struct Mirror {
+    namespace: String,
+    prefix: String,
+    queue_size: usize,
+    exclude: Vec<String>,
+    master: MasterClient,
+    from: NodeHandle,
+    to: NodeHandle,
+    mirrored: Mirrored,
+}
+
+impl Mirror {
+    /// Starts forwarding the topics which appeared since the last update and stops forwarding those which vanished
+    async fn update(&self, forwards: &mut BTreeMap<String, ChildTask<()>>) {
+        let published = match self
+            .master
+            .get_published_topics(self.namespace.as_str())
+            .await
+        {
+            Ok(published) => published,
+            Err(e) => {
+                warn!(
+                    "Failed to list the topics in {} to mirror: {e}",
+                    self.namespace
+                );
+                return;
+            }
+        };
+        let topics: Vec<String> = published
+            .into_iter()
+            .map(|(topic, _)| topic)
+            .filter(|topic| within(topic, &self.namespace) && !self.exclude.contains(topic))
+            .collect();
+
+        forwards.retain(|topic, _| {
+            let keep = topics.contains(topic);
+            if !keep {
+                debug!("Topic {topic} is no longer published, no longer mirroring it");
+            }
+            keep
+        });
+        for topic in topics {
+            if forwards.contains_key(&topic) {
+                continue;
+            }
+            match self.forward(&topic).await {
+                Ok(task) => {
+                    forwards.insert(topic, task);
+                }
+                // Tried again with the next update
+                Err(e) => warn!("Failed to mirror topic {topic}: {e}"),
+            }
+        }
+        *self.mirrored.lock().unwrap_or_else(PoisonError::into_inner) = forwards
+            .keys()
+            .map(|topic| {
+                let target = rename(topic, &self.namespace, &self.prefix);
+                (topic.clone(), target)
+            })
+            .collect();
+    }
+
+    async fn forward(&self, topic: &str) -> Result<ChildTask<()>, NodeError> {
+        // The definition isn't listed by the master, only sent by the publishers
+        let schema = self
+            .from
+            .topic_schemas(topic)
+            .await?
+            .into_iter()
+            .map(|(_, schema)| schema)
+            .find(|schema| !schema.definition.is_empty())
+            .ok_or_else(|| {
+                NodeError::IoError(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "no publisher shared its message definition",
+                ))
+            })?;
+        let target = rename(topic, &self.namespace, &self.prefix);
+        let mut subscriber = self.from.subscribe_any(topic, self.queue_size).await?;
+        let publisher = self
+            .to
+            .advertise_any(
+                &target,
+                &schema.type_name,
+                &schema.definition,
+                self.queue_size,
+                false,
+            )
+            .await?;
+        debug!("Mirroring {topic} of type {} to {target}", schema.type_name);
+
+        let topic = topic.to_owned();
+        let task =
+            self.from
+                .inner
+                .spawner
+                .spawn(format!("ros1 mirror topic {topic}"), async move {
+                    while let Some(msg) = subscriber.next().await {
+                        let msg = match msg {
+                            Ok(msg) => msg,
+                            Err(e) => {
+                                warn!("Failed to receive message on mirrored topic {topic}: {e}");
+                                continue;
+                            }
+                        };
+                        if let Err(e) = publisher.publish(&msg).await {
+                            warn!(
+                            "Failed to publish message of mirrored topic {topic} on {target}: {e}"
+                        );
+                        }
+                    }
+                });
+        Ok(task.into())
+    }
+}
+
+/// A running [NamespaceMirror], the mirrored topics are unadvertised when it is dropped.
+pub struct MirrorHandle {
+    mirrored: Mirrored,
+    _task: ChildTask<()>,
+}
+
+impl MirrorHandle {
+    /// The topics currently mirrored, as pairs of their name in the namespace and the name they are mirrored as.
+    pub fn topics(&self) -> Vec<(String, String)> {
+        self.mirrored
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(topic, target)| (topic.clone(), target.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test_log::test]
+    fn renames_topics_within_namespace() {
+        assert!(within("/robot1/odom", "/robot1"));
+        assert!(within("/robot1", "/robot1"));
+        assert!(!within("/robot10/odom", "/robot1"));
+        assert!(!within("/fleet/robot1/odom", "/robot1"));
+        assert_eq!(
+            rename("/robot1/camera/image", "/robot1", "/fleet/robot1"),
+            "/fleet/robot1/camera/image"
+        );
+    }
+}
@@ -1,10 +1,8 @@
+use roslibrust_common::TopicName;
 use std::fmt::Display;
 
-lazy_static::lazy_static! {
-    // See: https://wiki.ros.org/Names
-    static ref GRAPH_NAME_REGEX: regex::Regex = regex::Regex::new(r"^([/~a-zA-Z]){1}([a-zA-Z0-9_/])*([A-z0-9_])$").unwrap();
-}
-
+// Validation and resolution rules are shared with the rest of roslibrust via roslibrust_common
+// See: https://wiki.ros.org/Names
 #[derive(Clone, Debug, PartialEq)]
 pub struct Name {
     inner: String,
@@ -21,36 +19,17 @@ impl Name {
     }
 
     pub fn resolve_to_global(&self, node_name: &Name) -> Self {
-        if self.inner.starts_with('/') {
-            self.clone()
-        } else if self.inner.starts_with('~') {
-            Name {
-                inner: format!("{}/{}", node_name.inner, &self.inner[1..]),
-            }
-        } else {
-            let components = node_name.inner.split("/").collect::<Vec<_>>();
-            match components.len() {
-                0..=1 => unreachable!("Node name {} must have at least one /", node_name.inner),
-                2 => Name {
-                    inner: format!("/{}", self.inner),
-                },
-                len => Name {
-                    inner: format!(
-                        "{}/{}",
-                        components[1..len - 1].into_iter().fold(
-                            String::new(),
-                            |mut name, component| {
-                                name.push('/');
-                                name.push_str(component);
-                                name
-                            },
-                        ),
-                        self.inner
-                    ),
-                },
-            }
+        let resolved = TopicName::new(self.inner.as_str())
+            .and_then(|name| name.resolve_to_global(&node_name.inner))
+            .expect("Resolving a valid name against a valid node name produces a valid name");
+        Name {
+            inner: resolved.into(),
         }
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
 }
 
 impl Display for Name {
@@ -60,7 +39,7 @@ impl Display for Name {
 }
 
 fn is_valid(name: &str) -> bool {
-    GRAPH_NAME_REGEX.is_match(name)
+    TopicName::new(name).is_ok()
 }
 
 #[cfg(test)]
@@ -129,4 +108,4 @@ mod tests {
 
 #[derive(thiserror::Error, Debug)]
 #[error("Invalid Name: {0}")]
-pub struct InvalidNameError(String);
+pub struct InvalidNameError(pub(crate) String);
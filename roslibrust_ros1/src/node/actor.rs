@@ -1,15 +1,23 @@
 use crate::{
+    connections::ConnectionRegistry,
+    master_cache::MasterCache,
+    masterless::Discovery,
     names::Name,
-    node::{XmlRpcServer, XmlRpcServerHandle},
-    publisher::Publication,
-    service_client::ServiceClientLink,
-    service_server::ServiceServerLink,
+    node::{Registry, RegistryConfig, XmlRpcServer, XmlRpcServerHandle},
+    publisher::{Outbox, Publication},
+    service_client::{ProviderPolicy, ServiceClientLink, ServiceClientOptions},
+    service_server::{ServiceHandler, ServiceServerLink},
+    socket::SocketOptions,
     subscriber::Subscription,
-    MasterClient, NodeError, ProtocolParams, ServiceClient, TypeErasedCallback,
+    tcpros, MasterClient, NodeError, ProtocolParams, ServiceClient, ServiceClientAny,
+    ServiceOptions, Spawner, TypeErasedCallback,
 };
 use abort_on_drop::ChildTask;
 use log::*;
-use roslibrust_common::{Error, RosMessageType, RosServiceType, ServiceFn};
+use roslibrust_common::{
+    definitions::ServiceDefinition, ChannelMetrics, Error, RosMessageType, RosServiceType,
+    ServiceFn,
+};
 use std::{collections::HashMap, io, net::Ipv4Addr, sync::Arc};
 use tokio::sync::{broadcast, mpsc, oneshot};
 
@@ -41,13 +49,14 @@ pub enum NodeMsg {
     // This results in the node's task ending and the node being dropped.
     Shutdown,
     RegisterPublisher {
-        reply: oneshot::Sender<Result<(broadcast::Sender<Vec<u8>>, mpsc::Sender<()>), String>>,
+        reply: oneshot::Sender<Result<(Outbox, mpsc::Sender<()>), String>>,
         topic: String,
         topic_type: String,
         queue_size: usize,
         msg_definition: String,
         md5sum: String,
         latching: bool,
+        socket: SocketOptions,
     },
     RegisterSubscriber {
         reply: oneshot::Sender<Result<broadcast::Receiver<Vec<u8>>, String>>,
@@ -56,6 +65,7 @@ pub enum NodeMsg {
         queue_size: usize,
         msg_definition: String,
         md5sum: String,
+        socket: SocketOptions,
     },
     RegisterServiceClient {
         reply: oneshot::Sender<Result<ServiceClientLink, String>>,
@@ -63,13 +73,14 @@ pub enum NodeMsg {
         service_type: String,
         srv_definition: String,
         md5sum: String,
+        options: ServiceClientOptions,
     },
     RegisterServiceServer {
         reply: oneshot::Sender<Result<(), String>>,
         service: Name,
         service_type: String,
         srv_definition: String,
-        server: Box<TypeErasedCallback>,
+        server: ServiceHandler,
         md5sum: String,
     },
     UnregisterServiceServer {
@@ -99,6 +110,12 @@ pub(crate) struct NodeServerHandle {
     // Arc to the underlying node task. This is an option because internal handles
     // within the node shouldn't keep it alive (e.g. what we hand to xml server)
     pub(crate) _node_task: Option<Arc<ChildTask<()>>>,
+    // Counts of the messages the node's subscribers and publishers dropped, shared by all handles
+    pub(crate) metrics: ChannelMetrics,
+    // Spawns the node's tasks on its runtime and lists them, shared by all handles
+    pub(crate) spawner: Spawner,
+    // The open TCPROS connections of the node's publications and subscriptions, shared by all handles
+    pub(crate) connections: ConnectionRegistry,
 }
 
 impl NodeServerHandle {
@@ -163,7 +180,8 @@ impl NodeServerHandle {
         topic: &str,
         queue_size: usize,
         latching: bool,
-    ) -> Result<(broadcast::Sender<Vec<u8>>, mpsc::Sender<()>), NodeError> {
+        socket: SocketOptions,
+    ) -> Result<(Outbox, mpsc::Sender<()>), NodeError> {
         let (sender, receiver) = oneshot::channel();
         self.node_server_sender.send(NodeMsg::RegisterPublisher {
             reply: sender,
@@ -173,6 +191,7 @@ impl NodeServerHandle {
             msg_definition: T::DEFINITION.to_owned(),
             md5sum: T::MD5SUM.to_owned(),
             latching,
+            socket,
         })?;
         let received = receiver.await?;
         Ok(received.map_err(|_err| {
@@ -189,7 +208,8 @@ impl NodeServerHandle {
         msg_definition: &str,
         queue_size: usize,
         latching: bool,
-    ) -> Result<(broadcast::Sender<Vec<u8>>, mpsc::Sender<()>), NodeError> {
+        socket: SocketOptions,
+    ) -> Result<(Outbox, mpsc::Sender<()>), NodeError> {
         let (sender, receiver) = oneshot::channel();
 
         let md5sum;
@@ -216,6 +236,7 @@ impl NodeServerHandle {
             msg_definition: msg_definition.to_owned(),
             md5sum,
             latching,
+            socket,
         })?;
         let received = receiver.await?;
         Ok(received.map_err(|_err| {
@@ -242,6 +263,7 @@ impl NodeServerHandle {
     pub(crate) async fn register_service_client<T: RosServiceType>(
         &self,
         service_name: &Name,
+        options: ServiceClientOptions,
     ) -> Result<ServiceClient<T>, NodeError> {
         // Create a channel for hooking into the node server
         let (sender, receiver) = oneshot::channel();
@@ -256,6 +278,7 @@ impl NodeServerHandle {
                     [T::Request::DEFINITION, "\n", T::Response::DEFINITION].into_iter(),
                 ),
                 md5sum: T::MD5SUM.to_owned(),
+                options,
             })?;
         // Get a channel back from the node server for pushing requests into
         let received = receiver.await?;
@@ -272,6 +295,7 @@ impl NodeServerHandle {
         &self,
         service_name: &Name,
         server: F,
+        options: ServiceOptions,
     ) -> Result<(), NodeError>
     where
         T: RosServiceType,
@@ -284,13 +308,16 @@ impl NodeServerHandle {
         // This gives a generic closure that operates on byte arrays that we can then store and use freely
         let server_typeless =
             move |message: Vec<u8>| -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
-                let request = roslibrust_serde_rosmsg::from_slice::<T::Request>(&message)
-                    .map_err(|err| Error::SerializationError(err.to_string()))?;
+                let request = tcpros::deserialize::<T::Request>(&message)
+                    .map_err(Error::SerializationError)?;
                 let response = server(request)?;
-                Ok(roslibrust_serde_rosmsg::to_vec(&response)
-                    .map_err(|err| Error::SerializationError(err.to_string()))?)
+                Ok(tcpros::serialize(&response).map_err(Error::SerializationError)?)
             };
-        let server_typeless = Box::new(server_typeless);
+        let server_typeless = ServiceHandler {
+            callback: Box::new(server_typeless),
+            middleware: options.middleware,
+            drain_timeout: options.drain_timeout,
+        };
 
         self.node_server_sender
             .send(NodeMsg::RegisterServiceServer {
@@ -310,6 +337,71 @@ impl NodeServerHandle {
         })?)
     }
 
+    /// Registers a service client of a type only known at runtime with the underlying node server
+    pub(crate) async fn register_service_client_any(
+        &self,
+        service_name: &Name,
+        srv_definition: &ServiceDefinition,
+    ) -> Result<ServiceClientAny, NodeError> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.node_server_sender
+            .send(NodeMsg::RegisterServiceClient {
+                reply: sender,
+                service: service_name.to_owned(),
+                service_type: srv_definition.name().to_owned(),
+                srv_definition: format!(
+                    "{}\n{}",
+                    srv_definition.request().definition(),
+                    srv_definition.response().definition()
+                ),
+                md5sum: srv_definition.md5sum().to_owned(),
+                options: ServiceClientOptions::default(),
+            })?;
+        let received = receiver.await?;
+        let link = received.map_err(|err| {
+            log::error!("Failed to register service client: {err}");
+            NodeError::IoError(io::Error::from(io::ErrorKind::ConnectionAborted))
+        })?;
+        let sender = link.get_sender();
+
+        Ok(ServiceClientAny::new(service_name, sender, link))
+    }
+
+    /// Registers a service server of a type only known at runtime with the underlying node server,
+    /// `server` being called with the serialized requests and returning the serialized responses
+    pub(crate) async fn register_service_server_any(
+        &self,
+        service_name: &Name,
+        srv_definition: &ServiceDefinition,
+        server: Box<TypeErasedCallback>,
+    ) -> Result<(), NodeError> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.node_server_sender
+            .send(NodeMsg::RegisterServiceServer {
+                reply: sender,
+                service: service_name.to_owned(),
+                service_type: srv_definition.name().to_owned(),
+                srv_definition: format!(
+                    "{}\n{}",
+                    srv_definition.request().definition(),
+                    srv_definition.response().definition()
+                ),
+                server: ServiceHandler {
+                    callback: server,
+                    middleware: Vec::new(),
+                    drain_timeout: ServiceOptions::default().drain_timeout,
+                },
+                md5sum: srv_definition.md5sum().to_owned(),
+            })?;
+        let received = receiver.await?;
+        received.map_err(|err| {
+            log::error!("Failed to register service server: {err}");
+            NodeError::IoError(io::Error::from(io::ErrorKind::ConnectionAborted))
+        })
+    }
+
     /// Called to remove a service server
     /// Delegates to the NodeServer via channel
     pub(crate) async fn unadvertise_service(&self, service_name: &str) -> Result<(), NodeError> {
@@ -334,6 +426,7 @@ impl NodeServerHandle {
         &self,
         topic: &str,
         queue_size: usize,
+        socket: SocketOptions,
     ) -> Result<broadcast::Receiver<Vec<u8>>, NodeError> {
         // Type here is complicated, this is a channel that we're sending a channel receiver over
         // This channel is used to fire back the receiver of the underlying subscription
@@ -345,6 +438,7 @@ impl NodeServerHandle {
             queue_size,
             msg_definition: T::DEFINITION.to_owned(),
             md5sum: T::MD5SUM.to_owned(),
+            socket,
         })?;
         let received = receiver.await?;
         Ok(received.map_err(|err| {
@@ -383,8 +477,8 @@ impl NodeServerHandle {
 /// but nothing should specifically prevent that.
 /// This is sometimes referred to as the NodeServer in the documentation, many NodeHandles can point to one NodeServer
 pub(crate) struct Node {
-    // The xmlrpc client this node uses to make requests to master
-    client: MasterClient,
+    // The master this node makes requests to, or the discovery taking its place in masterless mode
+    client: Registry,
     // Server which handles updates from the rosmaster and other ROS nodes
     _xmlrpc_server: XmlRpcServerHandle,
     // Receiver for requests to the Node actor
@@ -412,26 +506,59 @@ pub(crate) struct Node {
 
 impl Node {
     pub(crate) async fn new(
-        master_uri: &str,
+        registry: RegistryConfig,
         hostname: &str,
         node_name: &Name,
         addr: Ipv4Addr,
+        runtime: tokio::runtime::Handle,
     ) -> Result<NodeServerHandle, NodeError> {
         let (node_sender, node_receiver) = mpsc::unbounded_channel();
+        let metrics = ChannelMetrics::default();
+        let spawner = Spawner::new(runtime.clone());
+        let connections = ConnectionRegistry::default();
         let xml_server_handle = NodeServerHandle {
             node_server_sender: node_sender.clone(),
             // None here because this handle should not keep task alive
             _node_task: None,
+            metrics: metrics.clone(),
+            spawner: spawner.clone(),
+            connections: connections.clone(),
         };
         // Create our xmlrpc server and bind our socket so we know our port and can determine our local URI
-        let xmlrpc_server = XmlRpcServer::new(addr, xml_server_handle)?;
+        let xmlrpc_server = {
+            // Registers the server's socket with the node's runtime
+            let _runtime = runtime.enter();
+            XmlRpcServer::new(addr, xml_server_handle.clone())?
+        };
         let client_uri = format!("http://{hostname}:{}", xmlrpc_server.port());
 
-        let rosmaster_client =
-            MasterClient::new(master_uri, client_uri, node_name.to_string()).await?;
+        let rosmaster_client = match registry {
+            RegistryConfig::Master(master_uri) => Registry::Master(
+                MasterClient::new(master_uri, client_uri, node_name.to_string()).await?,
+            ),
+            RegistryConfig::CachedMaster(master_uri, config) => {
+                Registry::CachedMaster(MasterCache::new(
+                    MasterClient::new(master_uri, client_uri, node_name.to_string()).await?,
+                    config,
+                ))
+            }
+            RegistryConfig::Masterless(config) => {
+                let _runtime = runtime.enter();
+                Registry::Masterless(Discovery::new(
+                    config,
+                    &node_name.to_string(),
+                    &client_uri,
+                    xml_server_handle,
+                    &spawner,
+                )?)
+            }
+        };
         let weak_handle = NodeServerHandle {
             node_server_sender: node_sender.clone(),
             _node_task: None,
+            metrics: metrics.clone(),
+            spawner: spawner.clone(),
+            connections: connections.clone(),
         };
         let mut node = Self {
             client: rosmaster_client,
@@ -447,30 +574,34 @@ impl Node {
         };
 
         let t = Arc::new(
-            tokio::spawn(async move {
-                loop {
-                    match node.node_msg_rx.recv().await {
-                        Some(NodeMsg::Shutdown) => {
-                            log::info!("Shutdown requested, shutting down node");
-                            break;
-                        }
-                        Some(node_msg) => {
-                            node.handle_msg(node_msg).await;
-                        }
-                        None => {
-                            // This isn't an really expected case?
-                            log::warn!("Node command channel closed, shutting down");
-                            break;
+            spawner
+                .spawn(format!("ros1 node {node_name}"), async move {
+                    loop {
+                        match node.node_msg_rx.recv().await {
+                            Some(NodeMsg::Shutdown) => {
+                                log::info!("Shutdown requested, shutting down node");
+                                break;
+                            }
+                            Some(node_msg) => {
+                                node.handle_msg(node_msg).await;
+                            }
+                            None => {
+                                // This isn't an really expected case?
+                                log::warn!("Node command channel closed, shutting down");
+                                break;
+                            }
                         }
                     }
-                }
-            })
-            .into(),
+                })
+                .into(),
         );
 
         let node_server_handle = NodeServerHandle {
             node_server_sender: node_sender,
             _node_task: Some(t),
+            metrics,
+            spawner,
+            connections,
         };
         Ok(node_server_handle)
     }
@@ -524,6 +655,7 @@ impl Node {
                 msg_definition,
                 md5sum,
                 latching,
+                socket,
             } => {
                 let res = self
                     .register_publisher(
@@ -533,13 +665,10 @@ impl Node {
                         msg_definition,
                         md5sum,
                         latching,
+                        socket,
                     )
                     .await;
-                match res {
-                    Ok(handle) => reply.send(Ok(handle)),
-                    Err(err) => reply.send(Err(err.to_string())),
-                }
-                .expect("Failed to reply on oneshot");
+                let _ = reply.send(res.map_err(|err| err.to_string()));
             }
             NodeMsg::UnregisterPublisher { reply, topic } => {
                 let _ = reply.send(
@@ -555,6 +684,7 @@ impl Node {
                 queue_size,
                 msg_definition,
                 md5sum,
+                socket,
             } => {
                 let _ = reply.send(
                     self.register_subscriber(
@@ -563,6 +693,7 @@ impl Node {
                         queue_size,
                         &msg_definition,
                         &md5sum,
+                        socket,
                     )
                     .await
                     .map_err(|err| err.to_string()),
@@ -574,11 +705,18 @@ impl Node {
                 service_type,
                 srv_definition,
                 md5sum,
+                options,
             } => {
                 let _ = reply.send(
-                    self.register_service_client(&service, &service_type, &srv_definition, &md5sum)
-                        .await
-                        .map_err(|err| err.to_string()),
+                    self.register_service_client(
+                        &service,
+                        &service_type,
+                        &srv_definition,
+                        &md5sum,
+                        options,
+                    )
+                    .await
+                    .map_err(|err| err.to_string()),
                 );
             }
             NodeMsg::RegisterServiceServer {
@@ -657,6 +795,7 @@ impl Node {
         queue_size: usize,
         msg_definition: &str,
         md5sum: &str,
+        socket: SocketOptions,
     ) -> Result<broadcast::Receiver<Vec<u8>>, NodeError> {
         match self.subscriptions.iter().find(|(key, _)| *key == topic) {
             Some((_topic, subscription)) => Ok(subscription.get_receiver()),
@@ -668,6 +807,9 @@ impl Node {
                     queue_size,
                     msg_definition.to_owned(),
                     md5sum.to_owned(),
+                    self.node_handle.spawner.clone(),
+                    self.node_handle.connections.clone(),
+                    socket,
                 );
                 let current_publishers = self.client.register_subscriber(topic, topic_type).await?;
                 for publisher in current_publishers {
@@ -682,6 +824,7 @@ impl Node {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn register_publisher(
         &mut self,
         topic: String,
@@ -690,7 +833,8 @@ impl Node {
         msg_definition: String,
         md5sum: String,
         latching: bool,
-    ) -> Result<(broadcast::Sender<Vec<u8>>, mpsc::Sender<()>), NodeError> {
+        socket: SocketOptions,
+    ) -> Result<(Outbox, mpsc::Sender<()>), NodeError> {
         // Return handle to existing Publication if it exists
         let existing_entry = {
             self.publishers.iter().find_map(|(key, value)| {
@@ -735,6 +879,7 @@ impl Node {
             &msg_definition,
             &md5sum,
             topic_type,
+            socket,
             self.node_handle.clone(),
         )
         .await
@@ -774,23 +919,33 @@ impl Node {
         service_type: &str,
         srv_definition: &str,
         md5sum: &str,
+        options: ServiceClientOptions,
     ) -> Result<ServiceClientLink, Box<dyn std::error::Error>> {
         log::debug!("Registering service client for {service}");
         let service_name = service.resolve_to_global(&self.node_name).to_string();
 
         log::debug!("Creating new service client for {service}");
-        let service_uri = self.client.lookup_service(&service_name).await?;
+        let service_uris = match options.providers {
+            ProviderPolicy::Pinned => vec![self.client.lookup_service(&service_name).await?],
+            ProviderPolicy::Failover | ProviderPolicy::RoundRobin => {
+                self.client.lookup_service_providers(&service_name).await?
+            }
+        };
 
-        log::debug!("Found service at {service_uri}");
+        log::debug!("Found service at {}", service_uris.join(", "));
         let server_link = ServiceClientLink::new(
             &self.node_name,
             &service_name,
             service_type,
-            &service_uri,
+            service_uris,
             srv_definition,
             md5sum,
+            options.providers,
+            self.client.clone(),
+            &self.node_handle.spawner,
         )
-        .await?;
+        .await
+        .inspect_err(|_| self.client.forget_service(&service_name))?;
 
         Ok(server_link)
     }
@@ -801,7 +956,7 @@ impl Node {
         service: &Name,
         service_type: &str,
         srv_definition: &str,
-        server: Box<TypeErasedCallback>,
+        server: ServiceHandler,
         md5sum: &str,
     ) -> Result<(), Box<dyn std::error::Error>> {
         let found = self.service_servers.get_mut(service_type);
@@ -816,6 +971,7 @@ impl Node {
             service_type.to_string(),
             md5sum.to_string(),
             srv_definition.to_string(),
+            self.node_handle.spawner.clone(),
         )
         .await?;
         let port = link.port();
@@ -899,7 +1055,9 @@ impl Node {
             }
         };
         // Spawn shutdown operation in a separate task
-        tokio::spawn(future);
+        self.node_handle
+            .spawner
+            .spawn(format!("ros1 node {} shutdown", self.node_name), future);
     }
 }
 
@@ -1,16 +1,33 @@
 use super::actor::{Node, NodeServerHandle};
+use super::RegistryConfig;
+use crate::connections::{diagnostic_msgs, diagnostics, ConnectionDiagnostics, ConnectionInfo};
 use crate::{
-    names::Name, publisher::Publisher, publisher::PublisherAny, service_client::ServiceClient,
-    subscriber::Subscriber, subscriber::SubscriberAny, NodeError, ServiceServer,
+    names::InvalidNameError, names::Name, publisher::Publisher, publisher::PublisherAny,
+    service_client::ServiceClient, service_client::ServiceClientAny, subscriber::probe_publisher,
+    subscriber::Subscriber, subscriber::SubscriberAny, MasterCacheConfig, MasterClient,
+    MasterlessConfig, NodeError, RosEnv, ServiceClientOptions, ServiceOptions, ServiceServer,
+    SocketOptions,
 };
-use roslibrust_common::ServiceFn;
+use roslibrust_common::names::Remappings;
+use roslibrust_common::schema::{MessageSchema, SchemaReport};
+use roslibrust_common::{
+    definitions::ServiceDefinition, ChannelMetrics, RosMessageType, ServiceFn, TaskInfo,
+};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, SystemTime};
 
 /// Represents a handle to an underlying Node. NodeHandle's can be freely cloned, moved, copied, etc.
 /// This class provides the user facing API for interacting with ROS.
 /// The last node handle dropped shuts down the node.
 #[derive(Clone)]
 pub struct NodeHandle {
-    inner: NodeServerHandle,
+    pub(crate) inner: NodeServerHandle,
+    // Fully qualified name of the node, used to resolve relative and private names
+    pub(crate) name: Name,
+    pub(crate) env: Arc<RosEnv>,
+    pub(crate) masterless: bool,
+    // Remappings applied to every name this node resolves, shared by all handles to the node
+    pub(crate) remappings: Arc<RwLock<Remappings>>,
 }
 
 impl NodeHandle {
@@ -18,24 +35,90 @@ impl NodeHandle {
     /// Creates a new node, connects, and returns a handle to it
     /// It is idiomatic to call this once per process and treat the created node as singleton.
     /// The returned handle can be freely clone'd to create additional handles without creating additional connections.
-    ///   - master_uri: Expects a fully resolved http uri for the master e.g. "http://my_host_name:11311"
-    ///   - name: The name of the node, expected to be a valid ros name, all names are interpreted as 'global' in
-    ///     ROS's namespace system. e.g. "my_node" -> "/my_node". "~my_node" is not supported
-    pub async fn new(master_uri: &str, name: &str) -> Result<NodeHandle, NodeError> {
-        let name = if name.starts_with("/") {
-            Name::new(name)?
-        } else {
-            Name::new(&format!("/{}", name))?
-        };
+    ///   - env: The [RosEnv] configuring the node, or a fully resolved http uri for the master e.g.
+    ///     "http://my_host_name:11311" in which case the other settings are read from the environment
+    ///   - name: The name of the node, expected to be a valid ros name. Relative names are placed in the namespace of
+    ///     `env`, e.g. "my_node" -> "/my_node" without a namespace. "~my_node" is not supported
+    pub async fn new(env: impl Into<RosEnv>, name: &str) -> Result<NodeHandle, NodeError> {
+        Self::new_with_runtime(env, name, tokio::runtime::Handle::current()).await
+    }
+
+    /// Like [NodeHandle::new], configured entirely from ROS's environment variables, see [RosEnv::from_env].
+    pub async fn from_env(name: &str) -> Result<NodeHandle, NodeError> {
+        Self::new(RosEnv::from_env(), name).await
+    }
+
+    /// Like [NodeHandle::new], running the node's internal tasks and network I/O on `runtime` instead of the runtime
+    /// this is called on, e.g. a dedicated runtime on pinned threads isolating pub/sub from the rest of the application.
+    /// The node stops working if `runtime` is shut down.
+    pub async fn new_with_runtime(
+        env: impl Into<RosEnv>,
+        name: &str,
+        runtime: tokio::runtime::Handle,
+    ) -> Result<NodeHandle, NodeError> {
+        let env = env.into();
+        let registry = RegistryConfig::Master(env.get_master_uri().to_owned());
+        Self::start(registry, env, name, runtime).await
+    }
+
+    /// Like [NodeHandle::new], answering repeated master lookups from a cache, see [crate::master_cache].
+    ///
+    /// Useful for applications creating many short-lived service clients, which otherwise each ask the master
+    /// where their service is.
+    pub async fn new_with_master_cache(
+        env: impl Into<RosEnv>,
+        name: &str,
+        config: MasterCacheConfig,
+    ) -> Result<NodeHandle, NodeError> {
+        let env = env.into();
+        let runtime = tokio::runtime::Handle::current();
+        let registry = RegistryConfig::CachedMaster(env.get_master_uri().to_owned(), config);
+        Self::start(registry, env, name, runtime).await
+    }
+
+    /// Creates a new node in the experimental masterless mode, in which it finds other masterless nodes through
+    /// multicast announcements instead of a rosmaster, see [crate::masterless].
+    ///
+    /// Publishers, subscribers and services work as they do with a master. The parameter server and queries of the
+    /// graph aren't available, and the node can't communicate with nodes using a master.
+    pub async fn new_masterless(
+        name: &str,
+        config: MasterlessConfig,
+    ) -> Result<NodeHandle, NodeError> {
+        let runtime = tokio::runtime::Handle::current();
+        Self::start(
+            RegistryConfig::Masterless(config),
+            RosEnv::from_env(),
+            name,
+            runtime,
+        )
+        .await
+    }
+
+    async fn start(
+        registry: RegistryConfig,
+        env: RosEnv,
+        name: &str,
+        runtime: tokio::runtime::Handle,
+    ) -> Result<NodeHandle, NodeError> {
+        let name = Name::new(env.node_name(name))?;
+        let masterless = matches!(registry, RegistryConfig::Masterless(_));
 
         // Extra safety check that our name resolves now
         let _ = Name::new("test").unwrap().resolve_to_global(&name);
 
         // Follow ROS rules and determine our IP and hostname
-        let (addr, hostname) = super::determine_addr().await?;
+        let (addr, hostname) = super::determine_addr(&env).await?;
 
-        let node = Node::new(master_uri, &hostname, &name, addr).await?;
-        let nh = NodeHandle { inner: node };
+        let node = Node::new(registry, &hostname, &name, addr, runtime).await?;
+        let remappings = env.get_remappings().clone();
+        let nh = NodeHandle {
+            inner: node,
+            name,
+            env: Arc::new(env),
+            masterless,
+            remappings: Arc::new(RwLock::new(remappings)),
+        };
 
         Ok(nh)
     }
@@ -48,10 +131,99 @@ impl NodeHandle {
             inner: NodeServerHandle {
                 node_server_sender: self.inner.node_server_sender.clone(),
                 _node_task: None,
+                metrics: self.inner.metrics.clone(),
+                spawner: self.inner.spawner.clone(),
+                connections: self.inner.connections.clone(),
             },
+            name: self.name.clone(),
+            env: self.env.clone(),
+            masterless: self.masterless,
+            remappings: self.remappings.clone(),
         }
     }
 
+    /// The configuration the node was created with
+    pub fn env(&self) -> &RosEnv {
+        &self.env
+    }
+
+    /// Counts of the messages this node's subscribers missed by falling behind, see [crate::LagPolicy], and of the
+    /// published messages skipped for subscribers whose connection couldn't keep up. Shared by all handles to the node.
+    pub fn channel_metrics(&self) -> &ChannelMetrics {
+        &self.inner.metrics
+    }
+
+    /// The background tasks this node is running, e.g. one per connection to a publisher of a subscribed topic.
+    /// Shared by all handles to the node, useful for finding connections which outlive their publisher or subscriber.
+    pub fn tasks(&self) -> Vec<TaskInfo> {
+        self.inner.spawner.registry.tasks()
+    }
+
+    /// The open TCPROS connections of this node, to publishers of the topics it subscribes to and from subscribers
+    /// of the topics it publishes, with the headers exchanged and the traffic since connecting. Shared by all
+    /// handles to the node, useful for debugging md5sum mismatches or connections which never carry messages.
+    pub fn connections(&self) -> Vec<ConnectionInfo> {
+        self.inner.connections.connections()
+    }
+
+    /// Publishes [NodeHandle::connections] as a `diagnostic_msgs/DiagnosticArray` on `topic` every `interval`, with
+    /// one status per connection, e.g. for `rqt_runtime_monitor`. Publishing stops when the returned value is dropped.
+    pub async fn publish_connections(
+        &self,
+        topic: &str,
+        interval: Duration,
+    ) -> Result<ConnectionDiagnostics, NodeError> {
+        let publisher = self
+            .advertise::<diagnostic_msgs::DiagnosticArray>(topic, 1, false)
+            .await?;
+        let node = self.weak_clone();
+        let task_name = format!("ros1 connection diagnostics on {topic}");
+        let task = self.inner.spawner.spawn(task_name, async move {
+            let mut ticks = tokio::time::interval(interval);
+            ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticks.tick().await;
+                let diagnostics =
+                    diagnostics(node.name.as_str(), &node.connections(), SystemTime::now());
+                if let Err(e) = publisher.publish(&diagnostics).await {
+                    log::debug!("Failed to publish connection diagnostics: {e}");
+                }
+            }
+        });
+        Ok(ConnectionDiagnostics::new(task.into()))
+    }
+
+    /// Validates a topic or service name and resolves it to a global name relative to this node.
+    /// See <https://wiki.ros.org/Names> for resolution rules, e.g. "~foo" -> "/my_node/foo".
+    /// Remappings given with [RosEnv::remappings] or added with [NodeHandle::remap] are applied to the resolved name.
+    fn resolve_name(&self, name: &str) -> Result<Name, NodeError> {
+        let resolved = Name::new(name)?.resolve_to_global(&self.name);
+        let remappings = self
+            .remappings
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        match remappings
+            .lookup(resolved.as_str(), Some(self.name.as_str()))
+            .map_err(invalid_name)?
+        {
+            Some(remapped) => Ok(Name::new(remapped)?),
+            None => Ok(resolved),
+        }
+    }
+
+    /// Remaps the topic or service name `from` to `to` for every publisher, subscriber and service this node creates
+    /// afterwards, like a `from:=to` command line argument, e.g. `nh.remap("/cmd_vel", "/robot1/cmd_vel")`.
+    /// Both names are resolved relative to this node. Shared by all handles to the node, and doesn't affect
+    /// publishers, subscribers and services which already exist.
+    pub fn remap(&self, from: &str, to: &str) -> Result<(), NodeError> {
+        self.remappings
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(from, to)
+            .map_err(invalid_name)?;
+        Ok(())
+    }
+
     /// This function may be removed...
     /// All node handles connect to a backend node server that actually handles the communication with ROS
     /// If this function returns false, the backend node server has shut down and this handle is invalid.
@@ -81,9 +253,18 @@ impl NodeHandle {
         queue_size: usize,
         latching: bool,
     ) -> Result<PublisherAny, NodeError> {
+        let topic_name = self.resolve_name(topic_name)?;
+        let topic_name = topic_name.as_str();
         let (sender, shutdown) = self
             .inner
-            .register_publisher_any(topic_name, topic_type, msg_definition, queue_size, latching)
+            .register_publisher_any(
+                topic_name,
+                topic_type,
+                msg_definition,
+                queue_size,
+                latching,
+                SocketOptions::new(),
+            )
             .await?;
         Ok(PublisherAny::new(topic_name, sender, shutdown))
     }
@@ -101,9 +282,24 @@ impl NodeHandle {
         queue_size: usize,
         latching: bool,
     ) -> Result<Publisher<T>, NodeError> {
+        self.advertise_with_options::<T>(topic_name, queue_size, latching, SocketOptions::new())
+            .await
+    }
+
+    /// Like [NodeHandle::advertise], applying `options` to the connection to each subscriber.
+    /// As with the queue size, the options of the first publisher of a topic apply to all of them.
+    pub async fn advertise_with_options<T: roslibrust_common::RosMessageType>(
+        &self,
+        topic_name: &str,
+        queue_size: usize,
+        latching: bool,
+        options: SocketOptions,
+    ) -> Result<Publisher<T>, NodeError> {
+        let topic_name = self.resolve_name(topic_name)?;
+        let topic_name = topic_name.as_str();
         let (sender, shutdown) = self
             .inner
-            .register_publisher::<T>(topic_name, queue_size, latching)
+            .register_publisher::<T>(topic_name, queue_size, latching, options)
             .await?;
         Ok(Publisher::new(topic_name, sender, shutdown))
     }
@@ -113,33 +309,81 @@ impl NodeHandle {
         topic_name: &str,
         queue_size: usize,
     ) -> Result<SubscriberAny, NodeError> {
+        let topic_name = self.resolve_name(topic_name)?;
+        let topic_name = topic_name.as_str();
         let receiver = self
             .inner
-            .register_subscriber::<roslibrust_common::ShapeShifter>(topic_name, queue_size)
+            .register_subscriber::<roslibrust_common::ShapeShifter>(
+                topic_name,
+                queue_size,
+                SocketOptions::new(),
+            )
             .await?;
-        Ok(SubscriberAny::new(receiver))
+        Ok(SubscriberAny::new(receiver, self.inner.metrics.clone()))
     }
 
+    /// Subscribes to a topic, sharing the node's connections to its publishers with any other subscribers to it.
+    ///
+    /// Only the first subscriber to a topic registers with the master and connects to the publishers, and its
+    /// `queue_size` sets how far behind each subscriber can fall, see [crate::LagPolicy].
     pub async fn subscribe<T: roslibrust_common::RosMessageType>(
         &self,
         topic_name: &str,
         queue_size: usize,
     ) -> Result<Subscriber<T>, NodeError> {
+        self.subscribe_with_options::<T>(topic_name, queue_size, SocketOptions::new())
+            .await
+    }
+
+    /// Like [NodeHandle::subscribe], applying `options` to the connection to each publisher, see [SocketOptions].
+    /// As with the queue size, the options of the first subscriber to a topic apply to all of them.
+    pub async fn subscribe_with_options<T: roslibrust_common::RosMessageType>(
+        &self,
+        topic_name: &str,
+        queue_size: usize,
+        options: SocketOptions,
+    ) -> Result<Subscriber<T>, NodeError> {
+        let topic_name = self.resolve_name(topic_name)?;
+        let topic_name = topic_name.as_str();
         let receiver = self
             .inner
-            .register_subscriber::<T>(topic_name, queue_size)
+            .register_subscriber::<T>(topic_name, queue_size, options)
             .await?;
-        Ok(Subscriber::new(receiver))
+        Ok(Subscriber::new(receiver, self.inner.metrics.clone()))
     }
 
     pub async fn service_client<T: roslibrust_common::RosServiceType>(
         &self,
         service_name: &str,
     ) -> Result<ServiceClient<T>, NodeError> {
-        let service_name = Name::new(service_name)?;
+        self.service_client_with_options::<T>(service_name, ServiceClientOptions::new())
+            .await
+    }
+
+    /// Like [NodeHandle::service_client], choosing between the nodes providing the service as configured by
+    /// `options`, e.g. to spread calls across several masterless nodes providing it:
+    ///
+    /// ```no_run
+    /// # async fn run(nh: roslibrust_ros1::NodeHandle) -> Result<(), roslibrust_ros1::NodeError> {
+    /// use roslibrust_ros1::{ProviderPolicy, ServiceClientOptions};
+    /// use roslibrust_test::ros1::std_srvs;
+    ///
+    /// let options = ServiceClientOptions::new().providers(ProviderPolicy::RoundRobin);
+    /// let client = nh
+    ///     .service_client_with_options::<std_srvs::Trigger>("/plan_path", options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn service_client_with_options<T: roslibrust_common::RosServiceType>(
+        &self,
+        service_name: &str,
+        options: ServiceClientOptions,
+    ) -> Result<ServiceClient<T>, NodeError> {
+        let service_name = self.resolve_name(service_name)?;
         let sender = self
             .inner
-            .register_service_client::<T>(&service_name)
+            .register_service_client::<T>(&service_name, options)
             .await?;
         Ok(sender)
     }
@@ -153,15 +397,149 @@ impl NodeHandle {
         T: roslibrust_common::RosServiceType,
         F: ServiceFn<T>,
     {
-        let service_name = Name::new(service_name)?;
+        self.advertise_service_with_options::<T, F>(service_name, server, ServiceOptions::new())
+            .await
+    }
+
+    /// Like [NodeHandle::advertise_service], with the service function wrapped in the [crate::ServiceMiddleware]
+    /// layers of `options`, e.g. to log requests, rate limit them, or only accept them from certain nodes:
+    ///
+    /// ```no_run
+    /// # async fn run(nh: roslibrust_ros1::NodeHandle) -> Result<(), roslibrust_ros1::NodeError> {
+    /// use roslibrust_ros1::{RateLimit, RequestLogger, ServiceOptions, ValidateCaller};
+    /// use roslibrust_test::ros1::std_srvs;
+    ///
+    /// let options = ServiceOptions::new()
+    ///     .layer(RequestLogger::new())
+    ///     .layer(ValidateCaller::allow_callers(["/operator_console"]))
+    ///     .layer(RateLimit::per_second(1));
+    /// let _server = nh
+    ///     .advertise_service_with_options::<std_srvs::Trigger, _>(
+    ///         "/emergency_stop",
+    ///         |_| Ok(std_srvs::TriggerResponse { success: true, message: String::new() }),
+    ///         options,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn advertise_service_with_options<T, F>(
+        &self,
+        service_name: &str,
+        server: F,
+        options: ServiceOptions,
+    ) -> Result<ServiceServer, NodeError>
+    where
+        T: roslibrust_common::RosServiceType,
+        F: ServiceFn<T>,
+    {
+        let service_name = self.resolve_name(service_name)?;
         let _response = self
             .inner
-            .register_service_server::<T, F>(&service_name, server)
+            .register_service_server::<T, F>(&service_name, server, options)
+            .await?;
+        // Super important. Don't clone self or we create a STRONG NodeHandle that keeps the node alive
+        Ok(ServiceServer::new(service_name, self.weak_clone()))
+    }
+
+    /// Create a new client for a service whose type is only known at runtime.
+    ///
+    /// This function is intended to be used for tools calling any service, such as a command line caller.
+    /// The service's definition is parsed from the text of its .srv file with [ServiceDefinition::parse],
+    /// and requests and responses are passed in their serialized form, see [ServiceClientAny::call].
+    pub async fn service_client_any(
+        &self,
+        service_name: &str,
+        srv_definition: &ServiceDefinition,
+    ) -> Result<ServiceClientAny, NodeError> {
+        let service_name = self.resolve_name(service_name)?;
+        self.inner
+            .register_service_client_any(&service_name, srv_definition)
+            .await
+    }
+
+    /// Advertise a service whose type is only known at runtime, the counterpart of [NodeHandle::service_client_any].
+    ///
+    /// The server is called with the serialized requests, with the body length as the first four bytes,
+    /// and returns the serialized responses in the same form.
+    pub async fn advertise_service_any<F>(
+        &self,
+        service_name: &str,
+        srv_definition: &ServiceDefinition,
+        server: F,
+    ) -> Result<ServiceServer, NodeError>
+    where
+        F: Fn(Vec<u8>) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let service_name = self.resolve_name(service_name)?;
+        self.inner
+            .register_service_server_any(&service_name, srv_definition, Box::new(server))
             .await?;
         // Super important. Don't clone self or we create a STRONG NodeHandle that keeps the node alive
         Ok(ServiceServer::new(service_name, self.weak_clone()))
     }
 
+    /// A client of the master this node is registered with, for queries the node itself doesn't make
+    pub(crate) async fn master_client(&self) -> Result<MasterClient, NodeError> {
+        Ok(MasterClient::new(
+            self.inner.get_master_uri().await?,
+            self.inner.get_client_uri().await?,
+            self.name.to_string(),
+        )
+        .await?)
+    }
+
+    /// Returns the schema each publisher of a topic advertises in its connection header by node name, for comparing
+    /// with [MessageSchema::of] the type subscribed with, see [NodeHandle::check_topic_schema].
+    ///
+    /// Publishers are connected to once and the connection dropped after reading the header.
+    /// Publishers which can't be reached are logged and left out.
+    pub async fn topic_schemas(
+        &self,
+        topic_name: &str,
+    ) -> Result<Vec<(String, MessageSchema)>, NodeError> {
+        let topic_name = self.resolve_name(topic_name)?;
+        let master = self.master_client().await?;
+        let state = master.get_system_state().await?;
+        let mut schemas = vec![];
+        for node in state.publishers_of(topic_name.as_str()) {
+            let header = match master.lookup_node(node).await {
+                Ok(uri) => probe_publisher(&self.name, topic_name.as_str(), &uri).await,
+                Err(e) => {
+                    log::warn!("Failed to look up publisher {node} of {topic_name}: {e}");
+                    continue;
+                }
+            };
+            match header {
+                Ok(header) => schemas.push((
+                    node.clone(),
+                    MessageSchema::new(header.topic_type, header.msg_definition)
+                        .with_md5sum(header.md5sum.unwrap_or_default()),
+                )),
+                Err(e) => log::warn!("Failed to read the schema of {node} for {topic_name}: {e}"),
+            }
+        }
+        Ok(schemas)
+    }
+
+    /// Compares `T` with the schema of each publisher of a topic, returning a report by node name which explains
+    /// any md5sum mismatch, see [NodeHandle::topic_schemas].
+    pub async fn check_topic_schema<T: RosMessageType>(
+        &self,
+        topic_name: &str,
+    ) -> Result<Vec<(String, SchemaReport)>, NodeError> {
+        let local = MessageSchema::of::<T>();
+        Ok(self
+            .topic_schemas(topic_name)
+            .await?
+            .into_iter()
+            .map(|(node, remote)| (node, local.compare(&remote)))
+            .collect())
+    }
+
     // TODO Major: This should probably be moved to NodeServerHandle?
     /// Not intended to be called manually
     /// Stops hosting the specified server.
@@ -174,7 +552,8 @@ impl NodeHandle {
         // This should be fine due to the "cmd dispatch" that is the current communication mechanism with NodeServer
         let copy = self.clone();
         let name_copy = service_name.to_string();
-        tokio::spawn(async move {
+        let task_name = format!("ros1 unadvertise service {service_name}");
+        self.inner.spawner.spawn(task_name, async move {
             let result = copy.inner.unadvertise_service(&name_copy).await;
             if let Err(e) = result {
                 log::error!("Failed to undvertise service: {e:?}");
@@ -183,3 +562,11 @@ impl NodeHandle {
         Ok(())
     }
 }
+
+/// Keeps the reason a name given to the remappings was rejected
+fn invalid_name(e: roslibrust_common::Error) -> InvalidNameError {
+    match e {
+        roslibrust_common::Error::InvalidName(reason) => InvalidNameError(reason),
+        e => InvalidNameError(e.to_string()),
+    }
+}
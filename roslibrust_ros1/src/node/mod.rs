@@ -3,7 +3,7 @@
 
 use roslibrust_common::Error;
 
-use super::{names::InvalidNameError, RosMasterError};
+use super::{names::InvalidNameError, RosEnv, RosMasterError};
 use std::{
     io,
     net::{IpAddr, Ipv4Addr},
@@ -11,10 +11,12 @@ use std::{
 
 pub(crate) mod actor;
 mod handle;
+mod registry;
 mod xmlrpc;
 use actor::*;
 use anyhow::anyhow;
 pub use handle::NodeHandle;
+pub(crate) use registry::*;
 use tokio::sync::{mpsc, oneshot};
 use xmlrpc::*;
 
@@ -25,24 +27,24 @@ pub struct ProtocolParams {
     pub port: u16,
 }
 
-// TODO at the end of the day I'd like to offer a builder pattern for configuration that allow manual setting of this or "ros idiomatic" behavior - Carter
-/// Following ROS's idiomatic address rules uses ROS_HOSTNAME and ROS_IP to determine the address that server should be hosted at.
+/// Following ROS's idiomatic address rules uses ROS_HOSTNAME and ROS_IP, as read into `env`, to determine the address
+/// that server should be hosted at.
 /// Returns both the resolved IpAddress of the host (used for actually opening the socket), and the String "hostname" which should
 /// be used in the URI.
-async fn determine_addr() -> Result<(Ipv4Addr, String), RosMasterError> {
+async fn determine_addr(env: &RosEnv) -> Result<(Ipv4Addr, String), RosMasterError> {
     // If ROS_IP is set that trumps anything else
-    if let Ok(ip_str) = std::env::var("ROS_IP") {
+    if let Some(ip_str) = env.get_ip() {
         let ip = ip_str.parse().map_err(|e| {
             RosMasterError::HostIpResolutionFailure(format!(
                 "ROS_IP environment variable did not parse to a valid IpAddr::V4: {e:?}"
             ))
         })?;
-        return Ok((ip, ip_str));
+        return Ok((ip, ip_str.to_owned()));
     }
     // If ROS_HOSTNAME is set that is next highest precedent
-    if let Ok(name) = std::env::var("ROS_HOSTNAME") {
-        let ip = hostname_to_ipv4(&name).await?;
-        return Ok((ip, name));
+    if let Some(name) = env.get_hostname() {
+        let ip = hostname_to_ipv4(name).await?;
+        return Ok((ip, name.to_owned()));
     }
     // If neither env var is set, use the computers "hostname"
     let name = gethostname::gethostname();
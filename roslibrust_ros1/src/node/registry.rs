@@ -0,0 +1,143 @@
+use crate::{
+    master_cache::MasterCache, masterless::Discovery, MasterCacheConfig, MasterClient,
+    MasterlessConfig, RosMasterError,
+};
+
+/// How a node finds the other nodes
+pub(crate) enum RegistryConfig {
+    /// Through the master with this uri
+    Master(String),
+    /// Through the master with this uri, caching its answers
+    CachedMaster(String, MasterCacheConfig),
+    Masterless(MasterlessConfig),
+}
+
+/// Where a node registers its publications, subscriptions and services, and looks up those of other nodes
+#[derive(Clone)]
+pub(crate) enum Registry {
+    Master(MasterClient),
+    CachedMaster(MasterCache),
+    Masterless(Discovery),
+}
+
+impl Registry {
+    /// The uri of the master, empty in masterless mode
+    pub(crate) fn get_master_uri(&self) -> &str {
+        match self {
+            Registry::Master(client) => client.get_master_uri(),
+            Registry::CachedMaster(cache) => cache.client().get_master_uri(),
+            Registry::Masterless(_) => "",
+        }
+    }
+
+    pub(crate) fn client_uri(&self) -> &str {
+        match self {
+            Registry::Master(client) => client.client_uri(),
+            Registry::CachedMaster(cache) => cache.client().client_uri(),
+            Registry::Masterless(discovery) => discovery.client_uri(),
+        }
+    }
+
+    /// Returns the uris of the current publishers of the topic
+    pub(crate) async fn register_subscriber(
+        &self,
+        topic: &str,
+        topic_type: &str,
+    ) -> Result<Vec<String>, RosMasterError> {
+        match self {
+            Registry::Master(client) => client.register_subscriber(topic, topic_type).await,
+            Registry::CachedMaster(cache) => {
+                cache.client().register_subscriber(topic, topic_type).await
+            }
+            Registry::Masterless(discovery) => Ok(discovery.register_subscriber(topic)),
+        }
+    }
+
+    pub(crate) async fn unregister_subscriber(&self, topic: &str) -> Result<bool, RosMasterError> {
+        match self {
+            Registry::Master(client) => client.unregister_subscriber(topic).await,
+            Registry::CachedMaster(cache) => cache.client().unregister_subscriber(topic).await,
+            Registry::Masterless(discovery) => Ok(discovery.unregister_subscriber(topic)),
+        }
+    }
+
+    /// Returns the uris of the current subscribers of the topic, which masterless nodes don't track
+    pub(crate) async fn register_publisher(
+        &self,
+        topic: &str,
+        topic_type: &str,
+    ) -> Result<Vec<String>, RosMasterError> {
+        match self {
+            Registry::Master(client) => client.register_publisher(topic, topic_type).await,
+            Registry::CachedMaster(cache) => {
+                cache.client().register_publisher(topic, topic_type).await
+            }
+            Registry::Masterless(discovery) => {
+                discovery.register_publisher(topic, topic_type).await;
+                Ok(vec![])
+            }
+        }
+    }
+
+    pub(crate) async fn unregister_publisher(&self, topic: &str) -> Result<bool, RosMasterError> {
+        match self {
+            Registry::Master(client) => client.unregister_publisher(topic).await,
+            Registry::CachedMaster(cache) => cache.client().unregister_publisher(topic).await,
+            Registry::Masterless(discovery) => Ok(discovery.unregister_publisher(topic).await),
+        }
+    }
+
+    pub(crate) async fn lookup_service(&self, service: &str) -> Result<String, RosMasterError> {
+        match self {
+            Registry::Master(client) => client.lookup_service(service).await,
+            Registry::CachedMaster(cache) => cache.lookup_service(service).await,
+            Registry::Masterless(discovery) => discovery.lookup_service(service).await,
+        }
+    }
+
+    /// The uris of every node providing the service. The master only knows the latest node to advertise a service,
+    /// whereas without a master all nodes announcing it are found.
+    pub(crate) async fn lookup_service_providers(
+        &self,
+        service: &str,
+    ) -> Result<Vec<String>, RosMasterError> {
+        match self {
+            Registry::Masterless(discovery) => discovery.lookup_service_providers(service).await,
+            _ => Ok(vec![self.lookup_service(service).await?]),
+        }
+    }
+
+    /// Forgets what is known about the service after failing to connect to it
+    pub(crate) fn forget_service(&self, service: &str) {
+        if let Registry::CachedMaster(cache) = self {
+            cache.forget_service(service);
+        }
+    }
+
+    pub(crate) async fn register_service(
+        &self,
+        service: String,
+        service_uri: String,
+    ) -> Result<(), RosMasterError> {
+        match self {
+            Registry::Master(client) => client.register_service(service, service_uri).await,
+            Registry::CachedMaster(cache) => cache.register_service(service, service_uri).await,
+            Registry::Masterless(discovery) => {
+                discovery.register_service(service, service_uri).await;
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) async fn unregister_service(
+        &self,
+        service: &str,
+        service_uri: String,
+    ) -> Result<bool, RosMasterError> {
+        match self {
+            Registry::Master(client) => client.unregister_service(service, service_uri).await,
+            Registry::CachedMaster(cache) => cache.unregister_service(service, service_uri).await,
+            Registry::Masterless(discovery) => Ok(discovery.unregister_service(service).await),
+        }
+    }
+}
@@ -48,6 +48,7 @@ impl XmlRpcServer {
         host_addr: Ipv4Addr,
         node_server: NodeServerHandle,
     ) -> Result<XmlRpcServerHandle, XmlRpcError> {
+        let spawner = node_server.spawner.clone();
         let make_svc = hyper::service::make_service_fn(move |connection| {
             debug!("New node xmlrpc connection {connection:?}");
             let node_server = node_server.clone();
@@ -62,7 +63,7 @@ impl XmlRpcServer {
         let server = server.serve(make_svc);
         let addr = server.local_addr();
 
-        let handle = tokio::spawn(async {
+        let handle = spawner.spawn(format!("ros1 xmlrpc server :{}", addr.port()), async {
             if let Err(err) = server.await {
                 log::error!("xmlrpc server encountered error: {err:?}");
             }
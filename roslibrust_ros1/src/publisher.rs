@@ -1,27 +1,115 @@
 use crate::{
+    compression::Compression,
+    connections::{ConnectionGuard, Direction},
     names::Name,
+    socket::SocketOptions,
     tcpros::{self, ConnectionHeader},
 };
 use abort_on_drop::ChildTask;
 use log::*;
-use roslibrust_common::RosMessageType;
+use roslibrust_common::{ChannelMetrics, RosMessageType};
 use std::{
     marker::PhantomData,
     net::{Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex, PoisonError},
 };
 use tokio::{
     io::AsyncWriteExt,
-    sync::broadcast::{self, error::RecvError},
+    sync::{
+        broadcast::{
+            self,
+            error::{RecvError, TryRecvError},
+        },
+        watch,
+    },
 };
 
+/// The most bytes of queued messages coalesced into a single write to a subscriber
+const MAX_BATCH_BYTES: usize = 64 * 1024;
+/// The most queued messages coalesced into a single write, well below the operating system's limit on buffers
+const MAX_BATCH_MESSAGES: usize = 64;
+
 use super::actor::NodeServerHandle;
 
+/// The queue messages are published into, shared by the publishers of a topic and the tasks writing to each of its
+/// subscribers. Each connection reports how many of the queued messages it has written, so publishers with
+/// backpressure can wait for the subscribers to take their messages.
+#[derive(Clone)]
+pub(crate) struct Outbox {
+    sender: broadcast::Sender<Arc<Vec<u8>>>,
+    // The number of messages queued so far, locked while queueing or connecting so both agree on the position of
+    // each message in the queue
+    queued: Arc<Mutex<u64>>,
+    // Position in the queue up to which each connection has written the messages
+    connections: Arc<Mutex<Vec<watch::Receiver<u64>>>>,
+}
+
+/// A message queued by [Outbox::send], to wait for it to be written to the subscribers connected at the time
+pub(crate) struct Queued {
+    position: u64,
+    connections: Vec<watch::Receiver<u64>>,
+}
+
+impl Queued {
+    /// Waits until every connection has written the message to its socket or closed
+    pub(crate) async fn written(mut self) {
+        for connection in &mut self.connections {
+            // An error means the connection closed, and won't be writing the message
+            let _ = connection
+                .wait_for(|written| *written >= self.position)
+                .await;
+        }
+    }
+}
+
+impl Outbox {
+    fn new(sender: broadcast::Sender<Arc<Vec<u8>>>) -> Self {
+        Self {
+            sender,
+            queued: Arc::new(Mutex::new(0)),
+            connections: Default::default(),
+        }
+    }
+
+    /// Queues a message for all connected subscribers
+    pub(crate) fn send(&self, data: Vec<u8>) -> Result<Queued, PublisherError> {
+        let mut queued = self.queued.lock().unwrap_or_else(PoisonError::into_inner);
+        self.sender
+            .send(Arc::new(data))
+            .map_err(|_| PublisherError::StreamClosed)?;
+        *queued += 1;
+        let mut connections = self
+            .connections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        connections.retain(|connection| connection.has_changed().is_ok());
+        Ok(Queued {
+            position: *queued,
+            connections: connections.clone(),
+        })
+    }
+
+    /// Receives the messages queued from now on for a new connection, which reports how many of them it has written
+    /// through the returned sender
+    fn connect(&self) -> (broadcast::Receiver<Arc<Vec<u8>>>, watch::Sender<u64>) {
+        let queued = self.queued.lock().unwrap_or_else(PoisonError::into_inner);
+        let (written, connection) = watch::channel(*queued);
+        self.connections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(connection);
+        (self.sender.subscribe(), written)
+    }
+}
+
 /// The regular Publisher representation returned by calling advertise on a [crate::NodeHandle].
 pub struct Publisher<T> {
     // Name of the topic this publisher is publishing on
     topic_name: String,
     // Actual channel on which messages are sent to be published
-    sender: broadcast::Sender<Vec<u8>>,
+    outbox: Outbox,
+    // Whether publish waits for the message to be written to the subscribers
+    backpressure: bool,
     // When the last publisher for a given topic is dropped, this channel is used to signal to cleanup
     // for the underlying publication
     _shutdown_channel: tokio::sync::mpsc::Sender<()>,
@@ -32,30 +120,37 @@ pub struct Publisher<T> {
 impl<T: RosMessageType> Publisher<T> {
     pub(crate) fn new(
         topic_name: &str,
-        sender: broadcast::Sender<Vec<u8>>,
+        outbox: Outbox,
         shutdown_channel: tokio::sync::mpsc::Sender<()>,
     ) -> Self {
         Self {
             topic_name: topic_name.to_owned(),
-            sender,
+            outbox,
+            backpressure: false,
             _shutdown_channel: shutdown_channel,
             phantom: PhantomData,
         }
     }
 
-    /// Queues a message to be sent on the related topic.
-    // TODO Major this no longer needs to be (or should be) async
+    /// With backpressure enabled [Publisher::publish] waits until the message has been written to the socket of
+    /// every subscriber connected when it was published, rather than returning once it is queued.
+    ///
+    /// A producer publishing faster than the network can carry then slows down to its pace, instead of the queue
+    /// overflowing and subscribers missing messages. A subscriber which stops reading stalls publishing until its
+    /// connection closes, so callers which can't afford that should publish under a timeout.
+    pub fn backpressure(mut self, enabled: bool) -> Self {
+        self.backpressure = enabled;
+        self
+    }
+
+    /// Queues a message to be sent on the related topic, with [Publisher::backpressure] waiting until it is sent.
     pub async fn publish(&self, data: &T) -> Result<(), PublisherError> {
-        let data = roslibrust_serde_rosmsg::to_vec(&data)?;
-        // TODO this is a pretty dumb...
-        // because of the internal channel used for re-direction this future doesn't
-        // actually complete when the data is sent, but merely when it is queued to be sent
-        // This function could probably be non-async
-        // Or we should do some significant re-work to have it only yield when the data is sent.
-        self.sender
-            .send(data)
-            .map_err(|_| PublisherError::StreamClosed)?;
+        let data = tcpros::serialize(data).map_err(PublisherError::SerializingError)?;
+        let queued = self.outbox.send(data)?;
         debug!("Publishing data on topic {}", self.topic_name);
+        if self.backpressure {
+            queued.written().await;
+        }
         Ok(())
     }
 }
@@ -65,7 +160,8 @@ impl<T: RosMessageType> Publisher<T> {
 /// Relies on user to provide serialized data. Typically used with playback from bag files.
 pub struct PublisherAny {
     topic_name: String,
-    sender: broadcast::Sender<Vec<u8>>,
+    outbox: Outbox,
+    backpressure: bool,
     // When the last publisher for a given topic is dropped, this channel is used to signal to cleanup
     // Don't need to send a message, simply dropping the last handle lets to node know to clean up
     // Note: this has to be used because tokio::sync::broadcast doesn't have a WeakSender
@@ -76,33 +172,35 @@ pub struct PublisherAny {
 impl PublisherAny {
     pub(crate) fn new(
         topic_name: &str,
-        sender: broadcast::Sender<Vec<u8>>,
+        outbox: Outbox,
         shutdown: tokio::sync::mpsc::Sender<()>,
     ) -> Self {
         Self {
             topic_name: topic_name.to_owned(),
-            sender,
+            outbox,
+            backpressure: false,
             _shutdown: shutdown,
             phantom: PhantomData,
         }
     }
 
-    /// Queues a message to be sent on the related topic.
+    /// Waits for each message to be written to the subscribers when publishing, see [Publisher::backpressure].
+    pub fn backpressure(mut self, enabled: bool) -> Self {
+        self.backpressure = enabled;
+        self
+    }
+
+    /// Queues a message to be sent on the related topic, with [PublisherAny::backpressure] waiting until it is sent.
     ///
     /// This expects the data to be the raw bytes of the message body as they would appear going over the wire.
     /// See ros1_publish_any.rs example for more details.
     /// Body length should be included as first four bytes.
-    // TODO this no longer needs to be (or should be) async
     pub async fn publish(&self, data: &Vec<u8>) -> Result<(), PublisherError> {
-        // TODO this is a pretty dumb...
-        // because of the internal channel used for re-direction this future doesn't
-        // actually complete when the data is sent, but merely when it is queued to be sent
-        // This function could probably be non-async
-        // Or we should do some significant re-work to have it only yield when the data is sent.
-        self.sender
-            .send(data.to_vec())
-            .map_err(|_| PublisherError::StreamClosed)?;
+        let queued = self.outbox.send(data.to_vec())?;
         debug!("Publishing data on topic {}", self.topic_name);
+        if self.backpressure {
+            queued.written().await;
+        }
         Ok(())
     }
 }
@@ -111,7 +209,7 @@ pub(crate) struct Publication {
     topic_type: String,
     listener_port: u16,
     _tcp_accept_task: ChildTask<()>,
-    publish_sender: broadcast::Sender<Vec<u8>>,
+    outbox: Outbox,
     // We store a weak handle to the shutdown channel
     // This allows us to create new Publisher with a shutdown sender, but doesn't keep the shutdown channel alive
     // Had to add this because broadcast doesn't have a weak sender equivalent
@@ -122,6 +220,7 @@ impl Publication {
     /// Spawns a new publication and sets up all tasks to run it
     /// Returns a handle to the publication and a mpsc::Sender to send messages to be published
     /// Dropping the Sender will (eventually) result in the publication being dropped and all tasks being canceled
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn new(
         node_name: &Name,
         latching: bool,
@@ -131,22 +230,17 @@ impl Publication {
         msg_definition: &str,
         md5sum: &str,
         topic_type: &str,
+        socket: SocketOptions,
         node_handle: NodeServerHandle,
-    ) -> Result<
-        (
-            Self,
-            broadcast::Sender<Vec<u8>>,
-            tokio::sync::mpsc::Sender<()>,
-        ),
-        std::io::Error,
-    > {
+    ) -> Result<(Self, Outbox, tokio::sync::mpsc::Sender<()>), std::io::Error> {
         // Get a socket for receiving connections on
         let host_addr = SocketAddr::from((host_addr, 0));
-        let tcp_listener = tokio::net::TcpListener::bind(host_addr).await?;
+        let tcp_listener = socket.listen(host_addr)?;
         let listener_port = tcp_listener.local_addr().unwrap().port();
 
         // Setup the channel will will receive messages to be published on
-        let (sender, receiver) = broadcast::channel::<Vec<u8>>(queue_size);
+        let (sender, receiver) = broadcast::channel::<Arc<Vec<u8>>>(queue_size);
+        let outbox = Outbox::new(sender);
 
         // Setup the ROS connection header that we'll respond to all incoming connections with
         let responding_conn_header = ConnectionHeader {
@@ -159,6 +253,7 @@ impl Publication {
             tcp_nodelay: false,
             service: None,
             persistent: None,
+            compression: None,
         };
         trace!("Publisher connection header: {responding_conn_header:?}");
 
@@ -168,42 +263,38 @@ impl Publication {
 
         // Create the task that will accept new TCP connections
         let topic_name_copy = topic_name.to_owned();
-        let tcp_accept_handle = tokio::spawn(async move {
+        let outbox_copy = outbox.clone();
+        let spawner = node_handle.spawner.clone();
+        let task_name = format!("ros1 publisher {topic_name} accepting on :{listener_port}");
+        let tcp_accept_handle = spawner.spawn(task_name, async move {
             Self::tcp_accept_task(
                 tcp_listener,
                 topic_name_copy,
                 responding_conn_header,
                 receiver,
+                outbox_copy,
                 shutdown_rx,
+                socket,
                 node_handle,
             )
             .await
         });
 
-        let sender_copy = sender.clone();
         Ok((
             Self {
                 topic_type: topic_type.to_owned(),
                 _tcp_accept_task: tcp_accept_handle.into(),
                 listener_port,
-                publish_sender: sender,
+                outbox: outbox.clone(),
                 weak_shutdown_channel,
             },
-            sender_copy,
+            outbox,
             shutdown_tx,
         ))
     }
 
-    pub(crate) fn get_senders(
-        &self,
-    ) -> (
-        broadcast::Sender<Vec<u8>>,
-        tokio::sync::mpsc::WeakSender<()>,
-    ) {
-        (
-            self.publish_sender.clone(),
-            self.weak_shutdown_channel.clone(),
-        )
+    pub(crate) fn get_senders(&self) -> (Outbox, tokio::sync::mpsc::WeakSender<()>) {
+        (self.outbox.clone(), self.weak_shutdown_channel.clone())
     }
 
     pub(crate) fn port(&self) -> u16 {
@@ -218,33 +309,88 @@ impl Publication {
     /// this task is spawned by new, and canceled when the Publication is dropped
     /// This task constantly pulls new messages from the main publish buffer and
     /// sends them to all of the TCP Streams that are connected to the topic.
+    #[allow(clippy::too_many_arguments)]
     async fn publish_task(
-        mut rx: broadcast::Receiver<Vec<u8>>, // Receives messages to publish from the main buffer of messages
+        mut rx: broadcast::Receiver<Arc<Vec<u8>>>, // Receives messages to publish from the main buffer of messages
+        written: watch::Sender<u64>, // Reports the position in the queue up to which messages were written
         mut stream: tokio::net::TcpStream,
         topic: String,
-        last_message: Option<Vec<u8>>, // If we're latching will contain a message to send right away
+        last_message: Option<Arc<Vec<u8>>>, // If we're latching will contain a message to send right away
+        compression: Compression,           // Negotiated with the subscriber
+        metrics: ChannelMetrics,
+        connection: ConnectionGuard,
     ) {
         let peer = stream.peer_addr();
         debug!("Publish task has started for publication: {topic} connection to {peer:?}");
 
         if let Some(last_message) = last_message {
-            let res = stream.write_all(&last_message).await;
+            let res = match compression.compress(&last_message) {
+                Ok(frame) => stream.write_all(&frame).await.map(|_| frame.len()),
+                Err(e) => Err(e),
+            };
             match res {
-                Ok(_) => {}
+                Ok(len) => connection.record(1, len),
                 Err(e) => {
                     error!("Failed to send latch message to subscriber: {e:?}");
                 }
             }
         }
 
+        let mut position = *written.borrow();
         loop {
             match rx.recv().await {
                 Ok(msg_to_publish) => {
                     trace!("Publish task got message to publish for topic: {topic}");
-                    let send_result = stream.write_all(&msg_to_publish[..]).await;
+                    position += 1;
+                    // Messages queued while the last write was in progress are coalesced into one write,
+                    // saving a syscall per message on high frequency topics without waiting for more
+                    let mut batch_len = msg_to_publish.len();
+                    let mut batch = vec![msg_to_publish];
+                    while batch_len < MAX_BATCH_BYTES && batch.len() < MAX_BATCH_MESSAGES {
+                        match rx.try_recv() {
+                            Ok(msg) => {
+                                batch_len += msg.len();
+                                batch.push(msg);
+                                position += 1;
+                            }
+                            Err(TryRecvError::Lagged(num)) => {
+                                debug!("TCP for peer {peer:?} is lagging behind, {num} messages were skipped");
+                                metrics.record_publisher_dropped(num);
+                                position += num;
+                            }
+                            // A closed channel is handled by the next recv()
+                            Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+                        }
+                    }
+                    // Uncompressed messages are written straight from the queue without copying them
+                    let compressed: Vec<Vec<u8>> = if compression == Compression::None {
+                        vec![]
+                    } else {
+                        match batch.iter().map(|msg| compression.compress(msg)).collect() {
+                            Ok(compressed) => compressed,
+                            Err(err) => {
+                                error!(
+                                    "Failed to compress messages for subscriber: {err}, removing"
+                                );
+                                break;
+                            }
+                        }
+                    };
+                    let buffers: Vec<&[u8]> = if compression == Compression::None {
+                        batch.iter().map(|msg| &msg[..]).collect()
+                    } else {
+                        compressed.iter().map(|frame| &frame[..]).collect()
+                    };
+                    let wire_len = buffers.iter().map(|buffer| buffer.len()).sum();
+                    let send_result = tcpros::write_all_vectored(&mut stream, &buffers).await;
                     match send_result {
                         Ok(_) => {
-                            trace!("Publish task sent message to topic: {topic}");
+                            connection.record(batch.len() as u64, wire_len);
+                            written.send_replace(position);
+                            trace!(
+                                "Publish task sent {} messages to topic: {topic}",
+                                batch.len()
+                            );
                         }
                         Err(err) => {
                             // Shut down this TCP connection if we can't write a whole message
@@ -255,6 +401,10 @@ impl Publication {
                 }
                 Err(RecvError::Lagged(num)) => {
                     debug!("TCP for peer {peer:?} is lagging behind, {num} messages were skipped");
+                    metrics.record_publisher_dropped(num);
+                    // Nobody waits for skipped messages
+                    position += num;
+                    written.send_replace(position);
                     continue;
                 }
                 Err(RecvError::Closed) => {
@@ -269,12 +419,15 @@ impl Publication {
     /// Wraps the functionality that the tcp_accept task will perform
     /// This task is spawned by new, and canceled when the Publication is dropped
     /// This task constantly accepts new TCP connections and adds them to the list of streams to send data to.
+    #[allow(clippy::too_many_arguments)]
     async fn tcp_accept_task(
         tcp_listener: tokio::net::TcpListener, // The TCP listener to accept connections on
         topic_name: String,                    // Only used for logging
         responding_conn_header: ConnectionHeader, // Header we respond with
-        mut rx: broadcast::Receiver<Vec<u8>>, // Receives messages to publish from the main buffer of messages
+        mut rx: broadcast::Receiver<Arc<Vec<u8>>>, // Receives messages to publish from the main buffer of messages
+        outbox: Outbox,                            // Connects each accepted subscriber to the queue
         mut shutdown_rx: tokio::sync::mpsc::Receiver<()>, // Channel to signal to the publication to clean itself up
+        socket: SocketOptions,                            // Applied to each accepted connection
         nh: NodeServerHandle,
     ) {
         debug!("TCP accept task has started for publication: {topic_name}");
@@ -327,6 +480,9 @@ impl Publication {
             };
 
             info!("Received connection from subscriber at {peer_addr} for topic {topic_name}");
+            if let Err(e) = socket.apply(&stream) {
+                warn!("Failed to apply socket options for subscriber at {peer_addr}: {e:?}");
+            }
             // Read the connection header:
             let connection_header = match tcpros::receive_header(&mut stream).await {
                 Ok(header) => header,
@@ -344,16 +500,22 @@ impl Publication {
                 "Received subscribe request for {:?} with md5sum {:?}",
                 connection_header.topic, connection_header.md5sum
             );
+            // Subscribers ask for Nagle's algorithm to be disabled when they prefer latency over fewer packets
+            if connection_header.tcp_nodelay {
+                if let Err(e) = stream.set_nodelay(true) {
+                    warn!("Failed to set tcp_nodelay for subscriber at {peer_addr}: {e:?}");
+                }
+            }
             // I can't find documentation for this anywhere, but when using
             // `rostopic hz` with one of our publishers I discovered that the rospy code sent "*" as the md5sum
             // To indicate a "generic subscription"...
             // I also discovered that `rostopic echo` does not send a md5sum (even thou ros documentation says its required)
-            if let Some(connection_md5sum) = connection_header.md5sum {
+            if let Some(connection_md5sum) = &connection_header.md5sum {
                 if connection_md5sum != "*" {
                     if let Some(local_md5sum) = &responding_conn_header.md5sum {
                         // TODO(lucasw) is it ok to match any with "*"?
                         // if local_md5sum != "*" && connection_md5sum != *local_md5sum {
-                        if connection_md5sum != *local_md5sum {
+                        if connection_md5sum != local_md5sum {
                             warn!(
                                     "Got subscribe request for {}, but md5sums do not match. Expected {:?}, received {:?}",
                                     topic_name,
@@ -370,6 +532,10 @@ impl Publication {
                     }
                 }
             }
+            // roslibrust subscribers may ask for the messages to be compressed, which we confirm in our response
+            let compression = Compression::negotiate(connection_header.compression.as_deref());
+            let mut responding_conn_header = responding_conn_header.clone();
+            responding_conn_header.compression = compression.name().map(str::to_owned);
             // Write our own connection header in response
             let response_header_bytes = responding_conn_header
                 .to_bytes(false)
@@ -382,11 +548,29 @@ impl Publication {
             // Create a new task to handle writing to the TCP stream
             // Note: we continue to hold on to a root "rx" in this accept task that means that we
             // always keep the channel open from the receive side.
-            let rx_copy = rx.resubscribe();
+            let (rx_copy, written) = outbox.connect();
             let topic_name_copy = topic_name.clone();
             let last_message_copy = last_message.clone();
-            tokio::spawn(async move {
-                Self::publish_task(rx_copy, stream, topic_name_copy, last_message_copy).await;
+            let metrics = nh.metrics.clone();
+            let connection = nh.connections.register(
+                Direction::Outbound,
+                peer_addr.to_string(),
+                responding_conn_header.clone(),
+                connection_header.clone(),
+            );
+            let task_name = format!("ros1 publisher {topic_name} to {peer_addr}");
+            nh.spawner.spawn(task_name, async move {
+                Self::publish_task(
+                    rx_copy,
+                    written,
+                    stream,
+                    topic_name_copy,
+                    last_message_copy,
+                    compression,
+                    metrics,
+                    connection,
+                )
+                .await;
             });
 
             debug!(
@@ -417,3 +601,36 @@ impl From<roslibrust_serde_rosmsg::Error> for PublisherError {
         Self::SerializingError(value.to_string())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    #[test_log::test(tokio::test)]
+    async fn waits_for_connections_to_write() {
+        let (sender, _receiver) = broadcast::channel(10);
+        let outbox = Outbox::new(sender);
+        // Nobody to wait for
+        outbox.send(vec![0]).unwrap().written().await;
+
+        let (mut fast_rx, fast) = outbox.connect();
+        let (_slow_rx, slow) = outbox.connect();
+        let queued = outbox.send(vec![1]).unwrap();
+        let written = tokio::spawn(queued.written());
+        assert_eq!(*fast_rx.recv().await.unwrap(), vec![1]);
+        fast.send_replace(2);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!written.is_finished());
+
+        // A closed connection won't write the message
+        drop(slow);
+        tokio::time::timeout(Duration::from_secs(1), written)
+            .await
+            .unwrap()
+            .unwrap();
+        // Connections closed before publishing aren't waited for
+        outbox.send(vec![2]).unwrap();
+        assert_eq!(outbox.connections.lock().unwrap().len(), 1);
+    }
+}
@@ -1,12 +1,19 @@
 use crate::{
     names::Name,
+    node::Registry,
     tcpros::{establish_connection, ConnectionHeader},
+    Spawner,
 };
 use abort_on_drop::ChildTask;
 use roslibrust_common::{Error, RosServiceType};
-use std::{marker::PhantomData, sync::Arc};
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
 use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt},
+    io::{AsyncRead, AsyncReadExt, AsyncWriteExt, ReadBuf, Take},
     net::TcpStream,
     sync::{
         mpsc::{self, UnboundedReceiver},
@@ -16,8 +23,93 @@ use tokio::{
 
 use super::tcpros;
 
-pub type CallServiceRequest = (Vec<u8>, oneshot::Sender<CallServiceResponse>);
+pub enum CallServiceRequest {
+    /// The response is read into memory in full before being handed back
+    Buffered(Vec<u8>, oneshot::Sender<CallServiceResponse>),
+    /// The response body is handed back as a reader over the connection
+    Streaming(Vec<u8>, oneshot::Sender<StreamingServiceResponse>),
+}
 pub type CallServiceResponse = roslibrust_common::Result<Vec<u8>>;
+pub type StreamingServiceResponse = roslibrust_common::Result<ServiceResponseReader>;
+
+/// Reads the serialized body of a service response straight off the connection to the service server.
+///
+/// Returned by [ServiceClient::call_streaming]. The body is pulled from the socket only as fast as it is read, so
+/// very large responses (e.g. maps) never have to be held in memory at once. The bytes yielded are the ROS1
+/// serialized response without its leading length, which is available from [ServiceResponseReader::remaining]
+/// before anything has been read.
+///
+/// The connection is shared by every clone of the [ServiceClient], so other calls wait until this reader is dropped.
+/// Any part of the body left unread at that point is discarded.
+pub struct ServiceResponseReader {
+    body: Option<Take<TcpStream>>,
+    // Hands the connection back to the service client's actor once we're done with it
+    returner: Option<oneshot::Sender<Take<TcpStream>>>,
+}
+
+impl ServiceResponseReader {
+    /// Number of bytes of the response body which have not been read yet
+    pub fn remaining(&self) -> u64 {
+        self.body.as_ref().map_or(0, Take::limit)
+    }
+}
+
+impl AsyncRead for ServiceResponseReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        match self.get_mut().body.as_mut() {
+            Some(body) => Pin::new(body).poll_read(cx, buf),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+}
+
+impl Drop for ServiceResponseReader {
+    fn drop(&mut self) {
+        if let (Some(body), Some(returner)) = (self.body.take(), self.returner.take()) {
+            // If the actor is gone there is nobody left to use the connection
+            let _ = returner.send(body);
+        }
+    }
+}
+
+/// How a [ServiceClient] chooses between the nodes providing its service, see [ServiceClientOptions::providers].
+///
+/// A master only knows of the node which registered a service last, so with a master the providers are the one node
+/// the master knows of, found again after a failure. In masterless mode every node announcing the service is a provider.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProviderPolicy {
+    /// Calls the provider found when the client was created for as long as the client lives, the default
+    #[default]
+    Pinned,
+    /// Calls one provider until connecting to or calling it fails, then looks the service up again and moves on to
+    /// the next provider. The call which failed is not retried, as it may have been handled.
+    Failover,
+    /// Sends each call to the next provider in turn, keeping a connection to each, and moves past failed providers
+    /// as [ProviderPolicy::Failover] does.
+    RoundRobin,
+}
+
+/// Configures a service client created with [crate::NodeHandle::service_client_with_options].
+#[derive(Clone, Debug, Default)]
+pub struct ServiceClientOptions {
+    pub(crate) providers: ProviderPolicy,
+}
+
+impl ServiceClientOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Chooses between the providers of the service with `policy`, [ProviderPolicy::Pinned] by default.
+    pub fn providers(mut self, policy: ProviderPolicy) -> Self {
+        self.providers = policy;
+        self
+    }
+}
 
 // Note: ServiceClient is clone, and this is expressly different behavior than calling .service_client() twice on NodeHandle
 // clonning a ServiceClient does not create a new connection to the service, but instead creates a second handle to the
@@ -51,12 +143,11 @@ impl<T: RosServiceType> ServiceClient<T> {
     }
 
     pub async fn call(&self, request: &T::Request) -> std::result::Result<T::Response, Error> {
-        let request_payload = roslibrust_serde_rosmsg::to_vec(request)
-            .map_err(|err| Error::SerializationError(err.to_string()))?;
+        let request_payload = tcpros::serialize(request).map_err(Error::SerializationError)?;
         let (response_tx, response_rx) = oneshot::channel();
 
         self.sender
-            .send((request_payload, response_tx))
+            .send(CallServiceRequest::Buffered(request_payload, response_tx))
             .map_err(|_err| Error::Disconnected)?;
 
         match response_rx.await {
@@ -66,8 +157,8 @@ impl<T: RosServiceType> ServiceClient<T> {
                     self.service_name,
                     result_payload
                 );
-                let response: T::Response = roslibrust_serde_rosmsg::from_slice(&result_payload)
-                    .map_err(|err| Error::SerializationError(err.to_string()))?;
+                let response: T::Response =
+                    tcpros::deserialize(&result_payload).map_err(Error::SerializationError)?;
                 return Ok(response);
             }
             Ok(Err(err)) => {
@@ -78,6 +169,67 @@ impl<T: RosServiceType> ServiceClient<T> {
             }
         }
     }
+
+    /// Calls the service, but rather than buffering and deserializing the response hands back a reader
+    /// over its serialized bytes as they arrive from the service server.
+    ///
+    /// Intended for very large responses which are written to disk or decoded incrementally.
+    /// A failure response from the server is still returned as an error.
+    pub async fn call_streaming(
+        &self,
+        request: &T::Request,
+    ) -> std::result::Result<ServiceResponseReader, Error> {
+        let request_payload = tcpros::serialize(request).map_err(Error::SerializationError)?;
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.sender
+            .send(CallServiceRequest::Streaming(request_payload, response_tx))
+            .map_err(|_err| Error::Disconnected)?;
+
+        response_rx.await.map_err(|_err| Error::Disconnected)?
+    }
+}
+
+/// A client of a service whose type is only known at runtime, see [crate::NodeHandle::service_client_any].
+///
+/// Like [ServiceClient] it is a handle to the connection to the service, which is closed when the last clone is dropped.
+#[derive(Clone)]
+pub struct ServiceClientAny {
+    service_name: Name,
+    sender: mpsc::UnboundedSender<CallServiceRequest>,
+    _link: Arc<ServiceClientLink>,
+}
+
+impl ServiceClientAny {
+    pub(crate) fn new(
+        service_name: &Name,
+        sender: mpsc::UnboundedSender<CallServiceRequest>,
+        link: ServiceClientLink,
+    ) -> ServiceClientAny {
+        Self {
+            service_name: service_name.to_owned(),
+            sender,
+            _link: Arc::new(link),
+        }
+    }
+
+    pub fn service_name(&self) -> &Name {
+        &self.service_name
+    }
+
+    /// Calls the service with a serialized request and returns the serialized response.
+    ///
+    /// Both are the raw bytes of the message body as they appear on the wire, with the body length as the first four
+    /// bytes, as for [crate::PublisherAny::publish].
+    pub async fn call(&self, request: &[u8]) -> std::result::Result<Vec<u8>, Error> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.sender
+            .send(CallServiceRequest::Buffered(request.to_vec(), response_tx))
+            .map_err(|_err| Error::Disconnected)?;
+
+        response_rx.await.map_err(|_err| Error::Disconnected)?
+    }
 }
 
 pub struct ServiceClientLink {
@@ -86,13 +238,19 @@ pub struct ServiceClientLink {
 }
 
 impl ServiceClientLink {
-    pub async fn new(
+    /// Connects to the first of `service_uris` which accepts, later calls picking providers by `policy` and looking
+    /// them up again in `registry` after a failure
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn new(
         node_name: &Name,
         service_name: &str,
         service_type: &str,
-        service_uri: &str,
+        service_uris: Vec<String>,
         srv_definition: &str,
         md5sum: &str,
+        policy: ProviderPolicy,
+        registry: Registry,
+        spawner: &Spawner,
     ) -> roslibrust_common::Result<Self> {
         let header = ConnectionHeader {
             caller_id: node_name.to_string(),
@@ -107,18 +265,35 @@ impl ServiceClientLink {
             tcp_nodelay: false,
             // We do want a persistent connection to our service clients
             persistent: Some(true),
+            compression: None,
         };
 
         let (call_tx, call_rx) = mpsc::unbounded_channel::<CallServiceRequest>();
 
-        let stream = establish_connection(&node_name, &service_name, &service_uri, header).await.map_err(|err| {
-            log::error!("Failed to establish connection to service URI {service_uri} for service {service_name}: {err}");
+        let task_name = format!(
+            "ros1 service client {service_name} to {}",
+            service_uris.join(", ")
+        );
+        let mut providers = Providers::new(
+            node_name.clone(),
+            service_name.to_owned(),
+            header,
+            policy,
+            Some(registry),
+            service_uris,
+        );
+        // Connect right away so a service which can't be reached is reported when the client is created
+        let (index, stream) = providers.take().await.map_err(|err| {
+            log::error!("Failed to establish connection to service {service_name}: {err}");
             Error::from(err)
         })?;
+        providers.give_back(index, stream);
+        // The first call goes to the provider just connected to
+        providers.current = index;
 
-        let actor_context = Self::actor_context(stream, service_name.to_owned(), call_rx);
+        let actor_context = Self::actor_context(providers, call_rx);
 
-        let handle = tokio::spawn(actor_context);
+        let handle = spawner.spawn(task_name, actor_context);
 
         Ok(Self {
             call_sender: call_tx,
@@ -131,19 +306,62 @@ impl ServiceClientLink {
     }
 
     async fn actor_context(
-        mut stream: TcpStream,
-        service_name: String,
+        mut providers: Providers,
         mut call_rx: UnboundedReceiver<CallServiceRequest>,
     ) {
+        let service_name = providers.service_name.clone();
         // Listen on a receiver for calls to forward to the service
         loop {
-            match call_rx.recv().await {
-                Some(request) => {
-                    Self::handle_service_call(&mut stream, &service_name, request).await
+            let Some(call) = call_rx.recv().await else {
+                // Channel closed
+                break;
+            };
+            let (index, mut stream) = match providers.take().await {
+                Ok(connection) => connection,
+                Err(err) => {
+                    log::error!("Failed to connect to a provider of service {service_name}: {err}");
+                    // The caller may have given up on the call already
+                    match call {
+                        CallServiceRequest::Buffered(_, sender) => {
+                            let _ = sender.send(Err(Error::from(err)));
+                        }
+                        CallServiceRequest::Streaming(_, sender) => {
+                            let _ = sender.send(Err(Error::from(err)));
+                        }
+                    }
+                    continue;
                 }
-                None => {
-                    // Channel closed
-                    break;
+            };
+            match call {
+                CallServiceRequest::Buffered(request, response_sender) => {
+                    let usable = Self::handle_service_call(
+                        &mut stream,
+                        &service_name,
+                        request,
+                        response_sender,
+                    )
+                    .await;
+                    if usable {
+                        providers.give_back(index, stream);
+                    } else {
+                        providers.failed(index).await;
+                    }
+                }
+                CallServiceRequest::Streaming(request, response_sender) => {
+                    match Self::handle_streaming_call(
+                        stream,
+                        &service_name,
+                        request,
+                        response_sender,
+                    )
+                    .await
+                    {
+                        Some(returned) => providers.give_back(index, returned),
+                        None => {
+                            log::error!("Connection for service {service_name} was not returned by a streaming response");
+                            providers.failed(index).await;
+                        }
+                    }
                 }
             }
         }
@@ -151,12 +369,18 @@ impl ServiceClientLink {
 
     /// Infallible version of handle_service_call that regardless of what occurs
     /// Sends the response back on the response channel, delegates work to handle_service_call_fallible
+    /// Returns false if the connection can't be used for further calls
     async fn handle_service_call(
         stream: &mut TcpStream,
         service_name: &str,
-        (request, response_sender): CallServiceRequest,
-    ) {
+        request: Vec<u8>,
+        response_sender: oneshot::Sender<CallServiceResponse>,
+    ) -> bool {
         let response = Self::handle_service_call_fallible(stream, request).await;
+        // A failure response from the server leaves the connection ready for the next call
+        let usable = response
+            .as_ref()
+            .map_or_else(|err| err.kind() == std::io::ErrorKind::Other, |_| true);
         let response = response.map_err(|err| {
             log::error!(
                 "Failed to send and receive service call for service {service_name}: {err:?}"
@@ -167,6 +391,60 @@ impl ServiceClientLink {
         if let Err(_err) = send_result {
             log::error!("Failed to send service call result back to handle for service {service_name}, channel closed");
         }
+        usable
+    }
+
+    /// Streaming counterpart of handle_service_call
+    /// Lends the connection to a [ServiceResponseReader] and waits for it to come back,
+    /// returns None if the connection was lost along the way
+    async fn handle_streaming_call(
+        mut stream: TcpStream,
+        service_name: &str,
+        request: Vec<u8>,
+        response_sender: oneshot::Sender<StreamingServiceResponse>,
+    ) -> Option<TcpStream> {
+        let body_len = match Self::start_streaming_call(&mut stream, request).await {
+            Ok(body_len) => body_len,
+            Err(err) => {
+                log::error!(
+                    "Failed to send and receive service call for service {service_name}: {err:?}"
+                );
+                if response_sender.send(Err(Error::from(err))).is_err() {
+                    log::error!("Failed to send service call result back to handle for service {service_name}, channel closed");
+                }
+                return Some(stream);
+            }
+        };
+
+        let (returner, returned) = oneshot::channel();
+        let reader = ServiceResponseReader {
+            body: Some(stream.take(body_len as u64)),
+            returner: Some(returner),
+        };
+        if response_sender.send(Ok(reader)).is_err() {
+            // The reader is dropped here, which returns the connection to us right away
+            log::error!("Failed to send service call result back to handle for service {service_name}, channel closed");
+        }
+
+        let mut body = returned.await.ok()?;
+        // Throw away whatever the caller didn't read so the next response starts in the right place
+        if let Err(err) = tokio::io::copy(&mut body, &mut tokio::io::sink()).await {
+            log::error!("Failed to discard remainder of streamed response for service {service_name}: {err:?}");
+            return None;
+        }
+        Some(body.into_inner())
+    }
+
+    /// Sends the request and reads up to the start of the response body, returning its length
+    async fn start_streaming_call(
+        stream: &mut TcpStream,
+        request: Vec<u8>,
+    ) -> Result<u32, std::io::Error> {
+        stream.write_all(&request).await?;
+        Self::receive_response_status(stream).await?;
+        let mut body_len_bytes = [0u8; 4];
+        stream.read_exact(&mut body_len_bytes).await?;
+        Ok(u32::from_le_bytes(body_len_bytes))
     }
 
     /// Helper function for calling a service
@@ -179,6 +457,15 @@ impl ServiceClientLink {
         // Send the bytes of the request to the service
         stream.write_all(&request).await?;
 
+        Self::receive_response_status(stream).await?;
+        // Parse length of the payload body
+        let body = tcpros::receive_body(stream).await?;
+        Ok(body)
+    }
+
+    /// Reads the success byte that starts every service response
+    /// If the call failed the error message that follows is read and returned as an error
+    async fn receive_response_status(stream: &mut TcpStream) -> Result<(), std::io::Error> {
         // Service calls magically have an extra byte in the TCPROS spec that indicates success/failure
         let mut success_byte = [0u8; 1];
         let _success_byte_read = stream.read_exact(&mut success_byte).await?;
@@ -195,9 +482,7 @@ impl ServiceClientLink {
         let success = success_byte[0] == 1;
 
         if success {
-            // Parse length of the payload body
-            let body = tcpros::receive_body(stream).await?;
-            Ok(body)
+            Ok(())
         } else {
             // Parse an error message as the body
             let error_body = tcpros::receive_body(stream).await?;
@@ -217,3 +502,284 @@ impl ServiceClientLink {
         }
     }
 }
+
+/// The providers of a service client's service, with the connections to those it has called
+pub(crate) struct Providers {
+    node_name: Name,
+    service_name: String,
+    header: ConnectionHeader,
+    policy: ProviderPolicy,
+    // Looks the providers up again after a failure, None to keep calling those given
+    registry: Option<Registry>,
+    uris: Vec<String>,
+    // The open connection to each provider, taken while a call is using it
+    connections: Vec<Option<TcpStream>>,
+    // The provider called next
+    current: usize,
+}
+
+impl Providers {
+    fn new(
+        node_name: Name,
+        service_name: String,
+        header: ConnectionHeader,
+        policy: ProviderPolicy,
+        registry: Option<Registry>,
+        uris: Vec<String>,
+    ) -> Self {
+        Self {
+            node_name,
+            service_name,
+            header,
+            policy,
+            registry,
+            connections: uris.iter().map(|_| None).collect(),
+            uris,
+            current: 0,
+        }
+    }
+
+    /// Takes the connection to the provider to call next, connecting to it first if needed.
+    /// Providers which can't be connected to are skipped unless the client is pinned to its provider.
+    async fn take(&mut self) -> Result<(usize, TcpStream), std::io::Error> {
+        if self.uris.is_empty() {
+            self.refresh().await;
+        }
+        let attempts = match self.policy {
+            ProviderPolicy::Pinned => 1,
+            ProviderPolicy::Failover | ProviderPolicy::RoundRobin => self.uris.len(),
+        };
+        let mut error = None;
+        for _ in 0..attempts {
+            let Some(uri) = self.uris.get(self.current).cloned() else {
+                break;
+            };
+            let index = self.current;
+            if self.policy == ProviderPolicy::RoundRobin {
+                self.current = (index + 1) % self.uris.len();
+            }
+            if let Some(stream) = self.connections[index].take() {
+                return Ok((index, stream));
+            }
+            match establish_connection(
+                &self.node_name,
+                &self.service_name,
+                &uri,
+                self.header.clone(),
+            )
+            .await
+            {
+                Ok(stream) => return Ok((index, stream)),
+                Err(err) => {
+                    log::warn!(
+                        "Failed to connect to {uri} providing service {}: {err}",
+                        self.service_name
+                    );
+                    error = Some(err);
+                    self.failed(index).await;
+                }
+            }
+        }
+        Err(error.unwrap_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("No providers of service {}", self.service_name),
+            )
+        }))
+    }
+
+    /// Returns the connection to a provider after a call which left it usable
+    fn give_back(&mut self, index: usize, stream: TcpStream) {
+        if let Some(connection) = self.connections.get_mut(index) {
+            *connection = Some(stream);
+        }
+    }
+
+    /// Drops the connection to a provider which failed, and unless pinned looks the providers up again and moves on
+    async fn failed(&mut self, index: usize) {
+        if let Some(connection) = self.connections.get_mut(index) {
+            *connection = None;
+        }
+        if self.policy == ProviderPolicy::Pinned {
+            return;
+        }
+        let failed = self.uris.get(index).cloned();
+        self.refresh().await;
+        // Move on to the provider after the one which failed
+        self.current =
+            match failed.and_then(|failed| self.uris.iter().position(|uri| *uri == failed)) {
+                Some(position) => (position + 1) % self.uris.len(),
+                None if self.uris.is_empty() => 0,
+                None => self.current % self.uris.len(),
+            };
+    }
+
+    /// Looks the providers up again, keeping the connections to those still around
+    async fn refresh(&mut self) {
+        let Some(registry) = &self.registry else {
+            return;
+        };
+        registry.forget_service(&self.service_name);
+        match registry.lookup_service_providers(&self.service_name).await {
+            Ok(uris) => {
+                let mut connections = std::mem::take(&mut self.connections);
+                self.connections = uris
+                    .iter()
+                    .map(|uri| {
+                        let kept = self.uris.iter().position(|known| known == uri);
+                        kept.and_then(|kept| connections[kept].take())
+                    })
+                    .collect();
+                self.uris = uris;
+            }
+            Err(err) => log::warn!(
+                "Failed to look up the providers of service {} again: {err}",
+                self.service_name
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Spawns a fake service server which answers each request with a body of `body_len` bytes counting up from 0,
+    /// hanging up after `calls` requests
+    async fn fake_server(body_len: u32, calls: usize) -> TcpStream {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut server, _) = listener.accept().await.unwrap();
+            for _ in 0..calls {
+                if tcpros::receive_body(&mut server).await.is_err() {
+                    break;
+                }
+                let body: Vec<u8> = (0..body_len).map(|i| i as u8).collect();
+                server.write_all(&[1]).await.unwrap();
+                server.write_all(&body_len.to_le_bytes()).await.unwrap();
+                for chunk in body.chunks(1000) {
+                    // Bail out quietly if the client hangs up on us
+                    if server.write_all(chunk).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        TcpStream::connect(addr).await.unwrap()
+    }
+
+    /// Providers already connected to `streams`, which can't be connected to again
+    fn connected(policy: ProviderPolicy, streams: Vec<TcpStream>) -> Providers {
+        let header = ConnectionHeader {
+            caller_id: "/client".to_owned(),
+            latching: false,
+            msg_definition: String::new(),
+            md5sum: Some("*".to_owned()),
+            topic: None,
+            service: Some("/fake".to_owned()),
+            topic_type: "test_msgs/Fake".to_owned(),
+            tcp_nodelay: false,
+            persistent: Some(true),
+            compression: None,
+        };
+        // Nothing listens on the lowest ports, so reconnecting fails straight away
+        let uris = (1..=streams.len()).map(|port| format!("127.0.0.1:{port}"));
+        let mut providers = Providers::new(
+            Name::new("/client").unwrap(),
+            "/fake".to_owned(),
+            header,
+            policy,
+            None,
+            uris.collect(),
+        );
+        providers.connections = streams.into_iter().map(Some).collect();
+        providers
+    }
+
+    async fn buffered_call(
+        sender: &mpsc::UnboundedSender<CallServiceRequest>,
+    ) -> CallServiceResponse {
+        let (tx, rx) = oneshot::channel();
+        sender
+            .send(CallServiceRequest::Buffered(vec![0, 0, 0, 0], tx))
+            .unwrap();
+        rx.await.unwrap()
+    }
+
+    async fn streaming_call(
+        sender: &mpsc::UnboundedSender<CallServiceRequest>,
+    ) -> ServiceResponseReader {
+        let (tx, rx) = oneshot::channel();
+        sender
+            .send(CallServiceRequest::Streaming(vec![0, 0, 0, 0], tx))
+            .unwrap();
+        rx.await.unwrap().unwrap()
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn streams_responses() {
+        const BODY_LEN: u32 = 1_000_000;
+        let stream = fake_server(BODY_LEN, usize::MAX).await;
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let _actor = ChildTask::from(tokio::spawn(ServiceClientLink::actor_context(
+            connected(ProviderPolicy::Pinned, vec![stream]),
+            receiver,
+        )));
+
+        // Stop part way through, the rest of the body has to be skipped for the next call to work
+        let mut reader = streaming_call(&sender).await;
+        assert_eq!(reader.remaining(), BODY_LEN as u64);
+        let mut start = [0u8; 10];
+        reader.read_exact(&mut start).await.unwrap();
+        assert_eq!(start, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        drop(reader);
+
+        let mut reader = streaming_call(&sender).await;
+        let mut body = vec![];
+        reader.read_to_end(&mut body).await.unwrap();
+        assert_eq!(body.len(), BODY_LEN as usize);
+        assert_eq!(body[257], 1);
+        assert_eq!(reader.remaining(), 0);
+        drop(reader);
+
+        // Buffered calls share the same connection
+        let (tx, rx) = oneshot::channel();
+        sender
+            .send(CallServiceRequest::Buffered(vec![0, 0, 0, 0], tx))
+            .unwrap();
+        let body = rx.await.unwrap().unwrap();
+        assert_eq!(body.len(), BODY_LEN as usize + 4);
+        assert_eq!(&body[..4], &BODY_LEN.to_le_bytes());
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn balances_between_providers() {
+        // The length of each response tells which provider answered
+        let streams = vec![
+            fake_server(1, usize::MAX).await,
+            fake_server(2, usize::MAX).await,
+        ];
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let _actor = ChildTask::from(tokio::spawn(ServiceClientLink::actor_context(
+            connected(ProviderPolicy::RoundRobin, streams),
+            receiver,
+        )));
+        for expected in [5, 6, 5, 6] {
+            assert_eq!(buffered_call(&sender).await.unwrap().len(), expected);
+        }
+
+        // The first provider goes away after one call, the one failing with it is not retried
+        let streams = vec![fake_server(1, 1).await, fake_server(2, usize::MAX).await];
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let _actor = ChildTask::from(tokio::spawn(ServiceClientLink::actor_context(
+            connected(ProviderPolicy::Failover, streams),
+            receiver,
+        )));
+        assert_eq!(buffered_call(&sender).await.unwrap().len(), 5);
+        assert!(buffered_call(&sender).await.is_err());
+        assert_eq!(buffered_call(&sender).await.unwrap().len(), 6);
+        assert_eq!(buffered_call(&sender).await.unwrap().len(), 6);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::tcpros::ConnectionHeader;
+use log::*;
+use std::{
+    collections::{HashSet, VecDeque},
+    net::SocketAddr,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// A request made to a service server, as seen by [ServiceMiddleware].
+pub struct ServiceRequest<'a> {
+    /// Name of the service being called
+    pub service: &'a str,
+    /// Address of the client's end of the connection
+    pub peer_addr: SocketAddr,
+    /// Connection header the client sent when it connected, `caller_id` is the name of the calling node
+    pub header: &'a ConnectionHeader,
+    /// The serialized request
+    pub body: &'a [u8],
+}
+
+/// Runs around each request to a service server, see [ServiceOptions::layer].
+pub trait ServiceMiddleware: Send + Sync + 'static {
+    /// Called before the request is handed to the service function.
+    /// Returning an error rejects the request, sending the error to the caller instead of calling the function.
+    fn before(&self, _request: &ServiceRequest) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called once the service function returned the serialized response or its error message, with how long it took.
+    /// Not called for requests rejected by a middleware.
+    fn after(&self, _request: &ServiceRequest, _response: Result<&[u8], &str>, _elapsed: Duration) {
+    }
+}
+
+/// Configures a service server created with [crate::NodeHandle::advertise_service_with_options].
+#[derive(Clone)]
+pub struct ServiceOptions {
+    pub(crate) middleware: Vec<Arc<dyn ServiceMiddleware>>,
+    pub(crate) drain_timeout: Duration,
+}
+
+impl Default for ServiceOptions {
+    fn default() -> Self {
+        Self {
+            middleware: vec![],
+            drain_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl ServiceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How long the requests being processed when the server is dropped or its node shuts down may take to finish,
+    /// 5 seconds by default. The server stops accepting connections straight away, and closes idle persistent
+    /// connections, but only closes the connections of requests still being processed once this runs out, so
+    /// callers get their responses rather than having their connection reset mid-call.
+    pub fn drain_timeout(mut self, drain_timeout: Duration) -> Self {
+        self.drain_timeout = drain_timeout;
+        self
+    }
+
+    /// Wraps the service function in `middleware`.
+    ///
+    /// The layer added first is the outermost: its [ServiceMiddleware::before] runs first and
+    /// its [ServiceMiddleware::after] last, and a request it rejects isn't seen by any later layer.
+    pub fn layer(mut self, middleware: impl ServiceMiddleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+}
+
+/// Logs every request with its caller, and every response with how long it took.
+pub struct RequestLogger {
+    level: Level,
+}
+
+impl Default for RequestLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestLogger {
+    /// Logs at info level, with errors from the service function at warn level.
+    pub fn new() -> Self {
+        Self { level: Level::Info }
+    }
+
+    /// Logs at `level` instead, errors from the service function are still logged at warn level or higher.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+}
+
+impl ServiceMiddleware for RequestLogger {
+    fn before(&self, request: &ServiceRequest) -> Result<(), String> {
+        log!(
+            self.level,
+            "Service {} called by {} ({}) with {} bytes",
+            request.service,
+            request.header.caller_id,
+            request.peer_addr,
+            request.body.len()
+        );
+        Ok(())
+    }
+
+    fn after(&self, request: &ServiceRequest, response: Result<&[u8], &str>, elapsed: Duration) {
+        match response {
+            Ok(response) => log!(
+                self.level,
+                "Service {} responded to {} with {} bytes in {elapsed:?}",
+                request.service,
+                request.header.caller_id,
+                response.len()
+            ),
+            Err(e) => log!(
+                self.level.min(Level::Warn),
+                "Service {} failed for {} in {elapsed:?}: {e}",
+                request.service,
+                request.header.caller_id
+            ),
+        }
+    }
+}
+
+/// Rejects requests beyond `max_requests` within any `period`, across all callers.
+pub struct RateLimit {
+    max_requests: usize,
+    period: Duration,
+    // When each request within the last period was accepted
+    accepted: Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimit {
+    pub fn new(max_requests: usize, period: Duration) -> Self {
+        Self {
+            max_requests,
+            period,
+            accepted: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Allows at most `hz` requests per second on average, in bursts of up to `hz` requests.
+    pub fn per_second(hz: usize) -> Self {
+        Self::new(hz, Duration::from_secs(1))
+    }
+}
+
+impl ServiceMiddleware for RateLimit {
+    fn before(&self, request: &ServiceRequest) -> Result<(), String> {
+        let now = Instant::now();
+        let mut accepted = self.accepted.lock().unwrap_or_else(PoisonError::into_inner);
+        while accepted
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= self.period)
+        {
+            accepted.pop_front();
+        }
+        if accepted.len() >= self.max_requests {
+            debug!(
+                "Rejecting request to {} from {}, rate limited",
+                request.service, request.header.caller_id
+            );
+            return Err(format!(
+                "Service {} is rate limited to {} requests per {:?}",
+                request.service, self.max_requests, self.period
+            ));
+        }
+        accepted.push_back(now);
+        Ok(())
+    }
+}
+
+type Validator = Box<dyn Fn(&ServiceRequest) -> Result<(), String> + Send + Sync + 'static>;
+
+/// Rejects requests from callers failing a check on their [ServiceRequest], typically on the connection header.
+///
+/// ROS1 doesn't authenticate connection headers, so checks on them keep out misconfigured nodes rather than
+/// attackers able to connect to the service's port.
+pub struct ValidateCaller {
+    validator: Validator,
+}
+
+impl ValidateCaller {
+    /// Rejects requests for which `validator` returns an error, which is sent to the caller.
+    pub fn new(
+        validator: impl Fn(&ServiceRequest) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            validator: Box::new(validator),
+        }
+    }
+
+    /// Only accepts requests from the nodes named in `callers`, compared with the header's `caller_id`.
+    pub fn allow_callers(callers: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let callers: HashSet<String> = callers.into_iter().map(Into::into).collect();
+        Self::new(move |request| {
+            if callers.contains(&request.header.caller_id) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Caller {} isn't allowed to call {}",
+                    request.header.caller_id, request.service
+                ))
+            }
+        })
+    }
+}
+
+impl ServiceMiddleware for ValidateCaller {
+    fn before(&self, request: &ServiceRequest) -> Result<(), String> {
+        let result = (self.validator)(request);
+        if let Err(e) = &result {
+            warn!(
+                "Rejecting request to {} from {} ({}): {e}",
+                request.service, request.header.caller_id, request.peer_addr
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn header(caller_id: &str) -> ConnectionHeader {
+        ConnectionHeader {
+            caller_id: caller_id.to_string(),
+            latching: false,
+            msg_definition: String::new(),
+            md5sum: Some("*".to_string()),
+            service: Some("/add_two_ints".to_string()),
+            topic: None,
+            topic_type: "test_msgs/AddTwoInts".to_string(),
+            tcp_nodelay: false,
+            persistent: None,
+            compression: None,
+        }
+    }
+
+    fn request(header: &ConnectionHeader) -> ServiceRequest<'_> {
+        ServiceRequest {
+            service: "/add_two_ints",
+            peer_addr: "127.0.0.1:1234".parse().unwrap(),
+            header,
+            body: &[],
+        }
+    }
+
+    #[test_log::test]
+    fn rate_limit_rejects_bursts() {
+        let limit = RateLimit::new(2, Duration::from_millis(100));
+        let header = header("/client");
+        assert!(limit.before(&request(&header)).is_ok());
+        assert!(limit.before(&request(&header)).is_ok());
+        assert!(limit.before(&request(&header)).is_err());
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(limit.before(&request(&header)).is_ok());
+    }
+
+    #[test_log::test]
+    fn validates_callers() {
+        let validate = ValidateCaller::allow_callers(["/planner"]);
+        assert!(validate.before(&request(&header("/planner"))).is_ok());
+        let rejected = validate.before(&request(&header("/intruder")));
+        assert_eq!(
+            rejected,
+            Err("Caller /intruder isn't allowed to call /add_two_ints".to_string())
+        );
+    }
+}
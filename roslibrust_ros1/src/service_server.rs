@@ -1,13 +1,17 @@
 use std::{
     net::{Ipv4Addr, SocketAddr},
     sync::Arc,
+    time::Duration,
 };
 
 use abort_on_drop::ChildTask;
 use log::*;
 use tokio::io::AsyncWriteExt;
+use tokio::sync::watch;
 
+use crate::service_middleware::{ServiceMiddleware, ServiceRequest};
 use crate::tcpros::{self, ConnectionHeader};
+use crate::Spawner;
 
 use super::{names::Name, NodeHandle, TypeErasedCallback};
 
@@ -39,11 +43,19 @@ impl Drop for ServiceServer {
     }
 }
 
+/// The type erased service function, the middleware wrapping it, and how long it may finish requests for after the
+/// server is dropped, see [crate::ServiceOptions]
+pub(crate) struct ServiceHandler {
+    pub(crate) callback: Box<TypeErasedCallback>,
+    pub(crate) middleware: Vec<Arc<dyn ServiceMiddleware>>,
+    pub(crate) drain_timeout: Duration,
+}
+
 /// Internal type held by the NodeServer to keep track of a given service server
 pub(crate) struct ServiceServerLink {
-    // Handle to internal task that is accepting and processing new requests
-    // Task is automatically aborted when link is dropped
-    _child_task: ChildTask<()>,
+    // Dropped along with the link, which tells the internal task accepting and processing requests to stop accepting
+    // connections and to finish the requests in flight before closing the rest
+    _shutdown: watch::Sender<()>,
     port: u16,
     service_name: String,
 }
@@ -55,14 +67,16 @@ impl Drop for ServiceServerLink {
 }
 
 impl ServiceServerLink {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn new(
-        method: Box<TypeErasedCallback>,
+        method: ServiceHandler,
         host_addr: Ipv4Addr,
         service_name: Name,
         node_name: Name,
         service_type: String, // name of the message type e.g. "std_srvs/Trigger"
         md5sum: String,       // md5sum of the service message type
         srv_definition: String, // Full text of the service message type definition
+        spawner: Spawner,     // Spawns the tasks serving requests
     ) -> Result<Self, std::io::Error> {
         // TODO A lot of this is duplicated with publisher
         // We could probably move chunks into tcpros.rs and re-use
@@ -75,8 +89,10 @@ impl ServiceServerLink {
             .expect("Bound tcp address did not have local address")
             .port();
         let service_name_copy = service_name.to_string();
+        let (shutdown, shutdown_receiver) = watch::channel(());
 
-        let task = tokio::spawn(Self::actor(
+        let task_name = format!("ros1 service server {service_name} accepting on :{port}");
+        let actor = Self::actor(
             tcp_listener,
             service_name,
             node_name,
@@ -84,10 +100,14 @@ impl ServiceServerLink {
             service_type,
             md5sum,
             srv_definition,
-        ));
+            spawner.clone(),
+            shutdown_receiver,
+        );
+        // Detached, the task ends by itself once it has drained after the link is dropped
+        spawner.spawn(task_name, actor);
 
         Ok(Self {
-            _child_task: task.into(),
+            _shutdown: shutdown,
             port,
             service_name: service_name_copy,
         })
@@ -101,35 +121,48 @@ impl ServiceServerLink {
 
     /// Internal static function that actually operates the service server
     /// When new() is called as task is spawned that runs this function
+    #[allow(clippy::too_many_arguments)]
     async fn actor(
         listener: tokio::net::TcpListener,
         service_name: Name, // Service path of the this service
         node_name: Name,    // Name of node we're running on
-        method: Box<TypeErasedCallback>,
+        method: ServiceHandler,
         service_type: String,
         md5sum: String,
         srv_definition: String,
+        spawner: Spawner,
+        mut shutdown: watch::Receiver<()>,
     ) {
         // We have to move our callback into an Arc so the separately spawned tasks for each service connection
         // can access it in parrallel and not worry about the lifetime.
         // TODO: it may be better to Arc it upfront?
+        let drain_timeout = method.drain_timeout;
         let arc_method = Arc::new(method);
         // Tasks list here is needed to ensure that dropping this future drops child futures
         let mut tasks: Vec<ChildTask<()>> = vec![];
         loop {
-            // Accept new TCP connections
-            match listener.accept().await {
+            // Accept new TCP connections until the link is dropped
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.changed() => break,
+            };
+            match accepted {
                 Ok((stream, peer_addr)) => {
-                    let task = tokio::spawn(Self::handle_tcp_connection(
-                        stream,
-                        peer_addr,
-                        service_name.clone(),
-                        node_name.clone(),
-                        arc_method.clone(),
-                        service_type.clone(),
-                        md5sum.clone(),
-                        srv_definition.clone(),
-                    ));
+                    let task_name = format!("ros1 service server {service_name} to {peer_addr}");
+                    let task = spawner.spawn(
+                        task_name,
+                        Self::handle_tcp_connection(
+                            stream,
+                            peer_addr,
+                            service_name.clone(),
+                            node_name.clone(),
+                            arc_method.clone(),
+                            service_type.clone(),
+                            md5sum.clone(),
+                            srv_definition.clone(),
+                            shutdown.clone(),
+                        ),
+                    );
                     // Add spawned task to child task list to ensure dropping shuts down server
                     tasks.push(task.into());
                 }
@@ -140,19 +173,34 @@ impl ServiceServerLink {
                 }
             };
         }
+
+        // Stop accepting connections, and give the requests in flight until the drain timeout to finish
+        drop(listener);
+        let drained = tokio::time::timeout(drain_timeout, async {
+            for task in tasks.iter_mut() {
+                let _ = task.await;
+            }
+        })
+        .await;
+        if drained.is_err() {
+            warn!("Service {service_name} didn't finish its requests within {drain_timeout:?}, closing their connections");
+        }
+        // Dropping the tasks closes the connections of any requests still being processed
     }
 
     /// Each TCP connection made to the service server is processed in a separate task
     /// This function handles a single TCP connection
+    #[allow(clippy::too_many_arguments)]
     async fn handle_tcp_connection(
         mut stream: tokio::net::TcpStream,
         peer_addr: SocketAddr,
         service_name: Name,
         node_name: Name,
-        method: Arc<Box<TypeErasedCallback>>,
+        method: Arc<ServiceHandler>,
         service_type: String,
         md5sum: String,
         srv_definition: String,
+        mut shutdown: watch::Receiver<()>,
     ) {
         // TODO for a bunch of the error branches in this handling
         // it is unclear whether we should respond over the socket
@@ -184,6 +232,7 @@ impl ServiceServerLink {
             topic_type: service_type.to_string(),
             tcp_nodelay: false,
             persistent: None,
+            compression: None,
         };
         let bytes = response_header.to_bytes(false).unwrap();
         if let Err(e) = stream.write_all(&bytes).await {
@@ -194,7 +243,15 @@ impl ServiceServerLink {
 
         // Each loop is one body:
         loop {
-            let full_body = match tcpros::receive_body(&mut stream).await {
+            let received = tokio::select! {
+                received = tcpros::receive_body(&mut stream) => received,
+                // A server being shut down closes connections between requests, never while it processes one
+                _ = shutdown.changed() => {
+                    debug!("Service server for {service_name} shut down, closing connection from {peer_addr}");
+                    return;
+                }
+            };
+            let full_body = match received {
                 Ok(body) => body,
                 Err(e) => {
                     // Note this was degraded to debug! from warn! as every single use client produces this message
@@ -204,10 +261,56 @@ impl ServiceServerLink {
                 }
             };
 
+            // Middleware sees the request before the user's function, and can reject it
+            let request = ServiceRequest {
+                service: service_name.as_str(),
+                peer_addr,
+                header: &connection_header,
+                body: &full_body,
+            };
+            let rejection = method
+                .middleware
+                .iter()
+                .find_map(|middleware| middleware.before(&request).err());
+            if let Some(rejection) = rejection {
+                let error_bytes = roslibrust_serde_rosmsg::to_vec(&rejection).unwrap();
+                let full_response = [vec![0u8], error_bytes].concat();
+                stream.write_all(&full_response).await.unwrap();
+                if let Some(true) = connection_header.persistent {
+                    continue;
+                }
+                break;
+            }
+
             // This is the actual invocation of the service function registered by the user
             // Because the user could register a function that blocks we want to wrap it in a tokio::spawn_blocking
             let method_clone = method.clone();
-            let response = tokio::task::spawn_blocking(move || (method_clone)(full_body)).await;
+            // The function consumes the request, so a copy is only kept when middleware needs to see it afterwards
+            let kept_body = (!method.middleware.is_empty()).then(|| full_body.clone());
+            let started = std::time::Instant::now();
+            let response =
+                tokio::task::spawn_blocking(move || (method_clone.callback)(full_body)).await;
+
+            if let (Ok(response), Some(body)) = (&response, &kept_body) {
+                let elapsed = started.elapsed();
+                let request = ServiceRequest {
+                    service: service_name.as_str(),
+                    peer_addr,
+                    header: &connection_header,
+                    body,
+                };
+                let error_string;
+                let outcome = match response {
+                    Ok(response) => Ok(response.as_slice()),
+                    Err(e) => {
+                        error_string = format!("{e:?}");
+                        Err(error_string.as_str())
+                    }
+                };
+                for middleware in method.middleware.iter().rev() {
+                    middleware.after(&request, outcome, elapsed);
+                }
+            }
 
             match response {
                 // User's function worked
@@ -254,3 +357,70 @@ impl ServiceServerLink {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::net::TcpStream;
+
+    #[test_log::test(tokio::test)]
+    async fn drains_requests_in_flight() {
+        // Echoes each request back after taking a while to process it
+        let method = ServiceHandler {
+            callback: Box::new(|request| {
+                std::thread::sleep(Duration::from_millis(200));
+                Ok(request)
+            }),
+            middleware: vec![],
+            drain_timeout: Duration::from_secs(1),
+        };
+        let link = ServiceServerLink::new(
+            method,
+            Ipv4Addr::LOCALHOST,
+            Name::new("/slow").unwrap(),
+            Name::new("/server").unwrap(),
+            "test_msgs/Slow".to_string(),
+            "*".to_string(),
+            String::new(),
+            Spawner::new(tokio::runtime::Handle::current()),
+        )
+        .await
+        .unwrap();
+        let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, link.port()));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let header = ConnectionHeader {
+            caller_id: "/client".to_string(),
+            latching: false,
+            msg_definition: String::new(),
+            md5sum: Some("*".to_string()),
+            service: Some("/slow".to_string()),
+            topic: None,
+            topic_type: "test_msgs/Slow".to_string(),
+            tcp_nodelay: false,
+            persistent: Some(true),
+            compression: None,
+        };
+        stream
+            .write_all(&header.to_bytes(false).unwrap())
+            .await
+            .unwrap();
+        tcpros::receive_header(&mut stream).await.unwrap();
+        let request = [4u32.to_le_bytes(), 42u32.to_le_bytes()].concat();
+        stream.write_all(&request).await.unwrap();
+
+        // Dropped while the request is being processed
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        std::mem::drop(link);
+
+        let mut ok = [0u8];
+        tokio::io::AsyncReadExt::read_exact(&mut stream, &mut ok)
+            .await
+            .unwrap();
+        assert_eq!(ok, [1]);
+        assert_eq!(tcpros::receive_body(&mut stream).await.unwrap(), request);
+        // The persistent connection is closed once its request is answered, and no new ones are accepted
+        assert!(tcpros::receive_body(&mut stream).await.is_err());
+        assert!(TcpStream::connect(addr).await.is_err());
+    }
+}
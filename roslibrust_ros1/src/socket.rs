@@ -0,0 +1,175 @@
+//! Socket level tuning and compression of the TCPROS connections of publishers and subscribers, see [SocketOptions].
+
+use crate::Compression;
+use socket2::{SockRef, TcpKeepalive};
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::{lookup_host, TcpListener, TcpSocket, TcpStream, ToSocketAddrs};
+
+/// Options applied to each TCPROS connection of a publisher or subscriber, all left to the operating system's
+/// defaults unless set.
+///
+/// Sustaining high bandwidth topics such as point clouds at gigabit rates usually needs socket buffers larger than
+/// the defaults, as messages arriving while the buffer is full are dropped by the kernel and retransmitted:
+/// ```no_run
+/// # async fn f(nh: roslibrust_ros1::NodeHandle) -> Result<(), roslibrust_ros1::NodeError> {
+/// use roslibrust_ros1::SocketOptions;
+/// use roslibrust_test::ros1::sensor_msgs;
+///
+/// let options = SocketOptions::new().recv_buffer_size(16 * 1024 * 1024);
+/// let subscriber = nh
+///     .subscribe_with_options::<sensor_msgs::PointCloud2>("/points", 10, options)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+/// Linux caps buffer sizes at `net.core.rmem_max` and `net.core.wmem_max`, which may need raising with sysctl for
+/// large buffers to take effect. A warning is logged when a buffer ends up smaller than requested.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SocketOptions {
+    recv_buffer_size: Option<usize>,
+    send_buffer_size: Option<usize>,
+    keepalive: Option<(Duration, Duration)>,
+    linger: Option<Duration>,
+    compression: Compression,
+}
+
+impl SocketOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets SO_RCVBUF, the bytes the kernel buffers for a connection before the application reads them.
+    /// Set before connecting, so the TCP window can scale to it.
+    pub fn recv_buffer_size(mut self, bytes: usize) -> Self {
+        self.recv_buffer_size = Some(bytes);
+        self
+    }
+
+    /// Sets SO_SNDBUF, the bytes the kernel buffers for a connection before they are sent
+    pub fn send_buffer_size(mut self, bytes: usize) -> Self {
+        self.send_buffer_size = Some(bytes);
+        self
+    }
+
+    /// Enables TCP keepalive, probing an idle connection after `idle` and then every `interval`, so connections to
+    /// peers which vanished without closing them are noticed
+    pub fn keepalive(mut self, idle: Duration, interval: Duration) -> Self {
+        self.keepalive = Some((idle, interval));
+        self
+    }
+
+    /// Sets SO_LINGER, how long closing a connection waits for unsent data to be delivered
+    pub fn linger(mut self, linger: Duration) -> Self {
+        self.linger = Some(linger);
+        self
+    }
+
+    /// Asks publishers to compress the messages they send a subscriber, see [Compression].
+    /// Has no effect on publishers, which compress for the subscribers which ask for it.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub(crate) fn get_compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Connects to `addr` with the options applied, the buffer sizes before connecting
+    pub(crate) async fn connect(&self, addr: impl ToSocketAddrs) -> io::Result<TcpStream> {
+        let mut error = None;
+        for addr in lookup_host(addr).await? {
+            let socket = match addr {
+                SocketAddr::V4(_) => TcpSocket::new_v4()?,
+                SocketAddr::V6(_) => TcpSocket::new_v6()?,
+            };
+            self.set_buffer_sizes(&SockRef::from(&socket))?;
+            match socket.connect(addr).await {
+                Ok(stream) => {
+                    self.apply(&stream)?;
+                    return Ok(stream);
+                }
+                Err(err) => error = Some(err),
+            }
+        }
+        Err(error.unwrap_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "No addresses to connect to")
+        }))
+    }
+
+    /// Listens on `addr` with the buffer sizes applied, which connections accepted from it inherit
+    pub(crate) fn listen(&self, addr: SocketAddr) -> io::Result<TcpListener> {
+        let socket = match addr {
+            SocketAddr::V4(_) => TcpSocket::new_v4()?,
+            SocketAddr::V6(_) => TcpSocket::new_v6()?,
+        };
+        #[cfg(unix)]
+        socket.set_reuseaddr(true)?;
+        self.set_buffer_sizes(&SockRef::from(&socket))?;
+        socket.bind(addr)?;
+        socket.listen(1024)
+    }
+
+    /// Applies the options to a connected or accepted stream
+    pub(crate) fn apply(&self, stream: &TcpStream) -> io::Result<()> {
+        let socket = SockRef::from(stream);
+        self.set_buffer_sizes(&socket)?;
+        if let Some((idle, interval)) = self.keepalive {
+            let keepalive = TcpKeepalive::new().with_time(idle).with_interval(interval);
+            socket.set_tcp_keepalive(&keepalive)?;
+        }
+        if let Some(linger) = self.linger {
+            socket.set_linger(Some(linger))?;
+        }
+        Ok(())
+    }
+
+    fn set_buffer_sizes(&self, socket: &SockRef<'_>) -> io::Result<()> {
+        if let Some(bytes) = self.recv_buffer_size {
+            socket.set_recv_buffer_size(bytes)?;
+            let actual = socket.recv_buffer_size()?;
+            if actual < bytes {
+                log::warn!("Receive buffer of {bytes} bytes was limited to {actual} bytes by the operating system, see net.core.rmem_max");
+            }
+        }
+        if let Some(bytes) = self.send_buffer_size {
+            socket.set_send_buffer_size(bytes)?;
+            let actual = socket.send_buffer_size()?;
+            if actual < bytes {
+                log::warn!("Send buffer of {bytes} bytes was limited to {actual} bytes by the operating system, see net.core.wmem_max");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test_log::test(tokio::test)]
+    async fn applies_socket_options() {
+        // Small enough to stay below the operating system's limits
+        let options = SocketOptions::new()
+            .recv_buffer_size(64 * 1024)
+            .send_buffer_size(64 * 1024)
+            .keepalive(Duration::from_secs(10), Duration::from_secs(2))
+            .linger(Duration::from_secs(1));
+        let listener = options.listen("127.0.0.1:0".parse().unwrap()).unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (client, accepted) = tokio::join!(options.connect(addr), listener.accept());
+        let client = client.unwrap();
+        let (accepted, _) = accepted.unwrap();
+        options.apply(&accepted).unwrap();
+
+        for stream in [&client, &accepted] {
+            let socket = SockRef::from(stream);
+            assert!(socket.recv_buffer_size().unwrap() >= 64 * 1024);
+            assert!(socket.send_buffer_size().unwrap() >= 64 * 1024);
+            assert!(socket.keepalive().unwrap());
+            assert_eq!(socket.linger().unwrap(), Some(Duration::from_secs(1)));
+        }
+    }
+}
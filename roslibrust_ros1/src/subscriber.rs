@@ -1,82 +1,231 @@
-use crate::{names::Name, tcpros::ConnectionHeader};
+use crate::{
+    compression::Compression,
+    connections::{ConnectionRegistry, Direction},
+    names::Name,
+    socket::SocketOptions,
+    tcpros::ConnectionHeader,
+    Spawner,
+};
 use abort_on_drop::ChildTask;
 use log::*;
-use roslibrust_common::{RosMessageType, ShapeShifter};
+use roslibrust_common::{ros1_codec::Encoded, ChannelMetrics, RosMessageType, ShapeShifter};
 use std::{marker::PhantomData, sync::Arc};
 use tokio::{
     io::AsyncWriteExt,
     net::TcpStream,
     sync::{
-        broadcast::{self, error::RecvError},
+        broadcast::{
+            self,
+            error::{RecvError, TryRecvError},
+        },
         RwLock,
     },
 };
 
 use super::tcpros;
 
+/// What a subscriber does when it falls behind the messages received on its topic.
+///
+/// All subscribers to a topic on a node share one connection to each of its publishers, and the received messages
+/// are fanned out to them through a channel holding the `queue_size` of the first subscriber. Each subscriber reads
+/// at its own pace, and one falling more than the queue size behind misses the oldest messages without affecting
+/// the others. The policy is chosen per subscriber with [Subscriber::with_lag_policy].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LagPolicy {
+    /// Return [SubscriberError::Lagged] with the number of messages missed, then continue from the oldest queued one
+    #[default]
+    Error,
+    /// Log the number of messages missed and continue from the oldest queued one
+    Skip,
+    /// Drop everything queued and continue from the most recently received message
+    Latest,
+}
+
+/// What a subscriber does with a message it fails to deserialize, e.g. from a publisher with a mismatched definition.
+///
+/// The data of the most recent message which failed is kept in every case, see [Subscriber::last_undecodable].
+/// The policy is chosen per subscriber with [Subscriber::with_decode_error_policy].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecodeErrorPolicy {
+    /// Return [SubscriberError::DeserializeError], then continue with the next message
+    #[default]
+    Error,
+    /// Log the error and continue with the next message
+    Skip,
+    /// Return [SubscriberError::DeserializeError], then end the subscription, returning None from then on
+    Close,
+}
+
+/// Receives the next message's data, handling falling behind according to the policy
+async fn recv(
+    receiver: &mut broadcast::Receiver<Vec<u8>>,
+    policy: LagPolicy,
+    metrics: &ChannelMetrics,
+) -> Option<Result<Vec<u8>, SubscriberError>> {
+    loop {
+        match receiver.recv().await {
+            Ok(data) => return Some(Ok(data)),
+            Err(RecvError::Closed) => return None,
+            Err(RecvError::Lagged(n)) => {
+                metrics.record_subscriber_dropped(n);
+                match policy {
+                    LagPolicy::Error => return Some(Err(SubscriberError::Lagged(n))),
+                    LagPolicy::Skip => warn!("Subscriber fell behind and skipped {n} messages"),
+                    LagPolicy::Latest => {
+                        let mut latest = None;
+                        loop {
+                            match receiver.try_recv() {
+                                Ok(data) => {
+                                    if latest.replace(data).is_some() {
+                                        metrics.record_subscriber_dropped(1);
+                                    }
+                                }
+                                Err(TryRecvError::Lagged(n)) => {
+                                    metrics.record_subscriber_dropped(n)
+                                }
+                                Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+                            }
+                        }
+                        if let Some(data) = latest {
+                            return Some(Ok(data));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub struct Subscriber<T> {
     receiver: broadcast::Receiver<Vec<u8>>,
+    lag_policy: LagPolicy,
+    decode_error_policy: DecodeErrorPolicy,
+    // Data of the most recent message which failed to deserialize
+    undecodable: Option<Vec<u8>>,
+    closed: bool,
+    metrics: ChannelMetrics,
     _phantom: PhantomData<T>,
 }
 
 impl<T: RosMessageType> Subscriber<T> {
-    pub(crate) fn new(receiver: broadcast::Receiver<Vec<u8>>) -> Self {
+    pub(crate) fn new(receiver: broadcast::Receiver<Vec<u8>>, metrics: ChannelMetrics) -> Self {
         Self {
             receiver,
+            lag_policy: LagPolicy::default(),
+            decode_error_policy: DecodeErrorPolicy::default(),
+            undecodable: None,
+            closed: false,
+            metrics,
             _phantom: PhantomData,
         }
     }
 
+    /// Sets what this subscriber does when it falls behind, [LagPolicy::Error] by default
+    pub fn with_lag_policy(mut self, lag_policy: LagPolicy) -> Self {
+        self.lag_policy = lag_policy;
+        self
+    }
+
+    /// Sets what this subscriber does with messages it fails to deserialize, [DecodeErrorPolicy::Error] by default
+    pub fn with_decode_error_policy(mut self, decode_error_policy: DecodeErrorPolicy) -> Self {
+        self.decode_error_policy = decode_error_policy;
+        self
+    }
+
+    /// The serialized data of the most recent message which failed to deserialize, kept regardless of the
+    /// [DecodeErrorPolicy] for logging or inspecting what a misbehaving publisher sent.
+    pub fn last_undecodable(&self) -> Option<&[u8]> {
+        self.undecodable.as_deref()
+    }
+
     pub async fn next(&mut self) -> Option<Result<T, SubscriberError>> {
-        trace!("Subscriber of type {:?} awaiting recv()", T::ROS_TYPE_NAME);
-        let data = match self.receiver.recv().await {
-            Ok(v) => {
-                trace!("Subscriber of type {:?} received data", T::ROS_TYPE_NAME);
-                v
+        loop {
+            if self.closed {
+                return None;
             }
-            Err(RecvError::Closed) => return None,
-            Err(RecvError::Lagged(n)) => return Some(Err(SubscriberError::Lagged(n))),
-        };
-        trace!(
-            "Subscriber of type {:?} deserializing data",
-            T::ROS_TYPE_NAME
-        );
-        let tick = tokio::time::Instant::now();
-        match roslibrust_serde_rosmsg::from_slice::<T>(&data[..]) {
-            Ok(p) => {
-                let duration = tick.elapsed();
-                trace!(
-                    "Subscriber of type {:?} deserialized data in {duration:?}",
-                    T::ROS_TYPE_NAME
-                );
-                Some(Ok(p))
+            trace!("Subscriber of type {:?} awaiting recv()", T::ROS_TYPE_NAME);
+            let data = match recv(&mut self.receiver, self.lag_policy, &self.metrics).await? {
+                Ok(v) => {
+                    trace!("Subscriber of type {:?} received data", T::ROS_TYPE_NAME);
+                    v
+                }
+                Err(e) => return Some(Err(e)),
+            };
+            trace!(
+                "Subscriber of type {:?} deserializing data",
+                T::ROS_TYPE_NAME
+            );
+            let tick = tokio::time::Instant::now();
+            match tcpros::deserialize::<T>(&data[..]) {
+                Ok(p) => {
+                    let duration = tick.elapsed();
+                    trace!(
+                        "Subscriber of type {:?} deserialized data in {duration:?}",
+                        T::ROS_TYPE_NAME
+                    );
+                    return Some(Ok(p));
+                }
+                Err(e) => {
+                    self.undecodable = Some(data);
+                    match self.decode_error_policy {
+                        DecodeErrorPolicy::Error => {
+                            return Some(Err(SubscriberError::DeserializeError(e)))
+                        }
+                        DecodeErrorPolicy::Skip => {
+                            warn!(
+                                "Skipping a {} which failed to deserialize: {e}",
+                                T::ROS_TYPE_NAME
+                            )
+                        }
+                        DecodeErrorPolicy::Close => {
+                            error!("Closing subscriber after failing to deserialize: {e}");
+                            self.closed = true;
+                            return Some(Err(SubscriberError::DeserializeError(e)));
+                        }
+                    }
+                }
             }
-            Err(e) => Some(Err(e.into())),
         }
     }
+
+    /// Waits for the next message without deserializing it, returning its data to be decoded on demand.
+    /// Types generated with `CodegenOptions::ros1_borrowed` can then be decoded with [Encoded::decode_borrowed],
+    /// referencing the data instead of copying their strings and byte arrays.
+    pub async fn next_encoded(&mut self) -> Option<Result<Encoded<T>, SubscriberError>> {
+        Some(
+            recv(&mut self.receiver, self.lag_policy, &self.metrics)
+                .await?
+                .map(Encoded::new),
+        )
+    }
 }
 
 pub struct SubscriberAny {
     receiver: broadcast::Receiver<Vec<u8>>,
+    lag_policy: LagPolicy,
+    metrics: ChannelMetrics,
     _phantom: PhantomData<ShapeShifter>,
 }
 
 impl SubscriberAny {
-    pub(crate) fn new(receiver: broadcast::Receiver<Vec<u8>>) -> Self {
+    pub(crate) fn new(receiver: broadcast::Receiver<Vec<u8>>, metrics: ChannelMetrics) -> Self {
         Self {
             receiver,
+            lag_policy: LagPolicy::default(),
+            metrics,
             _phantom: PhantomData,
         }
     }
 
+    /// Sets what this subscriber does when it falls behind, [LagPolicy::Error] by default
+    pub fn with_lag_policy(mut self, lag_policy: LagPolicy) -> Self {
+        self.lag_policy = lag_policy;
+        self
+    }
+
     // pub async fn next(&mut self) -> Option<Result<ShapeShifter, SubscriberError>> {
     pub async fn next(&mut self) -> Option<Result<Vec<u8>, SubscriberError>> {
-        let data = match self.receiver.recv().await {
-            Ok(v) => v,
-            Err(RecvError::Closed) => return None,
-            Err(RecvError::Lagged(n)) => return Some(Err(SubscriberError::Lagged(n))),
-        };
-        Some(Ok(data))
+        recv(&mut self.receiver, self.lag_policy, &self.metrics).await
     }
 }
 
@@ -86,9 +235,13 @@ pub struct Subscription {
     msg_sender: broadcast::Sender<Vec<u8>>,
     connection_header: ConnectionHeader,
     known_publishers: Arc<RwLock<Vec<String>>>,
+    spawner: Spawner,
+    connections: ConnectionRegistry,
+    socket: SocketOptions,
 }
 
 impl Subscription {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         node_name: &Name,
         topic_name: &str,
@@ -96,6 +249,9 @@ impl Subscription {
         queue_size: usize,
         msg_definition: String,
         md5sum: String,
+        spawner: Spawner,
+        connections: ConnectionRegistry,
+        socket: SocketOptions,
     ) -> Self {
         let (sender, receiver) = broadcast::channel(queue_size);
         let connection_header = ConnectionHeader {
@@ -108,6 +264,7 @@ impl Subscription {
             tcp_nodelay: false,
             service: None,
             persistent: None,
+            compression: socket.get_compression().name().map(str::to_owned),
         };
 
         Self {
@@ -116,6 +273,9 @@ impl Subscription {
             msg_sender: sender,
             connection_header,
             known_publishers: Arc::new(RwLock::new(vec![])),
+            spawner,
+            connections,
+            socket,
         }
     }
 
@@ -146,18 +306,34 @@ impl Subscription {
             let connection_header = self.connection_header.clone();
             let sender = self.msg_sender.clone();
             let publisher_list = self.known_publishers.clone();
+            let connections = self.connections.clone();
+            let socket = self.socket.clone();
             let publisher_uri = publisher_uri.to_owned();
             trace!("Creating new subscription connection for {publisher_uri} on {topic_name}");
-            let handle = tokio::spawn(async move {
-                if let Ok(mut stream) = establish_publisher_connection(
+            let task_name = format!("ros1 subscriber {topic_name} from {publisher_uri}");
+            let handle = self.spawner.spawn(task_name, async move {
+                if let Ok((mut stream, responded_header)) = establish_publisher_connection(
                     &node_name,
                     &topic_name,
                     &publisher_uri,
-                    connection_header,
+                    connection_header.clone(),
+                    &socket,
                 )
                 .await
                 {
                     publisher_list.write().await.push(publisher_uri.to_owned());
+                    // Publishers which aren't roslibrust nodes leave out the compression we asked for
+                    let compression = responded_header
+                        .compression
+                        .as_deref()
+                        .and_then(Compression::from_name)
+                        .unwrap_or_default();
+                    let connection = connections.register(
+                        Direction::Inbound,
+                        publisher_uri.clone(),
+                        connection_header,
+                        responded_header,
+                    );
                     // Repeatedly read from the stream until its dry
                     loop {
                         trace!(
@@ -172,6 +348,14 @@ impl Subscription {
                                     topic_name,
                                     publisher_uri
                                 );
+                                connection.record(1, body.len());
+                                let body = match compression.decompress(body) {
+                                    Ok(body) => body,
+                                    Err(e) => {
+                                        log::error!("Failed to decompress message from publisher: {e}, closing connection");
+                                        break;
+                                    }
+                                };
                                 let send_result = sender.send(body);
                                 if let Err(err) = send_result {
                                     log::error!("Unable to send message data due to dropped channel, closing connection: {err}");
@@ -198,9 +382,10 @@ async fn establish_publisher_connection(
     topic_name: &str,
     publisher_uri: &str,
     conn_header: ConnectionHeader,
-) -> Result<TcpStream, std::io::Error> {
+    socket: &SocketOptions,
+) -> Result<(TcpStream, ConnectionHeader), std::io::Error> {
     let publisher_channel_uri = send_topic_request(node_name, topic_name, publisher_uri).await?;
-    let mut stream = TcpStream::connect(publisher_channel_uri).await?;
+    let mut stream = socket.connect(publisher_channel_uri).await?;
 
     let conn_header_bytes = conn_header.to_bytes(true)?;
     stream.write_all(&conn_header_bytes[..]).await?;
@@ -214,7 +399,7 @@ async fn establish_publisher_connection(
                 "Established connection with publisher for {:?}",
                 conn_header.topic
             );
-            Ok(stream)
+            Ok((stream, responded_header))
         } else {
             log::error!(
                 "Tried to subscribe to {}, but md5sums do not match. Expected {:?}, received {:?}",
@@ -231,6 +416,32 @@ async fn establish_publisher_connection(
     .map_err(std::io::Error::from)
 }
 
+/// Connects to a publisher as if subscribing with any type, returning the connection header it responds with,
+/// which holds the type, md5sum and definition it publishes
+pub(crate) async fn probe_publisher(
+    node_name: &Name,
+    topic_name: &str,
+    publisher_uri: &str,
+) -> Result<ConnectionHeader, std::io::Error> {
+    let conn_header = ConnectionHeader {
+        caller_id: node_name.to_string(),
+        latching: false,
+        msg_definition: String::new(),
+        md5sum: Some("*".to_owned()),
+        topic: Some(topic_name.to_owned()),
+        topic_type: "*".to_owned(),
+        tcp_nodelay: false,
+        service: None,
+        persistent: None,
+        compression: None,
+    };
+    let publisher_channel_uri =
+        send_topic_request(node_name.as_str(), topic_name, publisher_uri).await?;
+    let mut stream = TcpStream::connect(publisher_channel_uri).await?;
+    stream.write_all(&conn_header.to_bytes(true)?).await?;
+    tcpros::receive_header(&mut stream).await
+}
+
 async fn send_topic_request(
     node_name: &str,
     topic_name: &str,
@@ -301,3 +512,81 @@ impl From<roslibrust_serde_rosmsg::Error> for SubscriberError {
         Self::DeserializeError(value.to_string())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Queues three messages in a channel holding two, so the receiver misses the first
+    fn lagged_receiver() -> broadcast::Receiver<Vec<u8>> {
+        let (sender, receiver) = broadcast::channel(2);
+        for i in 0..3 {
+            sender.send(vec![i]).unwrap();
+        }
+        receiver
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn lag_policies() {
+        let metrics = ChannelMetrics::default();
+        let mut receiver = lagged_receiver();
+        assert!(matches!(
+            recv(&mut receiver, LagPolicy::Error, &metrics).await,
+            Some(Err(SubscriberError::Lagged(1)))
+        ));
+        let next = recv(&mut receiver, LagPolicy::Error, &metrics).await;
+        assert_eq!(next.unwrap().unwrap(), [1]);
+
+        let mut receiver = lagged_receiver();
+        let next = recv(&mut receiver, LagPolicy::Skip, &metrics).await;
+        assert_eq!(next.unwrap().unwrap(), [1]);
+        let next = recv(&mut receiver, LagPolicy::Skip, &metrics).await;
+        assert_eq!(next.unwrap().unwrap(), [2]);
+
+        let mut receiver = lagged_receiver();
+        let next = recv(&mut receiver, LagPolicy::Latest, &metrics).await;
+        assert_eq!(next.unwrap().unwrap(), [2]);
+        assert!(recv(&mut receiver, LagPolicy::Latest, &metrics)
+            .await
+            .is_none());
+        // Each policy missed the first message, and the latest also skipped the second
+        assert_eq!(metrics.subscriber_dropped(), 4);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn decode_error_policies() {
+        use roslibrust_test::ros1::std_msgs;
+
+        let valid = roslibrust_serde_rosmsg::to_vec(&std_msgs::String {
+            data: "hello".to_owned(),
+        })
+        .unwrap();
+        // Claims a longer string than it holds
+        let invalid = vec![8, 0, 0, 0, 100, 0, 0, 0, 1, 2, 3, 4];
+        let subscriber = |policy| {
+            let (sender, receiver) = broadcast::channel(4);
+            sender.send(invalid.clone()).unwrap();
+            sender.send(valid.clone()).unwrap();
+            let subscriber =
+                Subscriber::<std_msgs::String>::new(receiver, ChannelMetrics::default())
+                    .with_decode_error_policy(policy);
+            (sender, subscriber)
+        };
+
+        let (_sender, mut errors) = subscriber(DecodeErrorPolicy::Error);
+        assert!(matches!(
+            errors.next().await,
+            Some(Err(SubscriberError::DeserializeError(_)))
+        ));
+        assert_eq!(errors.last_undecodable(), Some(&invalid[..]));
+        assert_eq!(errors.next().await.unwrap().unwrap().data, "hello");
+
+        let (_sender, mut skips) = subscriber(DecodeErrorPolicy::Skip);
+        assert_eq!(skips.next().await.unwrap().unwrap().data, "hello");
+        assert_eq!(skips.last_undecodable(), Some(&invalid[..]));
+
+        let (_sender, mut closes) = subscriber(DecodeErrorPolicy::Close);
+        assert!(matches!(closes.next().await, Some(Err(_))));
+        assert!(closes.next().await.is_none());
+    }
+}
@@ -1,8 +1,11 @@
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use log::*;
-use std::io::{Cursor, Read, Write};
+use roslibrust_common::RosMessageType;
+use std::io::{Cursor, IoSlice, Read, Write};
+use tokio::io::AsyncWrite;
 use tokio::net::TcpStream;
 
+use super::compression;
 use super::names::Name;
 
 // Implementation of ConnectionHeader is based off of ROS documentation here:
@@ -22,6 +25,9 @@ pub struct ConnectionHeader {
     pub topic_type: String,
     pub tcp_nodelay: bool, // TODO this field should be optional and None for service clients and servers
     pub persistent: Option<bool>,
+    /// Name of the algorithm messages are compressed with, requested by roslibrust subscribers and confirmed by
+    /// roslibrust publishers, see [crate::Compression]
+    pub compression: Option<String>,
     // TODO service server only has to respond with caller_id (all other fields optional)
 }
 
@@ -41,6 +47,7 @@ impl ConnectionHeader {
         let mut topic_type = String::new();
         let mut tcp_nodelay = false;
         let mut persistent = None;
+        let mut compression = None;
 
         // TODO: Unhandled: error, persistent
         while cursor.position() < header_data.len() as u64 {
@@ -85,6 +92,8 @@ impl ConnectionHeader {
                 let mut persistent_str = String::new();
                 field[equals_pos + 1..].clone_into(&mut persistent_str);
                 persistent = Some(&persistent_str != "0");
+            } else if field.starts_with(&format!("{}=", compression::HEADER_FIELD)) {
+                compression = Some(field[equals_pos + 1..].to_owned());
             } else if field.starts_with("probe=") {
                 // probe is apprantly an undocumented header field that is sent
                 // by certain ros tools when they initiate a service_client connection to a service server
@@ -112,6 +121,7 @@ impl ConnectionHeader {
             topic_type,
             tcp_nodelay,
             persistent,
+            compression,
         };
         trace!(
             "Got connection header: {header:?} for topic {:?}",
@@ -130,48 +140,54 @@ impl ConnectionHeader {
 
         let caller_id_str = format!("callerid={}", self.caller_id);
         header_data.write_u32::<LittleEndian>(caller_id_str.len() as u32)?;
-        header_data.write(caller_id_str.as_bytes())?;
+        header_data.write_all(caller_id_str.as_bytes())?;
 
         let latching_str = format!("latching={}", if self.latching { 1 } else { 0 });
         header_data.write_u32::<LittleEndian>(latching_str.len() as u32)?;
-        header_data.write(latching_str.as_bytes())?;
+        header_data.write_all(latching_str.as_bytes())?;
 
         if let Some(md5sum) = self.md5sum.as_ref() {
             let md5sum = format!("md5sum={}", md5sum);
             header_data.write_u32::<LittleEndian>(md5sum.len() as u32)?;
-            header_data.write(md5sum.as_bytes())?;
+            header_data.write_all(md5sum.as_bytes())?;
         }
 
         let msg_definition = format!("message_definition={}", self.msg_definition);
         header_data.write_u32::<LittleEndian>(msg_definition.len() as u32)?;
-        header_data.write(msg_definition.as_bytes())?;
+        header_data.write_all(msg_definition.as_bytes())?;
 
         if to_publisher {
             let tcp_nodelay = format!("tcp_nodelay={}", if self.tcp_nodelay { 1 } else { 0 });
             header_data.write_u32::<LittleEndian>(tcp_nodelay.len() as u32)?;
-            header_data.write(tcp_nodelay.as_bytes())?;
+            header_data.write_all(tcp_nodelay.as_bytes())?;
         }
 
         if let Some(topic) = self.topic.as_ref() {
             let topic = format!("topic={}", topic);
             header_data.write_u32::<LittleEndian>(topic.len() as u32)?;
-            header_data.write(topic.as_bytes())?;
+            header_data.write_all(topic.as_bytes())?;
         }
 
         if let Some(service) = self.service.as_ref() {
             let service = format!("service={}", service);
             header_data.write_u32::<LittleEndian>(service.len() as u32)?;
-            header_data.write(service.as_bytes())?;
+            header_data.write_all(service.as_bytes())?;
         }
 
         let topic_type = format!("type={}", self.topic_type);
         header_data.write_u32::<LittleEndian>(topic_type.len() as u32)?;
-        header_data.write(topic_type.as_bytes())?;
+        header_data.write_all(topic_type.as_bytes())?;
 
         if let Some(persistent) = self.persistent {
             let persistent = format!("persistent={}", if persistent { 1 } else { 0 });
             header_data.write_u32::<LittleEndian>(persistent.len() as u32)?;
-            header_data.write(persistent.as_bytes())?;
+            header_data.write_all(persistent.as_bytes())?;
+        }
+
+        if let Some(compression) = self.compression.as_ref() {
+            let compression = format!("{}={compression}", compression::HEADER_FIELD);
+            header_data.write_u32::<LittleEndian>(compression.len() as u32)?;
+            header_data.write_all(compression.as_bytes())?;
         }
 
         // Now that we know the length, stick its value in the first 4 bytes
@@ -285,6 +301,45 @@ pub async fn receive_body(stream: &mut TcpStream) -> Result<Vec<u8>, std::io::Er
     Ok(body)
 }
 
+/// Serializes a message including its length at the front,
+/// with the message's generated ROS1 encoder if it has one and serde_rosmsg otherwise
+pub(crate) fn serialize<T: RosMessageType>(msg: &T) -> Result<Vec<u8>, String> {
+    match msg.ros1_encode() {
+        Some(bytes) => Ok(bytes),
+        None => roslibrust_serde_rosmsg::to_vec(msg).map_err(|e| e.to_string()),
+    }
+}
+
+/// Deserializes a message which has its length at the front,
+/// with the message's generated ROS1 decoder if it has one and serde_rosmsg otherwise
+pub(crate) fn deserialize<T: RosMessageType>(data: &[u8]) -> Result<T, String> {
+    match T::ros1_decode(data) {
+        Some(msg) => msg.map_err(|e| e.to_string()),
+        None => roslibrust_serde_rosmsg::from_slice(data).map_err(|e| e.to_string()),
+    }
+}
+
+/// Writes all of the buffers in order, passing as many as the writer accepts to each vectored write
+/// so that several messages can be sent with one syscall and without copying them into one buffer
+pub(crate) async fn write_all_vectored<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    buffers: &[&[u8]],
+) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+    let mut slices: Vec<IoSlice> = buffers.iter().map(|buffer| IoSlice::new(buffer)).collect();
+    let mut slices = &mut slices[..];
+    // Drops any empty buffers at the front, which would otherwise look like a failed write
+    IoSlice::advance_slices(&mut slices, 0);
+    while !slices.is_empty() {
+        let written = writer.write_vectored(slices).await?;
+        if written == 0 {
+            return Err(std::io::ErrorKind::WriteZero.into());
+        }
+        IoSlice::advance_slices(&mut slices, written);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::ConnectionHeader;
@@ -339,4 +394,23 @@ mod test {
             Some("992ce8a1687cec8c8bd883ec73ca41d1".to_string())
         );
     }
+
+    /// Confirms buffers are written in order when the writer only takes part of them at a time
+    #[test_log::test(tokio::test)]
+    async fn writes_all_vectored() {
+        use tokio::io::AsyncReadExt;
+        // The duplex writes only as much of the first buffer as fits, exercising partial writes
+        let (mut writer, mut reader) = tokio::io::duplex(3);
+        let buffers: [&[u8]; 4] = [&[], &[1, 2], &[3, 4, 5, 6, 7], &[8]];
+        let read = tokio::spawn(async move {
+            let mut data = vec![];
+            reader.read_to_end(&mut data).await.unwrap();
+            data
+        });
+        super::write_all_vectored(&mut writer, &buffers)
+            .await
+            .unwrap();
+        drop(writer);
+        assert_eq!(read.await.unwrap(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
 }
@@ -1,23 +1,25 @@
 use crate::comm::Ops;
 use crate::comm::RosBridgeComm;
+use crate::dynamic::{self, DynamicMessage};
 use crate::{Publisher, ServiceHandle, Subscriber};
 use anyhow::anyhow;
 use dashmap::DashMap;
 use futures::StreamExt;
 use log::*;
+use roslibrust_common::schema::MessageSchema;
 use roslibrust_common::*;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError};
 use tokio::sync::RwLock;
 use tokio::time::Duration;
 use tokio_tungstenite::tungstenite::Message;
 
 use super::{
-    MessageQueue, PublisherHandle, Reader, ServiceCallback, ServiceClient, Socket, Subscription,
-    Writer, QUEUE_SIZE,
+    MessageQueue, PublisherHandle, Reader, ServiceCallback, ServiceClient, Socket, Spawner,
+    Subscription, Writer, QUEUE_SIZE,
 };
 
 /// Builder options for creating a client
@@ -25,6 +27,9 @@ use super::{
 pub struct ClientHandleOptions {
     url: String,
     timeout: Option<Duration>,
+    channel_config: ChannelConfig,
+    runtime: Option<tokio::runtime::Handle>,
+    check_publish_types: bool,
 }
 
 impl ClientHandleOptions {
@@ -33,6 +38,9 @@ impl ClientHandleOptions {
         ClientHandleOptions {
             url: url.into(),
             timeout: None,
+            channel_config: ChannelConfig::default(),
+            runtime: None,
+            check_publish_types: false,
         }
     }
 
@@ -43,6 +51,34 @@ impl ClientHandleOptions {
         self.timeout = Some(duration.into());
         self
     }
+
+    /// Configures the size of each subscriber's queue with `subscriber_queue_size`, 1000 messages by default.
+    /// When a queue is full its oldest message is dropped and counted in [ClientHandle::channel_metrics].
+    pub fn channel_config(mut self, channel_config: ChannelConfig) -> ClientHandleOptions {
+        self.channel_config = channel_config;
+        self
+    }
+
+    /// Runs the client's connection and internal tasks on `runtime` instead of the runtime the client is created on,
+    /// e.g. a dedicated runtime on pinned threads isolating pub/sub from the rest of the application.
+    /// The client stops working if `runtime` is shut down.
+    ///
+    /// Clients created outside of a tokio runtime, e.g. by an application using async-std or smol, run on a shared
+    /// background runtime unless one is given here, see [roslibrust_common::runtime].
+    pub fn runtime(mut self, runtime: tokio::runtime::Handle) -> ClientHandleOptions {
+        self.runtime = Some(runtime);
+        self
+    }
+
+    /// Makes [ClientHandle::advertise] look up the type of an existing topic with rosapi's `topic_type` service,
+    /// failing with [Error::SerializationError] if it isn't the type being advertised. Off by default.
+    ///
+    /// Without the check rosbridge accepts the advertisement and then drops every publish which doesn't match the
+    /// topic's type, reporting it only in its own logs. rosapi must be running alongside rosbridge.
+    pub fn check_publish_types(mut self, check: bool) -> ClientHandleOptions {
+        self.check_publish_types = check;
+        self
+    }
 }
 
 /// The ClientHandle is the fundamental object through which users of this library are expected to interact with it.
@@ -74,6 +110,12 @@ impl ClientHandleOptions {
 pub struct ClientHandle {
     pub(crate) inner: Arc<RwLock<Client>>,
     pub(crate) is_disconnected: Arc<AtomicBool>,
+    // Counts of the messages dropped from full subscriber queues, shared by all clones
+    metrics: ChannelMetrics,
+    // Spawns the client's tasks on its runtime and lists them, shared by all clones
+    spawner: Spawner,
+    // Remappings applied to the names of topics and services, shared by all clones
+    remappings: Arc<std::sync::RwLock<Remappings>>,
 }
 
 impl ClientHandle {
@@ -84,7 +126,21 @@ impl ClientHandle {
     /// This function respects the [ClientHandleOptions] timeout and will return with an error if a connection is not
     /// established within the timeout.
     pub async fn new_with_options(opts: ClientHandleOptions) -> Result<Self> {
-        let inner = Arc::new(RwLock::new(timeout(opts.timeout, Client::new(opts)).await?));
+        let spawner = Spawner::new(
+            opts.runtime
+                .clone()
+                .unwrap_or_else(roslibrust_common::runtime::handle),
+        );
+        let task_name = format!("rosbridge client {}", opts.url);
+        // Connecting on the client's runtime registers the socket with it
+        let connect = spawner.spawn(
+            format!("rosbridge connect {}", opts.url),
+            timeout(opts.timeout, Client::new(opts)),
+        );
+        let client = connect
+            .await
+            .map_err(|e| Error::Unexpected(anyhow!("Connecting to rosbridge failed: {e}")))??;
+        let inner = Arc::new(RwLock::new(client));
         let inner_weak = Arc::downgrade(&inner);
 
         // We connect when we create Client
@@ -92,14 +148,31 @@ impl ClientHandle {
 
         // Spawn the spin task
         // The internal stubborn spin task continues to try to reconnect on failure
-        let _ = tokio::task::spawn(stubborn_spin(inner_weak, is_disconnected.clone()));
+        spawner.spawn(
+            task_name,
+            stubborn_spin(inner_weak, is_disconnected.clone()),
+        );
 
         Ok(ClientHandle {
             inner,
             is_disconnected,
+            metrics: ChannelMetrics::default(),
+            spawner,
+            remappings: Arc::default(),
         })
     }
 
+    /// Counts of the messages dropped because a subscriber's queue was full, shared by all clones of this handle
+    pub fn channel_metrics(&self) -> &ChannelMetrics {
+        &self.metrics
+    }
+
+    /// The background tasks this client is running, e.g. to unsubscribe after a subscriber was dropped.
+    /// Shared by all clones of this handle, useful for finding tasks which never finish.
+    pub fn tasks(&self) -> Vec<TaskInfo> {
+        self.spawner.registry.tasks()
+    }
+
     /// Connects a rosbridge instance at the given url
     /// Expects a fully describe websocket url, e.g. 'ws://localhost:9090'
     /// When awaited will not resolve until connection is successfully made.
@@ -107,6 +180,31 @@ impl ClientHandle {
         Self::new_with_options(ClientHandleOptions::new(url)).await
     }
 
+    /// Remaps the topic or service name `from` to `to` for every publisher, subscriber, service and service call this
+    /// client makes afterwards, like a `from:=to` command line argument, e.g.
+    /// `client.remap("/cmd_vel", "/robot1/cmd_vel")`. Relative names are resolved in the root namespace.
+    /// Shared by all clones of this handle, and doesn't affect publishers, subscribers and services which already exist.
+    pub fn remap(&self, from: &str, to: &str) -> Result<()> {
+        self.remappings
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(from, to)
+    }
+
+    // The name `name` is remapped to, or `name` itself if no remapping matches it
+    fn remapped(&self, name: &str) -> Result<String> {
+        let remappings = self
+            .remappings
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        if remappings.is_empty() {
+            return Ok(name.to_string());
+        }
+        Ok(remappings
+            .lookup(name, None)?
+            .unwrap_or_else(|| name.to_string()))
+    }
+
     fn check_for_disconnect(&self) -> Result<()> {
         match self.is_disconnected.load(Ordering::Relaxed) {
             false => Ok(()),
@@ -118,6 +216,22 @@ impl ClientHandle {
     async fn _subscribe<Msg>(&self, topic_name: &str) -> Result<Subscriber<Msg>>
     where
         Msg: RosMessageType,
+    {
+        self._subscribe_with(topic_name, Msg::ROS_TYPE_NAME, |data| {
+            serde_json::from_str::<Msg>(data)
+        })
+        .await
+    }
+
+    // Internal implementation of subscribe, converting each message received with `parse`
+    async fn _subscribe_with<Msg>(
+        &self,
+        topic_name: &str,
+        topic_type: &str,
+        parse: impl Fn(&str) -> serde_json::Result<Msg> + Send + Sync + 'static,
+    ) -> Result<Subscriber<Msg>>
+    where
+        Msg: std::fmt::Debug + Send + 'static,
     {
         // Lookup / create a subscription entry for tracking
         let client = self.inner.read().await;
@@ -126,23 +240,34 @@ impl ClientHandle {
             .entry(topic_name.to_string())
             .or_insert(Subscription {
                 handles: HashMap::new(),
-                topic_type: Msg::ROS_TYPE_NAME.to_string(),
+                topic_type: topic_type.to_string(),
             });
 
-        // TODO Possible bug here? We send a subscribe message each time even if already subscribed
-        // Send subscribe message to rosbridge to initiate it sending us messages
-        let mut stream = client.writer.write().await;
-        stream.subscribe(topic_name, Msg::ROS_TYPE_NAME).await?;
+        // Only the first subscriber to a topic subscribes with rosbridge, later ones share the messages it sends
+        // The entry outlives its subscribers, so having no handles means rosbridge isn't sending us the topic
+        if cbs.handles.is_empty() {
+            // Send subscribe message to rosbridge to initiate it sending us messages
+            let mut stream = client.writer.write().await;
+            stream.subscribe(topic_name, topic_type).await?;
+        } else {
+            debug!("Already subscribed to {topic_name}, sharing the existing subscription");
+        }
 
         // Create a new watch channel for this topic
-        let queue = Arc::new(MessageQueue::new(QUEUE_SIZE));
+        let queue_size = client
+            .opts
+            .channel_config
+            .subscriber_queue_size
+            .unwrap_or(QUEUE_SIZE);
+        let queue = Arc::new(MessageQueue::new(queue_size));
 
         // Move the tx into a callback that takes raw string data
         // This allows us to store the callbacks generic on type, Msg conversion is embedded here
         let topic_name_copy = topic_name.to_string();
         let queue_copy = queue.clone();
+        let metrics = self.metrics.clone();
         let send_cb = Arc::new(move |data: &str| {
-            let converted = match serde_json::from_str::<Msg>(data) {
+            let converted = match parse(data) {
                 Err(e) => {
                     // TODO makes sense for callback to return Result<>, instead of this handling
                     // Should do better error propogation
@@ -164,7 +289,9 @@ impl ClientHandle {
                         "Queue on topic {} is full attempting to drop oldest message",
                         &topic_name_copy
                     );
-                    let _dropped = queue_copy.try_pop();
+                    if queue_copy.try_pop().is_some() {
+                        metrics.record_subscriber_dropped(1);
+                    }
                     // Retry pushing into queue
                     match queue_copy.try_push(msg) {
                         Ok(()) => {
@@ -211,6 +338,10 @@ impl ClientHandle {
     /// This function returns after a subscribe message has been sent to rosbridge, it will
     /// return immediately with an error if call while currently disconnected.
     ///
+    /// Only the first subscriber to a topic sends a subscribe message, further subscribers from this client or its
+    /// clones share it and each get a copy of the messages in their own queue. When a subscriber's queue is full its
+    /// oldest message is dropped, without affecting the other subscribers.
+    ///
     /// It does not error if subscribed type does not match the topic type or check this in anyway.
     /// If a type different that what is expected on the topic is published the deserialization of that message will fail,
     /// and the returned subscriber will simply not receive that message.
@@ -240,13 +371,100 @@ impl ClientHandle {
         Msg: RosMessageType,
     {
         self.check_for_disconnect()?;
+        let topic_name = self.remapped(topic_name)?;
         timeout(
             self.inner.read().await.opts.timeout,
-            self._subscribe(topic_name),
+            self._subscribe(&topic_name),
         )
         .await
     }
 
+    /// Subscribes to a topic whose type isn't known at compile time, see [DynamicMessage].
+    ///
+    /// The topic's type is looked up with rosapi's `topic_type` service and its schema is fetched with
+    /// [ClientHandle::message_schema], so rosapi must be running alongside rosbridge. Fails if the topic doesn't
+    /// exist yet, as its type can't be determined.
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    ///   let handle = roslibrust_rosbridge::ClientHandle::new("ws://localhost:9090").await?;
+    ///   let subscriber = handle.subscribe_dynamic("/odom").await?;
+    ///   let msg = subscriber.next().await;
+    ///   // Fields are looked up by their path, along with their types
+    ///   println!("x is {:?} of type {:?}", msg.get("pose.pose.position.x"), msg.field_type("pose.pose.position.x"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_dynamic(&self, topic_name: &str) -> Result<Subscriber<DynamicMessage>> {
+        self.check_for_disconnect()?;
+        let topic_name = self.remapped(topic_name)?;
+        let topic_name = topic_name.as_str();
+        let topic_type = self.topic_type(topic_name).await?;
+        if topic_type.is_empty() {
+            return Err(Error::ServerError(format!(
+                "Can't determine the type of {topic_name}, it doesn't exist"
+            )));
+        }
+        let schema = self.message_schema(&topic_type).await?;
+        timeout(
+            self.inner.read().await.opts.timeout,
+            self._subscribe_with(topic_name, &topic_type, move |data| {
+                serde_json::from_str::<Value>(data)
+                    .map(|value| DynamicMessage::new(schema.clone(), value))
+            }),
+        )
+        .await
+    }
+
+    // Looks up the type of a topic with rosapi, empty if the topic doesn't exist
+    async fn topic_type(&self, topic_name: &str) -> Result<String> {
+        Ok(self
+            .call_service::<dynamic::TopicType>(
+                "/rosapi/topic_type",
+                dynamic::TopicTypeRequest {
+                    topic: topic_name.to_string(),
+                },
+            )
+            .await?
+            .r#type)
+    }
+
+    /// Fetches the schema of `msg_type`, e.g. `geometry_msgs/PoseStamped`, from rosapi's `message_details` service.
+    ///
+    /// Schemas are cached, so each type is only fetched once per client.
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    ///   let handle = roslibrust_rosbridge::ClientHandle::new("ws://localhost:9090").await?;
+    ///   let schema = handle.message_schema("geometry_msgs/PoseStamped").await?;
+    ///   println!("{}", schema.definition);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn message_schema(&self, msg_type: &str) -> Result<Arc<MessageSchema>> {
+        if let Some(schema) = self.inner.read().await.schemas.get(msg_type) {
+            return Ok(schema.clone());
+        }
+        let details = self
+            .call_service::<dynamic::MessageDetails>(
+                "/rosapi/message_details",
+                dynamic::MessageDetailsRequest {
+                    r#type: msg_type.to_string(),
+                },
+            )
+            .await?;
+        let definition = dynamic::definition(msg_type, &details.typedefs)?;
+        let schema = Arc::new(MessageSchema::new(msg_type, definition));
+        self.inner
+            .read()
+            .await
+            .schemas
+            .insert(msg_type.to_string(), schema.clone());
+        Ok(schema)
+    }
+
     // Publishes a message
     // Fails immediately(ish) if disconnected
     // Returns success when message is put on websocket (no confirmation of receipt)
@@ -270,9 +488,9 @@ impl ClientHandle {
     ///
     /// This function returns with a failure if currently disconnected when called.
     ///
-    /// No type checking of the advertised type is performed. If the serialization of T is not
+    /// By default no type checking of the advertised type is performed. If the serialization of T is not
     /// accepted by rosbridge as compatible with rosmaster's type, that information will only be
-    /// available in rosbridge's logs.
+    /// available in rosbridge's logs. See [ClientHandleOptions::check_publish_types] to check it here instead.
     ///
     /// ```no_run
     /// # use roslibrust_test::ros1::*;
@@ -288,10 +506,34 @@ impl ClientHandle {
     /// # }
     /// ```
     pub async fn advertise<T>(&self, topic: &str) -> Result<Publisher<T>>
+    where
+        T: RosMessageType,
+    {
+        self.advertise_with_latching(topic, false).await
+    }
+
+    /// Advertises a topic like [ClientHandle::advertise], asking rosbridge_server to latch its publisher if
+    /// `latching` is set, which is how [roslibrust_common::PublisherQos::latching] is provided.
+    pub(crate) async fn advertise_with_latching<T>(
+        &self,
+        topic: &str,
+        latching: bool,
+    ) -> Result<Publisher<T>>
     where
         T: RosMessageType,
     {
         self.check_for_disconnect()?;
+        let topic = self.remapped(topic)?;
+        let topic = topic.as_str();
+        if self.inner.read().await.opts.check_publish_types {
+            let existing = self.topic_type(topic).await?;
+            if !existing.is_empty() && !dynamic::same_type(&existing, T::ROS_TYPE_NAME) {
+                return Err(Error::SerializationError(format!(
+                    "Can't advertise {topic} as {}, it already has the type {existing}",
+                    T::ROS_TYPE_NAME
+                )));
+            }
+        }
         let client = self.inner.read().await;
         if client.publishers.contains_key(topic) {
             // TODO if we ever remove this restriction we should still check types match
@@ -303,6 +545,7 @@ impl ClientHandle {
                 topic.to_string(),
                 PublisherHandle {
                     topic_type: T::ROS_TYPE_NAME.to_string(),
+                    latching,
                 },
             );
         }
@@ -310,7 +553,7 @@ impl ClientHandle {
         {
             let mut stream = client.writer.write().await;
             debug!("Advertise got lock on comm");
-            stream.advertise::<T>(topic).await?;
+            stream.advertise::<T>(topic, latching).await?;
         }
         Ok(Publisher::new(topic.to_string(), self.clone()))
     }
@@ -341,6 +584,8 @@ impl ClientHandle {
         req: S::Request,
     ) -> Result<S::Response> {
         self.check_for_disconnect()?;
+        let service = self.remapped(service)?;
+        let service = service.as_str();
         let (tx, rx) = tokio::sync::oneshot::channel();
         let rand_string: String = uuid::Uuid::new_v4().to_string();
         let client = self.inner.read().await;
@@ -364,7 +609,7 @@ impl ClientHandle {
 
         // Having to do manual timeout logic here because of error types
         let recv = if let Some(timeout) = client.opts.timeout {
-            tokio::time::timeout(timeout, rx)
+            roslibrust_common::runtime::timeout(timeout, rx)
                 .await
                 .map_err(|e| Error::Timeout(format!("Service call timed out: {e:?}")))?
         } else {
@@ -406,6 +651,8 @@ impl ClientHandle {
         F: ServiceFn<T>,
     {
         self.check_for_disconnect()?;
+        let topic = self.remapped(topic)?;
+        let topic = topic.as_str();
         {
             let client = self.inner.read().await;
             let mut writer = client.writer.write().await;
@@ -466,7 +713,8 @@ impl ClientHandle {
     pub(crate) fn unadvertise_service(&self, topic: &str) {
         let copy = self.inner.clone();
         let topic = topic.to_string();
-        tokio::spawn(async move {
+        let task_name = format!("rosbridge unadvertise service {topic}");
+        self.spawner.spawn(task_name, async move {
             let client = copy.read().await;
             let entry = client.services.remove(&topic);
             // Since this is called by drop we can't really propagate and error and instead simply have to log
@@ -492,7 +740,8 @@ impl ClientHandle {
     pub(crate) fn unadvertise(&self, topic_name: &str) {
         let copy = self.clone();
         let topic_name_copy = topic_name.to_string();
-        tokio::spawn(async move {
+        let task_name = format!("rosbridge unadvertise {topic_name}");
+        self.spawner.spawn(task_name, async move {
             // Remove publisher from our records
             let client = copy.inner.read().await;
             client.publishers.remove(&topic_name_copy);
@@ -517,7 +766,8 @@ impl ClientHandle {
         let topic_name = topic_name.to_string();
         let id = *id;
         // Actually send the unsubscribe message in a task so subscriber::Drop can call this function
-        tokio::spawn(async move {
+        let task_name = format!("rosbridge unsubscribe {topic_name}");
+        self.spawner.spawn(task_name, async move {
             // Identify the subscription entry for the subscriber
             let client = client.inner.read().await;
             let mut subscription = match client.subscriptions.get_mut(&topic_name) {
@@ -559,6 +809,8 @@ pub(crate) struct Client {
     // Contains any outstanding service calls we're waiting for a response on
     // Map key will be a uniquely generated id for each call
     service_calls: DashMap<String, tokio::sync::oneshot::Sender<Value>>,
+    // Schemas fetched from rosapi by message type, see ClientHandle::message_schema
+    schemas: DashMap<String, Arc<MessageSchema>>,
     opts: ClientHandleOptions,
 }
 
@@ -573,6 +825,7 @@ impl Client {
             services: DashMap::new(),
             subscriptions: DashMap::new(),
             service_calls: DashMap::new(),
+            schemas: DashMap::new(),
             opts,
         };
 
@@ -732,9 +985,10 @@ impl Client {
         // Re-advertise all publishers
         for publisher in self.publishers.iter() {
             let topic = publisher.key();
-            let topic_type = &publisher.value().topic_type;
+            let handle = publisher.value();
             let mut lock = self.writer.write().await;
-            lock.advertise_str(topic, topic_type).await?;
+            lock.advertise_str(topic, &handle.topic_type, handle.latching)
+                .await?;
         }
 
         // Resend rosbridge our subscription requests to re-establish inflight subscriptions
@@ -786,15 +1040,13 @@ async fn stubborn_spin(
 
 // Implementation of timeout that is a no-op if timeout is 0 or un-configured
 // Only works on functions that already return our result type
-// This might not be needed but reading tokio::timeout docs I couldn't confirm this
+// Callers may be outside of a tokio runtime, so the timer runs wherever roslibrust_common::runtime puts it
 async fn timeout<F, T>(timeout: Option<Duration>, future: F) -> Result<T>
 where
     F: futures::Future<Output = Result<T>>,
 {
     if let Some(t) = timeout {
-        tokio::time::timeout(t, future)
-            .await
-            .map_err(|e| Error::Timeout(format!("{e:?}")))?
+        roslibrust_common::runtime::timeout(t, future).await?
     } else {
         future.await
     }
@@ -10,9 +10,9 @@ use tokio_tungstenite::tungstenite::Message;
 
 /// Describes all documented rosbridge server operations
 pub(crate) enum Ops {
-    // These are in the definition, but not used right now
-    #[allow(dead_code)]
+    // Only sent by our server
     Status,
+    // These are in the definition, but not used right now
     #[allow(dead_code)]
     SetLevel,
     #[allow(dead_code)]
@@ -45,10 +45,10 @@ impl Into<&str> for &Ops {
             // These are unimplemented features of the library right now
             // Leaving them unimplemented here to try to catch bugs
             // TODO implement these
-            Ops::Status => unimplemented!(),
             Ops::SetLevel => unimplemented!(),
             Ops::Fragment => unimplemented!(),
             Ops::Auth => unimplemented!(),
+            Ops::Status => "status",
             Ops::Advertise => "advertise",
             Ops::Unadvertise => "unadvertise",
             Ops::Publish => "publish",
@@ -92,8 +92,8 @@ pub(crate) trait RosBridgeComm {
     async fn subscribe(&mut self, topic: &str, msg_type: &str) -> Result<()>;
     async fn unsubscribe(&mut self, topic: &str) -> Result<()>;
     async fn publish<T: RosMessageType>(&mut self, topic: &str, msg: &T) -> Result<()>;
-    async fn advertise<T: RosMessageType>(&mut self, topic: &str) -> Result<()>;
-    async fn advertise_str(&mut self, topic: &str, msg_type: &str) -> Result<()>;
+    async fn advertise<T: RosMessageType>(&mut self, topic: &str, latch: bool) -> Result<()>;
+    async fn advertise_str(&mut self, topic: &str, msg_type: &str, latch: bool) -> Result<()>;
     async fn call_service<Req: RosMessageType>(
         &mut self,
         service: &str,
@@ -155,19 +155,21 @@ impl RosBridgeComm for Writer {
         Ok(())
     }
 
-    async fn advertise<T: RosMessageType>(&mut self, topic: &str) -> Result<()> {
-        self.advertise_str(topic, T::ROS_TYPE_NAME).await
+    async fn advertise<T: RosMessageType>(&mut self, topic: &str, latch: bool) -> Result<()> {
+        self.advertise_str(topic, T::ROS_TYPE_NAME, latch).await
     }
 
     // Identical to advertise, but allows providing a string argument for the topic type
     // This is important as the type is erased in our list of publishers, and not available
     // when we try to reconnect
-    async fn advertise_str(&mut self, topic: &str, topic_type: &str) -> Result<()> {
+    // rosbridge_server latches the publisher it creates on our behalf when asked to
+    async fn advertise_str(&mut self, topic: &str, topic_type: &str, latch: bool) -> Result<()> {
         let msg = json!(
             {
                 "op": Ops::Advertise.to_string(),
                 "topic": topic.to_string(),
                 "type": topic_type,
+                "latch": latch,
             }
         );
         let msg = Message::Text(msg.to_string());
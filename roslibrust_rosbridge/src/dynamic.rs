@@ -0,0 +1,243 @@
+//! Subscribing to topics whose type isn't known at compile time, see [crate::ClientHandle::subscribe_dynamic].
+//!
+//! Rosbridge sends messages as JSON, so they can be received without a generated type. Their structure is fetched
+//! from rosapi's `message_details` service and turned into a [MessageSchema], which tells the type of every field.
+
+use roslibrust_common::{schema::MessageSchema, Error, Result, RosMessageType, RosServiceType};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// A message of a type only known at runtime, as received through rosbridge.
+#[derive(Clone, Debug)]
+pub struct DynamicMessage {
+    schema: Arc<MessageSchema>,
+    value: Value,
+}
+
+impl DynamicMessage {
+    pub(crate) fn new(schema: Arc<MessageSchema>, value: Value) -> Self {
+        Self { schema, value }
+    }
+
+    /// The schema of the message's type, shared by every message of the subscription.
+    pub fn schema(&self) -> &MessageSchema {
+        &self.schema
+    }
+
+    /// The message's fields as JSON.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Returns the message's fields as JSON.
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+
+    /// The value of a field given by its path, the names of the fields leading to it and indexes into arrays
+    /// separated by `.`, e.g. `pose.position.x` or `points.0.x`. None if the message has no such field.
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        path.split('.')
+            .try_fold(&self.value, |value, segment| match value {
+                Value::Array(elements) => elements.get(segment.parse::<usize>().ok()?),
+                value => value.get(segment),
+            })
+    }
+
+    /// The type of a field given by its path as in [DynamicMessage::get], e.g. `float64` for `pose.position.x`.
+    /// Indexing into an array gives the type of its elements.
+    pub fn field_type(&self, path: &str) -> Option<String> {
+        let mut field_type = self.schema.type_name.clone();
+        for segment in path.split('.') {
+            if let Some(element) = field_type.strip_suffix(']') {
+                if segment.parse::<usize>().is_ok() {
+                    field_type = element.split('[').next()?.to_owned();
+                    continue;
+                }
+                return None;
+            }
+            let fields = self.schema.messages.get(&field_type)?;
+            field_type = fields
+                .iter()
+                .find(|field| field.constant.is_none() && field.name == segment)?
+                .field_type
+                .clone();
+        }
+        Some(field_type)
+    }
+
+    /// Converts the message into a type known at compile time, e.g. once its type has been determined.
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T> {
+        T::deserialize(&self.value).map_err(|e| {
+            Error::SerializationError(format!(
+                "Failed to decode {} message: {e}",
+                self.schema.type_name
+            ))
+        })
+    }
+}
+
+/// rosapi's description of one message type, see [definition]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub(crate) struct TypeDef {
+    #[serde(rename = "type")]
+    pub(crate) type_name: String,
+    #[serde(default)]
+    pub(crate) fieldnames: Vec<String>,
+    #[serde(default)]
+    pub(crate) fieldtypes: Vec<String>,
+    /// -1 for fields which aren't arrays, 0 for arrays of any length, otherwise the length of the array
+    #[serde(default)]
+    pub(crate) fieldarraylen: Vec<i32>,
+}
+
+/// Builds the full definition of `type_name`, in the format of `gendeps --cat`, from the descriptions rosapi gives of
+/// it and of the messages it contains.
+///
+/// rosapi doesn't give the types of constants, so they are left out.
+pub(crate) fn definition(type_name: &str, typedefs: &[TypeDef]) -> Result<String> {
+    let describe = |typedef: &TypeDef| {
+        let mut lines = String::new();
+        for (i, name) in typedef.fieldnames.iter().enumerate() {
+            let field_type = typedef.fieldtypes.get(i).map(String::as_str).unwrap_or("");
+            let suffix = match typedef.fieldarraylen.get(i).copied().unwrap_or(-1) {
+                length if length < 0 => String::new(),
+                0 => "[]".to_owned(),
+                length => format!("[{length}]"),
+            };
+            lines.push_str(&format!("{field_type}{suffix} {name}\n"));
+        }
+        lines
+    };
+    let main = typedefs
+        .iter()
+        .find(|typedef| typedef.type_name == type_name)
+        .ok_or_else(|| {
+            Error::ServerError(format!("rosapi has no description of the type {type_name}"))
+        })?;
+    let mut definition = describe(main);
+    for typedef in typedefs
+        .iter()
+        .filter(|typedef| typedef.type_name != type_name)
+    {
+        definition.push_str(&format!(
+            "================================================================================\nMSG: {}\n{}",
+            typedef.type_name,
+            describe(typedef)
+        ));
+    }
+    Ok(definition)
+}
+
+/// True if two type names are the same type, where ROS2's `std_msgs/msg/String` is the same as `std_msgs/String`
+pub(crate) fn same_type(a: &str, b: &str) -> bool {
+    let strip = |name: &str| name.replacen("/msg/", "/", 1);
+    strip(a) == strip(b)
+}
+
+// The services of rosapi used to discover schemas, written out as roslibrust_rosbridge can't generate them itself
+
+macro_rules! rosapi_message {
+    ($name:ident, $ros_name:literal, { $($field:ident: $ty:ty),* }) => {
+        #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+        pub(crate) struct $name {
+            $(#[serde(default)] pub(crate) $field: $ty,)*
+        }
+
+        impl RosMessageType for $name {
+            const ROS_TYPE_NAME: &'static str = $ros_name;
+            const MD5SUM: &'static str = "";
+            const DEFINITION: &'static str = "";
+        }
+    };
+}
+
+rosapi_message!(TopicTypeRequest, "rosapi/TopicTypeRequest", { topic: String });
+rosapi_message!(TopicTypeResponse, "rosapi/TopicTypeResponse", { r#type: String });
+rosapi_message!(MessageDetailsRequest, "rosapi/MessageDetailsRequest", { r#type: String });
+rosapi_message!(MessageDetailsResponse, "rosapi/MessageDetailsResponse", { typedefs: Vec<TypeDef> });
+
+/// `/rosapi/topic_type`, the type of a topic, empty if it doesn't exist
+pub(crate) struct TopicType;
+
+impl RosServiceType for TopicType {
+    const ROS_SERVICE_NAME: &'static str = "rosapi/TopicType";
+    const MD5SUM: &'static str = "";
+    type Request = TopicTypeRequest;
+    type Response = TopicTypeResponse;
+}
+
+/// `/rosapi/message_details`, the descriptions of a message type and of every message it contains
+pub(crate) struct MessageDetails;
+
+impl RosServiceType for MessageDetails {
+    const ROS_SERVICE_NAME: &'static str = "rosapi/MessageDetails";
+    const MD5SUM: &'static str = "";
+    type Request = MessageDetailsRequest;
+    type Response = MessageDetailsResponse;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Abridged from rosapi's response for geometry_msgs/PoseStamped
+    const DETAILS: &str = r#"{"typedefs": [
+        {"type": "geometry_msgs/PoseStamped", "fieldnames": ["header", "pose"],
+         "fieldtypes": ["std_msgs/Header", "geometry_msgs/Pose"], "fieldarraylen": [-1, -1],
+         "examples": ["{}", "{}"], "constnames": [], "constvalues": []},
+        {"type": "std_msgs/Header", "fieldnames": ["seq", "stamp", "frame_id"],
+         "fieldtypes": ["uint32", "time", "string"], "fieldarraylen": [-1, -1, -1]},
+        {"type": "geometry_msgs/Pose", "fieldnames": ["position", "covariance"],
+         "fieldtypes": ["geometry_msgs/Point", "float64"], "fieldarraylen": [-1, 36]},
+        {"type": "geometry_msgs/Point", "fieldnames": ["x", "y", "z"],
+         "fieldtypes": ["float64", "float64", "float64"], "fieldarraylen": [-1, -1, -1]}
+    ]}"#;
+
+    #[test_log::test]
+    fn decodes_fields_with_discovered_schema() {
+        let details: MessageDetailsResponse = serde_json::from_str(DETAILS).unwrap();
+        let pose_stamped = definition("geometry_msgs/PoseStamped", &details.typedefs).unwrap();
+        assert!(pose_stamped.starts_with("std_msgs/Header header\ngeometry_msgs/Pose pose\n"));
+        assert!(pose_stamped.contains(
+            "MSG: geometry_msgs/Pose\ngeometry_msgs/Point position\nfloat64[36] covariance\n"
+        ));
+        let schema = Arc::new(MessageSchema::new(
+            "geometry_msgs/PoseStamped",
+            pose_stamped,
+        ));
+
+        let msg = DynamicMessage::new(
+            schema,
+            serde_json::json!({
+                "header": {"seq": 3, "stamp": {"secs": 1, "nsecs": 0}, "frame_id": "map"},
+                "pose": {"position": {"x": 1.5, "y": 0.0, "z": 0.0}, "covariance": [0.1]}
+            }),
+        );
+        assert_eq!(msg.get("pose.position.x"), Some(&serde_json::json!(1.5)));
+        assert_eq!(msg.get("pose.covariance.0"), Some(&serde_json::json!(0.1)));
+        assert_eq!(msg.get("pose.orientation"), None);
+        assert_eq!(
+            msg.field_type("pose.position.x").as_deref(),
+            Some("float64")
+        );
+        assert_eq!(msg.field_type("header").as_deref(), Some("std_msgs/Header"));
+        assert_eq!(
+            msg.field_type("pose.covariance.4").as_deref(),
+            Some("float64")
+        );
+        assert_eq!(msg.field_type("pose.missing"), None);
+        assert!(msg.decode::<String>().is_err());
+
+        assert!(definition("sensor_msgs/Image", &details.typedefs).is_err());
+    }
+
+    #[test_log::test]
+    fn compares_ros1_and_ros2_type_names() {
+        assert!(same_type("std_msgs/String", "std_msgs/String"));
+        assert!(same_type("std_msgs/msg/String", "std_msgs/String"));
+        assert!(!same_type("std_msgs/String", "std_msgs/Header"));
+        assert!(!same_type("std_msgs/msg/String", "geometry_msgs/String"));
+    }
+}
@@ -49,6 +49,15 @@ pub use publisher::*;
 mod client;
 pub use client::*;
 
+// Server is a transparent module, we directly expose internal types
+// Module exists only to organize source code
+mod server;
+pub use server::*;
+
+/// Receiving messages of types unknown at compile time
+pub mod dynamic;
+pub use dynamic::DynamicMessage;
+
 // Tests are fully private module
 #[cfg(test)]
 mod integration_tests;
@@ -135,7 +144,7 @@ type Writer = SplitSink<Socket, Message>;
 /// Topics have a fundamental queue *per subscriber* this is te queue type used for each subscriber.
 type MessageQueue<T> = deadqueue::limited::Queue<T>;
 
-// TODO queue size should be configurable for subscribers
+/// The default size of each subscriber's queue, see [ClientHandleOptions::channel_config]
 const QUEUE_SIZE: usize = 1_000;
 
 /// Internal tracking structure used to maintain information about each subscription our client has
@@ -152,6 +161,47 @@ pub(crate) struct Subscription {
 
 pub(crate) struct PublisherHandle {
     pub(crate) topic_type: String,
+    /// Whether the topic was advertised latched, used for re-advertising
+    pub(crate) latching: bool,
+}
+
+/// Spawns a client's internal tasks on the runtime it was created with, listing them in its [TaskRegistry]
+#[derive(Clone, Debug)]
+pub(crate) struct Spawner {
+    pub(crate) registry: TaskRegistry,
+    runtime: tokio::runtime::Handle,
+}
+
+impl Spawner {
+    pub(crate) fn new(runtime: tokio::runtime::Handle) -> Self {
+        Self {
+            registry: TaskRegistry::default(),
+            runtime,
+        }
+    }
+
+    /// Spawns a task, listed and named for tokio-console under `name`
+    #[cfg(not(all(tokio_unstable, feature = "tokio-console")))]
+    pub(crate) fn spawn<F>(&self, name: String, future: F) -> tokio::task::JoinHandle<F::Output>
+    where
+        F: std::future::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.runtime.spawn(self.registry.track(name, future))
+    }
+
+    /// Spawns a task, listed and named for tokio-console under `name`
+    #[cfg(all(tokio_unstable, feature = "tokio-console"))]
+    pub(crate) fn spawn<F>(&self, name: String, future: F) -> tokio::task::JoinHandle<F::Output>
+    where
+        F: std::future::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        tokio::task::Builder::new()
+            .name(&name)
+            .spawn_on(self.registry.track(name.clone(), future), &self.runtime)
+            .expect("Failed to spawn task")
+    }
 }
 
 // Implement the generic Service trait for our ServiceClient
@@ -168,28 +218,31 @@ impl ServiceProvider for crate::ClientHandle {
 
     async fn call_service<T: RosServiceType>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         request: T::Request,
     ) -> Result<T::Response> {
-        self.call_service::<T>(topic, request).await
+        let topic = topic.to_service_name()?;
+        self.call_service::<T>(topic.as_str(), request).await
     }
 
     async fn service_client<T: RosServiceType + 'static>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
     ) -> Result<Self::ServiceClient<T>> {
-        self.service_client::<T>(topic).await
+        let topic = topic.to_service_name()?;
+        self.service_client::<T>(topic.as_str()).await
     }
 
     async fn advertise_service<T: RosServiceType + 'static, F>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         server: F,
     ) -> Result<Self::ServiceServer>
     where
         F: ServiceFn<T>,
     {
-        self.advertise_service(topic, server).await
+        let topic = topic.to_service_name()?;
+        self.advertise_service(topic.as_str(), server).await
     }
 }
 
@@ -198,12 +251,54 @@ impl TopicProvider for crate::ClientHandle {
     type Publisher<T: RosMessageType> = crate::Publisher<T>;
     type Subscriber<T: RosMessageType> = crate::Subscriber<T>;
 
-    async fn advertise<T: RosMessageType>(&self, topic: &str) -> Result<Self::Publisher<T>> {
-        self.advertise::<T>(topic.as_ref()).await
+    // Note: names are validated here, but resolution of relative names is left to rosbridge_server
+    async fn advertise<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Publisher<T>> {
+        let topic = topic.to_topic_name()?;
+        self.advertise::<T>(topic.as_str()).await
+    }
+
+    async fn advertise_with_qos<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+        qos: PublisherQos,
+    ) -> Result<Self::Publisher<T>> {
+        let topic = topic.to_topic_name()?;
+        self.advertise_with_latching::<T>(topic.as_str(), qos.latching)
+            .await
+    }
+
+    async fn subscribe<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Subscriber<T>> {
+        let topic = topic.to_topic_name()?;
+        self.subscribe(topic.as_str()).await
+    }
+}
+
+// Parameters and the graph are reached through the rosapi node instead, see roslibrust_rosapi
+impl CapabilityProvider for crate::ClientHandle {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            latching: true,
+            service_clients: true,
+            service_servers: true,
+            ..Default::default()
+        }
+    }
+}
+
+// The rosbridge backend does not yet support simulated time, ROS time is wall clock time
+impl ClockProvider for crate::ClientHandle {
+    fn now(&self) -> std::time::SystemTime {
+        std::time::SystemTime::now()
     }
 
-    async fn subscribe<T: RosMessageType>(&self, topic: &str) -> Result<Self::Subscriber<T>> {
-        self.subscribe(topic).await
+    async fn sleep(&self, duration: std::time::Duration) {
+        roslibrust_common::runtime::sleep(duration).await
     }
 }
 
@@ -0,0 +1,529 @@
+//! A rosbridge protocol server which exposes any roslibrust backend to rosbridge clients
+//!
+//! Messages arrive from clients as JSON and have to be turned into concrete types before a backend can publish them,
+//! so the message and service types the server bridges are registered up front on [RosbridgeServerOptions].
+
+use crate::comm::Ops;
+use abort_on_drop::ChildTask;
+use futures::future::BoxFuture;
+use futures_util::{SinkExt, StreamExt};
+use log::{debug, error, warn};
+use roslibrust_common::{Error, Publish, Result, Ros, RosMessageType, RosServiceType, Subscribe};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+use tokio::{
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::mpsc,
+};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Number of messages queued for each client connection and each topic a client publishes on
+const QUEUE_SIZE: usize = 100;
+
+/// Type erasure of subscribing to a topic on the backend, forwarding each message to a client as a publish op
+type SubscribeFn<R> = Arc<
+    dyn Fn(&R, String, mpsc::Sender<Message>) -> BoxFuture<'static, Result<ChildTask<()>>>
+        + Send
+        + Sync,
+>;
+
+/// Type erasure of advertising a topic on the backend
+type AdvertiseFn<R> =
+    Arc<dyn Fn(&R, String) -> BoxFuture<'static, Result<ClientPublisher>> + Send + Sync>;
+
+/// Type erasure of calling a service through the backend with JSON arguments
+type CallFn<R> = Arc<dyn Fn(&R, String, Value) -> BoxFuture<'static, Result<Value>> + Send + Sync>;
+
+struct MessageBridge<R> {
+    subscribe: SubscribeFn<R>,
+    advertise: AdvertiseFn<R>,
+}
+
+/// Options for creating a [RosbridgeServer], chiefly the types it is able to bridge
+pub struct RosbridgeServerOptions<R: Ros> {
+    ros: R,
+    // Keyed by type name e.g. std_msgs/String
+    messages: HashMap<String, MessageBridge<R>>,
+    // Keyed by service name, as rosbridge clients don't send the type of service they are calling
+    services: HashMap<String, CallFn<R>>,
+}
+
+impl<R: Ros> RosbridgeServerOptions<R> {
+    /// Expects the backend which clients of the server will be connected to
+    pub fn new(ros: R) -> RosbridgeServerOptions<R> {
+        RosbridgeServerOptions {
+            ros,
+            messages: HashMap::new(),
+            services: HashMap::new(),
+        }
+    }
+
+    /// Allows clients to subscribe and publish to topics of type T
+    pub fn message<T: RosMessageType>(mut self) -> RosbridgeServerOptions<R> {
+        let subscribe: SubscribeFn<R> = Arc::new(|ros: &R, topic, sender| {
+            let ros = ros.clone();
+            Box::pin(async move {
+                let subscriber = ros.subscribe::<T>(topic.as_str()).await?;
+                Ok(tokio::spawn(forward_subscription(topic, subscriber, sender)).into())
+            })
+        });
+        let advertise: AdvertiseFn<R> = Arc::new(|ros: &R, topic| {
+            let ros = ros.clone();
+            Box::pin(async move {
+                let publisher = ros.advertise::<T>(topic.as_str()).await?;
+                let (sender, receiver) = mpsc::channel(QUEUE_SIZE);
+                Ok(ClientPublisher {
+                    sender,
+                    _task: tokio::spawn(publish_from_client(topic, publisher, receiver)).into(),
+                })
+            })
+        });
+        self.messages.insert(
+            T::ROS_TYPE_NAME.to_string(),
+            MessageBridge {
+                subscribe,
+                advertise,
+            },
+        );
+        self
+    }
+
+    /// Allows clients to call the service named `service`, which is of type T
+    pub fn service<T: RosServiceType>(mut self, service: &str) -> RosbridgeServerOptions<R> {
+        let call: CallFn<R> = Arc::new(|ros: &R, service, args| {
+            let ros = ros.clone();
+            Box::pin(async move {
+                // Clients may send no arguments at all for services with an empty request
+                let args = if args.is_null() { json!({}) } else { args };
+                let request: T::Request = serde_json::from_value(args)
+                    .map_err(|e| Error::SerializationError(e.to_string()))?;
+                let response = ros.call_service::<T>(service.as_str(), request).await?;
+                serde_json::to_value(response).map_err(|e| Error::SerializationError(e.to_string()))
+            })
+        });
+        self.services.insert(service.to_string(), call);
+        self
+    }
+
+    /// Looks up a message type by name, accepting the ROS2 style of name e.g. std_msgs/msg/String as well
+    fn message_bridge(&self, msg_type: &str) -> Option<&MessageBridge<R>> {
+        self.messages
+            .get(msg_type)
+            .or_else(|| self.messages.get(&msg_type.replacen("/msg/", "/", 1)))
+    }
+}
+
+/// A server implementing the [rosbridge protocol](https://github.com/RobotWebTools/rosbridge_suite/blob/ros2/ROSBRIDGE_PROTOCOL.md)
+/// on top of a roslibrust backend, allowing roslibrust to stand in for rosbridge_server.
+///
+/// Clients can use the advertise, unadvertise, publish, subscribe, unsubscribe and call_service operations
+/// for the types registered on [RosbridgeServerOptions]. The topics and services used by each client are
+/// cleaned up when it disconnects.
+///
+/// ```no_run
+/// # use roslibrust_test::ros1::*;
+/// # async fn example(ros: impl roslibrust_common::Ros) -> roslibrust_common::Result<()> {
+/// use roslibrust_rosbridge::{RosbridgeServer, RosbridgeServerOptions};
+/// let options = RosbridgeServerOptions::new(ros)
+///     .message::<std_msgs::String>()
+///     .service::<std_srvs::Trigger>("/reset");
+/// // Serves clients until dropped
+/// let server = RosbridgeServer::bind("0.0.0.0:9090", options).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RosbridgeServer {
+    local_addr: SocketAddr,
+    _accept_task: ChildTask<()>,
+}
+
+impl RosbridgeServer {
+    /// Starts listening for rosbridge clients on the given address.
+    pub async fn bind<R: Ros>(
+        addr: impl ToSocketAddrs,
+        options: RosbridgeServerOptions<R>,
+    ) -> Result<RosbridgeServer> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let accept_task = tokio::spawn(Self::accept_task(listener, Arc::new(options)));
+        Ok(RosbridgeServer {
+            local_addr,
+            _accept_task: accept_task.into(),
+        })
+    }
+
+    /// The address the server is listening on, useful when bound to port 0
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    async fn accept_task<R: Ros>(listener: TcpListener, options: Arc<RosbridgeServerOptions<R>>) {
+        // Client tasks are held here so they are all closed when the server is dropped
+        let mut clients = vec![];
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    error!("Failed to accept rosbridge client connection: {e}");
+                    continue;
+                }
+            };
+            debug!("Rosbridge client connected from {peer}");
+            let client: ChildTask<()> =
+                tokio::spawn(client_task(options.clone(), stream, peer)).into();
+            clients.retain(|client: &ChildTask<()>| !client.is_finished());
+            clients.push(client);
+        }
+    }
+}
+
+/// Publisher a client advertised, messages are handed to a task owning the backend's publisher
+struct ClientPublisher {
+    sender: mpsc::Sender<Value>,
+    _task: ChildTask<()>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ClientOp {
+    Advertise {
+        topic: String,
+        #[serde(rename = "type")]
+        msg_type: String,
+    },
+    Unadvertise {
+        topic: String,
+    },
+    Publish {
+        topic: String,
+        msg: Value,
+    },
+    Subscribe {
+        topic: String,
+        #[serde(rename = "type")]
+        msg_type: Option<String>,
+    },
+    Unsubscribe {
+        topic: String,
+    },
+    CallService {
+        service: String,
+        id: Option<String>,
+        #[serde(default)]
+        args: Value,
+    },
+    // Remaining ops such as advertise_service aren't supported and are reported back to the client
+    #[serde(other)]
+    Unsupported,
+}
+
+/// State of a single connected client, dropping it cleans up everything the client created
+struct Session<R: Ros> {
+    options: Arc<RosbridgeServerOptions<R>>,
+    sender: mpsc::Sender<Message>,
+    subscriptions: HashMap<String, ChildTask<()>>,
+    publishers: HashMap<String, ClientPublisher>,
+    // Service calls in progress, so they are abandoned if the client leaves
+    calls: Vec<ChildTask<()>>,
+}
+
+impl<R: Ros> Session<R> {
+    async fn handle_op(&mut self, text: &str) {
+        let op: ClientOp = match serde_json::from_str(text) {
+            Ok(op) => op,
+            Err(e) => {
+                self.status(format!("Failed to parse rosbridge op {text}: {e}"))
+                    .await;
+                return;
+            }
+        };
+        match op {
+            ClientOp::Advertise { topic, msg_type } => {
+                if self.publishers.contains_key(&topic) {
+                    return;
+                }
+                let Some(bridge) = self.options.message_bridge(&msg_type) else {
+                    self.status(format!("Can't advertise {topic}, unknown type {msg_type}"))
+                        .await;
+                    return;
+                };
+                match (bridge.advertise)(&self.options.ros, topic.clone()).await {
+                    Ok(publisher) => {
+                        self.publishers.insert(topic, publisher);
+                    }
+                    Err(e) => {
+                        self.status(format!("Failed to advertise {topic}: {e}"))
+                            .await
+                    }
+                }
+            }
+            ClientOp::Unadvertise { topic } => {
+                self.publishers.remove(&topic);
+            }
+            ClientOp::Publish { topic, msg } => {
+                let Some(publisher) = self.publishers.get(&topic) else {
+                    self.status(format!(
+                        "Can't publish to {topic}, it hasn't been advertised"
+                    ))
+                    .await;
+                    return;
+                };
+                if publisher.sender.send(msg).await.is_err() {
+                    // The publishing task logs why it stopped
+                    self.publishers.remove(&topic);
+                }
+            }
+            ClientOp::Subscribe { topic, msg_type } => {
+                if self.subscriptions.contains_key(&topic) {
+                    return;
+                }
+                let Some(msg_type) = msg_type else {
+                    self.status(format!("Can't subscribe to {topic} without a type"))
+                        .await;
+                    return;
+                };
+                let Some(bridge) = self.options.message_bridge(&msg_type) else {
+                    self.status(format!(
+                        "Can't subscribe to {topic}, unknown type {msg_type}"
+                    ))
+                    .await;
+                    return;
+                };
+                match (bridge.subscribe)(&self.options.ros, topic.clone(), self.sender.clone())
+                    .await
+                {
+                    Ok(subscription) => {
+                        self.subscriptions.insert(topic, subscription);
+                    }
+                    Err(e) => {
+                        self.status(format!("Failed to subscribe to {topic}: {e}"))
+                            .await
+                    }
+                }
+            }
+            ClientOp::Unsubscribe { topic } => {
+                self.subscriptions.remove(&topic);
+            }
+            ClientOp::CallService { service, id, args } => {
+                let Some(call) = self.options.services.get(&service) else {
+                    self.status(format!(
+                        "Can't call {service}, it isn't bridged by this server"
+                    ))
+                    .await;
+                    return;
+                };
+                // Calls are made in the background so a slow service doesn't hold up other ops
+                let response = call(&self.options.ros, service.clone(), args);
+                let sender = self.sender.clone();
+                let task = tokio::spawn(async move {
+                    let (result, values) = match response.await {
+                        Ok(values) => (true, values),
+                        Err(e) => (false, Value::String(e.to_string())),
+                    };
+                    let msg = json!({
+                        "op": Ops::ServiceResponse.to_string(),
+                        "service": service,
+                        "id": id,
+                        "result": result,
+                        "values": values,
+                    });
+                    let _ = sender.send(Message::Text(msg.to_string())).await;
+                });
+                self.calls.retain(|call| !call.is_finished());
+                self.calls.push(task.into());
+            }
+            ClientOp::Unsupported => {
+                self.status(format!("Unsupported rosbridge op: {text}"))
+                    .await;
+            }
+        }
+    }
+
+    /// Reports an error to the client with a status op
+    async fn status(&self, msg: String) {
+        warn!("{msg}");
+        let msg = json!({
+            "op": Ops::Status.to_string(),
+            "level": "error",
+            "msg": msg,
+        });
+        let _ = self.sender.send(Message::Text(msg.to_string())).await;
+    }
+}
+
+async fn client_task<R: Ros>(
+    options: Arc<RosbridgeServerOptions<R>>,
+    stream: TcpStream,
+    peer: SocketAddr,
+) {
+    let ws = match tokio_tungstenite::accept_async(stream).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            warn!("Failed websocket handshake with rosbridge client {peer}: {e}");
+            return;
+        }
+    };
+    let (mut writer, mut reader) = ws.split();
+    let (sender, mut receiver) = mpsc::channel(QUEUE_SIZE);
+    let _writer_task: ChildTask<()> = tokio::spawn(async move {
+        while let Some(msg) = receiver.recv().await {
+            if let Err(e) = writer.send(msg).await {
+                warn!("Failed to send to rosbridge client {peer}: {e}");
+                break;
+            }
+        }
+    })
+    .into();
+
+    let mut session = Session {
+        options,
+        sender,
+        subscriptions: HashMap::new(),
+        publishers: HashMap::new(),
+        calls: vec![],
+    };
+    while let Some(msg) = reader.next().await {
+        match msg {
+            Ok(Message::Text(text)) => session.handle_op(&text).await,
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(e) => {
+                warn!("Connection to rosbridge client {peer} failed: {e}");
+                break;
+            }
+        }
+    }
+    debug!("Rosbridge client {peer} disconnected");
+}
+
+/// Sends each message received by the backend to the client as a publish op
+async fn forward_subscription<T: RosMessageType>(
+    topic: String,
+    mut subscriber: impl Subscribe<T>,
+    sender: mpsc::Sender<Message>,
+) {
+    loop {
+        match subscriber.next().await {
+            Ok(msg) => {
+                let msg = json!({
+                    "op": Ops::Publish.to_string(),
+                    "topic": topic,
+                    "msg": msg,
+                });
+                if sender.send(Message::Text(msg.to_string())).await.is_err() {
+                    break;
+                }
+            }
+            Err(Error::Disconnected) => {
+                debug!(
+                    "Subscriber for {topic} disconnected, no longer forwarding to rosbridge client"
+                );
+                break;
+            }
+            Err(e) => warn!("Failed to receive message on {topic} for rosbridge client: {e}"),
+        }
+    }
+}
+
+/// Publishes each message the client sends on the backend
+async fn publish_from_client<T: RosMessageType>(
+    topic: String,
+    publisher: impl Publish<T>,
+    mut receiver: mpsc::Receiver<Value>,
+) {
+    while let Some(msg) = receiver.recv().await {
+        let msg: T = match serde_json::from_value(msg) {
+            Ok(msg) => msg,
+            Err(e) => {
+                warn!("Rosbridge client published a malformed message on {topic}: {e}");
+                continue;
+            }
+        };
+        if let Err(e) = publisher.publish(&msg).await {
+            warn!("Failed to publish message from rosbridge client on {topic}: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ClientHandle;
+    use roslibrust_common::{ServiceProvider, TopicProvider};
+    use roslibrust_mock::MockRos;
+    use roslibrust_test::ros1::{std_msgs, test_msgs};
+    use std::time::Duration;
+
+    #[test_log::test(tokio::test)]
+    async fn bridges_topics_and_services() {
+        let ros = MockRos::new();
+        let _service = ros
+            .advertise_service::<test_msgs::AddTwoInts, _>(
+                "/add_two_ints",
+                |request: test_msgs::AddTwoIntsRequest| {
+                    Ok(test_msgs::AddTwoIntsResponse {
+                        sum: request.a + request.b,
+                    })
+                },
+            )
+            .await
+            .unwrap();
+        let options = RosbridgeServerOptions::new(ros.clone())
+            .message::<std_msgs::String>()
+            .service::<test_msgs::AddTwoInts>("/add_two_ints");
+        let server = RosbridgeServer::bind("127.0.0.1:0", options).await.unwrap();
+        let client = ClientHandle::new(&format!("ws://{}", server.local_addr()))
+            .await
+            .unwrap();
+
+        let sum = client
+            .call_service::<test_msgs::AddTwoInts>(
+                "/add_two_ints",
+                test_msgs::AddTwoIntsRequest { a: 2, b: 3 },
+            )
+            .await
+            .unwrap();
+        assert_eq!(sum.sum, 5);
+
+        // Keep publishing until each side has finished subscribing
+        let client_sub = client
+            .subscribe::<std_msgs::String>("/to_client")
+            .await
+            .unwrap();
+        let ros_pub = ros
+            .advertise::<std_msgs::String>("/to_client")
+            .await
+            .unwrap();
+        let msg = std_msgs::String {
+            data: "hello".to_string(),
+        };
+        let received = loop {
+            ros_pub.publish(&msg).await.unwrap();
+            if let Ok(received) =
+                tokio::time::timeout(Duration::from_millis(10), client_sub.next()).await
+            {
+                break received;
+            }
+        };
+        assert_eq!(received, msg);
+
+        let mut ros_sub = ros
+            .subscribe::<std_msgs::String>("/from_client")
+            .await
+            .unwrap();
+        let client_pub = client
+            .advertise::<std_msgs::String>("/from_client")
+            .await
+            .unwrap();
+        let received = loop {
+            client_pub.publish(&msg).await.unwrap();
+            if let Ok(received) =
+                tokio::time::timeout(Duration::from_millis(10), ros_sub.next()).await
+            {
+                break received.unwrap();
+            }
+        };
+        assert_eq!(received, msg);
+    }
+}
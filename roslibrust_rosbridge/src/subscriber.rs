@@ -6,22 +6,21 @@ use log::error;
 use std::sync::Arc;
 
 use crate::{ClientHandle, MessageQueue};
-use roslibrust_common::RosMessageType;
 
 /// Represents a single instance of listening to a topic, and provides the ability to extract messages
 ///
 /// A single topic can be subscribed to multiple times and each subscriber will get a unique message queue.
 /// All subscribers will receive a copy of the incoming message anytime one is received.
 /// When the last subscriber is dropped the topic is automatically un-subscribed to.
-/// The internal message queue is currently fixed sized at 1_000 item maximum.
+/// The internal message queue holds 1_000 items by default, see [crate::ClientHandleOptions::channel_config].
+/// When it is full the oldest message is dropped, counted in [crate::ClientHandle::channel_metrics].
 ///
 /// The internal message queue is internally mutex'ed meaning const access to this class is sufficient for use.
 ///
 /// Roadmap:
-///  - Expose queue size control
 ///  - Provide unlimited queue (maybe?)
 ///  - Provide automatic alerting mechanism on queue growth / fullness
-pub struct Subscriber<T: RosMessageType> {
+pub struct Subscriber<T> {
     // Randomly generated unique id of the subscriber used to track its lifetime with the client
     id: uuid::Uuid,
     // ROS topic name this is subscribed to, currently only used in Drop impl to help client
@@ -31,7 +30,7 @@ pub struct Subscriber<T: RosMessageType> {
     queue: Arc<MessageQueue<T>>,
 }
 
-impl<T: RosMessageType> Subscriber<T> {
+impl<T> Subscriber<T> {
     // External API is accessed through ClientHandle::subscribe
     // This function is just a convenience wrapper for our internal API
     pub(crate) fn new(client: ClientHandle, queue: Arc<MessageQueue<T>>, topic: String) -> Self {
@@ -81,7 +80,7 @@ impl<T: RosMessageType> Subscriber<T> {
 
 /// Informs the client that the subscriber is being dropped so that
 /// the client can track when the last subscriber for a topic is dropped
-impl<T: RosMessageType> Drop for Subscriber<T> {
+impl<T> Drop for Subscriber<T> {
     fn drop(&mut self) {
         match self.client.unsubscribe(&self.topic, &self.id) {
             Ok(_) => {}
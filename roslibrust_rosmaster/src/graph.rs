@@ -0,0 +1,262 @@
+//! The registrations of publishers, subscribers and services, the graph half of the master API.
+
+use crate::{Notifier, CALLER_ID};
+use log::*;
+use serde_xmlrpc::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// The registrations of each topic and service, as lists of publishing / subscribing / providing nodes
+type SystemState = (
+    Vec<(String, Vec<String>)>,
+    Vec<(String, Vec<String>)>,
+    Vec<(String, Vec<String>)>,
+);
+
+/// Which nodes publish, subscribe to and provide what.
+///
+/// Calls which unregister are ignored unless they come from the node currently registered under the caller_id, so
+/// a node shutting down after being replaced by one of the same name doesn't unregister its replacement.
+#[derive(Default)]
+pub(crate) struct Graph {
+    // The xmlrpc URI of each node by its caller_id
+    nodes: HashMap<String, String>,
+    // The caller_ids of the publishers and subscribers of each topic
+    publishers: BTreeMap<String, Vec<String>>,
+    subscribers: BTreeMap<String, Vec<String>>,
+    topic_types: BTreeMap<String, String>,
+    // The caller_id and rosrpc URI of the provider of each service
+    services: BTreeMap<String, (String, String)>,
+}
+
+impl Graph {
+    /// Records the xmlrpc URI of `caller_id`. A node registering under the name of another one replaces it, and the
+    /// other node is told to shut down, as rosmaster does.
+    fn register_node(&mut self, caller_id: &str, api: &str, notifier: &Notifier) {
+        let Some(previous) = self.nodes.insert(caller_id.to_string(), api.to_string()) else {
+            return;
+        };
+        if previous == api {
+            return;
+        }
+        info!("New node registered as {caller_id}, shutting down the previous node at {previous}");
+        for publishers in self.publishers.values_mut() {
+            publishers.retain(|publisher| publisher != caller_id);
+        }
+        for subscribers in self.subscribers.values_mut() {
+            subscribers.retain(|subscriber| subscriber != caller_id);
+        }
+        self.services
+            .retain(|_, (provider, _)| provider.as_str() != caller_id);
+        let topics: Vec<String> = self.publishers.keys().cloned().collect();
+        for topic in topics {
+            self.update_subscribers(&topic, notifier);
+        }
+        notifier.call(
+            &previous,
+            "shutdown",
+            vec![
+                CALLER_ID.into(),
+                "new node registered with same name".into(),
+            ],
+        );
+    }
+
+    /// Whether `caller_id` is registered with the xmlrpc URI `api`
+    fn is_registered(&self, caller_id: &str, api: &str) -> bool {
+        self.nodes.get(caller_id).is_some_and(|known| known == api)
+    }
+
+    /// The xmlrpc URIs of `caller_ids`
+    fn apis(&self, caller_ids: Option<&Vec<String>>) -> Vec<String> {
+        caller_ids
+            .into_iter()
+            .flatten()
+            .filter_map(|caller_id| self.nodes.get(caller_id).cloned())
+            .collect()
+    }
+
+    /// Tells the subscribers of `topic` its current publishers
+    fn update_subscribers(&self, topic: &str, notifier: &Notifier) {
+        let publishers: Vec<Value> = self
+            .apis(self.publishers.get(topic))
+            .into_iter()
+            .map(Value::from)
+            .collect();
+        for api in self.apis(self.subscribers.get(topic)) {
+            notifier.call(
+                &api,
+                "publisherUpdate",
+                vec![
+                    CALLER_ID.into(),
+                    topic.into(),
+                    Value::Array(publishers.clone()),
+                ],
+            );
+        }
+    }
+
+    /// Registers `caller_id` as a publisher of `topic`, returning the xmlrpc URIs of its subscribers
+    pub(crate) fn register_publisher(
+        &mut self,
+        caller_id: &str,
+        topic: &str,
+        topic_type: &str,
+        api: &str,
+        notifier: &Notifier,
+    ) -> Vec<String> {
+        self.register_node(caller_id, api, notifier);
+        self.topic_types
+            .insert(topic.to_string(), topic_type.to_string());
+        add(
+            self.publishers.entry(topic.to_string()).or_default(),
+            caller_id,
+        );
+        self.update_subscribers(topic, notifier);
+        self.apis(self.subscribers.get(topic))
+    }
+
+    /// Returns whether `caller_id` was a publisher of `topic`
+    pub(crate) fn unregister_publisher(
+        &mut self,
+        caller_id: &str,
+        topic: &str,
+        api: &str,
+        notifier: &Notifier,
+    ) -> bool {
+        if !self.is_registered(caller_id, api) {
+            return false;
+        }
+        let removed = remove(self.publishers.get_mut(topic), caller_id);
+        if removed {
+            self.update_subscribers(topic, notifier);
+        }
+        removed
+    }
+
+    /// Registers `caller_id` as a subscriber of `topic`, returning the xmlrpc URIs of its publishers
+    pub(crate) fn register_subscriber(
+        &mut self,
+        caller_id: &str,
+        topic: &str,
+        topic_type: &str,
+        api: &str,
+        notifier: &Notifier,
+    ) -> Vec<String> {
+        self.register_node(caller_id, api, notifier);
+        // Publishers know the type of a topic best
+        self.topic_types
+            .entry(topic.to_string())
+            .or_insert_with(|| topic_type.to_string());
+        add(
+            self.subscribers.entry(topic.to_string()).or_default(),
+            caller_id,
+        );
+        self.apis(self.publishers.get(topic))
+    }
+
+    /// Returns whether `caller_id` was a subscriber of `topic`
+    pub(crate) fn unregister_subscriber(
+        &mut self,
+        caller_id: &str,
+        topic: &str,
+        api: &str,
+    ) -> bool {
+        self.is_registered(caller_id, api) && remove(self.subscribers.get_mut(topic), caller_id)
+    }
+
+    /// Registers `caller_id` as the provider of `service` at the rosrpc URI `service_api`, replacing any other
+    pub(crate) fn register_service(
+        &mut self,
+        caller_id: &str,
+        service: &str,
+        service_api: &str,
+        api: &str,
+        notifier: &Notifier,
+    ) {
+        self.register_node(caller_id, api, notifier);
+        self.services.insert(
+            service.to_string(),
+            (caller_id.to_string(), service_api.to_string()),
+        );
+    }
+
+    /// Returns whether `service` was provided at `service_api`
+    pub(crate) fn unregister_service(&mut self, service: &str, service_api: &str) -> bool {
+        let registered = self
+            .services
+            .get(service)
+            .is_some_and(|(_, api)| api == service_api);
+        if registered {
+            self.services.remove(service);
+        }
+        registered
+    }
+
+    /// The xmlrpc URI of `node`
+    pub(crate) fn lookup_node(&self, node: &str) -> Option<&str> {
+        self.nodes.get(node).map(String::as_str)
+    }
+
+    /// The rosrpc URI of `service`
+    pub(crate) fn lookup_service(&self, service: &str) -> Option<&str> {
+        self.services.get(service).map(|(_, api)| api.as_str())
+    }
+
+    /// The topics with a publisher in `subgraph`, with their types
+    pub(crate) fn published_topics(&self, subgraph: &str) -> Vec<(String, String)> {
+        self.publishers
+            .iter()
+            .filter(|(topic, publishers)| !publishers.is_empty() && topic.starts_with(subgraph))
+            .filter_map(|(topic, _)| {
+                let topic_type = self.topic_types.get(topic)?;
+                Some((topic.clone(), topic_type.clone()))
+            })
+            .collect()
+    }
+
+    /// Every topic ever published or subscribed to, with its type
+    pub(crate) fn topic_types(&self) -> Vec<(String, String)> {
+        self.topic_types.clone().into_iter().collect()
+    }
+
+    /// The publishers, subscribers and providers of every topic and service which has any
+    pub(crate) fn system_state(&self) -> SystemState {
+        let entries = |registrations: &BTreeMap<String, Vec<String>>| {
+            registrations
+                .iter()
+                .filter(|(_, nodes)| !nodes.is_empty())
+                .map(|(name, nodes)| (name.clone(), nodes.clone()))
+                .collect()
+        };
+        let services = self
+            .services
+            .iter()
+            .map(|(service, (caller_id, _))| (service.clone(), vec![caller_id.clone()]))
+            .collect();
+        (
+            entries(&self.publishers),
+            entries(&self.subscribers),
+            services,
+        )
+    }
+}
+
+/// Adds `caller_id` to `registrations` unless it is already registered
+fn add(registrations: &mut Vec<String>, caller_id: &str) {
+    if !registrations
+        .iter()
+        .any(|registered| registered == caller_id)
+    {
+        registrations.push(caller_id.to_string());
+    }
+}
+
+/// Removes `caller_id` from `registrations`, returning whether it was registered
+fn remove(registrations: Option<&mut Vec<String>>, caller_id: &str) -> bool {
+    let Some(registrations) = registrations else {
+        return false;
+    };
+    let before = registrations.len();
+    registrations.retain(|registered| registered != caller_id);
+    registrations.len() != before
+}
@@ -0,0 +1,435 @@
+//! A ROS1 master implemented in Rust, see [RosMaster].
+//!
+//! ROS1 nodes find each other through a master, usually started by `roscore` from a ROS installation. This crate
+//! implements the whole [master API](http://wiki.ros.org/ROS/Master_API) and the
+//! [parameter server API](http://wiki.ros.org/ROS/Parameter%20Server%20API) instead, so a graph of ROS1 nodes, in
+//! Rust, Python or C++, runs without ROS installed:
+//! ```no_run
+//! # #[tokio::main]
+//! # async fn main() {
+//! use roslibrust_rosmaster::RosMaster;
+//!
+//! let master = RosMaster::bind(([0, 0, 0, 0], 11311)).await.unwrap();
+//! println!("ROS_MASTER_URI={}", master.uri());
+//! // The master runs until dropped
+//! tokio::signal::ctrl_c().await.unwrap();
+//! # }
+//! ```
+//! With the `cli` feature the `roslibrust-rosmaster` binary runs a master from the command line in place of `rosmaster`.
+//!
+//! Like rosmaster the master only keeps the graph in memory, it doesn't start `rosout` or load parameters from launch
+//! files.
+
+use hyper::{Body, Request, Response};
+use log::*;
+use roslibrust_common::ParamName;
+use serde_xmlrpc::Value;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+mod graph;
+use graph::Graph;
+mod params;
+use params::Params;
+
+/// The caller_id the master uses when calling nodes
+const CALLER_ID: &str = "/master";
+
+/// A ROS1 master serving the master and parameter server APIs, stopped when dropped.
+pub struct RosMaster {
+    uri: String,
+    local_addr: SocketAddr,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl RosMaster {
+    /// Starts a master listening on `addr`, e.g. `([0, 0, 0, 0], 11311)` for the usual ROS_MASTER_URI of a host.
+    ///
+    /// Port 0 binds a free port, see [RosMaster::uri] for the one chosen.
+    pub async fn bind(addr: impl Into<SocketAddr>) -> std::io::Result<RosMaster> {
+        let addr = addr.into();
+        let server = hyper::Server::try_bind(&addr).map_err(std::io::Error::other)?;
+        let local_addr = server.local_addr();
+        // Nodes on other hosts can't reach a master listening on all interfaces at 0.0.0.0
+        let host = if local_addr.ip().is_unspecified() {
+            gethostname::gethostname().to_string_lossy().into_owned()
+        } else {
+            local_addr.ip().to_string()
+        };
+        let uri = format!("http://{host}:{}", local_addr.port());
+
+        let (notifier, notifications) = Notifier::new();
+        let master = Arc::new(Master {
+            uri: uri.clone(),
+            graph: Mutex::new(Graph::default()),
+            params: Mutex::new(Params::default()),
+            notifier,
+        });
+        let make_svc = hyper::service::make_service_fn(move |_connection| {
+            let master = master.clone();
+            async move {
+                Ok::<_, Infallible>(hyper::service::service_fn(move |request| {
+                    respond(master.clone(), request)
+                }))
+            }
+        });
+        let server = server.serve(make_svc);
+        info!("ROS master running at {uri}");
+        let tasks = vec![
+            tokio::spawn(async move {
+                if let Err(e) = server.await {
+                    error!("ROS master stopped: {e}");
+                }
+            }),
+            tokio::spawn(send_notifications(notifications)),
+        ];
+        Ok(RosMaster {
+            uri,
+            local_addr,
+            tasks,
+        })
+    }
+
+    /// The URI nodes reach the master at, their ROS_MASTER_URI
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// The address the master is listening on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for RosMaster {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+/// A call from the master to the xmlrpc API of a node at `api`
+#[derive(Debug)]
+struct Notification {
+    api: String,
+    method: &'static str,
+    args: Vec<Value>,
+}
+
+/// Queues calls to nodes, which are made one at a time so each node sees the updates of the graph in order
+#[derive(Clone)]
+struct Notifier {
+    sender: mpsc::UnboundedSender<Notification>,
+}
+
+impl Notifier {
+    fn new() -> (Notifier, mpsc::UnboundedReceiver<Notification>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        (Notifier { sender }, receiver)
+    }
+
+    fn call(&self, api: &str, method: &'static str, args: Vec<Value>) {
+        let _ = self.sender.send(Notification {
+            api: api.to_string(),
+            method,
+            args,
+        });
+    }
+}
+
+async fn send_notifications(mut notifications: mpsc::UnboundedReceiver<Notification>) {
+    let client = reqwest::Client::new();
+    while let Some(notification) = notifications.recv().await {
+        let body = match serde_xmlrpc::request_to_string(notification.method, notification.args) {
+            Ok(body) => body,
+            Err(e) => {
+                error!("Failed to serialize {}: {e}", notification.method);
+                continue;
+            }
+        };
+        if let Err(e) = client.post(&notification.api).body(body).send().await {
+            // The node may have shut down without unregistering
+            debug!(
+                "Failed to call {} at {}: {e}",
+                notification.method, notification.api
+            );
+        }
+    }
+}
+
+/// The value a call returns, or its status code and message when it failed
+type Reply = Result<Value, (i32, String)>;
+
+/// The arguments of the register calls, the caller_id, the name, its type or URI, and the caller's xmlrpc URI
+type Registration = (String, String, String, String);
+
+struct Master {
+    uri: String,
+    graph: Mutex<Graph>,
+    params: Mutex<Params>,
+    notifier: Notifier,
+}
+
+impl Master {
+    fn call(&self, method: &str, args: Vec<Value>) -> Reply {
+        trace!("ROS master called {method}({args:?})");
+        let invalid = |e: serde_xmlrpc::Error| (-1, format!("Invalid arguments to {method}: {e}"));
+        let notifier = &self.notifier;
+        match method {
+            "getUri" => Ok(self.uri.clone().into()),
+            "getPid" => Ok((std::process::id() as i32).into()),
+            "registerService" => {
+                let (caller_id, service, service_api, caller_api): Registration =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let mut graph = self.graph.lock().unwrap();
+                graph.register_service(&caller_id, &service, &service_api, &caller_api, notifier);
+                Ok(1.into())
+            }
+            "unregisterService" => {
+                let (_caller_id, service, service_api): (String, String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let mut graph = self.graph.lock().unwrap();
+                Ok((graph.unregister_service(&service, &service_api) as i32).into())
+            }
+            "registerSubscriber" => {
+                let (caller_id, topic, topic_type, caller_api): Registration =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let mut graph = self.graph.lock().unwrap();
+                to_value(graph.register_subscriber(
+                    &caller_id,
+                    &topic,
+                    &topic_type,
+                    &caller_api,
+                    notifier,
+                ))
+            }
+            "unregisterSubscriber" => {
+                let (caller_id, topic, caller_api): (String, String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let mut graph = self.graph.lock().unwrap();
+                Ok((graph.unregister_subscriber(&caller_id, &topic, &caller_api) as i32).into())
+            }
+            "registerPublisher" => {
+                let (caller_id, topic, topic_type, caller_api): Registration =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let mut graph = self.graph.lock().unwrap();
+                to_value(graph.register_publisher(
+                    &caller_id,
+                    &topic,
+                    &topic_type,
+                    &caller_api,
+                    notifier,
+                ))
+            }
+            "unregisterPublisher" => {
+                let (caller_id, topic, caller_api): (String, String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let mut graph = self.graph.lock().unwrap();
+                let removed = graph.unregister_publisher(&caller_id, &topic, &caller_api, notifier);
+                Ok((removed as i32).into())
+            }
+            "lookupNode" => {
+                let (_caller_id, node): (String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                match self.graph.lock().unwrap().lookup_node(&node) {
+                    Some(api) => Ok(api.into()),
+                    None => Err((-1, format!("unknown node [{node}]"))),
+                }
+            }
+            "lookupService" => {
+                let (_caller_id, service): (String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                match self.graph.lock().unwrap().lookup_service(&service) {
+                    Some(api) => Ok(api.into()),
+                    None => Err((-1, format!("no provider for [{service}]"))),
+                }
+            }
+            "getPublishedTopics" => {
+                let (_caller_id, subgraph): (String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                to_value(self.graph.lock().unwrap().published_topics(&subgraph))
+            }
+            "getTopicTypes" => to_value(self.graph.lock().unwrap().topic_types()),
+            "getSystemState" => to_value(self.graph.lock().unwrap().system_state()),
+            "getParam" => {
+                let (caller_id, key): (String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let key = resolve(&caller_id, &key)?;
+                match self.params.lock().unwrap().get(&key) {
+                    Some(value) => Ok(value),
+                    None => Err((-1, format!("Parameter [{key}] is not set"))),
+                }
+            }
+            "setParam" => {
+                if args.len() != 3 {
+                    return Err((
+                        -1,
+                        format!("setParam takes 3 arguments, got {}", args.len()),
+                    ));
+                }
+                let (caller_id, key): (String, String) =
+                    serde_xmlrpc::from_values(args[..2].to_vec()).map_err(invalid)?;
+                let key = resolve(&caller_id, &key)?;
+                let value = args[2].clone();
+                let mut params = self.params.lock().unwrap();
+                params.set(&key, value, notifier).map_err(|e| (-1, e))?;
+                Ok(0.into())
+            }
+            "deleteParam" => {
+                let (caller_id, key): (String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let key = resolve(&caller_id, &key)?;
+                if !self.params.lock().unwrap().delete(&key, notifier) {
+                    return Err((-1, format!("Parameter [{key}] is not set")));
+                }
+                Ok(0.into())
+            }
+            "hasParam" => {
+                let (caller_id, key): (String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let key = resolve(&caller_id, &key)?;
+                Ok(self.params.lock().unwrap().has(&key).into())
+            }
+            "searchParam" => {
+                let (caller_id, key): (String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                if key.starts_with('~') {
+                    return Err((-1, format!("Private names can't be searched for: {key}")));
+                }
+                match self.params.lock().unwrap().search(&caller_id, &key) {
+                    Some(found) => Ok(found.into()),
+                    None => Err((
+                        -1,
+                        format!("Cannot find parameter [{key}] in an upwards search"),
+                    )),
+                }
+            }
+            "subscribeParam" => {
+                let (caller_id, caller_api, key): (String, String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let key = resolve(&caller_id, &key)?;
+                let mut params = self.params.lock().unwrap();
+                Ok(params.subscribe(&caller_id, &caller_api, &key))
+            }
+            "unsubscribeParam" => {
+                let (caller_id, caller_api, key): (String, String, String) =
+                    serde_xmlrpc::from_values(args).map_err(invalid)?;
+                let key = resolve(&caller_id, &key)?;
+                let mut params = self.params.lock().unwrap();
+                Ok((params.unsubscribe(&caller_id, &caller_api, &key) as i32).into())
+            }
+            "getParamNames" => to_value(self.params.lock().unwrap().names()),
+            _ => Err((-1, format!("Unknown method {method}"))),
+        }
+    }
+}
+
+/// Resolves a parameter `key` in the namespace of `caller_id`, "/" being the root namespace
+fn resolve(caller_id: &str, key: &str) -> Result<String, (i32, String)> {
+    let key = key.trim_end_matches('/');
+    if key.is_empty() {
+        return Ok("/".to_string());
+    }
+    ParamName::new(key)
+        .and_then(|name| name.resolve_to_global(caller_id))
+        .map(String::from)
+        .map_err(|e| (-1, e.to_string()))
+}
+
+fn to_value(value: impl serde::Serialize) -> Reply {
+    serde_xmlrpc::to_value(value).map_err(|e| (-1, format!("Failed to serialize reply: {e}")))
+}
+
+async fn respond(
+    master: Arc<Master>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let reply = match hyper::body::to_bytes(request).await {
+        Ok(body) => match serde_xmlrpc::request_from_str(&String::from_utf8_lossy(&body)) {
+            Ok((method, args)) => master.call(&method, args),
+            Err(e) => Err((-1, format!("Invalid xmlrpc request: {e}"))),
+        },
+        Err(e) => Err((-1, format!("Failed to read request: {e}"))),
+    };
+    let (code, message, value) = match reply {
+        Ok(value) => (1, String::new(), value),
+        Err((code, message)) => {
+            debug!("ROS master replied with an error: {message}");
+            (code, message, Value::Int(0))
+        }
+    };
+    let response = serde_xmlrpc::response_to_string(
+        vec![Value::Array(vec![code.into(), message.into(), value])].into_iter(),
+    )
+    .expect("Replies of xmlrpc values are serializable");
+    Ok(Response::new(Body::from(response)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Calls `method` on the master at `uri`, returning the status code and value of its reply
+    async fn call(uri: &str, method: &str, args: Vec<Value>) -> (i32, Value) {
+        let body = serde_xmlrpc::request_to_string(method, args).unwrap();
+        let response = reqwest::Client::new()
+            .post(uri)
+            .body(body)
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        let (code, _message, value): (i32, String, Value) =
+            serde_xmlrpc::response_from_str(&response).unwrap();
+        (code, value)
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn serves_parameters() {
+        let master = RosMaster::bind(([127, 0, 0, 1], 0)).await.unwrap();
+        let uri = master.uri();
+        assert_eq!(
+            uri,
+            format!("http://127.0.0.1:{}", master.local_addr().port())
+        );
+
+        let (code, _) = call(
+            uri,
+            "setParam",
+            vec!["/ns/node".into(), "~rate".into(), 10.into()],
+        )
+        .await;
+        assert_eq!(code, 1);
+        let (code, value) = call(
+            uri,
+            "getParam",
+            vec!["/other".into(), "/ns/node/rate".into()],
+        )
+        .await;
+        assert_eq!((code, value), (1, Value::Int(10)));
+        let (code, value) = call(uri, "hasParam", vec!["/ns/node".into(), "rate".into()]).await;
+        assert_eq!((code, value), (1, Value::Bool(false)));
+        let (code, value) = call(
+            uri,
+            "searchParam",
+            vec!["/ns/node/sub".into(), "node/rate".into()],
+        )
+        .await;
+        assert_eq!((code, value), (1, Value::from("/ns/node/rate")));
+
+        let (code, _) = call(uri, "getParam", vec!["/other".into(), "/missing".into()]).await;
+        assert_eq!(code, -1);
+        let (code, _) = call(uri, "deleteParam", vec!["/other".into(), "/ns".into()]).await;
+        assert_eq!(code, 1);
+        let (code, value) = call(uri, "getParamNames", vec!["/other".into()]).await;
+        assert_eq!((code, value), (1, Value::Array(vec![])));
+        let (code, _) = call(uri, "unknownMethod", vec![]).await;
+        assert_eq!(code, -1);
+    }
+}
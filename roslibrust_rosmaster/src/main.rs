@@ -0,0 +1,30 @@
+//! `roslibrust-rosmaster` runs a ROS1 master and parameter server, in place of `rosmaster` or `roscore` on hosts
+//! without a ROS installation.
+//!
+//! Install with `cargo install roslibrust_rosmaster --features cli`, then run e.g. `roslibrust-rosmaster --port 11311`.
+
+use clap::Parser;
+use roslibrust_rosmaster::RosMaster;
+use std::net::IpAddr;
+
+#[derive(Parser, Debug)]
+#[command(about = "Runs a ROS1 master")]
+struct Args {
+    /// Port to serve the master API on
+    #[arg(long, short, default_value_t = 11311)]
+    port: u16,
+    /// Address to listen on, all interfaces by default
+    #[arg(long, default_value = "0.0.0.0")]
+    bind: IpAddr,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    env_logger::init();
+    let args = Args::parse();
+    let master = RosMaster::bind((args.bind, args.port)).await?;
+    println!("ROS_MASTER_URI={}", master.uri());
+    tokio::signal::ctrl_c().await?;
+    log::info!("Shutting down the ROS master");
+    Ok(())
+}
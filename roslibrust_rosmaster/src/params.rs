@@ -0,0 +1,314 @@
+//! The parameter server, the parameter half of the master API.
+
+use crate::{Notifier, CALLER_ID};
+use serde_xmlrpc::Value;
+use std::collections::BTreeMap;
+
+/// A parameter, or a namespace of parameters. Struct values are stored as namespaces so their members can be read and
+/// written as parameters of their own, e.g. setting "/a" to `{b: 1}` sets "/a/b" to 1.
+#[derive(Clone, Debug)]
+enum Entry {
+    Value(Value),
+    Namespace(BTreeMap<String, Entry>),
+}
+
+impl From<Value> for Entry {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Struct(members) => Entry::Namespace(
+                members
+                    .into_iter()
+                    .map(|(name, value)| (name, Entry::from(value)))
+                    .collect(),
+            ),
+            value => Entry::Value(value),
+        }
+    }
+}
+
+impl Entry {
+    fn to_value(&self) -> Value {
+        match self {
+            Entry::Value(value) => value.clone(),
+            Entry::Namespace(entries) => Value::Struct(
+                entries
+                    .iter()
+                    .map(|(name, entry)| (name.clone(), entry.to_value()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The full names of the parameters within this entry, which is named `name`
+    fn names(&self, name: &str, names: &mut Vec<String>) {
+        match self {
+            Entry::Value(_) => names.push(name.to_string()),
+            Entry::Namespace(entries) => {
+                for (child, entry) in entries {
+                    entry.names(&join(name, child), names);
+                }
+            }
+        }
+    }
+}
+
+/// The value of a deleted or unset parameter, as sent to its subscribers
+fn empty() -> Value {
+    Value::Struct(std::iter::empty().collect())
+}
+
+/// The names of the namespaces of `key` followed by its own, e.g. ["a", "b"] for "/a/b"
+fn segments(key: &str) -> impl Iterator<Item = &str> {
+    key.split('/').filter(|segment| !segment.is_empty())
+}
+
+fn join(namespace: &str, name: &str) -> String {
+    format!("{}/{name}", namespace.trim_end_matches('/'))
+}
+
+/// Whether `key` is `namespace` or within it
+fn contains(namespace: &str, key: &str) -> bool {
+    namespace == "/"
+        || key == namespace
+        || key
+            .strip_prefix(namespace)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// The parameters, and which nodes subscribed to which of them.
+///
+/// All keys are global names, "/" being the root namespace holding every parameter.
+pub(crate) struct Params {
+    root: Entry,
+    // The caller_id and xmlrpc URI of the subscribers of each key
+    subscribers: BTreeMap<String, Vec<(String, String)>>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            root: Entry::Namespace(BTreeMap::new()),
+            subscribers: BTreeMap::new(),
+        }
+    }
+}
+
+impl Params {
+    fn entry(&self, key: &str) -> Option<&Entry> {
+        segments(key).try_fold(&self.root, |entry, segment| match entry {
+            Entry::Namespace(entries) => entries.get(segment),
+            Entry::Value(_) => None,
+        })
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<Value> {
+        self.entry(key).map(Entry::to_value)
+    }
+
+    pub(crate) fn has(&self, key: &str) -> bool {
+        self.entry(key).is_some()
+    }
+
+    /// Sets `key` to `value`, replacing any parameter in its way, e.g. setting "/a/b" replaces a value at "/a" with a
+    /// namespace. Only a struct can be set at the root.
+    pub(crate) fn set(
+        &mut self,
+        key: &str,
+        value: Value,
+        notifier: &Notifier,
+    ) -> Result<(), String> {
+        let mut segments: Vec<&str> = segments(key).collect();
+        let Some(name) = segments.pop() else {
+            return match Entry::from(value) {
+                root @ Entry::Namespace(_) => {
+                    self.root = root;
+                    self.update_subscribers(key, notifier);
+                    Ok(())
+                }
+                Entry::Value(_) => {
+                    Err("The root namespace can only be set to a struct".to_string())
+                }
+            };
+        };
+        let mut entry = &mut self.root;
+        for segment in segments {
+            let Entry::Namespace(entries) = entry else {
+                unreachable!("Values in the way are replaced by namespaces")
+            };
+            entry = entries
+                .entry(segment.to_string())
+                .or_insert_with(|| Entry::Namespace(BTreeMap::new()));
+            if let Entry::Value(_) = entry {
+                *entry = Entry::Namespace(BTreeMap::new());
+            }
+        }
+        let Entry::Namespace(entries) = entry else {
+            unreachable!("Values in the way are replaced by namespaces")
+        };
+        entries.insert(name.to_string(), Entry::from(value));
+        self.update_subscribers(key, notifier);
+        Ok(())
+    }
+
+    /// Deletes `key` and any parameters within it, returning whether it was set. The root can't be deleted.
+    pub(crate) fn delete(&mut self, key: &str, notifier: &Notifier) -> bool {
+        let mut segments: Vec<&str> = segments(key).collect();
+        let Some(name) = segments.pop() else {
+            return false;
+        };
+        let namespace = segments
+            .iter()
+            .try_fold(&mut self.root, |entry, segment| match entry {
+                Entry::Namespace(entries) => entries.get_mut(*segment),
+                Entry::Value(_) => None,
+            });
+        let Some(Entry::Namespace(entries)) = namespace else {
+            return false;
+        };
+        if entries.remove(name).is_none() {
+            return false;
+        }
+        self.update_subscribers(key, notifier);
+        true
+    }
+
+    /// Finds `key` by searching upwards from the namespace `caller_id`, returning the full name it was found as.
+    ///
+    /// As in rosmaster only the first segment of `key` is searched for, e.g. "a/b" searched from "/ns/node" is found
+    /// as "/ns/a/b" if "/ns/a" is set, even if "/ns/a/b" isn't.
+    pub(crate) fn search(&self, caller_id: &str, key: &str) -> Option<String> {
+        if key.starts_with('/') {
+            return self.has(key).then(|| key.to_string());
+        }
+        let first = segments(key).next()?;
+        let namespaces: Vec<&str> = segments(caller_id).collect();
+        (0..=namespaces.len()).rev().find_map(|depth| {
+            let namespace = format!("/{}", namespaces[..depth].join("/"));
+            self.has(&join(&namespace, first))
+                .then(|| join(&namespace, key))
+        })
+    }
+
+    /// Subscribes `caller_id` to updates of `key`, returning its current value
+    pub(crate) fn subscribe(&mut self, caller_id: &str, api: &str, key: &str) -> Value {
+        let subscribers = self.subscribers.entry(key.to_string()).or_default();
+        if !subscribers.iter().any(|(_, known)| known == api) {
+            subscribers.retain(|(subscriber, _)| subscriber != caller_id);
+            subscribers.push((caller_id.to_string(), api.to_string()));
+        }
+        self.get(key).unwrap_or_else(empty)
+    }
+
+    /// Returns whether `caller_id` was subscribed to `key`
+    pub(crate) fn unsubscribe(&mut self, caller_id: &str, api: &str, key: &str) -> bool {
+        let Some(subscribers) = self.subscribers.get_mut(key) else {
+            return false;
+        };
+        let before = subscribers.len();
+        subscribers.retain(|(subscriber, known)| !(subscriber == caller_id && known == api));
+        subscribers.len() != before
+    }
+
+    /// The names of all parameters, not including the namespaces containing them
+    pub(crate) fn names(&self) -> Vec<String> {
+        let mut names = vec![];
+        self.root.names("/", &mut names);
+        names
+    }
+
+    /// Tells the subscribers of keys related to the changed `key` their new values. Subscribers of a key within `key`
+    /// are sent the new value of their key, subscribers of a namespace containing `key` are sent the value of `key`.
+    fn update_subscribers(&self, key: &str, notifier: &Notifier) {
+        for (subscribed, subscribers) in &self.subscribers {
+            let updated = if contains(key, subscribed) {
+                subscribed.as_str()
+            } else if contains(subscribed, key) {
+                key
+            } else {
+                continue;
+            };
+            let value = self.get(updated).unwrap_or_else(empty);
+            for (_, api) in subscribers {
+                notifier.call(
+                    api,
+                    "paramUpdate",
+                    vec![CALLER_ID.into(), updated.into(), value.clone()],
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn value(members: &[(&str, Value)]) -> Value {
+        Value::Struct(
+            members
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn namespaces_and_search() {
+        let (notifier, _notifications) = Notifier::new();
+        let mut params = Params::default();
+        params.set("/a/b", 1.into(), &notifier).unwrap();
+        params
+            .set("/a/c", value(&[("d", "e".into())]), &notifier)
+            .unwrap();
+        assert_eq!(params.get("/a/c/d"), Some("e".into()));
+        assert_eq!(
+            params.get("/a"),
+            Some(value(&[
+                ("b", 1.into()),
+                ("c", value(&[("d", "e".into())]))
+            ]))
+        );
+        assert_eq!(params.names(), vec!["/a/b", "/a/c/d"]);
+
+        // A value in the way of a key is replaced by a namespace
+        params.set("/a/b/f", true.into(), &notifier).unwrap();
+        assert_eq!(params.get("/a/b"), Some(value(&[("f", true.into())])));
+        assert!(params.set("/", 1.into(), &notifier).is_err());
+
+        assert_eq!(params.search("/a/node", "b"), Some("/a/b".to_string()));
+        assert_eq!(params.search("/x/y/node", "a/g"), Some("/a/g".to_string()));
+        assert_eq!(params.search("/x/node", "g"), None);
+
+        assert!(params.delete("/a/c", &notifier));
+        assert!(!params.delete("/a/c", &notifier));
+        assert!(!params.delete("/", &notifier));
+        assert!(!params.has("/a/c/d"));
+        assert_eq!(params.names(), vec!["/a/b/f"]);
+    }
+
+    #[test]
+    fn updates_subscribers_of_related_keys() {
+        let (notifier, mut notifications) = Notifier::new();
+        let mut params = Params::default();
+        assert_eq!(params.subscribe("/node", "http://node", "/a/b"), empty());
+        params.subscribe("/node", "http://node", "/x");
+
+        let mut updates = || {
+            std::iter::from_fn(|| notifications.try_recv().ok())
+                .map(|notification| (notification.args[1].clone(), notification.args[2].clone()))
+                .collect::<Vec<_>>()
+        };
+        params
+            .set("/a", value(&[("b", 2.into())]), &notifier)
+            .unwrap();
+        assert_eq!(updates(), vec![("/a/b".into(), 2.into())]);
+        params.set("/a/b/c", 3.into(), &notifier).unwrap();
+        assert_eq!(updates(), vec![("/a/b/c".into(), 3.into())]);
+        params.delete("/a", &notifier);
+        assert_eq!(updates(), vec![("/a/b".into(), empty())]);
+
+        assert!(params.unsubscribe("/node", "http://node", "/a/b"));
+        params.set("/a/b", 4.into(), &notifier).unwrap();
+        assert_eq!(updates(), vec![]);
+    }
+}
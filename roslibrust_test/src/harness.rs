@@ -0,0 +1,116 @@
+//! A private ROS1 master for integration tests, see [TestMaster].
+//!
+//! Tests of ROS1 nodes usually need a roscore, which in turn needs a ROS installation. [TestMaster] runs the master of
+//! [roslibrust_rosmaster] instead, and each test starts its own on a free port, so tests run in CI without ROS and in
+//! parallel without seeing each other's topics:
+//! ```no_run
+//! # #[tokio::main]
+//! # async fn main() {
+//! use roslibrust_test::harness::TestMaster;
+//! use roslibrust_test::ros1::std_msgs;
+//!
+//! let master = TestMaster::start().await.unwrap();
+//! let talker = master.node("/talker").await.unwrap();
+//! let listener = master.node("/listener").await.unwrap();
+//! let publisher = talker
+//!     .advertise::<std_msgs::String>("/chatter", 1, true)
+//!     .await
+//!     .unwrap();
+//! let mut subscriber = listener
+//!     .subscribe::<std_msgs::String>("/chatter", 1)
+//!     .await
+//!     .unwrap();
+//! # }
+//! ```
+
+use roslibrust::ros1::{NodeError, NodeHandle};
+use roslibrust_rosmaster::RosMaster;
+use std::net::Ipv4Addr;
+
+/// A ROS1 master running in the test's process, stopped when dropped.
+///
+/// Nodes connect to it with its [TestMaster::uri] in place of e.g. "http://localhost:11311", or are created with
+/// [TestMaster::node].
+pub struct TestMaster {
+    master: RosMaster,
+}
+
+impl TestMaster {
+    /// Starts a master on a free port of localhost.
+    pub async fn start() -> std::io::Result<TestMaster> {
+        let master = RosMaster::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        Ok(TestMaster { master })
+    }
+
+    /// The URI nodes reach the master at
+    pub fn uri(&self) -> &str {
+        self.master.uri()
+    }
+
+    /// Creates a node registered with this master
+    pub async fn node(&self, name: &str) -> Result<NodeHandle, NodeError> {
+        NodeHandle::new(self.uri(), name).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ros1::{std_msgs, std_srvs};
+    use roslibrust::ros1::MasterClient;
+
+    #[test_log::test(tokio::test)]
+    async fn runs_a_graph() {
+        let master = TestMaster::start().await.unwrap();
+        let talker = master.node("/talker").await.unwrap();
+        let listener = master.node("/listener").await.unwrap();
+
+        // Subscribing first, the listener learns of the publisher from publisherUpdate
+        let mut subscriber = listener
+            .subscribe::<std_msgs::String>("/chatter", 1)
+            .await
+            .unwrap();
+        let publisher = talker
+            .advertise::<std_msgs::String>("/chatter", 1, true)
+            .await
+            .unwrap();
+        publisher
+            .publish(&std_msgs::String {
+                data: "hello".to_string(),
+            })
+            .await
+            .unwrap();
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(2), subscriber.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg.data, "hello");
+
+        let _server = talker
+            .advertise_service::<std_srvs::Trigger, _>("/trigger", |_| {
+                Ok(std_srvs::TriggerResponse {
+                    success: true,
+                    message: "triggered".to_string(),
+                })
+            })
+            .await
+            .unwrap();
+        let client = listener
+            .service_client::<std_srvs::Trigger>("/trigger")
+            .await
+            .unwrap();
+        let response = client.call(&std_srvs::TriggerRequest {}).await.unwrap();
+        assert_eq!(response.message, "triggered");
+
+        let client = MasterClient::new(master.uri(), "http://127.0.0.1:1", "/checker")
+            .await
+            .unwrap();
+        let state = client.get_system_state().await.unwrap();
+        assert!(state.is_publishing("/chatter", "/talker"));
+        assert!(state.is_subscribed("/chatter", "/listener"));
+        assert!(state.is_service_provider("/trigger", "/talker"));
+        let topics = client.get_published_topics("").await.unwrap();
+        assert!(topics.contains(&("/chatter".to_string(), "std_msgs/String".to_string())));
+    }
+}
@@ -1,3 +1,6 @@
 // These are kept in separate namespaces to prevent name collisions
 pub mod ros1;
 pub mod ros2;
+
+/// Contains [harness::TestMaster], a ROS1 master for running integration tests without a ROS installation
+pub mod harness;
@@ -22,7 +22,20 @@ lazy_static! {
     static ref ROS_2_PATHS: Vec<PathBuf> = vec![ROS_2_PATH.into(), ROS_2_TEST_PATH.into()];
 }
 
-/// This main function is used to generate the contents of ros1.rs, ros2.rs
+/// Where the standard packages are generated to for roslibrust_interfaces
+const INTERFACES_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../roslibrust_interfaces/src");
+
+/// Generates the standard packages vendored in roslibrust_interfaces from a common_interfaces path,
+/// rosapi is left out as it is provided by roslibrust_rosapi
+fn generate_interfaces(path: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let (source, _paths) = roslibrust::codegen::build_helper::BuildHelper::new()
+        .search_path(path)
+        .exclude_package("rosapi")
+        .generate_source()?;
+    Ok(format_rust_source(source.to_string().as_str()).to_string())
+}
+
+/// This main function is used to generate the contents of ros1.rs, ros2.rs and the roslibrust_interfaces sources
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
     let (source, _paths) =
@@ -39,6 +52,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ])?;
     let source = format_rust_source(source.to_string().as_str()).to_string();
     std::fs::write(concat!(env!("CARGO_MANIFEST_DIR"), "/src/ros2.rs"), source)?;
+
+    std::fs::write(
+        format!("{INTERFACES_PATH}/ros1.rs"),
+        generate_interfaces(ROS_1_PATH)?,
+    )?;
+    std::fs::write(
+        format!("{INTERFACES_PATH}/ros2.rs"),
+        generate_interfaces(ROS_2_PATH)?,
+    )?;
     Ok(())
 }
 
@@ -114,4 +136,21 @@ mod test {
             panic!("Changes detected see diff!");
         }
     }
+
+    /// Confirms that the vendored roslibrust_interfaces sources have been regenerated
+    #[test]
+    fn interfaces_are_up_to_date() {
+        for (path, file) in [(ROS_1_PATH, "ros1.rs"), (ROS_2_PATH, "ros2.rs")] {
+            let source = generate_interfaces(path).unwrap();
+            let lib_contents = std::fs::read_to_string(format!("{INTERFACES_PATH}/{file}"))
+                .expect("Failed to load current roslibrust_interfaces contents");
+
+            let diff = diffy::create_patch(&source, &lib_contents);
+            println!("Diff of {file} is \n{}", diff);
+
+            if source.trim() != lib_contents.trim() {
+                panic!("Changes detected in {file} see diff!");
+            }
+        }
+    }
 }
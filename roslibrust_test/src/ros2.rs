@@ -22,7 +22,13 @@ pub mod actionlib_msgs {
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct GoalID {
+        #[doc = " The stamp should store the time at which this goal was requested."]
+        #[doc = " It is used by an action server when it tries to preempt all"]
+        #[doc = " goals that were requested before a certain time"]
         pub r#stamp: ::roslibrust::codegen::integral_types::Time,
+        #[doc = " The id provides a way to associate feedback and"]
+        #[doc = " result message with specific goal requests. The id"]
+        #[doc = " specified must be unique."]
         pub r#id: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for GoalID {
@@ -37,6 +43,14 @@ builtin_interfaces/Time stamp
 # result message with specific goal requests. The id
 # specified must be unique.
 string id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_140ea4a1bad4781edc32030c7983ab3a62c675f861c61c9b2200ae5e5398be24";
+    }
+    #[allow(unused)]
+    impl GoalID {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -51,6 +65,7 @@ string id"####;
     pub struct GoalStatus {
         pub r#goal_id: self::GoalID,
         pub r#status: u8,
+        #[doc = " Allow for the user to associate a string with GoalStatus for debugging."]
         pub r#text: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for GoalStatus {
@@ -90,18 +105,43 @@ builtin_interfaces/Time stamp
 # result message with specific goal requests. The id
 # specified must be unique.
 string id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_c90c64230954d90ee079cbdd22a14bca3650b3d403f5cc3faf0eee992c6ae9ff";
+    }
+    #[allow(unused)]
+    impl GoalStatus {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(unused)]
     impl GoalStatus {
+        #[doc = " The goal has yet to be processed by the action server."]
         pub const r#PENDING: u8 = 0u8;
+        #[doc = " The goal is currently being processed by the action server."]
         pub const r#ACTIVE: u8 = 1u8;
+        #[doc = " The goal received a cancel request after it started executing"]
         pub const r#PREEMPTED: u8 = 2u8;
+        #[doc = " and has since completed its execution (Terminal State)."]
+        #[doc = " The goal was achieved successfully by the action server"]
         pub const r#SUCCEEDED: u8 = 3u8;
+        #[doc = " (Terminal State)."]
+        #[doc = " The goal was aborted during execution by the action server due"]
         pub const r#ABORTED: u8 = 4u8;
+        #[doc = " to some failure (Terminal State)."]
+        #[doc = " The goal was rejected by the action server without being processed,"]
         pub const r#REJECTED: u8 = 5u8;
+        #[doc = " because the goal was unattainable or invalid (Terminal State)."]
+        #[doc = " The goal received a cancel request after it started executing"]
         pub const r#PREEMPTING: u8 = 6u8;
+        #[doc = " and has not yet completed execution."]
+        #[doc = " The goal received a cancel request before it started executing, but"]
         pub const r#RECALLING: u8 = 7u8;
+        #[doc = " the action server has not yet confirmed that the goal is canceled."]
+        #[doc = " The goal received a cancel request before it started executing"]
         pub const r#RECALLED: u8 = 8u8;
+        #[doc = " and was successfully cancelled (Terminal State)."]
+        #[doc = " An action client can determine that a goal is LOST. This should not"]
         pub const r#LOST: u8 = 9u8;
     }
     #[allow(non_snake_case)]
@@ -115,6 +155,8 @@ string id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct GoalStatusArray {
+        #[doc = " Stores the statuses for goals that are currently being tracked"]
+        #[doc = " by an action server"]
         pub r#header: std_msgs::Header,
         pub r#status_list: ::std::vec::Vec<self::GoalStatus>,
     }
@@ -126,16 +168,16 @@ string id"####;
 std_msgs/Header header
 GoalStatus[] status_list
 ================================================================================
-MSG: actionlib_msgs/GoalID
-# The stamp should store the time at which this goal was requested.
-# It is used by an action server when it tries to preempt all
-# goals that were requested before a certain time
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
 builtin_interfaces/Time stamp
 
-# The id provides a way to associate feedback and
-# result message with specific goal requests. The id
-# specified must be unique.
-string id
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: actionlib_msgs/GoalStatus
 GoalID goal_id
@@ -171,18 +213,15 @@ builtin_interfaces/Time stamp
 # The id provides a way to associate feedback and
 # result message with specific goal requests. The id
 # specified must be unique.
-string id
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+string id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_f3230208e8daec53eb7b8da6df1c1d1d4eb095f3082be922661c29a1f855a1bc";
+    }
+    #[allow(unused)]
+    impl GoalStatusArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
 }
 #[allow(unused_imports)]
@@ -209,7 +248,10 @@ pub mod diagnostic_msgs {
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct DiagnosticArray {
+        #[doc = " This message is used to send diagnostic information about the state of the robot."]
+        #[doc = " for timestamp"]
         pub r#header: std_msgs::Header,
+        #[doc = " an array of components being reported on"]
         pub r#status: ::std::vec::Vec<self::DiagnosticStatus>,
     }
     impl ::roslibrust::RosMessageType for DiagnosticArray {
@@ -219,6 +261,17 @@ pub mod diagnostic_msgs {
 std_msgs/Header header # for timestamp
 DiagnosticStatus[] status # an array of components being reported on
 ================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
+================================================================================
 MSG: diagnostic_msgs/DiagnosticStatus
 # This message holds the status of an individual component of the robot.
 
@@ -243,25 +296,17 @@ MSG: diagnostic_msgs/KeyValue
 # What to label this value when viewing.
 string key
 # A value to track over time.
-string value
-================================================================================
-MSG: diagnostic_msgs/KeyValue
-# What to label this value when viewing.
-string key
-# A value to track over time.
-string value
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+string value"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_5a8a36efb05fb25070fa0fb3810290c0e6cd4862b54a8fb975a1ee8dc55a333e";
+    }
+    #[allow(unused)]
+    impl DiagnosticArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This message holds the status of an individual component of the robot."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -273,10 +318,15 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct DiagnosticStatus {
+        #[doc = " Level of operation enumerated above."]
         pub r#level: u8,
+        #[doc = " A description of the test/component reporting."]
         pub r#name: ::std::string::String,
+        #[doc = " A description of the status."]
         pub r#message: ::std::string::String,
+        #[doc = " A hardware unique string."]
         pub r#hardware_id: ::std::string::String,
+        #[doc = " An array of values associated with the status."]
         pub r#values: ::std::vec::Vec<self::KeyValue>,
     }
     impl ::roslibrust::RosMessageType for DiagnosticStatus {
@@ -306,9 +356,18 @@ MSG: diagnostic_msgs/KeyValue
 string key
 # A value to track over time.
 string value"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_b0e3e692ea2d54a8af2f4ef1930e81556a2db55216b771f8a7d2724ed47bf0e4";
     }
     #[allow(unused)]
     impl DiagnosticStatus {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl DiagnosticStatus {
+        #[doc = " Possible levels of operations."]
         pub const r#OK: u8 = 0u8;
         pub const r#WARN: u8 = 1u8;
         pub const r#ERROR: u8 = 2u8;
@@ -325,7 +384,9 @@ string value"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct KeyValue {
+        #[doc = " What to label this value when viewing."]
         pub r#key: ::std::string::String,
+        #[doc = " A value to track over time."]
         pub r#value: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for KeyValue {
@@ -335,7 +396,19 @@ string value"####;
 string key
 # A value to track over time.
 string value"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_d68081eaa540288c5440753baecef0c4e16e81a5f78ad68902ded5100413bb42";
+    }
+    #[allow(unused)]
+    impl KeyValue {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This service is used as part of the process for loading analyzers at runtime,"]
+    #[doc = " and should be used by a loader script or program, not as a standalone service."]
+    #[doc = " Information about dynamic addition of analyzers can be found at"]
+    #[doc = " http://wiki.ros.org/diagnostics/Tutorials/Adding%20Analyzers%20at%20Runtime"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -347,6 +420,17 @@ string value"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct AddDiagnosticsRequest {
+        #[doc = " The load_namespace parameter defines the namespace where parameters for the"]
+        #[doc = " initialization of analyzers in the diagnostic aggregator have been loaded. The"]
+        #[doc = " value should be a global name (i.e. /my/name/space), not a relative"]
+        #[doc = " (my/name/space) or private (~my/name/space) name. Analyzers will not be added"]
+        #[doc = " if a non-global name is used. The call will also fail if the namespace"]
+        #[doc = " contains parameters that follow a namespace structure that does not conform to"]
+        #[doc = " that expected by the analyzer definitions. See"]
+        #[doc = " http://wiki.ros.org/diagnostics/Tutorials/Configuring%20Diagnostic%20Aggregators"]
+        #[doc = " and http://wiki.ros.org/diagnostics/Tutorials/Using%20the%20GenericAnalyzer"]
+        #[doc = " for examples of the structure of yaml files which are expected to have been"]
+        #[doc = " loaded into the namespace."]
         pub r#load_namespace: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for AddDiagnosticsRequest {
@@ -369,6 +453,14 @@ string value"####;
 # for examples of the structure of yaml files which are expected to have been
 # loaded into the namespace.
 string load_namespace"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_e0b6572a07c2b3ca2c4f2acf742297661f6ceb50f5baa4f9305b6094cf950146";
+    }
+    #[allow(unused)]
+    impl AddDiagnosticsRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -381,7 +473,12 @@ string load_namespace"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct AddDiagnosticsResponse {
+        #[doc = " True if diagnostic aggregator was updated with new diagnostics, False"]
+        #[doc = " otherwise. A false return value means that either there is a bond in the"]
+        #[doc = " aggregator which already used the requested namespace, or the initialization"]
+        #[doc = " of analyzers failed."]
         pub r#success: bool,
+        #[doc = " Message with additional information about the success or failure"]
         pub r#message: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for AddDiagnosticsResponse {
@@ -395,12 +492,22 @@ bool success
 
 # Message with additional information about the success or failure
 string message"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_e7070a2373c892c4cf071dcd90405d7696187eb2ff0e40d8b6472a04a4b73d22";
+    }
+    #[allow(unused)]
+    impl AddDiagnosticsResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct AddDiagnostics {}
     impl ::roslibrust::RosServiceType for AddDiagnostics {
         const ROS_SERVICE_NAME: &'static str = "diagnostic_msgs/AddDiagnostics";
         const MD5SUM: &'static str = "e6ac9bbde83d0d3186523c3687aecaee";
+        const TYPE_HASH: &'static str =
+            "RIHS01_6b91084c9f8bffd9b0c1f6883e0e8e491a831f4fc6c6aec77a4efaf3f1ea90b5";
         type Request = AddDiagnosticsRequest;
         type Response = AddDiagnosticsResponse;
     }
@@ -419,6 +526,14 @@ string message"####;
         const ROS_TYPE_NAME: &'static str = "diagnostic_msgs/SelfTestRequest";
         const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
         const DEFINITION: &'static str = r####""####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_3bb94b497e74e9beed2dddc7212d3ae596696d30413bd1550fe932fc20efb8a4";
+    }
+    #[allow(unused)]
+    impl SelfTestRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -466,19 +581,23 @@ MSG: diagnostic_msgs/KeyValue
 # What to label this value when viewing.
 string key
 # A value to track over time.
-string value
-================================================================================
-MSG: diagnostic_msgs/KeyValue
-# What to label this value when viewing.
-string key
-# A value to track over time.
 string value"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_489da82e00dce6671cb0ff423a0f0939b546336fbfe49d6c7e64c9b89f898f57";
+    }
+    #[allow(unused)]
+    impl SelfTestResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct SelfTest {}
     impl ::roslibrust::RosServiceType for SelfTest {
         const ROS_SERVICE_NAME: &'static str = "diagnostic_msgs/SelfTest";
         const MD5SUM: &'static str = "ac21b1bab7ab17546986536c22eb34e9";
+        const TYPE_HASH: &'static str =
+            "RIHS01_df1e4e54a814a16ccc77471e9239618d46818e8d0e3b45ad6f8c34c6e2abfef6";
         type Request = SelfTestRequest;
         type Response = SelfTestResponse;
     }
@@ -507,6 +626,7 @@ pub mod geometry_msgs {
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Accel {
+        #[doc = " This expresses acceleration in free space broken into its linear and angular parts."]
         pub r#linear: self::Vector3,
         pub r#angular: self::Vector3,
     }
@@ -527,6 +647,14 @@ MSG: geometry_msgs/Vector3
 float64 x
 float64 y
 float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_dc448243ded9b1fcbcca24aba0c22f013dae06c354ba2d849571c0a2a3f57ca0";
+    }
+    #[allow(unused)]
+    impl Accel {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -539,6 +667,7 @@ float64 z"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct AccelStamped {
+        #[doc = " An accel with reference coordinate frame and timestamp"]
         pub r#header: std_msgs::Header,
         pub r#accel: self::Accel,
     }
@@ -549,6 +678,17 @@ float64 z"####;
 std_msgs/Header header
 Accel accel
 ================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
+================================================================================
 MSG: geometry_msgs/Accel
 # This expresses acceleration in free space broken into its linear and angular parts.
 Vector3  linear
@@ -563,30 +703,17 @@ MSG: geometry_msgs/Vector3
 
 float64 x
 float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_ef1df9eabae0a708cc049a061ebcddc4e2a5f745730100ba680e086a9698b165";
+    }
+    #[allow(unused)]
+    impl AccelStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This expresses acceleration in free space with uncertainty."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -599,7 +726,11 @@ string frame_id"####;
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct AccelWithCovariance {
         pub r#accel: self::Accel,
-        #[default(_code = "[Default::default(); 36]")]
+        #[doc = " Row-major representation of the 6x6 covariance matrix"]
+        #[doc = " The orientation parameters use a fixed-axis representation."]
+        #[doc = " In order, the parameters are:"]
+        #[doc = " (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)"]
+        #[default(_code = "::std::array::from_fn(|_| Default::default())")]
         #[serde(with = "::roslibrust::codegen::BigArray")]
         pub r#covariance: [f64; 36],
     }
@@ -628,20 +759,17 @@ MSG: geometry_msgs/Vector3
 # A vector is always anchored at the origin.
 # When a transform is applied to a vector, only the rotational component is applied.
 
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
 float64 x
 float64 y
 float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_230d51bd53bc36f260574e73b42941cefe44684753480b6fc330c032c5db5997";
+    }
+    #[allow(unused)]
+    impl AccelWithCovariance {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -654,6 +782,7 @@ float64 z"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct AccelWithCovarianceStamped {
+        #[doc = " This represents an estimated accel with reference coordinate frame and timestamp."]
         pub r#header: std_msgs::Header,
         pub r#accel: self::AccelWithCovariance,
     }
@@ -664,21 +793,16 @@ float64 z"####;
 std_msgs/Header header
 AccelWithCovariance accel
 ================================================================================
-MSG: geometry_msgs/Accel
-# This expresses acceleration in free space broken into its linear and angular parts.
-Vector3  linear
-Vector3  angular
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x
-float64 y
-float64 z
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/AccelWithCovariance
 # This expresses acceleration in free space with uncertainty.
@@ -705,40 +829,15 @@ MSG: geometry_msgs/Vector3
 
 float64 x
 float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_61c9ad8928e71dd95ce791b2f02809ee2a0bbcc42cd0e4047fd00a822a08e444";
+    }
+    #[allow(unused)]
+    impl AccelWithCovarianceStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -751,8 +850,14 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Inertia {
+        #[doc = " Mass [kg]"]
         pub r#m: f64,
+        #[doc = " Center of mass [m]"]
         pub r#com: self::Vector3,
+        #[doc = " Inertia Tensor [kg-m^2]"]
+        #[doc = " | ixx ixy ixz |"]
+        #[doc = " I = | ixy iyy iyz |"]
+        #[doc = " | ixz iyz izz |"]
         pub r#ixx: f64,
         pub r#ixy: f64,
         pub r#ixz: f64,
@@ -790,7 +895,16 @@ MSG: geometry_msgs/Vector3
 float64 x
 float64 y
 float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_2ddd5dab5c347825ba2e56c895ddccfd0b8efe53ae931bf67f905529930b4bd7";
     }
+    #[allow(unused)]
+    impl Inertia {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " An Inertia with a time stamp and reference frame."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -813,6 +927,17 @@ float64 z"####;
 std_msgs/Header header
 Inertia inertia
 ================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
+================================================================================
 MSG: geometry_msgs/Inertia
 # Mass [kg]
 float64 m
@@ -840,29 +965,15 @@ MSG: geometry_msgs/Vector3
 
 float64 x
 float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_766be45976252babf7f9d8ac4ae7c912a7ceccf71035622529f27518b695aa09";
+    }
+    #[allow(unused)]
+    impl InertiaStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -875,6 +986,7 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Point {
+        #[doc = " This contains the position of a point in free space"]
         pub r#x: f64,
         pub r#y: f64,
         pub r#z: f64,
@@ -886,7 +998,22 @@ string frame_id"####;
 float64 x
 float64 y
 float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_6963084842a9b04494d6b2941d11444708d892da2f4b09843b9c43f42a7f6881";
+    }
+    #[allow(unused)]
+    impl Point {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This contains the position of a point in free space(with 32 bits of precision)."]
+    #[doc = " It is recommended to use Point wherever possible instead of Point32."]
+    #[doc = ""]
+    #[doc = " This recommendation is to promote interoperability."]
+    #[doc = ""]
+    #[doc = " This message is designed to take up less space when sending"]
+    #[doc = " lots of points at once, as in the case of a PointCloud."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -916,7 +1043,16 @@ float64 z"####;
 float32 x
 float32 y
 float32 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_2fc4db7cae16a4582c79a56b66173a8d48d52c7dc520ddc55a0d4bcf2a4bfdbc";
+    }
+    #[allow(unused)]
+    impl Point32 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This represents a Point with reference coordinate frame and timestamp"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -939,12 +1075,6 @@ float32 z"####;
 std_msgs/Header header
 Point point
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
 # This is generally used to communicate timestamped data
@@ -954,8 +1084,23 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
+string frame_id
+================================================================================
+MSG: geometry_msgs/Point
+# This contains the position of a point in free space
+float64 x
+float64 y
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_4c0296af86e01e562e9e0405d138a01537247580076c58ea38d7923ac1045897";
+    }
+    #[allow(unused)]
+    impl PointStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " A specification of a polygon where the first and last points are assumed to be connected"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -988,7 +1133,16 @@ MSG: geometry_msgs/Point32
 float32 x
 float32 y
 float32 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_3782f9f0bf044964d692d6c017d705e37611afb1f0bf6a9dee248a7dda0f784a";
+    }
+    #[allow(unused)]
+    impl Polygon {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This represents a Polygon with reference coordinate frame and timestamp"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1011,18 +1165,16 @@ float32 z"####;
 std_msgs/Header header
 Polygon polygon
 ================================================================================
-MSG: geometry_msgs/Point32
-# This contains the position of a point in free space(with 32 bits of precision).
-# It is recommended to use Point wherever possible instead of Point32.
-#
-# This recommendation is to promote interoperability.
-#
-# This message is designed to take up less space when sending
-# lots of points at once, as in the case of a PointCloud.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-float32 x
-float32 y
-float32 z
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Polygon
 # A specification of a polygon where the first and last points are assumed to be connected
@@ -1040,19 +1192,17 @@ MSG: geometry_msgs/Point32
 
 float32 x
 float32 y
-float32 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float32 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_b7cf07932f1523d4b4088075945c1a0141f7cd21da87cc940fc61652e9138b46";
+    }
+    #[allow(unused)]
+    impl PolygonStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " A representation of pose in free space, composed of position and orientation."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1088,7 +1238,17 @@ float64 x 0
 float64 y 0
 float64 z 0
 float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_d501954e9476cea2996984e812054b68026ae0bfae789d9a10b23daf35cc90fa";
     }
+    #[allow(unused)]
+    impl Pose {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " Deprecated as of Foxy and will potentially be removed in any following release."]
+    #[doc = " Please use the full 3D pose."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1117,7 +1277,16 @@ float64 w 1"####;
 float64 x
 float64 y
 float64 theta"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_d68efa5b46e70f7b16ca23085474fdac5a44b638783ec42f661da64da4724ccc";
+    }
+    #[allow(unused)]
+    impl Pose2D {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " An array of poses with a header for global reference."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1141,11 +1310,16 @@ std_msgs/Header header
 
 Pose[] poses
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -1165,27 +1339,17 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_af0cc36d190e104d546d168d6b39df04fa4b4ccecf59cb4c9ed328d3d5004aa0";
     }
+    #[allow(unused)]
+    impl PoseArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " A Pose with reference coordinate frame and timestamp"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1208,11 +1372,16 @@ string frame_id"####;
 std_msgs/Header header
 Pose pose
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -1232,27 +1401,17 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_10f3786d7d40fd2b54367835614bff85d4ad3b5dab62bf8bca0cc232d73b4cd8";
+    }
+    #[allow(unused)]
+    impl PoseStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This represents a pose in free space with uncertainty."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1265,7 +1424,11 @@ string frame_id"####;
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct PoseWithCovariance {
         pub r#pose: self::Pose,
-        #[default(_code = "[Default::default(); 36]")]
+        #[doc = " Row-major representation of the 6x6 covariance matrix"]
+        #[doc = " The orientation parameters use a fixed-axis representation."]
+        #[doc = " In order, the parameters are:"]
+        #[doc = " (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)"]
+        #[default(_code = "::std::array::from_fn(|_| Default::default())")]
         #[serde(with = "::roslibrust::codegen::BigArray")]
         pub r#covariance: [f64; 36],
     }
@@ -1282,12 +1445,6 @@ Pose pose
 # (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)
 float64[36] covariance
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -1303,19 +1460,20 @@ float64 z
 MSG: geometry_msgs/Quaternion
 # This represents an orientation in free space in quaternion form.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
 float64 x 0
 float64 y 0
 float64 z 0
 float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_9a7c0fd234b7f45c6098745ecccd773ca1085670e64107135397aee31c02e1bb";
+    }
+    #[allow(unused)]
+    impl PoseWithCovariance {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This expresses an estimated pose with a reference coordinate frame and timestamp"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1338,31 +1496,16 @@ float64 w 1"####;
 std_msgs/Header header
 PoseWithCovariance pose
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/PoseWithCovariance
 # This represents a pose in free space with uncertainty.
@@ -1375,12 +1518,6 @@ Pose pose
 # (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)
 float64[36] covariance
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -1399,35 +1536,17 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_26432f9803e43727d3c8f668d1fdb3c630f548af631e2f4e31382371bfea3b6e";
+    }
+    #[allow(unused)]
+    impl PoseWithCovarianceStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This represents an orientation in free space in quaternion form."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1457,7 +1576,16 @@ float64 x 0
 float64 y 0
 float64 z 0
 float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_8a765f66778c8ff7c8ab94afcc590a2ed5325a1d9a076ffff38fbce36f458684";
     }
+    #[allow(unused)]
+    impl Quaternion {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This represents an orientation with reference coordinate frame and timestamp."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1480,14 +1608,6 @@ float64 w 1"####;
 std_msgs/Header header
 Quaternion quaternion
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
 # This is generally used to communicate timestamped data
@@ -1497,8 +1617,25 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
+string frame_id
+================================================================================
+MSG: geometry_msgs/Quaternion
+# This represents an orientation in free space in quaternion form.
+
+float64 x 0
+float64 y 0
+float64 z 0
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_381add86c6c3160644d228ca342182c7fd6c7fab11c7a85ad817a9cc22dbac6e";
     }
+    #[allow(unused)]
+    impl QuaternionStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This represents the transform between two coordinate frames in free space."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1521,14 +1658,6 @@ string frame_id"####;
 Vector3 translation
 Quaternion rotation
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
 MSG: geometry_msgs/Vector3
 # This represents a vector in free space.
 
@@ -1538,8 +1667,34 @@ MSG: geometry_msgs/Vector3
 
 float64 x
 float64 y
-float64 z"####;
+float64 z
+================================================================================
+MSG: geometry_msgs/Quaternion
+# This represents an orientation in free space in quaternion form.
+
+float64 x 0
+float64 y 0
+float64 z 0
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_beb83fbe698636351461f6f35d1abb20010c43d55374d81bd041f1ba2581fddc";
     }
+    #[allow(unused)]
+    impl Transform {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This expresses a transform from coordinate frame header.frame_id"]
+    #[doc = " to the coordinate frame child_frame_id at the time of header.stamp"]
+    #[doc = ""]
+    #[doc = " This message is mostly used by the"]
+    #[doc = " <a href=\"https://index.ros.org/p/tf2/\">tf2</a> package."]
+    #[doc = " See its documentation for more information."]
+    #[doc = ""]
+    #[doc = " The child_frame_id is necessary in addition to the frame_id"]
+    #[doc = " in the Header to communicate the full reference for the transform"]
+    #[doc = " in a self contained message."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1551,8 +1706,11 @@ float64 z"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct TransformStamped {
+        #[doc = " The frame id in the header is used as the reference frame of this transform."]
         pub r#header: std_msgs::Header,
+        #[doc = " The frame id of the child frame to which this transform points."]
         pub r#child_frame_id: ::std::string::String,
+        #[doc = " Translation and rotation in 3-dimensions of child_frame_id from header.frame_id."]
         pub r#transform: self::Transform,
     }
     impl ::roslibrust::RosMessageType for TransformStamped {
@@ -1578,13 +1736,16 @@ string child_frame_id
 # Translation and rotation in 3-dimensions of child_frame_id from header.frame_id.
 Transform transform
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Transform
 # This represents the transform between two coordinate frames in free space.
@@ -1592,25 +1753,6 @@ MSG: geometry_msgs/Transform
 Vector3 translation
 Quaternion rotation
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Vector3
 # This represents a vector in free space.
 
@@ -1622,17 +1764,23 @@ float64 x
 float64 y
 float64 z
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+MSG: geometry_msgs/Quaternion
+# This represents an orientation in free space in quaternion form.
 
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 x 0
+float64 y 0
+float64 z 0
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_0a241f87d04668d94099cbb5ba11691d5ad32c2f29682e4eb5653424bd275206";
+    }
+    #[allow(unused)]
+    impl TransformStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This expresses velocity in free space broken into its linear and angular parts."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1665,7 +1813,16 @@ MSG: geometry_msgs/Vector3
 float64 x
 float64 y
 float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_9c45bf16fe0983d80e3cfe750d6835843d265a9a6c46bd2e609fcddde6fb8d2a";
+    }
+    #[allow(unused)]
+    impl Twist {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " A twist with reference coordinate frame and timestamp"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1688,6 +1845,17 @@ float64 z"####;
 std_msgs/Header header
 Twist twist
 ================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
+================================================================================
 MSG: geometry_msgs/Twist
 # This expresses velocity in free space broken into its linear and angular parts.
 
@@ -1703,30 +1871,17 @@ MSG: geometry_msgs/Vector3
 
 float64 x
 float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_5f0fcd4f81d5d06ad9b4c4c63e3ea51b82d6ae4d0558f1d475229b1121db6f64";
+    }
+    #[allow(unused)]
+    impl TwistStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This expresses velocity in free space with uncertainty."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1739,7 +1894,11 @@ string frame_id"####;
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct TwistWithCovariance {
         pub r#twist: self::Twist,
-        #[default(_code = "[Default::default(); 36]")]
+        #[doc = " Row-major representation of the 6x6 covariance matrix"]
+        #[doc = " The orientation parameters use a fixed-axis representation."]
+        #[doc = " In order, the parameters are:"]
+        #[doc = " (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)"]
+        #[default(_code = "::std::array::from_fn(|_| Default::default())")]
         #[serde(with = "::roslibrust::codegen::BigArray")]
         pub r#covariance: [f64; 36],
     }
@@ -1769,21 +1928,19 @@ MSG: geometry_msgs/Vector3
 # A vector is always anchored at the origin.
 # When a transform is applied to a vector, only the rotational component is applied.
 
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
 float64 x
 float64 y
 float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_49f574f033f095d8b6cd1beaca5ca7925e296e84af1716d16c89d38b059c8c18";
+    }
+    #[allow(unused)]
+    impl TwistWithCovariance {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This represents an estimated twist with reference coordinate frame and timestamp."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1806,22 +1963,16 @@ float64 z"####;
 std_msgs/Header header
 TwistWithCovariance twist
 ================================================================================
-MSG: geometry_msgs/Twist
-# This expresses velocity in free space broken into its linear and angular parts.
-
-Vector3  linear
-Vector3  angular
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x
-float64 y
-float64 z
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/TwistWithCovariance
 # This expresses velocity in free space with uncertainty.
@@ -1849,41 +2000,17 @@ MSG: geometry_msgs/Vector3
 
 float64 x
 float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_77b67434531e6529b7a0091357b186b6ebdb17fd9ffd3e0c7ce9d3fb11a44563";
+    }
+    #[allow(unused)]
+    impl TwistWithCovarianceStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This represents a vector in free space."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1911,7 +2038,16 @@ string frame_id"####;
 float64 x
 float64 y
 float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_cc12fe83e4c02719f1ce8070bfd14aecd40f75a96696a67a2a1f37f7dbb0765d";
     }
+    #[allow(unused)]
+    impl Vector3 {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This represents a Vector3 with reference coordinate frame and timestamp"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1937,17 +2073,6 @@ float64 z"####;
 std_msgs/Header header
 Vector3 vector
 ================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
 # This is generally used to communicate timestamped data
@@ -1957,8 +2082,28 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
+string frame_id
+================================================================================
+MSG: geometry_msgs/Vector3
+# This represents a vector in free space.
+
+# This is semantically different than a point.
+# A vector is always anchored at the origin.
+# When a transform is applied to a vector, only the rotational component is applied.
+
+float64 x
+float64 y
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_d4829622288cbb443886e7ea94ea5671a3b1be6bab4ad04224432a65f7d7887a";
+    }
+    #[allow(unused)]
+    impl Vector3Stamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This represents force in free space, separated into its linear and angular parts."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -1991,7 +2136,16 @@ MSG: geometry_msgs/Vector3
 float64 x
 float64 y
 float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_018e8519d57c16adbe97c9fe1460ef21fec7e31bc541de3d653a35895677ce52";
     }
+    #[allow(unused)]
+    impl Wrench {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " A wrench with reference coordinate frame and timestamp"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -2014,16 +2168,16 @@ float64 z"####;
 std_msgs/Header header
 Wrench wrench
 ================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x
-float64 y
-float64 z
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Wrench
 # This represents force in free space, separated into its linear and angular parts.
@@ -2040,18 +2194,15 @@ MSG: geometry_msgs/Vector3
 
 float64 x
 float64 y
-float64 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_8dc3deaf06b2ab281f9f9a742a8961c328ca7cec16e3fd6586d3a5c83fa78f77";
+    }
+    #[allow(unused)]
+    impl WrenchStamped {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
 }
 #[allow(unused_imports)]
@@ -2067,6 +2218,7 @@ pub mod nav_msgs {
     use super::test_msgs;
     use super::trajectory_msgs;
     use super::visualization_msgs;
+    #[doc = " An array of cells in a 2D grid"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -2079,8 +2231,11 @@ pub mod nav_msgs {
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct GridCells {
         pub r#header: std_msgs::Header,
+        #[doc = " Width of each cell"]
         pub r#cell_width: f32,
+        #[doc = " Height of each cell"]
         pub r#cell_height: f32,
+        #[doc = " Each cell is represented by the Point at the center of the cell"]
         pub r#cells: ::std::vec::Vec<geometry_msgs::Point>,
     }
     impl ::roslibrust::RosMessageType for GridCells {
@@ -2099,12 +2254,6 @@ float32 cell_height
 # Each cell is represented by the Point at the center of the cell
 geometry_msgs/Point[] cells
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
 # This is generally used to communicate timestamped data
@@ -2114,8 +2263,23 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
+string frame_id
+================================================================================
+MSG: geometry_msgs/Point
+# This contains the position of a point in free space
+float64 x
+float64 y
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_bb99c2f5d0a04750745a81ec6a8147aa373cce5bd17c8cd6507f2413354a6933";
+    }
+    #[allow(unused)]
+    impl GridCells {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This hold basic information about the characteristics of the OccupancyGrid"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -2127,10 +2291,16 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct MapMetaData {
+        #[doc = " The time at which the map was loaded"]
         pub r#map_load_time: ::roslibrust::codegen::integral_types::Time,
+        #[doc = " The map resolution [m/cell]"]
         pub r#resolution: f32,
+        #[doc = " Map width [cells]"]
         pub r#width: u32,
+        #[doc = " Map height [cells]"]
         pub r#height: u32,
+        #[doc = " The origin of the map [m, m, rad].  This is the real-world pose of the"]
+        #[doc = " bottom left corner of cell (0,0) in the map."]
         pub r#origin: geometry_msgs::Pose,
     }
     impl ::roslibrust::RosMessageType for MapMetaData {
@@ -2154,12 +2324,6 @@ uint32 height
 # bottom left corner of cell (0,0) in the map.
 geometry_msgs/Pose origin
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -2175,18 +2339,18 @@ float64 z
 MSG: geometry_msgs/Quaternion
 # This represents an orientation in free space in quaternion form.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
 float64 x 0
 float64 y 0
 float64 z 0
 float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_2772d4b2000ef2b35dbaeb80fd3946c1369f817fb4f75677d916d27c17d763c8";
+    }
+    #[allow(unused)]
+    impl MapMetaData {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -2199,8 +2363,16 @@ float64 w 1"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct OccupancyGrid {
+        #[doc = " This represents a 2-D grid map"]
         pub r#header: std_msgs::Header,
+        #[doc = " MetaData for the map"]
         pub r#info: self::MapMetaData,
+        #[doc = " The map data, in row-major order, starting with (0,0)."]
+        #[doc = " Cell (1, 0) will be listed second, representing the next cell in the x direction."]
+        #[doc = " Cell (0, 1) will be at the index equal to info.width, followed by (1, 1)."]
+        #[doc = " The values inside are application dependent, but frequently,"]
+        #[doc = " 0 represents unoccupied, 1 represents definitely occupied, and"]
+        #[doc = " -1 represents unknown."]
         pub r#data: ::std::vec::Vec<i8>,
     }
     impl ::roslibrust::RosMessageType for OccupancyGrid {
@@ -2220,39 +2392,16 @@ MapMetaData info
 # -1 represents unknown. 
 int8[] data
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: nav_msgs/MapMetaData
 # This hold basic information about the characteristics of the OccupancyGrid
@@ -2273,12 +2422,6 @@ uint32 height
 # bottom left corner of cell (0,0) in the map.
 geometry_msgs/Pose origin
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -2297,27 +2440,19 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_8d348150c12913a31ee0ec170fbf25089e4745d17035792a1ba94d6f0bc0cfc7";
+    }
+    #[allow(unused)]
+    impl OccupancyGrid {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This represents an estimate of a position and velocity in free space."]
+    #[doc = " The pose in this message should be specified in the coordinate frame given by header.frame_id"]
+    #[doc = " The twist in this message should be specified in the coordinate frame given by the child_frame_id"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -2329,9 +2464,13 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Odometry {
+        #[doc = " Includes the frame id of the pose parent."]
         pub r#header: std_msgs::Header,
+        #[doc = " Frame id the pose points to. The twist is in this coordinate frame."]
         pub r#child_frame_id: ::std::string::String,
+        #[doc = " Estimated pose that is typically relative to a fixed world frame."]
         pub r#pose: geometry_msgs::PoseWithCovariance,
+        #[doc = " Estimated linear and angular velocity relative to child_frame_id."]
         pub r#twist: geometry_msgs::TwistWithCovariance,
     }
     impl ::roslibrust::RosMessageType for Odometry {
@@ -2353,31 +2492,16 @@ geometry_msgs/PoseWithCovariance pose
 # Estimated linear and angular velocity relative to child_frame_id.
 geometry_msgs/TwistWithCovariance twist
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/PoseWithCovariance
 # This represents a pose in free space with uncertainty.
@@ -2390,12 +2514,6 @@ Pose pose
 # (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)
 float64[36] covariance
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -2416,39 +2534,6 @@ float64 y 0
 float64 z 0
 float64 w 1
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Twist
-# This expresses velocity in free space broken into its linear and angular parts.
-
-Vector3  linear
-Vector3  angular
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/TwistWithCovariance
 # This expresses velocity in free space with uncertainty.
 
@@ -2475,41 +2560,17 @@ MSG: geometry_msgs/Vector3
 
 float64 x
 float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_3cc97dc7fb7502f8714462c526d369e35b603cfc34d946e3f2eda2766dfec6e0";
     }
+    #[allow(unused)]
+    impl Odometry {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " An array of poses that represents a Path for a robot to follow."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -2521,7 +2582,9 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Path {
+        #[doc = " Indicates the frame_id of the path."]
         pub r#header: std_msgs::Header,
+        #[doc = " Array of poses to follow."]
         pub r#poses: ::std::vec::Vec<geometry_msgs::PoseStamped>,
     }
     impl ::roslibrust::RosMessageType for Path {
@@ -2535,31 +2598,16 @@ std_msgs/Header header
 # Array of poses to follow.
 geometry_msgs/PoseStamped[] poses
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/PoseStamped
 # A Pose with reference coordinate frame and timestamp
@@ -2567,12 +2615,6 @@ MSG: geometry_msgs/PoseStamped
 std_msgs/Header header
 Pose pose
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -2591,46 +2633,17 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_1957a5bb3cee5da65c4e52e52b65a93df227efce4c20f8458b36e73066ca334b";
+    }
+    #[allow(unused)]
+    impl Path {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Get the map as a nav_msgs/OccupancyGrid"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -2646,6 +2659,14 @@ string frame_id"####;
         const ROS_TYPE_NAME: &'static str = "nav_msgs/GetMapRequest";
         const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
         const DEFINITION: &'static str = r####"# Get the map as a nav_msgs/OccupancyGrid"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_b531e302f1bd6a81f8a5e03453266c378b1f711b4296000c1c0f53a40e00a7c4";
+    }
+    #[allow(unused)]
+    impl GetMapRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -2658,6 +2679,7 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct GetMapResponse {
+        #[doc = " The current map hosted by this map service."]
         pub r#map: self::OccupancyGrid,
     }
     impl ::roslibrust::RosMessageType for GetMapResponse {
@@ -2666,96 +2688,9 @@ string frame_id"####;
         const DEFINITION: &'static str = r####"# The current map hosted by this map service.
 OccupancyGrid map
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: nav_msgs/MapMetaData
-# This hold basic information about the characteristics of the OccupancyGrid
-
-# The time at which the map was loaded
-builtin_interfaces/Time map_load_time
-
-# The map resolution [m/cell]
-float32 resolution
-
-# Map width [cells]
-uint32 width
-
-# Map height [cells]
-uint32 height
-
-# The origin of the map [m, m, rad].  This is the real-world pose of the
-# bottom left corner of cell (0,0) in the map.
-geometry_msgs/Pose origin
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: nav_msgs/OccupancyGrid
-# This represents a 2-D grid map
-std_msgs/Header header
+MSG: nav_msgs/OccupancyGrid
+# This represents a 2-D grid map
+std_msgs/Header header
 
 # MetaData for the map
 MapMetaData info
@@ -2768,39 +2703,16 @@ MapMetaData info
 # -1 represents unknown. 
 int8[] data
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: nav_msgs/MapMetaData
 # This hold basic information about the characteristics of the OccupancyGrid
@@ -2821,12 +2733,6 @@ uint32 height
 # bottom left corner of cell (0,0) in the map.
 geometry_msgs/Pose origin
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -2845,46 +2751,27 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_45246f7a2ff0cbfa46e13865d4b95929ebb6d2846d1b85a20ad51030f4690dc2";
+    }
+    #[allow(unused)]
+    impl GetMapResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct GetMap {}
     impl ::roslibrust::RosServiceType for GetMap {
         const ROS_SERVICE_NAME: &'static str = "nav_msgs/GetMap";
         const MD5SUM: &'static str = "d6e8b0301af2dfe2244959ba20a4080a";
+        const TYPE_HASH: &'static str =
+            "RIHS01_c8ae77c9995b3554b5ba80e4d4d443f970ac65143102a1d893ec24fc07b31147";
         type Request = GetMapRequest;
         type Response = GetMapResponse;
     }
+    #[doc = " Get a plan from the current position to the goal Pose"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -2896,8 +2783,12 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct GetPlanRequest {
+        #[doc = " The start pose for the plan"]
         pub r#start: geometry_msgs::PoseStamped,
+        #[doc = " The final pose of the goal position"]
         pub r#goal: geometry_msgs::PoseStamped,
+        #[doc = " If the goal is obstructed, how many meters the planner can"]
+        #[doc = " relax the constraint in x and y before failing."]
         pub r#tolerance: f32,
     }
     impl ::roslibrust::RosMessageType for GetPlanRequest {
@@ -2915,43 +2806,22 @@ geometry_msgs/PoseStamped goal
 # relax the constraint in x and y before failing.
 float32 tolerance
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
 MSG: geometry_msgs/PoseStamped
 # A Pose with reference coordinate frame and timestamp
 
 std_msgs/Header header
 Pose pose
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -2971,45 +2841,15 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_f1bb9fab287948b4432a4a7e4f529e7ea6269cd06269ee8fcb911fdb4acd23c3";
+    }
+    #[allow(unused)]
+    impl GetPlanRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -3022,6 +2862,7 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct GetPlanResponse {
+        #[doc = " Array of poses from start to goal if one was successfully found."]
         pub r#plan: self::Path,
     }
     impl ::roslibrust::RosMessageType for GetPlanResponse {
@@ -3030,71 +2871,14 @@ string frame_id"####;
         const DEFINITION: &'static str = r####"# Array of poses from start to goal if one was successfully found.
 Path plan
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+MSG: nav_msgs/Path
+# An array of poses that represents a Path for a robot to follow.
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/PoseStamped
-# A Pose with reference coordinate frame and timestamp
-
-std_msgs/Header header
-Pose pose
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Indicates the frame_id of the path.
+std_msgs/Header header
+
+# Array of poses to follow.
+geometry_msgs/PoseStamped[] poses
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -3107,61 +2891,12 @@ builtin_interfaces/Time stamp
 # Transform frame with which this data is associated.
 string frame_id
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: nav_msgs/Path
-# An array of poses that represents a Path for a robot to follow.
-
-# Indicates the frame_id of the path.
-std_msgs/Header header
-
-# Array of poses to follow.
-geometry_msgs/PoseStamped[] poses
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
 MSG: geometry_msgs/PoseStamped
 # A Pose with reference coordinate frame and timestamp
 
 std_msgs/Header header
 Pose pose
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -3180,62 +2915,23 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_30a947cd360777929073296c368c591dc2117a89c15b7a975a6faa0a901836fd";
+    }
+    #[allow(unused)]
+    impl GetPlanResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct GetPlan {}
     impl ::roslibrust::RosServiceType for GetPlan {
         const ROS_SERVICE_NAME: &'static str = "nav_msgs/GetPlan";
         const MD5SUM: &'static str = "135edd06523950427d2cf5e0bb9780a2";
+        const TYPE_HASH: &'static str =
+            "RIHS01_234f7aff100f5edb8150366601687b027bcdc253db47decb88fff846193fe5e8";
         type Request = GetPlanRequest;
         type Response = GetPlanResponse;
     }
@@ -3250,6 +2946,9 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct LoadMapRequest {
+        #[doc = " URL of map resource"]
+        #[doc = " Can be an absolute path to a file: file:///path/to/maps/floor1.yaml"]
+        #[doc = " Or, relative to a ROS package: package://my_ros_package/maps/floor2.yaml"]
         pub r#map_url: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for LoadMapRequest {
@@ -3259,6 +2958,14 @@ string frame_id"####;
 # Can be an absolute path to a file: file:///path/to/maps/floor1.yaml
 # Or, relative to a ROS package: package://my_ros_package/maps/floor2.yaml
 string map_url"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_0f8fe3aac433c977d7c2f8b57ab1c3a17677e24880d76919b69e4208e99f5132";
+    }
+    #[allow(unused)]
+    impl LoadMapRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -3271,6 +2978,7 @@ string map_url"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct LoadMapResponse {
+        #[doc = " Returned map is only valid if result equals RESULT_SUCCESS"]
         pub r#map: self::OccupancyGrid,
         pub r#result: u8,
     }
@@ -3288,39 +2996,31 @@ uint8 RESULT_UNDEFINED_FAILURE=255
 nav_msgs/OccupancyGrid map
 uint8 result
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+MSG: nav_msgs/OccupancyGrid
+# This represents a 2-D grid map
+std_msgs/Header header
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# MetaData for the map
+MapMetaData info
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# The map data, in row-major order, starting with (0,0). 
+# Cell (1, 0) will be listed second, representing the next cell in the x direction. 
+# Cell (0, 1) will be at the index equal to info.width, followed by (1, 1).
+# The values inside are application dependent, but frequently, 
+# 0 represents unoccupied, 1 represents definitely occupied, and
+# -1 represents unknown. 
+int8[] data
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: nav_msgs/MapMetaData
 # This hold basic information about the characteristics of the OccupancyGrid
@@ -3341,12 +3041,6 @@ uint32 height
 # bottom left corner of cell (0,0) in the map.
 geometry_msgs/Pose origin
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -3365,15 +3059,62 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_e7970ddf9f58e10d34b5eae6d2baca31b2b3e2f2fe6d082b3aae2a5f92777b2a";
+    }
+    #[allow(unused)]
+    impl LoadMapResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl LoadMapResponse {
+        #[doc = " Result code defintions"]
+        pub const r#RESULT_SUCCESS: u8 = 0u8;
+        pub const r#RESULT_MAP_DOES_NOT_EXIST: u8 = 1u8;
+        pub const r#RESULT_INVALID_MAP_DATA: u8 = 2u8;
+        pub const r#RESULT_INVALID_MAP_METADATA: u8 = 3u8;
+        pub const r#RESULT_UNDEFINED_FAILURE: u8 = 255u8;
+    }
+    #[allow(dead_code)]
+    pub struct LoadMap {}
+    impl ::roslibrust::RosServiceType for LoadMap {
+        const ROS_SERVICE_NAME: &'static str = "nav_msgs/LoadMap";
+        const MD5SUM: &'static str = "96c8a15e8fe5c33ee245f610f020d6ba";
+        const TYPE_HASH: &'static str =
+            "RIHS01_1a192ac56c40fed2767dac26f0b371785372276bd465c902676d2dca135aae5a";
+        type Request = LoadMapRequest;
+        type Response = LoadMapResponse;
+    }
+    #[doc = " Set a new map together with an initial pose"]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct SetMapRequest {
+        #[doc = " Requested 2D map to be set."]
+        pub r#map: self::OccupancyGrid,
+        #[doc = " Estimated initial pose when setting new map."]
+        pub r#initial_pose: geometry_msgs::PoseWithCovarianceStamped,
+    }
+    impl ::roslibrust::RosMessageType for SetMapRequest {
+        const ROS_TYPE_NAME: &'static str = "nav_msgs/SetMapRequest";
+        const MD5SUM: &'static str = "98782a373ad73e1165352caf85923850";
+        const DEFINITION: &'static str = r####"# Set a new map together with an initial pose
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Requested 2D map to be set.
+nav_msgs/OccupancyGrid map
+
+# Estimated initial pose when setting new map.
+geometry_msgs/PoseWithCovarianceStamped initial_pose
 ================================================================================
 MSG: nav_msgs/OccupancyGrid
 # This represents a 2-D grid map
@@ -3390,39 +3131,16 @@ MapMetaData info
 # -1 represents unknown. 
 int8[] data
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: nav_msgs/MapMetaData
 # This hold basic information about the characteristics of the OccupancyGrid
@@ -3443,12 +3161,6 @@ uint32 height
 # bottom left corner of cell (0,0) in the map.
 geometry_msgs/Pose origin
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -3469,24 +3181,186 @@ float64 y 0
 float64 z 0
 float64 w 1
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: geometry_msgs/PoseWithCovarianceStamped
+# This expresses an estimated pose with a reference coordinate frame and timestamp
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+std_msgs/Header header
+PoseWithCovariance pose
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+MSG: geometry_msgs/PoseWithCovariance
+# This represents a pose in free space with uncertainty.
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+Pose pose
 
-# Transform frame with which this data is associated.
-string frame_id
+# Row-major representation of the 6x6 covariance matrix
+# The orientation parameters use a fixed-axis representation.
+# In order, the parameters are:
+# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)
+float64[36] covariance"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_5a83a0c20af4d79248fc3287524bf358b603fb5a05818b5ec85b50fa6e5d0266";
+    }
+    #[allow(unused)]
+    impl SetMapRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct SetMapResponse {
+        #[doc = " True if the map was successfully set, false otherwise."]
+        pub r#success: bool,
+    }
+    impl ::roslibrust::RosMessageType for SetMapResponse {
+        const ROS_TYPE_NAME: &'static str = "nav_msgs/SetMapResponse";
+        const MD5SUM: &'static str = "358e233cde0c8a8bcfea4ce193f8fc15";
+        const DEFINITION: &'static str = r####"# True if the map was successfully set, false otherwise.
+bool success"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_e7251879322c7b12285b9c191d68d27495b6dede051f3cd79b0bf334147b7375";
+    }
+    #[allow(unused)]
+    impl SetMapResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(dead_code)]
+    pub struct SetMap {}
+    impl ::roslibrust::RosServiceType for SetMap {
+        const ROS_SERVICE_NAME: &'static str = "nav_msgs/SetMap";
+        const MD5SUM: &'static str = "6c3f8182fbcb3d4ee7aef02d1dcd1e16";
+        const TYPE_HASH: &'static str =
+            "RIHS01_5e11a5b2ca53d8ae85b666a019f16c9904ebc787828f1f566c4e048a1ddedfb4";
+        type Request = SetMapRequest;
+        type Response = SetMapResponse;
+    }
+}
+#[allow(unused_imports)]
+pub mod sensor_msgs {
+    use super::actionlib_msgs;
+    use super::diagnostic_msgs;
+    use super::geometry_msgs;
+    use super::nav_msgs;
+    use super::shape_msgs;
+    use super::std_msgs;
+    use super::std_srvs;
+    use super::stereo_msgs;
+    use super::test_msgs;
+    use super::trajectory_msgs;
+    use super::visualization_msgs;
+    #[doc = " Constants are chosen to match the enums in the linux kernel"]
+    #[doc = " defined in include/linux/power_supply.h as of version 3.7"]
+    #[doc = " The one difference is for style reasons the constants are"]
+    #[doc = " all uppercase not mixed case."]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct BatteryState {
+        pub r#header: std_msgs::Header,
+        #[doc = " Voltage in Volts (Mandatory)"]
+        pub r#voltage: f32,
+        #[doc = " Temperature in Degrees Celsius (If unmeasured NaN)"]
+        pub r#temperature: f32,
+        #[doc = " Negative when discharging (A)  (If unmeasured NaN)"]
+        pub r#current: f32,
+        #[doc = " Current charge in Ah  (If unmeasured NaN)"]
+        pub r#charge: f32,
+        #[doc = " Capacity in Ah (last full capacity)  (If unmeasured NaN)"]
+        pub r#capacity: f32,
+        #[doc = " Capacity in Ah (design capacity)  (If unmeasured NaN)"]
+        pub r#design_capacity: f32,
+        #[doc = " Charge percentage on 0 to 1 range  (If unmeasured NaN)"]
+        pub r#percentage: f32,
+        #[doc = " The charging status as reported. Values defined above"]
+        pub r#power_supply_status: u8,
+        #[doc = " The battery health metric. Values defined above"]
+        pub r#power_supply_health: u8,
+        #[doc = " The battery chemistry. Values defined above"]
+        pub r#power_supply_technology: u8,
+        #[doc = " True if the battery is present"]
+        pub r#present: bool,
+        #[doc = " An array of individual cell voltages for each cell in the pack"]
+        pub r#cell_voltage: ::std::vec::Vec<f32>,
+        #[doc = " If individual voltages unknown but number of cells known set each to NaN"]
+        #[doc = " An array of individual cell temperatures for each cell in the pack"]
+        pub r#cell_temperature: ::std::vec::Vec<f32>,
+        #[doc = " If individual temperatures unknown but number of cells known set each to NaN"]
+        #[doc = " The location into which the battery is inserted. (slot number or plug)"]
+        pub r#location: ::std::string::String,
+        #[doc = " The best approximation of the battery serial number"]
+        pub r#serial_number: ::std::string::String,
+    }
+    impl ::roslibrust::RosMessageType for BatteryState {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/BatteryState";
+        const MD5SUM: &'static str = "0e25cedcd370a46961764fe3a9d2ddcb";
+        const DEFINITION: &'static str = r####"# Constants are chosen to match the enums in the linux kernel
+# defined in include/linux/power_supply.h as of version 3.7
+# The one difference is for style reasons the constants are
+# all uppercase not mixed case.
+
+# Power supply status constants
+uint8 POWER_SUPPLY_STATUS_UNKNOWN = 0
+uint8 POWER_SUPPLY_STATUS_CHARGING = 1
+uint8 POWER_SUPPLY_STATUS_DISCHARGING = 2
+uint8 POWER_SUPPLY_STATUS_NOT_CHARGING = 3
+uint8 POWER_SUPPLY_STATUS_FULL = 4
+
+# Power supply health constants
+uint8 POWER_SUPPLY_HEALTH_UNKNOWN = 0
+uint8 POWER_SUPPLY_HEALTH_GOOD = 1
+uint8 POWER_SUPPLY_HEALTH_OVERHEAT = 2
+uint8 POWER_SUPPLY_HEALTH_DEAD = 3
+uint8 POWER_SUPPLY_HEALTH_OVERVOLTAGE = 4
+uint8 POWER_SUPPLY_HEALTH_UNSPEC_FAILURE = 5
+uint8 POWER_SUPPLY_HEALTH_COLD = 6
+uint8 POWER_SUPPLY_HEALTH_WATCHDOG_TIMER_EXPIRE = 7
+uint8 POWER_SUPPLY_HEALTH_SAFETY_TIMER_EXPIRE = 8
+
+# Power supply technology (chemistry) constants
+uint8 POWER_SUPPLY_TECHNOLOGY_UNKNOWN = 0
+uint8 POWER_SUPPLY_TECHNOLOGY_NIMH = 1
+uint8 POWER_SUPPLY_TECHNOLOGY_LION = 2
+uint8 POWER_SUPPLY_TECHNOLOGY_LIPO = 3
+uint8 POWER_SUPPLY_TECHNOLOGY_LIFE = 4
+uint8 POWER_SUPPLY_TECHNOLOGY_NICD = 5
+uint8 POWER_SUPPLY_TECHNOLOGY_LIMN = 6
+
+std_msgs/Header  header
+float32 voltage          # Voltage in Volts (Mandatory)
+float32 temperature      # Temperature in Degrees Celsius (If unmeasured NaN)
+float32 current          # Negative when discharging (A)  (If unmeasured NaN)
+float32 charge           # Current charge in Ah  (If unmeasured NaN)
+float32 capacity         # Capacity in Ah (last full capacity)  (If unmeasured NaN)
+float32 design_capacity  # Capacity in Ah (design capacity)  (If unmeasured NaN)
+float32 percentage       # Charge percentage on 0 to 1 range  (If unmeasured NaN)
+uint8   power_supply_status     # The charging status as reported. Values defined above
+uint8   power_supply_health     # The battery health metric. Values defined above
+uint8   power_supply_technology # The battery chemistry. Values defined above
+bool    present          # True if the battery is present
+
+float32[] cell_voltage   # An array of individual cell voltages for each cell in the pack
+                         # If individual voltages unknown but number of cells known set each to NaN
+float32[] cell_temperature # An array of individual cell temperatures for each cell in the pack
+                           # If individual temperatures unknown but number of cells known set each to NaN
+string location          # The location into which the battery is inserted. (slot number or plug)
+string serial_number     # The best approximation of the battery serial number
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -3498,23 +3372,65 @@ builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_4bee5dfce981c98faa6828b868307a0a73f992ed0789f374ee96c8f840e69741";
     }
     #[allow(unused)]
-    impl LoadMapResponse {
-        pub const r#RESULT_SUCCESS: u8 = 0u8;
-        pub const r#RESULT_MAP_DOES_NOT_EXIST: u8 = 1u8;
-        pub const r#RESULT_INVALID_MAP_DATA: u8 = 2u8;
-        pub const r#RESULT_INVALID_MAP_METADATA: u8 = 3u8;
-        pub const r#RESULT_UNDEFINED_FAILURE: u8 = 255u8;
+    impl BatteryState {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
-    #[allow(dead_code)]
-    pub struct LoadMap {}
-    impl ::roslibrust::RosServiceType for LoadMap {
-        const ROS_SERVICE_NAME: &'static str = "nav_msgs/LoadMap";
-        const MD5SUM: &'static str = "96c8a15e8fe5c33ee245f610f020d6ba";
-        type Request = LoadMapRequest;
-        type Response = LoadMapResponse;
+    #[allow(unused)]
+    impl BatteryState {
+        #[doc = " Power supply status constants"]
+        pub const r#POWER_SUPPLY_STATUS_UNKNOWN: u8 = 0u8;
+        pub const r#POWER_SUPPLY_STATUS_CHARGING: u8 = 1u8;
+        pub const r#POWER_SUPPLY_STATUS_DISCHARGING: u8 = 2u8;
+        pub const r#POWER_SUPPLY_STATUS_NOT_CHARGING: u8 = 3u8;
+        pub const r#POWER_SUPPLY_STATUS_FULL: u8 = 4u8;
+        #[doc = " Power supply health constants"]
+        pub const r#POWER_SUPPLY_HEALTH_UNKNOWN: u8 = 0u8;
+        pub const r#POWER_SUPPLY_HEALTH_GOOD: u8 = 1u8;
+        pub const r#POWER_SUPPLY_HEALTH_OVERHEAT: u8 = 2u8;
+        pub const r#POWER_SUPPLY_HEALTH_DEAD: u8 = 3u8;
+        pub const r#POWER_SUPPLY_HEALTH_OVERVOLTAGE: u8 = 4u8;
+        pub const r#POWER_SUPPLY_HEALTH_UNSPEC_FAILURE: u8 = 5u8;
+        pub const r#POWER_SUPPLY_HEALTH_COLD: u8 = 6u8;
+        pub const r#POWER_SUPPLY_HEALTH_WATCHDOG_TIMER_EXPIRE: u8 = 7u8;
+        pub const r#POWER_SUPPLY_HEALTH_SAFETY_TIMER_EXPIRE: u8 = 8u8;
+        #[doc = " Power supply technology (chemistry) constants"]
+        pub const r#POWER_SUPPLY_TECHNOLOGY_UNKNOWN: u8 = 0u8;
+        pub const r#POWER_SUPPLY_TECHNOLOGY_NIMH: u8 = 1u8;
+        pub const r#POWER_SUPPLY_TECHNOLOGY_LION: u8 = 2u8;
+        pub const r#POWER_SUPPLY_TECHNOLOGY_LIPO: u8 = 3u8;
+        pub const r#POWER_SUPPLY_TECHNOLOGY_LIFE: u8 = 4u8;
+        pub const r#POWER_SUPPLY_TECHNOLOGY_NICD: u8 = 5u8;
+        pub const r#POWER_SUPPLY_TECHNOLOGY_LIMN: u8 = 6u8;
     }
+    #[doc = " This message defines meta information for a camera. It should be in a"]
+    #[doc = " camera namespace on topic \"camera_info\" and accompanied by up to five"]
+    #[doc = " image topics named:"]
+    #[doc = ""]
+    #[doc = " image_raw - raw data from the camera driver, possibly Bayer encoded"]
+    #[doc = " image            - monochrome, distorted"]
+    #[doc = " image_color      - color, distorted"]
+    #[doc = " image_rect       - monochrome, rectified"]
+    #[doc = " image_rect_color - color, rectified"]
+    #[doc = ""]
+    #[doc = " The image_pipeline contains packages (image_proc, stereo_image_proc)"]
+    #[doc = " for producing the four processed image topics from image_raw and"]
+    #[doc = " camera_info. The meaning of the camera parameters are described in"]
+    #[doc = " detail at http://www.ros.org/wiki/image_pipeline/CameraInfo."]
+    #[doc = ""]
+    #[doc = " The image_geometry package provides a user-friendly interface to"]
+    #[doc = " common operations using this meta information. If you want to, e.g.,"]
+    #[doc = " project a 3d point into image coordinates, we strongly recommend"]
+    #[doc = " using image_geometry."]
+    #[doc = ""]
+    #[doc = " If the camera is uncalibrated, the matrices D, K, R, P should be left"]
+    #[doc = " zeroed out. In particular, clients may assume that K[0] == 0.0"]
+    #[doc = " indicates an uncalibrated camera."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -3525,176 +3441,212 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct SetMapRequest {
-        pub r#map: self::OccupancyGrid,
-        pub r#initial_pose: geometry_msgs::PoseWithCovarianceStamped,
-    }
-    impl ::roslibrust::RosMessageType for SetMapRequest {
-        const ROS_TYPE_NAME: &'static str = "nav_msgs/SetMapRequest";
-        const MD5SUM: &'static str = "98782a373ad73e1165352caf85923850";
-        const DEFINITION: &'static str = r####"# Set a new map together with an initial pose
-
-# Requested 2D map to be set.
-nav_msgs/OccupancyGrid map
-
-# Estimated initial pose when setting new map.
-geometry_msgs/PoseWithCovarianceStamped initial_pose
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+    pub struct CameraInfo {
+        #[doc = " Time of image acquisition, camera coordinate frame ID"]
+        #[doc = " Header timestamp should be acquisition time of image"]
+        pub r#header: std_msgs::Header,
+        #[doc = " The image dimensions with which the camera was calibrated."]
+        #[doc = " Normally this will be the full camera resolution in pixels."]
+        pub r#height: u32,
+        pub r#width: u32,
+        #[doc = " The distortion model used. Supported models are listed in"]
+        #[doc = " sensor_msgs/distortion_models.hpp. For most cameras, \"plumb_bob\" - a"]
+        #[doc = " simple model of radial and tangential distortion - is sufficent."]
+        pub r#distortion_model: ::std::string::String,
+        #[doc = " The distortion parameters, size depending on the distortion model."]
+        #[doc = " For \"plumb_bob\", the 5 parameters are: (k1, k2, t1, t2, k3)."]
+        pub r#d: ::std::vec::Vec<f64>,
+        #[doc = " Intrinsic camera matrix for the raw (distorted) images."]
+        #[doc = " [fx  0 cx]"]
+        #[doc = " K = [ 0 fy cy]"]
+        #[doc = " [ 0  0  1]"]
+        #[doc = " Projects 3D points in the camera coordinate frame to 2D pixel"]
+        #[doc = " coordinates using the focal lengths (fx, fy) and principal point"]
+        #[doc = " (cx, cy)."]
+        #[doc = " 3x3 row-major matrix"]
+        pub r#k: [f64; 9],
+        #[doc = " Rectification matrix (stereo cameras only)"]
+        #[doc = " A rotation matrix aligning the camera coordinate system to the ideal"]
+        #[doc = " stereo image plane so that epipolar lines in both stereo images are"]
+        #[doc = " parallel."]
+        #[doc = " 3x3 row-major matrix"]
+        pub r#r: [f64; 9],
+        #[doc = " Projection/camera matrix"]
+        #[doc = " [fx'  0  cx' Tx]"]
+        #[doc = " P = [ 0  fy' cy' Ty]"]
+        #[doc = " [ 0   0   1   0]"]
+        #[doc = " By convention, this matrix specifies the intrinsic (camera) matrix"]
+        #[doc = " of the processed (rectified) image. That is, the left 3x3 portion"]
+        #[doc = " is the normal camera intrinsic matrix for the rectified image."]
+        #[doc = " It projects 3D points in the camera coordinate frame to 2D pixel"]
+        #[doc = " coordinates using the focal lengths (fx', fy') and principal point"]
+        #[doc = " (cx', cy') - these may differ from the values in K."]
+        #[doc = " For monocular cameras, Tx = Ty = 0. Normally, monocular cameras will"]
+        #[doc = " also have R = the identity and P[1:3,1:3] = K."]
+        #[doc = " For a stereo pair, the fourth column [Tx Ty 0]' is related to the"]
+        #[doc = " position of the optical center of the second camera in the first"]
+        #[doc = " camera's frame. We assume Tz = 0 so both cameras are in the same"]
+        #[doc = " stereo image plane. The first camera always has Tx = Ty = 0. For"]
+        #[doc = " the right (second) camera of a horizontal stereo pair, Ty = 0 and"]
+        #[doc = " Tx = -fx' * B, where B is the baseline between the cameras."]
+        #[doc = " Given a 3D point [X Y Z]', the projection (x, y) of the point onto"]
+        #[doc = " the rectified image is given by:"]
+        #[doc = " [u v w]' = P * [X Y Z 1]'"]
+        #[doc = " x = u / w"]
+        #[doc = " y = v / w"]
+        #[doc = " This holds for both images of a stereo pair."]
+        #[doc = " 3x4 row-major matrix"]
+        pub r#p: [f64; 12],
+        #[doc = " Binning refers here to any camera setting which combines rectangular"]
+        #[doc = " neighborhoods of pixels into larger \"super-pixels.\" It reduces the"]
+        #[doc = " resolution of the output image to"]
+        #[doc = " (width / binning_x) x (height / binning_y)."]
+        #[doc = " The default values binning_x = binning_y = 0 is considered the same"]
+        #[doc = " as binning_x = binning_y = 1 (no subsampling)."]
+        pub r#binning_x: u32,
+        pub r#binning_y: u32,
+        #[doc = " Region of interest (subwindow of full camera resolution), given in"]
+        #[doc = " full resolution (unbinned) image coordinates. A particular ROI"]
+        #[doc = " always denotes the same window of pixels on the camera sensor,"]
+        #[doc = " regardless of binning settings."]
+        #[doc = " The default setting of roi (all values 0) is considered the same as"]
+        #[doc = " full resolution (roi.width = width, roi.height = height)."]
+        pub r#roi: self::RegionOfInterest,
+    }
+    impl ::roslibrust::RosMessageType for CameraInfo {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/CameraInfo";
+        const MD5SUM: &'static str = "47b55ddbbf2ec398f94cddf328bbc2ac";
+        const DEFINITION: &'static str = r####"# This message defines meta information for a camera. It should be in a
+# camera namespace on topic "camera_info" and accompanied by up to five
+# image topics named:
+#
+#   image_raw - raw data from the camera driver, possibly Bayer encoded
+#   image            - monochrome, distorted
+#   image_color      - color, distorted
+#   image_rect       - monochrome, rectified
+#   image_rect_color - color, rectified
+#
+# The image_pipeline contains packages (image_proc, stereo_image_proc)
+# for producing the four processed image topics from image_raw and
+# camera_info. The meaning of the camera parameters are described in
+# detail at http://www.ros.org/wiki/image_pipeline/CameraInfo.
+#
+# The image_geometry package provides a user-friendly interface to
+# common operations using this meta information. If you want to, e.g.,
+# project a 3d point into image coordinates, we strongly recommend
+# using image_geometry.
+#
+# If the camera is uncalibrated, the matrices D, K, R, P should be left
+# zeroed out. In particular, clients may assume that K[0] == 0.0
+# indicates an uncalibrated camera.
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+#######################################################################
+#                     Image acquisition info                          #
+#######################################################################
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/PoseWithCovariance
-# This represents a pose in free space with uncertainty.
+# Time of image acquisition, camera coordinate frame ID
+std_msgs/Header header # Header timestamp should be acquisition time of image
+                             # Header frame_id should be optical frame of camera
+                             # origin of frame should be optical center of camera
+                             # +x should point to the right in the image
+                             # +y should point down in the image
+                             # +z should point into the plane of the image
 
-Pose pose
 
-# Row-major representation of the 6x6 covariance matrix
-# The orientation parameters use a fixed-axis representation.
-# In order, the parameters are:
-# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)
-float64[36] covariance
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+#######################################################################
+#                      Calibration Parameters                         #
+#######################################################################
+# These are fixed during camera calibration. Their values will be the #
+# same in all messages until the camera is recalibrated. Note that    #
+# self-calibrating systems may "recalibrate" frequently.              #
+#                                                                     #
+# The internal parameters can be used to warp a raw (distorted) image #
+# to:                                                                 #
+#   1. An undistorted image (requires D and K)                        #
+#   2. A rectified image (requires D, K, R)                           #
+# The projection matrix P projects 3D points into the rectified image.#
+#######################################################################
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# The image dimensions with which the camera was calibrated.
+# Normally this will be the full camera resolution in pixels.
+uint32 height
+uint32 width
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# The distortion model used. Supported models are listed in
+# sensor_msgs/distortion_models.hpp. For most cameras, "plumb_bob" - a
+# simple model of radial and tangential distortion - is sufficent.
+string distortion_model
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/PoseWithCovarianceStamped
-# This expresses an estimated pose with a reference coordinate frame and timestamp
+# The distortion parameters, size depending on the distortion model.
+# For "plumb_bob", the 5 parameters are: (k1, k2, t1, t2, k3).
+float64[] d
 
-std_msgs/Header header
-PoseWithCovariance pose
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+# Intrinsic camera matrix for the raw (distorted) images.
+#     [fx  0 cx]
+# K = [ 0 fy cy]
+#     [ 0  0  1]
+# Projects 3D points in the camera coordinate frame to 2D pixel
+# coordinates using the focal lengths (fx, fy) and principal point
+# (cx, cy).
+float64[9]  k # 3x3 row-major matrix
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Rectification matrix (stereo cameras only)
+# A rotation matrix aligning the camera coordinate system to the ideal
+# stereo image plane so that epipolar lines in both stereo images are
+# parallel.
+float64[9]  r # 3x3 row-major matrix
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/PoseWithCovariance
-# This represents a pose in free space with uncertainty.
+# Projection/camera matrix
+#     [fx'  0  cx' Tx]
+# P = [ 0  fy' cy' Ty]
+#     [ 0   0   1   0]
+# By convention, this matrix specifies the intrinsic (camera) matrix
+#  of the processed (rectified) image. That is, the left 3x3 portion
+#  is the normal camera intrinsic matrix for the rectified image.
+# It projects 3D points in the camera coordinate frame to 2D pixel
+#  coordinates using the focal lengths (fx', fy') and principal point
+#  (cx', cy') - these may differ from the values in K.
+# For monocular cameras, Tx = Ty = 0. Normally, monocular cameras will
+#  also have R = the identity and P[1:3,1:3] = K.
+# For a stereo pair, the fourth column [Tx Ty 0]' is related to the
+#  position of the optical center of the second camera in the first
+#  camera's frame. We assume Tz = 0 so both cameras are in the same
+#  stereo image plane. The first camera always has Tx = Ty = 0. For
+#  the right (second) camera of a horizontal stereo pair, Ty = 0 and
+#  Tx = -fx' * B, where B is the baseline between the cameras.
+# Given a 3D point [X Y Z]', the projection (x, y) of the point onto
+#  the rectified image is given by:
+#  [u v w]' = P * [X Y Z 1]'
+#         x = u / w
+#         y = v / w
+#  This holds for both images of a stereo pair.
+float64[12] p # 3x4 row-major matrix
 
-Pose pose
 
-# Row-major representation of the 6x6 covariance matrix
-# The orientation parameters use a fixed-axis representation.
-# In order, the parameters are:
-# (x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)
-float64[36] covariance
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+#######################################################################
+#                      Operational Parameters                         #
+#######################################################################
+# These define the image region actually captured by the camera       #
+# driver. Although they affect the geometry of the output image, they #
+# may be changed freely without recalibrating the camera.             #
+#######################################################################
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Binning refers here to any camera setting which combines rectangular
+#  neighborhoods of pixels into larger "super-pixels." It reduces the
+#  resolution of the output image to
+#  (width / binning_x) x (height / binning_y).
+# The default values binning_x = binning_y = 0 is considered the same
+#  as binning_x = binning_y = 1 (no subsampling).
+uint32 binning_x
+uint32 binning_y
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Region of interest (subwindow of full camera resolution), given in
+#  full resolution (unbinned) image coordinates. A particular ROI
+#  always denotes the same window of pixels on the camera sensor,
+#  regardless of binning settings.
+# The default setting of roi (all values 0) is considered the same as
+#  full resolution (roi.width = width, roi.height = height).
+RegionOfInterest roi
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -3707,191 +3659,233 @@ builtin_interfaces/Time stamp
 # Transform frame with which this data is associated.
 string frame_id
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: nav_msgs/MapMetaData
-# This hold basic information about the characteristics of the OccupancyGrid
+MSG: sensor_msgs/RegionOfInterest
+# This message is used to specify a region of interest within an image.
+#
+# When used to specify the ROI setting of the camera when the image was
+# taken, the height and width fields should either match the height and
+# width fields for the associated image; or height = width = 0
+# indicates that the full resolution image was captured.
 
-# The time at which the map was loaded
-builtin_interfaces/Time map_load_time
+uint32 x_offset  # Leftmost pixel of the ROI
+                 # (0 if the ROI includes the left edge of the image)
+uint32 y_offset  # Topmost pixel of the ROI
+                 # (0 if the ROI includes the top edge of the image)
+uint32 height    # Height of ROI
+uint32 width     # Width of ROI
 
-# The map resolution [m/cell]
-float32 resolution
+# True if a distinct rectified ROI should be calculated from the "raw"
+# ROI in this message. Typically this should be False if the full image
+# is captured (ROI not used), and True if a subwindow is captured (ROI
+# used).
+bool do_rectify"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_b3dfd68ff46c9d56c80fd3bd4ed22c7a4ddce8c8348f2f59c299e73118e7e275";
+    }
+    #[allow(unused)]
+    impl CameraInfo {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This message is used by the PointCloud message to hold optional data"]
+    #[doc = " associated with each point in the cloud. The length of the values"]
+    #[doc = " array should be the same as the length of the points array in the"]
+    #[doc = " PointCloud, and each value should be associated with the corresponding"]
+    #[doc = " point."]
+    #[doc = ""]
+    #[doc = " Channel names in existing practice include:"]
+    #[doc = " \"u\", \"v\" - row and column (respectively) in the left stereo image."]
+    #[doc = " This is opposite to usual conventions but remains for"]
+    #[doc = " historical reasons. The newer PointCloud2 message has no"]
+    #[doc = " such problem."]
+    #[doc = " \"rgb\" - For point clouds produced by color stereo cameras. uint8"]
+    #[doc = " (R,G,B) values packed into the least significant 24 bits,"]
+    #[doc = " in order."]
+    #[doc = " \"intensity\" - laser or pixel intensity."]
+    #[doc = " \"distance\""]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct ChannelFloat32 {
+        #[doc = " The channel name should give semantics of the channel (e.g."]
+        #[doc = " \"intensity\" instead of \"value\")."]
+        pub r#name: ::std::string::String,
+        #[doc = " The values array should be 1-1 with the elements of the associated"]
+        #[doc = " PointCloud."]
+        pub r#values: ::std::vec::Vec<f32>,
+    }
+    impl ::roslibrust::RosMessageType for ChannelFloat32 {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/ChannelFloat32";
+        const MD5SUM: &'static str = "3d40139cdd33dfedcb71ffeeeb42ae7f";
+        const DEFINITION: &'static str = r####"# This message is used by the PointCloud message to hold optional data
+# associated with each point in the cloud. The length of the values
+# array should be the same as the length of the points array in the
+# PointCloud, and each value should be associated with the corresponding
+# point.
+#
+# Channel names in existing practice include:
+#   "u", "v" - row and column (respectively) in the left stereo image.
+#              This is opposite to usual conventions but remains for
+#              historical reasons. The newer PointCloud2 message has no
+#              such problem.
+#   "rgb" - For point clouds produced by color stereo cameras. uint8
+#           (R,G,B) values packed into the least significant 24 bits,
+#           in order.
+#   "intensity" - laser or pixel intensity.
+#   "distance"
 
-# Map width [cells]
-uint32 width
+# The channel name should give semantics of the channel (e.g.
+# "intensity" instead of "value").
+string name
 
-# Map height [cells]
-uint32 height
+# The values array should be 1-1 with the elements of the associated
+# PointCloud.
+float32[] values"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_92665437ddf39346f4ba39ee32e648390605b633cc077d40f4bd4d7b58af6cd4";
+    }
+    #[allow(unused)]
+    impl ChannelFloat32 {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This message contains a compressed image."]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct CompressedImage {
+        #[doc = " Header timestamp should be acquisition time of image"]
+        pub r#header: std_msgs::Header,
+        #[doc = " Specifies the format of the data"]
+        pub r#format: ::std::string::String,
+        #[doc = " Compressed image buffer"]
+        #[serde(with = "::roslibrust::codegen::serde_bytes")]
+        pub r#data: ::std::vec::Vec<u8>,
+    }
+    impl ::roslibrust::RosMessageType for CompressedImage {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/CompressedImage";
+        const MD5SUM: &'static str = "1df88053b24348f5f499666c7cb1d980";
+        const DEFINITION: &'static str = r####"# This message contains a compressed image.
 
-# The origin of the map [m, m, rad].  This is the real-world pose of the
-# bottom left corner of cell (0,0) in the map.
-geometry_msgs/Pose origin
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+std_msgs/Header header # Header timestamp should be acquisition time of image
+                             # Header frame_id should be optical frame of camera
+                             # origin of frame should be optical center of cameara
+                             # +x should point to the right in the image
+                             # +y should point down in the image
+                             # +z should point into to plane of the image
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+string format                # Specifies the format of the data
+                             #   Acceptable values:
+                             #     jpeg, png, tiff
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+uint8[] data                 # Compressed image buffer
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: nav_msgs/OccupancyGrid
-# This represents a 2-D grid map
-std_msgs/Header header
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-# MetaData for the map
-MapMetaData info
+# Transform frame with which this data is associated.
+string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_15640771531571185e2efc8a100baf923961a4d15d5569652e6cb6691e8e371a";
+    }
+    #[allow(unused)]
+    impl CompressedImage {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " Single pressure reading.  This message is appropriate for measuring the"]
+    #[doc = " pressure inside of a fluid (air, water, etc).  This also includes"]
+    #[doc = " atmospheric or barometric pressure."]
+    #[doc = ""]
+    #[doc = " This message is not appropriate for force/pressure contact sensors."]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct FluidPressure {
+        #[doc = " timestamp of the measurement"]
+        pub r#header: std_msgs::Header,
+        #[doc = " Absolute pressure reading in Pascals."]
+        pub r#fluid_pressure: f64,
+        #[doc = " 0 is interpreted as variance unknown"]
+        pub r#variance: f64,
+    }
+    impl ::roslibrust::RosMessageType for FluidPressure {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/FluidPressure";
+        const MD5SUM: &'static str = "4967e6ff4dcf72e6b8fca0600661e0b6";
+        const DEFINITION: &'static str = r####"# Single pressure reading.  This message is appropriate for measuring the
+# pressure inside of a fluid (air, water, etc).  This also includes
+# atmospheric or barometric pressure.
+#
+# This message is not appropriate for force/pressure contact sensors.
 
-# The map data, in row-major order, starting with (0,0). 
-# Cell (1, 0) will be listed second, representing the next cell in the x direction. 
-# Cell (0, 1) will be at the index equal to info.width, followed by (1, 1).
-# The values inside are application dependent, but frequently, 
-# 0 represents unoccupied, 1 represents definitely occupied, and
-# -1 represents unknown. 
-int8[] data
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+std_msgs/Header header # timestamp of the measurement
+                             # frame_id is the location of the pressure sensor
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+float64 fluid_pressure       # Absolute pressure reading in Pascals.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+float64 variance             # 0 is interpreted as variance unknown
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: nav_msgs/MapMetaData
-# This hold basic information about the characteristics of the OccupancyGrid
-
-# The time at which the map was loaded
-builtin_interfaces/Time map_load_time
-
-# The map resolution [m/cell]
-float32 resolution
-
-# Map width [cells]
-uint32 width
-
-# Map height [cells]
-uint32 height
-
-# The origin of the map [m, m, rad].  This is the real-world pose of the
-# bottom left corner of cell (0,0) in the map.
-geometry_msgs/Pose origin
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_22dfb2b145a0bd5a31a1ac3882a1b32148b51d9b2f3bab250290d66f3595bc32";
     }
+    #[allow(unused)]
+    impl FluidPressure {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " Single photometric illuminance measurement.  Light should be assumed to be"]
+    #[doc = " measured along the sensor's x-axis (the area of detection is the y-z plane)."]
+    #[doc = " The illuminance should have a 0 or positive value and be received with"]
+    #[doc = " the sensor's +X axis pointing toward the light source."]
+    #[doc = ""]
+    #[doc = " Photometric illuminance is the measure of the human eye's sensitivity of the"]
+    #[doc = " intensity of light encountering or passing through a surface."]
+    #[doc = ""]
+    #[doc = " All other Photometric and Radiometric measurements should not use this message."]
+    #[doc = " This message cannot represent:"]
+    #[doc = " - Luminous intensity (candela/light source output)"]
+    #[doc = " - Luminance (nits/light output per area)"]
+    #[doc = " - Irradiance (watt/area), etc."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -3902,37 +3896,59 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct SetMapResponse {
-        pub r#success: bool,
+    pub struct Illuminance {
+        #[doc = " timestamp is the time the illuminance was measured"]
+        pub r#header: std_msgs::Header,
+        #[doc = " Measurement of the Photometric Illuminance in Lux."]
+        pub r#illuminance: f64,
+        #[doc = " 0 is interpreted as variance unknown"]
+        pub r#variance: f64,
     }
-    impl ::roslibrust::RosMessageType for SetMapResponse {
-        const ROS_TYPE_NAME: &'static str = "nav_msgs/SetMapResponse";
-        const MD5SUM: &'static str = "358e233cde0c8a8bcfea4ce193f8fc15";
-        const DEFINITION: &'static str = r####"# True if the map was successfully set, false otherwise.
-bool success"####;
+    impl ::roslibrust::RosMessageType for Illuminance {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Illuminance";
+        const MD5SUM: &'static str = "94ccac1a1be684df74466dfc561512aa";
+        const DEFINITION: &'static str = r####"# Single photometric illuminance measurement.  Light should be assumed to be
+# measured along the sensor's x-axis (the area of detection is the y-z plane).
+# The illuminance should have a 0 or positive value and be received with
+# the sensor's +X axis pointing toward the light source.
+#
+# Photometric illuminance is the measure of the human eye's sensitivity of the
+# intensity of light encountering or passing through a surface.
+#
+# All other Photometric and Radiometric measurements should not use this message.
+# This message cannot represent:
+#  - Luminous intensity (candela/light source output)
+#  - Luminance (nits/light output per area)
+#  - Irradiance (watt/area), etc.
+
+std_msgs/Header header # timestamp is the time the illuminance was measured
+                             # frame_id is the location and direction of the reading
+
+float64 illuminance          # Measurement of the Photometric Illuminance in Lux.
+
+float64 variance             # 0 is interpreted as variance unknown
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_b954b25f452fcf81a91c9c2a7e3b3fd85c4c873d452aecb3cfd8fd1da732a22d";
     }
-    #[allow(dead_code)]
-    pub struct SetMap {}
-    impl ::roslibrust::RosServiceType for SetMap {
-        const ROS_SERVICE_NAME: &'static str = "nav_msgs/SetMap";
-        const MD5SUM: &'static str = "6c3f8182fbcb3d4ee7aef02d1dcd1e16";
-        type Request = SetMapRequest;
-        type Response = SetMapResponse;
+    #[allow(unused)]
+    impl Illuminance {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
-}
-#[allow(unused_imports)]
-pub mod sensor_msgs {
-    use super::actionlib_msgs;
-    use super::diagnostic_msgs;
-    use super::geometry_msgs;
-    use super::nav_msgs;
-    use super::shape_msgs;
-    use super::std_msgs;
-    use super::std_srvs;
-    use super::stereo_msgs;
-    use super::test_msgs;
-    use super::trajectory_msgs;
-    use super::visualization_msgs;
+    #[doc = " This message contains an uncompressed image"]
+    #[doc = " (0, 0) is at top-left corner of image"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -3943,78 +3959,52 @@ pub mod sensor_msgs {
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct BatteryState {
+    pub struct Image {
+        #[doc = " Header timestamp should be acquisition time of image"]
         pub r#header: std_msgs::Header,
-        pub r#voltage: f32,
-        pub r#temperature: f32,
-        pub r#current: f32,
-        pub r#charge: f32,
-        pub r#capacity: f32,
-        pub r#design_capacity: f32,
-        pub r#percentage: f32,
-        pub r#power_supply_status: u8,
-        pub r#power_supply_health: u8,
-        pub r#power_supply_technology: u8,
-        pub r#present: bool,
-        pub r#cell_voltage: ::std::vec::Vec<f32>,
-        pub r#cell_temperature: ::std::vec::Vec<f32>,
-        pub r#location: ::std::string::String,
-        pub r#serial_number: ::std::string::String,
+        #[doc = " image height, that is, number of rows"]
+        pub r#height: u32,
+        #[doc = " image width, that is, number of columns"]
+        pub r#width: u32,
+        #[doc = " Encoding of pixels -- channel meaning, ordering, size"]
+        pub r#encoding: ::std::string::String,
+        #[doc = " is this data bigendian?"]
+        pub r#is_bigendian: u8,
+        #[doc = " Full row length in bytes"]
+        pub r#step: u32,
+        #[doc = " actual matrix data, size is (step * rows)"]
+        #[serde(with = "::roslibrust::codegen::serde_bytes")]
+        pub r#data: ::std::vec::Vec<u8>,
     }
-    impl ::roslibrust::RosMessageType for BatteryState {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/BatteryState";
-        const MD5SUM: &'static str = "0e25cedcd370a46961764fe3a9d2ddcb";
-        const DEFINITION: &'static str = r####"# Constants are chosen to match the enums in the linux kernel
-# defined in include/linux/power_supply.h as of version 3.7
-# The one difference is for style reasons the constants are
-# all uppercase not mixed case.
+    impl ::roslibrust::RosMessageType for Image {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Image";
+        const MD5SUM: &'static str = "9c8b3d25a28b72f070da359dbecf985b";
+        const DEFINITION: &'static str = r####"# This message contains an uncompressed image
+# (0, 0) is at top-left corner of image
 
-# Power supply status constants
-uint8 POWER_SUPPLY_STATUS_UNKNOWN = 0
-uint8 POWER_SUPPLY_STATUS_CHARGING = 1
-uint8 POWER_SUPPLY_STATUS_DISCHARGING = 2
-uint8 POWER_SUPPLY_STATUS_NOT_CHARGING = 3
-uint8 POWER_SUPPLY_STATUS_FULL = 4
+std_msgs/Header header # Header timestamp should be acquisition time of image
+                             # Header frame_id should be optical frame of camera
+                             # origin of frame should be optical center of cameara
+                             # +x should point to the right in the image
+                             # +y should point down in the image
+                             # +z should point into to plane of the image
+                             # If the frame_id here and the frame_id of the CameraInfo
+                             # message associated with the image conflict
+                             # the behavior is undefined
 
-# Power supply health constants
-uint8 POWER_SUPPLY_HEALTH_UNKNOWN = 0
-uint8 POWER_SUPPLY_HEALTH_GOOD = 1
-uint8 POWER_SUPPLY_HEALTH_OVERHEAT = 2
-uint8 POWER_SUPPLY_HEALTH_DEAD = 3
-uint8 POWER_SUPPLY_HEALTH_OVERVOLTAGE = 4
-uint8 POWER_SUPPLY_HEALTH_UNSPEC_FAILURE = 5
-uint8 POWER_SUPPLY_HEALTH_COLD = 6
-uint8 POWER_SUPPLY_HEALTH_WATCHDOG_TIMER_EXPIRE = 7
-uint8 POWER_SUPPLY_HEALTH_SAFETY_TIMER_EXPIRE = 8
+uint32 height                # image height, that is, number of rows
+uint32 width                 # image width, that is, number of columns
 
-# Power supply technology (chemistry) constants
-uint8 POWER_SUPPLY_TECHNOLOGY_UNKNOWN = 0
-uint8 POWER_SUPPLY_TECHNOLOGY_NIMH = 1
-uint8 POWER_SUPPLY_TECHNOLOGY_LION = 2
-uint8 POWER_SUPPLY_TECHNOLOGY_LIPO = 3
-uint8 POWER_SUPPLY_TECHNOLOGY_LIFE = 4
-uint8 POWER_SUPPLY_TECHNOLOGY_NICD = 5
-uint8 POWER_SUPPLY_TECHNOLOGY_LIMN = 6
+# The legal values for encoding are in file include/sensor_msgs/image_encodings.hpp
+# If you want to standardize a new string format, join
+# ros-users@lists.ros.org and send an email proposing a new encoding.
 
-std_msgs/Header  header
-float32 voltage          # Voltage in Volts (Mandatory)
-float32 temperature      # Temperature in Degrees Celsius (If unmeasured NaN)
-float32 current          # Negative when discharging (A)  (If unmeasured NaN)
-float32 charge           # Current charge in Ah  (If unmeasured NaN)
-float32 capacity         # Capacity in Ah (last full capacity)  (If unmeasured NaN)
-float32 design_capacity  # Capacity in Ah (design capacity)  (If unmeasured NaN)
-float32 percentage       # Charge percentage on 0 to 1 range  (If unmeasured NaN)
-uint8   power_supply_status     # The charging status as reported. Values defined above
-uint8   power_supply_health     # The battery health metric. Values defined above
-uint8   power_supply_technology # The battery chemistry. Values defined above
-bool    present          # True if the battery is present
+string encoding       # Encoding of pixels -- channel meaning, ordering, size
+                      # taken from the list of strings in include/sensor_msgs/image_encodings.hpp
 
-float32[] cell_voltage   # An array of individual cell voltages for each cell in the pack
-                         # If individual voltages unknown but number of cells known set each to NaN
-float32[] cell_temperature # An array of individual cell temperatures for each cell in the pack
-                           # If individual temperatures unknown but number of cells known set each to NaN
-string location          # The location into which the battery is inserted. (slot number or plug)
-string serial_number     # The best approximation of the battery serial number
+uint8 is_bigendian    # is this data bigendian?
+uint32 step           # Full row length in bytes
+uint8[] data          # actual matrix data, size is (step * rows)
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -4026,31 +4016,28 @@ builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_d31d41a9a4c4bc8eae9be757b0beed306564f7526c88ea6a4588fb9582527d47";
     }
     #[allow(unused)]
-    impl BatteryState {
-        pub const r#POWER_SUPPLY_STATUS_UNKNOWN: u8 = 0u8;
-        pub const r#POWER_SUPPLY_STATUS_CHARGING: u8 = 1u8;
-        pub const r#POWER_SUPPLY_STATUS_DISCHARGING: u8 = 2u8;
-        pub const r#POWER_SUPPLY_STATUS_NOT_CHARGING: u8 = 3u8;
-        pub const r#POWER_SUPPLY_STATUS_FULL: u8 = 4u8;
-        pub const r#POWER_SUPPLY_HEALTH_UNKNOWN: u8 = 0u8;
-        pub const r#POWER_SUPPLY_HEALTH_GOOD: u8 = 1u8;
-        pub const r#POWER_SUPPLY_HEALTH_OVERHEAT: u8 = 2u8;
-        pub const r#POWER_SUPPLY_HEALTH_DEAD: u8 = 3u8;
-        pub const r#POWER_SUPPLY_HEALTH_OVERVOLTAGE: u8 = 4u8;
-        pub const r#POWER_SUPPLY_HEALTH_UNSPEC_FAILURE: u8 = 5u8;
-        pub const r#POWER_SUPPLY_HEALTH_COLD: u8 = 6u8;
-        pub const r#POWER_SUPPLY_HEALTH_WATCHDOG_TIMER_EXPIRE: u8 = 7u8;
-        pub const r#POWER_SUPPLY_HEALTH_SAFETY_TIMER_EXPIRE: u8 = 8u8;
-        pub const r#POWER_SUPPLY_TECHNOLOGY_UNKNOWN: u8 = 0u8;
-        pub const r#POWER_SUPPLY_TECHNOLOGY_NIMH: u8 = 1u8;
-        pub const r#POWER_SUPPLY_TECHNOLOGY_LION: u8 = 2u8;
-        pub const r#POWER_SUPPLY_TECHNOLOGY_LIPO: u8 = 3u8;
-        pub const r#POWER_SUPPLY_TECHNOLOGY_LIFE: u8 = 4u8;
-        pub const r#POWER_SUPPLY_TECHNOLOGY_NICD: u8 = 5u8;
-        pub const r#POWER_SUPPLY_TECHNOLOGY_LIMN: u8 = 6u8;
+    impl Image {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This is a message to hold data from an IMU (Inertial Measurement Unit)"]
+    #[doc = ""]
+    #[doc = " Accelerations should be in m/s^2 (not in g's), and rotational velocity should be in rad/sec"]
+    #[doc = ""]
+    #[doc = " If the covariance of the measurement is known, it should be filled in (if all you know is the"]
+    #[doc = " variance of each measurement, e.g. from the datasheet, just put those along the diagonal)"]
+    #[doc = " A covariance matrix of all zeros will be interpreted as \"covariance unknown\", and to use the"]
+    #[doc = " data a covariance will have to be assumed or gotten from some other source"]
+    #[doc = ""]
+    #[doc = " If you have no estimate for one of the data elements (e.g. your IMU doesn't produce an"]
+    #[doc = " orientation estimate), please set element 0 of the associated covariance matrix to -1"]
+    #[doc = " If you are interpreting this message, please check for a value of -1 in the first element of each"]
+    #[doc = " covariance matrix, and disregard the associated estimate."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4061,174 +4048,147 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct CameraInfo {
+    pub struct Imu {
         pub r#header: std_msgs::Header,
-        pub r#height: u32,
-        pub r#width: u32,
-        pub r#distortion_model: ::std::string::String,
-        pub r#d: ::std::vec::Vec<f64>,
-        pub r#k: [f64; 9],
-        pub r#r: [f64; 9],
-        pub r#p: [f64; 12],
-        pub r#binning_x: u32,
-        pub r#binning_y: u32,
-        pub r#roi: self::RegionOfInterest,
+        pub r#orientation: geometry_msgs::Quaternion,
+        #[doc = " Row major about x, y, z axes"]
+        pub r#orientation_covariance: [f64; 9],
+        pub r#angular_velocity: geometry_msgs::Vector3,
+        #[doc = " Row major about x, y, z axes"]
+        pub r#angular_velocity_covariance: [f64; 9],
+        pub r#linear_acceleration: geometry_msgs::Vector3,
+        #[doc = " Row major x, y z"]
+        pub r#linear_acceleration_covariance: [f64; 9],
     }
-    impl ::roslibrust::RosMessageType for CameraInfo {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/CameraInfo";
-        const MD5SUM: &'static str = "47b55ddbbf2ec398f94cddf328bbc2ac";
-        const DEFINITION: &'static str = r####"# This message defines meta information for a camera. It should be in a
-# camera namespace on topic "camera_info" and accompanied by up to five
-# image topics named:
-#
-#   image_raw - raw data from the camera driver, possibly Bayer encoded
-#   image            - monochrome, distorted
-#   image_color      - color, distorted
-#   image_rect       - monochrome, rectified
-#   image_rect_color - color, rectified
+    impl ::roslibrust::RosMessageType for Imu {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Imu";
+        const MD5SUM: &'static str = "058a92f712764b4ade1563e82041c569";
+        const DEFINITION: &'static str = r####"# This is a message to hold data from an IMU (Inertial Measurement Unit)
 #
-# The image_pipeline contains packages (image_proc, stereo_image_proc)
-# for producing the four processed image topics from image_raw and
-# camera_info. The meaning of the camera parameters are described in
-# detail at http://www.ros.org/wiki/image_pipeline/CameraInfo.
+# Accelerations should be in m/s^2 (not in g's), and rotational velocity should be in rad/sec
 #
-# The image_geometry package provides a user-friendly interface to
-# common operations using this meta information. If you want to, e.g.,
-# project a 3d point into image coordinates, we strongly recommend
-# using image_geometry.
+# If the covariance of the measurement is known, it should be filled in (if all you know is the
+# variance of each measurement, e.g. from the datasheet, just put those along the diagonal)
+# A covariance matrix of all zeros will be interpreted as "covariance unknown", and to use the
+# data a covariance will have to be assumed or gotten from some other source
 #
-# If the camera is uncalibrated, the matrices D, K, R, P should be left
-# zeroed out. In particular, clients may assume that K[0] == 0.0
-# indicates an uncalibrated camera.
-
-#######################################################################
-#                     Image acquisition info                          #
-#######################################################################
-
-# Time of image acquisition, camera coordinate frame ID
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of camera
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into the plane of the image
-
-
-#######################################################################
-#                      Calibration Parameters                         #
-#######################################################################
-# These are fixed during camera calibration. Their values will be the #
-# same in all messages until the camera is recalibrated. Note that    #
-# self-calibrating systems may "recalibrate" frequently.              #
-#                                                                     #
-# The internal parameters can be used to warp a raw (distorted) image #
-# to:                                                                 #
-#   1. An undistorted image (requires D and K)                        #
-#   2. A rectified image (requires D, K, R)                           #
-# The projection matrix P projects 3D points into the rectified image.#
-#######################################################################
-
-# The image dimensions with which the camera was calibrated.
-# Normally this will be the full camera resolution in pixels.
-uint32 height
-uint32 width
+# If you have no estimate for one of the data elements (e.g. your IMU doesn't produce an
+# orientation estimate), please set element 0 of the associated covariance matrix to -1
+# If you are interpreting this message, please check for a value of -1 in the first element of each
+# covariance matrix, and disregard the associated estimate.
 
-# The distortion model used. Supported models are listed in
-# sensor_msgs/distortion_models.hpp. For most cameras, "plumb_bob" - a
-# simple model of radial and tangential distortion - is sufficent.
-string distortion_model
+std_msgs/Header header
 
-# The distortion parameters, size depending on the distortion model.
-# For "plumb_bob", the 5 parameters are: (k1, k2, t1, t2, k3).
-float64[] d
+geometry_msgs/Quaternion orientation
+float64[9] orientation_covariance # Row major about x, y, z axes
 
-# Intrinsic camera matrix for the raw (distorted) images.
-#     [fx  0 cx]
-# K = [ 0 fy cy]
-#     [ 0  0  1]
-# Projects 3D points in the camera coordinate frame to 2D pixel
-# coordinates using the focal lengths (fx, fy) and principal point
-# (cx, cy).
-float64[9]  k # 3x3 row-major matrix
+geometry_msgs/Vector3 angular_velocity
+float64[9] angular_velocity_covariance # Row major about x, y, z axes
 
-# Rectification matrix (stereo cameras only)
-# A rotation matrix aligning the camera coordinate system to the ideal
-# stereo image plane so that epipolar lines in both stereo images are
-# parallel.
-float64[9]  r # 3x3 row-major matrix
+geometry_msgs/Vector3 linear_acceleration
+float64[9] linear_acceleration_covariance # Row major x, y z
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-# Projection/camera matrix
-#     [fx'  0  cx' Tx]
-# P = [ 0  fy' cy' Ty]
-#     [ 0   0   1   0]
-# By convention, this matrix specifies the intrinsic (camera) matrix
-#  of the processed (rectified) image. That is, the left 3x3 portion
-#  is the normal camera intrinsic matrix for the rectified image.
-# It projects 3D points in the camera coordinate frame to 2D pixel
-#  coordinates using the focal lengths (fx', fy') and principal point
-#  (cx', cy') - these may differ from the values in K.
-# For monocular cameras, Tx = Ty = 0. Normally, monocular cameras will
-#  also have R = the identity and P[1:3,1:3] = K.
-# For a stereo pair, the fourth column [Tx Ty 0]' is related to the
-#  position of the optical center of the second camera in the first
-#  camera's frame. We assume Tz = 0 so both cameras are in the same
-#  stereo image plane. The first camera always has Tx = Ty = 0. For
-#  the right (second) camera of a horizontal stereo pair, Ty = 0 and
-#  Tx = -fx' * B, where B is the baseline between the cameras.
-# Given a 3D point [X Y Z]', the projection (x, y) of the point onto
-#  the rectified image is given by:
-#  [u v w]' = P * [X Y Z 1]'
-#         x = u / w
-#         y = v / w
-#  This holds for both images of a stereo pair.
-float64[12] p # 3x4 row-major matrix
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
+# Transform frame with which this data is associated.
+string frame_id
+================================================================================
+MSG: geometry_msgs/Quaternion
+# This represents an orientation in free space in quaternion form.
 
-#######################################################################
-#                      Operational Parameters                         #
-#######################################################################
-# These define the image region actually captured by the camera       #
-# driver. Although they affect the geometry of the output image, they #
-# may be changed freely without recalibrating the camera.             #
-#######################################################################
+float64 x 0
+float64 y 0
+float64 z 0
+float64 w 1
+================================================================================
+MSG: geometry_msgs/Vector3
+# This represents a vector in free space.
 
-# Binning refers here to any camera setting which combines rectangular
-#  neighborhoods of pixels into larger "super-pixels." It reduces the
-#  resolution of the output image to
-#  (width / binning_x) x (height / binning_y).
-# The default values binning_x = binning_y = 0 is considered the same
-#  as binning_x = binning_y = 1 (no subsampling).
-uint32 binning_x
-uint32 binning_y
+# This is semantically different than a point.
+# A vector is always anchored at the origin.
+# When a transform is applied to a vector, only the rotational component is applied.
 
-# Region of interest (subwindow of full camera resolution), given in
-#  full resolution (unbinned) image coordinates. A particular ROI
-#  always denotes the same window of pixels on the camera sensor,
-#  regardless of binning settings.
-# The default setting of roi (all values 0) is considered the same as
-#  full resolution (roi.width = width, roi.height = height).
-RegionOfInterest roi
-================================================================================
-MSG: sensor_msgs/RegionOfInterest
-# This message is used to specify a region of interest within an image.
+float64 x
+float64 y
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_7d9a00ff131080897a5ec7e26e315954b8eae3353c3f995c55faf71574000b5b";
+    }
+    #[allow(unused)]
+    impl Imu {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This is a message that holds data to describe the state of a set of torque controlled joints."]
+    #[doc = ""]
+    #[doc = " The state of each joint (revolute or prismatic) is defined by:"]
+    #[doc = " * the position of the joint (rad or m),"]
+    #[doc = " * the velocity of the joint (rad/s or m/s) and"]
+    #[doc = " * the effort that is applied in the joint (Nm or N)."]
+    #[doc = ""]
+    #[doc = " Each joint is uniquely identified by its name"]
+    #[doc = " The header specifies the time at which the joint states were recorded. All the joint states"]
+    #[doc = " in one message have to be recorded at the same time."]
+    #[doc = ""]
+    #[doc = " This message consists of a multiple arrays, one for each part of the joint state."]
+    #[doc = " The goal is to make each of the fields optional. When e.g. your joints have no"]
+    #[doc = " effort associated with them, you can leave the effort array empty."]
+    #[doc = ""]
+    #[doc = " All arrays in this message should have the same size, or be empty."]
+    #[doc = " This is the only way to uniquely associate the joint name with the correct"]
+    #[doc = " states."]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct JointState {
+        pub r#header: std_msgs::Header,
+        pub r#name: ::std::vec::Vec<::std::string::String>,
+        pub r#position: ::std::vec::Vec<f64>,
+        pub r#velocity: ::std::vec::Vec<f64>,
+        pub r#effort: ::std::vec::Vec<f64>,
+    }
+    impl ::roslibrust::RosMessageType for JointState {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/JointState";
+        const MD5SUM: &'static str = "3f61f1439a9898cdd864497d378ce55c";
+        const DEFINITION: &'static str = r####"# This is a message that holds data to describe the state of a set of torque controlled joints.
 #
-# When used to specify the ROI setting of the camera when the image was
-# taken, the height and width fields should either match the height and
-# width fields for the associated image; or height = width = 0
-# indicates that the full resolution image was captured.
+# The state of each joint (revolute or prismatic) is defined by:
+#  * the position of the joint (rad or m),
+#  * the velocity of the joint (rad/s or m/s) and
+#  * the effort that is applied in the joint (Nm or N).
+#
+# Each joint is uniquely identified by its name
+# The header specifies the time at which the joint states were recorded. All the joint states
+# in one message have to be recorded at the same time.
+#
+# This message consists of a multiple arrays, one for each part of the joint state.
+# The goal is to make each of the fields optional. When e.g. your joints have no
+# effort associated with them, you can leave the effort array empty.
+#
+# All arrays in this message should have the same size, or be empty.
+# This is the only way to uniquely associate the joint name with the correct
+# states.
 
-uint32 x_offset  # Leftmost pixel of the ROI
-                 # (0 if the ROI includes the left edge of the image)
-uint32 y_offset  # Topmost pixel of the ROI
-                 # (0 if the ROI includes the top edge of the image)
-uint32 height    # Height of ROI
-uint32 width     # Width of ROI
+std_msgs/Header header
 
-# True if a distinct rectified ROI should be calculated from the "raw"
-# ROI in this message. Typically this should be False if the full image
-# is captured (ROI not used), and True if a subwindow is captured (ROI
-# used).
-bool do_rectify
+string[] name
+float64[] position
+float64[] velocity
+float64[] effort
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -4240,7 +4200,16 @@ builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_a13ee3a330e346c9d87b5aa18d24e11690752bd33a0350f11c5882bc9179260e";
+    }
+    #[allow(unused)]
+    impl JointState {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Reports the state of a joystick's axes and buttons."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4251,37 +4220,46 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct ChannelFloat32 {
-        pub r#name: ::std::string::String,
-        pub r#values: ::std::vec::Vec<f32>,
+    pub struct Joy {
+        #[doc = " The timestamp is the time at which data is received from the joystick."]
+        pub r#header: std_msgs::Header,
+        #[doc = " The axes measurements from a joystick."]
+        pub r#axes: ::std::vec::Vec<f32>,
+        #[doc = " The buttons measurements from a joystick."]
+        pub r#buttons: ::std::vec::Vec<i32>,
     }
-    impl ::roslibrust::RosMessageType for ChannelFloat32 {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/ChannelFloat32";
-        const MD5SUM: &'static str = "3d40139cdd33dfedcb71ffeeeb42ae7f";
-        const DEFINITION: &'static str = r####"# This message is used by the PointCloud message to hold optional data
-# associated with each point in the cloud. The length of the values
-# array should be the same as the length of the points array in the
-# PointCloud, and each value should be associated with the corresponding
-# point.
-#
-# Channel names in existing practice include:
-#   "u", "v" - row and column (respectively) in the left stereo image.
-#              This is opposite to usual conventions but remains for
-#              historical reasons. The newer PointCloud2 message has no
-#              such problem.
-#   "rgb" - For point clouds produced by color stereo cameras. uint8
-#           (R,G,B) values packed into the least significant 24 bits,
-#           in order.
-#   "intensity" - laser or pixel intensity.
-#   "distance"
+    impl ::roslibrust::RosMessageType for Joy {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Joy";
+        const MD5SUM: &'static str = "967f985c9ca9013a4669430613e3e016";
+        const DEFINITION: &'static str = r####"# Reports the state of a joystick's axes and buttons.
 
-# The channel name should give semantics of the channel (e.g.
-# "intensity" instead of "value").
-string name
+# The timestamp is the time at which data is received from the joystick.
+std_msgs/Header header
 
-# The values array should be 1-1 with the elements of the associated
-# PointCloud.
-float32[] values"####;
+# The axes measurements from a joystick.
+float32[] axes
+
+# The buttons measurements from a joystick.
+int32[] buttons
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_0d356c79cad3401e35ffeb75a96a96e08be3ef896b8b83841d73e890989372c5";
+    }
+    #[allow(unused)]
+    impl Joy {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -4293,40 +4271,47 @@ float32[] values"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct CompressedImage {
-        pub r#header: std_msgs::Header,
-        pub r#format: ::std::string::String,
-        #[serde(with = "::roslibrust::codegen::serde_bytes")]
-        pub r#data: ::std::vec::Vec<u8>,
+    pub struct JoyFeedback {
+        pub r#type: u8,
+        #[doc = " This will hold an id number for each type of each feedback."]
+        #[doc = " Example, the first led would be id=0, the second would be id=1"]
+        pub r#id: u8,
+        #[doc = " Intensity of the feedback, from 0.0 to 1.0, inclusive.  If device is"]
+        #[doc = " actually binary, driver should treat 0<=x<0.5 as off, 0.5<=x<=1 as on."]
+        pub r#intensity: f32,
     }
-    impl ::roslibrust::RosMessageType for CompressedImage {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/CompressedImage";
-        const MD5SUM: &'static str = "1df88053b24348f5f499666c7cb1d980";
-        const DEFINITION: &'static str = r####"# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+    impl ::roslibrust::RosMessageType for JoyFeedback {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/JoyFeedback";
+        const MD5SUM: &'static str = "f4dcd73460360d98f36e55ee7f2e46f1";
+        const DEFINITION: &'static str = r####"# Declare of the type of feedback
+uint8 TYPE_LED    = 0
+uint8 TYPE_RUMBLE = 1
+uint8 TYPE_BUZZER = 2
 
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+uint8 type
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# This will hold an id number for each type of each feedback.
+# Example, the first led would be id=0, the second would be id=1
+uint8 id
 
-# Transform frame with which this data is associated.
-string frame_id"####;
+# Intensity of the feedback, from 0.0 to 1.0, inclusive.  If device is
+# actually binary, driver should treat 0<=x<0.5 as off, 0.5<=x<=1 as on.
+float32 intensity"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_231dd362f71d6fc08272770d07120ad5fe5874ce2dbac70109b28986834290cd";
+    }
+    #[allow(unused)]
+    impl JoyFeedback {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl JoyFeedback {
+        #[doc = " Declare of the type of feedback"]
+        pub const r#TYPE_LED: u8 = 0u8;
+        pub const r#TYPE_RUMBLE: u8 = 1u8;
+        pub const r#TYPE_BUZZER: u8 = 2u8;
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -4338,38 +4323,78 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct FluidPressure {
-        pub r#header: std_msgs::Header,
-        pub r#fluid_pressure: f64,
-        pub r#variance: f64,
+    pub struct JoyFeedbackArray {
+        #[doc = " This message publishes values for multiple feedback at once."]
+        pub r#array: ::std::vec::Vec<self::JoyFeedback>,
     }
-    impl ::roslibrust::RosMessageType for FluidPressure {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/FluidPressure";
-        const MD5SUM: &'static str = "4967e6ff4dcf72e6b8fca0600661e0b6";
-        const DEFINITION: &'static str = r####"# Single pressure reading.  This message is appropriate for measuring the
-# pressure inside of a fluid (air, water, etc).  This also includes
-# atmospheric or barometric pressure.
-#
-# This message is not appropriate for force/pressure contact sensors.
-
-std_msgs/Header header # timestamp of the measurement
-                             # frame_id is the location of the pressure sensor
+    impl ::roslibrust::RosMessageType for JoyFeedbackArray {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/JoyFeedbackArray";
+        const MD5SUM: &'static str = "cde5730a895b1fc4dee6f91b754b213d";
+        const DEFINITION: &'static str = r####"# This message publishes values for multiple feedback at once.
+JoyFeedback[] array
+================================================================================
+MSG: sensor_msgs/JoyFeedback
+# Declare of the type of feedback
+uint8 TYPE_LED    = 0
+uint8 TYPE_RUMBLE = 1
+uint8 TYPE_BUZZER = 2
 
-float64 fluid_pressure       # Absolute pressure reading in Pascals.
+uint8 type
 
-float64 variance             # 0 is interpreted as variance unknown
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# This will hold an id number for each type of each feedback.
+# Example, the first led would be id=0, the second would be id=1
+uint8 id
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# Intensity of the feedback, from 0.0 to 1.0, inclusive.  If device is
+# actually binary, driver should treat 0<=x<0.5 as off, 0.5<=x<=1 as on.
+float32 intensity"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_3287c32e1b688cae04555e465443df3cca7dae76ee4ebf85c4658d585037bcaa";
+    }
+    #[allow(unused)]
+    impl JoyFeedbackArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This message is a submessage of MultiEchoLaserScan and is not intended"]
+    #[doc = " to be used separately."]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct LaserEcho {
+        #[doc = " Multiple values of ranges or intensities."]
+        pub r#echoes: ::std::vec::Vec<f32>,
+    }
+    impl ::roslibrust::RosMessageType for LaserEcho {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/LaserEcho";
+        const MD5SUM: &'static str = "8bc5ae449b200fba4d552b4225586696";
+        const DEFINITION: &'static str = r####"# This message is a submessage of MultiEchoLaserScan and is not intended
+# to be used separately.
 
-# Transform frame with which this data is associated.
-string frame_id"####;
+float32[] echoes  # Multiple values of ranges or intensities.
+                  # Each array represents data from the same angle increment."####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_0fbc05a0db7d37fe52c0f0375356db55da0046f7ef5bd27ca6b34bd0582bc952";
+    }
+    #[allow(unused)]
+    impl LaserEcho {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Single scan from a planar laser range-finder"]
+    #[doc = ""]
+    #[doc = " If you have another ranging device with different behavior (e.g. a sonar"]
+    #[doc = " array), please find or create a different message, since applications"]
+    #[doc = " will make fairly laser-specific assumptions about this data"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4380,34 +4405,64 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct Illuminance {
+    pub struct LaserScan {
+        #[doc = " timestamp in the header is the acquisition time of"]
         pub r#header: std_msgs::Header,
-        pub r#illuminance: f64,
-        pub r#variance: f64,
+        #[doc = " start angle of the scan [rad]"]
+        pub r#angle_min: f32,
+        #[doc = " end angle of the scan [rad]"]
+        pub r#angle_max: f32,
+        #[doc = " angular distance between measurements [rad]"]
+        pub r#angle_increment: f32,
+        #[doc = " time between measurements [seconds] - if your scanner"]
+        pub r#time_increment: f32,
+        #[doc = " is moving, this will be used in interpolating position"]
+        #[doc = " of 3d points"]
+        #[doc = " time between scans [seconds]"]
+        pub r#scan_time: f32,
+        #[doc = " minimum range value [m]"]
+        pub r#range_min: f32,
+        #[doc = " maximum range value [m]"]
+        pub r#range_max: f32,
+        #[doc = " range data [m]"]
+        pub r#ranges: ::std::vec::Vec<f32>,
+        #[doc = " (Note: values < range_min or > range_max should be discarded)"]
+        #[doc = " intensity data [device-specific units].  If your"]
+        pub r#intensities: ::std::vec::Vec<f32>,
     }
-    impl ::roslibrust::RosMessageType for Illuminance {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Illuminance";
-        const MD5SUM: &'static str = "94ccac1a1be684df74466dfc561512aa";
-        const DEFINITION: &'static str = r####"# Single photometric illuminance measurement.  Light should be assumed to be
-# measured along the sensor's x-axis (the area of detection is the y-z plane).
-# The illuminance should have a 0 or positive value and be received with
-# the sensor's +X axis pointing toward the light source.
-#
-# Photometric illuminance is the measure of the human eye's sensitivity of the
-# intensity of light encountering or passing through a surface.
+    impl ::roslibrust::RosMessageType for LaserScan {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/LaserScan";
+        const MD5SUM: &'static str = "f86984b4383bf67523c75820e114e988";
+        const DEFINITION: &'static str = r####"# Single scan from a planar laser range-finder
 #
-# All other Photometric and Radiometric measurements should not use this message.
-# This message cannot represent:
-#  - Luminous intensity (candela/light source output)
-#  - Luminance (nits/light output per area)
-#  - Irradiance (watt/area), etc.
+# If you have another ranging device with different behavior (e.g. a sonar
+# array), please find or create a different message, since applications
+# will make fairly laser-specific assumptions about this data
 
-std_msgs/Header header # timestamp is the time the illuminance was measured
-                             # frame_id is the location and direction of the reading
+std_msgs/Header header # timestamp in the header is the acquisition time of
+                             # the first ray in the scan.
+                             #
+                             # in frame frame_id, angles are measured around
+                             # the positive Z axis (counterclockwise, if Z is up)
+                             # with zero angle being forward along the x axis
 
-float64 illuminance          # Measurement of the Photometric Illuminance in Lux.
+float32 angle_min            # start angle of the scan [rad]
+float32 angle_max            # end angle of the scan [rad]
+float32 angle_increment      # angular distance between measurements [rad]
 
-float64 variance             # 0 is interpreted as variance unknown
+float32 time_increment       # time between measurements [seconds] - if your scanner
+                             # is moving, this will be used in interpolating position
+                             # of 3d points
+float32 scan_time            # time between scans [seconds]
+
+float32 range_min            # minimum range value [m]
+float32 range_max            # maximum range value [m]
+
+float32[] ranges             # range data [m]
+                             # (Note: values < range_min or > range_max should be discarded)
+float32[] intensities        # intensity data [device-specific units].  If your
+                             # device does not provide intensities, please leave
+                             # the array empty.
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -4419,7 +4474,23 @@ builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_64c191398013af96509d518dac71d5164f9382553fce5c1f8cca5be7924bd828";
+    }
+    #[allow(unused)]
+    impl LaserScan {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Measurement of the Magnetic Field vector at a specific location."]
+    #[doc = ""]
+    #[doc = " If the covariance of the measurement is known, it should be filled in."]
+    #[doc = " If all you know is the variance of each measurement, e.g. from the datasheet,"]
+    #[doc = " just put those along the diagonal."]
+    #[doc = " A covariance matrix of all zeros will be interpreted as \"covariance unknown\","]
+    #[doc = " and to use the data a covariance will have to be assumed or gotten from some"]
+    #[doc = " other source."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4430,45 +4501,38 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct Image {
+    pub struct MagneticField {
+        #[doc = " timestamp is the time the"]
         pub r#header: std_msgs::Header,
-        pub r#height: u32,
-        pub r#width: u32,
-        pub r#encoding: ::std::string::String,
-        pub r#is_bigendian: u8,
-        pub r#step: u32,
-        #[serde(with = "::roslibrust::codegen::serde_bytes")]
-        pub r#data: ::std::vec::Vec<u8>,
+        #[doc = " x, y, and z components of the"]
+        pub r#magnetic_field: geometry_msgs::Vector3,
+        #[doc = " Row major about x, y, z axes"]
+        pub r#magnetic_field_covariance: [f64; 9],
     }
-    impl ::roslibrust::RosMessageType for Image {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Image";
-        const MD5SUM: &'static str = "9c8b3d25a28b72f070da359dbecf985b";
-        const DEFINITION: &'static str = r####"# This message contains an uncompressed image
-# (0, 0) is at top-left corner of image
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-                             # If the frame_id here and the frame_id of the CameraInfo
-                             # message associated with the image conflict
-                             # the behavior is undefined
-
-uint32 height                # image height, that is, number of rows
-uint32 width                 # image width, that is, number of columns
+    impl ::roslibrust::RosMessageType for MagneticField {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/MagneticField";
+        const MD5SUM: &'static str = "c8761d20eb9dc59addd882f1d4de2266";
+        const DEFINITION: &'static str = r####"# Measurement of the Magnetic Field vector at a specific location.
+#
+# If the covariance of the measurement is known, it should be filled in.
+# If all you know is the variance of each measurement, e.g. from the datasheet,
+# just put those along the diagonal.
+# A covariance matrix of all zeros will be interpreted as "covariance unknown",
+# and to use the data a covariance will have to be assumed or gotten from some
+# other source.
 
-# The legal values for encoding are in file include/sensor_msgs/image_encodings.hpp
-# If you want to standardize a new string format, join
-# ros-users@lists.ros.org and send an email proposing a new encoding.
+std_msgs/Header header               # timestamp is the time the
+                                           # field was measured
+                                           # frame_id is the location and orientation
+                                           # of the field measurement
 
-string encoding       # Encoding of pixels -- channel meaning, ordering, size
-                      # taken from the list of strings in include/sensor_msgs/image_encodings.hpp
+geometry_msgs/Vector3 magnetic_field # x, y, and z components of the
+                                           # field vector in Tesla
+                                           # If your sensor does not output 3 axes,
+                                           # put NaNs in the components not reported.
 
-uint8 is_bigendian    # is this data bigendian?
-uint32 step           # Full row length in bytes
-uint8[] data          # actual matrix data, size is (step * rows)
+float64[9] magnetic_field_covariance       # Row major about x, y, z axes
+                                           # 0 is interpreted as variance unknown
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -4479,8 +4543,46 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
+string frame_id
+================================================================================
+MSG: geometry_msgs/Vector3
+# This represents a vector in free space.
+
+# This is semantically different than a point.
+# A vector is always anchored at the origin.
+# When a transform is applied to a vector, only the rotational component is applied.
+
+float64 x
+float64 y
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_e80f32f56a20486c9923008fc1a1db07bbb273cbbf6a5b3bfa00835ee00e4dff";
+    }
+    #[allow(unused)]
+    impl MagneticField {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Representation of state for joints with multiple degrees of freedom,"]
+    #[doc = " following the structure of JointState which can only represent a single degree of freedom."]
+    #[doc = ""]
+    #[doc = " It is assumed that a joint in a system corresponds to a transform that gets applied"]
+    #[doc = " along the kinematic chain. For example, a planar joint (as in URDF) is 3DOF (x, y, yaw)"]
+    #[doc = " and those 3DOF can be expressed as a transformation matrix, and that transformation"]
+    #[doc = " matrix can be converted back to (x, y, yaw)"]
+    #[doc = ""]
+    #[doc = " Each joint is uniquely identified by its name"]
+    #[doc = " The header specifies the time at which the joint states were recorded. All the joint states"]
+    #[doc = " in one message have to be recorded at the same time."]
+    #[doc = ""]
+    #[doc = " This message consists of a multiple arrays, one for each part of the joint state."]
+    #[doc = " The goal is to make each of the fields optional. When e.g. your joints have no"]
+    #[doc = " wrench associated with them, you can leave the wrench array empty."]
+    #[doc = ""]
+    #[doc = " All arrays in this message should have the same size, or be empty."]
+    #[doc = " This is the only way to uniquely associate the joint name with the correct"]
+    #[doc = " states."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4491,50 +4593,59 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct Imu {
+    pub struct MultiDOFJointState {
         pub r#header: std_msgs::Header,
-        pub r#orientation: geometry_msgs::Quaternion,
-        pub r#orientation_covariance: [f64; 9],
-        pub r#angular_velocity: geometry_msgs::Vector3,
-        pub r#angular_velocity_covariance: [f64; 9],
-        pub r#linear_acceleration: geometry_msgs::Vector3,
-        pub r#linear_acceleration_covariance: [f64; 9],
+        pub r#joint_names: ::std::vec::Vec<::std::string::String>,
+        pub r#transforms: ::std::vec::Vec<geometry_msgs::Transform>,
+        pub r#twist: ::std::vec::Vec<geometry_msgs::Twist>,
+        pub r#wrench: ::std::vec::Vec<geometry_msgs::Wrench>,
     }
-    impl ::roslibrust::RosMessageType for Imu {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Imu";
-        const MD5SUM: &'static str = "058a92f712764b4ade1563e82041c569";
-        const DEFINITION: &'static str = r####"# This is a message to hold data from an IMU (Inertial Measurement Unit)
+    impl ::roslibrust::RosMessageType for MultiDOFJointState {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/MultiDOFJointState";
+        const MD5SUM: &'static str = "9eb02d78422731545fd7e9b60069f261";
+        const DEFINITION: &'static str = r####"# Representation of state for joints with multiple degrees of freedom,
+# following the structure of JointState which can only represent a single degree of freedom.
 #
-# Accelerations should be in m/s^2 (not in g's), and rotational velocity should be in rad/sec
+# It is assumed that a joint in a system corresponds to a transform that gets applied
+# along the kinematic chain. For example, a planar joint (as in URDF) is 3DOF (x, y, yaw)
+# and those 3DOF can be expressed as a transformation matrix, and that transformation
+# matrix can be converted back to (x, y, yaw)
 #
-# If the covariance of the measurement is known, it should be filled in (if all you know is the
-# variance of each measurement, e.g. from the datasheet, just put those along the diagonal)
-# A covariance matrix of all zeros will be interpreted as "covariance unknown", and to use the
-# data a covariance will have to be assumed or gotten from some other source
+# Each joint is uniquely identified by its name
+# The header specifies the time at which the joint states were recorded. All the joint states
+# in one message have to be recorded at the same time.
 #
-# If you have no estimate for one of the data elements (e.g. your IMU doesn't produce an
-# orientation estimate), please set element 0 of the associated covariance matrix to -1
-# If you are interpreting this message, please check for a value of -1 in the first element of each
-# covariance matrix, and disregard the associated estimate.
+# This message consists of a multiple arrays, one for each part of the joint state.
+# The goal is to make each of the fields optional. When e.g. your joints have no
+# wrench associated with them, you can leave the wrench array empty.
+#
+# All arrays in this message should have the same size, or be empty.
+# This is the only way to uniquely associate the joint name with the correct
+# states.
 
 std_msgs/Header header
 
-geometry_msgs/Quaternion orientation
-float64[9] orientation_covariance # Row major about x, y, z axes
+string[] joint_names
+geometry_msgs/Transform[] transforms
+geometry_msgs/Twist[] twist
+geometry_msgs/Wrench[] wrench
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-geometry_msgs/Vector3 angular_velocity
-float64[9] angular_velocity_covariance # Row major about x, y, z axes
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-geometry_msgs/Vector3 linear_acceleration
-float64[9] linear_acceleration_covariance # Row major x, y z
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: geometry_msgs/Transform
+# This represents the transform between two coordinate frames in free space.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+Vector3 translation
+Quaternion rotation
 ================================================================================
 MSG: geometry_msgs/Vector3
 # This represents a vector in free space.
@@ -4547,17 +4658,39 @@ float64 x
 float64 y
 float64 z
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+MSG: geometry_msgs/Quaternion
+# This represents an orientation in free space in quaternion form.
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+float64 x 0
+float64 y 0
+float64 z 0
+float64 w 1
+================================================================================
+MSG: geometry_msgs/Twist
+# This expresses velocity in free space broken into its linear and angular parts.
 
-# Transform frame with which this data is associated.
-string frame_id"####;
+Vector3  linear
+Vector3  angular
+================================================================================
+MSG: geometry_msgs/Wrench
+# This represents force in free space, separated into its linear and angular parts.
+
+Vector3  force
+Vector3  torque"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_4d4ded702cfba7ff3ec783835c1a1425f75e53939a430ff355d1fee4b3bbc40b";
+    }
+    #[allow(unused)]
+    impl MultiDOFJointState {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Single scan from a multi-echo planar laser range-finder"]
+    #[doc = ""]
+    #[doc = " If you have another ranging device with different behavior (e.g. a sonar"]
+    #[doc = " array), please find or create a different message, since applications"]
+    #[doc = " will make fairly laser-specific assumptions about this data"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4568,41 +4701,68 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct JointState {
+    pub struct MultiEchoLaserScan {
+        #[doc = " timestamp in the header is the acquisition time of"]
         pub r#header: std_msgs::Header,
-        pub r#name: ::std::vec::Vec<::std::string::String>,
-        pub r#position: ::std::vec::Vec<f64>,
-        pub r#velocity: ::std::vec::Vec<f64>,
-        pub r#effort: ::std::vec::Vec<f64>,
+        #[doc = " start angle of the scan [rad]"]
+        pub r#angle_min: f32,
+        #[doc = " end angle of the scan [rad]"]
+        pub r#angle_max: f32,
+        #[doc = " angular distance between measurements [rad]"]
+        pub r#angle_increment: f32,
+        #[doc = " time between measurements [seconds] - if your scanner"]
+        pub r#time_increment: f32,
+        #[doc = " is moving, this will be used in interpolating position"]
+        #[doc = " of 3d points"]
+        #[doc = " time between scans [seconds]"]
+        pub r#scan_time: f32,
+        #[doc = " minimum range value [m]"]
+        pub r#range_min: f32,
+        #[doc = " maximum range value [m]"]
+        pub r#range_max: f32,
+        #[doc = " range data [m]"]
+        pub r#ranges: ::std::vec::Vec<self::LaserEcho>,
+        #[doc = " (Note: NaNs, values < range_min or > range_max should be discarded)"]
+        #[doc = " +Inf measurements are out of range"]
+        #[doc = " -Inf measurements are too close to determine exact distance."]
+        #[doc = " intensity data [device-specific units].  If your"]
+        pub r#intensities: ::std::vec::Vec<self::LaserEcho>,
     }
-    impl ::roslibrust::RosMessageType for JointState {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/JointState";
-        const MD5SUM: &'static str = "3f61f1439a9898cdd864497d378ce55c";
-        const DEFINITION: &'static str = r####"# This is a message that holds data to describe the state of a set of torque controlled joints.
-#
-# The state of each joint (revolute or prismatic) is defined by:
-#  * the position of the joint (rad or m),
-#  * the velocity of the joint (rad/s or m/s) and
-#  * the effort that is applied in the joint (Nm or N).
-#
-# Each joint is uniquely identified by its name
-# The header specifies the time at which the joint states were recorded. All the joint states
-# in one message have to be recorded at the same time.
-#
-# This message consists of a multiple arrays, one for each part of the joint state.
-# The goal is to make each of the fields optional. When e.g. your joints have no
-# effort associated with them, you can leave the effort array empty.
+    impl ::roslibrust::RosMessageType for MultiEchoLaserScan {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/MultiEchoLaserScan";
+        const MD5SUM: &'static str = "fda674281c16cdee9a79d075ab27d12f";
+        const DEFINITION: &'static str = r####"# Single scan from a multi-echo planar laser range-finder
 #
-# All arrays in this message should have the same size, or be empty.
-# This is the only way to uniquely associate the joint name with the correct
-# states.
+# If you have another ranging device with different behavior (e.g. a sonar
+# array), please find or create a different message, since applications
+# will make fairly laser-specific assumptions about this data
 
-std_msgs/Header header
+std_msgs/Header header # timestamp in the header is the acquisition time of
+                             # the first ray in the scan.
+                             #
+                             # in frame frame_id, angles are measured around
+                             # the positive Z axis (counterclockwise, if Z is up)
+                             # with zero angle being forward along the x axis
 
-string[] name
-float64[] position
-float64[] velocity
-float64[] effort
+float32 angle_min            # start angle of the scan [rad]
+float32 angle_max            # end angle of the scan [rad]
+float32 angle_increment      # angular distance between measurements [rad]
+
+float32 time_increment       # time between measurements [seconds] - if your scanner
+                             # is moving, this will be used in interpolating position
+                             # of 3d points
+float32 scan_time            # time between scans [seconds]
+
+float32 range_min            # minimum range value [m]
+float32 range_max            # maximum range value [m]
+
+LaserEcho[] ranges           # range data [m]
+                             # (Note: NaNs, values < range_min or > range_max should be discarded)
+                             # +Inf measurements are out of range
+                             # -Inf measurements are too close to determine exact distance.
+LaserEcho[] intensities      # intensity data [device-specific units].  If your
+                             # device does not provide intensities, please leave
+                             # the array empty.
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -4613,8 +4773,26 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
+string frame_id
+================================================================================
+MSG: sensor_msgs/LaserEcho
+# This message is a submessage of MultiEchoLaserScan and is not intended
+# to be used separately.
+
+float32[] echoes  # Multiple values of ranges or intensities.
+                  # Each array represents data from the same angle increment."####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_ba5eac341cd5bbb2701527aa4568e8baec172b69cadb9a1945d6f149d087ee48";
+    }
+    #[allow(unused)]
+    impl MultiEchoLaserScan {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Navigation Satellite fix for any Global Navigation Satellite System"]
+    #[doc = ""]
+    #[doc = " Specified using the WGS 84 reference ellipsoid"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4625,24 +4803,81 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct Joy {
+    pub struct NavSatFix {
+        #[doc = " header.stamp specifies the ROS time for this measurement (the"]
+        #[doc = " corresponding satellite time may be reported using the"]
+        #[doc = " sensor_msgs/TimeReference message)."]
+        #[doc = ""]
+        #[doc = " header.frame_id is the frame of reference reported by the satellite"]
+        #[doc = " receiver, usually the location of the antenna.  This is a"]
+        #[doc = " Euclidean frame relative to the vehicle, not a reference"]
+        #[doc = " ellipsoid."]
         pub r#header: std_msgs::Header,
-        pub r#axes: ::std::vec::Vec<f32>,
-        pub r#buttons: ::std::vec::Vec<i32>,
+        #[doc = " Satellite fix status information."]
+        pub r#status: self::NavSatStatus,
+        #[doc = " Latitude [degrees]. Positive is north of equator; negative is south."]
+        pub r#latitude: f64,
+        #[doc = " Longitude [degrees]. Positive is east of prime meridian; negative is west."]
+        pub r#longitude: f64,
+        #[doc = " Altitude [m]. Positive is above the WGS 84 ellipsoid"]
+        #[doc = " (quiet NaN if no altitude is available)."]
+        pub r#altitude: f64,
+        #[doc = " Position covariance [m^2] defined relative to a tangential plane"]
+        #[doc = " through the reported position. The components are East, North, and"]
+        #[doc = " Up (ENU), in row-major order."]
+        #[doc = ""]
+        #[doc = " Beware: this coordinate system exhibits singularities at the poles."]
+        pub r#position_covariance: [f64; 9],
+        pub r#position_covariance_type: u8,
     }
-    impl ::roslibrust::RosMessageType for Joy {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Joy";
-        const MD5SUM: &'static str = "967f985c9ca9013a4669430613e3e016";
-        const DEFINITION: &'static str = r####"# Reports the state of a joystick's axes and buttons.
-
-# The timestamp is the time at which data is received from the joystick.
-std_msgs/Header header
+    impl ::roslibrust::RosMessageType for NavSatFix {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/NavSatFix";
+        const MD5SUM: &'static str = "faa1756146a6a934d7e4ef0e3855c531";
+        const DEFINITION: &'static str = r####"# Navigation Satellite fix for any Global Navigation Satellite System
+#
+# Specified using the WGS 84 reference ellipsoid
 
-# The axes measurements from a joystick.
-float32[] axes
+# header.stamp specifies the ROS time for this measurement (the
+#        corresponding satellite time may be reported using the
+#        sensor_msgs/TimeReference message).
+#
+# header.frame_id is the frame of reference reported by the satellite
+#        receiver, usually the location of the antenna.  This is a
+#        Euclidean frame relative to the vehicle, not a reference
+#        ellipsoid.
+std_msgs/Header header
 
-# The buttons measurements from a joystick.
-int32[] buttons
+# Satellite fix status information.
+NavSatStatus status
+
+# Latitude [degrees]. Positive is north of equator; negative is south.
+float64 latitude
+
+# Longitude [degrees]. Positive is east of prime meridian; negative is west.
+float64 longitude
+
+# Altitude [m]. Positive is above the WGS 84 ellipsoid
+# (quiet NaN if no altitude is available).
+float64 altitude
+
+# Position covariance [m^2] defined relative to a tangential plane
+# through the reported position. The components are East, North, and
+# Up (ENU), in row-major order.
+#
+# Beware: this coordinate system exhibits singularities at the poles.
+float64[9] position_covariance
+
+# If the covariance of the fix is known, fill it in completely. If the
+# GPS receiver provides the variance of each measurement, put them
+# along the diagonal. If only Dilution of Precision is available,
+# estimate an approximate covariance from that.
+
+uint8 COVARIANCE_TYPE_UNKNOWN = 0
+uint8 COVARIANCE_TYPE_APPROXIMATED = 1
+uint8 COVARIANCE_TYPE_DIAGONAL_KNOWN = 2
+uint8 COVARIANCE_TYPE_KNOWN = 3
+
+uint8 position_covariance_type
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -4653,47 +4888,52 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct JoyFeedback {
-        pub r#type: u8,
-        pub r#id: u8,
-        pub r#intensity: f32,
-    }
-    impl ::roslibrust::RosMessageType for JoyFeedback {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/JoyFeedback";
-        const MD5SUM: &'static str = "f4dcd73460360d98f36e55ee7f2e46f1";
-        const DEFINITION: &'static str = r####"# Declare of the type of feedback
-uint8 TYPE_LED    = 0
-uint8 TYPE_RUMBLE = 1
-uint8 TYPE_BUZZER = 2
+string frame_id
+================================================================================
+MSG: sensor_msgs/NavSatStatus
+# Navigation Satellite fix status for any Global Navigation Satellite System.
+#
+# Whether to output an augmented fix is determined by both the fix
+# type and the last time differential corrections were received.  A
+# fix is valid when status >= STATUS_FIX.
 
-uint8 type
+int8 STATUS_NO_FIX =  -1        # unable to fix position
+int8 STATUS_FIX =      0        # unaugmented fix
+int8 STATUS_SBAS_FIX = 1        # with satellite-based augmentation
+int8 STATUS_GBAS_FIX = 2        # with ground-based augmentation
 
-# This will hold an id number for each type of each feedback.
-# Example, the first led would be id=0, the second would be id=1
-uint8 id
+int8 status
 
-# Intensity of the feedback, from 0.0 to 1.0, inclusive.  If device is
-# actually binary, driver should treat 0<=x<0.5 as off, 0.5<=x<=1 as on.
-float32 intensity"####;
+# Bits defining which Global Navigation Satellite System signals were
+# used by the receiver.
+
+uint16 SERVICE_GPS =     1
+uint16 SERVICE_GLONASS = 2
+uint16 SERVICE_COMPASS = 4      # includes BeiDou.
+uint16 SERVICE_GALILEO = 8
+
+uint16 service"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_62223ab3fe210a15976021da7afddc9e200dc9ec75231c1b6a557fc598a65404";
     }
     #[allow(unused)]
-    impl JoyFeedback {
-        pub const r#TYPE_LED: u8 = 0u8;
-        pub const r#TYPE_RUMBLE: u8 = 1u8;
-        pub const r#TYPE_BUZZER: u8 = 2u8;
+    impl NavSatFix {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl NavSatFix {
+        pub const r#COVARIANCE_TYPE_UNKNOWN: u8 = 0u8;
+        pub const r#COVARIANCE_TYPE_APPROXIMATED: u8 = 1u8;
+        pub const r#COVARIANCE_TYPE_DIAGONAL_KNOWN: u8 = 2u8;
+        pub const r#COVARIANCE_TYPE_KNOWN: u8 = 3u8;
     }
+    #[doc = " Navigation Satellite fix status for any Global Navigation Satellite System."]
+    #[doc = ""]
+    #[doc = " Whether to output an augmented fix is determined by both the fix"]
+    #[doc = " type and the last time differential corrections were received.  A"]
+    #[doc = " fix is valid when status >= STATUS_FIX."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4704,53 +4944,62 @@ float32 intensity"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct JoyFeedbackArray {
-        pub r#array: ::std::vec::Vec<self::JoyFeedback>,
+    pub struct NavSatStatus {
+        pub r#status: i8,
+        pub r#service: u16,
     }
-    impl ::roslibrust::RosMessageType for JoyFeedbackArray {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/JoyFeedbackArray";
-        const MD5SUM: &'static str = "cde5730a895b1fc4dee6f91b754b213d";
-        const DEFINITION: &'static str = r####"# This message publishes values for multiple feedback at once.
-JoyFeedback[] array
-================================================================================
-MSG: sensor_msgs/JoyFeedback
-# Declare of the type of feedback
-uint8 TYPE_LED    = 0
-uint8 TYPE_RUMBLE = 1
-uint8 TYPE_BUZZER = 2
+    impl ::roslibrust::RosMessageType for NavSatStatus {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/NavSatStatus";
+        const MD5SUM: &'static str = "331cdbddfa4bc96ffc3b9ad98900a54c";
+        const DEFINITION: &'static str = r####"# Navigation Satellite fix status for any Global Navigation Satellite System.
+#
+# Whether to output an augmented fix is determined by both the fix
+# type and the last time differential corrections were received.  A
+# fix is valid when status >= STATUS_FIX.
 
-uint8 type
+int8 STATUS_NO_FIX =  -1        # unable to fix position
+int8 STATUS_FIX =      0        # unaugmented fix
+int8 STATUS_SBAS_FIX = 1        # with satellite-based augmentation
+int8 STATUS_GBAS_FIX = 2        # with ground-based augmentation
 
-# This will hold an id number for each type of each feedback.
-# Example, the first led would be id=0, the second would be id=1
-uint8 id
+int8 status
 
-# Intensity of the feedback, from 0.0 to 1.0, inclusive.  If device is
-# actually binary, driver should treat 0<=x<0.5 as off, 0.5<=x<=1 as on.
-float32 intensity"####;
+# Bits defining which Global Navigation Satellite System signals were
+# used by the receiver.
+
+uint16 SERVICE_GPS =     1
+uint16 SERVICE_GLONASS = 2
+uint16 SERVICE_COMPASS = 4      # includes BeiDou.
+uint16 SERVICE_GALILEO = 8
+
+uint16 service"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_d1ed3befa628e09571bd273b888ba1c1fd187c9a5e0006b385d7e5e9095a3204";
     }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct LaserEcho {
-        pub r#echoes: ::std::vec::Vec<f32>,
+    #[allow(unused)]
+    impl NavSatStatus {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
-    impl ::roslibrust::RosMessageType for LaserEcho {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/LaserEcho";
-        const MD5SUM: &'static str = "8bc5ae449b200fba4d552b4225586696";
-        const DEFINITION: &'static str = r####"# This message is a submessage of MultiEchoLaserScan and is not intended
-# to be used separately.
-
-float32[] echoes  # Multiple values of ranges or intensities.
-                  # Each array represents data from the same angle increment."####;
+    #[allow(unused)]
+    impl NavSatStatus {
+        #[doc = " unable to fix position"]
+        pub const r#STATUS_NO_FIX: i8 = -1i8;
+        #[doc = " unaugmented fix"]
+        pub const r#STATUS_FIX: i8 = 0i8;
+        #[doc = " with satellite-based augmentation"]
+        pub const r#STATUS_SBAS_FIX: i8 = 1i8;
+        #[doc = " with ground-based augmentation"]
+        pub const r#STATUS_GBAS_FIX: i8 = 2i8;
+        pub const r#SERVICE_GPS: u16 = 1u16;
+        pub const r#SERVICE_GLONASS: u16 = 2u16;
+        #[doc = " includes BeiDou."]
+        pub const r#SERVICE_COMPASS: u16 = 4u16;
+        pub const r#SERVICE_GALILEO: u16 = 8u16;
     }
+    #[doc = " THIS MESSAGE IS DEPRECATED AS OF FOXY"]
+    #[doc = " Please use sensor_msgs/PointCloud2"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4761,51 +5010,37 @@ float32[] echoes  # Multiple values of ranges or intensities.
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct LaserScan {
+    pub struct PointCloud {
+        #[doc = " Time of sensor data acquisition, coordinate frame ID."]
         pub r#header: std_msgs::Header,
-        pub r#angle_min: f32,
-        pub r#angle_max: f32,
-        pub r#angle_increment: f32,
-        pub r#time_increment: f32,
-        pub r#scan_time: f32,
-        pub r#range_min: f32,
-        pub r#range_max: f32,
-        pub r#ranges: ::std::vec::Vec<f32>,
-        pub r#intensities: ::std::vec::Vec<f32>,
+        #[doc = " Array of 3d points. Each Point32 should be interpreted as a 3d point"]
+        #[doc = " in the frame given in the header."]
+        pub r#points: ::std::vec::Vec<geometry_msgs::Point32>,
+        #[doc = " Each channel should have the same number of elements as points array,"]
+        #[doc = " and the data in each channel should correspond 1:1 with each point."]
+        #[doc = " Channel names in common practice are listed in ChannelFloat32.msg."]
+        pub r#channels: ::std::vec::Vec<self::ChannelFloat32>,
     }
-    impl ::roslibrust::RosMessageType for LaserScan {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/LaserScan";
-        const MD5SUM: &'static str = "f86984b4383bf67523c75820e114e988";
-        const DEFINITION: &'static str = r####"# Single scan from a planar laser range-finder
-#
-# If you have another ranging device with different behavior (e.g. a sonar
-# array), please find or create a different message, since applications
-# will make fairly laser-specific assumptions about this data
-
-std_msgs/Header header # timestamp in the header is the acquisition time of
-                             # the first ray in the scan.
-                             #
-                             # in frame frame_id, angles are measured around
-                             # the positive Z axis (counterclockwise, if Z is up)
-                             # with zero angle being forward along the x axis
+    impl ::roslibrust::RosMessageType for PointCloud {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/PointCloud";
+        const MD5SUM: &'static str = "95c9c548e015c235b38b961c79973db7";
+        const DEFINITION: &'static str = r####"## THIS MESSAGE IS DEPRECATED AS OF FOXY
+## Please use sensor_msgs/PointCloud2
 
-float32 angle_min            # start angle of the scan [rad]
-float32 angle_max            # end angle of the scan [rad]
-float32 angle_increment      # angular distance between measurements [rad]
+# This message holds a collection of 3d points, plus optional additional
+# information about each point.
 
-float32 time_increment       # time between measurements [seconds] - if your scanner
-                             # is moving, this will be used in interpolating position
-                             # of 3d points
-float32 scan_time            # time between scans [seconds]
+# Time of sensor data acquisition, coordinate frame ID.
+std_msgs/Header header
 
-float32 range_min            # minimum range value [m]
-float32 range_max            # maximum range value [m]
+# Array of 3d points. Each Point32 should be interpreted as a 3d point
+# in the frame given in the header.
+geometry_msgs/Point32[] points
 
-float32[] ranges             # range data [m]
-                             # (Note: values < range_min or > range_max should be discarded)
-float32[] intensities        # intensity data [device-specific units].  If your
-                             # device does not provide intensities, please leave
-                             # the array empty.
+# Each channel should have the same number of elements as points array,
+# and the data in each channel should correspond 1:1 with each point.
+# Channel names in common practice are listed in ChannelFloat32.msg.
+ChannelFloat32[] channels
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -4816,70 +5051,63 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct MagneticField {
-        pub r#header: std_msgs::Header,
-        pub r#magnetic_field: geometry_msgs::Vector3,
-        pub r#magnetic_field_covariance: [f64; 9],
-    }
-    impl ::roslibrust::RosMessageType for MagneticField {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/MagneticField";
-        const MD5SUM: &'static str = "c8761d20eb9dc59addd882f1d4de2266";
-        const DEFINITION: &'static str = r####"# Measurement of the Magnetic Field vector at a specific location.
-#
-# If the covariance of the measurement is known, it should be filled in.
-# If all you know is the variance of each measurement, e.g. from the datasheet,
-# just put those along the diagonal.
-# A covariance matrix of all zeros will be interpreted as "covariance unknown",
-# and to use the data a covariance will have to be assumed or gotten from some
-# other source.
-
-std_msgs/Header header               # timestamp is the time the
-                                           # field was measured
-                                           # frame_id is the location and orientation
-                                           # of the field measurement
-
-geometry_msgs/Vector3 magnetic_field # x, y, and z components of the
-                                           # field vector in Tesla
-                                           # If your sensor does not output 3 axes,
-                                           # put NaNs in the components not reported.
-
-float64[9] magnetic_field_covariance       # Row major about x, y, z axes
-                                           # 0 is interpreted as variance unknown
+string frame_id
 ================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+MSG: geometry_msgs/Point32
+# This contains the position of a point in free space(with 32 bits of precision).
+# It is recommended to use Point wherever possible instead of Point32.
+#
+# This recommendation is to promote interoperability.
+#
+# This message is designed to take up less space when sending
+# lots of points at once, as in the case of a PointCloud.
 
-float64 x
-float64 y
-float64 z
+float32 x
+float32 y
+float32 z
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+MSG: sensor_msgs/ChannelFloat32
+# This message is used by the PointCloud message to hold optional data
+# associated with each point in the cloud. The length of the values
+# array should be the same as the length of the points array in the
+# PointCloud, and each value should be associated with the corresponding
+# point.
+#
+# Channel names in existing practice include:
+#   "u", "v" - row and column (respectively) in the left stereo image.
+#              This is opposite to usual conventions but remains for
+#              historical reasons. The newer PointCloud2 message has no
+#              such problem.
+#   "rgb" - For point clouds produced by color stereo cameras. uint8
+#           (R,G,B) values packed into the least significant 24 bits,
+#           in order.
+#   "intensity" - laser or pixel intensity.
+#   "distance"
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# The channel name should give semantics of the channel (e.g.
+# "intensity" instead of "value").
+string name
 
-# Transform frame with which this data is associated.
-string frame_id"####;
+# The values array should be 1-1 with the elements of the associated
+# PointCloud.
+float32[] values"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_614593df71d3c2b9bd4604a71b750fd218f0d65c045ea988b713719455a65b3b";
+    }
+    #[allow(unused)]
+    impl PointCloud {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This message holds a collection of N-dimensional points, which may"]
+    #[doc = " contain additional information such as normals, intensity, etc. The"]
+    #[doc = " point data is stored as a binary blob, its layout described by the"]
+    #[doc = " contents of the \"fields\" array."]
+    #[doc = ""]
+    #[doc = " The point cloud data may be organized 2d (image-like) or 1d (unordered)."]
+    #[doc = " Point clouds organized as 2d images may be produced by camera depth sensors"]
+    #[doc = " such as stereo or time-of-flight."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -4890,120 +5118,56 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct MultiDOFJointState {
+    pub struct PointCloud2 {
+        #[doc = " Time of sensor data acquisition, and the coordinate frame ID (for 3d points)."]
         pub r#header: std_msgs::Header,
-        pub r#joint_names: ::std::vec::Vec<::std::string::String>,
-        pub r#transforms: ::std::vec::Vec<geometry_msgs::Transform>,
-        pub r#twist: ::std::vec::Vec<geometry_msgs::Twist>,
-        pub r#wrench: ::std::vec::Vec<geometry_msgs::Wrench>,
+        #[doc = " 2D structure of the point cloud. If the cloud is unordered, height is"]
+        #[doc = " 1 and width is the length of the point cloud."]
+        pub r#height: u32,
+        pub r#width: u32,
+        #[doc = " Describes the channels and their layout in the binary data blob."]
+        pub r#fields: ::std::vec::Vec<self::PointField>,
+        #[doc = " Is this data bigendian?"]
+        pub r#is_bigendian: bool,
+        #[doc = " Length of a point in bytes"]
+        pub r#point_step: u32,
+        #[doc = " Length of a row in bytes"]
+        pub r#row_step: u32,
+        #[doc = " Actual point data, size is (row_step*height)"]
+        #[serde(with = "::roslibrust::codegen::serde_bytes")]
+        pub r#data: ::std::vec::Vec<u8>,
+        #[doc = " True if there are no invalid points"]
+        pub r#is_dense: bool,
     }
-    impl ::roslibrust::RosMessageType for MultiDOFJointState {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/MultiDOFJointState";
-        const MD5SUM: &'static str = "9eb02d78422731545fd7e9b60069f261";
-        const DEFINITION: &'static str = r####"# Representation of state for joints with multiple degrees of freedom,
-# following the structure of JointState which can only represent a single degree of freedom.
-#
-# It is assumed that a joint in a system corresponds to a transform that gets applied
-# along the kinematic chain. For example, a planar joint (as in URDF) is 3DOF (x, y, yaw)
-# and those 3DOF can be expressed as a transformation matrix, and that transformation
-# matrix can be converted back to (x, y, yaw)
-#
-# Each joint is uniquely identified by its name
-# The header specifies the time at which the joint states were recorded. All the joint states
-# in one message have to be recorded at the same time.
-#
-# This message consists of a multiple arrays, one for each part of the joint state.
-# The goal is to make each of the fields optional. When e.g. your joints have no
-# wrench associated with them, you can leave the wrench array empty.
+    impl ::roslibrust::RosMessageType for PointCloud2 {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/PointCloud2";
+        const MD5SUM: &'static str = "c61ffb665fe19735825e4dd31b53913d";
+        const DEFINITION: &'static str = r####"# This message holds a collection of N-dimensional points, which may
+# contain additional information such as normals, intensity, etc. The
+# point data is stored as a binary blob, its layout described by the
+# contents of the "fields" array.
 #
-# All arrays in this message should have the same size, or be empty.
-# This is the only way to uniquely associate the joint name with the correct
-# states.
+# The point cloud data may be organized 2d (image-like) or 1d (unordered).
+# Point clouds organized as 2d images may be produced by camera depth sensors
+# such as stereo or time-of-flight.
 
+# Time of sensor data acquisition, and the coordinate frame ID (for 3d points).
 std_msgs/Header header
 
-string[] joint_names
-geometry_msgs/Transform[] transforms
-geometry_msgs/Twist[] twist
-geometry_msgs/Wrench[] wrench
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Transform
-# This represents the transform between two coordinate frames in free space.
-
-Vector3 translation
-Quaternion rotation
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Twist
-# This expresses velocity in free space broken into its linear and angular parts.
-
-Vector3  linear
-Vector3  angular
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Wrench
-# This represents force in free space, separated into its linear and angular parts.
+# 2D structure of the point cloud. If the cloud is unordered, height is
+# 1 and width is the length of the point cloud.
+uint32 height
+uint32 width
 
-Vector3  force
-Vector3  torque
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
+# Describes the channels and their layout in the binary data blob.
+PointField[] fields
 
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+bool    is_bigendian # Is this data bigendian?
+uint32  point_step   # Length of a point in bytes
+uint32  row_step     # Length of a row in bytes
+uint8[] data         # Actual point data, size is (row_step*height)
 
-float64 x
-float64 y
-float64 z
+bool is_dense        # True if there are no invalid points
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -5014,7 +5178,33 @@ MSG: std_msgs/Header
 builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
-string frame_id"####;
+string frame_id
+================================================================================
+MSG: sensor_msgs/PointField
+# This message holds the description of one point entry in the
+# PointCloud2 message format.
+uint8 INT8    = 1
+uint8 UINT8   = 2
+uint8 INT16   = 3
+uint8 UINT16  = 4
+uint8 INT32   = 5
+uint8 UINT32  = 6
+uint8 FLOAT32 = 7
+uint8 FLOAT64 = 8
+
+# Common PointField names are x, y, z, intensity, rgb, rgba
+string name      # Name of field
+uint32 offset    # Offset from start of point struct
+uint8  datatype  # Datatype enumeration, see above
+uint32 count     # How many elements in the field"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_9198cabf7da3796ae6fe19c4cb3bdd3525492988c70522628af5daa124bae2b5";
+    }
+    #[allow(unused)]
+    impl PointCloud2 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -5026,72 +5216,67 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct MultiEchoLaserScan {
-        pub r#header: std_msgs::Header,
-        pub r#angle_min: f32,
-        pub r#angle_max: f32,
-        pub r#angle_increment: f32,
-        pub r#time_increment: f32,
-        pub r#scan_time: f32,
-        pub r#range_min: f32,
-        pub r#range_max: f32,
-        pub r#ranges: ::std::vec::Vec<self::LaserEcho>,
-        pub r#intensities: ::std::vec::Vec<self::LaserEcho>,
+    pub struct PointField {
+        #[doc = " Common PointField names are x, y, z, intensity, rgb, rgba"]
+        #[doc = " Name of field"]
+        pub r#name: ::std::string::String,
+        #[doc = " Offset from start of point struct"]
+        pub r#offset: u32,
+        #[doc = " Datatype enumeration, see above"]
+        pub r#datatype: u8,
+        #[doc = " How many elements in the field"]
+        pub r#count: u32,
     }
-    impl ::roslibrust::RosMessageType for MultiEchoLaserScan {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/MultiEchoLaserScan";
-        const MD5SUM: &'static str = "fda674281c16cdee9a79d075ab27d12f";
-        const DEFINITION: &'static str = r####"# Single scan from a multi-echo planar laser range-finder
-#
-# If you have another ranging device with different behavior (e.g. a sonar
-# array), please find or create a different message, since applications
-# will make fairly laser-specific assumptions about this data
-
-std_msgs/Header header # timestamp in the header is the acquisition time of
-                             # the first ray in the scan.
-                             #
-                             # in frame frame_id, angles are measured around
-                             # the positive Z axis (counterclockwise, if Z is up)
-                             # with zero angle being forward along the x axis
-
-float32 angle_min            # start angle of the scan [rad]
-float32 angle_max            # end angle of the scan [rad]
-float32 angle_increment      # angular distance between measurements [rad]
-
-float32 time_increment       # time between measurements [seconds] - if your scanner
-                             # is moving, this will be used in interpolating position
-                             # of 3d points
-float32 scan_time            # time between scans [seconds]
-
-float32 range_min            # minimum range value [m]
-float32 range_max            # maximum range value [m]
-
-LaserEcho[] ranges           # range data [m]
-                             # (Note: NaNs, values < range_min or > range_max should be discarded)
-                             # +Inf measurements are out of range
-                             # -Inf measurements are too close to determine exact distance.
-LaserEcho[] intensities      # intensity data [device-specific units].  If your
-                             # device does not provide intensities, please leave
-                             # the array empty.
-================================================================================
-MSG: sensor_msgs/LaserEcho
-# This message is a submessage of MultiEchoLaserScan and is not intended
-# to be used separately.
-
-float32[] echoes  # Multiple values of ranges or intensities.
-                  # Each array represents data from the same angle increment.
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+    impl ::roslibrust::RosMessageType for PointField {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/PointField";
+        const MD5SUM: &'static str = "268eacb2962780ceac86cbd17e328150";
+        const DEFINITION: &'static str = r####"# This message holds the description of one point entry in the
+# PointCloud2 message format.
+uint8 INT8    = 1
+uint8 UINT8   = 2
+uint8 INT16   = 3
+uint8 UINT16  = 4
+uint8 INT32   = 5
+uint8 UINT32  = 6
+uint8 FLOAT32 = 7
+uint8 FLOAT64 = 8
 
-# Transform frame with which this data is associated.
-string frame_id"####;
+# Common PointField names are x, y, z, intensity, rgb, rgba
+string name      # Name of field
+uint32 offset    # Offset from start of point struct
+uint8  datatype  # Datatype enumeration, see above
+uint32 count     # How many elements in the field"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_5c6a4750728c2bcfbbf7037225b20b02d4429634732146b742dee1726637ef01";
+    }
+    #[allow(unused)]
+    impl PointField {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[allow(unused)]
+    impl PointField {
+        #[doc = " This message holds the description of one point entry in the"]
+        #[doc = " PointCloud2 message format."]
+        pub const r#INT8: u8 = 1u8;
+        pub const r#UINT8: u8 = 2u8;
+        pub const r#INT16: u8 = 3u8;
+        pub const r#UINT16: u8 = 4u8;
+        pub const r#INT32: u8 = 5u8;
+        pub const r#UINT32: u8 = 6u8;
+        pub const r#FLOAT32: u8 = 7u8;
+        pub const r#FLOAT64: u8 = 8u8;
+    }
+    #[doc = " Single range reading from an active ranger that emits energy and reports"]
+    #[doc = " one range reading that is valid along an arc at the distance measured."]
+    #[doc = " This message is  not appropriate for laser scanners. See the LaserScan"]
+    #[doc = " message if you are working with a laser scanner."]
+    #[doc = ""]
+    #[doc = " This message also can represent a fixed-distance (binary) ranger.  This"]
+    #[doc = " sensor will have min_range===max_range===distance of detection."]
+    #[doc = " These sensors follow REP 117 and will output -Inf if the object is detected"]
+    #[doc = " and +Inf if the object is outside of the detection range."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -5102,87 +5287,62 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct NavSatFix {
+    pub struct Range {
+        #[doc = " timestamp in the header is the time the ranger"]
         pub r#header: std_msgs::Header,
-        pub r#status: self::NavSatStatus,
-        pub r#latitude: f64,
-        pub r#longitude: f64,
-        pub r#altitude: f64,
-        pub r#position_covariance: [f64; 9],
-        pub r#position_covariance_type: u8,
+        #[doc = " the type of radiation used by the sensor"]
+        pub r#radiation_type: u8,
+        #[doc = " the size of the arc that the distance reading is"]
+        pub r#field_of_view: f32,
+        #[doc = " minimum range value [m]"]
+        pub r#min_range: f32,
+        #[doc = " maximum range value [m]"]
+        pub r#max_range: f32,
+        #[doc = " range data [m]"]
+        pub r#range: f32,
     }
-    impl ::roslibrust::RosMessageType for NavSatFix {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/NavSatFix";
-        const MD5SUM: &'static str = "faa1756146a6a934d7e4ef0e3855c531";
-        const DEFINITION: &'static str = r####"# Navigation Satellite fix for any Global Navigation Satellite System
-#
-# Specified using the WGS 84 reference ellipsoid
-
-# header.stamp specifies the ROS time for this measurement (the
-#        corresponding satellite time may be reported using the
-#        sensor_msgs/TimeReference message).
-#
-# header.frame_id is the frame of reference reported by the satellite
-#        receiver, usually the location of the antenna.  This is a
-#        Euclidean frame relative to the vehicle, not a reference
-#        ellipsoid.
-std_msgs/Header header
-
-# Satellite fix status information.
-NavSatStatus status
-
-# Latitude [degrees]. Positive is north of equator; negative is south.
-float64 latitude
-
-# Longitude [degrees]. Positive is east of prime meridian; negative is west.
-float64 longitude
-
-# Altitude [m]. Positive is above the WGS 84 ellipsoid
-# (quiet NaN if no altitude is available).
-float64 altitude
-
-# Position covariance [m^2] defined relative to a tangential plane
-# through the reported position. The components are East, North, and
-# Up (ENU), in row-major order.
+    impl ::roslibrust::RosMessageType for Range {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Range";
+        const MD5SUM: &'static str = "1ec40687acdf15b9559a6ff690722eae";
+        const DEFINITION: &'static str = r####"# Single range reading from an active ranger that emits energy and reports
+# one range reading that is valid along an arc at the distance measured.
+# This message is  not appropriate for laser scanners. See the LaserScan
+# message if you are working with a laser scanner.
 #
-# Beware: this coordinate system exhibits singularities at the poles.
-float64[9] position_covariance
-
-# If the covariance of the fix is known, fill it in completely. If the
-# GPS receiver provides the variance of each measurement, put them
-# along the diagonal. If only Dilution of Precision is available,
-# estimate an approximate covariance from that.
-
-uint8 COVARIANCE_TYPE_UNKNOWN = 0
-uint8 COVARIANCE_TYPE_APPROXIMATED = 1
-uint8 COVARIANCE_TYPE_DIAGONAL_KNOWN = 2
-uint8 COVARIANCE_TYPE_KNOWN = 3
+# This message also can represent a fixed-distance (binary) ranger.  This
+# sensor will have min_range===max_range===distance of detection.
+# These sensors follow REP 117 and will output -Inf if the object is detected
+# and +Inf if the object is outside of the detection range.
 
-uint8 position_covariance_type
-================================================================================
-MSG: sensor_msgs/NavSatStatus
-# Navigation Satellite fix status for any Global Navigation Satellite System.
-#
-# Whether to output an augmented fix is determined by both the fix
-# type and the last time differential corrections were received.  A
-# fix is valid when status >= STATUS_FIX.
+std_msgs/Header header # timestamp in the header is the time the ranger
+                             # returned the distance reading
 
-int8 STATUS_NO_FIX =  -1        # unable to fix position
-int8 STATUS_FIX =      0        # unaugmented fix
-int8 STATUS_SBAS_FIX = 1        # with satellite-based augmentation
-int8 STATUS_GBAS_FIX = 2        # with ground-based augmentation
+# Radiation type enums
+# If you want a value added to this list, send an email to the ros-users list
+uint8 ULTRASOUND=0
+uint8 INFRARED=1
 
-int8 status
+uint8 radiation_type    # the type of radiation used by the sensor
+                        # (sound, IR, etc) [enum]
 
-# Bits defining which Global Navigation Satellite System signals were
-# used by the receiver.
+float32 field_of_view   # the size of the arc that the distance reading is
+                        # valid for [rad]
+                        # the object causing the range reading may have
+                        # been anywhere within -field_of_view/2 and
+                        # field_of_view/2 at the measured range.
+                        # 0 angle corresponds to the x-axis of the sensor.
 
-uint16 SERVICE_GPS =     1
-uint16 SERVICE_GLONASS = 2
-uint16 SERVICE_COMPASS = 4      # includes BeiDou.
-uint16 SERVICE_GALILEO = 8
+float32 min_range       # minimum range value [m]
+float32 max_range       # maximum range value [m]
+                        # Fixed distance rangers require min_range==max_range
 
-uint16 service
+float32 range           # range data [m]
+                        # (Note: values < range_min or > range_max should be discarded)
+                        # Fixed distance rangers only output -Inf or +Inf.
+                        # -Inf represents a detection within fixed distance.
+                        # (Detection too close to the sensor to quantify)
+                        # +Inf represents no detection within the fixed distance.
+                        # (Object out of range)
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -5194,14 +5354,28 @@ builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_9430b1915b94d4268ff903679e8ecd09b6a67d331bd028738ec1eeb592891ebd";
     }
     #[allow(unused)]
-    impl NavSatFix {
-        pub const r#COVARIANCE_TYPE_UNKNOWN: u8 = 0u8;
-        pub const r#COVARIANCE_TYPE_APPROXIMATED: u8 = 1u8;
-        pub const r#COVARIANCE_TYPE_DIAGONAL_KNOWN: u8 = 2u8;
-        pub const r#COVARIANCE_TYPE_KNOWN: u8 = 3u8;
+    impl Range {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl Range {
+        #[doc = " Radiation type enums"]
+        #[doc = " If you want a value added to this list, send an email to the ros-users list"]
+        pub const r#ULTRASOUND: u8 = 0u8;
+        pub const r#INFRARED: u8 = 1u8;
     }
+    #[doc = " This message is used to specify a region of interest within an image."]
+    #[doc = ""]
+    #[doc = " When used to specify the ROI setting of the camera when the image was"]
+    #[doc = " taken, the height and width fields should either match the height and"]
+    #[doc = " width fields for the associated image; or height = width = 0"]
+    #[doc = " indicates that the full resolution image was captured."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -5212,47 +5386,57 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct NavSatStatus {
-        pub r#status: i8,
-        pub r#service: u16,
+    pub struct RegionOfInterest {
+        #[doc = " Leftmost pixel of the ROI"]
+        pub r#x_offset: u32,
+        #[doc = " (0 if the ROI includes the left edge of the image)"]
+        #[doc = " Topmost pixel of the ROI"]
+        pub r#y_offset: u32,
+        #[doc = " (0 if the ROI includes the top edge of the image)"]
+        #[doc = " Height of ROI"]
+        pub r#height: u32,
+        #[doc = " Width of ROI"]
+        pub r#width: u32,
+        #[doc = " True if a distinct rectified ROI should be calculated from the \"raw\""]
+        #[doc = " ROI in this message. Typically this should be False if the full image"]
+        #[doc = " is captured (ROI not used), and True if a subwindow is captured (ROI"]
+        #[doc = " used)."]
+        pub r#do_rectify: bool,
     }
-    impl ::roslibrust::RosMessageType for NavSatStatus {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/NavSatStatus";
-        const MD5SUM: &'static str = "331cdbddfa4bc96ffc3b9ad98900a54c";
-        const DEFINITION: &'static str = r####"# Navigation Satellite fix status for any Global Navigation Satellite System.
+    impl ::roslibrust::RosMessageType for RegionOfInterest {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/RegionOfInterest";
+        const MD5SUM: &'static str = "bdb633039d588fcccb441a4d43ccfe09";
+        const DEFINITION: &'static str = r####"# This message is used to specify a region of interest within an image.
 #
-# Whether to output an augmented fix is determined by both the fix
-# type and the last time differential corrections were received.  A
-# fix is valid when status >= STATUS_FIX.
-
-int8 STATUS_NO_FIX =  -1        # unable to fix position
-int8 STATUS_FIX =      0        # unaugmented fix
-int8 STATUS_SBAS_FIX = 1        # with satellite-based augmentation
-int8 STATUS_GBAS_FIX = 2        # with ground-based augmentation
-
-int8 status
+# When used to specify the ROI setting of the camera when the image was
+# taken, the height and width fields should either match the height and
+# width fields for the associated image; or height = width = 0
+# indicates that the full resolution image was captured.
 
-# Bits defining which Global Navigation Satellite System signals were
-# used by the receiver.
+uint32 x_offset  # Leftmost pixel of the ROI
+                 # (0 if the ROI includes the left edge of the image)
+uint32 y_offset  # Topmost pixel of the ROI
+                 # (0 if the ROI includes the top edge of the image)
+uint32 height    # Height of ROI
+uint32 width     # Width of ROI
 
-uint16 SERVICE_GPS =     1
-uint16 SERVICE_GLONASS = 2
-uint16 SERVICE_COMPASS = 4      # includes BeiDou.
-uint16 SERVICE_GALILEO = 8
-
-uint16 service"####;
+# True if a distinct rectified ROI should be calculated from the "raw"
+# ROI in this message. Typically this should be False if the full image
+# is captured (ROI not used), and True if a subwindow is captured (ROI
+# used).
+bool do_rectify"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_ad16bcba5f9131dcdba6fbded19f726f5440e3c513b4fb586dd3027eeed8abb1";
     }
     #[allow(unused)]
-    impl NavSatStatus {
-        pub const r#STATUS_NO_FIX: i8 = -1i8;
-        pub const r#STATUS_FIX: i8 = 0i8;
-        pub const r#STATUS_SBAS_FIX: i8 = 1i8;
-        pub const r#STATUS_GBAS_FIX: i8 = 2i8;
-        pub const r#SERVICE_GPS: u16 = 1u16;
-        pub const r#SERVICE_GLONASS: u16 = 2u16;
-        pub const r#SERVICE_COMPASS: u16 = 4u16;
-        pub const r#SERVICE_GALILEO: u16 = 8u16;
+    impl RegionOfInterest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Single reading from a relative humidity sensor."]
+    #[doc = " Defines the ratio of partial pressure of water vapor to the saturated vapor"]
+    #[doc = " pressure at a temperature."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -5263,70 +5447,30 @@ uint16 service"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct PointCloud {
+    pub struct RelativeHumidity {
+        #[doc = " timestamp of the measurement"]
         pub r#header: std_msgs::Header,
-        pub r#points: ::std::vec::Vec<geometry_msgs::Point32>,
-        pub r#channels: ::std::vec::Vec<self::ChannelFloat32>,
+        #[doc = " Expression of the relative humidity"]
+        pub r#relative_humidity: f64,
+        #[doc = " 0 is interpreted as variance unknown"]
+        pub r#variance: f64,
     }
-    impl ::roslibrust::RosMessageType for PointCloud {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/PointCloud";
-        const MD5SUM: &'static str = "95c9c548e015c235b38b961c79973db7";
-        const DEFINITION: &'static str = r####"## THIS MESSAGE IS DEPRECATED AS OF FOXY
-## Please use sensor_msgs/PointCloud2
-
-# This message holds a collection of 3d points, plus optional additional
-# information about each point.
-
-# Time of sensor data acquisition, coordinate frame ID.
-std_msgs/Header header
-
-# Array of 3d points. Each Point32 should be interpreted as a 3d point
-# in the frame given in the header.
-geometry_msgs/Point32[] points
-
-# Each channel should have the same number of elements as points array,
-# and the data in each channel should correspond 1:1 with each point.
-# Channel names in common practice are listed in ChannelFloat32.msg.
-ChannelFloat32[] channels
-================================================================================
-MSG: geometry_msgs/Point32
-# This contains the position of a point in free space(with 32 bits of precision).
-# It is recommended to use Point wherever possible instead of Point32.
-#
-# This recommendation is to promote interoperability.
-#
-# This message is designed to take up less space when sending
-# lots of points at once, as in the case of a PointCloud.
+    impl ::roslibrust::RosMessageType for RelativeHumidity {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/RelativeHumidity";
+        const MD5SUM: &'static str = "71cfefa31dcc94f47083b1e89e6fa5c9";
+        const DEFINITION: &'static str = r####"# Single reading from a relative humidity sensor.
+# Defines the ratio of partial pressure of water vapor to the saturated vapor
+# pressure at a temperature.
 
-float32 x
-float32 y
-float32 z
-================================================================================
-MSG: sensor_msgs/ChannelFloat32
-# This message is used by the PointCloud message to hold optional data
-# associated with each point in the cloud. The length of the values
-# array should be the same as the length of the points array in the
-# PointCloud, and each value should be associated with the corresponding
-# point.
-#
-# Channel names in existing practice include:
-#   "u", "v" - row and column (respectively) in the left stereo image.
-#              This is opposite to usual conventions but remains for
-#              historical reasons. The newer PointCloud2 message has no
-#              such problem.
-#   "rgb" - For point clouds produced by color stereo cameras. uint8
-#           (R,G,B) values packed into the least significant 24 bits,
-#           in order.
-#   "intensity" - laser or pixel intensity.
-#   "distance"
+std_msgs/Header header # timestamp of the measurement
+                             # frame_id is the location of the humidity sensor
 
-# The channel name should give semantics of the channel (e.g.
-# "intensity" instead of "value").
-string name
+float64 relative_humidity    # Expression of the relative humidity
+                             # from 0.0 to 1.0.
+                             # 0.0 is no partial pressure of water vapor
+                             # 1.0 represents partial pressure of saturation
 
-# The values array should be 1-1 with the elements of the associated
-# PointCloud.
-float32[] values
+float64 variance             # 0 is interpreted as variance unknown
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -5338,7 +5482,16 @@ builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_8687c99b4fb393cb2e545e407b5ea7fd0b5d8960bcd849a0f86c544740138839";
+    }
+    #[allow(unused)]
+    impl RelativeHumidity {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Single temperature reading."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -5349,65 +5502,25 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct PointCloud2 {
+    pub struct Temperature {
+        #[doc = " timestamp is the time the temperature was measured"]
         pub r#header: std_msgs::Header,
-        pub r#height: u32,
-        pub r#width: u32,
-        pub r#fields: ::std::vec::Vec<self::PointField>,
-        pub r#is_bigendian: bool,
-        pub r#point_step: u32,
-        pub r#row_step: u32,
-        #[serde(with = "::roslibrust::codegen::serde_bytes")]
-        pub r#data: ::std::vec::Vec<u8>,
-        pub r#is_dense: bool,
+        #[doc = " Measurement of the Temperature in Degrees Celsius."]
+        pub r#temperature: f64,
+        #[doc = " 0 is interpreted as variance unknown."]
+        pub r#variance: f64,
     }
-    impl ::roslibrust::RosMessageType for PointCloud2 {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/PointCloud2";
-        const MD5SUM: &'static str = "c61ffb665fe19735825e4dd31b53913d";
-        const DEFINITION: &'static str = r####"# This message holds a collection of N-dimensional points, which may
-# contain additional information such as normals, intensity, etc. The
-# point data is stored as a binary blob, its layout described by the
-# contents of the "fields" array.
-#
-# The point cloud data may be organized 2d (image-like) or 1d (unordered).
-# Point clouds organized as 2d images may be produced by camera depth sensors
-# such as stereo or time-of-flight.
-
-# Time of sensor data acquisition, and the coordinate frame ID (for 3d points).
-std_msgs/Header header
-
-# 2D structure of the point cloud. If the cloud is unordered, height is
-# 1 and width is the length of the point cloud.
-uint32 height
-uint32 width
-
-# Describes the channels and their layout in the binary data blob.
-PointField[] fields
+    impl ::roslibrust::RosMessageType for Temperature {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Temperature";
+        const MD5SUM: &'static str = "c6df0674fcfebff84a15927a80ebb14b";
+        const DEFINITION: &'static str = r####"# Single temperature reading.
 
-bool    is_bigendian # Is this data bigendian?
-uint32  point_step   # Length of a point in bytes
-uint32  row_step     # Length of a row in bytes
-uint8[] data         # Actual point data, size is (row_step*height)
+std_msgs/Header header # timestamp is the time the temperature was measured
+                             # frame_id is the location of the temperature reading
 
-bool is_dense        # True if there are no invalid points
-================================================================================
-MSG: sensor_msgs/PointField
-# This message holds the description of one point entry in the
-# PointCloud2 message format.
-uint8 INT8    = 1
-uint8 UINT8   = 2
-uint8 INT16   = 3
-uint8 UINT16  = 4
-uint8 INT32   = 5
-uint8 UINT32  = 6
-uint8 FLOAT32 = 7
-uint8 FLOAT64 = 8
+float64 temperature          # Measurement of the Temperature in Degrees Celsius.
 
-# Common PointField names are x, y, z, intensity, rgb, rgba
-string name      # Name of field
-uint32 offset    # Offset from start of point struct
-uint8  datatype  # Datatype enumeration, see above
-uint32 count     # How many elements in the field
+float64 variance             # 0 is interpreted as variance unknown.
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -5419,7 +5532,16 @@ builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_72514a14126ab9f8a9abec974c78e5610a367b59db5da355ff1fb982d5bad4b8";
+    }
+    #[allow(unused)]
+    impl Temperature {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Measurement from an external time source not actively synchronized with the system clock."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -5430,43 +5552,51 @@ string frame_id"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct PointField {
-        pub r#name: ::std::string::String,
-        pub r#offset: u32,
-        pub r#datatype: u8,
-        pub r#count: u32,
+    pub struct TimeReference {
+        #[doc = " stamp is system time for which measurement was valid"]
+        pub r#header: std_msgs::Header,
+        #[doc = " corresponding time from this external source"]
+        pub r#time_ref: ::roslibrust::codegen::integral_types::Time,
+        #[doc = " (optional) name of time source"]
+        pub r#source: ::std::string::String,
     }
-    impl ::roslibrust::RosMessageType for PointField {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/PointField";
-        const MD5SUM: &'static str = "268eacb2962780ceac86cbd17e328150";
-        const DEFINITION: &'static str = r####"# This message holds the description of one point entry in the
-# PointCloud2 message format.
-uint8 INT8    = 1
-uint8 UINT8   = 2
-uint8 INT16   = 3
-uint8 UINT16  = 4
-uint8 INT32   = 5
-uint8 UINT32  = 6
-uint8 FLOAT32 = 7
-uint8 FLOAT64 = 8
+    impl ::roslibrust::RosMessageType for TimeReference {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/TimeReference";
+        const MD5SUM: &'static str = "7cb7ae5aa838323e9028637e304e0ad7";
+        const DEFINITION: &'static str = r####"# Measurement from an external time source not actively synchronized with the system clock.
 
-# Common PointField names are x, y, z, intensity, rgb, rgba
-string name      # Name of field
-uint32 offset    # Offset from start of point struct
-uint8  datatype  # Datatype enumeration, see above
-uint32 count     # How many elements in the field"####;
+std_msgs/Header header      # stamp is system time for which measurement was valid
+                                  # frame_id is not used
+
+builtin_interfaces/Time time_ref  # corresponding time from this external source
+string source                     # (optional) name of time source
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_dd66e84cf40bbb5d5a40472e6ecf2675a031334d4c426abdb2ad41801a8efc99";
     }
     #[allow(unused)]
-    impl PointField {
-        pub const r#INT8: u8 = 1u8;
-        pub const r#UINT8: u8 = 2u8;
-        pub const r#INT16: u8 = 3u8;
-        pub const r#UINT16: u8 = 4u8;
-        pub const r#INT32: u8 = 5u8;
-        pub const r#UINT32: u8 = 6u8;
-        pub const r#FLOAT32: u8 = 7u8;
-        pub const r#FLOAT64: u8 = 8u8;
+    impl TimeReference {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This service requests that a camera stores the given CameraInfo as that"]
+    #[doc = " camera's calibration information."]
+    #[doc = ""]
+    #[doc = " The width and height in the camera_info field should match what the"]
+    #[doc = " camera is currently outputting on its camera_info topic, and the camera"]
+    #[doc = " will assume that the region of the imager that is being referred to is"]
+    #[doc = " the region that the camera is currently capturing."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -5477,254 +5607,20 @@ uint32 count     # How many elements in the field"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct Range {
-        pub r#header: std_msgs::Header,
-        pub r#radiation_type: u8,
-        pub r#field_of_view: f32,
-        pub r#min_range: f32,
-        pub r#max_range: f32,
-        pub r#range: f32,
+    pub struct SetCameraInfoRequest {
+        #[doc = " The camera_info to store"]
+        pub r#camera_info: self::CameraInfo,
     }
-    impl ::roslibrust::RosMessageType for Range {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Range";
-        const MD5SUM: &'static str = "1ec40687acdf15b9559a6ff690722eae";
-        const DEFINITION: &'static str = r####"# Single range reading from an active ranger that emits energy and reports
-# one range reading that is valid along an arc at the distance measured.
-# This message is  not appropriate for laser scanners. See the LaserScan
-# message if you are working with a laser scanner.
+    impl ::roslibrust::RosMessageType for SetCameraInfoRequest {
+        const ROS_TYPE_NAME: &'static str = "sensor_msgs/SetCameraInfoRequest";
+        const MD5SUM: &'static str = "251c96e357751cc7c699c496178141d5";
+        const DEFINITION: &'static str = r####"# This service requests that a camera stores the given CameraInfo as that
+# camera's calibration information.
 #
-# This message also can represent a fixed-distance (binary) ranger.  This
-# sensor will have min_range===max_range===distance of detection.
-# These sensors follow REP 117 and will output -Inf if the object is detected
-# and +Inf if the object is outside of the detection range.
-
-std_msgs/Header header # timestamp in the header is the time the ranger
-                             # returned the distance reading
-
-# Radiation type enums
-# If you want a value added to this list, send an email to the ros-users list
-uint8 ULTRASOUND=0
-uint8 INFRARED=1
-
-uint8 radiation_type    # the type of radiation used by the sensor
-                        # (sound, IR, etc) [enum]
-
-float32 field_of_view   # the size of the arc that the distance reading is
-                        # valid for [rad]
-                        # the object causing the range reading may have
-                        # been anywhere within -field_of_view/2 and
-                        # field_of_view/2 at the measured range.
-                        # 0 angle corresponds to the x-axis of the sensor.
-
-float32 min_range       # minimum range value [m]
-float32 max_range       # maximum range value [m]
-                        # Fixed distance rangers require min_range==max_range
-
-float32 range           # range data [m]
-                        # (Note: values < range_min or > range_max should be discarded)
-                        # Fixed distance rangers only output -Inf or +Inf.
-                        # -Inf represents a detection within fixed distance.
-                        # (Detection too close to the sensor to quantify)
-                        # +Inf represents no detection within the fixed distance.
-                        # (Object out of range)
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(unused)]
-    impl Range {
-        pub const r#ULTRASOUND: u8 = 0u8;
-        pub const r#INFRARED: u8 = 1u8;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct RegionOfInterest {
-        pub r#x_offset: u32,
-        pub r#y_offset: u32,
-        pub r#height: u32,
-        pub r#width: u32,
-        pub r#do_rectify: bool,
-    }
-    impl ::roslibrust::RosMessageType for RegionOfInterest {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/RegionOfInterest";
-        const MD5SUM: &'static str = "bdb633039d588fcccb441a4d43ccfe09";
-        const DEFINITION: &'static str = r####"# This message is used to specify a region of interest within an image.
-#
-# When used to specify the ROI setting of the camera when the image was
-# taken, the height and width fields should either match the height and
-# width fields for the associated image; or height = width = 0
-# indicates that the full resolution image was captured.
-
-uint32 x_offset  # Leftmost pixel of the ROI
-                 # (0 if the ROI includes the left edge of the image)
-uint32 y_offset  # Topmost pixel of the ROI
-                 # (0 if the ROI includes the top edge of the image)
-uint32 height    # Height of ROI
-uint32 width     # Width of ROI
-
-# True if a distinct rectified ROI should be calculated from the "raw"
-# ROI in this message. Typically this should be False if the full image
-# is captured (ROI not used), and True if a subwindow is captured (ROI
-# used).
-bool do_rectify"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct RelativeHumidity {
-        pub r#header: std_msgs::Header,
-        pub r#relative_humidity: f64,
-        pub r#variance: f64,
-    }
-    impl ::roslibrust::RosMessageType for RelativeHumidity {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/RelativeHumidity";
-        const MD5SUM: &'static str = "71cfefa31dcc94f47083b1e89e6fa5c9";
-        const DEFINITION: &'static str = r####"# Single reading from a relative humidity sensor.
-# Defines the ratio of partial pressure of water vapor to the saturated vapor
-# pressure at a temperature.
-
-std_msgs/Header header # timestamp of the measurement
-                             # frame_id is the location of the humidity sensor
-
-float64 relative_humidity    # Expression of the relative humidity
-                             # from 0.0 to 1.0.
-                             # 0.0 is no partial pressure of water vapor
-                             # 1.0 represents partial pressure of saturation
-
-float64 variance             # 0 is interpreted as variance unknown
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct Temperature {
-        pub r#header: std_msgs::Header,
-        pub r#temperature: f64,
-        pub r#variance: f64,
-    }
-    impl ::roslibrust::RosMessageType for Temperature {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/Temperature";
-        const MD5SUM: &'static str = "c6df0674fcfebff84a15927a80ebb14b";
-        const DEFINITION: &'static str = r####"# Single temperature reading.
-
-std_msgs/Header header # timestamp is the time the temperature was measured
-                             # frame_id is the location of the temperature reading
-
-float64 temperature          # Measurement of the Temperature in Degrees Celsius.
-
-float64 variance             # 0 is interpreted as variance unknown.
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct TimeReference {
-        pub r#header: std_msgs::Header,
-        pub r#time_ref: ::roslibrust::codegen::integral_types::Time,
-        pub r#source: ::std::string::String,
-    }
-    impl ::roslibrust::RosMessageType for TimeReference {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/TimeReference";
-        const MD5SUM: &'static str = "7cb7ae5aa838323e9028637e304e0ad7";
-        const DEFINITION: &'static str = r####"# Measurement from an external time source not actively synchronized with the system clock.
-
-std_msgs/Header header      # stamp is system time for which measurement was valid
-                                  # frame_id is not used
-
-builtin_interfaces/Time time_ref  # corresponding time from this external source
-string source                     # (optional) name of time source
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct SetCameraInfoRequest {
-        pub r#camera_info: self::CameraInfo,
-    }
-    impl ::roslibrust::RosMessageType for SetCameraInfoRequest {
-        const ROS_TYPE_NAME: &'static str = "sensor_msgs/SetCameraInfoRequest";
-        const MD5SUM: &'static str = "251c96e357751cc7c699c496178141d5";
-        const DEFINITION: &'static str = r####"# This service requests that a camera stores the given CameraInfo as that
-# camera's calibration information.
-#
-# The width and height in the camera_info field should match what the
-# camera is currently outputting on its camera_info topic, and the camera
-# will assume that the region of the imager that is being referred to is
-# the region that the camera is currently capturing.
+# The width and height in the camera_info field should match what the
+# camera is currently outputting on its camera_info topic, and the camera
+# will assume that the region of the imager that is being referred to is
+# the region that the camera is currently capturing.
 
 sensor_msgs/CameraInfo camera_info # The camera_info to store
 ================================================================================
@@ -5861,27 +5757,6 @@ uint32 binning_y
 #  full resolution (roi.width = width, roi.height = height).
 RegionOfInterest roi
 ================================================================================
-MSG: sensor_msgs/RegionOfInterest
-# This message is used to specify a region of interest within an image.
-#
-# When used to specify the ROI setting of the camera when the image was
-# taken, the height and width fields should either match the height and
-# width fields for the associated image; or height = width = 0
-# indicates that the full resolution image was captured.
-
-uint32 x_offset  # Leftmost pixel of the ROI
-                 # (0 if the ROI includes the left edge of the image)
-uint32 y_offset  # Topmost pixel of the ROI
-                 # (0 if the ROI includes the top edge of the image)
-uint32 height    # Height of ROI
-uint32 width     # Width of ROI
-
-# True if a distinct rectified ROI should be calculated from the "raw"
-# ROI in this message. Typically this should be False if the full image
-# is captured (ROI not used), and True if a subwindow is captured (ROI
-# used).
-bool do_rectify
-================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
 # This is generally used to communicate timestamped data
@@ -5912,18 +5787,15 @@ uint32 width     # Width of ROI
 # ROI in this message. Typically this should be False if the full image
 # is captured (ROI not used), and True if a subwindow is captured (ROI
 # used).
-bool do_rectify
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
+bool do_rectify"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_27dc5497730fdb6930e66cb879c210e31a83022acc5bbc999f24f55286029f87";
+    }
+    #[allow(unused)]
+    impl SetCameraInfoRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -5936,7 +5808,9 @@ string frame_id"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct SetCameraInfoResponse {
+        #[doc = " True if the call succeeded"]
         pub r#success: bool,
+        #[doc = " Used to give details about success"]
         pub r#status_message: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for SetCameraInfoResponse {
@@ -5944,12 +5818,22 @@ string frame_id"####;
         const MD5SUM: &'static str = "2ec6f3eff0161f4257b808b12bc830c2";
         const DEFINITION: &'static str = r####"bool success                             # True if the call succeeded
 string status_message                    # Used to give details about success"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_4db6d7df20cdcdc05430945e76fa58e1eab1285c15f31d26fad76e2c1b10299c";
+    }
+    #[allow(unused)]
+    impl SetCameraInfoResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct SetCameraInfo {}
     impl ::roslibrust::RosServiceType for SetCameraInfo {
         const ROS_SERVICE_NAME: &'static str = "sensor_msgs/SetCameraInfo";
         const MD5SUM: &'static str = "c191a50a3d5730b8679f4b95b3948b15";
+        const TYPE_HASH: &'static str =
+            "RIHS01_a10cca5d33dc637c8d49db50ab288701a3592bb9cd854f2f16a0659613b68984";
         type Request = SetCameraInfoRequest;
         type Response = SetCameraInfoResponse;
     }
@@ -5967,6 +5851,7 @@ pub mod shape_msgs {
     use super::test_msgs;
     use super::trajectory_msgs;
     use super::visualization_msgs;
+    #[doc = " Definition of a mesh."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -5978,7 +5863,9 @@ pub mod shape_msgs {
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Mesh {
+        #[doc = " List of triangles; the index values refer to positions in vertices[]."]
         pub r#triangles: ::std::vec::Vec<self::MeshTriangle>,
+        #[doc = " The actual vertices that make up the mesh."]
         pub r#vertices: ::std::vec::Vec<geometry_msgs::Point>,
     }
     impl ::roslibrust::RosMessageType for Mesh {
@@ -5992,17 +5879,26 @@ MeshTriangle[] triangles
 # The actual vertices that make up the mesh.
 geometry_msgs/Point[] vertices
 ================================================================================
+MSG: shape_msgs/MeshTriangle
+# Definition of a triangle's vertices.
+
+uint32[3] vertex_indices
+================================================================================
 MSG: geometry_msgs/Point
 # This contains the position of a point in free space
 float64 x
 float64 y
-float64 z
-================================================================================
-MSG: shape_msgs/MeshTriangle
-# Definition of a triangle's vertices.
-
-uint32[3] vertex_indices"####;
+float64 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_f2150b82d8ee7e8bc3f396a2b158aefb4b9a5510a474be271ba1268aebb55289";
+    }
+    #[allow(unused)]
+    impl Mesh {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Definition of a triangle's vertices."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6022,6 +5918,14 @@ uint32[3] vertex_indices"####;
         const DEFINITION: &'static str = r####"# Definition of a triangle's vertices.
 
 uint32[3] vertex_indices"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_618e5c073eeb729e433ef6226e72c01d995c459fb7d76348c9700409a5020bd0";
+    }
+    #[allow(unused)]
+    impl MeshTriangle {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -6034,6 +5938,12 @@ uint32[3] vertex_indices"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Plane {
+        #[doc = " Representation of a plane, using the plane equation ax + by + cz + d = 0."]
+        #[doc = ""]
+        #[doc = " a := coef[0]"]
+        #[doc = " b := coef[1]"]
+        #[doc = " c := coef[2]"]
+        #[doc = " d := coef[3]"]
         pub r#coef: [f64; 4],
     }
     impl ::roslibrust::RosMessageType for Plane {
@@ -6046,7 +5956,17 @@ uint32[3] vertex_indices"####;
 # c := coef[2]
 # d := coef[3]
 float64[4] coef"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_dfbfe8314689c850615d4a727af017e9aa86c10e369a606c8c851ef8f16c58c8";
+    }
+    #[allow(unused)]
+    impl Plane {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Defines box, sphere, cylinder, cone and prism."]
+    #[doc = " All shapes are defined to have their bounding boxes centered around 0,0,0."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6058,13 +5978,17 @@ float64[4] coef"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct SolidPrimitive {
+        #[doc = " The type of the shape"]
         pub r#type: u8,
-        pub r#dimensions: [f64; 0],
+        #[doc = " The dimensions of the shape"]
+        #[doc = " At no point will dimensions have a length > 3."]
+        #[serde(with = "::roslibrust::codegen::BoundedSequence::<3>")]
+        pub r#dimensions: ::std::vec::Vec<f64>,
         pub r#polygon: geometry_msgs::Polygon,
     }
     impl ::roslibrust::RosMessageType for SolidPrimitive {
         const ROS_TYPE_NAME: &'static str = "shape_msgs/SolidPrimitive";
-        const MD5SUM: &'static str = "0cdf91a0a45ccd7bc1e0deb784cb2958";
+        const MD5SUM: &'static str = "8c7314fcbb621a6a7a6af3200d726861";
         const DEFINITION: &'static str = r####"# Defines box, sphere, cylinder, cone and prism.
 # All shapes are defined to have their bounding boxes centered around 0,0,0.
 
@@ -6116,19 +6040,6 @@ uint8 CONE_RADIUS=1
 uint8 PRISM_HEIGHT=0
 geometry_msgs/Polygon polygon
 ================================================================================
-MSG: geometry_msgs/Point32
-# This contains the position of a point in free space(with 32 bits of precision).
-# It is recommended to use Point wherever possible instead of Point32.
-#
-# This recommendation is to promote interoperability.
-#
-# This message is designed to take up less space when sending
-# lots of points at once, as in the case of a PointCloud.
-
-float32 x
-float32 y
-float32 z
-================================================================================
 MSG: geometry_msgs/Polygon
 # A specification of a polygon where the first and last points are assumed to be connected
 
@@ -6146,6 +6057,14 @@ MSG: geometry_msgs/Point32
 float32 x
 float32 y
 float32 z"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_2802a15190aadc3f496584df4b0b4c5824d8a0b31aaef839faa75bc34dda38ac";
+    }
+    #[allow(unused)]
+    impl SolidPrimitive {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(unused)]
     impl SolidPrimitive {
@@ -6154,9 +6073,11 @@ float32 z"####;
         pub const r#CYLINDER: u8 = 3u8;
         pub const r#CONE: u8 = 4u8;
         pub const r#PRISM: u8 = 5u8;
+        #[doc = " For type BOX, the X, Y, and Z dimensions are the length of the corresponding sides of the box."]
         pub const r#BOX_X: u8 = 0u8;
         pub const r#BOX_Y: u8 = 1u8;
         pub const r#BOX_Z: u8 = 2u8;
+        #[doc = " For the SPHERE type, only one component is used, and it gives the radius of the sphere."]
         pub const r#SPHERE_RADIUS: u8 = 0u8;
         pub const r#CYLINDER_HEIGHT: u8 = 0u8;
         pub const r#CYLINDER_RADIUS: u8 = 1u8;
@@ -6178,6 +6099,10 @@ pub mod std_msgs {
     use super::test_msgs;
     use super::trajectory_msgs;
     use super::visualization_msgs;
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6200,7 +6125,19 @@ pub mod std_msgs {
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 bool data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_feb91e995ff9ebd09c0cb3d2aed18b11077585839fb5db80193b62d74528f6c9";
+    }
+    #[allow(unused)]
+    impl Bool {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6223,7 +6160,19 @@ bool data"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 byte data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_41e1a3345f73fe93ede006da826a6ee274af23dd4653976ff249b0f44e3e798f";
+    }
+    #[allow(unused)]
+    impl Byte {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6235,7 +6184,9 @@ byte data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct ByteMultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<u8>,
     }
     impl ::roslibrust::RosMessageType for ByteMultiArray {
@@ -6252,16 +6203,6 @@ byte data"####;
 MultiArrayLayout  layout        # specification of data layout
 byte[]            data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -6304,7 +6245,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_972fec7f50ab3c1d06783c228e79e8a9a509021708c511c059926261ada901d4";
+    }
+    #[allow(unused)]
+    impl ByteMultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6327,6 +6280,14 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 char data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_3ad2d04dd29ba19d04b16659afa3ccaedd691914b02a64e82e252f2fa6a586a9";
+    }
+    #[allow(unused)]
+    impl Char {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -6351,6 +6312,14 @@ char data"####;
 float32 g
 float32 b
 float32 a"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_77a7a5b9ae477306097665106e0413ba74440245b1f3d0c6d6405fe5c7813fe8";
+    }
+    #[allow(unused)]
+    impl ColorRGBA {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -6367,7 +6336,19 @@ float32 a"####;
         const ROS_TYPE_NAME: &'static str = "std_msgs/Empty";
         const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
         const DEFINITION: &'static str = r####""####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_20b625256f32d5dbc0d04fee44f43c41e51c70d3502f84b4a08e7a9c26a96312";
     }
+    #[allow(unused)]
+    impl Empty {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6390,7 +6371,19 @@ float32 a"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 float32 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_7170d3d8f841f7be3172ce5f4f59f3a4d7f63b0447e8b33327601ad64d83d6e2";
+    }
+    #[allow(unused)]
+    impl Float32 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6402,7 +6395,9 @@ float32 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Float32MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<f32>,
     }
     impl ::roslibrust::RosMessageType for Float32MultiArray {
@@ -6419,16 +6414,6 @@ float32 data"####;
 MultiArrayLayout  layout        # specification of data layout
 float32[]         data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -6471,7 +6456,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_0599f6f85b4bfca379873a0b4375a0aca022156bd2d7021275d116ed1fa8bfe0";
+    }
+    #[allow(unused)]
+    impl Float32MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6494,7 +6491,19 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 float64 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_705ba9c3d1a09df43737eb67095534de36fd426c0587779bda2bc51fe790182a";
+    }
+    #[allow(unused)]
+    impl Float64 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6506,7 +6515,9 @@ float64 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Float64MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<f64>,
     }
     impl ::roslibrust::RosMessageType for Float64MultiArray {
@@ -6523,16 +6534,6 @@ float64 data"####;
 MultiArrayLayout  layout        # specification of data layout
 float64[]         data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -6575,7 +6576,18 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_1025ddc6b9552d191f89ef1a8d2f60f3d373e28b283d8891ddcc974e8c55397f";
+    }
+    #[allow(unused)]
+    impl Float64MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Standard metadata for higher-level stamped data types."]
+    #[doc = " This is generally used to communicate timestamped data"]
+    #[doc = " in a particular coordinate frame."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6587,7 +6599,9 @@ uint32 stride  # stride of given dimension"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Header {
+        #[doc = " Two-integer timestamp that is expressed as seconds and nanoseconds."]
         pub r#stamp: ::roslibrust::codegen::integral_types::Time,
+        #[doc = " Transform frame with which this data is associated."]
         pub r#frame_id: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for Header {
@@ -6602,7 +6616,19 @@ builtin_interfaces/Time stamp
 
 # Transform frame with which this data is associated.
 string frame_id"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_f49fb3ae2cf070f793645ff749683ac6b06203e41c891e17701b1cb597ce6a01";
     }
+    #[allow(unused)]
+    impl Header {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6625,7 +6651,19 @@ string frame_id"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 int16 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_1dcc3464e47c288a55f943a389d337cdb06804de3f5cd7a266b0de718eee17e5";
+    }
+    #[allow(unused)]
+    impl Int16 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6637,7 +6675,9 @@ int16 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Int16MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<i16>,
     }
     impl ::roslibrust::RosMessageType for Int16MultiArray {
@@ -6654,16 +6694,6 @@ int16 data"####;
 MultiArrayLayout  layout        # specification of data layout
 int16[]           data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -6706,7 +6736,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_b58810e8e5b90fb19a5062469eb8409f5ab11a446d60de7157a1457e52a076ce";
+    }
+    #[allow(unused)]
+    impl Int16MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6729,7 +6771,19 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 int32 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_b6578ded3c58c626cfe8d1a6fb6e04f706f97e9f03d2727c9ff4e74b1cef0deb";
+    }
+    #[allow(unused)]
+    impl Int32 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6741,7 +6795,9 @@ int32 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Int32MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<i32>,
     }
     impl ::roslibrust::RosMessageType for Int32MultiArray {
@@ -6758,16 +6814,6 @@ int32 data"####;
 MultiArrayLayout  layout        # specification of data layout
 int32[]           data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -6810,7 +6856,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_84a7346323525d1b4dfca899df3820f245e54009dac5a6b69217d14fdefd1701";
+    }
+    #[allow(unused)]
+    impl Int32MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6833,7 +6891,19 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 int64 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_8cd1048c2f186b6bd9a92472dc1ce51723c0833a221e2b7aecfff111774f4b49";
     }
+    #[allow(unused)]
+    impl Int64 {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6845,7 +6915,9 @@ int64 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Int64MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<i64>,
     }
     impl ::roslibrust::RosMessageType for Int64MultiArray {
@@ -6862,16 +6934,6 @@ int64 data"####;
 MultiArrayLayout  layout        # specification of data layout
 int64[]           data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -6914,7 +6976,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_e60f9fe34d697f0939ad49d33158693c1277fbac0e2f04b7c2995dc21c89b422";
+    }
+    #[allow(unused)]
+    impl Int64MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6937,7 +7011,19 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 int8 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_26525065a403d972cb672f0777e333f0c799ad444ae5fcd79e43d1e73bd0f440";
+    }
+    #[allow(unused)]
+    impl Int8 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -6949,7 +7035,9 @@ int8 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct Int8MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<i8>,
     }
     impl ::roslibrust::RosMessageType for Int8MultiArray {
@@ -6966,16 +7054,6 @@ int8 data"####;
 MultiArrayLayout  layout        # specification of data layout
 int8[]            data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -7018,7 +7096,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_f21998d4b492abd63330765d75d5831238d400740386f651f13a872a4d2188db";
     }
+    #[allow(unused)]
+    impl Int8MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7030,8 +7120,11 @@ uint32 stride  # stride of given dimension"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct MultiArrayDimension {
+        #[doc = " label of given dimension"]
         pub r#label: ::std::string::String,
+        #[doc = " size of given dimension (in type units)"]
         pub r#size: u32,
+        #[doc = " stride of given dimension"]
         pub r#stride: u32,
     }
     impl ::roslibrust::RosMessageType for MultiArrayDimension {
@@ -7045,7 +7138,19 @@ uint32 stride  # stride of given dimension"####;
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_5e773a60a4c7fc8a54985f307c7837aa2994252a126c301957a24e31282c9cbe";
+    }
+    #[allow(unused)]
+    impl MultiArrayDimension {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7057,7 +7162,9 @@ uint32 stride  # stride of given dimension"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct MultiArrayLayout {
+        #[doc = " Array of dimension properties"]
         pub r#dim: ::std::vec::Vec<self::MultiArrayDimension>,
+        #[doc = " padding bytes at front of data"]
         pub r#data_offset: u32,
     }
     impl ::roslibrust::RosMessageType for MultiArrayLayout {
@@ -7104,7 +7211,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_4c66e6f78e740ac103a94cf63259f968e48c617e7699e829b63c21a5cb50dac6";
     }
+    #[allow(unused)]
+    impl MultiArrayLayout {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7127,7 +7246,19 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 string data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18";
+    }
+    #[allow(unused)]
+    impl String {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7150,7 +7281,19 @@ string data"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 uint16 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_08a406e4b022bc22e907f985d6a9e9dd1d4fbecae573549cf49350113e7757b1";
     }
+    #[allow(unused)]
+    impl UInt16 {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7162,7 +7305,9 @@ uint16 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct UInt16MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<u16>,
     }
     impl ::roslibrust::RosMessageType for UInt16MultiArray {
@@ -7179,16 +7324,6 @@ uint16 data"####;
 MultiArrayLayout  layout        # specification of data layout
 uint16[]            data        # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -7231,7 +7366,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_94fe73428ec63baecc774f8fb82406123e9291cf728f1b7c91caf5335129492b";
+    }
+    #[allow(unused)]
+    impl UInt16MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7254,7 +7401,19 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 uint32 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_a5c874829b752bc5fa190024b0ad76f578cc278271e855c7d02a818b3516fb4a";
+    }
+    #[allow(unused)]
+    impl UInt32 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7266,7 +7425,9 @@ uint32 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct UInt32MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<u32>,
     }
     impl ::roslibrust::RosMessageType for UInt32MultiArray {
@@ -7283,16 +7444,6 @@ uint32 data"####;
 MultiArrayLayout  layout        # specification of data layout
 uint32[]          data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -7335,7 +7486,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_6c2577c7ad3cbdcc2164a41c12f1d5ad314ea320f3fb1ee47e78019fe16bb5b0";
     }
+    #[allow(unused)]
+    impl UInt32MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7358,7 +7521,19 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 uint64 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_fbdc52018fc13755dce18024d1a671c856aa8b4aaf63adfb095b608f98e8c943";
+    }
+    #[allow(unused)]
+    impl UInt64 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7370,7 +7545,9 @@ uint64 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct UInt64MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         pub r#data: ::std::vec::Vec<u64>,
     }
     impl ::roslibrust::RosMessageType for UInt64MultiArray {
@@ -7387,16 +7564,6 @@ uint64 data"####;
 MultiArrayLayout  layout        # specification of data layout
 uint64[]          data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -7439,7 +7606,19 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_fc1c685c2f76bdc6983da025cb25d2db5fb5157b059e300f6d957d86f981b366";
+    }
+    #[allow(unused)]
+    impl UInt64MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7462,7 +7641,19 @@ uint32 stride  # stride of given dimension"####;
 # However if you would like to continue using this please use the equivalent in example_msgs.
 
 uint8 data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_6138bd83d8c3569cb80a667db03cfc1629f529fee79d944c39c34e352e72f010";
+    }
+    #[allow(unused)]
+    impl UInt8 {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " This was originally provided as an example message."]
+    #[doc = " It is deprecated as of Foxy"]
+    #[doc = " It is recommended to create your own semantically meaningful message."]
+    #[doc = " However if you would like to continue using this please use the equivalent in example_msgs."]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -7474,7 +7665,9 @@ uint8 data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct UInt8MultiArray {
+        #[doc = " specification of data layout"]
         pub r#layout: self::MultiArrayLayout,
+        #[doc = " array of data"]
         #[serde(with = "::roslibrust::codegen::serde_bytes")]
         pub r#data: ::std::vec::Vec<u8>,
     }
@@ -7492,16 +7685,6 @@ uint8 data"####;
 MultiArrayLayout  layout        # specification of data layout
 uint8[]           data          # array of data
 ================================================================================
-MSG: std_msgs/MultiArrayDimension
-# This was originally provided as an example message.
-# It is deprecated as of Foxy
-# It is recommended to create your own semantically meaningful message.
-# However if you would like to continue using this please use the equivalent in example_msgs.
-
-string label   # label of given dimension
-uint32 size    # size of given dimension (in type units)
-uint32 stride  # stride of given dimension
-================================================================================
 MSG: std_msgs/MultiArrayLayout
 # This was originally provided as an example message.
 # It is deprecated as of Foxy
@@ -7544,6 +7727,14 @@ MSG: std_msgs/MultiArrayDimension
 string label   # label of given dimension
 uint32 size    # size of given dimension (in type units)
 uint32 stride  # stride of given dimension"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_5687e861b8d307a5e48b7515467ae7a5fc2daf805bd0ce6d8e9e604bade9f385";
+    }
+    #[allow(unused)]
+    impl UInt8MultiArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
 }
 #[allow(unused_imports)]
@@ -7574,6 +7765,14 @@ pub mod std_srvs {
         const ROS_TYPE_NAME: &'static str = "std_srvs/EmptyRequest";
         const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
         const DEFINITION: &'static str = r####""####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_458eaf200a3f63d9cc53d19436edebc0c140ef4c93ca75d5bd4ce19fd7759e35";
+    }
+    #[allow(unused)]
+    impl EmptyRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -7590,12 +7789,22 @@ pub mod std_srvs {
         const ROS_TYPE_NAME: &'static str = "std_srvs/EmptyResponse";
         const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
         const DEFINITION: &'static str = r####""####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_0a2858b3f60df644a61d0fa44cfc923a3d3628e0ad9c8ad8534b1a2c92a413dc";
+    }
+    #[allow(unused)]
+    impl EmptyResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct Empty {}
     impl ::roslibrust::RosServiceType for Empty {
         const ROS_SERVICE_NAME: &'static str = "std_srvs/Empty";
         const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const TYPE_HASH: &'static str =
+            "RIHS01_5888399dedec5ccc85ea6451949fd2c9f97bfdf963f9a588821639fcd31b5d19";
         type Request = EmptyRequest;
         type Response = EmptyResponse;
     }
@@ -7610,6 +7819,7 @@ pub mod std_srvs {
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct SetBoolRequest {
+        #[doc = " e.g. for hardware enabling / disabling"]
         pub r#data: bool,
     }
     impl ::roslibrust::RosMessageType for SetBoolRequest {
@@ -7617,6 +7827,14 @@ pub mod std_srvs {
         const MD5SUM: &'static str = "8b94c1b53db61fb6aed406028ad6332a";
         const DEFINITION: &'static str =
             r####"bool data # e.g. for hardware enabling / disabling"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_c62fbb99d94e1b25e8ef9e109f9581956bb1b3361a45a4e5810c36a90d29932e";
+    }
+    #[allow(unused)]
+    impl SetBoolRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -7629,7 +7847,9 @@ pub mod std_srvs {
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct SetBoolResponse {
+        #[doc = " indicate successful run of triggered service"]
         pub r#success: bool,
+        #[doc = " informational, e.g. for error messages"]
         pub r#message: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for SetBoolResponse {
@@ -7637,12 +7857,22 @@ pub mod std_srvs {
         const MD5SUM: &'static str = "937c9679a518e3a18d831e57125ea522";
         const DEFINITION: &'static str = r####"bool success   # indicate successful run of triggered service
 string message # informational, e.g. for error messages"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_d0814e7f7b4880ab77e9c57426c7aa1562ab69f11eef8e2e968812f9cbd0b059";
+    }
+    #[allow(unused)]
+    impl SetBoolResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct SetBool {}
     impl ::roslibrust::RosServiceType for SetBool {
         const ROS_SERVICE_NAME: &'static str = "std_srvs/SetBool";
         const MD5SUM: &'static str = "09fb03525b03e7ea1fd3992bafd87e16";
+        const TYPE_HASH: &'static str =
+            "RIHS01_abe9e4bb6b41b40e6789712c00ec8871923e089af3f667a79992a428cff2da0a";
         type Request = SetBoolRequest;
         type Response = SetBoolResponse;
     }
@@ -7661,6 +7891,14 @@ string message # informational, e.g. for error messages"####;
         const ROS_TYPE_NAME: &'static str = "std_srvs/TriggerRequest";
         const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
         const DEFINITION: &'static str = r####""####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_d010825374ce8918e72bfd826c82603e60f45419e932ea976f807b74a863a199";
+    }
+    #[allow(unused)]
+    impl TriggerRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -7673,7 +7911,9 @@ string message # informational, e.g. for error messages"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct TriggerResponse {
+        #[doc = " indicate successful run of triggered service"]
         pub r#success: bool,
+        #[doc = " informational, e.g. for error messages"]
         pub r#message: ::std::string::String,
     }
     impl ::roslibrust::RosMessageType for TriggerResponse {
@@ -7681,12 +7921,22 @@ string message # informational, e.g. for error messages"####;
         const MD5SUM: &'static str = "937c9679a518e3a18d831e57125ea522";
         const DEFINITION: &'static str = r####"bool success   # indicate successful run of triggered service
 string message # informational, e.g. for error messages"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_2d946c21e2fc3f1e9ca6986a8191d85fcc70097a8bb7771a053564bc47009cdf";
+    }
+    #[allow(unused)]
+    impl TriggerResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct Trigger {}
     impl ::roslibrust::RosServiceType for Trigger {
         const ROS_SERVICE_NAME: &'static str = "std_srvs/Trigger";
         const MD5SUM: &'static str = "937c9679a518e3a18d831e57125ea522";
+        const TYPE_HASH: &'static str =
+            "RIHS01_eeff2cd6fa5ad9d27cdf4dec64818317839b62f212a91e6b5304b634b2062c5f";
         type Request = TriggerRequest;
         type Response = TriggerResponse;
     }
@@ -7715,4315 +7965,80 @@ pub mod stereo_msgs {
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct DisparityImage {
+        #[doc = " Separate header for compatibility with current TimeSynchronizer."]
+        #[doc = " Likely to be removed in a later release, use image.header instead."]
         pub r#header: std_msgs::Header,
+        #[doc = " Floating point disparity image. The disparities are pre-adjusted for any"]
+        #[doc = " x-offset between the principal points of the two cameras (in the case"]
+        #[doc = " that they are verged). That is: d = x_l - x_r - (cx_l - cx_r)"]
         pub r#image: sensor_msgs::Image,
+        #[doc = " Stereo geometry. For disparity d, the depth from the camera is Z = fT/d."]
+        #[doc = " Focal length, pixels"]
         pub r#f: f32,
+        #[doc = " Baseline, world units"]
         pub r#t: f32,
+        #[doc = " Subwindow of (potentially) valid disparity values."]
         pub r#valid_window: sensor_msgs::RegionOfInterest,
+        #[doc = " The range of disparities searched."]
+        #[doc = " In the disparity image, any disparity less than min_disparity is invalid."]
+        #[doc = " The disparity search range defines the horopter, or 3D volume that the"]
+        #[doc = " stereo algorithm can \"see\". Points with Z outside of:"]
+        #[doc = " Z_min = fT / max_disparity"]
+        #[doc = " Z_max = fT / min_disparity"]
+        #[doc = " could not be found."]
         pub r#min_disparity: f32,
         pub r#max_disparity: f32,
+        #[doc = " Smallest allowed disparity increment. The smallest achievable depth range"]
+        #[doc = " resolution is delta_Z = (Z^2/fT)*delta_d."]
         pub r#delta_d: f32,
     }
     impl ::roslibrust::RosMessageType for DisparityImage {
         const ROS_TYPE_NAME: &'static str = "stereo_msgs/DisparityImage";
-        const MD5SUM: &'static str = "cb0de8feef04280238c7b77d74b2beca";
-        const DEFINITION: &'static str = r####"# Separate header for compatibility with current TimeSynchronizer.
-# Likely to be removed in a later release, use image.header instead.
-std_msgs/Header header
-
-# Floating point disparity image. The disparities are pre-adjusted for any
-# x-offset between the principal points of the two cameras (in the case
-# that they are verged). That is: d = x_l - x_r - (cx_l - cx_r)
-sensor_msgs/Image image
-
-# Stereo geometry. For disparity d, the depth from the camera is Z = fT/d.
-float32 f # Focal length, pixels
-float32 t # Baseline, world units
-
-# Subwindow of (potentially) valid disparity values.
-sensor_msgs/RegionOfInterest valid_window
-
-# The range of disparities searched.
-# In the disparity image, any disparity less than min_disparity is invalid.
-# The disparity search range defines the horopter, or 3D volume that the
-# stereo algorithm can "see". Points with Z outside of:
-#     Z_min = fT / max_disparity
-#     Z_max = fT / min_disparity
-# could not be found.
-float32 min_disparity
-float32 max_disparity
-
-# Smallest allowed disparity increment. The smallest achievable depth range
-# resolution is delta_Z = (Z^2/fT)*delta_d.
-float32 delta_d
-================================================================================
-MSG: sensor_msgs/Image
-# This message contains an uncompressed image
-# (0, 0) is at top-left corner of image
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-                             # If the frame_id here and the frame_id of the CameraInfo
-                             # message associated with the image conflict
-                             # the behavior is undefined
-
-uint32 height                # image height, that is, number of rows
-uint32 width                 # image width, that is, number of columns
-
-# The legal values for encoding are in file include/sensor_msgs/image_encodings.hpp
-# If you want to standardize a new string format, join
-# ros-users@lists.ros.org and send an email proposing a new encoding.
-
-string encoding       # Encoding of pixels -- channel meaning, ordering, size
-                      # taken from the list of strings in include/sensor_msgs/image_encodings.hpp
-
-uint8 is_bigendian    # is this data bigendian?
-uint32 step           # Full row length in bytes
-uint8[] data          # actual matrix data, size is (step * rows)
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: sensor_msgs/RegionOfInterest
-# This message is used to specify a region of interest within an image.
-#
-# When used to specify the ROI setting of the camera when the image was
-# taken, the height and width fields should either match the height and
-# width fields for the associated image; or height = width = 0
-# indicates that the full resolution image was captured.
-
-uint32 x_offset  # Leftmost pixel of the ROI
-                 # (0 if the ROI includes the left edge of the image)
-uint32 y_offset  # Topmost pixel of the ROI
-                 # (0 if the ROI includes the top edge of the image)
-uint32 height    # Height of ROI
-uint32 width     # Width of ROI
-
-# True if a distinct rectified ROI should be calculated from the "raw"
-# ROI in this message. Typically this should be False if the full image
-# is captured (ROI not used), and True if a subwindow is captured (ROI
-# used).
-bool do_rectify
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
-    }
-}
-#[allow(unused_imports)]
-pub mod test_msgs {
-    use super::actionlib_msgs;
-    use super::diagnostic_msgs;
-    use super::geometry_msgs;
-    use super::nav_msgs;
-    use super::sensor_msgs;
-    use super::shape_msgs;
-    use super::std_msgs;
-    use super::std_srvs;
-    use super::stereo_msgs;
-    use super::trajectory_msgs;
-    use super::visualization_msgs;
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct Defaults {
-        #[default(42u8)]
-        pub r#x: u8,
-        # [default (- 2000i16)]
-        pub r#y: i16,
-        #[default("John Doe")]
-        pub r#full_name: ::std::string::String,
-        #[default(_code = "vec![-200, -100, 0, 100, 200]")]
-        pub r#samples: ::std::vec::Vec<i32>,
-        #[default(_code = "vec![-200.0, -1.0, 0.0]")]
-        pub r#f_samples: ::std::vec::Vec<f32>,
-        #[default(_code = "[\"hello\", \"world\"].iter().map(|x| x.to_string()).collect()")]
-        pub r#s_vec: ::std::vec::Vec<::std::string::String>,
-    }
-    impl ::roslibrust::RosMessageType for Defaults {
-        const ROS_TYPE_NAME: &'static str = "test_msgs/Defaults";
-        const MD5SUM: &'static str = "43c441dc2b521c313f54affd982b5314";
-        const DEFINITION: &'static str = r####"# This message is specifically for testing generating of default values
-# Examples based on https://docs.ros.org/en/rolling/Concepts/About-ROS-Interfaces.html
-uint8 x 42
-int16 y -2000
-string full_name "John Doe"
-int32[] samples [-200, -100, 0, 100, 200]
-
-# More complicated examples to stress the system, floats with mixed precision
-float32[] f_samples [-200, -1.0, 0]
-string[] s_vec ["hello", "world"]
-# This may or may not be valid ROS, it probably is, but we don't handle yet
-# TODO handle this somehow
-# string[] s_vec_2 ['hello', 'world']
-
-# TODO ROS says this is valid, but we currently don't handle
-#string single_quote 'Jane Doe'"####;
-    }
-}
-#[allow(unused_imports)]
-pub mod trajectory_msgs {
-    use super::actionlib_msgs;
-    use super::diagnostic_msgs;
-    use super::geometry_msgs;
-    use super::nav_msgs;
-    use super::sensor_msgs;
-    use super::shape_msgs;
-    use super::std_msgs;
-    use super::std_srvs;
-    use super::stereo_msgs;
-    use super::test_msgs;
-    use super::visualization_msgs;
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct JointTrajectory {
-        pub r#header: std_msgs::Header,
-        pub r#joint_names: ::std::vec::Vec<::std::string::String>,
-        pub r#points: ::std::vec::Vec<self::JointTrajectoryPoint>,
-    }
-    impl ::roslibrust::RosMessageType for JointTrajectory {
-        const ROS_TYPE_NAME: &'static str = "trajectory_msgs/JointTrajectory";
-        const MD5SUM: &'static str = "d63e3b4556d9dbd9f48b5ab4a03f1fee";
-        const DEFINITION: &'static str = r####"# The header is used to specify the coordinate frame and the reference time for
-# the trajectory durations
-std_msgs/Header header
-
-# The names of the active joints in each trajectory point. These names are
-# ordered and must correspond to the values in each trajectory point.
-string[] joint_names
-
-# Array of trajectory points, which describe the positions, velocities,
-# accelerations and/or efforts of the joints at each time point.
-JointTrajectoryPoint[] points
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: trajectory_msgs/JointTrajectoryPoint
-# Each trajectory point specifies either positions[, velocities[, accelerations]]
-# or positions[, effort] for the trajectory to be executed.
-# All specified values are in the same order as the joint names in JointTrajectory.msg.
-
-# Single DOF joint positions for each joint relative to their "0" position.
-# The units depend on the specific joint type: radians for revolute or
-# continuous joints, and meters for prismatic joints.
-float64[] positions
-
-# The rate of change in position of each joint. Units are joint type dependent.
-# Radians/second for revolute or continuous joints, and meters/second for
-# prismatic joints.
-float64[] velocities
-
-# Rate of change in velocity of each joint. Units are joint type dependent.
-# Radians/second^2 for revolute or continuous joints, and meters/second^2 for
-# prismatic joints.
-float64[] accelerations
-
-# The torque or the force to be applied at each joint. For revolute/continuous
-# joints effort denotes a torque in newton-meters. For prismatic joints, effort
-# denotes a force in newtons.
-float64[] effort
-
-# Desired time from the trajectory start to arrive at this trajectory point.
-builtin_interfaces/Duration time_from_start"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct JointTrajectoryPoint {
-        pub r#positions: ::std::vec::Vec<f64>,
-        pub r#velocities: ::std::vec::Vec<f64>,
-        pub r#accelerations: ::std::vec::Vec<f64>,
-        pub r#effort: ::std::vec::Vec<f64>,
-        pub r#time_from_start: ::roslibrust::codegen::integral_types::Duration,
-    }
-    impl ::roslibrust::RosMessageType for JointTrajectoryPoint {
-        const ROS_TYPE_NAME: &'static str = "trajectory_msgs/JointTrajectoryPoint";
-        const MD5SUM: &'static str = "2c812f86aa886c93954e333721749ac5";
-        const DEFINITION: &'static str = r####"# Each trajectory point specifies either positions[, velocities[, accelerations]]
-# or positions[, effort] for the trajectory to be executed.
-# All specified values are in the same order as the joint names in JointTrajectory.msg.
-
-# Single DOF joint positions for each joint relative to their "0" position.
-# The units depend on the specific joint type: radians for revolute or
-# continuous joints, and meters for prismatic joints.
-float64[] positions
-
-# The rate of change in position of each joint. Units are joint type dependent.
-# Radians/second for revolute or continuous joints, and meters/second for
-# prismatic joints.
-float64[] velocities
-
-# Rate of change in velocity of each joint. Units are joint type dependent.
-# Radians/second^2 for revolute or continuous joints, and meters/second^2 for
-# prismatic joints.
-float64[] accelerations
-
-# The torque or the force to be applied at each joint. For revolute/continuous
-# joints effort denotes a torque in newton-meters. For prismatic joints, effort
-# denotes a force in newtons.
-float64[] effort
-
-# Desired time from the trajectory start to arrive at this trajectory point.
-builtin_interfaces/Duration time_from_start"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct MultiDOFJointTrajectory {
-        pub r#header: std_msgs::Header,
-        pub r#joint_names: ::std::vec::Vec<::std::string::String>,
-        pub r#points: ::std::vec::Vec<self::MultiDOFJointTrajectoryPoint>,
-    }
-    impl ::roslibrust::RosMessageType for MultiDOFJointTrajectory {
-        const ROS_TYPE_NAME: &'static str = "trajectory_msgs/MultiDOFJointTrajectory";
-        const MD5SUM: &'static str = "d00d14d97bd70c5eb648278240cfb066";
-        const DEFINITION: &'static str = r####"# The header is used to specify the coordinate frame and the reference time for the trajectory durations
-std_msgs/Header header
-
-# A representation of a multi-dof joint trajectory (each point is a transformation)
-# Each point along the trajectory will include an array of positions/velocities/accelerations
-# that has the same length as the array of joint names, and has the same order of joints as 
-# the joint names array.
-
-string[] joint_names
-MultiDOFJointTrajectoryPoint[] points
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Transform
-# This represents the transform between two coordinate frames in free space.
-
-Vector3 translation
-Quaternion rotation
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Twist
-# This expresses velocity in free space broken into its linear and angular parts.
-
-Vector3  linear
-Vector3  angular
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: trajectory_msgs/MultiDOFJointTrajectoryPoint
-# Each multi-dof joint can specify a transform (up to 6 DOF).
-geometry_msgs/Transform[] transforms
-
-# There can be a velocity specified for the origin of the joint.
-geometry_msgs/Twist[] velocities
-
-# There can be an acceleration specified for the origin of the joint.
-geometry_msgs/Twist[] accelerations
-
-# Desired time from the trajectory start to arrive at this trajectory point.
-builtin_interfaces/Duration time_from_start
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Transform
-# This represents the transform between two coordinate frames in free space.
-
-Vector3 translation
-Quaternion rotation
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Twist
-# This expresses velocity in free space broken into its linear and angular parts.
-
-Vector3  linear
-Vector3  angular
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct MultiDOFJointTrajectoryPoint {
-        pub r#transforms: ::std::vec::Vec<geometry_msgs::Transform>,
-        pub r#velocities: ::std::vec::Vec<geometry_msgs::Twist>,
-        pub r#accelerations: ::std::vec::Vec<geometry_msgs::Twist>,
-        pub r#time_from_start: ::roslibrust::codegen::integral_types::Duration,
-    }
-    impl ::roslibrust::RosMessageType for MultiDOFJointTrajectoryPoint {
-        const ROS_TYPE_NAME: &'static str = "trajectory_msgs/MultiDOFJointTrajectoryPoint";
-        const MD5SUM: &'static str = "6731945e53cbc0fbc6e93c28f7416a71";
-        const DEFINITION: &'static str = r####"# Each multi-dof joint can specify a transform (up to 6 DOF).
-geometry_msgs/Transform[] transforms
-
-# There can be a velocity specified for the origin of the joint.
-geometry_msgs/Twist[] velocities
-
-# There can be an acceleration specified for the origin of the joint.
-geometry_msgs/Twist[] accelerations
-
-# Desired time from the trajectory start to arrive at this trajectory point.
-builtin_interfaces/Duration time_from_start
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Transform
-# This represents the transform between two coordinate frames in free space.
-
-Vector3 translation
-Quaternion rotation
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Twist
-# This expresses velocity in free space broken into its linear and angular parts.
-
-Vector3  linear
-Vector3  angular
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z"####;
-    }
-}
-#[allow(unused_imports)]
-pub mod visualization_msgs {
-    use super::actionlib_msgs;
-    use super::diagnostic_msgs;
-    use super::geometry_msgs;
-    use super::nav_msgs;
-    use super::sensor_msgs;
-    use super::shape_msgs;
-    use super::std_msgs;
-    use super::std_srvs;
-    use super::stereo_msgs;
-    use super::test_msgs;
-    use super::trajectory_msgs;
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct ImageMarker {
-        pub r#header: std_msgs::Header,
-        pub r#ns: ::std::string::String,
-        pub r#id: i32,
-        pub r#type: i32,
-        pub r#action: i32,
-        pub r#position: geometry_msgs::Point,
-        pub r#scale: f32,
-        pub r#outline_color: std_msgs::ColorRGBA,
-        pub r#filled: u8,
-        pub r#fill_color: std_msgs::ColorRGBA,
-        pub r#lifetime: ::roslibrust::codegen::integral_types::Duration,
-        pub r#points: ::std::vec::Vec<geometry_msgs::Point>,
-        pub r#outline_colors: ::std::vec::Vec<std_msgs::ColorRGBA>,
-    }
-    impl ::roslibrust::RosMessageType for ImageMarker {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/ImageMarker";
-        const MD5SUM: &'static str = "829dd5d9ba39b8c3844252ebd8b47b96";
-        const DEFINITION: &'static str = r####"int32 CIRCLE=0
-int32 LINE_STRIP=1
-int32 LINE_LIST=2
-int32 POLYGON=3
-int32 POINTS=4
-
-int32 ADD=0
-int32 REMOVE=1
-
-std_msgs/Header header
-# Namespace which is used with the id to form a unique id.
-string ns
-# Unique id within the namespace.
-int32 id
-# One of the above types, e.g. CIRCLE, LINE_STRIP, etc.
-int32 type
-# Either ADD or REMOVE.
-int32 action
-# Two-dimensional coordinate position, in pixel-coordinates.
-geometry_msgs/Point position
-# The scale of the object, e.g. the diameter for a CIRCLE.
-float32 scale
-# The outline color of the marker.
-std_msgs/ColorRGBA outline_color
-# Whether or not to fill in the shape with color.
-uint8 filled
-# Fill color; in the range: [0.0-1.0]
-std_msgs/ColorRGBA fill_color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-
-# Coordinates in 2D in pixel coords. Used for LINE_STRIP, LINE_LIST, POINTS, etc.
-geometry_msgs/Point[] points
-# The color for each line, point, etc. in the points field.
-std_msgs/ColorRGBA[] outline_colors
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(unused)]
-    impl ImageMarker {
-        pub const r#CIRCLE: i32 = 0i32;
-        pub const r#LINE_STRIP: i32 = 1i32;
-        pub const r#LINE_LIST: i32 = 2i32;
-        pub const r#POLYGON: i32 = 3i32;
-        pub const r#POINTS: i32 = 4i32;
-        pub const r#ADD: i32 = 0i32;
-        pub const r#REMOVE: i32 = 1i32;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct InteractiveMarker {
-        pub r#header: std_msgs::Header,
-        pub r#pose: geometry_msgs::Pose,
-        pub r#name: ::std::string::String,
-        pub r#description: ::std::string::String,
-        pub r#scale: f32,
-        pub r#menu_entries: ::std::vec::Vec<self::MenuEntry>,
-        pub r#controls: ::std::vec::Vec<self::InteractiveMarkerControl>,
-    }
-    impl ::roslibrust::RosMessageType for InteractiveMarker {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarker";
-        const MD5SUM: &'static str = "d71737fa44c5bdefd6bdb4fa9b2b86e5";
-        const DEFINITION: &'static str = r####"# Time/frame info.
-# If header.time is set to 0, the marker will be retransformed into
-# its frame on each timestep. You will receive the pose feedback
-# in the same frame.
-# Otherwise, you might receive feedback in a different frame.
-# For rviz, this will be the current 'fixed frame' set by the user.
-std_msgs/Header header
-
-# Initial pose. Also, defines the pivot point for rotations.
-geometry_msgs/Pose pose
-
-# Identifying string. Must be globally unique in
-# the topic that this message is sent through.
-string name
-
-# Short description (< 40 characters).
-string description
-
-# Scale to be used for default controls (default=1).
-float32 scale
-
-# All menu and submenu entries associated with this marker.
-MenuEntry[] menu_entries
-
-# List of controls displayed for this marker.
-InteractiveMarkerControl[] controls
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/InteractiveMarkerControl
-# Represents a control that is to be displayed together with an interactive marker
-
-# Identifying string for this control.
-# You need to assign a unique value to this to receive feedback from the GUI
-# on what actions the user performs on this control (e.g. a button click).
-string name
-
-
-# Defines the local coordinate frame (relative to the pose of the parent
-# interactive marker) in which is being rotated and translated.
-# Default: Identity
-geometry_msgs/Quaternion orientation
-
-
-# Orientation mode: controls how orientation changes.
-# INHERIT: Follow orientation of interactive marker
-# FIXED: Keep orientation fixed at initial state
-# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
-uint8 INHERIT = 0
-uint8 FIXED = 1
-uint8 VIEW_FACING = 2
-
-uint8 orientation_mode
-
-# Interaction mode for this control
-#
-# NONE: This control is only meant for visualization; no context menu.
-# MENU: Like NONE, but right-click menu is active.
-# BUTTON: Element can be left-clicked.
-# MOVE_AXIS: Translate along local x-axis.
-# MOVE_PLANE: Translate in local y-z plane.
-# ROTATE_AXIS: Rotate around local x-axis.
-# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
-uint8 NONE = 0
-uint8 MENU = 1
-uint8 BUTTON = 2
-uint8 MOVE_AXIS = 3
-uint8 MOVE_PLANE = 4
-uint8 ROTATE_AXIS = 5
-uint8 MOVE_ROTATE = 6
-# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
-# MOVE_3D: Translate freely in 3D space.
-# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
-# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
-uint8 MOVE_3D = 7
-uint8 ROTATE_3D = 8
-uint8 MOVE_ROTATE_3D = 9
-
-uint8 interaction_mode
-
-
-# If true, the contained markers will also be visible
-# when the gui is not in interactive mode.
-bool always_visible
-
-
-# Markers to be displayed as custom visual representation.
-# Leave this empty to use the default control handles.
-#
-# Note:
-# - The markers can be defined in an arbitrary coordinate frame,
-#   but will be transformed into the local frame of the interactive marker.
-# - If the header of a marker is empty, its pose will be interpreted as
-#   relative to the pose of the parent interactive marker.
-Marker[] markers
-
-
-# In VIEW_FACING mode, set this to true if you don't want the markers
-# to be aligned with the camera view point. The markers will show up
-# as in INHERIT mode.
-bool independent_marker_orientation
-
-
-# Short description (< 40 characters) of what this control does,
-# e.g. "Move the robot".
-# Default: A generic description based on the interaction mode
-string description
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MenuEntry
-# MenuEntry message.
-#
-# Each InteractiveMarker message has an array of MenuEntry messages.
-# A collection of MenuEntries together describe a
-# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
-# array.  The tree structure is represented by giving each menu entry
-# an ID number and a "parent_id" field.  Top-level entries are the
-# ones with parent_id = 0.  Menu entries are ordered within their
-# level the same way they are ordered in the containing array.  Parent
-# entries must appear before their children.
-#
-# Example:
-# - id = 3
-#   parent_id = 0
-#   title = "fun"
-# - id = 2
-#   parent_id = 0
-#   title = "robot"
-# - id = 4
-#   parent_id = 2
-#   title = "pr2"
-# - id = 5
-#   parent_id = 2
-#   title = "turtle"
-#
-# Gives a menu tree like this:
-#  - fun
-#  - robot
-#    - pr2
-#    - turtle
-
-# ID is a number for each menu entry.  Must be unique within the
-# control, and should never be 0.
-uint32 id
-
-# ID of the parent of this menu entry, if it is a submenu.  If this
-# menu entry is a top-level entry, set parent_id to 0.
-uint32 parent_id
-
-# menu / entry title
-string title
-
-# Arguments to command indicated by command_type (below)
-string command
-
-# Command_type stores the type of response desired when this menu
-# entry is clicked.
-# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
-# ROSRUN: execute "rosrun" with arguments given in the command field (above).
-# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
-uint8 FEEDBACK=0
-uint8 ROSRUN=1
-uint8 ROSLAUNCH=2
-uint8 command_type
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct InteractiveMarkerControl {
-        pub r#name: ::std::string::String,
-        pub r#orientation: geometry_msgs::Quaternion,
-        pub r#orientation_mode: u8,
-        pub r#interaction_mode: u8,
-        pub r#always_visible: bool,
-        pub r#markers: ::std::vec::Vec<self::Marker>,
-        pub r#independent_marker_orientation: bool,
-        pub r#description: ::std::string::String,
-    }
-    impl ::roslibrust::RosMessageType for InteractiveMarkerControl {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerControl";
-        const MD5SUM: &'static str = "7b945e790a2d68f430a6eb79f33bf8df";
-        const DEFINITION: &'static str = r####"# Represents a control that is to be displayed together with an interactive marker
-
-# Identifying string for this control.
-# You need to assign a unique value to this to receive feedback from the GUI
-# on what actions the user performs on this control (e.g. a button click).
-string name
-
-
-# Defines the local coordinate frame (relative to the pose of the parent
-# interactive marker) in which is being rotated and translated.
-# Default: Identity
-geometry_msgs/Quaternion orientation
-
-
-# Orientation mode: controls how orientation changes.
-# INHERIT: Follow orientation of interactive marker
-# FIXED: Keep orientation fixed at initial state
-# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
-uint8 INHERIT = 0
-uint8 FIXED = 1
-uint8 VIEW_FACING = 2
-
-uint8 orientation_mode
-
-# Interaction mode for this control
-#
-# NONE: This control is only meant for visualization; no context menu.
-# MENU: Like NONE, but right-click menu is active.
-# BUTTON: Element can be left-clicked.
-# MOVE_AXIS: Translate along local x-axis.
-# MOVE_PLANE: Translate in local y-z plane.
-# ROTATE_AXIS: Rotate around local x-axis.
-# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
-uint8 NONE = 0
-uint8 MENU = 1
-uint8 BUTTON = 2
-uint8 MOVE_AXIS = 3
-uint8 MOVE_PLANE = 4
-uint8 ROTATE_AXIS = 5
-uint8 MOVE_ROTATE = 6
-# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
-# MOVE_3D: Translate freely in 3D space.
-# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
-# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
-uint8 MOVE_3D = 7
-uint8 ROTATE_3D = 8
-uint8 MOVE_ROTATE_3D = 9
-
-uint8 interaction_mode
-
-
-# If true, the contained markers will also be visible
-# when the gui is not in interactive mode.
-bool always_visible
-
-
-# Markers to be displayed as custom visual representation.
-# Leave this empty to use the default control handles.
-#
-# Note:
-# - The markers can be defined in an arbitrary coordinate frame,
-#   but will be transformed into the local frame of the interactive marker.
-# - If the header of a marker is empty, its pose will be interpreted as
-#   relative to the pose of the parent interactive marker.
-Marker[] markers
-
-
-# In VIEW_FACING mode, set this to true if you don't want the markers
-# to be aligned with the camera view point. The markers will show up
-# as in INHERIT mode.
-bool independent_marker_orientation
-
-
-# Short description (< 40 characters) of what this control does,
-# e.g. "Move the robot".
-# Default: A generic description based on the interaction mode
-string description
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v"####;
-    }
-    #[allow(unused)]
-    impl InteractiveMarkerControl {
-        pub const r#INHERIT: u8 = 0u8;
-        pub const r#FIXED: u8 = 1u8;
-        pub const r#VIEW_FACING: u8 = 2u8;
-        pub const r#NONE: u8 = 0u8;
-        pub const r#MENU: u8 = 1u8;
-        pub const r#BUTTON: u8 = 2u8;
-        pub const r#MOVE_AXIS: u8 = 3u8;
-        pub const r#MOVE_PLANE: u8 = 4u8;
-        pub const r#ROTATE_AXIS: u8 = 5u8;
-        pub const r#MOVE_ROTATE: u8 = 6u8;
-        pub const r#MOVE_3D: u8 = 7u8;
-        pub const r#ROTATE_3D: u8 = 8u8;
-        pub const r#MOVE_ROTATE_3D: u8 = 9u8;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct InteractiveMarkerFeedback {
-        pub r#header: std_msgs::Header,
-        pub r#client_id: ::std::string::String,
-        pub r#marker_name: ::std::string::String,
-        pub r#control_name: ::std::string::String,
-        pub r#event_type: u8,
-        pub r#pose: geometry_msgs::Pose,
-        pub r#menu_entry_id: u32,
-        pub r#mouse_point: geometry_msgs::Point,
-        pub r#mouse_point_valid: bool,
-    }
-    impl ::roslibrust::RosMessageType for InteractiveMarkerFeedback {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerFeedback";
-        const MD5SUM: &'static str = "880e5141421ed8d30906fad686bc17bd";
-        const DEFINITION: &'static str = r####"# Time/frame info.
-std_msgs/Header header
-
-# Identifying string. Must be unique in the topic namespace.
-string client_id
-
-# Feedback message sent back from the GUI, e.g.
-# when the status of an interactive marker was modified by the user.
-
-# Specifies which interactive marker and control this message refers to
-string marker_name
-string control_name
-
-# Type of the event
-# KEEP_ALIVE: sent while dragging to keep up control of the marker
-# MENU_SELECT: a menu entry has been selected
-# BUTTON_CLICK: a button control has been clicked
-# POSE_UPDATE: the pose has been changed using one of the controls
-uint8 KEEP_ALIVE = 0
-uint8 POSE_UPDATE = 1
-uint8 MENU_SELECT = 2
-uint8 BUTTON_CLICK = 3
-
-uint8 MOUSE_DOWN = 4
-uint8 MOUSE_UP = 5
-
-uint8 event_type
-
-# Current pose of the marker
-# Note: Has to be valid for all feedback types.
-geometry_msgs/Pose pose
-
-# Contains the ID of the selected menu entry
-# Only valid for MENU_SELECT events.
-uint32 menu_entry_id
-
-# If event_type is BUTTON_CLICK, MOUSE_DOWN, or MOUSE_UP, mouse_point
-# may contain the 3 dimensional position of the event on the
-# control.  If it does, mouse_point_valid will be true.  mouse_point
-# will be relative to the frame listed in the header.
-geometry_msgs/Point mouse_point
-bool mouse_point_valid
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(unused)]
-    impl InteractiveMarkerFeedback {
-        pub const r#KEEP_ALIVE: u8 = 0u8;
-        pub const r#POSE_UPDATE: u8 = 1u8;
-        pub const r#MENU_SELECT: u8 = 2u8;
-        pub const r#BUTTON_CLICK: u8 = 3u8;
-        pub const r#MOUSE_DOWN: u8 = 4u8;
-        pub const r#MOUSE_UP: u8 = 5u8;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct InteractiveMarkerInit {
-        pub r#server_id: ::std::string::String,
-        pub r#seq_num: u64,
-        pub r#markers: ::std::vec::Vec<self::InteractiveMarker>,
-    }
-    impl ::roslibrust::RosMessageType for InteractiveMarkerInit {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerInit";
-        const MD5SUM: &'static str = "5d275694a5cb7ea4627f917a9eb1b4cd";
-        const DEFINITION: &'static str = r####"# Identifying string. Must be unique in the topic namespace
-# that this server works on.
-string server_id
-
-# Sequence number.
-# The client will use this to detect if it has missed a subsequent
-# update.  Every update message will have the same sequence number as
-# an init message.  Clients will likely want to unsubscribe from the
-# init topic after a successful initialization to avoid receiving
-# duplicate data.
-uint64 seq_num
-
-# All markers.
-InteractiveMarker[] markers
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/InteractiveMarker
-# Time/frame info.
-# If header.time is set to 0, the marker will be retransformed into
-# its frame on each timestep. You will receive the pose feedback
-# in the same frame.
-# Otherwise, you might receive feedback in a different frame.
-# For rviz, this will be the current 'fixed frame' set by the user.
-std_msgs/Header header
-
-# Initial pose. Also, defines the pivot point for rotations.
-geometry_msgs/Pose pose
-
-# Identifying string. Must be globally unique in
-# the topic that this message is sent through.
-string name
-
-# Short description (< 40 characters).
-string description
-
-# Scale to be used for default controls (default=1).
-float32 scale
-
-# All menu and submenu entries associated with this marker.
-MenuEntry[] menu_entries
-
-# List of controls displayed for this marker.
-InteractiveMarkerControl[] controls
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/InteractiveMarkerControl
-# Represents a control that is to be displayed together with an interactive marker
-
-# Identifying string for this control.
-# You need to assign a unique value to this to receive feedback from the GUI
-# on what actions the user performs on this control (e.g. a button click).
-string name
-
-
-# Defines the local coordinate frame (relative to the pose of the parent
-# interactive marker) in which is being rotated and translated.
-# Default: Identity
-geometry_msgs/Quaternion orientation
-
-
-# Orientation mode: controls how orientation changes.
-# INHERIT: Follow orientation of interactive marker
-# FIXED: Keep orientation fixed at initial state
-# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
-uint8 INHERIT = 0
-uint8 FIXED = 1
-uint8 VIEW_FACING = 2
-
-uint8 orientation_mode
-
-# Interaction mode for this control
-#
-# NONE: This control is only meant for visualization; no context menu.
-# MENU: Like NONE, but right-click menu is active.
-# BUTTON: Element can be left-clicked.
-# MOVE_AXIS: Translate along local x-axis.
-# MOVE_PLANE: Translate in local y-z plane.
-# ROTATE_AXIS: Rotate around local x-axis.
-# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
-uint8 NONE = 0
-uint8 MENU = 1
-uint8 BUTTON = 2
-uint8 MOVE_AXIS = 3
-uint8 MOVE_PLANE = 4
-uint8 ROTATE_AXIS = 5
-uint8 MOVE_ROTATE = 6
-# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
-# MOVE_3D: Translate freely in 3D space.
-# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
-# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
-uint8 MOVE_3D = 7
-uint8 ROTATE_3D = 8
-uint8 MOVE_ROTATE_3D = 9
-
-uint8 interaction_mode
-
-
-# If true, the contained markers will also be visible
-# when the gui is not in interactive mode.
-bool always_visible
-
-
-# Markers to be displayed as custom visual representation.
-# Leave this empty to use the default control handles.
-#
-# Note:
-# - The markers can be defined in an arbitrary coordinate frame,
-#   but will be transformed into the local frame of the interactive marker.
-# - If the header of a marker is empty, its pose will be interpreted as
-#   relative to the pose of the parent interactive marker.
-Marker[] markers
-
-
-# In VIEW_FACING mode, set this to true if you don't want the markers
-# to be aligned with the camera view point. The markers will show up
-# as in INHERIT mode.
-bool independent_marker_orientation
-
-
-# Short description (< 40 characters) of what this control does,
-# e.g. "Move the robot".
-# Default: A generic description based on the interaction mode
-string description
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MenuEntry
-# MenuEntry message.
-#
-# Each InteractiveMarker message has an array of MenuEntry messages.
-# A collection of MenuEntries together describe a
-# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
-# array.  The tree structure is represented by giving each menu entry
-# an ID number and a "parent_id" field.  Top-level entries are the
-# ones with parent_id = 0.  Menu entries are ordered within their
-# level the same way they are ordered in the containing array.  Parent
-# entries must appear before their children.
-#
-# Example:
-# - id = 3
-#   parent_id = 0
-#   title = "fun"
-# - id = 2
-#   parent_id = 0
-#   title = "robot"
-# - id = 4
-#   parent_id = 2
-#   title = "pr2"
-# - id = 5
-#   parent_id = 2
-#   title = "turtle"
-#
-# Gives a menu tree like this:
-#  - fun
-#  - robot
-#    - pr2
-#    - turtle
-
-# ID is a number for each menu entry.  Must be unique within the
-# control, and should never be 0.
-uint32 id
-
-# ID of the parent of this menu entry, if it is a submenu.  If this
-# menu entry is a top-level entry, set parent_id to 0.
-uint32 parent_id
-
-# menu / entry title
-string title
-
-# Arguments to command indicated by command_type (below)
-string command
-
-# Command_type stores the type of response desired when this menu
-# entry is clicked.
-# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
-# ROSRUN: execute "rosrun" with arguments given in the command field (above).
-# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
-uint8 FEEDBACK=0
-uint8 ROSRUN=1
-uint8 ROSLAUNCH=2
-uint8 command_type
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/InteractiveMarkerControl
-# Represents a control that is to be displayed together with an interactive marker
-
-# Identifying string for this control.
-# You need to assign a unique value to this to receive feedback from the GUI
-# on what actions the user performs on this control (e.g. a button click).
-string name
-
-
-# Defines the local coordinate frame (relative to the pose of the parent
-# interactive marker) in which is being rotated and translated.
-# Default: Identity
-geometry_msgs/Quaternion orientation
-
-
-# Orientation mode: controls how orientation changes.
-# INHERIT: Follow orientation of interactive marker
-# FIXED: Keep orientation fixed at initial state
-# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
-uint8 INHERIT = 0
-uint8 FIXED = 1
-uint8 VIEW_FACING = 2
-
-uint8 orientation_mode
-
-# Interaction mode for this control
-#
-# NONE: This control is only meant for visualization; no context menu.
-# MENU: Like NONE, but right-click menu is active.
-# BUTTON: Element can be left-clicked.
-# MOVE_AXIS: Translate along local x-axis.
-# MOVE_PLANE: Translate in local y-z plane.
-# ROTATE_AXIS: Rotate around local x-axis.
-# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
-uint8 NONE = 0
-uint8 MENU = 1
-uint8 BUTTON = 2
-uint8 MOVE_AXIS = 3
-uint8 MOVE_PLANE = 4
-uint8 ROTATE_AXIS = 5
-uint8 MOVE_ROTATE = 6
-# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
-# MOVE_3D: Translate freely in 3D space.
-# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
-# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
-uint8 MOVE_3D = 7
-uint8 ROTATE_3D = 8
-uint8 MOVE_ROTATE_3D = 9
-
-uint8 interaction_mode
-
-
-# If true, the contained markers will also be visible
-# when the gui is not in interactive mode.
-bool always_visible
-
-
-# Markers to be displayed as custom visual representation.
-# Leave this empty to use the default control handles.
-#
-# Note:
-# - The markers can be defined in an arbitrary coordinate frame,
-#   but will be transformed into the local frame of the interactive marker.
-# - If the header of a marker is empty, its pose will be interpreted as
-#   relative to the pose of the parent interactive marker.
-Marker[] markers
-
-
-# In VIEW_FACING mode, set this to true if you don't want the markers
-# to be aligned with the camera view point. The markers will show up
-# as in INHERIT mode.
-bool independent_marker_orientation
-
-
-# Short description (< 40 characters) of what this control does,
-# e.g. "Move the robot".
-# Default: A generic description based on the interaction mode
-string description
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MenuEntry
-# MenuEntry message.
-#
-# Each InteractiveMarker message has an array of MenuEntry messages.
-# A collection of MenuEntries together describe a
-# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
-# array.  The tree structure is represented by giving each menu entry
-# an ID number and a "parent_id" field.  Top-level entries are the
-# ones with parent_id = 0.  Menu entries are ordered within their
-# level the same way they are ordered in the containing array.  Parent
-# entries must appear before their children.
-#
-# Example:
-# - id = 3
-#   parent_id = 0
-#   title = "fun"
-# - id = 2
-#   parent_id = 0
-#   title = "robot"
-# - id = 4
-#   parent_id = 2
-#   title = "pr2"
-# - id = 5
-#   parent_id = 2
-#   title = "turtle"
-#
-# Gives a menu tree like this:
-#  - fun
-#  - robot
-#    - pr2
-#    - turtle
-
-# ID is a number for each menu entry.  Must be unique within the
-# control, and should never be 0.
-uint32 id
-
-# ID of the parent of this menu entry, if it is a submenu.  If this
-# menu entry is a top-level entry, set parent_id to 0.
-uint32 parent_id
-
-# menu / entry title
-string title
-
-# Arguments to command indicated by command_type (below)
-string command
-
-# Command_type stores the type of response desired when this menu
-# entry is clicked.
-# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
-# ROSRUN: execute "rosrun" with arguments given in the command field (above).
-# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
-uint8 FEEDBACK=0
-uint8 ROSRUN=1
-uint8 ROSLAUNCH=2
-uint8 command_type
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct InteractiveMarkerPose {
-        pub r#header: std_msgs::Header,
-        pub r#pose: geometry_msgs::Pose,
-        pub r#name: ::std::string::String,
-    }
-    impl ::roslibrust::RosMessageType for InteractiveMarkerPose {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerPose";
-        const MD5SUM: &'static str = "b88540594a0f8e3fe46c720be41faa03";
-        const DEFINITION: &'static str = r####"# Time/frame info.
-std_msgs/Header header
-
-# Initial pose. Also, defines the pivot point for rotations.
-geometry_msgs/Pose pose
-
-# Identifying string. Must be globally unique in
-# the topic that this message is sent through.
-string name
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct InteractiveMarkerUpdate {
-        pub r#server_id: ::std::string::String,
-        pub r#seq_num: u64,
-        pub r#type: u8,
-        pub r#markers: ::std::vec::Vec<self::InteractiveMarker>,
-        pub r#poses: ::std::vec::Vec<self::InteractiveMarkerPose>,
-        pub r#erases: ::std::vec::Vec<::std::string::String>,
-    }
-    impl ::roslibrust::RosMessageType for InteractiveMarkerUpdate {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerUpdate";
-        const MD5SUM: &'static str = "8f52c675c849441ae87da82eaa4d6eb5";
-        const DEFINITION: &'static str = r####"# Identifying string. Must be unique in the topic namespace
-# that this server works on.
-string server_id
-
-# Sequence number.
-# The client will use this to detect if it has missed an update.
-uint64 seq_num
-
-# Type holds the purpose of this message.  It must be one of UPDATE or KEEP_ALIVE.
-# UPDATE: Incremental update to previous state.
-#         The sequence number must be 1 higher than for
-#         the previous update.
-# KEEP_ALIVE: Indicates the that the server is still living.
-#             The sequence number does not increase.
-#             No payload data should be filled out (markers, poses, or erases).
-uint8 KEEP_ALIVE = 0
-uint8 UPDATE = 1
-
-uint8 type
-
-# Note: No guarantees on the order of processing.
-#       Contents must be kept consistent by sender.
-
-# Markers to be added or updated
-InteractiveMarker[] markers
-
-# Poses of markers that should be moved
-InteractiveMarkerPose[] poses
-
-# Names of markers to be erased
-string[] erases
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/InteractiveMarker
-# Time/frame info.
-# If header.time is set to 0, the marker will be retransformed into
-# its frame on each timestep. You will receive the pose feedback
-# in the same frame.
-# Otherwise, you might receive feedback in a different frame.
-# For rviz, this will be the current 'fixed frame' set by the user.
-std_msgs/Header header
-
-# Initial pose. Also, defines the pivot point for rotations.
-geometry_msgs/Pose pose
-
-# Identifying string. Must be globally unique in
-# the topic that this message is sent through.
-string name
-
-# Short description (< 40 characters).
-string description
-
-# Scale to be used for default controls (default=1).
-float32 scale
-
-# All menu and submenu entries associated with this marker.
-MenuEntry[] menu_entries
-
-# List of controls displayed for this marker.
-InteractiveMarkerControl[] controls
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/InteractiveMarkerControl
-# Represents a control that is to be displayed together with an interactive marker
-
-# Identifying string for this control.
-# You need to assign a unique value to this to receive feedback from the GUI
-# on what actions the user performs on this control (e.g. a button click).
-string name
-
-
-# Defines the local coordinate frame (relative to the pose of the parent
-# interactive marker) in which is being rotated and translated.
-# Default: Identity
-geometry_msgs/Quaternion orientation
-
-
-# Orientation mode: controls how orientation changes.
-# INHERIT: Follow orientation of interactive marker
-# FIXED: Keep orientation fixed at initial state
-# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
-uint8 INHERIT = 0
-uint8 FIXED = 1
-uint8 VIEW_FACING = 2
-
-uint8 orientation_mode
-
-# Interaction mode for this control
-#
-# NONE: This control is only meant for visualization; no context menu.
-# MENU: Like NONE, but right-click menu is active.
-# BUTTON: Element can be left-clicked.
-# MOVE_AXIS: Translate along local x-axis.
-# MOVE_PLANE: Translate in local y-z plane.
-# ROTATE_AXIS: Rotate around local x-axis.
-# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
-uint8 NONE = 0
-uint8 MENU = 1
-uint8 BUTTON = 2
-uint8 MOVE_AXIS = 3
-uint8 MOVE_PLANE = 4
-uint8 ROTATE_AXIS = 5
-uint8 MOVE_ROTATE = 6
-# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
-# MOVE_3D: Translate freely in 3D space.
-# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
-# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
-uint8 MOVE_3D = 7
-uint8 ROTATE_3D = 8
-uint8 MOVE_ROTATE_3D = 9
-
-uint8 interaction_mode
-
-
-# If true, the contained markers will also be visible
-# when the gui is not in interactive mode.
-bool always_visible
-
-
-# Markers to be displayed as custom visual representation.
-# Leave this empty to use the default control handles.
-#
-# Note:
-# - The markers can be defined in an arbitrary coordinate frame,
-#   but will be transformed into the local frame of the interactive marker.
-# - If the header of a marker is empty, its pose will be interpreted as
-#   relative to the pose of the parent interactive marker.
-Marker[] markers
-
-
-# In VIEW_FACING mode, set this to true if you don't want the markers
-# to be aligned with the camera view point. The markers will show up
-# as in INHERIT mode.
-bool independent_marker_orientation
-
-
-# Short description (< 40 characters) of what this control does,
-# e.g. "Move the robot".
-# Default: A generic description based on the interaction mode
-string description
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+        const MD5SUM: &'static str = "cb0de8feef04280238c7b77d74b2beca";
+        const DEFINITION: &'static str = r####"# Separate header for compatibility with current TimeSynchronizer.
+# Likely to be removed in a later release, use image.header instead.
+std_msgs/Header header
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Floating point disparity image. The disparities are pre-adjusted for any
+# x-offset between the principal points of the two cameras (in the case
+# that they are verged). That is: d = x_l - x_r - (cx_l - cx_r)
+sensor_msgs/Image image
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# Stereo geometry. For disparity d, the depth from the camera is Z = fT/d.
+float32 f # Focal length, pixels
+float32 t # Baseline, world units
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Subwindow of (potentially) valid disparity values.
+sensor_msgs/RegionOfInterest valid_window
+
+# The range of disparities searched.
+# In the disparity image, any disparity less than min_disparity is invalid.
+# The disparity search range defines the horopter, or 3D volume that the
+# stereo algorithm can "see". Points with Z outside of:
+#     Z_min = fT / max_disparity
+#     Z_max = fT / min_disparity
+# could not be found.
+float32 min_disparity
+float32 max_disparity
+
+# Smallest allowed disparity increment. The smallest achievable depth range
+# resolution is delta_Z = (Z^2/fT)*delta_d.
+float32 delta_d
 ================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-float64 x
-float64 y
-float64 z
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
+MSG: sensor_msgs/Image
+# This message contains an uncompressed image
+# (0, 0) is at top-left corner of image
 
 std_msgs/Header header # Header timestamp should be acquisition time of image
                              # Header frame_id should be optical frame of camera
@@ -12031,29 +8046,284 @@ std_msgs/Header header # Header timestamp should be acquisition time of image
                              # +x should point to the right in the image
                              # +y should point down in the image
                              # +z should point into to plane of the image
+                             # If the frame_id here and the frame_id of the CameraInfo
+                             # message associated with the image conflict
+                             # the behavior is undefined
 
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+uint32 height                # image height, that is, number of rows
+uint32 width                 # image width, that is, number of columns
 
-uint8[] data                 # Compressed image buffer
+# The legal values for encoding are in file include/sensor_msgs/image_encodings.hpp
+# If you want to standardize a new string format, join
+# ros-users@lists.ros.org and send an email proposing a new encoding.
+
+string encoding       # Encoding of pixels -- channel meaning, ordering, size
+                      # taken from the list of strings in include/sensor_msgs/image_encodings.hpp
+
+uint8 is_bigendian    # is this data bigendian?
+uint32 step           # Full row length in bytes
+uint8[] data          # actual matrix data, size is (step * rows)
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+MSG: sensor_msgs/RegionOfInterest
+# This message is used to specify a region of interest within an image.
+#
+# When used to specify the ROI setting of the camera when the image was
+# taken, the height and width fields should either match the height and
+# width fields for the associated image; or height = width = 0
+# indicates that the full resolution image was captured.
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+uint32 x_offset  # Leftmost pixel of the ROI
+                 # (0 if the ROI includes the left edge of the image)
+uint32 y_offset  # Topmost pixel of the ROI
+                 # (0 if the ROI includes the top edge of the image)
+uint32 height    # Height of ROI
+uint32 width     # Width of ROI
 
-# Transform frame with which this data is associated.
-string frame_id
+# True if a distinct rectified ROI should be calculated from the "raw"
+# ROI in this message. Typically this should be False if the full image
+# is captured (ROI not used), and True if a subwindow is captured (ROI
+# used).
+bool do_rectify"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_1ec1ff6b5bace919e4544a37f2d96ead9f81783701b7b7a4d97a09325ecf2711";
+    }
+    #[allow(unused)]
+    impl DisparityImage {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+}
+#[allow(unused_imports)]
+pub mod test_msgs {
+    use super::actionlib_msgs;
+    use super::diagnostic_msgs;
+    use super::geometry_msgs;
+    use super::nav_msgs;
+    use super::sensor_msgs;
+    use super::shape_msgs;
+    use super::std_msgs;
+    use super::std_srvs;
+    use super::stereo_msgs;
+    use super::trajectory_msgs;
+    use super::visualization_msgs;
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct Bounded {
+        #[doc = " This message is specifically for testing generation of bounded strings and sequences"]
+        #[serde(with = "::roslibrust::codegen::BoundedString::<5>")]
+        pub r#name: ::std::string::String,
+        #[serde(with = "::roslibrust::codegen::BoundedSequence::<3>")]
+        pub r#samples: ::std::vec::Vec<i32>,
+        #[serde(with = "::roslibrust::codegen::BoundedSequence::<4>")]
+        pub r#data: ::std::vec::Vec<u8>,
+        #[serde(with = "::roslibrust::codegen::BoundedStrings::<2, 3>")]
+        pub r#tags: ::std::vec::Vec<::std::string::String>,
+        #[serde(with = "::roslibrust::codegen::BoundedStrings::<{ usize::MAX }, 3>")]
+        pub r#codes: ::std::vec::Vec<::std::string::String>,
+        #[serde(with = "::roslibrust::codegen::BoundedStrings::<{ usize::MAX }, 3>")]
+        pub r#pair: [::std::string::String; 2],
+    }
+    impl ::roslibrust::RosMessageType for Bounded {
+        const ROS_TYPE_NAME: &'static str = "test_msgs/Bounded";
+        const MD5SUM: &'static str = "3abd409f4af0dbd6f5f84a366ef6ff48";
+        const DEFINITION: &'static str = r####"# This message is specifically for testing generation of bounded strings and sequences
+string<=5 name
+int32[<=3] samples
+uint8[<=4] data
+string<=3[<=2] tags
+string<=3[] codes
+string<=3[2] pair"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_c2d1481ff783db047a5e45f50ac9cbd0b775d6214aeeaf64dde3f2c6c6246992";
+    }
+    #[allow(unused)]
+    impl Bounded {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct Defaults {
+        #[doc = " This message is specifically for testing generating of default values"]
+        #[doc = " Examples based on https://docs.ros.org/en/rolling/Concepts/About-ROS-Interfaces.html"]
+        #[default(42u8)]
+        pub r#x: u8,
+        # [default (- 2000i16)]
+        pub r#y: i16,
+        #[default("John Doe")]
+        pub r#full_name: ::std::string::String,
+        #[default(_code = "vec![-200, -100, 0, 100, 200]")]
+        pub r#samples: ::std::vec::Vec<i32>,
+        #[doc = " More complicated examples to stress the system, floats with mixed precision"]
+        #[default(_code = "vec![-200.0, -1.0, 0.0]")]
+        pub r#f_samples: ::std::vec::Vec<f32>,
+        #[default(_code = "[\"hello\", \"world\"].iter().map(|x| x.to_string()).collect()")]
+        pub r#s_vec: ::std::vec::Vec<::std::string::String>,
+        #[default(_code = "[\"hello\", \"world\"].iter().map(|x| x.to_string()).collect()")]
+        pub r#s_vec_2: ::std::vec::Vec<::std::string::String>,
+        #[default("Jane Doe")]
+        pub r#single_quote: ::std::string::String,
+        #[doc = " Fixed size arrays, e.g. covariances which use -1 to signal unknown"]
+        #[default(_code = "[-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]")]
+        pub r#covariance: [f64; 9],
+        #[default(_code = "[\"left\", \"right\"].map(|x| x.to_string())")]
+        pub r#s_fixed: [::std::string::String; 2],
+    }
+    impl ::roslibrust::RosMessageType for Defaults {
+        const ROS_TYPE_NAME: &'static str = "test_msgs/Defaults";
+        const MD5SUM: &'static str = "35a6cd0c8c5154941d81d6c8127a7394";
+        const DEFINITION: &'static str = r####"# This message is specifically for testing generating of default values
+# Examples based on https://docs.ros.org/en/rolling/Concepts/About-ROS-Interfaces.html
+uint8 x 42
+int16 y -2000
+string full_name "John Doe"
+int32[] samples [-200, -100, 0, 100, 200]
+
+# More complicated examples to stress the system, floats with mixed precision
+float32[] f_samples [-200, -1.0, 0]
+string[] s_vec ["hello", "world"]
+string[] s_vec_2 ['hello', 'world']
+string single_quote 'Jane Doe'
+
+# Fixed size arrays, e.g. covariances which use -1 to signal unknown
+float64[9] covariance [-1, 0, 0, 0, 0, 0, 0, 0, 0]
+string[2] s_fixed ["left", 'right']"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_6ceac7bc3a1aeb5b0220d7fb85f1803a3846040031ef419bf1dc9f56c0f419f8";
+    }
+    #[allow(unused)]
+    impl Defaults {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct FixedArrays {
+        #[doc = " This message is specifically for testing generation of fixed size arrays"]
+        #[doc = " Fixed size arrays are generated as rust arrays, which are sent without a length prefix"]
+        pub r#uuid: [u8; 16],
+        #[default(_code = "::std::array::from_fn(|_| Default::default())")]
+        #[serde(with = "::roslibrust::codegen::BigArray")]
+        pub r#covariance: [f64; 36],
+        #[doc = " Large arrays of non Copy types need special handling for Default"]
+        #[default(_code = "::std::array::from_fn(|_| Default::default())")]
+        #[serde(with = "::roslibrust::codegen::BigArray")]
+        pub r#names: [::std::string::String; 33],
+        pub r#defaults: [self::Defaults; 2],
+    }
+    impl ::roslibrust::RosMessageType for FixedArrays {
+        const ROS_TYPE_NAME: &'static str = "test_msgs/FixedArrays";
+        const MD5SUM: &'static str = "e745e633d50b82b132e2b456e99d643e";
+        const DEFINITION: &'static str = r####"# This message is specifically for testing generation of fixed size arrays
+# Fixed size arrays are generated as rust arrays, which are sent without a length prefix
+uint8[16] uuid
+float64[36] covariance
+# Large arrays of non Copy types need special handling for Default
+string[33] names
+Defaults[2] defaults
 ================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
+MSG: test_msgs/Defaults
+# This message is specifically for testing generating of default values
+# Examples based on https://docs.ros.org/en/rolling/Concepts/About-ROS-Interfaces.html
+uint8 x 42
+int16 y -2000
+string full_name "John Doe"
+int32[] samples [-200, -100, 0, 100, 200]
+
+# More complicated examples to stress the system, floats with mixed precision
+float32[] f_samples [-200, -1.0, 0]
+string[] s_vec ["hello", "world"]
+string[] s_vec_2 ['hello', 'world']
+string single_quote 'Jane Doe'
+
+# Fixed size arrays, e.g. covariances which use -1 to signal unknown
+float64[9] covariance [-1, 0, 0, 0, 0, 0, 0, 0, 0]
+string[2] s_fixed ["left", 'right']"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_5afc75d7f4840047688b6e041221a875466851185b703f1d5c2dcf9df5edc437";
+    }
+    #[allow(unused)]
+    impl FixedArrays {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+}
+#[allow(unused_imports)]
+pub mod trajectory_msgs {
+    use super::actionlib_msgs;
+    use super::diagnostic_msgs;
+    use super::geometry_msgs;
+    use super::nav_msgs;
+    use super::sensor_msgs;
+    use super::shape_msgs;
+    use super::std_msgs;
+    use super::std_srvs;
+    use super::stereo_msgs;
+    use super::test_msgs;
+    use super::visualization_msgs;
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct JointTrajectory {
+        #[doc = " The header is used to specify the coordinate frame and the reference time for"]
+        #[doc = " the trajectory durations"]
+        pub r#header: std_msgs::Header,
+        #[doc = " The names of the active joints in each trajectory point. These names are"]
+        #[doc = " ordered and must correspond to the values in each trajectory point."]
+        pub r#joint_names: ::std::vec::Vec<::std::string::String>,
+        #[doc = " Array of trajectory points, which describe the positions, velocities,"]
+        #[doc = " accelerations and/or efforts of the joints at each time point."]
+        pub r#points: ::std::vec::Vec<self::JointTrajectoryPoint>,
+    }
+    impl ::roslibrust::RosMessageType for JointTrajectory {
+        const ROS_TYPE_NAME: &'static str = "trajectory_msgs/JointTrajectory";
+        const MD5SUM: &'static str = "d63e3b4556d9dbd9f48b5ab4a03f1fee";
+        const DEFINITION: &'static str = r####"# The header is used to specify the coordinate frame and the reference time for
+# the trajectory durations
+std_msgs/Header header
+
+# The names of the active joints in each trajectory point. These names are
+# ordered and must correspond to the values in each trajectory point.
+string[] joint_names
+
+# Array of trajectory points, which describe the positions, velocities,
+# accelerations and/or efforts of the joints at each time point.
+JointTrajectoryPoint[] points
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -12066,187 +8336,180 @@ builtin_interfaces/Time stamp
 # Transform frame with which this data is associated.
 string frame_id
 ================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MenuEntry
-# MenuEntry message.
-#
-# Each InteractiveMarker message has an array of MenuEntry messages.
-# A collection of MenuEntries together describe a
-# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
-# array.  The tree structure is represented by giving each menu entry
-# an ID number and a "parent_id" field.  Top-level entries are the
-# ones with parent_id = 0.  Menu entries are ordered within their
-# level the same way they are ordered in the containing array.  Parent
-# entries must appear before their children.
-#
-# Example:
-# - id = 3
-#   parent_id = 0
-#   title = "fun"
-# - id = 2
-#   parent_id = 0
-#   title = "robot"
-# - id = 4
-#   parent_id = 2
-#   title = "pr2"
-# - id = 5
-#   parent_id = 2
-#   title = "turtle"
-#
-# Gives a menu tree like this:
-#  - fun
-#  - robot
-#    - pr2
-#    - turtle
-
-# ID is a number for each menu entry.  Must be unique within the
-# control, and should never be 0.
-uint32 id
-
-# ID of the parent of this menu entry, if it is a submenu.  If this
-# menu entry is a top-level entry, set parent_id to 0.
-uint32 parent_id
-
-# menu / entry title
-string title
-
-# Arguments to command indicated by command_type (below)
-string command
-
-# Command_type stores the type of response desired when this menu
-# entry is clicked.
-# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
-# ROSRUN: execute "rosrun" with arguments given in the command field (above).
-# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
-uint8 FEEDBACK=0
-uint8 ROSRUN=1
-uint8 ROSLAUNCH=2
-uint8 command_type
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/InteractiveMarkerControl
-# Represents a control that is to be displayed together with an interactive marker
-
-# Identifying string for this control.
-# You need to assign a unique value to this to receive feedback from the GUI
-# on what actions the user performs on this control (e.g. a button click).
-string name
-
+MSG: trajectory_msgs/JointTrajectoryPoint
+# Each trajectory point specifies either positions[, velocities[, accelerations]]
+# or positions[, effort] for the trajectory to be executed.
+# All specified values are in the same order as the joint names in JointTrajectory.msg.
 
-# Defines the local coordinate frame (relative to the pose of the parent
-# interactive marker) in which is being rotated and translated.
-# Default: Identity
-geometry_msgs/Quaternion orientation
+# Single DOF joint positions for each joint relative to their "0" position.
+# The units depend on the specific joint type: radians for revolute or
+# continuous joints, and meters for prismatic joints.
+float64[] positions
 
+# The rate of change in position of each joint. Units are joint type dependent.
+# Radians/second for revolute or continuous joints, and meters/second for
+# prismatic joints.
+float64[] velocities
 
-# Orientation mode: controls how orientation changes.
-# INHERIT: Follow orientation of interactive marker
-# FIXED: Keep orientation fixed at initial state
-# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
-uint8 INHERIT = 0
-uint8 FIXED = 1
-uint8 VIEW_FACING = 2
+# Rate of change in velocity of each joint. Units are joint type dependent.
+# Radians/second^2 for revolute or continuous joints, and meters/second^2 for
+# prismatic joints.
+float64[] accelerations
 
-uint8 orientation_mode
+# The torque or the force to be applied at each joint. For revolute/continuous
+# joints effort denotes a torque in newton-meters. For prismatic joints, effort
+# denotes a force in newtons.
+float64[] effort
 
-# Interaction mode for this control
-#
-# NONE: This control is only meant for visualization; no context menu.
-# MENU: Like NONE, but right-click menu is active.
-# BUTTON: Element can be left-clicked.
-# MOVE_AXIS: Translate along local x-axis.
-# MOVE_PLANE: Translate in local y-z plane.
-# ROTATE_AXIS: Rotate around local x-axis.
-# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
-uint8 NONE = 0
-uint8 MENU = 1
-uint8 BUTTON = 2
-uint8 MOVE_AXIS = 3
-uint8 MOVE_PLANE = 4
-uint8 ROTATE_AXIS = 5
-uint8 MOVE_ROTATE = 6
-# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
-# MOVE_3D: Translate freely in 3D space.
-# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
-# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
-uint8 MOVE_3D = 7
-uint8 ROTATE_3D = 8
-uint8 MOVE_ROTATE_3D = 9
+# Desired time from the trajectory start to arrive at this trajectory point.
+builtin_interfaces/Duration time_from_start"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_179b33eba59d676f6d967ac71fe35e7ca2f64b2f3928f4a018cec115e213796e";
+    }
+    #[allow(unused)]
+    impl JointTrajectory {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " Each trajectory point specifies either positions[, velocities[, accelerations]]"]
+    #[doc = " or positions[, effort] for the trajectory to be executed."]
+    #[doc = " All specified values are in the same order as the joint names in JointTrajectory.msg."]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct JointTrajectoryPoint {
+        #[doc = " Single DOF joint positions for each joint relative to their \"0\" position."]
+        #[doc = " The units depend on the specific joint type: radians for revolute or"]
+        #[doc = " continuous joints, and meters for prismatic joints."]
+        pub r#positions: ::std::vec::Vec<f64>,
+        #[doc = " The rate of change in position of each joint. Units are joint type dependent."]
+        #[doc = " Radians/second for revolute or continuous joints, and meters/second for"]
+        #[doc = " prismatic joints."]
+        pub r#velocities: ::std::vec::Vec<f64>,
+        #[doc = " Rate of change in velocity of each joint. Units are joint type dependent."]
+        #[doc = " Radians/second^2 for revolute or continuous joints, and meters/second^2 for"]
+        #[doc = " prismatic joints."]
+        pub r#accelerations: ::std::vec::Vec<f64>,
+        #[doc = " The torque or the force to be applied at each joint. For revolute/continuous"]
+        #[doc = " joints effort denotes a torque in newton-meters. For prismatic joints, effort"]
+        #[doc = " denotes a force in newtons."]
+        pub r#effort: ::std::vec::Vec<f64>,
+        #[doc = " Desired time from the trajectory start to arrive at this trajectory point."]
+        pub r#time_from_start: ::roslibrust::codegen::integral_types::Duration,
+    }
+    impl ::roslibrust::RosMessageType for JointTrajectoryPoint {
+        const ROS_TYPE_NAME: &'static str = "trajectory_msgs/JointTrajectoryPoint";
+        const MD5SUM: &'static str = "2c812f86aa886c93954e333721749ac5";
+        const DEFINITION: &'static str = r####"# Each trajectory point specifies either positions[, velocities[, accelerations]]
+# or positions[, effort] for the trajectory to be executed.
+# All specified values are in the same order as the joint names in JointTrajectory.msg.
 
-uint8 interaction_mode
+# Single DOF joint positions for each joint relative to their "0" position.
+# The units depend on the specific joint type: radians for revolute or
+# continuous joints, and meters for prismatic joints.
+float64[] positions
 
+# The rate of change in position of each joint. Units are joint type dependent.
+# Radians/second for revolute or continuous joints, and meters/second for
+# prismatic joints.
+float64[] velocities
 
-# If true, the contained markers will also be visible
-# when the gui is not in interactive mode.
-bool always_visible
+# Rate of change in velocity of each joint. Units are joint type dependent.
+# Radians/second^2 for revolute or continuous joints, and meters/second^2 for
+# prismatic joints.
+float64[] accelerations
 
+# The torque or the force to be applied at each joint. For revolute/continuous
+# joints effort denotes a torque in newton-meters. For prismatic joints, effort
+# denotes a force in newtons.
+float64[] effort
 
-# Markers to be displayed as custom visual representation.
-# Leave this empty to use the default control handles.
-#
-# Note:
-# - The markers can be defined in an arbitrary coordinate frame,
-#   but will be transformed into the local frame of the interactive marker.
-# - If the header of a marker is empty, its pose will be interpreted as
-#   relative to the pose of the parent interactive marker.
-Marker[] markers
+# Desired time from the trajectory start to arrive at this trajectory point.
+builtin_interfaces/Duration time_from_start"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_de8907036d8bd45aac6f30cc9044a3d4a329c42cbf719aff7d95a584cfa532d7";
+    }
+    #[allow(unused)]
+    impl JointTrajectoryPoint {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct MultiDOFJointTrajectory {
+        #[doc = " The header is used to specify the coordinate frame and the reference time for the trajectory durations"]
+        pub r#header: std_msgs::Header,
+        pub r#joint_names: ::std::vec::Vec<::std::string::String>,
+        pub r#points: ::std::vec::Vec<self::MultiDOFJointTrajectoryPoint>,
+    }
+    impl ::roslibrust::RosMessageType for MultiDOFJointTrajectory {
+        const ROS_TYPE_NAME: &'static str = "trajectory_msgs/MultiDOFJointTrajectory";
+        const MD5SUM: &'static str = "d00d14d97bd70c5eb648278240cfb066";
+        const DEFINITION: &'static str = r####"# The header is used to specify the coordinate frame and the reference time for the trajectory durations
+std_msgs/Header header
 
+# A representation of a multi-dof joint trajectory (each point is a transformation)
+# Each point along the trajectory will include an array of positions/velocities/accelerations
+# that has the same length as the array of joint names, and has the same order of joints as 
+# the joint names array.
 
-# In VIEW_FACING mode, set this to true if you don't want the markers
-# to be aligned with the camera view point. The markers will show up
-# as in INHERIT mode.
-bool independent_marker_orientation
+string[] joint_names
+MultiDOFJointTrajectoryPoint[] points
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
 
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
 
-# Short description (< 40 characters) of what this control does,
-# e.g. "Move the robot".
-# Default: A generic description based on the interaction mode
-string description
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: trajectory_msgs/MultiDOFJointTrajectoryPoint
+# Each multi-dof joint can specify a transform (up to 6 DOF).
+geometry_msgs/Transform[] transforms
+
+# There can be a velocity specified for the origin of the joint.
+geometry_msgs/Twist[] velocities
+
+# There can be an acceleration specified for the origin of the joint.
+geometry_msgs/Twist[] accelerations
+
+# Desired time from the trajectory start to arrive at this trajectory point.
+builtin_interfaces/Duration time_from_start
 ================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+MSG: geometry_msgs/Transform
+# This represents the transform between two coordinate frames in free space.
 
-Point position
-Quaternion orientation
+Vector3 translation
+Quaternion rotation
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
+MSG: geometry_msgs/Vector3
+# This represents a vector in free space.
+
+# This is semantically different than a point.
+# A vector is always anchored at the origin.
+# When a transform is applied to a vector, only the rotational component is applied.
+
 float64 x
 float64 y
 float64 z
@@ -12259,13 +8522,60 @@ float64 y 0
 float64 z 0
 float64 w 1
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: geometry_msgs/Twist
+# This expresses velocity in free space broken into its linear and angular parts.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+Vector3  linear
+Vector3  angular"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_3a18fd095292a65cfde8833c72985a30af981f3ec44494655c6267262b443a4a";
+    }
+    #[allow(unused)]
+    impl MultiDOFJointTrajectory {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct MultiDOFJointTrajectoryPoint {
+        #[doc = " Each multi-dof joint can specify a transform (up to 6 DOF)."]
+        pub r#transforms: ::std::vec::Vec<geometry_msgs::Transform>,
+        #[doc = " There can be a velocity specified for the origin of the joint."]
+        pub r#velocities: ::std::vec::Vec<geometry_msgs::Twist>,
+        #[doc = " There can be an acceleration specified for the origin of the joint."]
+        pub r#accelerations: ::std::vec::Vec<geometry_msgs::Twist>,
+        #[doc = " Desired time from the trajectory start to arrive at this trajectory point."]
+        pub r#time_from_start: ::roslibrust::codegen::integral_types::Duration,
+    }
+    impl ::roslibrust::RosMessageType for MultiDOFJointTrajectoryPoint {
+        const ROS_TYPE_NAME: &'static str = "trajectory_msgs/MultiDOFJointTrajectoryPoint";
+        const MD5SUM: &'static str = "6731945e53cbc0fbc6e93c28f7416a71";
+        const DEFINITION: &'static str = r####"# Each multi-dof joint can specify a transform (up to 6 DOF).
+geometry_msgs/Transform[] transforms
+
+# There can be a velocity specified for the origin of the joint.
+geometry_msgs/Twist[] velocities
+
+# There can be an acceleration specified for the origin of the joint.
+geometry_msgs/Twist[] accelerations
+
+# Desired time from the trajectory start to arrive at this trajectory point.
+builtin_interfaces/Duration time_from_start
+================================================================================
+MSG: geometry_msgs/Transform
+# This represents the transform between two coordinate frames in free space.
+
+Vector3 translation
+Quaternion rotation
 ================================================================================
 MSG: geometry_msgs/Vector3
 # This represents a vector in free space.
@@ -12278,38 +8588,222 @@ float64 x
 float64 y
 float64 z
 ================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
+MSG: geometry_msgs/Quaternion
+# This represents an orientation in free space in quaternion form.
 
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
+float64 x 0
+float64 y 0
+float64 z 0
+float64 w 1
+================================================================================
+MSG: geometry_msgs/Twist
+# This expresses velocity in free space broken into its linear and angular parts.
+
+Vector3  linear
+Vector3  angular"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_6ada1085b5ee64eaa069b074968e69f0e27c8c5e6f5bb0586dd1c834ef0e32b8";
+    }
+    #[allow(unused)]
+    impl MultiDOFJointTrajectoryPoint {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+}
+#[allow(unused_imports)]
+pub mod visualization_msgs {
+    use super::actionlib_msgs;
+    use super::diagnostic_msgs;
+    use super::geometry_msgs;
+    use super::nav_msgs;
+    use super::sensor_msgs;
+    use super::shape_msgs;
+    use super::std_msgs;
+    use super::std_srvs;
+    use super::stereo_msgs;
+    use super::test_msgs;
+    use super::trajectory_msgs;
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct ImageMarker {
+        pub r#header: std_msgs::Header,
+        #[doc = " Namespace which is used with the id to form a unique id."]
+        pub r#ns: ::std::string::String,
+        #[doc = " Unique id within the namespace."]
+        pub r#id: i32,
+        #[doc = " One of the above types, e.g. CIRCLE, LINE_STRIP, etc."]
+        pub r#type: i32,
+        #[doc = " Either ADD or REMOVE."]
+        pub r#action: i32,
+        #[doc = " Two-dimensional coordinate position, in pixel-coordinates."]
+        pub r#position: geometry_msgs::Point,
+        #[doc = " The scale of the object, e.g. the diameter for a CIRCLE."]
+        pub r#scale: f32,
+        #[doc = " The outline color of the marker."]
+        pub r#outline_color: std_msgs::ColorRGBA,
+        #[doc = " Whether or not to fill in the shape with color."]
+        pub r#filled: u8,
+        #[doc = " Fill color; in the range: [0.0-1.0]"]
+        pub r#fill_color: std_msgs::ColorRGBA,
+        #[doc = " How long the object should last before being automatically deleted."]
+        #[doc = " 0 indicates forever."]
+        pub r#lifetime: ::roslibrust::codegen::integral_types::Duration,
+        #[doc = " Coordinates in 2D in pixel coords. Used for LINE_STRIP, LINE_LIST, POINTS, etc."]
+        pub r#points: ::std::vec::Vec<geometry_msgs::Point>,
+        #[doc = " The color for each line, point, etc. in the points field."]
+        pub r#outline_colors: ::std::vec::Vec<std_msgs::ColorRGBA>,
+    }
+    impl ::roslibrust::RosMessageType for ImageMarker {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/ImageMarker";
+        const MD5SUM: &'static str = "829dd5d9ba39b8c3844252ebd8b47b96";
+        const DEFINITION: &'static str = r####"int32 CIRCLE=0
+int32 LINE_STRIP=1
+int32 LINE_LIST=2
+int32 POLYGON=3
+int32 POINTS=4
+
+int32 ADD=0
+int32 REMOVE=1
+
+std_msgs/Header header
+# Namespace which is used with the id to form a unique id.
+string ns
+# Unique id within the namespace.
+int32 id
+# One of the above types, e.g. CIRCLE, LINE_STRIP, etc.
+int32 type
+# Either ADD or REMOVE.
+int32 action
+# Two-dimensional coordinate position, in pixel-coordinates.
+geometry_msgs/Point position
+# The scale of the object, e.g. the diameter for a CIRCLE.
+float32 scale
+# The outline color of the marker.
+std_msgs/ColorRGBA outline_color
+# Whether or not to fill in the shape with color.
+uint8 filled
+# Fill color; in the range: [0.0-1.0]
+std_msgs/ColorRGBA fill_color
+# How long the object should last before being automatically deleted.
+# 0 indicates forever.
+builtin_interfaces/Duration lifetime
+
+# Coordinates in 2D in pixel coords. Used for LINE_STRIP, LINE_LIST, POINTS, etc.
+geometry_msgs/Point[] points
+# The color for each line, point, etc. in the points field.
+std_msgs/ColorRGBA[] outline_colors
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
+================================================================================
+MSG: geometry_msgs/Point
+# This contains the position of a point in free space
+float64 x
+float64 y
+float64 z
+================================================================================
+MSG: std_msgs/ColorRGBA
+float32 r
+float32 g
+float32 b
+float32 a"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_603152491ef2331c200a5305230d31f6e8704875944b388da0f547c415d11836";
+    }
+    #[allow(unused)]
+    impl ImageMarker {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl ImageMarker {
+        pub const r#CIRCLE: i32 = 0i32;
+        pub const r#LINE_STRIP: i32 = 1i32;
+        pub const r#LINE_LIST: i32 = 2i32;
+        pub const r#POLYGON: i32 = 3i32;
+        pub const r#POINTS: i32 = 4i32;
+        pub const r#ADD: i32 = 0i32;
+        pub const r#REMOVE: i32 = 1i32;
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct InteractiveMarker {
+        #[doc = " Time/frame info."]
+        #[doc = " If header.time is set to 0, the marker will be retransformed into"]
+        #[doc = " its frame on each timestep. You will receive the pose feedback"]
+        #[doc = " in the same frame."]
+        #[doc = " Otherwise, you might receive feedback in a different frame."]
+        #[doc = " For rviz, this will be the current 'fixed frame' set by the user."]
+        pub r#header: std_msgs::Header,
+        #[doc = " Initial pose. Also, defines the pivot point for rotations."]
+        pub r#pose: geometry_msgs::Pose,
+        #[doc = " Identifying string. Must be globally unique in"]
+        #[doc = " the topic that this message is sent through."]
+        pub r#name: ::std::string::String,
+        #[doc = " Short description (< 40 characters)."]
+        pub r#description: ::std::string::String,
+        #[doc = " Scale to be used for default controls (default=1)."]
+        pub r#scale: f32,
+        #[doc = " All menu and submenu entries associated with this marker."]
+        pub r#menu_entries: ::std::vec::Vec<self::MenuEntry>,
+        #[doc = " List of controls displayed for this marker."]
+        pub r#controls: ::std::vec::Vec<self::InteractiveMarkerControl>,
+    }
+    impl ::roslibrust::RosMessageType for InteractiveMarker {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarker";
+        const MD5SUM: &'static str = "d71737fa44c5bdefd6bdb4fa9b2b86e5";
+        const DEFINITION: &'static str = r####"# Time/frame info.
+# If header.time is set to 0, the marker will be retransformed into
+# its frame on each timestep. You will receive the pose feedback
+# in the same frame.
+# Otherwise, you might receive feedback in a different frame.
+# For rviz, this will be the current 'fixed frame' set by the user.
+std_msgs/Header header
 
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+# Initial pose. Also, defines the pivot point for rotations.
+geometry_msgs/Pose pose
 
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# Identifying string. Must be globally unique in
+# the topic that this message is sent through.
+string name
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# Short description (< 40 characters).
+string description
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
+# Scale to be used for default controls (default=1).
+float32 scale
+
+# All menu and submenu entries associated with this marker.
+MenuEntry[] menu_entries
+
+# List of controls displayed for this marker.
+InteractiveMarkerControl[] controls
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -12322,94 +8816,6 @@ builtin_interfaces/Time stamp
 # Transform frame with which this data is associated.
 string frame_id
 ================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -12430,156 +8836,140 @@ float64 y 0
 float64 z 0
 float64 w 1
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
+MSG: visualization_msgs/MenuEntry
+# MenuEntry message.
+#
+# Each InteractiveMarker message has an array of MenuEntry messages.
+# A collection of MenuEntries together describe a
+# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
+# array.  The tree structure is represented by giving each menu entry
+# an ID number and a "parent_id" field.  Top-level entries are the
+# ones with parent_id = 0.  Menu entries are ordered within their
+# level the same way they are ordered in the containing array.  Parent
+# entries must appear before their children.
+#
+# Example:
+# - id = 3
+#   parent_id = 0
+#   title = "fun"
+# - id = 2
+#   parent_id = 0
+#   title = "robot"
+# - id = 4
+#   parent_id = 2
+#   title = "pr2"
+# - id = 5
+#   parent_id = 2
+#   title = "turtle"
+#
+# Gives a menu tree like this:
+#  - fun
+#  - robot
+#    - pr2
+#    - turtle
 
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+# ID is a number for each menu entry.  Must be unique within the
+# control, and should never be 0.
+uint32 id
 
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
+# ID of the parent of this menu entry, if it is a submenu.  If this
+# menu entry is a top-level entry, set parent_id to 0.
+uint32 parent_id
 
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
+# menu / entry title
+string title
 
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+# Arguments to command indicated by command_type (below)
+string command
 
-uint8[] data                 # Compressed image buffer
+# Command_type stores the type of response desired when this menu
+# entry is clicked.
+# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
+# ROSRUN: execute "rosrun" with arguments given in the command field (above).
+# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
+uint8 FEEDBACK=0
+uint8 ROSRUN=1
+uint8 ROSLAUNCH=2
+uint8 command_type
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+MSG: visualization_msgs/InteractiveMarkerControl
+# Represents a control that is to be displayed together with an interactive marker
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# Identifying string for this control.
+# You need to assign a unique value to this to receive feedback from the GUI
+# on what actions the user performs on this control (e.g. a button click).
+string name
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
+# Defines the local coordinate frame (relative to the pose of the parent
+# interactive marker) in which is being rotated and translated.
+# Default: Identity
+geometry_msgs/Quaternion orientation
 
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
 
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
+# Orientation mode: controls how orientation changes.
+# INHERIT: Follow orientation of interactive marker
+# FIXED: Keep orientation fixed at initial state
+# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
+uint8 INHERIT = 0
+uint8 FIXED = 1
+uint8 VIEW_FACING = 2
 
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
+uint8 orientation_mode
 
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/InteractiveMarkerPose
-# Time/frame info.
-std_msgs/Header header
+# Interaction mode for this control
+#
+# NONE: This control is only meant for visualization; no context menu.
+# MENU: Like NONE, but right-click menu is active.
+# BUTTON: Element can be left-clicked.
+# MOVE_AXIS: Translate along local x-axis.
+# MOVE_PLANE: Translate in local y-z plane.
+# ROTATE_AXIS: Rotate around local x-axis.
+# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
+uint8 NONE = 0
+uint8 MENU = 1
+uint8 BUTTON = 2
+uint8 MOVE_AXIS = 3
+uint8 MOVE_PLANE = 4
+uint8 ROTATE_AXIS = 5
+uint8 MOVE_ROTATE = 6
+# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
+# MOVE_3D: Translate freely in 3D space.
+# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
+# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
+uint8 MOVE_3D = 7
+uint8 ROTATE_3D = 8
+uint8 MOVE_ROTATE_3D = 9
 
-# Initial pose. Also, defines the pivot point for rotations.
-geometry_msgs/Pose pose
+uint8 interaction_mode
 
-# Identifying string. Must be globally unique in
-# the topic that this message is sent through.
-string name
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+# If true, the contained markers will also be visible
+# when the gui is not in interactive mode.
+bool always_visible
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# Markers to be displayed as custom visual representation.
+# Leave this empty to use the default control handles.
+#
+# Note:
+# - The markers can be defined in an arbitrary coordinate frame,
+#   but will be transformed into the local frame of the interactive marker.
+# - If the header of a marker is empty, its pose will be interpreted as
+#   relative to the pose of the parent interactive marker.
+Marker[] markers
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
 
-# Transform frame with which this data is associated.
-string frame_id
+# In VIEW_FACING mode, set this to true if you don't want the markers
+# to be aligned with the camera view point. The markers will show up
+# as in INHERIT mode.
+bool independent_marker_orientation
+
+
+# Short description (< 40 characters) of what this control does,
+# e.g. "Move the robot".
+# Default: A generic description based on the interaction mode
+string description
 ================================================================================
 MSG: visualization_msgs/Marker
 # See:
@@ -12663,40 +9053,6 @@ string mesh_resource
 MeshFile mesh_file
 bool mesh_use_embedded_materials
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
 MSG: geometry_msgs/Vector3
 # This represents a vector in free space.
 
@@ -12708,6 +9064,12 @@ float64 x
 float64 y
 float64 z
 ================================================================================
+MSG: std_msgs/ColorRGBA
+float32 r
+float32 g
+float32 b
+float32 a
+================================================================================
 MSG: sensor_msgs/CompressedImage
 # This message contains a compressed image.
 
@@ -12724,127 +9086,31 @@ string format                # Specifies the format of the data
 
 uint8[] data                 # Compressed image buffer
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
 MSG: visualization_msgs/UVCoordinate
 # Location of the pixel as a ratio of the width of a 2D texture.
 # Values should be in range: [0.0-1.0].
 float32 u
 float32 v
 ================================================================================
-MSG: visualization_msgs/MenuEntry
-# MenuEntry message.
-#
-# Each InteractiveMarker message has an array of MenuEntry messages.
-# A collection of MenuEntries together describe a
-# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
-# array.  The tree structure is represented by giving each menu entry
-# an ID number and a "parent_id" field.  Top-level entries are the
-# ones with parent_id = 0.  Menu entries are ordered within their
-# level the same way they are ordered in the containing array.  Parent
-# entries must appear before their children.
-#
-# Example:
-# - id = 3
-#   parent_id = 0
-#   title = "fun"
-# - id = 2
-#   parent_id = 0
-#   title = "robot"
-# - id = 4
-#   parent_id = 2
-#   title = "pr2"
-# - id = 5
-#   parent_id = 2
-#   title = "turtle"
-#
-# Gives a menu tree like this:
-#  - fun
-#  - robot
-#    - pr2
-#    - turtle
-
-# ID is a number for each menu entry.  Must be unique within the
-# control, and should never be 0.
-uint32 id
-
-# ID of the parent of this menu entry, if it is a submenu.  If this
-# menu entry is a top-level entry, set parent_id to 0.
-uint32 parent_id
-
-# menu / entry title
-string title
-
-# Arguments to command indicated by command_type (below)
-string command
-
-# Command_type stores the type of response desired when this menu
-# entry is clicked.
-# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
-# ROSRUN: execute "rosrun" with arguments given in the command field (above).
-# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
-uint8 FEEDBACK=0
-uint8 ROSRUN=1
-uint8 ROSLAUNCH=2
-uint8 command_type
-================================================================================
 MSG: visualization_msgs/MeshFile
 # Used to send raw mesh files.
 
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v"####;
+# The filename is used for both debug purposes and to provide a file extension
+# for whatever parser is used.
+string filename
+
+# This stores the raw text of the mesh file.
+uint8[] data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_3d5b51448b51d73b0f395b94d259edd3a5d269ae9f7d9fd5cceb9ae4b72be346";
     }
     #[allow(unused)]
-    impl InteractiveMarkerUpdate {
-        pub const r#KEEP_ALIVE: u8 = 0u8;
-        pub const r#UPDATE: u8 = 1u8;
+    impl InteractiveMarker {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
+    #[doc = " Represents a control that is to be displayed together with an interactive marker"]
     #[allow(non_snake_case)]
     #[derive(
         :: roslibrust :: codegen :: Deserialize,
@@ -12855,31 +9121,129 @@ float32 v"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct Marker {
-        pub r#header: std_msgs::Header,
-        pub r#ns: ::std::string::String,
-        pub r#id: i32,
-        pub r#type: i32,
-        pub r#action: i32,
-        pub r#pose: geometry_msgs::Pose,
-        pub r#scale: geometry_msgs::Vector3,
-        pub r#color: std_msgs::ColorRGBA,
-        pub r#lifetime: ::roslibrust::codegen::integral_types::Duration,
-        pub r#frame_locked: bool,
-        pub r#points: ::std::vec::Vec<geometry_msgs::Point>,
-        pub r#colors: ::std::vec::Vec<std_msgs::ColorRGBA>,
-        pub r#texture_resource: ::std::string::String,
-        pub r#texture: sensor_msgs::CompressedImage,
-        pub r#uv_coordinates: ::std::vec::Vec<self::UVCoordinate>,
-        pub r#text: ::std::string::String,
-        pub r#mesh_resource: ::std::string::String,
-        pub r#mesh_file: self::MeshFile,
-        pub r#mesh_use_embedded_materials: bool,
+    pub struct InteractiveMarkerControl {
+        #[doc = " Identifying string for this control."]
+        #[doc = " You need to assign a unique value to this to receive feedback from the GUI"]
+        #[doc = " on what actions the user performs on this control (e.g. a button click)."]
+        pub r#name: ::std::string::String,
+        #[doc = " Defines the local coordinate frame (relative to the pose of the parent"]
+        #[doc = " interactive marker) in which is being rotated and translated."]
+        #[doc = " Default: Identity"]
+        pub r#orientation: geometry_msgs::Quaternion,
+        pub r#orientation_mode: u8,
+        pub r#interaction_mode: u8,
+        #[doc = " If true, the contained markers will also be visible"]
+        #[doc = " when the gui is not in interactive mode."]
+        pub r#always_visible: bool,
+        #[doc = " Markers to be displayed as custom visual representation."]
+        #[doc = " Leave this empty to use the default control handles."]
+        #[doc = ""]
+        #[doc = " Note:"]
+        #[doc = " - The markers can be defined in an arbitrary coordinate frame,"]
+        #[doc = " but will be transformed into the local frame of the interactive marker."]
+        #[doc = " - If the header of a marker is empty, its pose will be interpreted as"]
+        #[doc = " relative to the pose of the parent interactive marker."]
+        pub r#markers: ::std::vec::Vec<self::Marker>,
+        #[doc = " In VIEW_FACING mode, set this to true if you don't want the markers"]
+        #[doc = " to be aligned with the camera view point. The markers will show up"]
+        #[doc = " as in INHERIT mode."]
+        pub r#independent_marker_orientation: bool,
+        #[doc = " Short description (< 40 characters) of what this control does,"]
+        #[doc = " e.g. \"Move the robot\"."]
+        #[doc = " Default: A generic description based on the interaction mode"]
+        pub r#description: ::std::string::String,
     }
-    impl ::roslibrust::RosMessageType for Marker {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/Marker";
-        const MD5SUM: &'static str = "56c6324983a404ead7a426609371feed";
-        const DEFINITION: &'static str = r####"# See:
+    impl ::roslibrust::RosMessageType for InteractiveMarkerControl {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerControl";
+        const MD5SUM: &'static str = "7b945e790a2d68f430a6eb79f33bf8df";
+        const DEFINITION: &'static str = r####"# Represents a control that is to be displayed together with an interactive marker
+
+# Identifying string for this control.
+# You need to assign a unique value to this to receive feedback from the GUI
+# on what actions the user performs on this control (e.g. a button click).
+string name
+
+
+# Defines the local coordinate frame (relative to the pose of the parent
+# interactive marker) in which is being rotated and translated.
+# Default: Identity
+geometry_msgs/Quaternion orientation
+
+
+# Orientation mode: controls how orientation changes.
+# INHERIT: Follow orientation of interactive marker
+# FIXED: Keep orientation fixed at initial state
+# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
+uint8 INHERIT = 0
+uint8 FIXED = 1
+uint8 VIEW_FACING = 2
+
+uint8 orientation_mode
+
+# Interaction mode for this control
+#
+# NONE: This control is only meant for visualization; no context menu.
+# MENU: Like NONE, but right-click menu is active.
+# BUTTON: Element can be left-clicked.
+# MOVE_AXIS: Translate along local x-axis.
+# MOVE_PLANE: Translate in local y-z plane.
+# ROTATE_AXIS: Rotate around local x-axis.
+# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
+uint8 NONE = 0
+uint8 MENU = 1
+uint8 BUTTON = 2
+uint8 MOVE_AXIS = 3
+uint8 MOVE_PLANE = 4
+uint8 ROTATE_AXIS = 5
+uint8 MOVE_ROTATE = 6
+# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
+# MOVE_3D: Translate freely in 3D space.
+# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
+# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
+uint8 MOVE_3D = 7
+uint8 ROTATE_3D = 8
+uint8 MOVE_ROTATE_3D = 9
+
+uint8 interaction_mode
+
+
+# If true, the contained markers will also be visible
+# when the gui is not in interactive mode.
+bool always_visible
+
+
+# Markers to be displayed as custom visual representation.
+# Leave this empty to use the default control handles.
+#
+# Note:
+# - The markers can be defined in an arbitrary coordinate frame,
+#   but will be transformed into the local frame of the interactive marker.
+# - If the header of a marker is empty, its pose will be interpreted as
+#   relative to the pose of the parent interactive marker.
+Marker[] markers
+
+
+# In VIEW_FACING mode, set this to true if you don't want the markers
+# to be aligned with the camera view point. The markers will show up
+# as in INHERIT mode.
+bool independent_marker_orientation
+
+
+# Short description (< 40 characters) of what this control does,
+# e.g. "Move the robot".
+# Default: A generic description based on the interaction mode
+string description
+================================================================================
+MSG: geometry_msgs/Quaternion
+# This represents an orientation in free space in quaternion form.
+
+float64 x 0
+float64 y 0
+float64 z 0
+float64 w 1
+================================================================================
+MSG: visualization_msgs/Marker
+# See:
 #  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
 #  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
 #
@@ -12960,11 +9324,16 @@ string mesh_resource
 MeshFile mesh_file
 bool mesh_use_embedded_materials
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -12978,22 +9347,6 @@ float64 x
 float64 y
 float64 z
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
 MSG: geometry_msgs/Vector3
 # This represents a vector in free space.
 
@@ -13005,6 +9358,12 @@ float64 x
 float64 y
 float64 z
 ================================================================================
+MSG: std_msgs/ColorRGBA
+float32 r
+float32 g
+float32 b
+float32 a
+================================================================================
 MSG: sensor_msgs/CompressedImage
 # This message contains a compressed image.
 
@@ -13015,28 +9374,146 @@ std_msgs/Header header # Header timestamp should be acquisition time of image
                              # +y should point down in the image
                              # +z should point into to plane of the image
 
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+string format                # Specifies the format of the data
+                             #   Acceptable values:
+                             #     jpeg, png, tiff
+
+uint8[] data                 # Compressed image buffer
+================================================================================
+MSG: visualization_msgs/UVCoordinate
+# Location of the pixel as a ratio of the width of a 2D texture.
+# Values should be in range: [0.0-1.0].
+float32 u
+float32 v
+================================================================================
+MSG: visualization_msgs/MeshFile
+# Used to send raw mesh files.
+
+# The filename is used for both debug purposes and to provide a file extension
+# for whatever parser is used.
+string filename
+
+# This stores the raw text of the mesh file.
+uint8[] data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_60e2fa36344f5f4791b24a809542a18bffd555f563550d4b22b3bbfc31ec0ed5";
+    }
+    #[allow(unused)]
+    impl InteractiveMarkerControl {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl InteractiveMarkerControl {
+        #[doc = " Orientation mode: controls how orientation changes."]
+        #[doc = " INHERIT: Follow orientation of interactive marker"]
+        #[doc = " FIXED: Keep orientation fixed at initial state"]
+        #[doc = " VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up)."]
+        pub const r#INHERIT: u8 = 0u8;
+        pub const r#FIXED: u8 = 1u8;
+        pub const r#VIEW_FACING: u8 = 2u8;
+        #[doc = " Interaction mode for this control"]
+        #[doc = ""]
+        #[doc = " NONE: This control is only meant for visualization; no context menu."]
+        #[doc = " MENU: Like NONE, but right-click menu is active."]
+        #[doc = " BUTTON: Element can be left-clicked."]
+        #[doc = " MOVE_AXIS: Translate along local x-axis."]
+        #[doc = " MOVE_PLANE: Translate in local y-z plane."]
+        #[doc = " ROTATE_AXIS: Rotate around local x-axis."]
+        #[doc = " MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS."]
+        pub const r#NONE: u8 = 0u8;
+        pub const r#MENU: u8 = 1u8;
+        pub const r#BUTTON: u8 = 2u8;
+        pub const r#MOVE_AXIS: u8 = 3u8;
+        pub const r#MOVE_PLANE: u8 = 4u8;
+        pub const r#ROTATE_AXIS: u8 = 5u8;
+        pub const r#MOVE_ROTATE: u8 = 6u8;
+        #[doc = " \"3D\" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors."]
+        #[doc = " MOVE_3D: Translate freely in 3D space."]
+        #[doc = " ROTATE_3D: Rotate freely in 3D space about the origin of parent frame."]
+        #[doc = " MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin."]
+        pub const r#MOVE_3D: u8 = 7u8;
+        pub const r#ROTATE_3D: u8 = 8u8;
+        pub const r#MOVE_ROTATE_3D: u8 = 9u8;
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct InteractiveMarkerFeedback {
+        #[doc = " Time/frame info."]
+        pub r#header: std_msgs::Header,
+        #[doc = " Identifying string. Must be unique in the topic namespace."]
+        pub r#client_id: ::std::string::String,
+        #[doc = " Specifies which interactive marker and control this message refers to"]
+        pub r#marker_name: ::std::string::String,
+        pub r#control_name: ::std::string::String,
+        pub r#event_type: u8,
+        #[doc = " Current pose of the marker"]
+        #[doc = " Note: Has to be valid for all feedback types."]
+        pub r#pose: geometry_msgs::Pose,
+        #[doc = " Contains the ID of the selected menu entry"]
+        #[doc = " Only valid for MENU_SELECT events."]
+        pub r#menu_entry_id: u32,
+        #[doc = " If event_type is BUTTON_CLICK, MOUSE_DOWN, or MOUSE_UP, mouse_point"]
+        #[doc = " may contain the 3 dimensional position of the event on the"]
+        #[doc = " control.  If it does, mouse_point_valid will be true.  mouse_point"]
+        #[doc = " will be relative to the frame listed in the header."]
+        pub r#mouse_point: geometry_msgs::Point,
+        pub r#mouse_point_valid: bool,
+    }
+    impl ::roslibrust::RosMessageType for InteractiveMarkerFeedback {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerFeedback";
+        const MD5SUM: &'static str = "880e5141421ed8d30906fad686bc17bd";
+        const DEFINITION: &'static str = r####"# Time/frame info.
+std_msgs/Header header
+
+# Identifying string. Must be unique in the topic namespace.
+string client_id
+
+# Feedback message sent back from the GUI, e.g.
+# when the status of an interactive marker was modified by the user.
+
+# Specifies which interactive marker and control this message refers to
+string marker_name
+string control_name
+
+# Type of the event
+# KEEP_ALIVE: sent while dragging to keep up control of the marker
+# MENU_SELECT: a menu entry has been selected
+# BUTTON_CLICK: a button control has been clicked
+# POSE_UPDATE: the pose has been changed using one of the controls
+uint8 KEEP_ALIVE = 0
+uint8 POSE_UPDATE = 1
+uint8 MENU_SELECT = 2
+uint8 BUTTON_CLICK = 3
+
+uint8 MOUSE_DOWN = 4
+uint8 MOUSE_UP = 5
 
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+uint8 event_type
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# Current pose of the marker
+# Note: Has to be valid for all feedback types.
+geometry_msgs/Pose pose
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
+# Contains the ID of the selected menu entry
+# Only valid for MENU_SELECT events.
+uint32 menu_entry_id
+
+# If event_type is BUTTON_CLICK, MOUSE_DOWN, or MOUSE_UP, mouse_point
+# may contain the 3 dimensional position of the event on the
+# control.  If it does, mouse_point_valid will be true.  mouse_point
+# will be relative to the frame listed in the header.
+geometry_msgs/Point mouse_point
+bool mouse_point_valid
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -13049,40 +9526,47 @@ builtin_interfaces/Time stamp
 # Transform frame with which this data is associated.
 string frame_id
 ================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
+MSG: geometry_msgs/Pose
+# A representation of pose in free space, composed of position and orientation.
 
-# This stores the raw text of the mesh file.
-uint8[] data
+Point position
+Quaternion orientation
 ================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v"####;
+MSG: geometry_msgs/Point
+# This contains the position of a point in free space
+float64 x
+float64 y
+float64 z
+================================================================================
+MSG: geometry_msgs/Quaternion
+# This represents an orientation in free space in quaternion form.
+
+float64 x 0
+float64 y 0
+float64 z 0
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_6cc48741df9f05d19ba7d9ea3101e9fcd1309c9d6bda3c55668ba607492f725e";
     }
     #[allow(unused)]
-    impl Marker {
-        pub const r#ARROW: i32 = 0i32;
-        pub const r#CUBE: i32 = 1i32;
-        pub const r#SPHERE: i32 = 2i32;
-        pub const r#CYLINDER: i32 = 3i32;
-        pub const r#LINE_STRIP: i32 = 4i32;
-        pub const r#LINE_LIST: i32 = 5i32;
-        pub const r#CUBE_LIST: i32 = 6i32;
-        pub const r#SPHERE_LIST: i32 = 7i32;
-        pub const r#POINTS: i32 = 8i32;
-        pub const r#TEXT_VIEW_FACING: i32 = 9i32;
-        pub const r#MESH_RESOURCE: i32 = 10i32;
-        pub const r#TRIANGLE_LIST: i32 = 11i32;
-        pub const r#ADD: i32 = 0i32;
-        pub const r#MODIFY: i32 = 0i32;
-        pub const r#DELETE: i32 = 2i32;
-        pub const r#DELETEALL: i32 = 3i32;
+    impl InteractiveMarkerFeedback {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl InteractiveMarkerFeedback {
+        #[doc = " Type of the event"]
+        #[doc = " KEEP_ALIVE: sent while dragging to keep up control of the marker"]
+        #[doc = " MENU_SELECT: a menu entry has been selected"]
+        #[doc = " BUTTON_CLICK: a button control has been clicked"]
+        #[doc = " POSE_UPDATE: the pose has been changed using one of the controls"]
+        pub const r#KEEP_ALIVE: u8 = 0u8;
+        pub const r#POSE_UPDATE: u8 = 1u8;
+        pub const r#MENU_SELECT: u8 = 2u8;
+        pub const r#BUTTON_CLICK: u8 = 3u8;
+        pub const r#MOUSE_DOWN: u8 = 4u8;
+        pub const r#MOUSE_UP: u8 = 5u8;
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -13094,19 +9578,76 @@ float32 v"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct MarkerArray {
-        pub r#markers: ::std::vec::Vec<self::Marker>,
+    pub struct InteractiveMarkerInit {
+        #[doc = " Identifying string. Must be unique in the topic namespace"]
+        #[doc = " that this server works on."]
+        pub r#server_id: ::std::string::String,
+        #[doc = " Sequence number."]
+        #[doc = " The client will use this to detect if it has missed a subsequent"]
+        #[doc = " update.  Every update message will have the same sequence number as"]
+        #[doc = " an init message.  Clients will likely want to unsubscribe from the"]
+        #[doc = " init topic after a successful initialization to avoid receiving"]
+        #[doc = " duplicate data."]
+        pub r#seq_num: u64,
+        #[doc = " All markers."]
+        pub r#markers: ::std::vec::Vec<self::InteractiveMarker>,
     }
-    impl ::roslibrust::RosMessageType for MarkerArray {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/MarkerArray";
-        const MD5SUM: &'static str = "11e38f15427197858cf46456867167bd";
-        const DEFINITION: &'static str = r####"Marker[] markers
+    impl ::roslibrust::RosMessageType for InteractiveMarkerInit {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerInit";
+        const MD5SUM: &'static str = "5d275694a5cb7ea4627f917a9eb1b4cd";
+        const DEFINITION: &'static str = r####"# Identifying string. Must be unique in the topic namespace
+# that this server works on.
+string server_id
+
+# Sequence number.
+# The client will use this to detect if it has missed a subsequent
+# update.  Every update message will have the same sequence number as
+# an init message.  Clients will likely want to unsubscribe from the
+# init topic after a successful initialization to avoid receiving
+# duplicate data.
+uint64 seq_num
+
+# All markers.
+InteractiveMarker[] markers
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: visualization_msgs/InteractiveMarker
+# Time/frame info.
+# If header.time is set to 0, the marker will be retransformed into
+# its frame on each timestep. You will receive the pose feedback
+# in the same frame.
+# Otherwise, you might receive feedback in a different frame.
+# For rviz, this will be the current 'fixed frame' set by the user.
+std_msgs/Header header
+
+# Initial pose. Also, defines the pivot point for rotations.
+geometry_msgs/Pose pose
+
+# Identifying string. Must be globally unique in
+# the topic that this message is sent through.
+string name
+
+# Short description (< 40 characters).
+string description
+
+# Scale to be used for default controls (default=1).
+float32 scale
+
+# All menu and submenu entries associated with this marker.
+MenuEntry[] menu_entries
+
+# List of controls displayed for this marker.
+InteractiveMarkerControl[] controls
+================================================================================
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -13128,68 +9669,140 @@ float64 y 0
 float64 z 0
 float64 w 1
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: visualization_msgs/MenuEntry
+# MenuEntry message.
+#
+# Each InteractiveMarker message has an array of MenuEntry messages.
+# A collection of MenuEntries together describe a
+# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
+# array.  The tree structure is represented by giving each menu entry
+# an ID number and a "parent_id" field.  Top-level entries are the
+# ones with parent_id = 0.  Menu entries are ordered within their
+# level the same way they are ordered in the containing array.  Parent
+# entries must appear before their children.
+#
+# Example:
+# - id = 3
+#   parent_id = 0
+#   title = "fun"
+# - id = 2
+#   parent_id = 0
+#   title = "robot"
+# - id = 4
+#   parent_id = 2
+#   title = "pr2"
+# - id = 5
+#   parent_id = 2
+#   title = "turtle"
+#
+# Gives a menu tree like this:
+#  - fun
+#  - robot
+#    - pr2
+#    - turtle
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# ID is a number for each menu entry.  Must be unique within the
+# control, and should never be 0.
+uint32 id
+
+# ID of the parent of this menu entry, if it is a submenu.  If this
+# menu entry is a top-level entry, set parent_id to 0.
+uint32 parent_id
+
+# menu / entry title
+string title
+
+# Arguments to command indicated by command_type (below)
+string command
+
+# Command_type stores the type of response desired when this menu
+# entry is clicked.
+# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
+# ROSRUN: execute "rosrun" with arguments given in the command field (above).
+# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
+uint8 FEEDBACK=0
+uint8 ROSRUN=1
+uint8 ROSLAUNCH=2
+uint8 command_type
 ================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
+MSG: visualization_msgs/InteractiveMarkerControl
+# Represents a control that is to be displayed together with an interactive marker
+
+# Identifying string for this control.
+# You need to assign a unique value to this to receive feedback from the GUI
+# on what actions the user performs on this control (e.g. a button click).
+string name
+
+
+# Defines the local coordinate frame (relative to the pose of the parent
+# interactive marker) in which is being rotated and translated.
+# Default: Identity
+geometry_msgs/Quaternion orientation
+
+
+# Orientation mode: controls how orientation changes.
+# INHERIT: Follow orientation of interactive marker
+# FIXED: Keep orientation fixed at initial state
+# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
+uint8 INHERIT = 0
+uint8 FIXED = 1
+uint8 VIEW_FACING = 2
+
+uint8 orientation_mode
+
+# Interaction mode for this control
+#
+# NONE: This control is only meant for visualization; no context menu.
+# MENU: Like NONE, but right-click menu is active.
+# BUTTON: Element can be left-clicked.
+# MOVE_AXIS: Translate along local x-axis.
+# MOVE_PLANE: Translate in local y-z plane.
+# ROTATE_AXIS: Rotate around local x-axis.
+# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
+uint8 NONE = 0
+uint8 MENU = 1
+uint8 BUTTON = 2
+uint8 MOVE_AXIS = 3
+uint8 MOVE_PLANE = 4
+uint8 ROTATE_AXIS = 5
+uint8 MOVE_ROTATE = 6
+# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
+# MOVE_3D: Translate freely in 3D space.
+# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
+# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
+uint8 MOVE_3D = 7
+uint8 ROTATE_3D = 8
+uint8 MOVE_ROTATE_3D = 9
 
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+uint8 interaction_mode
 
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
 
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
+# If true, the contained markers will also be visible
+# when the gui is not in interactive mode.
+bool always_visible
 
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
 
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# Markers to be displayed as custom visual representation.
+# Leave this empty to use the default control handles.
+#
+# Note:
+# - The markers can be defined in an arbitrary coordinate frame,
+#   but will be transformed into the local frame of the interactive marker.
+# - If the header of a marker is empty, its pose will be interpreted as
+#   relative to the pose of the parent interactive marker.
+Marker[] markers
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# In VIEW_FACING mode, set this to true if you don't want the markers
+# to be aligned with the camera view point. The markers will show up
+# as in INHERIT mode.
+bool independent_marker_orientation
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
 
-# Transform frame with which this data is associated.
-string frame_id
+# Short description (< 40 characters) of what this control does,
+# e.g. "Move the robot".
+# Default: A generic description based on the interaction mode
+string description
 ================================================================================
 MSG: visualization_msgs/Marker
 # See:
@@ -13273,227 +9886,47 @@ string mesh_resource
 MeshFile mesh_file
 bool mesh_use_embedded_materials
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
 MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct MenuEntry {
-        pub r#id: u32,
-        pub r#parent_id: u32,
-        pub r#title: ::std::string::String,
-        pub r#command: ::std::string::String,
-        pub r#command_type: u8,
-    }
-    impl ::roslibrust::RosMessageType for MenuEntry {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/MenuEntry";
-        const MD5SUM: &'static str = "b90ec63024573de83b57aa93eb39be2d";
-        const DEFINITION: &'static str = r####"# MenuEntry message.
-#
-# Each InteractiveMarker message has an array of MenuEntry messages.
-# A collection of MenuEntries together describe a
-# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
-# array.  The tree structure is represented by giving each menu entry
-# an ID number and a "parent_id" field.  Top-level entries are the
-# ones with parent_id = 0.  Menu entries are ordered within their
-# level the same way they are ordered in the containing array.  Parent
-# entries must appear before their children.
-#
-# Example:
-# - id = 3
-#   parent_id = 0
-#   title = "fun"
-# - id = 2
-#   parent_id = 0
-#   title = "robot"
-# - id = 4
-#   parent_id = 2
-#   title = "pr2"
-# - id = 5
-#   parent_id = 2
-#   title = "turtle"
-#
-# Gives a menu tree like this:
-#  - fun
-#  - robot
-#    - pr2
-#    - turtle
-
-# ID is a number for each menu entry.  Must be unique within the
-# control, and should never be 0.
-uint32 id
+# This represents a vector in free space.
 
-# ID of the parent of this menu entry, if it is a submenu.  If this
-# menu entry is a top-level entry, set parent_id to 0.
-uint32 parent_id
+# This is semantically different than a point.
+# A vector is always anchored at the origin.
+# When a transform is applied to a vector, only the rotational component is applied.
 
-# menu / entry title
-string title
+float64 x
+float64 y
+float64 z
+================================================================================
+MSG: std_msgs/ColorRGBA
+float32 r
+float32 g
+float32 b
+float32 a
+================================================================================
+MSG: sensor_msgs/CompressedImage
+# This message contains a compressed image.
 
-# Arguments to command indicated by command_type (below)
-string command
+std_msgs/Header header # Header timestamp should be acquisition time of image
+                             # Header frame_id should be optical frame of camera
+                             # origin of frame should be optical center of cameara
+                             # +x should point to the right in the image
+                             # +y should point down in the image
+                             # +z should point into to plane of the image
 
-# Command_type stores the type of response desired when this menu
-# entry is clicked.
-# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
-# ROSRUN: execute "rosrun" with arguments given in the command field (above).
-# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
-uint8 FEEDBACK=0
-uint8 ROSRUN=1
-uint8 ROSLAUNCH=2
-uint8 command_type"####;
-    }
-    #[allow(unused)]
-    impl MenuEntry {
-        pub const r#FEEDBACK: u8 = 0u8;
-        pub const r#ROSRUN: u8 = 1u8;
-        pub const r#ROSLAUNCH: u8 = 2u8;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct MeshFile {
-        pub r#filename: ::std::string::String,
-        #[serde(with = "::roslibrust::codegen::serde_bytes")]
-        pub r#data: ::std::vec::Vec<u8>,
-    }
-    impl ::roslibrust::RosMessageType for MeshFile {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/MeshFile";
-        const MD5SUM: &'static str = "39f264648e441626a1045a7d9ef1ba17";
-        const DEFINITION: &'static str = r####"# Used to send raw mesh files.
+string format                # Specifies the format of the data
+                             #   Acceptable values:
+                             #     jpeg, png, tiff
+
+uint8[] data                 # Compressed image buffer
+================================================================================
+MSG: visualization_msgs/UVCoordinate
+# Location of the pixel as a ratio of the width of a 2D texture.
+# Values should be in range: [0.0-1.0].
+float32 u
+float32 v
+================================================================================
+MSG: visualization_msgs/MeshFile
+# Used to send raw mesh files.
 
 # The filename is used for both debug purposes and to provide a file extension
 # for whatever parser is used.
@@ -13501,44 +9934,14 @@ string filename
 
 # This stores the raw text of the mesh file.
 uint8[] data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_23fda1b3373b154d9d6408dd7b9f8129b2a2b76b905ee421e8c4109d8bf71f78";
     }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct UVCoordinate {
-        pub r#u: f32,
-        pub r#v: f32,
-    }
-    impl ::roslibrust::RosMessageType for UVCoordinate {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/UVCoordinate";
-        const MD5SUM: &'static str = "4f5254e0e12914c461d4b17a0cd07f7f";
-        const DEFINITION: &'static str = r####"# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v"####;
-    }
-    #[allow(non_snake_case)]
-    #[derive(
-        :: roslibrust :: codegen :: Deserialize,
-        :: roslibrust :: codegen :: Serialize,
-        :: roslibrust :: codegen :: SmartDefault,
-        Debug,
-        Clone,
-        PartialEq,
-    )]
-    #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct GetInteractiveMarkersRequest {}
-    impl ::roslibrust::RosMessageType for GetInteractiveMarkersRequest {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/GetInteractiveMarkersRequest";
-        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
-        const DEFINITION: &'static str = r####""####;
+    #[allow(unused)]
+    impl InteractiveMarkerInit {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(non_snake_case)]
     #[derive(
@@ -13550,27 +9953,38 @@ float32 v"####;
         PartialEq,
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
-    pub struct GetInteractiveMarkersResponse {
-        pub r#sequence_number: u64,
-        pub r#markers: ::std::vec::Vec<self::InteractiveMarker>,
+    pub struct InteractiveMarkerPose {
+        #[doc = " Time/frame info."]
+        pub r#header: std_msgs::Header,
+        #[doc = " Initial pose. Also, defines the pivot point for rotations."]
+        pub r#pose: geometry_msgs::Pose,
+        #[doc = " Identifying string. Must be globally unique in"]
+        #[doc = " the topic that this message is sent through."]
+        pub r#name: ::std::string::String,
     }
-    impl ::roslibrust::RosMessageType for GetInteractiveMarkersResponse {
-        const ROS_TYPE_NAME: &'static str = "visualization_msgs/GetInteractiveMarkersResponse";
-        const MD5SUM: &'static str = "923b76ef2c497d4ff5f83a061d424d3b";
-        const DEFINITION: &'static str = r####"# Sequence number.
-# Set to the sequence number of the latest update message
-# at the time the server received the request.
-# Clients use this to detect if any updates were missed.
-uint64 sequence_number
+    impl ::roslibrust::RosMessageType for InteractiveMarkerPose {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerPose";
+        const MD5SUM: &'static str = "b88540594a0f8e3fe46c720be41faa03";
+        const DEFINITION: &'static str = r####"# Time/frame info.
+std_msgs/Header header
 
-# All interactive markers provided by the server.
-InteractiveMarker[] markers
+# Initial pose. Also, defines the pivot point for rotations.
+geometry_msgs/Pose pose
+
+# Identifying string. Must be globally unique in
+# the topic that this message is sent through.
+string name
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -13590,70 +10004,75 @@ MSG: geometry_msgs/Quaternion
 float64 x 0
 float64 y 0
 float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
+float64 w 1"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_c60e9a4407d5f709a63e0fe9caea324aee08fe717cd090209ebe35012ce7cb66";
+    }
+    #[allow(unused)]
+    impl InteractiveMarkerPose {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct InteractiveMarkerUpdate {
+        #[doc = " Identifying string. Must be unique in the topic namespace"]
+        #[doc = " that this server works on."]
+        pub r#server_id: ::std::string::String,
+        #[doc = " Sequence number."]
+        #[doc = " The client will use this to detect if it has missed an update."]
+        pub r#seq_num: u64,
+        pub r#type: u8,
+        #[doc = " Markers to be added or updated"]
+        pub r#markers: ::std::vec::Vec<self::InteractiveMarker>,
+        #[doc = " Poses of markers that should be moved"]
+        pub r#poses: ::std::vec::Vec<self::InteractiveMarkerPose>,
+        #[doc = " Names of markers to be erased"]
+        pub r#erases: ::std::vec::Vec<::std::string::String>,
+    }
+    impl ::roslibrust::RosMessageType for InteractiveMarkerUpdate {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/InteractiveMarkerUpdate";
+        const MD5SUM: &'static str = "8f52c675c849441ae87da82eaa4d6eb5";
+        const DEFINITION: &'static str = r####"# Identifying string. Must be unique in the topic namespace
+# that this server works on.
+string server_id
 
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
+# Sequence number.
+# The client will use this to detect if it has missed an update.
+uint64 seq_num
 
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+# Type holds the purpose of this message.  It must be one of UPDATE or KEEP_ALIVE.
+# UPDATE: Incremental update to previous state.
+#         The sequence number must be 1 higher than for
+#         the previous update.
+# KEEP_ALIVE: Indicates the that the server is still living.
+#             The sequence number does not increase.
+#             No payload data should be filled out (markers, poses, or erases).
+uint8 KEEP_ALIVE = 0
+uint8 UPDATE = 1
 
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+uint8 type
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# Note: No guarantees on the order of processing.
+#       Contents must be kept consistent by sender.
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# Markers to be added or updated
+InteractiveMarker[] markers
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# Poses of markers that should be moved
+InteractiveMarkerPose[] poses
 
-# Transform frame with which this data is associated.
-string frame_id
+# Names of markers to be erased
+string[] erases
 ================================================================================
 MSG: visualization_msgs/InteractiveMarker
 # Time/frame info.
@@ -13683,11 +10102,16 @@ MenuEntry[] menu_entries
 # List of controls displayed for this marker.
 InteractiveMarkerControl[] controls
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -13709,68 +10133,61 @@ float64 y 0
 float64 z 0
 float64 w 1
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
-
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+MSG: visualization_msgs/MenuEntry
+# MenuEntry message.
+#
+# Each InteractiveMarker message has an array of MenuEntry messages.
+# A collection of MenuEntries together describe a
+# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
+# array.  The tree structure is represented by giving each menu entry
+# an ID number and a "parent_id" field.  Top-level entries are the
+# ones with parent_id = 0.  Menu entries are ordered within their
+# level the same way they are ordered in the containing array.  Parent
+# entries must appear before their children.
+#
+# Example:
+# - id = 3
+#   parent_id = 0
+#   title = "fun"
+# - id = 2
+#   parent_id = 0
+#   title = "robot"
+# - id = 4
+#   parent_id = 2
+#   title = "pr2"
+# - id = 5
+#   parent_id = 2
+#   title = "turtle"
+#
+# Gives a menu tree like this:
+#  - fun
+#  - robot
+#    - pr2
+#    - turtle
 
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# ID is a number for each menu entry.  Must be unique within the
+# control, and should never be 0.
+uint32 id
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# ID of the parent of this menu entry, if it is a submenu.  If this
+# menu entry is a top-level entry, set parent_id to 0.
+uint32 parent_id
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# menu / entry title
+string title
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# Arguments to command indicated by command_type (below)
+string command
 
-# Transform frame with which this data is associated.
-string frame_id
+# Command_type stores the type of response desired when this menu
+# entry is clicked.
+# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
+# ROSRUN: execute "rosrun" with arguments given in the command field (above).
+# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
+uint8 FEEDBACK=0
+uint8 ROSRUN=1
+uint8 ROSLAUNCH=2
+uint8 command_type
 ================================================================================
 MSG: visualization_msgs/InteractiveMarkerControl
 # Represents a control that is to be displayed together with an interactive marker
@@ -13851,39 +10268,87 @@ bool independent_marker_orientation
 # Default: A generic description based on the interaction mode
 string description
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
+MSG: visualization_msgs/Marker
+# See:
+#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
+#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
+#
+# for more information on using this message with rviz.
 
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+int32 ARROW=0
+int32 CUBE=1
+int32 SPHERE=2
+int32 CYLINDER=3
+int32 LINE_STRIP=4
+int32 LINE_LIST=5
+int32 CUBE_LIST=6
+int32 SPHERE_LIST=7
+int32 POINTS=8
+int32 TEXT_VIEW_FACING=9
+int32 MESH_RESOURCE=10
+int32 TRIANGLE_LIST=11
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+int32 ADD=0
+int32 MODIFY=0
+int32 DELETE=2
+int32 DELETEALL=3
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# Header for timestamp and frame id.
+std_msgs/Header header
+# Namespace in which to place the object.
+# Used in conjunction with id to create a unique name for the object.
+string ns
+# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
+int32 id
+# Type of object.
+int32 type
+# Action to take; one of:
+#  - 0 add/modify an object
+#  - 1 (deprecated)
+#  - 2 deletes an object (with the given ns and id)
+#  - 3 deletes all objects (or those with the given ns if any)
+int32 action
+# Pose of the object with respect the frame_id specified in the header.
+geometry_msgs/Pose pose
+# Scale of the object; 1,1,1 means default (usually 1 meter square).
+geometry_msgs/Vector3 scale
+# Color of the object; in the range: [0.0-1.0]
+std_msgs/ColorRGBA color
+# How long the object should last before being automatically deleted.
+# 0 indicates forever.
+builtin_interfaces/Duration lifetime
+# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
+bool frame_locked
+
+# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
+geometry_msgs/Point[] points
+# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
+# The number of colors provided must either be 0 or equal to the number of points provided.
+# NOTE: alpha is not yet used
+std_msgs/ColorRGBA[] colors
+
+# Texture resource is a special URI that can either reference a texture file in
+# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
+# or an embedded texture via a string matching the format:
+#   "embedded://texture_name"
+string texture_resource
+# An image to be loaded into the rendering engine as the texture for this marker.
+# This will be used iff texture_resource is set to embedded.
+sensor_msgs/CompressedImage texture
+# Location of each vertex within the texture; in the range: [0.0-1.0]
+UVCoordinate[] uv_coordinates
+
+# Only used for text markers
+string text
+
+# Only used for MESH_RESOURCE markers.
+# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
+# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
+# use the following format for mesh_resource:
+#   "embedded://mesh_name"
+string mesh_resource
+MeshFile mesh_file
+bool mesh_use_embedded_materials
 ================================================================================
 MSG: geometry_msgs/Vector3
 # This represents a vector in free space.
@@ -13896,6 +10361,12 @@ float64 x
 float64 y
 float64 z
 ================================================================================
+MSG: std_msgs/ColorRGBA
+float32 r
+float32 g
+float32 b
+float32 a
+================================================================================
 MSG: sensor_msgs/CompressedImage
 # This message contains a compressed image.
 
@@ -13908,40 +10379,130 @@ std_msgs/Header header # Header timestamp should be acquisition time of image
 
 string format                # Specifies the format of the data
                              #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+                             #     jpeg, png, tiff
 
-# Transform frame with which this data is associated.
-string frame_id
+uint8[] data                 # Compressed image buffer
 ================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
+MSG: visualization_msgs/UVCoordinate
+# Location of the pixel as a ratio of the width of a 2D texture.
+# Values should be in range: [0.0-1.0].
+float32 u
+float32 v
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+MSG: visualization_msgs/MeshFile
+# Used to send raw mesh files.
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# The filename is used for both debug purposes and to provide a file extension
+# for whatever parser is used.
+string filename
 
-# Transform frame with which this data is associated.
-string frame_id
+# This stores the raw text of the mesh file.
+uint8[] data
 ================================================================================
-MSG: visualization_msgs/Marker
-# See:
+MSG: visualization_msgs/InteractiveMarkerPose
+# Time/frame info.
+std_msgs/Header header
+
+# Initial pose. Also, defines the pivot point for rotations.
+geometry_msgs/Pose pose
+
+# Identifying string. Must be globally unique in
+# the topic that this message is sent through.
+string name"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_0a8b000c4fd4d50876ac716a7de018911599a2f015795388e956a2ca8b0c54f0";
+    }
+    #[allow(unused)]
+    impl InteractiveMarkerUpdate {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl InteractiveMarkerUpdate {
+        #[doc = " Type holds the purpose of this message.  It must be one of UPDATE or KEEP_ALIVE."]
+        #[doc = " UPDATE: Incremental update to previous state."]
+        #[doc = " The sequence number must be 1 higher than for"]
+        #[doc = " the previous update."]
+        #[doc = " KEEP_ALIVE: Indicates the that the server is still living."]
+        #[doc = " The sequence number does not increase."]
+        #[doc = " No payload data should be filled out (markers, poses, or erases)."]
+        pub const r#KEEP_ALIVE: u8 = 0u8;
+        pub const r#UPDATE: u8 = 1u8;
+    }
+    #[doc = " See:"]
+    #[doc = " - http://www.ros.org/wiki/rviz/DisplayTypes/Marker"]
+    #[doc = " - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes"]
+    #[doc = ""]
+    #[doc = " for more information on using this message with rviz."]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct Marker {
+        #[doc = " Header for timestamp and frame id."]
+        pub r#header: std_msgs::Header,
+        #[doc = " Namespace in which to place the object."]
+        #[doc = " Used in conjunction with id to create a unique name for the object."]
+        pub r#ns: ::std::string::String,
+        #[doc = " Object ID used in conjunction with the namespace for manipulating and deleting the object later."]
+        pub r#id: i32,
+        #[doc = " Type of object."]
+        pub r#type: i32,
+        #[doc = " Action to take; one of:"]
+        #[doc = " - 0 add/modify an object"]
+        #[doc = " - 1 (deprecated)"]
+        #[doc = " - 2 deletes an object (with the given ns and id)"]
+        #[doc = " - 3 deletes all objects (or those with the given ns if any)"]
+        pub r#action: i32,
+        #[doc = " Pose of the object with respect the frame_id specified in the header."]
+        pub r#pose: geometry_msgs::Pose,
+        #[doc = " Scale of the object; 1,1,1 means default (usually 1 meter square)."]
+        pub r#scale: geometry_msgs::Vector3,
+        #[doc = " Color of the object; in the range: [0.0-1.0]"]
+        pub r#color: std_msgs::ColorRGBA,
+        #[doc = " How long the object should last before being automatically deleted."]
+        #[doc = " 0 indicates forever."]
+        pub r#lifetime: ::roslibrust::codegen::integral_types::Duration,
+        #[doc = " If this marker should be frame-locked, i.e. retransformed into its frame every timestep."]
+        pub r#frame_locked: bool,
+        #[doc = " Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)"]
+        pub r#points: ::std::vec::Vec<geometry_msgs::Point>,
+        #[doc = " Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)"]
+        #[doc = " The number of colors provided must either be 0 or equal to the number of points provided."]
+        #[doc = " NOTE: alpha is not yet used"]
+        pub r#colors: ::std::vec::Vec<std_msgs::ColorRGBA>,
+        #[doc = " Texture resource is a special URI that can either reference a texture file in"]
+        #[doc = " a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]"]
+        #[doc = " or an embedded texture via a string matching the format:"]
+        #[doc = " \"embedded://texture_name\""]
+        pub r#texture_resource: ::std::string::String,
+        #[doc = " An image to be loaded into the rendering engine as the texture for this marker."]
+        #[doc = " This will be used iff texture_resource is set to embedded."]
+        pub r#texture: sensor_msgs::CompressedImage,
+        #[doc = " Location of each vertex within the texture; in the range: [0.0-1.0]"]
+        pub r#uv_coordinates: ::std::vec::Vec<self::UVCoordinate>,
+        #[doc = " Only used for text markers"]
+        pub r#text: ::std::string::String,
+        #[doc = " Only used for MESH_RESOURCE markers."]
+        #[doc = " Similar to texture_resource, mesh_resource uses resource retriever to load a mesh."]
+        #[doc = " Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,"]
+        #[doc = " use the following format for mesh_resource:"]
+        #[doc = " \"embedded://mesh_name\""]
+        pub r#mesh_resource: ::std::string::String,
+        pub r#mesh_file: self::MeshFile,
+        pub r#mesh_use_embedded_materials: bool,
+    }
+    impl ::roslibrust::RosMessageType for Marker {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/Marker";
+        const MD5SUM: &'static str = "56c6324983a404ead7a426609371feed";
+        const DEFINITION: &'static str = r####"# See:
 #  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
 #  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
 #
@@ -14022,11 +10583,16 @@ string mesh_resource
 MeshFile mesh_file
 bool mesh_use_embedded_materials
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -14043,14 +10609,6 @@ float64 z
 MSG: geometry_msgs/Quaternion
 # This represents an orientation in free space in quaternion form.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
 float64 x 0
 float64 y 0
 float64 z 0
@@ -14067,6 +10625,12 @@ float64 x
 float64 y
 float64 z
 ================================================================================
+MSG: std_msgs/ColorRGBA
+float32 r
+float32 g
+float32 b
+float32 a
+================================================================================
 MSG: sensor_msgs/CompressedImage
 # This message contains a compressed image.
 
@@ -14083,44 +10647,6 @@ string format                # Specifies the format of the data
 
 uint8[] data                 # Compressed image buffer
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
 MSG: visualization_msgs/UVCoordinate
 # Location of the pixel as a ratio of the width of a 2D texture.
 # Values should be in range: [0.0-1.0].
@@ -14135,13 +10661,52 @@ MSG: visualization_msgs/MeshFile
 string filename
 
 # This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
+uint8[] data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_45b13ccf791f225962bf74e746f9644518855d783a6f42ba0cc14fde2b4f3ce0";
+    }
+    #[allow(unused)]
+    impl Marker {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl Marker {
+        pub const r#ARROW: i32 = 0i32;
+        pub const r#CUBE: i32 = 1i32;
+        pub const r#SPHERE: i32 = 2i32;
+        pub const r#CYLINDER: i32 = 3i32;
+        pub const r#LINE_STRIP: i32 = 4i32;
+        pub const r#LINE_LIST: i32 = 5i32;
+        pub const r#CUBE_LIST: i32 = 6i32;
+        pub const r#SPHERE_LIST: i32 = 7i32;
+        pub const r#POINTS: i32 = 8i32;
+        pub const r#TEXT_VIEW_FACING: i32 = 9i32;
+        pub const r#MESH_RESOURCE: i32 = 10i32;
+        pub const r#TRIANGLE_LIST: i32 = 11i32;
+        pub const r#ADD: i32 = 0i32;
+        pub const r#MODIFY: i32 = 0i32;
+        pub const r#DELETE: i32 = 2i32;
+        pub const r#DELETEALL: i32 = 3i32;
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct MarkerArray {
+        pub r#markers: ::std::vec::Vec<self::Marker>,
+    }
+    impl ::roslibrust::RosMessageType for MarkerArray {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/MarkerArray";
+        const MD5SUM: &'static str = "11e38f15427197858cf46456867167bd";
+        const DEFINITION: &'static str = r####"Marker[] markers
 ================================================================================
 MSG: visualization_msgs/Marker
 # See:
@@ -14225,11 +10790,16 @@ string mesh_resource
 MeshFile mesh_file
 bool mesh_use_embedded_materials
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
+MSG: std_msgs/Header
+# Standard metadata for higher-level stamped data types.
+# This is generally used to communicate timestamped data
+# in a particular coordinate frame.
+
+# Two-integer timestamp that is expressed as seconds and nanoseconds.
+builtin_interfaces/Time stamp
+
+# Transform frame with which this data is associated.
+string frame_id
 ================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
@@ -14246,14 +10816,6 @@ float64 z
 MSG: geometry_msgs/Quaternion
 # This represents an orientation in free space in quaternion form.
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
 float64 x 0
 float64 y 0
 float64 z 0
@@ -14270,49 +10832,33 @@ float64 x
 float64 y
 float64 z
 ================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
-
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
-
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
-
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
 MSG: std_msgs/ColorRGBA
 float32 r
 float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+float32 b
+float32 a
+================================================================================
+MSG: sensor_msgs/CompressedImage
+# This message contains a compressed image.
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+std_msgs/Header header # Header timestamp should be acquisition time of image
+                             # Header frame_id should be optical frame of camera
+                             # origin of frame should be optical center of cameara
+                             # +x should point to the right in the image
+                             # +y should point down in the image
+                             # +z should point into to plane of the image
 
-# Transform frame with which this data is associated.
-string frame_id
+string format                # Specifies the format of the data
+                             #   Acceptable values:
+                             #     jpeg, png, tiff
+
+uint8[] data                 # Compressed image buffer
+================================================================================
+MSG: visualization_msgs/UVCoordinate
+# Location of the pixel as a ratio of the width of a 2D texture.
+# Values should be in range: [0.0-1.0].
+float32 u
+float32 v
 ================================================================================
 MSG: visualization_msgs/MeshFile
 # Used to send raw mesh files.
@@ -14322,16 +10868,73 @@ MSG: visualization_msgs/MeshFile
 string filename
 
 # This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MenuEntry
-# MenuEntry message.
+uint8[] data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_86cb8800b6fb05b5eff1abd7a56f62a5641d3ae9a1c29e78e67e704f1d067dcf";
+    }
+    #[allow(unused)]
+    impl MarkerArray {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[doc = " MenuEntry message."]
+    #[doc = ""]
+    #[doc = " Each InteractiveMarker message has an array of MenuEntry messages."]
+    #[doc = " A collection of MenuEntries together describe a"]
+    #[doc = " menu/submenu/subsubmenu/etc tree, though they are stored in a flat"]
+    #[doc = " array.  The tree structure is represented by giving each menu entry"]
+    #[doc = " an ID number and a \"parent_id\" field.  Top-level entries are the"]
+    #[doc = " ones with parent_id = 0.  Menu entries are ordered within their"]
+    #[doc = " level the same way they are ordered in the containing array.  Parent"]
+    #[doc = " entries must appear before their children."]
+    #[doc = ""]
+    #[doc = " Example:"]
+    #[doc = " - id = 3"]
+    #[doc = " parent_id = 0"]
+    #[doc = " title = \"fun\""]
+    #[doc = " - id = 2"]
+    #[doc = " parent_id = 0"]
+    #[doc = " title = \"robot\""]
+    #[doc = " - id = 4"]
+    #[doc = " parent_id = 2"]
+    #[doc = " title = \"pr2\""]
+    #[doc = " - id = 5"]
+    #[doc = " parent_id = 2"]
+    #[doc = " title = \"turtle\""]
+    #[doc = ""]
+    #[doc = " Gives a menu tree like this:"]
+    #[doc = " - fun"]
+    #[doc = " - robot"]
+    #[doc = " - pr2"]
+    #[doc = " - turtle"]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct MenuEntry {
+        #[doc = " ID is a number for each menu entry.  Must be unique within the"]
+        #[doc = " control, and should never be 0."]
+        pub r#id: u32,
+        #[doc = " ID of the parent of this menu entry, if it is a submenu.  If this"]
+        #[doc = " menu entry is a top-level entry, set parent_id to 0."]
+        pub r#parent_id: u32,
+        #[doc = " menu / entry title"]
+        pub r#title: ::std::string::String,
+        #[doc = " Arguments to command indicated by command_type (below)"]
+        pub r#command: ::std::string::String,
+        pub r#command_type: u8,
+    }
+    impl ::roslibrust::RosMessageType for MenuEntry {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/MenuEntry";
+        const MD5SUM: &'static str = "b90ec63024573de83b57aa93eb39be2d";
+        const DEFINITION: &'static str = r####"# MenuEntry message.
 #
 # Each InteractiveMarker message has an array of MenuEntry messages.
 # A collection of MenuEntries together describe a
@@ -14384,180 +10987,180 @@ string command
 uint8 FEEDBACK=0
 uint8 ROSRUN=1
 uint8 ROSLAUNCH=2
-uint8 command_type
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
+uint8 command_type"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_22170c387c70fd4236232ec902de8604e72ff027342c7c0f28ad9f68c64c51d6";
+    }
+    #[allow(unused)]
+    impl MenuEntry {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(unused)]
+    impl MenuEntry {
+        #[doc = " Command_type stores the type of response desired when this menu"]
+        #[doc = " entry is clicked."]
+        #[doc = " FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id."]
+        #[doc = " ROSRUN: execute \"rosrun\" with arguments given in the command field (above)."]
+        #[doc = " ROSLAUNCH: execute \"roslaunch\" with arguments given in the command field (above)."]
+        pub const r#FEEDBACK: u8 = 0u8;
+        pub const r#ROSRUN: u8 = 1u8;
+        pub const r#ROSLAUNCH: u8 = 2u8;
+    }
+    #[doc = " Used to send raw mesh files."]
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct MeshFile {
+        #[doc = " The filename is used for both debug purposes and to provide a file extension"]
+        #[doc = " for whatever parser is used."]
+        pub r#filename: ::std::string::String,
+        #[doc = " This stores the raw text of the mesh file."]
+        #[serde(with = "::roslibrust::codegen::serde_bytes")]
+        pub r#data: ::std::vec::Vec<u8>,
+    }
+    impl ::roslibrust::RosMessageType for MeshFile {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/MeshFile";
+        const MD5SUM: &'static str = "39f264648e441626a1045a7d9ef1ba17";
+        const DEFINITION: &'static str = r####"# Used to send raw mesh files.
 
 # The filename is used for both debug purposes and to provide a file extension
 # for whatever parser is used.
 string filename
 
 # This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
+uint8[] data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_7710ece15a148fb7c9b546364cfb215bb06098087bd6394fe5b73a493508f8c4";
+    }
+    #[allow(unused)]
+    impl MeshFile {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct UVCoordinate {
+        #[doc = " Location of the pixel as a ratio of the width of a 2D texture."]
+        #[doc = " Values should be in range: [0.0-1.0]."]
+        pub r#u: f32,
+        pub r#v: f32,
+    }
+    impl ::roslibrust::RosMessageType for UVCoordinate {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/UVCoordinate";
+        const MD5SUM: &'static str = "4f5254e0e12914c461d4b17a0cd07f7f";
+        const DEFINITION: &'static str = r####"# Location of the pixel as a ratio of the width of a 2D texture.
 # Values should be in range: [0.0-1.0].
 float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/InteractiveMarkerControl
-# Represents a control that is to be displayed together with an interactive marker
-
-# Identifying string for this control.
-# You need to assign a unique value to this to receive feedback from the GUI
-# on what actions the user performs on this control (e.g. a button click).
-string name
-
-
-# Defines the local coordinate frame (relative to the pose of the parent
-# interactive marker) in which is being rotated and translated.
-# Default: Identity
-geometry_msgs/Quaternion orientation
-
-
-# Orientation mode: controls how orientation changes.
-# INHERIT: Follow orientation of interactive marker
-# FIXED: Keep orientation fixed at initial state
-# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
-uint8 INHERIT = 0
-uint8 FIXED = 1
-uint8 VIEW_FACING = 2
-
-uint8 orientation_mode
-
-# Interaction mode for this control
-#
-# NONE: This control is only meant for visualization; no context menu.
-# MENU: Like NONE, but right-click menu is active.
-# BUTTON: Element can be left-clicked.
-# MOVE_AXIS: Translate along local x-axis.
-# MOVE_PLANE: Translate in local y-z plane.
-# ROTATE_AXIS: Rotate around local x-axis.
-# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
-uint8 NONE = 0
-uint8 MENU = 1
-uint8 BUTTON = 2
-uint8 MOVE_AXIS = 3
-uint8 MOVE_PLANE = 4
-uint8 ROTATE_AXIS = 5
-uint8 MOVE_ROTATE = 6
-# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
-# MOVE_3D: Translate freely in 3D space.
-# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
-# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
-uint8 MOVE_3D = 7
-uint8 ROTATE_3D = 8
-uint8 MOVE_ROTATE_3D = 9
-
-uint8 interaction_mode
-
-
-# If true, the contained markers will also be visible
-# when the gui is not in interactive mode.
-bool always_visible
-
-
-# Markers to be displayed as custom visual representation.
-# Leave this empty to use the default control handles.
-#
-# Note:
-# - The markers can be defined in an arbitrary coordinate frame,
-#   but will be transformed into the local frame of the interactive marker.
-# - If the header of a marker is empty, its pose will be interpreted as
-#   relative to the pose of the parent interactive marker.
-Marker[] markers
-
-
-# In VIEW_FACING mode, set this to true if you don't want the markers
-# to be aligned with the camera view point. The markers will show up
-# as in INHERIT mode.
-bool independent_marker_orientation
-
-
-# Short description (< 40 characters) of what this control does,
-# e.g. "Move the robot".
-# Default: A generic description based on the interaction mode
-string description
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+float32 v"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_f27f7ed21fe360c6066944f856b801a0c0d1e94e815b6886444b42d90b196a26";
+    }
+    #[allow(unused)]
+    impl UVCoordinate {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct GetInteractiveMarkersRequest {}
+    impl ::roslibrust::RosMessageType for GetInteractiveMarkersRequest {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/GetInteractiveMarkersRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = r####""####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_7fa7e0830e3cbe9c15927a29fdc09fedcd9a86757e375f29ce6b0744cb0b6905";
+    }
+    #[allow(unused)]
+    impl GetInteractiveMarkersRequest {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    #[allow(non_snake_case)]
+    #[derive(
+        :: roslibrust :: codegen :: Deserialize,
+        :: roslibrust :: codegen :: Serialize,
+        :: roslibrust :: codegen :: SmartDefault,
+        Debug,
+        Clone,
+        PartialEq,
+    )]
+    #[serde(crate = "::roslibrust::codegen::serde")]
+    pub struct GetInteractiveMarkersResponse {
+        #[doc = " Sequence number."]
+        #[doc = " Set to the sequence number of the latest update message"]
+        #[doc = " at the time the server received the request."]
+        #[doc = " Clients use this to detect if any updates were missed."]
+        pub r#sequence_number: u64,
+        #[doc = " All interactive markers provided by the server."]
+        pub r#markers: ::std::vec::Vec<self::InteractiveMarker>,
+    }
+    impl ::roslibrust::RosMessageType for GetInteractiveMarkersResponse {
+        const ROS_TYPE_NAME: &'static str = "visualization_msgs/GetInteractiveMarkersResponse";
+        const MD5SUM: &'static str = "923b76ef2c497d4ff5f83a061d424d3b";
+        const DEFINITION: &'static str = r####"# Sequence number.
+# Set to the sequence number of the latest update message
+# at the time the server received the request.
+# Clients use this to detect if any updates were missed.
+uint64 sequence_number
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
+# All interactive markers provided by the server.
+InteractiveMarker[] markers
 ================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
-
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+MSG: visualization_msgs/InteractiveMarker
+# Time/frame info.
+# If header.time is set to 0, the marker will be retransformed into
+# its frame on each timestep. You will receive the pose feedback
+# in the same frame.
+# Otherwise, you might receive feedback in a different frame.
+# For rviz, this will be the current 'fixed frame' set by the user.
+std_msgs/Header header
 
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
+# Initial pose. Also, defines the pivot point for rotations.
+geometry_msgs/Pose pose
 
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
+# Identifying string. Must be globally unique in
+# the topic that this message is sent through.
+string name
 
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+# Short description (< 40 characters).
+string description
 
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# Scale to be used for default controls (default=1).
+float32 scale
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
+# All menu and submenu entries associated with this marker.
+MenuEntry[] menu_entries
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
+# List of controls displayed for this marker.
+InteractiveMarkerControl[] controls
 ================================================================================
 MSG: std_msgs/Header
 # Standard metadata for higher-level stamped data types.
@@ -14570,94 +11173,6 @@ builtin_interfaces/Time stamp
 # Transform frame with which this data is associated.
 string frame_id
 ================================================================================
-MSG: visualization_msgs/Marker
-# See:
-#  - http://www.ros.org/wiki/rviz/DisplayTypes/Marker
-#  - http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes
-#
-# for more information on using this message with rviz.
-
-int32 ARROW=0
-int32 CUBE=1
-int32 SPHERE=2
-int32 CYLINDER=3
-int32 LINE_STRIP=4
-int32 LINE_LIST=5
-int32 CUBE_LIST=6
-int32 SPHERE_LIST=7
-int32 POINTS=8
-int32 TEXT_VIEW_FACING=9
-int32 MESH_RESOURCE=10
-int32 TRIANGLE_LIST=11
-
-int32 ADD=0
-int32 MODIFY=0
-int32 DELETE=2
-int32 DELETEALL=3
-
-# Header for timestamp and frame id.
-std_msgs/Header header
-# Namespace in which to place the object.
-# Used in conjunction with id to create a unique name for the object.
-string ns
-# Object ID used in conjunction with the namespace for manipulating and deleting the object later.
-int32 id
-# Type of object.
-int32 type
-# Action to take; one of:
-#  - 0 add/modify an object
-#  - 1 (deprecated)
-#  - 2 deletes an object (with the given ns and id)
-#  - 3 deletes all objects (or those with the given ns if any)
-int32 action
-# Pose of the object with respect the frame_id specified in the header.
-geometry_msgs/Pose pose
-# Scale of the object; 1,1,1 means default (usually 1 meter square).
-geometry_msgs/Vector3 scale
-# Color of the object; in the range: [0.0-1.0]
-std_msgs/ColorRGBA color
-# How long the object should last before being automatically deleted.
-# 0 indicates forever.
-builtin_interfaces/Duration lifetime
-# If this marker should be frame-locked, i.e. retransformed into its frame every timestep.
-bool frame_locked
-
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-geometry_msgs/Point[] points
-# Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, etc.)
-# The number of colors provided must either be 0 or equal to the number of points provided.
-# NOTE: alpha is not yet used
-std_msgs/ColorRGBA[] colors
-
-# Texture resource is a special URI that can either reference a texture file in
-# a format acceptable to (resource retriever)[https://index.ros.org/p/resource_retriever/]
-# or an embedded texture via a string matching the format:
-#   "embedded://texture_name"
-string texture_resource
-# An image to be loaded into the rendering engine as the texture for this marker.
-# This will be used iff texture_resource is set to embedded.
-sensor_msgs/CompressedImage texture
-# Location of each vertex within the texture; in the range: [0.0-1.0]
-UVCoordinate[] uv_coordinates
-
-# Only used for text markers
-string text
-
-# Only used for MESH_RESOURCE markers.
-# Similar to texture_resource, mesh_resource uses resource retriever to load a mesh.
-# Optionally, a mesh file can be sent in-message via the mesh_file field. If doing so,
-# use the following format for mesh_resource:
-#   "embedded://mesh_name"
-string mesh_resource
-MeshFile mesh_file
-bool mesh_use_embedded_materials
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
 MSG: geometry_msgs/Pose
 # A representation of pose in free space, composed of position and orientation.
 
@@ -14678,100 +11193,140 @@ float64 y 0
 float64 z 0
 float64 w 1
 ================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
+MSG: visualization_msgs/MenuEntry
+# MenuEntry message.
+#
+# Each InteractiveMarker message has an array of MenuEntry messages.
+# A collection of MenuEntries together describe a
+# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
+# array.  The tree structure is represented by giving each menu entry
+# an ID number and a "parent_id" field.  Top-level entries are the
+# ones with parent_id = 0.  Menu entries are ordered within their
+# level the same way they are ordered in the containing array.  Parent
+# entries must appear before their children.
+#
+# Example:
+# - id = 3
+#   parent_id = 0
+#   title = "fun"
+# - id = 2
+#   parent_id = 0
+#   title = "robot"
+# - id = 4
+#   parent_id = 2
+#   title = "pr2"
+# - id = 5
+#   parent_id = 2
+#   title = "turtle"
+#
+# Gives a menu tree like this:
+#  - fun
+#  - robot
+#    - pr2
+#    - turtle
 
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Vector3
-# This represents a vector in free space.
+# ID is a number for each menu entry.  Must be unique within the
+# control, and should never be 0.
+uint32 id
 
-# This is semantically different than a point.
-# A vector is always anchored at the origin.
-# When a transform is applied to a vector, only the rotational component is applied.
+# ID of the parent of this menu entry, if it is a submenu.  If this
+# menu entry is a top-level entry, set parent_id to 0.
+uint32 parent_id
 
-float64 x
-float64 y
-float64 z
+# menu / entry title
+string title
+
+# Arguments to command indicated by command_type (below)
+string command
+
+# Command_type stores the type of response desired when this menu
+# entry is clicked.
+# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
+# ROSRUN: execute "rosrun" with arguments given in the command field (above).
+# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
+uint8 FEEDBACK=0
+uint8 ROSRUN=1
+uint8 ROSLAUNCH=2
+uint8 command_type
 ================================================================================
-MSG: sensor_msgs/CompressedImage
-# This message contains a compressed image.
+MSG: visualization_msgs/InteractiveMarkerControl
+# Represents a control that is to be displayed together with an interactive marker
+
+# Identifying string for this control.
+# You need to assign a unique value to this to receive feedback from the GUI
+# on what actions the user performs on this control (e.g. a button click).
+string name
+
+
+# Defines the local coordinate frame (relative to the pose of the parent
+# interactive marker) in which is being rotated and translated.
+# Default: Identity
+geometry_msgs/Quaternion orientation
+
+
+# Orientation mode: controls how orientation changes.
+# INHERIT: Follow orientation of interactive marker
+# FIXED: Keep orientation fixed at initial state
+# VIEW_FACING: Align y-z plane with screen (x: forward, y:left, z:up).
+uint8 INHERIT = 0
+uint8 FIXED = 1
+uint8 VIEW_FACING = 2
 
-std_msgs/Header header # Header timestamp should be acquisition time of image
-                             # Header frame_id should be optical frame of camera
-                             # origin of frame should be optical center of cameara
-                             # +x should point to the right in the image
-                             # +y should point down in the image
-                             # +z should point into to plane of the image
+uint8 orientation_mode
 
-string format                # Specifies the format of the data
-                             #   Acceptable values:
-                             #     jpeg, png, tiff
+# Interaction mode for this control
+#
+# NONE: This control is only meant for visualization; no context menu.
+# MENU: Like NONE, but right-click menu is active.
+# BUTTON: Element can be left-clicked.
+# MOVE_AXIS: Translate along local x-axis.
+# MOVE_PLANE: Translate in local y-z plane.
+# ROTATE_AXIS: Rotate around local x-axis.
+# MOVE_ROTATE: Combines MOVE_PLANE and ROTATE_AXIS.
+uint8 NONE = 0
+uint8 MENU = 1
+uint8 BUTTON = 2
+uint8 MOVE_AXIS = 3
+uint8 MOVE_PLANE = 4
+uint8 ROTATE_AXIS = 5
+uint8 MOVE_ROTATE = 6
+# "3D" interaction modes work with the mouse+SHIFT+CTRL or with 3D cursors.
+# MOVE_3D: Translate freely in 3D space.
+# ROTATE_3D: Rotate freely in 3D space about the origin of parent frame.
+# MOVE_ROTATE_3D: Full 6-DOF freedom of translation and rotation about the cursor origin.
+uint8 MOVE_3D = 7
+uint8 ROTATE_3D = 8
+uint8 MOVE_ROTATE_3D = 9
 
-uint8[] data                 # Compressed image buffer
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+uint8 interaction_mode
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
+# If true, the contained markers will also be visible
+# when the gui is not in interactive mode.
+bool always_visible
 
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
 
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
+# Markers to be displayed as custom visual representation.
+# Leave this empty to use the default control handles.
+#
+# Note:
+# - The markers can be defined in an arbitrary coordinate frame,
+#   but will be transformed into the local frame of the interactive marker.
+# - If the header of a marker is empty, its pose will be interpreted as
+#   relative to the pose of the parent interactive marker.
+Marker[] markers
 
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
 
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
+# In VIEW_FACING mode, set this to true if you don't want the markers
+# to be aligned with the camera view point. The markers will show up
+# as in INHERIT mode.
+bool independent_marker_orientation
 
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
 
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v
+# Short description (< 40 characters) of what this control does,
+# e.g. "Move the robot".
+# Default: A generic description based on the interaction mode
+string description
 ================================================================================
 MSG: visualization_msgs/Marker
 # See:
@@ -14855,40 +11410,6 @@ string mesh_resource
 MeshFile mesh_file
 bool mesh_use_embedded_materials
 ================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Pose
-# A representation of pose in free space, composed of position and orientation.
-
-Point position
-Quaternion orientation
-================================================================================
-MSG: geometry_msgs/Point
-# This contains the position of a point in free space
-float64 x
-float64 y
-float64 z
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
-MSG: geometry_msgs/Quaternion
-# This represents an orientation in free space in quaternion form.
-
-float64 x 0
-float64 y 0
-float64 z 0
-float64 w 1
-================================================================================
 MSG: geometry_msgs/Vector3
 # This represents a vector in free space.
 
@@ -14900,6 +11421,12 @@ float64 x
 float64 y
 float64 z
 ================================================================================
+MSG: std_msgs/ColorRGBA
+float32 r
+float32 g
+float32 b
+float32 a
+================================================================================
 MSG: sensor_msgs/CompressedImage
 # This message contains a compressed image.
 
@@ -14916,106 +11443,12 @@ string format                # Specifies the format of the data
 
 uint8[] data                 # Compressed image buffer
 ================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: std_msgs/ColorRGBA
-float32 r
-float32 g
-float32 b
-float32 a
-================================================================================
-MSG: std_msgs/Header
-# Standard metadata for higher-level stamped data types.
-# This is generally used to communicate timestamped data
-# in a particular coordinate frame.
-
-# Two-integer timestamp that is expressed as seconds and nanoseconds.
-builtin_interfaces/Time stamp
-
-# Transform frame with which this data is associated.
-string frame_id
-================================================================================
-MSG: visualization_msgs/MeshFile
-# Used to send raw mesh files.
-
-# The filename is used for both debug purposes and to provide a file extension
-# for whatever parser is used.
-string filename
-
-# This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
 MSG: visualization_msgs/UVCoordinate
 # Location of the pixel as a ratio of the width of a 2D texture.
 # Values should be in range: [0.0-1.0].
 float32 u
 float32 v
 ================================================================================
-MSG: visualization_msgs/MenuEntry
-# MenuEntry message.
-#
-# Each InteractiveMarker message has an array of MenuEntry messages.
-# A collection of MenuEntries together describe a
-# menu/submenu/subsubmenu/etc tree, though they are stored in a flat
-# array.  The tree structure is represented by giving each menu entry
-# an ID number and a "parent_id" field.  Top-level entries are the
-# ones with parent_id = 0.  Menu entries are ordered within their
-# level the same way they are ordered in the containing array.  Parent
-# entries must appear before their children.
-#
-# Example:
-# - id = 3
-#   parent_id = 0
-#   title = "fun"
-# - id = 2
-#   parent_id = 0
-#   title = "robot"
-# - id = 4
-#   parent_id = 2
-#   title = "pr2"
-# - id = 5
-#   parent_id = 2
-#   title = "turtle"
-#
-# Gives a menu tree like this:
-#  - fun
-#  - robot
-#    - pr2
-#    - turtle
-
-# ID is a number for each menu entry.  Must be unique within the
-# control, and should never be 0.
-uint32 id
-
-# ID of the parent of this menu entry, if it is a submenu.  If this
-# menu entry is a top-level entry, set parent_id to 0.
-uint32 parent_id
-
-# menu / entry title
-string title
-
-# Arguments to command indicated by command_type (below)
-string command
-
-# Command_type stores the type of response desired when this menu
-# entry is clicked.
-# FEEDBACK: send an InteractiveMarkerFeedback message with menu_entry_id set to this entry's id.
-# ROSRUN: execute "rosrun" with arguments given in the command field (above).
-# ROSLAUNCH: execute "roslaunch" with arguments given in the command field (above).
-uint8 FEEDBACK=0
-uint8 ROSRUN=1
-uint8 ROSLAUNCH=2
-uint8 command_type
-================================================================================
 MSG: visualization_msgs/MeshFile
 # Used to send raw mesh files.
 
@@ -15024,19 +11457,23 @@ MSG: visualization_msgs/MeshFile
 string filename
 
 # This stores the raw text of the mesh file.
-uint8[] data
-================================================================================
-MSG: visualization_msgs/UVCoordinate
-# Location of the pixel as a ratio of the width of a 2D texture.
-# Values should be in range: [0.0-1.0].
-float32 u
-float32 v"####;
+uint8[] data"####;
+        const TYPE_HASH: &'static str =
+            "RIHS01_414620cf7471b5469b159989af34edd1ed9276be592e43d1b7544204f4177302";
+    }
+    #[allow(unused)]
+    impl GetInteractiveMarkersResponse {
+        pub fn new() -> Self {
+            Self::default()
+        }
     }
     #[allow(dead_code)]
     pub struct GetInteractiveMarkers {}
     impl ::roslibrust::RosServiceType for GetInteractiveMarkers {
         const ROS_SERVICE_NAME: &'static str = "visualization_msgs/GetInteractiveMarkers";
         const MD5SUM: &'static str = "923b76ef2c497d4ff5f83a061d424d3b";
+        const TYPE_HASH: &'static str =
+            "RIHS01_bfdfaa861f4a8422b4ec3bd02c6681e4a45a83bdc2913d8facdf923d8ad1376a";
         type Request = GetInteractiveMarkersRequest;
         type Response = GetInteractiveMarkersResponse;
     }
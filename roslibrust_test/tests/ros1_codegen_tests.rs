@@ -48,3 +48,22 @@ fn test_gendeps_in_message_definition() {
     assert!(geometry_msgs::PointStamped::DEFINITION.contains("MSG: geometry_msgs/Point"));
     assert!(geometry_msgs::PointStamped::DEFINITION.contains("MSG: std_msgs/Header"));
 }
+
+#[test]
+fn test_gendeps_order_and_uniqueness() {
+    // Each dependency is included once with its own definition, in depth first order of first use like gendeps
+    let sections: Vec<&str> = geometry_msgs::PoseWithCovarianceStamped::DEFINITION
+        .lines()
+        .filter_map(|line| line.strip_prefix("MSG: "))
+        .collect();
+    assert_eq!(
+        sections,
+        vec![
+            "std_msgs/Header",
+            "geometry_msgs/PoseWithCovariance",
+            "geometry_msgs/Pose",
+            "geometry_msgs/Point",
+            "geometry_msgs/Quaternion"
+        ]
+    );
+}
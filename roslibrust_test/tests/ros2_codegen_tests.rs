@@ -9,6 +9,13 @@ fn test_defaults() {
     assert_eq!(x.samples, vec![-200, -100, 0, 100, 200]);
     assert_eq!(x.s_vec, vec!["hello", "world"]);
     assert_eq!(x.f_samples, vec![-200.0, -1.0, 0.0]);
+    assert_eq!(x.s_vec_2, vec!["hello", "world"]);
+    assert_eq!(x.single_quote, "Jane Doe");
+    assert_eq!(x.covariance, [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(x.s_fixed, ["left", "right"]);
+
+    // new() should honor the same defaults
+    assert_eq!(test_msgs::Defaults::new(), x);
 }
 
 #[test]
@@ -23,3 +30,83 @@ fn fixed_sized_arrays() {
     let x: geometry_msgs::TwistWithCovariance = Default::default();
     let _y: [f64; 36] = x.covariance;
 }
+
+#[test]
+fn fixed_sized_arrays_wire_format() {
+    let mut x = test_msgs::FixedArrays::new();
+    // Arrays larger than 32 of non Copy types must still have a Default
+    assert_eq!(x.names.len(), 33);
+    assert_eq!(x.defaults[1].x, 42);
+    x.uuid[15] = 7;
+    x.covariance[35] = -1.0;
+    x.names[32] = "last".to_string();
+
+    // Fixed size arrays are not length prefixed on the wire
+    let bytes = roslibrust_serde_rosmsg::to_vec(&x).unwrap();
+    let defaults_len = roslibrust_serde_rosmsg::to_vec(&x.defaults[0])
+        .unwrap()
+        .len()
+        - 4;
+    assert_eq!(
+        bytes.len(),
+        4 + 16 + 36 * 8 + (33 * 4 + "last".len()) + 2 * defaults_len
+    );
+    let y: test_msgs::FixedArrays = roslibrust_serde_rosmsg::from_slice(&bytes).unwrap();
+    assert_eq!(x, y);
+}
+
+#[test]
+fn bounded_fields_are_validated() {
+    let mut x = test_msgs::Bounded {
+        name: "hello".to_string(),
+        samples: vec![1, 2, 3],
+        data: vec![0; 4],
+        tags: vec!["a".to_string(), "abc".to_string()],
+        codes: vec!["xyz".to_string(); 10],
+        pair: ["l".to_string(), "r".to_string()],
+    };
+    // Bounded fields are serialized the same as unbounded ones
+    let bytes = roslibrust_serde_rosmsg::to_vec(&x).unwrap();
+    let y: test_msgs::Bounded = roslibrust_serde_rosmsg::from_slice(&bytes).unwrap();
+    assert_eq!(x, y);
+
+    x.name = "too long".to_string();
+    assert!(roslibrust_serde_rosmsg::to_vec(&x).is_err());
+    x.name.clear();
+    x.samples.push(4);
+    assert!(roslibrust_serde_rosmsg::to_vec(&x).is_err());
+    x.samples.clear();
+    x.pair[1] = "right".to_string();
+    assert!(roslibrust_serde_rosmsg::to_vec(&x).is_err());
+
+    // Over length data is also rejected when received
+    let bytes = roslibrust_serde_rosmsg::to_vec(&std_msgs::String {
+        data: "too long".to_string(),
+    })
+    .unwrap();
+    #[derive(roslibrust::codegen::Deserialize, Debug)]
+    #[serde(crate = "roslibrust::codegen::serde")]
+    struct Name {
+        #[serde(with = "roslibrust::codegen::BoundedString::<5>")]
+        _data: String,
+    }
+    assert!(roslibrust_serde_rosmsg::from_slice::<Name>(&bytes).is_err());
+}
+
+#[test]
+fn type_hashes_match_rosidl() {
+    use roslibrust::{RosMessageType, RosServiceType};
+    // Hashes generated by rosidl for the same types
+    assert_eq!(
+        std_msgs::String::TYPE_HASH,
+        "RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18"
+    );
+    assert_eq!(
+        std_msgs::Header::TYPE_HASH,
+        "RIHS01_f49fb3ae2cf070f793645ff749683ac6b06203e41c891e17701b1cb597ce6a01"
+    );
+    assert!(std_srvs::Trigger::TYPE_HASH.starts_with("RIHS01_"));
+    assert_ne!(std_srvs::Trigger::TYPE_HASH, std_srvs::SetBool::TYPE_HASH);
+    // ROS1 types have no type hash
+    assert_eq!(roslibrust_test::ros1::std_msgs::Header::TYPE_HASH, "");
+}
@@ -0,0 +1,274 @@
+//! Key/value metadata sent alongside messages as zenoh attachments, used to propagate W3C trace-context between
+//! processes, see [crate::ZenohPublisher::publish_with_attachment] and [crate::ZenohSubscriber::next_with_attachment].
+
+use roslibrust_common::{Error, Result};
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, Hasher};
+
+/// The key trace-context is stored under, as named by the W3C trace-context specification
+pub const TRACEPARENT: &str = "traceparent";
+/// The key vendor specific trace state is stored under, as named by the W3C trace-context specification
+pub const TRACESTATE: &str = "tracestate";
+
+/// String key/values attached to a published message.
+///
+/// Encoded as a sequence of key/value strings, each prefixed with its length as a little endian u32.
+/// zenoh-bridge-ros1 ignores attachments, so they are only seen by other zenoh participants.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Attachment {
+    values: BTreeMap<String, String>,
+}
+
+impl Attachment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an attachment carrying `context` as its `traceparent`
+    pub fn with_trace(context: &TraceContext) -> Self {
+        let mut attachment = Self::new();
+        attachment.set_trace(context);
+        attachment
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.values.insert(key.into(), value.into())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.values.remove(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The trace-context of the attachment, None if it has no valid `traceparent`
+    pub fn trace(&self) -> Option<TraceContext> {
+        self.get(TRACEPARENT)?.parse().ok()
+    }
+
+    pub fn set_trace(&mut self, context: &TraceContext) {
+        self.insert(TRACEPARENT, context.to_string());
+    }
+
+    /// Creates a span for handling a received message, its `trace_id` and `parent_id` fields recording the
+    /// trace-context of the attachment if any.
+    #[cfg(feature = "tracing")]
+    pub fn span(&self, topic: &str) -> tracing::Span {
+        match self.trace() {
+            Some(context) => tracing::info_span!(
+                "zenoh_receive",
+                topic,
+                trace_id = %hex::encode(context.trace_id),
+                parent_id = %hex::encode(context.parent_id),
+            ),
+            None => tracing::info_span!(
+                "zenoh_receive",
+                topic,
+                trace_id = tracing::field::Empty,
+                parent_id = tracing::field::Empty,
+            ),
+        }
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        for (key, value) in &self.values {
+            for s in [key, value] {
+                bytes.extend_from_slice(&(s.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(s.as_bytes());
+            }
+        }
+        bytes
+    }
+
+    pub(crate) fn from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        fn next<'a>(bytes: &mut &'a [u8]) -> Option<&'a [u8]> {
+            let len = u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?) as usize;
+            let s = bytes.get(4..4 + len)?;
+            *bytes = &bytes[4 + len..];
+            Some(s)
+        }
+        let invalid = || Error::SerializationError("Invalid attachment".to_string());
+        let mut attachment = Self::new();
+        while !bytes.is_empty() {
+            let key = next(&mut bytes).ok_or_else(invalid)?;
+            let value = next(&mut bytes).ok_or_else(invalid)?;
+            attachment.insert(
+                std::str::from_utf8(key).map_err(|_| invalid())?,
+                std::str::from_utf8(value).map_err(|_| invalid())?,
+            );
+        }
+        Ok(attachment)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Attachment {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            values: iter
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+}
+
+/// A W3C trace-context `traceparent`, see <https://www.w3.org/TR/trace-context/#traceparent-header>.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TraceContext {
+    pub trace_id: [u8; 16],
+    /// The id of the span the message was sent from
+    pub parent_id: [u8; 8],
+    pub flags: u8,
+}
+
+impl TraceContext {
+    /// Set in [TraceContext::flags] when the caller recorded the trace
+    pub const SAMPLED: u8 = 0x01;
+
+    /// Starts a new sampled trace with random ids
+    pub fn new() -> Self {
+        let mut trace_id = [0; 16];
+        trace_id[..8].copy_from_slice(&random_id());
+        trace_id[8..].copy_from_slice(&random_id());
+        Self {
+            trace_id,
+            parent_id: random_id(),
+            flags: Self::SAMPLED,
+        }
+    }
+
+    /// The context of a new span within the same trace, to forward with messages sent while handling this one
+    pub fn child(&self) -> Self {
+        Self {
+            parent_id: random_id(),
+            ..*self
+        }
+    }
+
+    pub fn sampled(&self) -> bool {
+        self.flags & Self::SAMPLED != 0
+    }
+}
+
+impl Default for TraceContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for TraceContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "00-{}-{}-{:02x}",
+            hex::encode(self.trace_id),
+            hex::encode(self.parent_id),
+            self.flags
+        )
+    }
+}
+
+impl std::str::FromStr for TraceContext {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::SerializationError(format!("Invalid traceparent: {s}"));
+        let parts: Vec<&str> = s.split('-').collect();
+        // Later versions may append fields, but keep the leading ones
+        let [version, trace_id, parent_id, flags, rest @ ..] = parts.as_slice() else {
+            return Err(invalid());
+        };
+        if *version == "ff" || (*version == "00" && !rest.is_empty()) {
+            return Err(invalid());
+        }
+        let mut context = TraceContext {
+            trace_id: [0; 16],
+            parent_id: [0; 8],
+            flags: 0,
+        };
+        hex::decode_to_slice(trace_id, &mut context.trace_id).map_err(|_| invalid())?;
+        hex::decode_to_slice(parent_id, &mut context.parent_id).map_err(|_| invalid())?;
+        let mut flag = [0];
+        hex::decode_to_slice(flags, &mut flag).map_err(|_| invalid())?;
+        context.flags = flag[0];
+        // All zero ids are explicitly invalid
+        if context.trace_id == [0; 16] || context.parent_id == [0; 8] {
+            return Err(invalid());
+        }
+        Ok(context)
+    }
+}
+
+/// A random non-zero id, seeded from the randomly keyed hasher of the standard library
+fn random_id() -> [u8; 8] {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    loop {
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u64(COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
+        let id = hasher.finish();
+        if id != 0 {
+            return id.to_be_bytes();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_traceparent() {
+        let traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let context: TraceContext = traceparent.parse().unwrap();
+        assert_eq!(
+            hex::encode(context.trace_id),
+            "4bf92f3577b34da6a3ce929d0e0e4736"
+        );
+        assert_eq!(hex::encode(context.parent_id), "00f067aa0ba902b7");
+        assert!(context.sampled());
+        assert_eq!(context.to_string(), traceparent);
+
+        let child = context.child();
+        assert_eq!(child.trace_id, context.trace_id);
+        assert_ne!(child.parent_id, context.parent_id);
+        assert_ne!(TraceContext::new().trace_id, TraceContext::new().trace_id);
+
+        for invalid in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e47-00f067aa0ba902b7-01",
+        ] {
+            assert!(invalid.parse::<TraceContext>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn encodes_attachments() {
+        let context = TraceContext::new();
+        let mut attachment = Attachment::with_trace(&context);
+        attachment.insert(TRACESTATE, "congo=t61rcWkgMzE");
+        attachment.insert("robot", "");
+
+        let decoded = Attachment::from_bytes(&attachment.to_bytes()).unwrap();
+        assert_eq!(decoded, attachment);
+        assert_eq!(decoded.trace(), Some(context));
+        assert_eq!(decoded.get("robot"), Some(""));
+
+        assert!(Attachment::from_bytes(&[]).unwrap().is_empty());
+        assert!(Attachment::from_bytes(&[3, 0, 0, 0, b'a']).is_err());
+    }
+}
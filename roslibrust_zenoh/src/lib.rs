@@ -4,19 +4,71 @@
 use roslibrust_common::*;
 
 use log::*;
+use std::sync::{Arc, Mutex};
 use zenoh::bytes::ZBytes;
+use zenoh::Wait;
+
+pub mod lifecycle;
+
+pub mod attachment;
+pub use attachment::{Attachment, TraceContext};
 
 /// A wrapper around a normal zenoh session that adds roslibrust specific functionality.
 /// Should be created via [ZenohClient::new], and then used via the [TopicProvider] and [ServiceProvider] traits.
 #[derive(Clone)]
 pub struct ZenohClient {
     session: zenoh::Session,
+    channel_config: ChannelConfig,
+    metrics: ChannelMetrics,
+    runtime: Option<tokio::runtime::Handle>,
 }
 
 impl ZenohClient {
     /// Creates a new client wrapped around a Zenoh session
     pub fn new(session: zenoh::Session) -> Self {
-        Self { session }
+        Self {
+            session,
+            channel_config: ChannelConfig::default(),
+            metrics: ChannelMetrics::default(),
+            runtime: None,
+        }
+    }
+
+    /// Configures the queues of subscribers and service servers created afterwards.
+    ///
+    /// `subscriber_queue_size` is passed on to zenoh, which holds back delivery rather than dropping messages when
+    /// a subscriber's queue is full. `service_queue_size` bounds the requests queued for each service server, which
+    /// is otherwise unbounded, new requests being dropped and counted in [ZenohClient::channel_metrics] when full.
+    pub fn channel_config(mut self, channel_config: ChannelConfig) -> Self {
+        self.channel_config = channel_config;
+        self
+    }
+
+    /// Counts of the service requests dropped because a service server's queue was full, shared by all clones
+    pub fn channel_metrics(&self) -> &ChannelMetrics {
+        &self.metrics
+    }
+
+    /// Runs the tasks serving service servers created afterwards on `runtime` instead of the runtime they are
+    /// advertised on. Zenoh's own network I/O always runs on zenoh's internal runtime.
+    ///
+    /// Service servers advertised outside of a tokio runtime, e.g. by an application using async-std or smol, run on
+    /// a shared background runtime unless one is given here, see [roslibrust_common::runtime].
+    pub fn runtime(mut self, runtime: tokio::runtime::Handle) -> Self {
+        self.runtime = Some(runtime);
+        self
+    }
+
+    /// Spawns an internal task on the configured runtime, or the current one
+    fn spawn<F>(&self, future: F) -> tokio::task::JoinHandle<F::Output>
+    where
+        F: std::future::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        match &self.runtime {
+            Some(runtime) => runtime.spawn(future),
+            None => roslibrust_common::runtime::handle().spawn(future),
+        }
     }
 }
 
@@ -24,14 +76,30 @@ impl ZenohClient {
 /// This type is self de-registering, and dropping the publisher will automatically un-advertise the topic.
 pub struct ZenohPublisher<T> {
     publisher: zenoh::pubsub::Publisher<'static>,
+    latch: Option<Latch>,
     _marker: std::marker::PhantomData<T>,
 }
 
+/// The last sample put by a latching publisher, its payload and attachment
+type LatchedSample = Option<(ZBytes, Option<ZBytes>)>;
+
+/// Emulates latching for a [ZenohPublisher], which zenoh only provides through storages.
+///
+/// The last sample published is kept, and given in reply to queries on the topic, which each [ZenohSubscriber]
+/// sends when it subscribes. Subscribers outside of roslibrust, e.g. zenoh-bridge-ros1's, don't query and so
+/// only receive the samples published after they subscribed.
+struct Latch {
+    last: Arc<Mutex<LatchedSample>>,
+    // Dropping this will stop zenoh's declaration of the queryable
+    _queryable: zenoh::query::Queryable<()>,
+}
+
 impl<T: RosMessageType> Publish<T> for ZenohPublisher<T> {
     async fn publish(&self, data: &T) -> Result<()> {
         let bytes = roslibrust_serde_rosmsg::to_vec_skip_length(data).map_err(|e| {
             Error::SerializationError(format!("Failed to serialize message: {e:?}"))
         })?;
+        self.latch(&bytes, None);
 
         match self.publisher.put(&bytes).await {
             Ok(()) => Ok(()),
@@ -42,6 +110,46 @@ impl<T: RosMessageType> Publish<T> for ZenohPublisher<T> {
     }
 }
 
+impl<T> ZenohPublisher<T> {
+    /// Keeps a sample about to be put as the last one, if this publisher is latching
+    fn latch(&self, payload: &[u8], attachment: Option<&ZBytes>) {
+        if let Some(latch) = &self.latch {
+            *latch.last.lock().unwrap() =
+                Some((ZBytes::from(payload.to_vec()), attachment.cloned()));
+        }
+    }
+}
+
+impl<T: RosMessageType> ZenohPublisher<T> {
+    /// Publishes a message with key/values such as a W3C trace-context attached,
+    /// received by [ZenohSubscriber::next_with_attachment].
+    pub async fn publish_with_attachment(&self, data: &T, attachment: &Attachment) -> Result<()> {
+        let bytes = roslibrust_serde_rosmsg::to_vec_skip_length(data).map_err(|e| {
+            Error::SerializationError(format!("Failed to serialize message: {e:?}"))
+        })?;
+
+        #[cfg(feature = "tracing")]
+        if let Some(context) = attachment.trace() {
+            tracing::trace!(
+                key_expr = %self.publisher.key_expr(),
+                trace_id = %hex::encode(context.trace_id),
+                parent_id = %hex::encode(context.parent_id),
+                "publishing traced message"
+            );
+        }
+
+        let attachment = ZBytes::from(attachment.to_bytes());
+        self.latch(&bytes, Some(&attachment));
+
+        match self.publisher.put(&bytes).attachment(attachment).await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(Error::Unexpected(anyhow::anyhow!(
+                "Failed to publish message to zenoh: {e:?}"
+            ))),
+        }
+    }
+}
+
 // Using type alias here, I have no idea why zenoh has this type so deep
 type ZenohSubInner =
     zenoh::pubsub::Subscriber<zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>>;
@@ -52,12 +160,25 @@ type ZenohSubInner =
 /// It is typically used with types generated by roslibrust's codegen.
 pub struct ZenohSubscriber<T> {
     subscriber: ZenohSubInner,
+    // Replies of latching publishers to the query sent on subscribing, until they are exhausted or outdated
+    latched: Option<zenoh::handlers::FifoChannelHandler<zenoh::query::Reply>>,
     _marker: std::marker::PhantomData<T>,
 }
 
 impl<T: RosMessageType> Subscribe<T> for ZenohSubscriber<T> {
     async fn next(&mut self) -> Result<T> {
-        let next = self.subscriber.recv_async().await;
+        let (msg, _attachment) = self.next_with_attachment().await?;
+        Ok(msg)
+    }
+}
+
+impl<T: RosMessageType> ZenohSubscriber<T> {
+    /// Receives the next message along with the key/values attached by its publisher,
+    /// which are empty if it was published without any, e.g. by zenoh-bridge-ros1.
+    ///
+    /// With the `tracing` feature [Attachment::span] creates a span continuing the sender's trace.
+    pub async fn next_with_attachment(&mut self) -> Result<(T, Attachment)> {
+        let next = self.next_sample().await;
 
         let sample = match next {
             Ok(sample) => sample,
@@ -76,7 +197,36 @@ impl<T: RosMessageType> Subscribe<T> for ZenohSubscriber<T> {
             .map_err(|e| {
                 Error::SerializationError(format!("Failed to deserialize sample: {e:?}"))
             })?;
-        Ok(msg)
+        let attachment = match sample.attachment() {
+            Some(attachment) => Attachment::from_bytes(&attachment.to_bytes())?,
+            None => Attachment::new(),
+        };
+        Ok((msg, attachment))
+    }
+
+    /// Receives the next sample, replaying the samples of latching publishers first unless a newer sample is
+    /// published before they arrive
+    async fn next_sample(&mut self) -> std::result::Result<zenoh::sample::Sample, zenoh::Error> {
+        while let Some(latched) = &self.latched {
+            let reply = tokio::select! {
+                biased;
+                reply = latched.recv_async() => reply,
+                sample = self.subscriber.recv_async() => {
+                    // Anything latched is older than this sample
+                    self.latched = None;
+                    return sample;
+                }
+            };
+            match reply {
+                Ok(reply) => match reply.into_result() {
+                    Ok(sample) => return Ok(sample),
+                    Err(e) => debug!("Failed to replay a latched sample: {e:?}"),
+                },
+                // Every latching publisher has replied
+                Err(_) => self.latched = None,
+            }
+        }
+        self.subscriber.recv_async().await
     }
 }
 
@@ -85,8 +235,12 @@ impl TopicProvider for ZenohClient {
 
     type Subscriber<T: RosMessageType> = ZenohSubscriber<T>;
 
-    async fn advertise<T: RosMessageType>(&self, topic: &str) -> Result<Self::Publisher<T>> {
-        let mangled_topic = mangle_topic(topic, T::ROS_TYPE_NAME, T::MD5SUM);
+    async fn advertise<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Publisher<T>> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        let mangled_topic = mangle_topic(topic.as_str(), T::ROS_TYPE_NAME, T::MD5SUM);
         let publisher = match self.session.declare_publisher(mangled_topic).await {
             Ok(publisher) => publisher,
             Err(e) => {
@@ -99,13 +253,70 @@ impl TopicProvider for ZenohClient {
 
         Ok(ZenohPublisher {
             publisher,
+            latch: None,
             _marker: std::marker::PhantomData,
         })
     }
 
-    async fn subscribe<T: RosMessageType>(&self, topic: &str) -> Result<Self::Subscriber<T>> {
-        let mangled_topic = mangle_topic(topic, T::ROS_TYPE_NAME, T::MD5SUM);
-        let sub = match self.session.declare_subscriber(mangled_topic).await {
+    async fn advertise_with_qos<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+        qos: PublisherQos,
+    ) -> Result<Self::Publisher<T>> {
+        let mut publisher = self.advertise::<T>(topic).await?;
+        if !qos.latching {
+            return Ok(publisher);
+        }
+
+        let last: Arc<Mutex<LatchedSample>> = Arc::new(Mutex::new(None));
+        let queryable = self
+            .session
+            .declare_queryable(publisher.publisher.key_expr().clone())
+            .callback({
+                let last = last.clone();
+                move |query| {
+                    // Nothing to reply with until the first message is published
+                    let Some((payload, attachment)) = last.lock().unwrap().clone() else {
+                        return;
+                    };
+                    debug!("Replaying latched sample on {}", query.key_expr());
+                    if let Err(e) = query
+                        .reply(query.key_expr().clone(), payload)
+                        .attachment(attachment)
+                        .wait()
+                    {
+                        error!("Failed to reply with latched sample: {e:?}");
+                    }
+                }
+            })
+            .await
+            .map_err(|e| {
+                Error::Unexpected(anyhow::anyhow!("Failed to declare queryable: {e:?}"))
+            })?;
+        publisher.latch = Some(Latch {
+            last,
+            _queryable: queryable,
+        });
+        Ok(publisher)
+    }
+
+    async fn subscribe<T: RosMessageType>(
+        &self,
+        topic: impl ToTopicName + Send,
+    ) -> Result<Self::Subscriber<T>> {
+        let topic = topic.to_topic_name()?.resolve_to_root()?;
+        let mangled_topic = mangle_topic(topic.as_str(), T::ROS_TYPE_NAME, T::MD5SUM);
+        let capacity = self
+            .channel_config
+            .subscriber_queue_size
+            .map(zenoh::handlers::FifoChannel::new)
+            .unwrap_or_default();
+        let sub = match self
+            .session
+            .declare_subscriber(mangled_topic)
+            .with(capacity)
+            .await
+        {
             Ok(sub) => sub,
             Err(e) => {
                 // TODO errors still suck with this API...
@@ -114,13 +325,45 @@ impl TopicProvider for ZenohClient {
                 )));
             }
         };
+        // Latching publishers reply with the last sample they published
+        let latched = match self.session.get(sub.key_expr()).await {
+            Ok(latched) => Some(latched),
+            Err(e) => {
+                warn!("Failed to query latched samples: {e:?}");
+                None
+            }
+        };
         Ok(ZenohSubscriber {
             subscriber: sub,
+            latched,
             _marker: std::marker::PhantomData,
         })
     }
 }
 
+impl CapabilityProvider for ZenohClient {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            // Emulated, see ZenohPublisher
+            latching: true,
+            service_clients: true,
+            service_servers: true,
+            ..Default::default()
+        }
+    }
+}
+
+// The zenoh backend does not yet support simulated time, ROS time is wall clock time
+impl ClockProvider for ZenohClient {
+    fn now(&self) -> std::time::SystemTime {
+        std::time::SystemTime::now()
+    }
+
+    async fn sleep(&self, duration: std::time::Duration) {
+        roslibrust_common::runtime::sleep(duration).await
+    }
+}
+
 /// Takes in a regular ros topic and type and returns a zenoh topic mangled in the way the zenoh-ros1-plugin does
 fn mangle_topic(topic: &str, type_str: &str, md5sum: &str) -> String {
     // Name mangling stuff!
@@ -222,7 +465,7 @@ impl ServiceProvider for ZenohClient {
 
     async fn call_service<T: RosServiceType>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         request: T::Request,
     ) -> Result<T::Response> {
         // TODO should be able to optimize this...
@@ -232,9 +475,10 @@ impl ServiceProvider for ZenohClient {
 
     async fn service_client<T: RosServiceType + 'static>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
     ) -> Result<Self::ServiceClient<T>> {
-        let mangled_topic = mangle_topic(topic, T::ROS_SERVICE_NAME, T::MD5SUM);
+        let topic = topic.to_service_name()?.resolve_to_root()?;
+        let mangled_topic = mangle_topic(topic.as_str(), T::ROS_SERVICE_NAME, T::MD5SUM);
 
         Ok(ZenohServiceClient {
             session: self.session.clone(),
@@ -245,21 +489,31 @@ impl ServiceProvider for ZenohClient {
 
     async fn advertise_service<T: RosServiceType + 'static, F: ServiceFn<T>>(
         &self,
-        topic: &str,
+        topic: impl ToServiceName + Send,
         server: F,
     ) -> Result<Self::ServiceServer> {
-        let mangled_topic = mangle_topic(topic, T::ROS_SERVICE_NAME, T::MD5SUM);
-
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let topic = topic.to_service_name()?.resolve_to_root()?;
+        let mangled_topic = mangle_topic(topic.as_str(), T::ROS_SERVICE_NAME, T::MD5SUM);
+
+        // Without a configured size the queue is effectively unbounded
+        let queue_size = self
+            .channel_config
+            .service_queue_size
+            .unwrap_or(tokio::sync::Semaphore::MAX_PERMITS);
+        let (tx, mut rx) = tokio::sync::mpsc::channel(queue_size);
         let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
 
+        let metrics = self.metrics.clone();
         let x = self
             .session
             .declare_queryable(mangled_topic)
-            .callback(move |query| {
-                let _ = tx.send(query).map_err(|e| {
-                    error!("Failed to send query: {e:?}");
-                });
+            .callback(move |query| match tx.try_send(query) {
+                Ok(()) => {}
+                Err(tokio::sync::mpsc::error::TrySendError::Full(query)) => {
+                    warn!("Service queue is full, dropping query: {query:?}");
+                    metrics.record_service_dropped(1);
+                }
+                Err(e) => error!("Failed to send query: {e:?}"),
             })
             .await
             .map_err(|e| {
@@ -271,7 +525,7 @@ impl ServiceProvider for ZenohClient {
 
         // Spawn a task to handle the queries
         // This task will shut down when queryable is dropped
-        tokio::spawn(async move {
+        self.spawn(async move {
             while let Some(query) = rx.recv().await {
                 debug!("Got query: {query:?}");
                 let Some(payload) = query.payload() else {
@@ -330,7 +584,8 @@ impl ServiceProvider for ZenohClient {
 
         // Note: I'm uncertain about "discovery_namespace" and just using * for now
         // Note: I'm uncertain about "bridge_namespace" and just using * for now
-        let (type_mangle, service_name) = mangle_service(topic, T::ROS_SERVICE_NAME, T::MD5SUM);
+        let (type_mangle, service_name) =
+            mangle_service(topic.as_str(), T::ROS_SERVICE_NAME, T::MD5SUM);
         let zenoh_info_topic = format!("ros1_discovery_info/*/srv/{type_mangle}/*/{service_name}");
 
         let q2 = self
@@ -342,7 +597,7 @@ impl ServiceProvider for ZenohClient {
                     "Failed to declare queryable for service discovery: {e:?}"
                 ))
             })?;
-        tokio::spawn(async move {
+        self.spawn(async move {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
             loop {
                 let shutdown = shutdown_rx.try_recv();
@@ -400,6 +655,43 @@ mod tests {
             "7374645f737276732f536574426f6f6c/09fb03525b03e7ea1fd3992bafd87e16/service_server_rs/my_set_bool");
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn latching_publisher_replays_to_new_subscribers() {
+        let mut config = zenoh::Config::default();
+        config
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        let client = ZenohClient::new(zenoh::open(config).await.unwrap());
+        let qos = PublisherQos { latching: true };
+        let publisher = client
+            .advertise_with_qos::<primitives::String>("/latched", qos)
+            .await
+            .unwrap();
+        publisher.publish(&"hello".into()).await.unwrap();
+
+        let mut subscriber = client
+            .subscribe::<primitives::String>("/latched")
+            .await
+            .unwrap();
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(2), subscriber.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg.data, "hello");
+
+        // Subscribers joining while others are subscribed are replayed the last message as well
+        publisher.publish(&"again".into()).await.unwrap();
+        let mut late = client
+            .subscribe::<primitives::String>("/latched")
+            .await
+            .unwrap();
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(2), late.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg.data, "again");
+    }
+
     #[test]
     #[should_panic]
     fn confirm_client_handle_impls_ros() {
@@ -0,0 +1,854 @@
+//! Managed lifecycle nodes in the style of ROS2, see [LifecycleNode].
+//!
+//! A lifecycle node starts out unconfigured and is moved between its states by a launch system or other manager
+//! through the node's `change_state` service. Each transition runs the matching [LifecycleCallbacks] method, whose
+//! [CallbackReturn] decides which state the node ends up in, and is announced on the node's `transition_event` topic.
+
+use crate::ZenohClient;
+use lifecycle_msgs::{State, Transition, TransitionDescription, TransitionEvent};
+use log::*;
+use roslibrust_common::*;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, watch};
+
+/// The outcome of a [LifecycleCallbacks] method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallbackReturn {
+    /// The transition completes and the node moves on to the transition's goal state
+    Success,
+    /// The transition is aborted and the node returns to the state it started from
+    Failure,
+    /// The node moves to [LifecycleState::ErrorProcessing] and [LifecycleCallbacks::on_error] is called
+    Error,
+}
+
+/// The user code run on each transition of a [LifecycleNode], every method defaults to succeeding.
+///
+/// Methods are called with the primary state the transition started from.
+pub trait LifecycleCallbacks: Send + 'static {
+    /// Called on the `configure` transition, succeeding moves the node to [LifecycleState::Inactive]
+    fn on_configure(&mut self, _previous_state: LifecycleState) -> CallbackReturn {
+        CallbackReturn::Success
+    }
+
+    /// Called on the `activate` transition, succeeding moves the node to [LifecycleState::Active]
+    fn on_activate(&mut self, _previous_state: LifecycleState) -> CallbackReturn {
+        CallbackReturn::Success
+    }
+
+    /// Called on the `deactivate` transition, succeeding moves the node to [LifecycleState::Inactive]
+    fn on_deactivate(&mut self, _previous_state: LifecycleState) -> CallbackReturn {
+        CallbackReturn::Success
+    }
+
+    /// Called on the `cleanup` transition, succeeding moves the node to [LifecycleState::Unconfigured]
+    fn on_cleanup(&mut self, _previous_state: LifecycleState) -> CallbackReturn {
+        CallbackReturn::Success
+    }
+
+    /// Called on the `shutdown` transition, the node is [LifecycleState::Finalized] afterwards unless this errors
+    fn on_shutdown(&mut self, _previous_state: LifecycleState) -> CallbackReturn {
+        CallbackReturn::Success
+    }
+
+    /// Called when another callback returned [CallbackReturn::Error], succeeding moves the node to
+    /// [LifecycleState::Unconfigured] and anything else to [LifecycleState::Finalized]
+    fn on_error(&mut self, _previous_state: LifecycleState) -> CallbackReturn {
+        CallbackReturn::Success
+    }
+}
+
+/// The states of a [LifecycleNode], with the ids and labels used by ROS2's lifecycle_msgs/State
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleState {
+    Unconfigured = 1,
+    Inactive = 2,
+    Active = 3,
+    Finalized = 4,
+    Configuring = 10,
+    CleaningUp = 11,
+    ShuttingDown = 12,
+    Activating = 13,
+    Deactivating = 14,
+    ErrorProcessing = 15,
+}
+
+impl LifecycleState {
+    const ALL: [LifecycleState; 10] = [
+        LifecycleState::Unconfigured,
+        LifecycleState::Inactive,
+        LifecycleState::Active,
+        LifecycleState::Finalized,
+        LifecycleState::Configuring,
+        LifecycleState::CleaningUp,
+        LifecycleState::ShuttingDown,
+        LifecycleState::Activating,
+        LifecycleState::Deactivating,
+        LifecycleState::ErrorProcessing,
+    ];
+
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LifecycleState::Unconfigured => "unconfigured",
+            LifecycleState::Inactive => "inactive",
+            LifecycleState::Active => "active",
+            LifecycleState::Finalized => "finalized",
+            LifecycleState::Configuring => "configuring",
+            LifecycleState::CleaningUp => "cleaningup",
+            LifecycleState::ShuttingDown => "shuttingdown",
+            LifecycleState::Activating => "activating",
+            LifecycleState::Deactivating => "deactivating",
+            LifecycleState::ErrorProcessing => "errorprocessing",
+        }
+    }
+
+    fn to_msg(self) -> State {
+        State {
+            id: self.id(),
+            label: self.label().to_string(),
+        }
+    }
+}
+
+/// The transitions between the primary states of a [LifecycleNode] that can be requested
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleTransition {
+    Configure,
+    Cleanup,
+    Activate,
+    Deactivate,
+    Shutdown,
+}
+
+impl LifecycleTransition {
+    const ALL: [LifecycleTransition; 5] = [
+        LifecycleTransition::Configure,
+        LifecycleTransition::Cleanup,
+        LifecycleTransition::Activate,
+        LifecycleTransition::Deactivate,
+        LifecycleTransition::Shutdown,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LifecycleTransition::Configure => "configure",
+            LifecycleTransition::Cleanup => "cleanup",
+            LifecycleTransition::Activate => "activate",
+            LifecycleTransition::Deactivate => "deactivate",
+            LifecycleTransition::Shutdown => "shutdown",
+        }
+    }
+
+    /// The id of this transition when started from `state`, shutdown having one id per primary state
+    fn id(self, state: LifecycleState) -> u8 {
+        match (self, state) {
+            (LifecycleTransition::Configure, _) => 1,
+            (LifecycleTransition::Cleanup, _) => 2,
+            (LifecycleTransition::Activate, _) => 3,
+            (LifecycleTransition::Deactivate, _) => 4,
+            (LifecycleTransition::Shutdown, LifecycleState::Unconfigured) => 5,
+            (LifecycleTransition::Shutdown, LifecycleState::Inactive) => 6,
+            (LifecycleTransition::Shutdown, _) => 7,
+        }
+    }
+
+    /// Returns the intermediate state this transition passes through when available from `state`
+    fn intermediate_state(self, state: LifecycleState) -> Option<LifecycleState> {
+        use LifecycleState::*;
+        match (self, state) {
+            (LifecycleTransition::Configure, Unconfigured) => Some(Configuring),
+            (LifecycleTransition::Cleanup, Inactive) => Some(CleaningUp),
+            (LifecycleTransition::Activate, Inactive) => Some(Activating),
+            (LifecycleTransition::Deactivate, Active) => Some(Deactivating),
+            (LifecycleTransition::Shutdown, Unconfigured | Inactive | Active) => Some(ShuttingDown),
+            _ => None,
+        }
+    }
+
+    /// The state reached when this transition's callback succeeds
+    fn goal_state(self) -> LifecycleState {
+        match self {
+            LifecycleTransition::Configure => LifecycleState::Inactive,
+            LifecycleTransition::Cleanup => LifecycleState::Unconfigured,
+            LifecycleTransition::Activate => LifecycleState::Active,
+            LifecycleTransition::Deactivate => LifecycleState::Inactive,
+            LifecycleTransition::Shutdown => LifecycleState::Finalized,
+        }
+    }
+
+    /// The base of the ids of the transitions out of this transition's intermediate state
+    fn result_id(self) -> u8 {
+        match self {
+            LifecycleTransition::Configure => 10,
+            LifecycleTransition::Cleanup => 20,
+            LifecycleTransition::Activate => 30,
+            LifecycleTransition::Deactivate => 40,
+            LifecycleTransition::Shutdown => 50,
+        }
+    }
+
+    /// Finds the transition requested in a ChangeState request, by its id or by its label when the id is 0
+    fn from_msg(transition: &Transition, state: LifecycleState) -> Option<Self> {
+        LifecycleTransition::ALL.into_iter().find(|t| {
+            if transition.id == 0 {
+                t.label() == transition.label
+            } else {
+                t.id(state) == transition.id
+            }
+        })
+    }
+}
+
+/// The state of a lifecycle node shared between its services and [LifecycleNode]
+struct StateMachine {
+    state: LifecycleState,
+    callbacks: Box<dyn LifecycleCallbacks>,
+    events: mpsc::UnboundedSender<TransitionEvent>,
+    state_tx: watch::Sender<LifecycleState>,
+}
+
+impl StateMachine {
+    fn trigger(&mut self, transition: LifecycleTransition) -> Result<CallbackReturn> {
+        let start = self.state;
+        let Some(intermediate) = transition.intermediate_state(start) else {
+            return Err(Error::Unexpected(anyhow::anyhow!(
+                "Transition {} is not available in state {}",
+                transition.label(),
+                start.label()
+            )));
+        };
+        self.change(transition.id(start), transition.label(), intermediate);
+
+        let result = match transition {
+            LifecycleTransition::Configure => self.callbacks.on_configure(start),
+            LifecycleTransition::Cleanup => self.callbacks.on_cleanup(start),
+            LifecycleTransition::Activate => self.callbacks.on_activate(start),
+            LifecycleTransition::Deactivate => self.callbacks.on_deactivate(start),
+            LifecycleTransition::Shutdown => self.callbacks.on_shutdown(start),
+        };
+        let next = match (result, transition) {
+            (CallbackReturn::Success, _) => transition.goal_state(),
+            // A failed shutdown still finalizes the node, as in ROS2
+            (CallbackReturn::Failure, LifecycleTransition::Shutdown) => LifecycleState::Finalized,
+            (CallbackReturn::Failure, _) => start,
+            (CallbackReturn::Error, _) => LifecycleState::ErrorProcessing,
+        };
+        self.change(
+            transition.result_id() + result_offset(result),
+            result_label(result),
+            next,
+        );
+
+        if result == CallbackReturn::Error {
+            let error_result = self.callbacks.on_error(start);
+            let next = match error_result {
+                CallbackReturn::Success => LifecycleState::Unconfigured,
+                _ => LifecycleState::Finalized,
+            };
+            self.change(
+                60 + result_offset(error_result),
+                result_label(error_result),
+                next,
+            );
+        }
+        Ok(result)
+    }
+
+    /// Moves to `goal`, publishing a transition event and notifying watchers
+    fn change(&mut self, transition_id: u8, transition_label: &str, goal: LifecycleState) {
+        let start = std::mem::replace(&mut self.state, goal);
+        debug!("Lifecycle transition {transition_label} from {start:?} to {goal:?}");
+        // The zenoh backend's ROS time is wall clock time
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+        let _ = self.events.send(TransitionEvent {
+            timestamp,
+            transition: Transition {
+                id: transition_id,
+                label: transition_label.to_string(),
+            },
+            start_state: start.to_msg(),
+            goal_state: goal.to_msg(),
+        });
+        self.state_tx.send_replace(goal);
+    }
+
+    fn available_transitions(&self) -> Vec<TransitionDescription> {
+        LifecycleTransition::ALL
+            .into_iter()
+            .filter_map(|transition| {
+                let intermediate = transition.intermediate_state(self.state)?;
+                Some(TransitionDescription {
+                    transition: Transition {
+                        id: transition.id(self.state),
+                        label: transition.label().to_string(),
+                    },
+                    start_state: self.state.to_msg(),
+                    goal_state: intermediate.to_msg(),
+                })
+            })
+            .collect()
+    }
+}
+
+fn result_offset(result: CallbackReturn) -> u8 {
+    match result {
+        CallbackReturn::Success => 0,
+        CallbackReturn::Failure => 1,
+        CallbackReturn::Error => 2,
+    }
+}
+
+fn result_label(result: CallbackReturn) -> &'static str {
+    match result {
+        CallbackReturn::Success => "transition_success",
+        CallbackReturn::Failure => "transition_failure",
+        CallbackReturn::Error => "transition_error",
+    }
+}
+
+/// A managed node following ROS2's lifecycle, whose state is driven through its services.
+///
+/// Advertises `<node>/change_state`, `<node>/get_state`, `<node>/get_available_states` and
+/// `<node>/get_available_transitions`, and publishes every change of state on `<node>/transition_event`.
+/// Dropping the node removes its services and stops publishing events.
+pub struct LifecycleNode {
+    machine: Arc<Mutex<StateMachine>>,
+    state_rx: watch::Receiver<LifecycleState>,
+    _services: Vec<crate::ZenohServiceServer>,
+}
+
+impl LifecycleNode {
+    /// Creates a lifecycle node named `node_name` in [LifecycleState::Unconfigured], running `callbacks` on its
+    /// transitions
+    pub async fn new(
+        client: &ZenohClient,
+        node_name: &str,
+        callbacks: impl LifecycleCallbacks,
+    ) -> Result<Self> {
+        let node_name = node_name.trim_end_matches('/');
+        let publisher = client
+            .advertise::<TransitionEvent>(format!("{node_name}/transition_event"))
+            .await?;
+        let (events, mut events_rx) = mpsc::unbounded_channel();
+        let (state_tx, state_rx) = watch::channel(LifecycleState::Unconfigured);
+        let machine = Arc::new(Mutex::new(StateMachine {
+            state: LifecycleState::Unconfigured,
+            callbacks: Box::new(callbacks),
+            events,
+            state_tx,
+        }));
+
+        // Ends once the node and its services are dropped, closing the channel
+        client.spawn(async move {
+            while let Some(event) = events_rx.recv().await {
+                if let Err(e) = publisher.publish(&event).await {
+                    error!("Failed to publish lifecycle transition event: {e:?}");
+                }
+            }
+        });
+
+        // Services are evaluated within spawn_blocking, so they can block on the state machine
+        let m = machine.clone();
+        let change_state = client
+            .advertise_service::<lifecycle_msgs::ChangeState, _>(
+                format!("{node_name}/change_state"),
+                move |request: lifecycle_msgs::ChangeStateRequest| {
+                    let mut machine = lock(&m);
+                    let success =
+                        match LifecycleTransition::from_msg(&request.transition, machine.state) {
+                            Some(transition) => {
+                                matches!(machine.trigger(transition), Ok(CallbackReturn::Success))
+                            }
+                            None => {
+                                warn!("Unknown lifecycle transition requested: {request:?}");
+                                false
+                            }
+                        };
+                    Ok(lifecycle_msgs::ChangeStateResponse { success })
+                },
+            )
+            .await?;
+        let m = machine.clone();
+        let get_state = client
+            .advertise_service::<lifecycle_msgs::GetState, _>(
+                format!("{node_name}/get_state"),
+                move |_| {
+                    Ok(lifecycle_msgs::GetStateResponse {
+                        current_state: lock(&m).state.to_msg(),
+                    })
+                },
+            )
+            .await?;
+        let get_available_states = client
+            .advertise_service::<lifecycle_msgs::GetAvailableStates, _>(
+                format!("{node_name}/get_available_states"),
+                |_| {
+                    Ok(lifecycle_msgs::GetAvailableStatesResponse {
+                        available_states: LifecycleState::ALL.map(LifecycleState::to_msg).to_vec(),
+                    })
+                },
+            )
+            .await?;
+        let m = machine.clone();
+        let get_available_transitions = client
+            .advertise_service::<lifecycle_msgs::GetAvailableTransitions, _>(
+                format!("{node_name}/get_available_transitions"),
+                move |_| {
+                    Ok(lifecycle_msgs::GetAvailableTransitionsResponse {
+                        available_transitions: lock(&m).available_transitions(),
+                    })
+                },
+            )
+            .await?;
+
+        Ok(Self {
+            machine,
+            state_rx,
+            _services: vec![
+                change_state,
+                get_state,
+                get_available_states,
+                get_available_transitions,
+            ],
+        })
+    }
+
+    /// The node's current state
+    pub fn state(&self) -> LifecycleState {
+        *self.state_rx.borrow()
+    }
+
+    /// Returns a receiver notified of every change of the node's state, e.g. to only publish while active
+    pub fn watch_state(&self) -> watch::Receiver<LifecycleState> {
+        self.state_rx.clone()
+    }
+
+    /// Triggers `transition` from within the application, as the `change_state` service does.
+    ///
+    /// The callback runs on the calling thread. Returns an error if the transition isn't available in the current
+    /// state, otherwise the callback's result.
+    pub fn trigger(&self, transition: LifecycleTransition) -> Result<CallbackReturn> {
+        lock(&self.machine).trigger(transition)
+    }
+}
+
+/// A panicking callback leaves the state machine usable, in the state it was in
+fn lock(machine: &Mutex<StateMachine>) -> std::sync::MutexGuard<'_, StateMachine> {
+    machine.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The messages and services of ROS2's lifecycle_msgs package used by [LifecycleNode].
+///
+/// Their definitions and md5sums follow ROS1's conventions, as the zenoh backend talks to ROS1 through
+/// zenoh-ros1-bridge.
+pub mod lifecycle_msgs {
+    use roslibrust_common::{RosMessageType, RosServiceType};
+
+    macro_rules! state_definition {
+        () => {
+            "uint8 PRIMARY_STATE_UNKNOWN = 0\nuint8 PRIMARY_STATE_UNCONFIGURED = 1\nuint8 PRIMARY_STATE_INACTIVE = 2\nuint8 PRIMARY_STATE_ACTIVE = 3\nuint8 PRIMARY_STATE_FINALIZED = 4\nuint8 TRANSITION_STATE_CONFIGURING = 10\nuint8 TRANSITION_STATE_CLEANINGUP = 11\nuint8 TRANSITION_STATE_SHUTTINGDOWN = 12\nuint8 TRANSITION_STATE_ACTIVATING = 13\nuint8 TRANSITION_STATE_DEACTIVATING = 14\nuint8 TRANSITION_STATE_ERRORPROCESSING = 15\nuint8 id\nstring label"
+        };
+    }
+
+    macro_rules! transition_definition {
+        () => {
+            "uint8 TRANSITION_CREATE = 0\nuint8 TRANSITION_CONFIGURE = 1\nuint8 TRANSITION_CLEANUP = 2\nuint8 TRANSITION_ACTIVATE = 3\nuint8 TRANSITION_DEACTIVATE = 4\nuint8 TRANSITION_UNCONFIGURED_SHUTDOWN = 5\nuint8 TRANSITION_INACTIVE_SHUTDOWN = 6\nuint8 TRANSITION_ACTIVE_SHUTDOWN = 7\nuint8 TRANSITION_DESTROY = 8\nuint8 TRANSITION_ON_CONFIGURE_SUCCESS = 10\nuint8 TRANSITION_ON_CONFIGURE_FAILURE = 11\nuint8 TRANSITION_ON_CONFIGURE_ERROR = 12\nuint8 TRANSITION_ON_CLEANUP_SUCCESS = 20\nuint8 TRANSITION_ON_CLEANUP_FAILURE = 21\nuint8 TRANSITION_ON_CLEANUP_ERROR = 22\nuint8 TRANSITION_ON_ACTIVATE_SUCCESS = 30\nuint8 TRANSITION_ON_ACTIVATE_FAILURE = 31\nuint8 TRANSITION_ON_ACTIVATE_ERROR = 32\nuint8 TRANSITION_ON_DEACTIVATE_SUCCESS = 40\nuint8 TRANSITION_ON_DEACTIVATE_FAILURE = 41\nuint8 TRANSITION_ON_DEACTIVATE_ERROR = 42\nuint8 TRANSITION_ON_SHUTDOWN_SUCCESS = 50\nuint8 TRANSITION_ON_SHUTDOWN_FAILURE = 51\nuint8 TRANSITION_ON_SHUTDOWN_ERROR = 52\nuint8 TRANSITION_ON_ERROR_SUCCESS = 60\nuint8 TRANSITION_ON_ERROR_FAILURE = 61\nuint8 TRANSITION_ON_ERROR_ERROR = 62\nuint8 TRANSITION_CALLBACK_SUCCESS = 97\nuint8 TRANSITION_CALLBACK_FAILURE = 98\nuint8 TRANSITION_CALLBACK_ERROR = 99\nuint8 id\nstring label"
+        };
+    }
+
+    macro_rules! state_dependency {
+        () => {
+            concat!("================================================================================\nMSG: lifecycle_msgs/State\n", state_definition!())
+        };
+    }
+
+    macro_rules! transition_dependency {
+        () => {
+            concat!(
+                "================================================================================\nMSG: lifecycle_msgs/Transition\n",
+                transition_definition!(),
+                "\n"
+            )
+        };
+    }
+
+    macro_rules! transition_description_dependency {
+        () => {
+            concat!(
+                "================================================================================\nMSG: lifecycle_msgs/TransitionDescription\n",
+                "Transition transition\nState start_state\nState goal_state\n",
+                transition_dependency!(),
+                state_dependency!()
+            )
+        };
+    }
+
+    /// A state of a lifecycle node, the primary states having ids below 10
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct State {
+        pub id: u8,
+        pub label: String,
+    }
+
+    impl RosMessageType for State {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/State";
+        const MD5SUM: &'static str = "245b75da3b047157ebbb244e4013610e";
+        const DEFINITION: &'static str = state_definition!();
+    }
+
+    /// A transition between states of a lifecycle node, identified by its id or by its label when the id is 0
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct Transition {
+        pub id: u8,
+        pub label: String,
+    }
+
+    impl RosMessageType for Transition {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/Transition";
+        const MD5SUM: &'static str = "dda509813cb894fb24e590e6f08d0e21";
+        const DEFINITION: &'static str = transition_definition!();
+    }
+
+    /// A transition available from the current state, see [GetAvailableTransitions]
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct TransitionDescription {
+        pub transition: Transition,
+        pub start_state: State,
+        pub goal_state: State,
+    }
+
+    impl RosMessageType for TransitionDescription {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/TransitionDescription";
+        const MD5SUM: &'static str = "ea3ca1ca7e33761d71c4643d4b010d0b";
+        const DEFINITION: &'static str = concat!(
+            "Transition transition\nState start_state\nState goal_state\n",
+            transition_dependency!(),
+            state_dependency!()
+        );
+    }
+
+    /// Published on a lifecycle node's `transition_event` topic for every change of its state
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct TransitionEvent {
+        /// Nanoseconds since the unix epoch
+        pub timestamp: u64,
+        pub transition: Transition,
+        pub start_state: State,
+        pub goal_state: State,
+    }
+
+    impl RosMessageType for TransitionEvent {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/TransitionEvent";
+        const MD5SUM: &'static str = "3941065d18f1e94e70115adca77b622a";
+        const DEFINITION: &'static str = concat!(
+            "uint64 timestamp\nTransition transition\nState start_state\nState goal_state\n",
+            transition_dependency!(),
+            state_dependency!()
+        );
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct ChangeStateRequest {
+        pub transition: Transition,
+    }
+
+    impl RosMessageType for ChangeStateRequest {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/ChangeStateRequest";
+        const MD5SUM: &'static str = "1c403abe5f67cc59dc4d0303391c50ca";
+        const DEFINITION: &'static str =
+            concat!("Transition transition\n", transition_dependency!());
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct ChangeStateResponse {
+        pub success: bool,
+    }
+
+    impl RosMessageType for ChangeStateResponse {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/ChangeStateResponse";
+        const MD5SUM: &'static str = "358e233cde0c8a8bcfea4ce193f8fc15";
+        const DEFINITION: &'static str = "bool success";
+    }
+
+    /// Triggers a transition of a lifecycle node, succeeding when it reached the transition's goal state
+    pub struct ChangeState;
+
+    impl RosServiceType for ChangeState {
+        const ROS_SERVICE_NAME: &'static str = "lifecycle_msgs/ChangeState";
+        const MD5SUM: &'static str = "ffc3b243551f80931bfc3372959d41f2";
+        type Request = ChangeStateRequest;
+        type Response = ChangeStateResponse;
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct GetStateRequest {}
+
+    impl RosMessageType for GetStateRequest {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/GetStateRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "";
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct GetStateResponse {
+        pub current_state: State,
+    }
+
+    impl RosMessageType for GetStateResponse {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/GetStateResponse";
+        const MD5SUM: &'static str = "db428c05d10d50f6592ba030b8e4b051";
+        const DEFINITION: &'static str = concat!("State current_state\n", state_dependency!());
+    }
+
+    /// Returns the current state of a lifecycle node
+    pub struct GetState;
+
+    impl RosServiceType for GetState {
+        const ROS_SERVICE_NAME: &'static str = "lifecycle_msgs/GetState";
+        const MD5SUM: &'static str = "db428c05d10d50f6592ba030b8e4b051";
+        type Request = GetStateRequest;
+        type Response = GetStateResponse;
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct GetAvailableStatesRequest {}
+
+    impl RosMessageType for GetAvailableStatesRequest {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/GetAvailableStatesRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "";
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct GetAvailableStatesResponse {
+        pub available_states: Vec<State>,
+    }
+
+    impl RosMessageType for GetAvailableStatesResponse {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/GetAvailableStatesResponse";
+        const MD5SUM: &'static str = "4f932bc31559332a55e79933c010fa18";
+        const DEFINITION: &'static str = concat!("State[] available_states\n", state_dependency!());
+    }
+
+    /// Lists all states of a lifecycle node
+    pub struct GetAvailableStates;
+
+    impl RosServiceType for GetAvailableStates {
+        const ROS_SERVICE_NAME: &'static str = "lifecycle_msgs/GetAvailableStates";
+        const MD5SUM: &'static str = "4f932bc31559332a55e79933c010fa18";
+        type Request = GetAvailableStatesRequest;
+        type Response = GetAvailableStatesResponse;
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct GetAvailableTransitionsRequest {}
+
+    impl RosMessageType for GetAvailableTransitionsRequest {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/GetAvailableTransitionsRequest";
+        const MD5SUM: &'static str = "d41d8cd98f00b204e9800998ecf8427e";
+        const DEFINITION: &'static str = "";
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone, PartialEq)]
+    pub struct GetAvailableTransitionsResponse {
+        pub available_transitions: Vec<TransitionDescription>,
+    }
+
+    impl RosMessageType for GetAvailableTransitionsResponse {
+        const ROS_TYPE_NAME: &'static str = "lifecycle_msgs/GetAvailableTransitionsResponse";
+        const MD5SUM: &'static str = "a80c7b354bcdb5430ab0da0943807eb5";
+        const DEFINITION: &'static str = concat!(
+            "TransitionDescription[] available_transitions\n",
+            transition_description_dependency!()
+        );
+    }
+
+    /// Lists the transitions available from the current state of a lifecycle node
+    pub struct GetAvailableTransitions;
+
+    impl RosServiceType for GetAvailableTransitions {
+        const ROS_SERVICE_NAME: &'static str = "lifecycle_msgs/GetAvailableTransitions";
+        const MD5SUM: &'static str = "a80c7b354bcdb5430ab0da0943807eb5";
+        type Request = GetAvailableTransitionsRequest;
+        type Response = GetAvailableTransitionsResponse;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use roslibrust_common::md5sum;
+
+    struct Scripted {
+        result: CallbackReturn,
+        error_result: CallbackReturn,
+    }
+
+    impl LifecycleCallbacks for Scripted {
+        fn on_configure(&mut self, _previous_state: LifecycleState) -> CallbackReturn {
+            self.result
+        }
+
+        fn on_error(&mut self, _previous_state: LifecycleState) -> CallbackReturn {
+            self.error_result
+        }
+    }
+
+    fn new_machine(
+        result: CallbackReturn,
+        error_result: CallbackReturn,
+    ) -> (StateMachine, mpsc::UnboundedReceiver<TransitionEvent>) {
+        let (events, events_rx) = mpsc::unbounded_channel();
+        let (state_tx, _) = watch::channel(LifecycleState::Unconfigured);
+        let machine = StateMachine {
+            state: LifecycleState::Unconfigured,
+            callbacks: Box::new(Scripted {
+                result,
+                error_result,
+            }),
+            events,
+            state_tx,
+        };
+        (machine, events_rx)
+    }
+
+    /// Returns the (transition id, goal state id) of each event published so far
+    fn drain(events: &mut mpsc::UnboundedReceiver<TransitionEvent>) -> Vec<(u8, u8)> {
+        std::iter::from_fn(|| events.try_recv().ok())
+            .map(|event| (event.transition.id, event.goal_state.id))
+            .collect()
+    }
+
+    #[test]
+    fn runs_transitions() {
+        let (mut machine, mut events) =
+            new_machine(CallbackReturn::Success, CallbackReturn::Success);
+        for (transition, state) in [
+            (LifecycleTransition::Configure, LifecycleState::Inactive),
+            (LifecycleTransition::Activate, LifecycleState::Active),
+            (LifecycleTransition::Deactivate, LifecycleState::Inactive),
+            (LifecycleTransition::Cleanup, LifecycleState::Unconfigured),
+            (LifecycleTransition::Shutdown, LifecycleState::Finalized),
+        ] {
+            assert_eq!(
+                machine.trigger(transition).unwrap(),
+                CallbackReturn::Success
+            );
+            assert_eq!(machine.state, state);
+        }
+        assert_eq!(
+            drain(&mut events),
+            vec![
+                (1, 10),
+                (10, 2),
+                (3, 13),
+                (30, 3),
+                (4, 14),
+                (40, 2),
+                (2, 11),
+                (20, 1),
+                (5, 12),
+                (50, 4)
+            ]
+        );
+        assert!(machine.available_transitions().is_empty());
+    }
+
+    #[test]
+    fn handles_failures_and_errors() {
+        let (mut machine, mut events) =
+            new_machine(CallbackReturn::Failure, CallbackReturn::Success);
+        assert_eq!(
+            machine.trigger(LifecycleTransition::Configure).unwrap(),
+            CallbackReturn::Failure
+        );
+        assert_eq!(machine.state, LifecycleState::Unconfigured);
+        assert_eq!(drain(&mut events), vec![(1, 10), (11, 1)]);
+
+        let (mut machine, mut events) = new_machine(CallbackReturn::Error, CallbackReturn::Success);
+        machine.trigger(LifecycleTransition::Configure).unwrap();
+        assert_eq!(machine.state, LifecycleState::Unconfigured);
+        assert_eq!(drain(&mut events), vec![(1, 10), (12, 15), (60, 1)]);
+
+        let (mut machine, mut events) = new_machine(CallbackReturn::Error, CallbackReturn::Failure);
+        machine.trigger(LifecycleTransition::Configure).unwrap();
+        assert_eq!(machine.state, LifecycleState::Finalized);
+        assert_eq!(drain(&mut events), vec![(1, 10), (12, 15), (61, 4)]);
+    }
+
+    #[test]
+    fn resolves_requested_transitions() {
+        let (mut machine, mut events) =
+            new_machine(CallbackReturn::Success, CallbackReturn::Success);
+        assert!(machine.trigger(LifecycleTransition::Activate).is_err());
+        assert!(drain(&mut events).is_empty());
+
+        let by_label = Transition {
+            id: 0,
+            label: "configure".to_string(),
+        };
+        assert_eq!(
+            LifecycleTransition::from_msg(&by_label, machine.state),
+            Some(LifecycleTransition::Configure)
+        );
+        machine.trigger(LifecycleTransition::Configure).unwrap();
+
+        let shutdown = Transition {
+            id: 6,
+            label: String::new(),
+        };
+        assert_eq!(
+            LifecycleTransition::from_msg(&shutdown, machine.state),
+            Some(LifecycleTransition::Shutdown)
+        );
+        let available: Vec<_> = machine
+            .available_transitions()
+            .into_iter()
+            .map(|t| t.transition.id)
+            .collect();
+        assert_eq!(available, vec![2, 3, 6]);
+    }
+
+    #[test]
+    fn md5sums_match_definitions() {
+        use lifecycle_msgs::*;
+
+        fn check<T: RosMessageType>() {
+            let name = T::ROS_TYPE_NAME;
+            if T::DEFINITION.is_empty() {
+                assert_eq!(T::MD5SUM, "d41d8cd98f00b204e9800998ecf8427e", "{name}");
+            } else {
+                let md5sum = md5sum::from_message_definition(name, T::DEFINITION).unwrap();
+                assert_eq!(T::MD5SUM, md5sum, "{name}");
+            }
+        }
+
+        fn check_srv<T: RosServiceType>() {
+            check::<T::Request>();
+            check::<T::Response>();
+            let md5sum = md5sum::from_service_definition(
+                T::ROS_SERVICE_NAME,
+                T::Request::DEFINITION,
+                T::Response::DEFINITION,
+            )
+            .unwrap();
+            assert_eq!(T::MD5SUM, md5sum, "{}", T::ROS_SERVICE_NAME);
+        }
+
+        check::<State>();
+        check::<Transition>();
+        check::<TransitionDescription>();
+        check::<TransitionEvent>();
+        check_srv::<ChangeState>();
+        check_srv::<GetState>();
+        check_srv::<GetAvailableStates>();
+        check_srv::<GetAvailableTransitions>();
+    }
+}